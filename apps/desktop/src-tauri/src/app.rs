@@ -64,6 +64,9 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             app.manage(crate::state::OptionKeyDatabase::new(pool.clone()));
             app.manage(crate::state::GoogleOAuthState::from_env());
             app.manage(crate::state::OverlayState::new());
+            app.manage(crate::state::McpPermissionState::new());
+            app.manage(crate::state::MediaImportState::new());
+            app.manage(crate::state::ActiveAppProfileState::new());
 
             match app.handle().path().app_data_dir() {
                 Ok(app_data_dir) => crate::system::crypto::init_crypto(&app_data_dir),
@@ -97,9 +100,45 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
                 let recorder: Arc<dyn Recorder> =
                     Arc::new(crate::platform::audio::RecordingManager::new());
 
+                crate::system::warm_microphone::start(Arc::clone(&recorder), pool.clone());
+                crate::system::audio_devices::start_watcher(
+                    app_handle.clone(),
+                    Arc::clone(&recorder),
+                );
+
+                let pool_for_calibration = pool.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(err) =
+                        crate::system::mic_calibration::apply_saved(pool_for_calibration).await
+                    {
+                        eprintln!("[app] Failed to load microphone calibrations: {err}");
+                    }
+                });
+
                 app.manage(recorder);
                 app.manage(transcriber_state);
                 app.manage(crate::system::meeting_audio_store::MeetingAudioWriterState::new());
+                app.manage(crate::state::LocalApiState::new());
+                app.manage(crate::state::AmbientState::new());
+
+                let pool_for_api = pool.clone();
+                let app_handle_for_api = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(err) = start_local_api(&app_handle_for_api, pool_for_api).await {
+                        eprintln!("[app] Failed to start local API: {err}");
+                    }
+                });
+
+                app.manage(crate::state::RetentionState::new());
+                app.state::<crate::state::RetentionState>()
+                    .start(app_handle.clone(), pool.clone());
+
+                match crate::system::mcp_socket::McpSocketServer::start(app_handle.clone()) {
+                    Ok(server) => {
+                        app.manage(server);
+                    }
+                    Err(err) => eprintln!("[app] Failed to start MCP server: {err}"),
+                }
 
                 let pool_for_bg = pool.clone();
                 let app_handle_for_bg = app_handle.clone();
@@ -192,6 +231,9 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::start_google_sign_in,
             crate::commands::user_preferences_set,
             crate::commands::list_microphones,
+            crate::commands::watch_microphones,
+            crate::commands::microphone_calibrate,
+            crate::commands::microphone_calibration_list,
             crate::commands::list_gpus,
             crate::commands::get_screen_visible_area,
             crate::commands::get_monitor_at_cursor,
@@ -202,27 +244,52 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::get_current_app_info,
             crate::commands::app_target_upsert,
             crate::commands::app_target_list,
+            crate::commands::transcript_normalize,
+            crate::commands::app_profile_upsert,
+            crate::commands::app_profile_list,
+            crate::commands::app_profile_delete,
+            crate::commands::app_profile_resolve,
+            crate::commands::post_process_prompt,
+            crate::commands::post_process_transcript,
             crate::commands::start_recording,
             crate::commands::stop_recording,
+            crate::commands::ambient_arm,
+            crate::commands::ambient_disarm,
+            crate::commands::ambient_status,
             crate::commands::store_transcription_audio,
             crate::commands::storage_upload_data,
             crate::commands::storage_get_download_url,
             crate::commands::transcribe_audio,
+            crate::commands::transcribe_audio_detailed,
             crate::commands::surface_main_window,
             crate::commands::set_toast_overlay_click_through,
             crate::commands::set_agent_overlay_click_through,
             crate::commands::restore_overlay_focus,
             crate::commands::paste,
+            crate::commands::dictation_parse,
+            crate::commands::paste_dictation,
             crate::commands::transcription_create,
             crate::commands::transcription_list,
             crate::commands::transcription_delete,
             crate::commands::transcription_update,
             crate::commands::transcription_audio_load,
             crate::commands::purge_stale_transcription_audio,
+            crate::commands::retention_policy_list,
+            crate::commands::retention_policy_save,
+            crate::commands::retention_report,
+            crate::commands::retention_run,
+            crate::commands::retention_set_starred,
+            crate::commands::analytics_usage,
             crate::commands::term_create,
             crate::commands::term_update,
             crate::commands::term_list,
             crate::commands::term_delete,
+            crate::commands::term_suggestion_list,
+            crate::commands::term_suggestion_review,
+            crate::commands::voice_command_create,
+            crate::commands::voice_command_update,
+            crate::commands::voice_command_list,
+            crate::commands::voice_command_delete,
             crate::commands::hotkey_list,
             crate::commands::hotkey_save,
             crate::commands::hotkey_delete,
@@ -232,6 +299,8 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::api_key_delete,
             crate::commands::api_key_update,
             crate::commands::tone_upsert,
+            crate::commands::tone_render,
+            crate::commands::tone_preview,
             crate::commands::tone_list,
             crate::commands::tone_get,
             crate::commands::tone_delete,
@@ -251,20 +320,57 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::conversation_delete,
             crate::commands::message_create,
             crate::commands::message_list,
+            crate::commands::conversation_branch,
+            crate::commands::conversation_switch_branch,
+            crate::commands::conversation_context,
+            crate::commands::conversation_summary_create,
+            crate::commands::conversation_fork,
             crate::commands::meeting_create,
             crate::commands::meeting_list,
             crate::commands::meeting_update,
             crate::commands::meeting_delete,
             crate::commands::meeting_segment_list,
+            crate::commands::meeting_action_item_list,
+            crate::commands::meeting_action_item_list_open,
+            crate::commands::meeting_action_item_create,
+            crate::commands::meeting_action_item_update,
+            crate::commands::meeting_action_item_delete,
+            crate::commands::meeting_action_items_replace,
+            crate::commands::meeting_decision_list,
+            crate::commands::meeting_decision_create,
+            crate::commands::meeting_decision_update,
+            crate::commands::meeting_decision_delete,
+            crate::commands::meeting_decisions_replace,
+            crate::commands::meeting_ask,
+            crate::commands::meeting_ask_prepare,
+            crate::commands::meeting_ask_record,
+            crate::commands::meeting_conversation_get,
+            crate::commands::meeting_export,
+            crate::commands::transcription_export,
+            crate::commands::media_import,
+            crate::commands::media_import_cancel,
             crate::commands::meeting_segments_create_batch,
             crate::commands::meeting_segment_rename_speaker,
             crate::commands::meeting_start_audio_writer,
             crate::commands::meeting_append_audio_chunk,
             crate::commands::meeting_finalize_audio_writer,
             crate::commands::meeting_audio_load,
+            crate::commands::local_api_status,
+            crate::commands::local_api_configure,
+            crate::commands::mcp_permission_respond,
         ])
 }
 
+/// Stops background work that must not be cut off mid-write when the app
+/// exits.
+pub fn handle_run_event(app: &tauri::AppHandle, event: tauri::RunEvent) {
+    if let tauri::RunEvent::Exit = event {
+        if let Some(retention) = app.try_state::<crate::state::RetentionState>() {
+            retention.stop();
+        }
+    }
+}
+
 async fn initialize_transcriber_background(app: &tauri::AppHandle) -> Result<(), String> {
     use std::sync::Arc;
     use tauri::Manager;
@@ -293,3 +399,19 @@ async fn initialize_transcriber_background(app: &tauri::AppHandle) -> Result<(),
 
     Ok(())
 }
+
+/// Start the loopback API at launch if the user enabled it.
+async fn start_local_api(app: &tauri::AppHandle, pool: sqlx::SqlitePool) -> Result<(), String> {
+    let settings = crate::db::local_api_queries::fetch_local_api_settings(pool)
+        .await
+        .map_err(|err| err.to_string())?;
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let verifier = crate::system::local_api::TokenVerifier::from_settings(&settings)
+        .ok_or_else(|| "Local API token is missing".to_string())?;
+    app.state::<crate::state::LocalApiState>()
+        .restart(app.clone(), settings.port, verifier)
+        .map(|_| ())
+}
@@ -0,0 +1,735 @@
+//! Headless command-line mode (`OSVoice cli <command>`), for scripting batch
+//! jobs and CI checks against the local Whisper stack and `osvoice.db`.
+//! Every command prints JSON to stdout; failures print `{"error": …}` to
+//! stderr and exit non-zero.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::net::{Ipv4Addr, Shutdown, TcpStream};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+use thiserror::Error;
+
+use crate::domain::{
+    McpEndpoint, Term, TimestampGranularity, Tone, TranscriptExportFormat, TranscriptExportOptions,
+};
+use crate::platform::{Transcriber, TranscriptionRequest};
+use crate::system::ids::new_id;
+use crate::system::mcp::{McpHost, McpSession};
+use crate::system::models::WhisperModelSize;
+use crate::system::term_engine::TermEngine;
+use crate::system::transcript_export::ExportDocument;
+
+pub const CLI_ARG: &str = "cli";
+
+const DEFAULT_LIST_LIMIT: u32 = 50;
+const BOOLEAN_FLAGS: &[&str] = &[
+    "pretty",
+    "download",
+    "no-dictionary",
+    "help",
+    "no-speakers",
+    "merge-speakers",
+];
+
+const USAGE: &str = "\
+Usage: OSVoice cli <command> [options]
+
+Commands:
+  transcribe <file>...               Transcribe audio or video files
+      --model <size>                 tiny, base, small or medium (default: base)
+      --language <code>              Spoken language, e.g. en (default: app setting)
+      --prompt <text>                Initial prompt instead of the dictionary glossary
+      --download                     Download the model if it is missing
+      --no-dictionary                Skip dictionary prompting and replacements
+  transcriptions list                [--limit N] [--offset N]
+  transcriptions search <query>      [--limit N]
+  transcriptions export              [--format json|txt] [--limit N]
+  meetings list                      [--limit N] [--offset N]
+  meetings actions                   [--limit N] [--offset N]
+  meetings show <id>
+  meetings export <id>               [--format json|txt|md|srt|vtt]
+      --timestamps <precision>       none, seconds or ms (default: seconds)
+      --no-speakers                  Leave out speaker labels
+      --merge-speakers               Join consecutive lines from the same speaker
+  models list
+  models download <size>
+  models remove <size>
+  terms list
+  terms add <phrase>                 [--replacement <text>]
+  terms remove <id>
+  tones list
+  tones add --name <name> --template <text>
+  tones remove <id>
+  mcp                                Serve the MCP protocol on stdin/stdout. Bridges to
+                                     the running app, or serves history only when the
+                                     app is closed
+
+Global options:
+  --db <path>                        Database file (env OSVOICE_DB_PATH)
+  --models-dir <path>                Whisper model directory (env OSVOICE_MODELS_DIR)
+  --app-id <identifier>              App identifier used to find the default
+                                     directories (env OSVOICE_APP_IDENTIFIER,
+                                     default: this build's identifier)
+  --pretty                           Pretty-print JSON output
+";
+
+#[derive(Debug, Error)]
+enum CliError {
+    #[error("{0}")]
+    Usage(String),
+    #[error("{0}")]
+    Failed(String),
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("{0}")]
+    Io(#[from] io::Error),
+}
+
+type CliResult<T = Value> = Result<T, CliError>;
+
+/// Run the CLI with the arguments that follow `cli` and return the process
+/// exit code. `app_identifier` is this build's Tauri identifier, used to find
+/// the app's directories unless overridden.
+pub fn run(args: Vec<String>, app_identifier: &str) -> i32 {
+    let parsed = match ParsedArgs::parse(args) {
+        Ok(parsed) => parsed,
+        Err(err) => return report(err),
+    };
+    if parsed.positional.is_empty() || parsed.has("help") {
+        print!("{USAGE}");
+        return 0;
+    }
+
+    let cli = Cli::from_args(&parsed, app_identifier);
+    if parsed.positional[0] == "mcp" {
+        return match cli.serve_mcp() {
+            Ok(()) => 0,
+            Err(err) => report(err),
+        };
+    }
+
+    match cli.dispatch(&parsed) {
+        Ok(value) => {
+            let output = if cli.pretty {
+                serde_json::to_string_pretty(&value)
+            } else {
+                serde_json::to_string(&value)
+            };
+            match output {
+                Ok(output) => {
+                    println!("{output}");
+                    0
+                }
+                Err(err) => report(CliError::Failed(err.to_string())),
+            }
+        }
+        Err(err) => report(err),
+    }
+}
+
+fn report(err: CliError) -> i32 {
+    eprintln!("{}", json!({ "error": err.to_string() }));
+    match err {
+        CliError::Usage(_) => {
+            eprint!("\n{USAGE}");
+            2
+        }
+        _ => 1,
+    }
+}
+
+struct ParsedArgs {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl ParsedArgs {
+    fn parse(args: Vec<String>) -> CliResult<Self> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut iter = args.into_iter();
+
+        while let Some(arg) = iter.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                positional.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = name.split_once('=') {
+                options.insert(name.to_string(), value.to_string());
+            } else if BOOLEAN_FLAGS.contains(&name) {
+                options.insert(name.to_string(), String::new());
+            } else {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| CliError::Usage(format!("Missing value for --{name}")))?;
+                options.insert(name.to_string(), value);
+            }
+        }
+
+        Ok(Self {
+            positional,
+            options,
+        })
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    fn required_option(&self, name: &str) -> CliResult<&str> {
+        self.option(name)
+            .filter(|value| !value.trim().is_empty())
+            .ok_or_else(|| CliError::Usage(format!("Missing --{name}")))
+    }
+
+    fn number(&self, name: &str, default: u32) -> CliResult<u32> {
+        match self.option(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| CliError::Usage(format!("--{name} must be a number"))),
+            None => Ok(default),
+        }
+    }
+
+    fn argument(&self, index: usize, name: &str) -> CliResult<&str> {
+        self.positional
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| CliError::Usage(format!("Missing <{name}>")))
+    }
+}
+
+struct Cli {
+    db_path: PathBuf,
+    models_dir: PathBuf,
+    mcp_endpoint_path: Option<PathBuf>,
+    pretty: bool,
+}
+
+impl Cli {
+    fn from_args(args: &ParsedArgs, app_identifier: &str) -> Self {
+        let setting = |option: &str, env: &str| {
+            args.option(option)
+                .map(str::to_string)
+                .or_else(|| std::env::var(env).ok())
+                .filter(|value| !value.trim().is_empty())
+        };
+        let identifier = setting("app-id", "OSVOICE_APP_IDENTIFIER")
+            .unwrap_or_else(|| app_identifier.to_string());
+
+        let db_path = setting("db", "OSVOICE_DB_PATH")
+            .map(PathBuf::from)
+            .or_else(|| {
+                crate::system::paths::standalone_config_dir(&identifier)
+                    .map(|dir| dir.join(crate::db::DB_FILENAME))
+            })
+            .unwrap_or_else(|| PathBuf::from(crate::db::DB_FILENAME));
+        let models_dir = setting("models-dir", "OSVOICE_MODELS_DIR")
+            .map(PathBuf::from)
+            .or_else(|| crate::system::paths::standalone_models_dir(&identifier))
+            .unwrap_or_else(|| PathBuf::from("models"));
+
+        Self {
+            db_path,
+            models_dir,
+            mcp_endpoint_path: crate::system::paths::standalone_mcp_endpoint_path(&identifier),
+            pretty: args.has("pretty"),
+        }
+    }
+
+    fn dispatch(&self, args: &ParsedArgs) -> CliResult {
+        let command = args.argument(0, "command")?;
+        let subcommand = args.positional.get(1).map(String::as_str);
+
+        match (command, subcommand) {
+            ("transcribe", _) => self.transcribe(args),
+            ("transcriptions", Some("list")) => self.with_db(|pool| async move {
+                let transcriptions = crate::db::transcription_queries::fetch_transcriptions(
+                    pool,
+                    args.number("limit", DEFAULT_LIST_LIMIT)?,
+                    args.number("offset", 0)?,
+                )
+                .await?;
+                to_json(&transcriptions)
+            }),
+            ("transcriptions", Some("search")) => self.with_db(|pool| async move {
+                let transcriptions = crate::db::transcription_queries::search_transcriptions(
+                    pool,
+                    args.argument(2, "query")?,
+                    args.number("limit", DEFAULT_LIST_LIMIT)?,
+                )
+                .await?;
+                to_json(&transcriptions)
+            }),
+            ("transcriptions", Some("export")) => self.with_db(|pool| async move {
+                let transcriptions = crate::db::transcription_queries::fetch_transcriptions(
+                    pool,
+                    args.number("limit", u32::MAX)?,
+                    0,
+                )
+                .await?;
+                match export_format(args)? {
+                    ExportFormat::Json => to_json(&transcriptions),
+                    ExportFormat::Text => Ok(Value::String(
+                        transcriptions
+                            .iter()
+                            .map(|transcription| transcription.transcript.trim())
+                            .collect::<Vec<_>>()
+                            .join("\n\n"),
+                    )),
+                }
+            }),
+            ("meetings", Some("list")) => self.with_db(|pool| async move {
+                let meetings = crate::db::meeting_queries::fetch_meetings(
+                    pool,
+                    args.number("limit", DEFAULT_LIST_LIMIT)?,
+                    args.number("offset", 0)?,
+                )
+                .await?;
+                to_json(&meetings)
+            }),
+            ("meetings", Some("actions")) => self.with_db(|pool| async move {
+                let items = crate::db::meeting_queries::fetch_open_action_items(
+                    pool,
+                    args.number("limit", DEFAULT_LIST_LIMIT)?,
+                    args.number("offset", 0)?,
+                )
+                .await?;
+                to_json(&items)
+            }),
+            ("meetings", Some("show")) => self.with_db(|pool| async move {
+                to_json(&load_meeting(pool, args.argument(2, "id")?).await?)
+            }),
+            ("meetings", Some("export")) => self.with_db(|pool| async move {
+                let details = load_meeting(pool, args.argument(2, "id")?).await?;
+                let options = meeting_export_options(args)?;
+                if options.format == TranscriptExportFormat::Json {
+                    return to_json(&details);
+                }
+
+                let document = ExportDocument::from_meeting(
+                    &details.meeting,
+                    &details.segments,
+                    &details.action_items,
+                    &details.decisions,
+                );
+                let export = crate::system::transcript_export::export(&document, &options)
+                    .map_err(CliError::Failed)?;
+                Ok(Value::String(export.content))
+            }),
+            ("models", Some("list")) => self.list_models(),
+            ("models", Some("download")) => {
+                let size = parse_model_size(args.argument(2, "size")?)?;
+                let path = self.models_dir.join(size.filename());
+                crate::system::models::ensure_whisper_model_at(&path, size)?;
+                Ok(self.model_entry(size))
+            }
+            ("models", Some("remove")) => {
+                let size = parse_model_size(args.argument(2, "size")?)?;
+                let path = self.models_dir.join(size.filename());
+                let removed = match std::fs::remove_file(&path) {
+                    Ok(()) => true,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => false,
+                    Err(err) => return Err(err.into()),
+                };
+                Ok(json!({ "size": size.as_str(), "removed": removed }))
+            }
+            ("terms", Some("list")) => self.with_db(|pool| async move {
+                to_json(&crate::db::term_queries::fetch_terms(pool).await?)
+            }),
+            ("terms", Some("add")) => self.with_db(|pool| async move {
+                let source = args.argument(2, "phrase")?.trim();
+                if source.is_empty() {
+                    return Err(CliError::Usage("Term phrase cannot be empty".into()));
+                }
+                let replacement = args.option("replacement").map(str::trim);
+                let term = Term {
+                    id: new_id(),
+                    created_at: chrono::Utc::now().timestamp_millis(),
+                    created_by_user_id: String::new(),
+                    source_value: source.to_string(),
+                    destination_value: replacement.unwrap_or_default().to_string(),
+                    is_replacement: replacement.is_some(),
+                    is_deleted: false,
+                };
+                to_json(&crate::db::term_queries::insert_term(pool, &term).await?)
+            }),
+            ("terms", Some("remove")) => self.with_db(|pool| async move {
+                let id = args.argument(2, "id")?;
+                crate::db::term_queries::delete_term(pool, id).await?;
+                Ok(json!({ "id": id, "removed": true }))
+            }),
+            ("tones", Some("list")) => self.with_db(|pool| async move {
+                to_json(&crate::db::tone_queries::fetch_all_tones(pool).await?)
+            }),
+            ("tones", Some("add")) => self.with_db(|pool| async move {
+                let template = args.required_option("template")?;
+                let issues = crate::system::tone_template::validate(template);
+                if !issues.is_empty() {
+                    return Err(CliError::Failed(format!(
+                        "Invalid tone template. {}",
+                        crate::system::tone_template::describe_issues(&issues)
+                    )));
+                }
+                let sort_order = crate::db::tone_queries::count_tones(pool.clone()).await?;
+                let tone = Tone {
+                    id: new_id(),
+                    name: args.required_option("name")?.trim().to_string(),
+                    prompt_template: template.to_string(),
+                    created_at: chrono::Utc::now().timestamp_millis(),
+                    sort_order: i32::try_from(sort_order).unwrap_or(i32::MAX),
+                };
+                to_json(&crate::db::tone_queries::insert_tone(pool, &tone).await?)
+            }),
+            ("tones", Some("remove")) => self.with_db(|pool| async move {
+                let id = args.argument(2, "id")?;
+                crate::db::tone_queries::delete_tone(pool, id).await?;
+                Ok(json!({ "id": id, "removed": true }))
+            }),
+            _ => Err(CliError::Usage(format!(
+                "Unknown command '{}'",
+                args.positional.join(" ")
+            ))),
+        }
+    }
+
+    /// Bridge stdio to the running app's MCP socket so every tool is
+    /// available, or serve the read-only tools from the database directly.
+    fn serve_mcp(&self) -> CliResult<()> {
+        if let Some(stream) = self.connect_to_app() {
+            let mut upstream = stream.try_clone()?;
+            std::thread::spawn(move || {
+                let _ = io::copy(&mut io::stdin().lock(), &mut upstream);
+                let _ = upstream.shutdown(Shutdown::Write);
+            });
+            io::copy(&mut &stream, &mut io::stdout().lock())?;
+            return Ok(());
+        }
+
+        let host = StandaloneMcpHost {
+            pool: self.open_db()?,
+        };
+        McpSession::new(host).serve(io::stdin().lock(), io::stdout().lock())?;
+        Ok(())
+    }
+
+    fn connect_to_app(&self) -> Option<TcpStream> {
+        let contents = std::fs::read(self.mcp_endpoint_path.as_ref()?).ok()?;
+        let endpoint: McpEndpoint = serde_json::from_slice(&contents).ok()?;
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, endpoint.port)).ok()?;
+        writeln!(stream, "{}", endpoint.token).ok()?;
+        Some(stream)
+    }
+
+    fn with_db<F, Fut>(&self, run: F) -> CliResult
+    where
+        F: FnOnce(SqlitePool) -> Fut,
+        Fut: std::future::Future<Output = CliResult>,
+    {
+        let pool = self.open_db()?;
+        tauri::async_runtime::block_on(async move {
+            let result = run(pool.clone()).await;
+            pool.close().await;
+            result
+        })
+    }
+
+    /// Open the app's existing database and apply any migrations a newer
+    /// build added since the app last ran. A missing file is an error rather
+    /// than created here.
+    fn open_db(&self) -> CliResult<SqlitePool> {
+        if !self.db_path.exists() {
+            return Err(CliError::Failed(format!(
+                "Database not found at {}. Start the app once or pass --db.",
+                self.db_path.display()
+            )));
+        }
+
+        let options =
+            SqliteConnectOptions::from_str(&format!("sqlite:{}", self.db_path.to_string_lossy()))?
+                .create_if_missing(false);
+        tauri::async_runtime::block_on(async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect_with(options)
+                .await?;
+            crate::db::run_pending_migrations(&pool)
+                .await
+                .map_err(|err| CliError::Failed(format!("Database migration failed: {err}")))?;
+            Ok(pool)
+        })
+    }
+
+    fn load_dictionary(&self) -> CliResult<(TermEngine, Option<String>)> {
+        let pool = self.open_db()?;
+        tauri::async_runtime::block_on(async move {
+            let engine = TermEngine::load(pool.clone()).await;
+            let preferences = crate::db::preferences_queries::fetch_user_preferences(
+                pool.clone(),
+                crate::db::preferences_queries::LOCAL_USER_ID,
+            )
+            .await;
+            pool.close().await;
+            let language =
+                preferences?.and_then(|preferences| preferences.active_dictation_language);
+            Ok((engine?, language))
+        })
+    }
+
+    fn list_models(&self) -> CliResult {
+        Ok(Value::Array(
+            WhisperModelSize::ALL
+                .into_iter()
+                .map(|size| self.model_entry(size))
+                .collect(),
+        ))
+    }
+
+    fn model_entry(&self, size: WhisperModelSize) -> Value {
+        let path = self.models_dir.join(size.filename());
+        let bytes = std::fs::metadata(&path).ok().map(|metadata| metadata.len());
+        json!({
+            "size": size.as_str(),
+            "path": path,
+            "downloaded": bytes.is_some(),
+            "bytes": bytes,
+        })
+    }
+
+    fn transcribe(&self, args: &ParsedArgs) -> CliResult {
+        let files: Vec<&Path> = args.positional[1..].iter().map(Path::new).collect();
+        if files.is_empty() {
+            return Err(CliError::Usage("Missing <file>".into()));
+        }
+
+        let size = parse_model_size(args.option("model").unwrap_or("base"))?;
+        let model_path = self.models_dir.join(size.filename());
+        if args.has("download") {
+            crate::system::models::ensure_whisper_model_at(&model_path, size)?;
+        } else if !model_path.exists() {
+            return Err(CliError::Failed(format!(
+                "Whisper model '{size}' is not downloaded. Run `models download {size}` or pass --download."
+            )));
+        }
+
+        // The dictionary and default language come from the app database
+        // when there is one; transcription itself works without it.
+        let (term_engine, default_language) = if args.has("no-dictionary") || !self.db_path.exists()
+        {
+            (TermEngine::default(), None)
+        } else {
+            self.load_dictionary()?
+        };
+
+        let locale = args
+            .option("language")
+            .map(str::to_string)
+            .or(default_language);
+        let transcriber = crate::platform::whisper::WhisperTranscriber::new(&model_path)
+            .map_err(CliError::Failed)?;
+        let mut request = TranscriptionRequest {
+            language: locale.as_deref().map(language_code),
+            ..TranscriptionRequest::default()
+        };
+        let count_tokens = |text: &str| {
+            transcriber
+                .count_tokens(text, Some(&request))
+                .unwrap_or_else(|| crate::system::term_engine::estimate_tokens(text))
+        };
+        let initial_prompt = match args.option("prompt") {
+            Some(prompt) => crate::system::term_engine::bound_initial_prompt(prompt, &count_tokens),
+            None => Some(
+                term_engine.initial_prompt(
+                    locale
+                        .as_deref()
+                        .and_then(crate::system::term_engine::locale_prompt_preamble),
+                    &count_tokens,
+                ),
+            ),
+        };
+        request.initial_prompt = initial_prompt;
+
+        let mut results = Vec::with_capacity(files.len());
+        for file in files {
+            let (samples, sample_rate) = crate::system::audio_file::decode_audio_file(file)
+                .map_err(|err| CliError::Failed(format!("{}: {err}", file.display())))?;
+            let started = Instant::now();
+            let text = transcriber
+                .transcribe(&samples, sample_rate, Some(&request))
+                .map_err(|err| CliError::Failed(format!("{}: {err}", file.display())))?;
+            results.push(json!({
+                "file": file,
+                "text": term_engine.apply_replacements(&text),
+                "model": size.as_str(),
+                "language": request.language,
+                "audioDurationMs": samples.len() as u64 * 1000 / u64::from(sample_rate.max(1)),
+                "transcriptionDurationMs": started.elapsed().as_millis() as u64,
+            }));
+        }
+
+        Ok(Value::Array(results))
+    }
+}
+
+/// Whisper takes a bare language code, so "pt-BR" becomes "pt".
+fn language_code(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_ascii_lowercase()
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MeetingDetails {
+    meeting: crate::domain::Meeting,
+    segments: Vec<crate::domain::MeetingSegment>,
+    action_items: Vec<crate::domain::MeetingActionItem>,
+    decisions: Vec<crate::domain::MeetingDecision>,
+}
+
+async fn load_meeting(pool: SqlitePool, id: &str) -> CliResult<MeetingDetails> {
+    let meeting = crate::db::meeting_queries::fetch_meeting(pool.clone(), id)
+        .await?
+        .ok_or_else(|| CliError::Failed(format!("Meeting '{id}' not found")))?;
+    let segments = crate::db::meeting_queries::fetch_meeting_segments(pool.clone(), id).await?;
+    let action_items =
+        crate::db::meeting_queries::fetch_meeting_action_items(pool.clone(), id).await?;
+    let decisions = crate::db::meeting_queries::fetch_meeting_decisions(pool, id).await?;
+    Ok(MeetingDetails {
+        meeting,
+        segments,
+        action_items,
+        decisions,
+    })
+}
+
+enum ExportFormat {
+    Json,
+    Text,
+}
+
+fn export_format(args: &ParsedArgs) -> CliResult<ExportFormat> {
+    match args.option("format").unwrap_or("json") {
+        "json" => Ok(ExportFormat::Json),
+        "txt" | "text" => Ok(ExportFormat::Text),
+        other => Err(CliError::Usage(format!("Unknown export format '{other}'"))),
+    }
+}
+
+fn meeting_export_options(args: &ParsedArgs) -> CliResult<TranscriptExportOptions> {
+    let format = match args.option("format").unwrap_or("json") {
+        "json" => TranscriptExportFormat::Json,
+        "txt" | "text" => TranscriptExportFormat::Text,
+        "md" | "markdown" => TranscriptExportFormat::Markdown,
+        "srt" => TranscriptExportFormat::Srt,
+        "vtt" => TranscriptExportFormat::Vtt,
+        other => return Err(CliError::Usage(format!("Unknown export format '{other}'"))),
+    };
+    let timestamps = match args.option("timestamps").unwrap_or("seconds") {
+        "none" => TimestampGranularity::None,
+        "seconds" => TimestampGranularity::Seconds,
+        "ms" | "milliseconds" => TimestampGranularity::Milliseconds,
+        other => {
+            return Err(CliError::Usage(format!(
+                "Unknown timestamp precision '{other}'"
+            )))
+        }
+    };
+
+    Ok(TranscriptExportOptions {
+        format,
+        speaker_labels: !args.has("no-speakers"),
+        timestamps,
+        merge_speakers: args.has("merge-speakers"),
+        merge_gap_ms: None,
+    })
+}
+
+fn parse_model_size(value: &str) -> CliResult<WhisperModelSize> {
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("Unknown model size '{value}'")))
+}
+
+/// MCP host for when the app is closed. Clients can still list the tools,
+/// but every call needs the app's permission prompt, so calls explain that
+/// the app has to be running.
+struct StandaloneMcpHost {
+    pool: SqlitePool,
+}
+
+impl StandaloneMcpHost {
+    fn unavailable(tool: &str) -> String {
+        format!("{tool} needs the OSVoice app to be running")
+    }
+}
+
+impl McpHost for StandaloneMcpHost {
+    fn pool(&self) -> Result<SqlitePool, String> {
+        Ok(self.pool.clone())
+    }
+
+    fn approve(&self, _client: &str, tool: &str, _summary: &str) -> Result<(), String> {
+        Err(Self::unavailable(tool))
+    }
+
+    fn dictate(&self, _duration: Duration) -> Result<String, String> {
+        Err(Self::unavailable("dictate"))
+    }
+
+    fn paste_text(&self, _text: &str) -> Result<(), String> {
+        Err(Self::unavailable("paste_text"))
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> CliResult {
+    serde_json::to_value(value).map_err(|err| CliError::Failed(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_flags_and_options() {
+        let parsed = ParsedArgs::parse(args(&[
+            "transcribe",
+            "a.wav",
+            "--model",
+            "small",
+            "--pretty",
+            "--language=pt-BR",
+            "b.flac",
+        ]))
+        .unwrap();
+        assert_eq!(parsed.positional, ["transcribe", "a.wav", "b.flac"]);
+        assert_eq!(parsed.option("model"), Some("small"));
+        assert_eq!(
+            parsed.option("language").map(language_code),
+            Some("pt".into())
+        );
+        assert!(parsed.has("pretty"));
+
+        assert!(matches!(
+            ParsedArgs::parse(args(&["terms", "add", "Kubernetes", "--replacement"])),
+            Err(CliError::Usage(_))
+        ));
+    }
+}
@@ -24,6 +24,8 @@ use crate::platform::input::paste_text_into_focused_field as platform_paste_text
 pub struct StopRecordingResponse {
     pub samples: Vec<f32>,
     pub sample_rate: u32,
+    /// Problems with the recording itself, such as a device switch.
+    pub warnings: Vec<String>,
 }
 
 #[derive(serde::Serialize)]
@@ -36,7 +38,12 @@ pub struct StartRecordingResponse {
 #[serde(rename_all = "camelCase")]
 pub struct CurrentAppInfoResponse {
     pub app_name: String,
+    /// Id the app is registered under; `None` when the name has no letters
+    /// or digits.
+    pub app_target_id: Option<String>,
     pub icon_base64: String,
+    pub app_identifier: Option<String>,
+    pub window_title: Option<String>,
 }
 
 #[derive(serde::Serialize)]
@@ -64,6 +71,11 @@ pub struct AppTargetUpsertArgs {
     pub icon_path: Option<String>,
     #[serde(default)]
     pub paste_keybind: Option<String>,
+    #[serde(default)]
+    pub itn_enabled: Option<bool>,
+    /// Omitted to keep the stored value; an empty string clears it.
+    #[serde(default)]
+    pub translation_language: Option<String>,
 }
 
 #[derive(serde::Deserialize)]
@@ -88,7 +100,7 @@ pub struct StartRecordingArgs {
     pub preferred_microphone: Option<String>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranscriptionDeviceSelectionDto {
     #[serde(default)]
@@ -117,7 +129,7 @@ impl TranscriptionDeviceSelectionDto {
     }
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranscriptionOptionsDto {
     #[serde(default)]
@@ -126,6 +138,78 @@ pub struct TranscriptionOptionsDto {
     pub initial_prompt: Option<String>,
     #[serde(default)]
     pub language: Option<String>,
+    /// App profile whose language, model size and dictionary take precedence.
+    #[serde(default)]
+    pub profile_id: Option<String>,
+    /// Have Whisper translate the speech to English.
+    #[serde(default)]
+    pub translate: bool,
+    /// The user's languages. When detection is on in the preferences and the
+    /// app profile sets no language, the spoken one is detected among these.
+    #[serde(default)]
+    pub language_candidates: Vec<String>,
+    /// App the dictation is for; its ITN setting applies.
+    #[serde(default)]
+    pub app_target_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscribedAudioDto {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected_language: Option<String>,
+    /// How likely `detected_language` was among the candidate languages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_probability: Option<f32>,
+    /// Whether the text was translated to English by Whisper.
+    pub translated: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessPromptArgs {
+    pub transcript: String,
+    /// Language of the transcript; defaults to the dictation language.
+    #[serde(default)]
+    pub dictation_language: Option<String>,
+    #[serde(default)]
+    pub tone_id: Option<String>,
+    /// Template text for tones that only exist in the webview, such as the
+    /// built-in ones. Takes precedence over `tone_id`.
+    #[serde(default)]
+    pub prompt_template: Option<String>,
+    #[serde(default)]
+    pub text_field_context: Option<crate::domain::TextFieldContext>,
+    #[serde(default)]
+    pub app_name: Option<String>,
+    /// Language to translate the transcript into while rewriting it.
+    #[serde(default)]
+    pub target_language: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessTranscriptArgs {
+    /// Echoed back with the outcome on `EVT_POST_PROCESS_FINISHED`.
+    pub request_id: String,
+    /// Transcription that receives the rewrite, even if the webview is gone
+    /// by the time it finishes.
+    #[serde(default)]
+    pub transcription_id: Option<String>,
+    /// Overrides the API key stored in preferences.
+    #[serde(default)]
+    pub api_key_id: Option<String>,
+    #[serde(flatten)]
+    pub prompt: PostProcessPromptArgs,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneRenderArgs {
+    pub prompt_template: String,
+    #[serde(default)]
+    pub context: crate::domain::ToneTemplateContext,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -171,7 +255,9 @@ pub async fn user_set_one(
     user: crate::domain::User,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::User, String> {
-    crate::db::user_queries::upsert_user(database.pool(), &user)
+    let pool = database.pool();
+    let user = crate::system::incognito::guard_usage(pool.clone(), user).await?;
+    crate::db::user_queries::upsert_user(pool, &user)
         .await
         .map_err(|err| err.to_string())
 }
@@ -189,10 +275,28 @@ pub async fn user_get_one(
 pub async fn user_preferences_set(
     preferences: crate::domain::UserPreferences,
     database: State<'_, crate::state::OptionKeyDatabase>,
+    recorder: State<'_, Arc<dyn crate::platform::Recorder>>,
 ) -> Result<crate::domain::UserPreferences, String> {
-    crate::db::preferences_queries::upsert_user_preferences(database.pool(), &preferences)
-        .await
-        .map_err(|err| err.to_string())
+    let saved =
+        crate::db::preferences_queries::upsert_user_preferences(database.pool(), &preferences)
+            .await
+            .map_err(|err| err.to_string())?;
+
+    recorder.set_input_gain(saved.input_gain);
+
+    // Opening or closing the warm microphone talks to the audio host. The
+    // preferences are saved either way; the next recording retries it.
+    let recorder = Arc::clone(&recorder);
+    let pre_roll = crate::system::warm_microphone::pre_roll_from_preferences(&saved);
+    let applied =
+        tauri::async_runtime::spawn_blocking(move || recorder.set_warm_pre_roll(pre_roll))
+            .await
+            .map_err(|err| err.to_string())?;
+    if let Err(err) = applied {
+        eprintln!("[warm_microphone] Failed to apply warm microphone: {err}");
+    }
+
+    Ok(saved)
 }
 
 #[tauri::command]
@@ -233,6 +337,59 @@ pub fn list_microphones() -> Vec<crate::platform::audio::InputDeviceDescriptor>
     crate::platform::audio::list_input_devices()
 }
 
+#[tauri::command]
+pub fn watch_microphones(watching: bool) {
+    crate::system::audio_devices::set_picker_open(watching);
+}
+
+#[tauri::command]
+pub async fn microphone_calibrate(
+    app: AppHandle,
+    recorder: State<'_, Arc<dyn crate::platform::Recorder>>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MicrophoneCalibrationReport, String> {
+    if recorder.current_sample_rate().is_some() {
+        return Err("Stop recording before calibrating microphones.".to_string());
+    }
+
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        crate::system::mic_calibration::calibrate_all(|progress| {
+            if let Err(err) = app.emit(crate::domain::EVT_MICROPHONE_CALIBRATION_PROGRESS, progress)
+            {
+                eprintln!("[mic_calibration] Failed to emit calibration progress: {err}");
+            }
+        })
+    })
+    .await
+    .map_err(|err| err.to_string())?;
+
+    for calibration in &report.results {
+        crate::db::microphone_calibration_queries::save_microphone_calibration(
+            database.pool(),
+            calibration,
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+    }
+    crate::system::mic_calibration::apply_saved(database.pool())
+        .await
+        .map_err(|err| err.to_string())?;
+    // The cached device may no longer rank first.
+    recorder.clear_device_cache();
+    recorder.refresh_input_gain();
+
+    Ok(report)
+}
+
+#[tauri::command]
+pub async fn microphone_calibration_list(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::MicrophoneCalibration>, String> {
+    crate::db::microphone_calibration_queries::fetch_microphone_calibrations(database.pool())
+        .await
+        .map_err(|err| err.to_string())
+}
+
 #[tauri::command]
 pub fn list_gpus() -> Vec<crate::system::gpu::GpuAdapterInfo> {
     crate::system::gpu::list_available_gpus()
@@ -272,8 +429,11 @@ pub fn request_accessibility_permission() -> Result<crate::domain::PermissionSta
 pub fn get_current_app_info() -> Result<CurrentAppInfoResponse, String> {
     crate::platform::app_info::get_current_app_info()
         .map(|info| CurrentAppInfoResponse {
+            app_target_id: crate::system::app_profiles::app_target_id_for_name(&info.app_name),
             app_name: info.app_name,
             icon_base64: info.icon_base64,
+            app_identifier: info.app_identifier,
+            window_title: info.window_title,
         })
         .map_err(|err| err.to_string())
 }
@@ -290,6 +450,8 @@ pub async fn app_target_upsert(
         args.tone_id,
         args.icon_path,
         args.paste_keybind,
+        args.itn_enabled,
+        args.translation_language,
     )
     .await
     .map_err(|err| err.to_string())
@@ -304,14 +466,448 @@ pub async fn app_target_list(
         .map_err(|err| err.to_string())
 }
 
+async fn fetch_optional_app_profile(
+    pool: sqlx::SqlitePool,
+    profile_id: Option<&str>,
+) -> Result<Option<crate::domain::AppProfile>, String> {
+    match profile_id {
+        Some(profile_id) => crate::db::app_profile_queries::fetch_app_profile(pool, profile_id)
+            .await
+            .map_err(|err| err.to_string()),
+        None => Ok(None),
+    }
+}
+
+/// The profile a dictation step should follow: the one the caller names, or
+/// else the one resolved when recording started.
+async fn fetch_dictation_app_profile(
+    pool: sqlx::SqlitePool,
+    profile_id: Option<&str>,
+    active: &crate::state::ActiveAppProfileState,
+) -> Result<Option<crate::domain::AppProfile>, String> {
+    let active_id = active.get().and_then(|resolved| resolved.profile_id);
+    fetch_optional_app_profile(pool, profile_id.or(active_id.as_deref())).await
+}
+
+#[tauri::command]
+pub async fn app_profile_upsert(
+    profile: crate::domain::AppProfile,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::AppProfile, String> {
+    crate::system::app_profiles::validate_profile(&profile).map_err(|err| err.to_string())?;
+    if let Some(model_size) = profile.model_size.as_deref() {
+        model_size
+            .parse::<WhisperModelSize>()
+            .map_err(|_| format!("Unknown model size '{model_size}'"))?;
+    }
+
+    crate::db::app_profile_queries::upsert_app_profile(database.pool(), &profile)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn app_profile_list(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::AppProfile>, String> {
+    crate::db::app_profile_queries::fetch_app_profiles(database.pool())
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn app_profile_delete(
+    id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::app_profile_queries::delete_app_profile(database.pool(), &id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Work out which app profile applies to the focused window.
+async fn resolve_focused_app_profile(
+    pool: sqlx::SqlitePool,
+) -> Result<crate::domain::ResolvedAppProfile, String> {
+    use crate::system::app_profiles::{app_target_id_for_name, match_profile, resolve, FocusedApp};
+
+    let identity = tauri::async_runtime::spawn_blocking(|| {
+        crate::platform::app_info::get_focused_app_identity()
+    })
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())?;
+
+    let app_target_id = app_target_id_for_name(&identity.app_name);
+    let app_target = match app_target_id.as_deref() {
+        Some(id) => crate::db::app_target_queries::fetch_app_target(pool.clone(), id)
+            .await
+            .map_err(|err| err.to_string())?,
+        None => None,
+    };
+    let profiles = crate::db::app_profile_queries::fetch_app_profiles(pool)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let profile = match_profile(
+        &profiles,
+        &FocusedApp {
+            app_identifier: identity.app_identifier.as_deref(),
+            app_target_id: app_target_id.as_deref(),
+            window_title: identity.window_title.as_deref(),
+        },
+    );
+
+    Ok(resolve(
+        profile,
+        app_target.as_ref(),
+        &identity.app_name,
+        identity.app_identifier.as_deref(),
+        identity.window_title.as_deref(),
+    ))
+}
+
+/// The app profile for the current dictation, as resolved by
+/// `start_recording`. Outside a dictation the focused window is resolved now.
+#[tauri::command]
+pub async fn app_profile_resolve(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active: State<'_, crate::state::ActiveAppProfileState>,
+) -> Result<crate::domain::ResolvedAppProfile, String> {
+    if let Some(resolved) = active.get() {
+        return Ok(resolved);
+    }
+
+    let resolved = resolve_focused_app_profile(database.pool()).await?;
+    active.set(Some(resolved.clone()));
+    Ok(resolved)
+}
+
+/// Whether ITN applies to a dictation. The app profile decides first, then
+/// the app's own setting; unknown apps get it.
+async fn itn_enabled_for(
+    pool: sqlx::SqlitePool,
+    profile: Option<&crate::domain::AppProfile>,
+    app_target_id: Option<&str>,
+) -> Result<bool, String> {
+    if let Some(enabled) = profile.and_then(|profile| profile.itn_enabled) {
+        return Ok(enabled);
+    }
+    let app_target = match app_target_id {
+        Some(app_target_id) => crate::db::app_target_queries::fetch_app_target(pool, app_target_id)
+            .await
+            .map_err(|err| err.to_string())?,
+        None => None,
+    };
+    Ok(app_target.is_none_or(|target| target.itn_enabled))
+}
+
+/// Apply an app profile's capitalization and trailing punctuation to a
+/// dictated transcript. Local transcripts were already normalized while
+/// transcribing; pass `apply_itn` for the others to rewrite spoken-form
+/// numbers, dates, amounts and addresses in the active dictation language.
+#[tauri::command]
+pub async fn transcript_normalize(
+    text: String,
+    app_target_id: Option<String>,
+    profile_id: Option<String>,
+    apply_itn: bool,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active_profile: State<'_, crate::state::ActiveAppProfileState>,
+) -> Result<String, String> {
+    let pool = database.pool();
+
+    let profile =
+        fetch_dictation_app_profile(pool.clone(), profile_id.as_deref(), &active_profile).await?;
+    let app_target_id = app_target_id.or_else(|| {
+        active_profile
+            .get()
+            .and_then(|resolved| resolved.app_target_id)
+    });
+    let itn_enabled = apply_itn
+        && itn_enabled_for(pool.clone(), profile.as_ref(), app_target_id.as_deref()).await?;
+
+    let text = if itn_enabled {
+        let profile_language = profile
+            .as_ref()
+            .and_then(|profile| profile.language.clone())
+            .filter(|language| !language.trim().is_empty());
+        let dictation_language = match profile_language {
+            Some(language) => Some(language),
+            None => crate::db::preferences_queries::fetch_user_preferences(
+                pool,
+                crate::db::preferences_queries::LOCAL_USER_ID,
+            )
+            .await
+            .map_err(|err| err.to_string())?
+            .and_then(|preferences| preferences.active_dictation_language),
+        };
+        crate::system::itn::normalize_or_keep(text, dictation_language.as_deref())
+    } else {
+        text
+    };
+
+    Ok(match profile {
+        Some(profile) => crate::system::app_profiles::apply_text_style(
+            &text,
+            profile.auto_capitalize,
+            profile.trailing_punctuation,
+        ),
+        None => text,
+    })
+}
+
+/// The Ollama-compatible server used for LLM features. The URL and model come
+/// from preferences, falling back to the given or selected Ollama API key.
+/// `None` when no model is configured.
+async fn resolve_ollama_connection(
+    pool: sqlx::SqlitePool,
+    preferences: Option<&crate::domain::UserPreferences>,
+    api_key_id: Option<String>,
+) -> Result<Option<crate::system::post_processing::OllamaConnection>, String> {
+    use crate::system::post_processing::{OllamaConnection, OLLAMA_DEFAULT_URL};
+
+    let api_key_id = api_key_id.or_else(|| {
+        preferences.and_then(|preferences| preferences.post_processing_api_key_id.clone())
+    });
+    let api_key = match api_key_id {
+        Some(id) => crate::db::api_key_queries::fetch_api_keys(pool)
+            .await
+            .map_err(|err| err.to_string())?
+            .into_iter()
+            .find(|key| key.id == id && key.provider == "ollama"),
+        None => None,
+    };
+
+    let non_empty = |value: &Option<String>| value.clone().filter(|value| !value.trim().is_empty());
+    let base_url = preferences
+        .and_then(|preferences| non_empty(&preferences.post_processing_ollama_url))
+        .or_else(|| api_key.as_ref().and_then(|key| non_empty(&key.base_url)))
+        .unwrap_or_else(|| OLLAMA_DEFAULT_URL.to_string());
+    let model = preferences
+        .and_then(|preferences| non_empty(&preferences.post_processing_ollama_model))
+        .or_else(|| {
+            api_key
+                .as_ref()
+                .and_then(|key| non_empty(&key.post_processing_model))
+        });
+
+    Ok(model.map(|model| OllamaConnection {
+        base_url,
+        model,
+        api_key: api_key
+            .as_ref()
+            .and_then(|key| reveal_api_key(&key.salt, &key.key_ciphertext).ok()),
+    }))
+}
+
+/// The tone template, rendered for the dictation context, and the transcript
+/// language for a post-processing prompt. A template that fails to render is
+/// used as plain text and reported in the returned warnings.
+async fn resolve_post_process_inputs(
+    pool: sqlx::SqlitePool,
+    preferences: Option<&crate::domain::UserPreferences>,
+    args: &PostProcessPromptArgs,
+) -> Result<(Option<String>, String, Vec<String>), String> {
+    let tone_id = args
+        .tone_id
+        .clone()
+        .or_else(|| preferences.and_then(|preferences| preferences.active_tone_id.clone()));
+    let tone_template = match (args.prompt_template.clone(), tone_id) {
+        (Some(template), _) => Some(template),
+        (None, Some(tone_id)) => crate::db::tone_queries::fetch_tone_by_id(pool.clone(), &tone_id)
+            .await
+            .map_err(|err| err.to_string())?
+            .map(|tone| tone.prompt_template),
+        (None, None) => None,
+    };
+    let dictation_language = args
+        .dictation_language
+        .clone()
+        .filter(|language| !language.trim().is_empty())
+        .or_else(|| {
+            preferences.and_then(|preferences| preferences.active_dictation_language.clone())
+        })
+        .unwrap_or_else(|| "en".to_string());
+
+    let text_field_context = args.text_field_context.clone().unwrap_or_default();
+    let template_context = tone_template_context(
+        pool,
+        crate::domain::ToneTemplateContext {
+            app_name: args.app_name.clone(),
+            selected_text: text_field_context.selected_text,
+            preceding_text: text_field_context.preceding_text,
+            following_text: text_field_context.following_text,
+            ..Default::default()
+        },
+    )
+    .await;
+    let mut warnings = Vec::new();
+    let tone_template = tone_template.map(|template| {
+        match crate::system::tone_template::render_template(&template, &template_context) {
+            Ok(rendered) => rendered,
+            Err(issues) => {
+                warnings.push(format!(
+                    "Tone template was used as plain text: {}",
+                    crate::system::tone_template::describe_issues(&issues)
+                ));
+                template
+            }
+        }
+    });
+
+    Ok((tone_template, dictation_language, warnings))
+}
+
+/// Build the post-processing prompt for providers the webview calls itself,
+/// so every provider gets the same prompt.
+#[tauri::command]
+pub async fn post_process_prompt(
+    args: PostProcessPromptArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::PostProcessPrompt, String> {
+    let pool = database.pool();
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool.clone(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    let (tone_template, dictation_language, warnings) =
+        resolve_post_process_inputs(pool, preferences.as_ref(), &args).await?;
+    let request = crate::system::post_processing::PostProcessRequest {
+        transcript: &args.transcript,
+        dictation_language: &dictation_language,
+        tone_template: tone_template.as_deref(),
+        context: args.text_field_context.as_ref(),
+        target_language: args.target_language.as_deref(),
+    };
+    let prompt = crate::system::post_processing::build_post_process_prompt(&request);
+
+    Ok(crate::domain::PostProcessPrompt {
+        prompt,
+        target_language: request.translation_target().map(str::to_string),
+        warnings,
+    })
+}
+
+/// Start rewriting a transcript with the active tone, translating it when
+/// `target_language` asks for it, through an Ollama-compatible server and
+/// return straight away. The rewrite runs in the
+/// backend, is stored on `transcription_id` when given, and is announced on
+/// `EVT_POST_PROCESS_FINISHED`, so it completes even if the webview reloads.
+/// Request failures are reported as warnings in the outcome.
+#[tauri::command]
+pub async fn post_process_transcript(
+    app: AppHandle,
+    args: PostProcessTranscriptArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    use crate::system::post_processing::{
+        post_process, OllamaSettings, PostProcessRequest, POST_PROCESS_DEVICE_OLLAMA,
+    };
+
+    let pool = database.pool();
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool.clone(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    let connection =
+        resolve_ollama_connection(pool.clone(), preferences.as_ref(), args.api_key_id.clone())
+            .await?
+            .ok_or_else(|| "No model configured for Ollama post-processing.".to_string())?;
+    let (tone_template, dictation_language, template_warnings) =
+        resolve_post_process_inputs(pool.clone(), preferences.as_ref(), &args.prompt).await?;
+
+    tauri::async_runtime::spawn(async move {
+        let PostProcessTranscriptArgs {
+            request_id,
+            transcription_id,
+            prompt,
+            ..
+        } = args;
+        let transcript = prompt.transcript.clone();
+
+        let result = tauri::async_runtime::spawn_blocking(move || {
+            let client = connection
+                .client(OllamaSettings::default())
+                .map_err(|err| err.to_string())?;
+            Ok::<_, String>(post_process(
+                &client,
+                &PostProcessRequest {
+                    transcript: &prompt.transcript,
+                    dictation_language: &dictation_language,
+                    tone_template: tone_template.as_deref(),
+                    context: prompt.text_field_context.as_ref(),
+                    target_language: prompt.target_language.as_deref(),
+                },
+            ))
+        })
+        .await
+        .map_err(|err| err.to_string())
+        .and_then(|result| result);
+
+        let mut outcome = result.unwrap_or_else(|err| crate::domain::PostProcessOutcome {
+            transcript,
+            post_process_prompt: None,
+            post_process_mode: "api".to_string(),
+            post_process_device: Some(POST_PROCESS_DEVICE_OLLAMA.to_string()),
+            postprocess_duration_ms: None,
+            target_language: None,
+            warnings: vec![format!("Post-processing failed: {err}")],
+        });
+        outcome.warnings.splice(0..0, template_warnings);
+
+        if let Some(id) = transcription_id {
+            if let Err(err) = store_post_process_outcome(pool, &id, &outcome).await {
+                eprintln!("[post_processing] Failed to store the rewrite for {id}: {err}");
+            }
+        }
+
+        if let Err(err) = app.emit(
+            crate::domain::EVT_POST_PROCESS_FINISHED,
+            crate::domain::PostProcessFinished {
+                request_id,
+                outcome,
+            },
+        ) {
+            eprintln!("[post_processing] Failed to emit the rewrite: {err}");
+        }
+    });
+
+    Ok(())
+}
+
+async fn store_post_process_outcome(
+    pool: sqlx::SqlitePool,
+    id: &str,
+    outcome: &crate::domain::PostProcessOutcome,
+) -> Result<(), sqlx::Error> {
+    let Some(mut transcription) =
+        crate::db::transcription_queries::fetch_transcription(pool.clone(), id).await?
+    else {
+        return Ok(());
+    };
+    // A dictation with voice commands is stored as the text it pasted.
+    let outcome = crate::domain::PostProcessOutcome {
+        transcript: crate::system::post_processing::strip_step_markers(&outcome.transcript),
+        ..outcome.clone()
+    };
+    outcome.apply_to(&mut transcription);
+    crate::db::transcription_queries::update_transcription(pool, &transcription).await?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn transcription_create(
     transcription: crate::domain::Transcription,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Transcription, String> {
-    crate::db::transcription_queries::insert_transcription(database.pool(), &transcription)
-        .await
-        .map_err(|err| err.to_string())
+    crate::system::history::create_transcription(database.pool(), transcription).await
 }
 
 #[tauri::command]
@@ -360,9 +956,38 @@ pub async fn transcription_update(
     transcription: crate::domain::Transcription,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Transcription, String> {
-    crate::db::transcription_queries::update_transcription(database.pool(), &transcription)
-        .await
-        .map_err(|err| err.to_string())
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    let pool = database.pool();
+    let previous =
+        crate::db::transcription_queries::fetch_transcription(pool.clone(), &transcription.id)
+            .await
+            .map_err(|err| err.to_string())?;
+    let updated =
+        crate::db::transcription_queries::update_transcription(pool.clone(), &transcription)
+            .await
+            .map_err(|err| err.to_string())?;
+
+    if let Some(previous) = previous {
+        if crate::system::term_feedback::is_user_edit(&previous, &updated) {
+            let raw = updated
+                .raw_transcript
+                .as_deref()
+                .unwrap_or(&previous.transcript);
+            if let Err(err) = crate::system::term_feedback::learn_from_edit(
+                pool,
+                &updated.id,
+                raw,
+                &previous.transcript,
+                &updated.transcript,
+            )
+            .await
+            {
+                eprintln!("Failed to learn from transcript edit: {err}");
+            }
+        }
+    }
+
+    Ok(updated)
 }
 
 #[tauri::command]
@@ -420,6 +1045,26 @@ pub async fn term_create(
         .map_err(|err| err.to_string())
 }
 
+/// Corrections users keep making to transcripts, offered as new terms.
+#[tauri::command]
+pub async fn term_suggestion_list(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::TermSuggestion>, String> {
+    crate::system::term_feedback::pending_suggestions(database.pool()).await
+}
+
+/// Records the user's decision. Accepting creates the replacement term
+/// locally; a caller using a synced dictionary also saves the returned term
+/// there.
+#[tauri::command]
+pub async fn term_suggestion_review(
+    id: String,
+    status: crate::domain::TermSuggestionStatus,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::TermSuggestionReview, String> {
+    crate::system::term_feedback::review(database.pool(), &id, status).await
+}
+
 #[tauri::command]
 pub async fn term_update(
     term: crate::domain::Term,
@@ -449,6 +1094,47 @@ pub async fn term_delete(
         .map_err(|err| err.to_string())
 }
 
+#[tauri::command]
+pub async fn voice_command_create(
+    command: crate::domain::VoiceCommand,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::VoiceCommand, String> {
+    crate::system::voice_commands::validate_voice_command(&command)?;
+    crate::db::voice_command_queries::insert_voice_command(database.pool(), &command)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn voice_command_update(
+    command: crate::domain::VoiceCommand,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::VoiceCommand, String> {
+    crate::system::voice_commands::validate_voice_command(&command)?;
+    crate::db::voice_command_queries::update_voice_command(database.pool(), &command)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn voice_command_list(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::VoiceCommand>, String> {
+    crate::db::voice_command_queries::fetch_voice_commands(database.pool())
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn voice_command_delete(
+    id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::voice_command_queries::delete_voice_command(database.pool(), &id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
 #[tauri::command]
 pub async fn hotkey_list(
     database: State<'_, crate::state::OptionKeyDatabase>,
@@ -571,11 +1257,191 @@ pub async fn api_key_update(
         .map_err(|err| err.to_string())
 }
 
-#[tauri::command]
-pub async fn tone_upsert(
+const MIN_LOCAL_API_PORT: u16 = 1024;
+
+fn local_api_status_view(
+    settings: &crate::domain::LocalApiSettings,
+    addr: Option<std::net::SocketAddr>,
+) -> crate::domain::LocalApiStatus {
+    let token = match (&settings.token_salt, &settings.token_ciphertext) {
+        (Some(salt), Some(ciphertext)) => reveal_api_key(salt, ciphertext)
+            .map_err(|err| eprintln!("Failed to reveal local API token: {err}"))
+            .ok(),
+        _ => None,
+    };
+
+    crate::domain::LocalApiStatus {
+        enabled: settings.enabled,
+        port: addr.map(|addr| addr.port()).unwrap_or(settings.port),
+        running: addr.is_some(),
+        url: addr.map(|addr| format!("http://{addr}")),
+        token,
+        token_suffix: settings.token_suffix.clone(),
+    }
+}
+
+#[tauri::command]
+pub async fn local_api_status(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    local_api: State<'_, crate::state::LocalApiState>,
+) -> Result<crate::domain::LocalApiStatus, String> {
+    let settings = crate::db::local_api_queries::fetch_local_api_settings(database.pool())
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(local_api_status_view(&settings, local_api.addr()))
+}
+
+/// Enable, disable or reconfigure the loopback API. A token is created the
+/// first time it is enabled and whenever `regenerate_token` is set.
+#[tauri::command]
+pub async fn local_api_configure(
+    app: AppHandle,
+    request: crate::domain::LocalApiConfigureRequest,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    local_api: State<'_, crate::state::LocalApiState>,
+) -> Result<crate::domain::LocalApiStatus, String> {
+    let mut settings = crate::db::local_api_queries::fetch_local_api_settings(database.pool())
+        .await
+        .map_err(|err| err.to_string())?;
+
+    if let Some(port) = request.port {
+        if port < MIN_LOCAL_API_PORT {
+            return Err(format!(
+                "Port must be between {MIN_LOCAL_API_PORT} and {}",
+                u16::MAX
+            ));
+        }
+        settings.port = port;
+    }
+    settings.enabled = request.enabled;
+
+    if request.regenerate_token || settings.token_hash.is_none() {
+        let token = crate::platform::keyboard_ipc::generate_token();
+        let protected = protect_api_key(&token);
+        settings.token_salt = Some(protected.salt_b64);
+        settings.token_hash = Some(protected.hash_b64);
+        settings.token_ciphertext = Some(protected.ciphertext_b64);
+        settings.token_suffix = protected.key_suffix;
+    }
+
+    crate::db::local_api_queries::save_local_api_settings(database.pool(), &settings)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    if settings.enabled {
+        let verifier = crate::system::local_api::TokenVerifier::from_settings(&settings)
+            .ok_or_else(|| "Local API token is missing".to_string())?;
+        local_api.restart(app, settings.port, verifier)?;
+    } else {
+        local_api.stop();
+    }
+
+    Ok(local_api_status_view(&settings, local_api.addr()))
+}
+
+/// Answer a permission prompt raised by an MCP client's tool call.
+#[tauri::command]
+pub fn mcp_permission_respond(
+    request_id: String,
+    decision: crate::domain::McpPermissionDecision,
+    permissions: State<'_, crate::state::McpPermissionState>,
+) -> Result<(), String> {
+    permissions.respond(&request_id, decision)
+}
+
+/// Fill the parts of a template context that the backend knows: the local
+/// user's profile, today's date and the dictation language.
+async fn tone_template_context(
+    pool: sqlx::SqlitePool,
+    partial: crate::domain::ToneTemplateContext,
+) -> crate::domain::ToneTemplateContext {
+    let user = crate::db::user_queries::fetch_user(pool.clone())
+        .await
+        .ok()
+        .flatten();
+    let language = crate::db::preferences_queries::fetch_user_preferences(
+        pool,
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .ok()
+    .flatten()
+    .and_then(|preferences| preferences.active_dictation_language);
+
+    crate::domain::ToneTemplateContext {
+        user_name: partial
+            .user_name
+            .or_else(|| user.as_ref().map(|user| user.name.clone())),
+        user_company: partial
+            .user_company
+            .or_else(|| user.as_ref().and_then(|user| user.company.clone())),
+        user_title: partial
+            .user_title
+            .or_else(|| user.as_ref().and_then(|user| user.title.clone())),
+        date: partial
+            .date
+            .or_else(|| Some(chrono::Local::now().format("%A, %B %-d, %Y").to_string())),
+        language: partial.language.or(language),
+        ..partial
+    }
+}
+
+/// Render a tone template against the current dictation context. Invalid
+/// templates are reported as an error rather than sent on as raw text.
+#[tauri::command]
+pub async fn tone_render(
+    args: ToneRenderArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<String, String> {
+    let context = tone_template_context(database.pool(), args.context).await;
+    crate::system::tone_template::render_template(&args.prompt_template, &context).map_err(
+        |issues| {
+            format!(
+                "Invalid tone template. {}",
+                crate::system::tone_template::describe_issues(&issues)
+            )
+        },
+    )
+}
+
+/// Render a template against sample values for the tone editor, reporting
+/// any syntax errors or unknown variables.
+#[tauri::command]
+pub async fn tone_preview(
+    args: ToneRenderArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::ToneTemplatePreview, String> {
+    let context = tone_template_context(database.pool(), args.context).await;
+    let context = crate::system::tone_template::sample_context(context);
+
+    Ok(
+        match crate::system::tone_template::render_template(&args.prompt_template, &context) {
+            Ok(rendered) => crate::domain::ToneTemplatePreview {
+                rendered,
+                issues: Vec::new(),
+            },
+            Err(issues) => crate::domain::ToneTemplatePreview {
+                rendered: args.prompt_template,
+                issues,
+            },
+        },
+    )
+}
+
+#[tauri::command]
+pub async fn tone_upsert(
     tone: crate::domain::Tone,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Tone, String> {
+    let issues = crate::system::tone_template::validate(&tone.prompt_template);
+    if !issues.is_empty() {
+        return Err(format!(
+            "Invalid tone template. {}",
+            crate::system::tone_template::describe_issues(&issues)
+        ));
+    }
+
     let pool = database.pool();
 
     if let Some(existing) = crate::db::tone_queries::fetch_tone_by_id(pool.clone(), &tone.id)
@@ -643,10 +1509,11 @@ pub async fn clear_local_data(
     let pool = database.pool();
     let mut transaction = pool.begin().await.map_err(|err| err.to_string())?;
 
-    const TABLES_TO_CLEAR: [&str; 6] = [
+    const TABLES_TO_CLEAR: [&str; 7] = [
         "user_profiles",
         "transcriptions",
         "terms",
+        "voice_commands",
         "hotkeys",
         "api_keys",
         "user_preferences",
@@ -665,6 +1532,14 @@ pub async fn clear_local_data(
     if let Err(err) = sqlx::query("VACUUM").execute(&pool).await {
         eprintln!("VACUUM failed after clearing local data: {err}");
     }
+    // VACUUM may renumber the segment rowids the search index points at.
+    if let Err(err) =
+        sqlx::query("INSERT INTO meeting_segments_fts (meeting_segments_fts) VALUES ('rebuild')")
+            .execute(&pool)
+            .await
+    {
+        eprintln!("Rebuilding the meeting search index failed: {err}");
+    }
 
     Ok(())
 }
@@ -691,11 +1566,14 @@ pub fn play_audio(clip: AudioClip) -> Result<(), String> {
 pub async fn start_recording(
     app: AppHandle,
     recorder: State<'_, Arc<dyn crate::platform::Recorder>>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active_profile: State<'_, crate::state::ActiveAppProfileState>,
     args: Option<StartRecordingArgs>,
 ) -> Result<StartRecordingResponse, String> {
     let options = args.unwrap_or_default();
 
-    recorder.set_preferred_input_device(options.preferred_microphone.clone());
+    // The focused window is looked up while the microphone opens.
+    let profile_task = tauri::async_runtime::spawn(resolve_focused_app_profile(database.pool()));
 
     let level_emit_handle = app.clone();
     let level_emitter: LevelCallback = Arc::new(move |levels: Vec<f32>| {
@@ -714,7 +1592,11 @@ pub async fn start_recording(
     });
 
     let recorder_clone = Arc::clone(&recorder);
+    let preferred_microphone = options.preferred_microphone.clone();
     let start_result = tauri::async_runtime::spawn_blocking(move || {
+        // Switching devices reopens a warm microphone, so keep it off the
+        // async runtime.
+        recorder_clone.set_preferred_input_device(preferred_microphone);
         match recorder_clone.start(Some(level_emitter), Some(chunk_emitter)) {
             Ok(()) => Ok(()),
             Err(err) => {
@@ -731,6 +1613,19 @@ pub async fn start_recording(
 
     match start_result {
         Ok(()) => {
+            let resolved = match profile_task.await {
+                Ok(Ok(resolved)) => Some(resolved),
+                Ok(Err(err)) => {
+                    eprintln!("[start_recording] Failed to resolve app profile: {err}");
+                    None
+                }
+                Err(err) => {
+                    eprintln!("[start_recording] App profile lookup panicked: {err}");
+                    None
+                }
+            };
+            active_profile.set(resolved);
+
             let reported_sample_rate = recorder.current_sample_rate().unwrap_or(16_000);
             Ok(StartRecordingResponse {
                 sample_rate: reported_sample_rate,
@@ -763,6 +1658,11 @@ pub async fn stop_recording(
             Ok(StopRecordingResponse {
                 samples: audio.samples,
                 sample_rate: audio.sample_rate,
+                warnings: result
+                    .failovers
+                    .iter()
+                    .map(crate::domain::InputFailover::warning)
+                    .collect(),
             })
         }
         Err(err) => {
@@ -775,6 +1675,7 @@ pub async fn stop_recording(
                 return Ok(StopRecordingResponse {
                     samples: Vec::new(),
                     sample_rate: 0,
+                    warnings: Vec::new(),
                 });
             }
 
@@ -787,13 +1688,90 @@ pub async fn stop_recording(
     .map_err(|err| err.to_string())?
 }
 
+/// Keeps the microphone on standby and starts dictation when the wake
+/// phrase is heard. Audio stays in memory until then.
+#[tauri::command]
+pub async fn ambient_arm(
+    app: AppHandle,
+    recorder: State<'_, Arc<dyn crate::platform::Recorder>>,
+    transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::AmbientStatus, String> {
+    use crate::system::ambient::{AmbientListener, WakePhrase};
+
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        database.pool(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    let wake_phrase = preferences
+        .as_ref()
+        .and_then(|preferences| preferences.ambient_wake_phrase.as_deref())
+        .map(str::trim)
+        .filter(|phrase| !phrase.is_empty())
+        .unwrap_or(crate::domain::DEFAULT_WAKE_PHRASE);
+    let phrase = WakePhrase::new(wake_phrase)
+        .ok_or_else(|| "The wake phrase needs at least one word.".to_string())?;
+    let preferred_microphone = preferences.and_then(|preferences| preferences.preferred_microphone);
+
+    // The spotter always runs the smallest model, whatever dictation uses.
+    let handle = app.clone();
+    let model_path = tauri::async_runtime::spawn_blocking(move || {
+        crate::system::models::ensure_whisper_model(&handle, WhisperModelSize::Tiny)
+            .map_err(|err| err.to_string())
+    })
+    .await
+    .map_err(|err| err.to_string())??;
+    let transcriber = ensure_transcriber(&transcriber_state, &model_path)?;
+    let model_path = model_path.to_string_lossy().into_owned();
+
+    let recorder = Arc::clone(&recorder);
+    let handle = app.clone();
+    let status = tauri::async_runtime::spawn_blocking(move || {
+        recorder.set_preferred_input_device(preferred_microphone);
+        handle.state::<crate::state::AmbientState>().arm(|| {
+            AmbientListener::start(handle.clone(), recorder, transcriber, model_path, phrase)
+        })
+    })
+    .await
+    .map_err(|err| err.to_string())??;
+
+    crate::system::ambient::publish_status(&app, &status);
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn ambient_disarm(app: AppHandle) -> Result<crate::domain::AmbientStatus, String> {
+    // Stopping joins the spotter thread, which may be mid-transcription.
+    let handle = app.clone();
+    let status = tauri::async_runtime::spawn_blocking(move || {
+        handle.state::<crate::state::AmbientState>().disarm()
+    })
+    .await
+    .map_err(|err| err.to_string())?;
+
+    crate::system::ambient::publish_status(&app, &status);
+    Ok(status)
+}
+
+#[tauri::command]
+pub fn ambient_status(
+    ambient: State<'_, crate::state::AmbientState>,
+) -> crate::domain::AmbientStatus {
+    ambient.status()
+}
+
 #[tauri::command]
 pub async fn store_transcription_audio(
     app: AppHandle,
     id: String,
     samples: Vec<f64>,
     sample_rate: u32,
+    database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<TranscriptionAudioSnapshot, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
     if sample_rate == 0 {
         return Err("Audio sample rate must be greater than zero".to_string());
     }
@@ -847,6 +1825,24 @@ pub fn storage_get_download_url(app: AppHandle, path: String) -> Result<String,
     repo.get_download_url(&path).map_err(|err| err.to_string())
 }
 
+/// The shared Whisper transcriber, loaded with `model_path` on first use.
+fn ensure_transcriber(
+    transcriber_state: &crate::state::TranscriberState,
+    model_path: &std::path::Path,
+) -> Result<Arc<dyn crate::platform::Transcriber>, String> {
+    if let Some(existing) = transcriber_state.get() {
+        return Ok(existing.clone());
+    }
+
+    eprintln!("[transcribe_audio] Transcriber not initialized, performing lazy initialization...");
+    let new_transcriber: Arc<dyn crate::platform::Transcriber> = Arc::new(
+        crate::platform::whisper::WhisperTranscriber::new(model_path)
+            .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
+    );
+    let _ = transcriber_state.initialize(new_transcriber.clone());
+    Ok(new_transcriber)
+}
+
 #[tauri::command]
 pub async fn transcribe_audio(
     app: AppHandle,
@@ -854,28 +1850,62 @@ pub async fn transcribe_audio(
     sample_rate: u32,
     options: Option<TranscriptionOptionsDto>,
     transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active_profile: State<'_, crate::state::ActiveAppProfileState>,
 ) -> Result<String, String> {
+    transcribe_audio_detailed(
+        app,
+        samples,
+        sample_rate,
+        options,
+        transcriber_state,
+        database,
+        active_profile,
+    )
+    .await
+    .map(|output| output.text)
+}
+
+/// Transcribes like `transcribe_audio`, also reporting the detected language
+/// and whether Whisper translated the speech.
+#[tauri::command]
+pub async fn transcribe_audio_detailed(
+    app: AppHandle,
+    samples: Vec<f64>,
+    sample_rate: u32,
+    options: Option<TranscriptionOptionsDto>,
+    transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active_profile: State<'_, crate::state::ActiveAppProfileState>,
+) -> Result<TranscribedAudioDto, String> {
     let mut request = TranscriptionRequest::default();
     let mut model_size = WhisperModelSize::default();
+    let mut profile_id = None;
+    let mut language_candidates = Vec::new();
+    let mut caller_prompt = None;
+    let mut app_target_id = None;
 
     if let Some(TranscriptionOptionsDto {
         device,
         model_size: maybe_model_size,
         initial_prompt,
         language: maybe_language,
+        profile_id: maybe_profile_id,
+        translate,
+        language_candidates: maybe_language_candidates,
+        app_target_id: maybe_app_target_id,
     }) = options
     {
+        profile_id = maybe_profile_id;
+        app_target_id = maybe_app_target_id;
+        language_candidates = maybe_language_candidates;
+
         if let Some(device_dto) = device {
             request = device_dto.into_request();
         }
+        request.translate = translate;
 
-        if let Some(prompt_value) = initial_prompt {
-            let sanitized: String = prompt_value.chars().filter(|ch| *ch != '\0').collect();
-            let trimmed = sanitized.trim();
-            if !trimmed.is_empty() {
-                request.initial_prompt = Some(trimmed.to_string());
-            }
-        }
+        caller_prompt = initial_prompt;
 
         if let Some(language_value) = maybe_language {
             let sanitized: String = language_value.chars().filter(|ch| *ch != '\0').collect();
@@ -900,6 +1930,109 @@ pub async fn transcribe_audio(
         }
     }
 
+    let profile =
+        fetch_dictation_app_profile(database.pool(), profile_id.as_deref(), &active_profile)
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("[transcribe_audio] Failed to load app profile: {err}");
+                None
+            });
+    let app_target_id = app_target_id.or_else(|| {
+        active_profile
+            .get()
+            .and_then(|resolved| resolved.app_target_id)
+    });
+    let mut profile_whisper_language = None;
+    if let Some(profile) = profile.as_ref() {
+        if let Some(language) = profile
+            .language
+            .as_deref()
+            .map(str::trim)
+            .filter(|language| !language.is_empty())
+        {
+            // Profiles store dictation locales; Whisper wants the bare language.
+            let whisper_language = language.split(['-', '_']).next().unwrap_or(language);
+            profile_whisper_language = Some(whisper_language.to_ascii_lowercase());
+            request.language = profile_whisper_language.clone();
+        }
+        if let Some(parsed) = profile
+            .model_size
+            .as_deref()
+            .and_then(|size| size.parse::<WhisperModelSize>().ok())
+        {
+            model_size = parsed;
+        }
+    }
+
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        database.pool(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("[transcribe_audio] Failed to load preferences: {err}");
+        None
+    });
+
+    // A per-app language skips detection, and so does turning it off in the
+    // preferences; the caller's language is used as sent then.
+    let candidates = crate::system::language_id::detection_candidates(
+        preferences
+            .as_ref()
+            .is_some_and(|preferences| preferences.language_detection_enabled),
+        profile_whisper_language.as_deref(),
+        &language_candidates,
+    );
+    if let [language] = candidates.as_slice() {
+        request.language = Some(language.clone());
+    }
+    if candidates.len() > 1 {
+        request.language = None;
+        request.fallback_language =
+            crate::db::preferences_queries::fetch_last_dictation_language(database.pool())
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("[transcribe_audio] Failed to load last dictation language: {err}");
+                    None
+                });
+        request.language_candidates = candidates;
+    }
+
+    let term_ids = profile
+        .as_ref()
+        .and_then(|profile| profile.term_ids.as_deref());
+    let term_engine =
+        crate::system::term_engine::TermEngine::load_subset(database.pool(), term_ids)
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("[transcribe_audio] Failed to load dictionary terms: {err}");
+                crate::system::term_engine::TermEngine::default()
+            });
+
+    // The profile's dictation locale wins over the global one, for the prompt
+    // preamble as well as ITN.
+    let profile_language = profile
+        .as_ref()
+        .and_then(|profile| profile.language.clone())
+        .filter(|language| !language.trim().is_empty());
+    let dictation_language = profile_language.or_else(|| {
+        preferences
+            .as_ref()
+            .and_then(|preferences| preferences.active_dictation_language.clone())
+    });
+    let preamble = dictation_language
+        .as_deref()
+        .and_then(crate::system::term_engine::locale_prompt_preamble);
+
+    // ITN is a nicety: if its setting cannot be read, the transcript is kept
+    // as transcribed.
+    let itn_enabled = itn_enabled_for(database.pool(), profile.as_ref(), app_target_id.as_deref())
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("[transcribe_audio] Failed to load ITN setting: {err}");
+            false
+        });
+
     let initial_path = crate::system::paths::whisper_model_path(&app, model_size)
         .map_err(|err| err.to_string())?;
 
@@ -915,18 +2048,7 @@ pub async fn transcribe_audio(
         .map_err(|err| err.to_string())??
     };
 
-    let transcriber = if let Some(existing) = transcriber_state.get() {
-        existing.clone()
-    } else {
-        eprintln!("[transcribe_audio] Transcriber not initialized, performing lazy initialization...");
-        let init_model_path = model_path.clone();
-        let new_transcriber: Arc<dyn crate::platform::Transcriber> = Arc::new(
-            crate::platform::whisper::WhisperTranscriber::new(&init_model_path)
-                .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
-        );
-        let _ = transcriber_state.initialize(new_transcriber.clone());
-        new_transcriber
-    };
+    let transcriber = ensure_transcriber(&transcriber_state, &model_path)?;
 
     let model_path_string = model_path.to_string_lossy().into_owned();
     request.model_path = Some(model_path_string);
@@ -952,20 +2074,87 @@ pub async fn transcribe_audio(
             return Err("No usable audio samples provided".to_string());
         }
 
-        let request_ref = request.as_ref();
-        transcriber
-            .transcribe(filtered.as_slice(), sample_rate, request_ref)
-            .map(|text| text.trim().to_string())
+        let mut request = request;
+        // The prompt budget is in the model's own tokens.
+        let initial_prompt = {
+            let request_ref = request.as_ref();
+            let count_tokens = |text: &str| {
+                transcriber
+                    .count_tokens(text, request_ref)
+                    .unwrap_or_else(|| crate::system::term_engine::estimate_tokens(text))
+            };
+            caller_prompt
+                .as_deref()
+                .and_then(|prompt| {
+                    crate::system::term_engine::bound_initial_prompt(prompt, &count_tokens)
+                })
+                .unwrap_or_else(|| term_engine.initial_prompt(preamble, &count_tokens))
+        };
+        if let Some(request) = request.as_mut() {
+            request.initial_prompt = Some(initial_prompt);
+        }
+
+        let output =
+            transcriber.transcribe_detailed(filtered.as_slice(), sample_rate, request.as_ref())?;
+        let translated = output.translated;
+        let detected_language = output.detected_language;
+        let language_probability = output
+            .language_choice
+            .as_ref()
+            .map(|choice| choice.probability);
+        // Only confident detections become the fallback for unclear clips.
+        let confident_language = output
+            .language_choice
+            .filter(|choice| choice.confident)
+            .map(|choice| choice.language);
+
+        let mut text = term_engine.apply_replacements(output.text.trim());
+        if itn_enabled {
+            let written_language = if translated {
+                Some("en")
+            } else {
+                detected_language.as_deref()
+            };
+            let language = crate::system::itn::transcript_language(
+                dictation_language.as_deref(),
+                written_language,
+            );
+            text = crate::system::itn::normalize_or_keep(text, language);
+        }
+
+        Ok((
+            TranscribedAudioDto {
+                text,
+                detected_language,
+                language_probability,
+                translated,
+            },
+            confident_language,
+        ))
     })
     .await;
 
     match join_result {
         Ok(result) => {
-            if let Err(err) = result.as_ref() {
-                eprintln!("Transcription failed: {err}");
+            let (output, confident_language) = match result {
+                Ok(result) => result,
+                Err(err) => {
+                    eprintln!("Transcription failed: {err}");
+                    return Err(err);
+                }
+            };
+
+            if let Some(language) = confident_language {
+                let pool = database.pool();
+                if let Err(err) =
+                    crate::db::preferences_queries::update_last_dictation_language(pool, &language)
+                        .await
+                {
+                    eprintln!("[transcribe_audio] Failed to store dictation language: {err}");
+                }
             }
 
-            result
+            Ok(output)
         }
         Err(err) => {
             let message = format!("Transcription task join error: {err}");
@@ -983,7 +2172,7 @@ pub async fn purge_stale_transcription_audio(
     let pool = database.pool();
 
     let rows = sqlx::query(
-        "SELECT id, audio_path
+        "SELECT id, audio_path, starred
          FROM transcriptions
          WHERE audio_path IS NOT NULL
          ORDER BY timestamp DESC",
@@ -995,6 +2184,7 @@ pub async fn purge_stale_transcription_audio(
     let stale_entries: Vec<(String, String)> = rows
         .into_iter()
         .skip(MAX_RETAINED_TRANSCRIPTION_AUDIO)
+        .filter(|row| !row.get::<bool, _>("starred"))
         .map(|row| {
             (
                 row.get::<String, _>("id"),
@@ -1029,6 +2219,63 @@ pub async fn purge_stale_transcription_audio(
     Ok(purged_ids)
 }
 
+#[tauri::command]
+pub async fn retention_policy_list(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::RetentionPolicy>, String> {
+    crate::db::retention_queries::fetch_retention_policies(database.pool())
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn retention_policy_save(
+    policy: crate::domain::RetentionPolicy,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::RetentionPolicy, String> {
+    crate::db::retention_queries::save_retention_policy(database.pool(), &policy)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Lists what the enabled retention policies would remove right now.
+#[tauri::command]
+pub async fn retention_report(
+    app: AppHandle,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::RetentionReport, String> {
+    crate::system::retention::run(&app, database.pool(), true).await
+}
+
+#[tauri::command]
+pub async fn retention_run(
+    app: AppHandle,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::RetentionReport, String> {
+    crate::system::retention::run(&app, database.pool(), false).await
+}
+
+#[tauri::command]
+pub async fn retention_set_starred(
+    target: crate::domain::RetentionTarget,
+    id: String,
+    starred: bool,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::retention_queries::set_starred(database.pool(), target, &id, starred)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Usage statistics recomputed from the stored transcription history.
+#[tauri::command]
+pub async fn analytics_usage(
+    request: crate::domain::UsageAnalyticsRequest,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::UsageAnalytics, String> {
+    crate::system::analytics::usage(database.pool(), &request).await
+}
+
 #[tauri::command]
 pub fn surface_main_window(app: AppHandle) -> Result<(), String> {
     let window = app
@@ -1085,6 +2332,104 @@ pub async fn paste(text: String, keybind: Option<String>) -> Result<(), String>
     }
 }
 
+/// Each clipboard paste restores the previous clipboard about 800ms later, so a
+/// second paste in the same dictation has to wait that out or it would capture
+/// the first paste's text as the clipboard to restore.
+const CLIPBOARD_RESTORE_SETTLE: std::time::Duration = std::time::Duration::from_millis(900);
+const DICTATION_STEP_GAP: std::time::Duration = std::time::Duration::from_millis(40);
+
+fn run_dictation_steps(
+    steps: &[crate::domain::DictationStep],
+    keybind: Option<&str>,
+    type_text: bool,
+) -> Result<(), String> {
+    use crate::domain::DictationStep;
+
+    let mut pasted_text = false;
+    for step in steps {
+        match step {
+            DictationStep::Text(text) => {
+                if pasted_text {
+                    std::thread::sleep(CLIPBOARD_RESTORE_SETTLE);
+                }
+                if type_text {
+                    crate::platform::input::type_text_into_focused_field(text)?;
+                } else {
+                    platform_paste_text(text, keybind)?;
+                }
+                pasted_text = true;
+            }
+            DictationStep::Keys(chord) => {
+                crate::platform::input::send_key_chord(chord)?;
+            }
+        }
+        std::thread::sleep(DICTATION_STEP_GAP);
+    }
+
+    Ok(())
+}
+
+/// Split a raw transcript into text and the spoken editing commands in it.
+#[tauri::command]
+pub async fn dictation_parse(
+    text: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::DictationStep>, String> {
+    let pool = database.pool();
+    let user_commands = crate::db::voice_command_queries::fetch_voice_commands(pool.clone())
+        .await
+        .map_err(|err| err.to_string())?;
+    let keyword_required = crate::db::preferences_queries::fetch_user_preferences(
+        pool,
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?
+    .is_some_and(|preferences| preferences.voice_command_keyword_required);
+    Ok(crate::system::voice_commands::parse_dictation(
+        &text,
+        &user_commands,
+        keyword_required,
+    ))
+}
+
+/// Insert a parsed dictation, pressing keys for its commands. The app profile
+/// decides whether text is pasted or typed.
+#[tauri::command]
+pub async fn paste_dictation(
+    steps: Vec<crate::domain::DictationStep>,
+    keybind: Option<String>,
+    profile_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    active_profile: State<'_, crate::state::ActiveAppProfileState>,
+) -> Result<(), String> {
+    let type_text =
+        fetch_dictation_app_profile(database.pool(), profile_id.as_deref(), &active_profile)
+            .await?
+            .and_then(|profile| profile.insertion_strategy)
+            .is_some_and(|strategy| strategy == crate::domain::INSERTION_STRATEGY_TYPE);
+
+    let join_result = tauri::async_runtime::spawn_blocking(move || {
+        run_dictation_steps(&steps, keybind.as_deref(), type_text)
+    })
+    .await;
+
+    match join_result {
+        Ok(result) => {
+            if let Err(err) = result.as_ref() {
+                eprintln!("Dictation paste failed: {err}");
+            }
+
+            result
+        }
+        Err(err) => {
+            let message = format!("Dictation paste task join error: {err}");
+            eprintln!("{message}");
+            Err(message)
+        }
+    }
+}
+
 #[tauri::command]
 pub fn set_phase(
     app: AppHandle,
@@ -1195,9 +2540,7 @@ pub async fn meeting_create(
     meeting: crate::domain::Meeting,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Meeting, String> {
-    crate::db::meeting_queries::insert_meeting(database.pool(), &meeting)
-        .await
-        .map_err(|err| err.to_string())
+    crate::system::history::create_meeting(database.pool(), meeting).await
 }
 
 #[tauri::command]
@@ -1260,24 +2603,306 @@ pub async fn meeting_segment_list(
 }
 
 #[tauri::command]
-pub async fn meeting_segments_create_batch(
-    segments: Vec<crate::domain::MeetingSegment>,
+pub async fn meeting_action_item_list(
+    meeting_id: String,
     database: State<'_, crate::state::OptionKeyDatabase>,
-) -> Result<Vec<crate::domain::MeetingSegment>, String> {
-    const MAX_BATCH_SEGMENTS: usize = 500;
-    if segments.len() > MAX_BATCH_SEGMENTS {
-        return Err(format!(
-            "Segment batch too large: {} segments (max {})",
-            segments.len(),
-            MAX_BATCH_SEGMENTS
-        ));
+) -> Result<Vec<crate::domain::MeetingActionItem>, String> {
+    crate::db::meeting_queries::fetch_meeting_action_items(database.pool(), &meeting_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Open action items from every meeting, soonest due first.
+#[tauri::command]
+pub async fn meeting_action_item_list_open(
+    limit: u32,
+    offset: u32,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::OpenMeetingActionItem>, String> {
+    crate::db::meeting_queries::fetch_open_action_items(database.pool(), limit, offset)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn meeting_action_item_create(
+    item: crate::domain::MeetingActionItem,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingActionItem, String> {
+    crate::system::history::create_action_item(database.pool(), item).await
+}
+
+#[tauri::command]
+pub async fn meeting_action_item_update(
+    item: crate::domain::MeetingActionItem,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingActionItem, String> {
+    item.validate()?;
+    crate::db::meeting_queries::update_meeting_action_item(database.pool(), &item)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::RowNotFound => format!("Action item {} not found", item.id),
+            err => err.to_string(),
+        })
+}
+
+#[tauri::command]
+pub async fn meeting_action_item_delete(
+    id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::meeting_queries::delete_meeting_action_item(database.pool(), &id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Replace a meeting's action items, e.g. after regenerating its summary.
+#[tauri::command]
+pub async fn meeting_action_items_replace(
+    meeting_id: String,
+    items: Vec<crate::domain::MeetingActionItem>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::MeetingActionItem>, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    for item in &items {
+        item.validate()?;
+        if item.meeting_id != meeting_id {
+            return Err(format!(
+                "Action item {} belongs to another meeting",
+                item.id
+            ));
+        }
     }
 
-    crate::db::meeting_queries::insert_meeting_segments(database.pool(), &segments)
+    crate::db::meeting_queries::replace_meeting_action_items(database.pool(), &meeting_id, &items)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn meeting_decision_list(
+    meeting_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::MeetingDecision>, String> {
+    crate::db::meeting_queries::fetch_meeting_decisions(database.pool(), &meeting_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn meeting_decision_create(
+    decision: crate::domain::MeetingDecision,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingDecision, String> {
+    crate::system::history::create_decision(database.pool(), decision).await
+}
+
+#[tauri::command]
+pub async fn meeting_decision_update(
+    decision: crate::domain::MeetingDecision,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingDecision, String> {
+    decision.validate()?;
+    crate::db::meeting_queries::update_meeting_decision(database.pool(), &decision)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::RowNotFound => format!("Decision {} not found", decision.id),
+            err => err.to_string(),
+        })
+}
+
+#[tauri::command]
+pub async fn meeting_decision_delete(
+    id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::meeting_queries::delete_meeting_decision(database.pool(), &id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn meeting_decisions_replace(
+    meeting_id: String,
+    decisions: Vec<crate::domain::MeetingDecision>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::MeetingDecision>, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    for decision in &decisions {
+        decision.validate()?;
+        if decision.meeting_id != meeting_id {
+            return Err(format!(
+                "Decision {} belongs to another meeting",
+                decision.id
+            ));
+        }
+    }
+
+    crate::db::meeting_queries::replace_meeting_decisions(database.pool(), &meeting_id, &decisions)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Answer a question about a meeting or date range from its transcript,
+/// through the same Ollama-compatible server as post-processing. Other
+/// providers go through `meeting_ask_prepare` and `meeting_ask_record`.
+#[tauri::command]
+pub async fn meeting_ask(
+    request: crate::domain::MeetingQuestion,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingAnswer, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    let pool = database.pool();
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool.clone(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    let connection = resolve_ollama_connection(
+        pool.clone(),
+        preferences.as_ref(),
+        request.api_key_id.clone(),
+    )
+    .await?
+    .ok_or_else(|| "No Ollama model configured for meeting questions.".to_string())?;
+
+    crate::system::meeting_qa::ask(pool, connection, request).await
+}
+
+/// Build the prompt for a meeting question answered by a provider the
+/// webview calls itself.
+#[tauri::command]
+pub async fn meeting_ask_prepare(
+    request: crate::domain::MeetingQuestion,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingQuestionPrompt, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    crate::system::meeting_qa::prepare(database.pool(), request).await
+}
+
+/// Store the answer to a question built by `meeting_ask_prepare`.
+#[tauri::command]
+pub async fn meeting_ask_record(
+    prompt: crate::domain::MeetingQuestionPrompt,
+    answer: String,
+    model: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MeetingAnswer, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    crate::system::meeting_qa::record(database.pool(), prompt, &answer, model).await
+}
+
+/// The conversation that `meeting_ask` continues for a meeting, if any.
+#[tauri::command]
+pub async fn meeting_conversation_get(
+    meeting_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Option<crate::domain::Conversation>, String> {
+    crate::db::conversation_queries::fetch_latest_meeting_conversation(database.pool(), &meeting_id)
         .await
         .map_err(|err| err.to_string())
 }
 
+/// Render a meeting as subtitles, Markdown, plain text or JSON.
+#[tauri::command]
+pub async fn meeting_export(
+    meeting_id: String,
+    options: crate::domain::TranscriptExportOptions,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::TranscriptExport, String> {
+    let pool = database.pool();
+    let meeting = crate::db::meeting_queries::fetch_meeting(pool.clone(), &meeting_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("Meeting {meeting_id} not found"))?;
+    let segments = crate::db::meeting_queries::fetch_meeting_segments(pool.clone(), &meeting_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    let action_items =
+        crate::db::meeting_queries::fetch_meeting_action_items(pool.clone(), &meeting_id)
+            .await
+            .map_err(|err| err.to_string())?;
+    let decisions = crate::db::meeting_queries::fetch_meeting_decisions(pool, &meeting_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let document = crate::system::transcript_export::ExportDocument::from_meeting(
+        &meeting,
+        &segments,
+        &action_items,
+        &decisions,
+    );
+    crate::system::transcript_export::export(&document, &options)
+}
+
+/// Render a single dictation with the same exporters as meetings.
+#[tauri::command]
+pub async fn transcription_export(
+    transcription_id: String,
+    options: crate::domain::TranscriptExportOptions,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::TranscriptExport, String> {
+    let transcription =
+        crate::db::transcription_queries::fetch_transcription(database.pool(), &transcription_id)
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| format!("Transcription {transcription_id} not found"))?;
+
+    let document =
+        crate::system::transcript_export::ExportDocument::from_transcription(&transcription);
+    crate::system::transcript_export::export(&document, &options)
+}
+
+/// Decode an audio or video file and transcribe it locally into a new
+/// meeting or transcription with id `import_id`. Progress is emitted on
+/// `EVT_MEDIA_IMPORT_PROGRESS` and the import stops early when
+/// `media_import_cancel` is called with the same id.
+#[tauri::command]
+pub async fn media_import(
+    app: AppHandle,
+    import_id: String,
+    path: String,
+    target: crate::domain::MediaImportTarget,
+    options: Option<TranscriptionOptionsDto>,
+    imports: State<'_, crate::state::MediaImportState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::MediaImportResult, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    let cancel = imports.begin(&import_id)?;
+    let result = crate::system::media_import::import_media(
+        app,
+        import_id.clone(),
+        PathBuf::from(path),
+        target,
+        options,
+        cancel,
+    )
+    .await;
+    imports.finish(&import_id);
+    result
+}
+
+#[tauri::command]
+pub fn media_import_cancel(
+    import_id: String,
+    imports: State<'_, crate::state::MediaImportState>,
+) -> Result<(), String> {
+    if imports.cancel(&import_id) {
+        Ok(())
+    } else {
+        Err(format!("Import {import_id} is not running"))
+    }
+}
+
+#[tauri::command]
+pub async fn meeting_segments_create_batch(
+    segments: Vec<crate::domain::MeetingSegment>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::MeetingSegment>, String> {
+    crate::system::history::create_meeting_segments(database.pool(), segments).await
+}
+
 #[tauri::command]
 pub async fn meeting_segment_rename_speaker(
     meeting_id: String,
@@ -1301,24 +2926,12 @@ pub async fn meeting_start_audio_writer(
     meeting_id: String,
     sample_rate: u32,
     writer_state: State<'_, crate::system::meeting_audio_store::MeetingAudioWriterState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<(), String> {
-    if sample_rate == 0 {
-        return Err("Sample rate must be greater than zero".to_string());
-    }
-
     let path = crate::system::meeting_audio_store::meeting_audio_path_for(&app, &meeting_id)
         .map_err(|err| err.to_string())?;
 
-    let writer = crate::system::meeting_audio_store::MeetingWavWriter::create(path, sample_rate)
-        .map_err(|err| err.to_string())?;
-
-    let mut guard = writer_state.writer.lock().map_err(|err| err.to_string())?;
-    if guard.is_some() {
-        return Err("A meeting audio writer is already active".to_string());
-    }
-    *guard = Some(writer);
-
-    Ok(())
+    writer_state.start(database.pool(), path, sample_rate).await
 }
 
 #[tauri::command]
@@ -1335,26 +2948,14 @@ pub async fn meeting_append_audio_chunk(
         ));
     }
 
-    let mut guard = writer_state.writer.lock().map_err(|err| err.to_string())?;
-    let writer = guard
-        .as_mut()
-        .ok_or_else(|| "No active meeting audio writer".to_string())?;
-
-    writer
-        .append_samples(&samples)
-        .map_err(|err| err.to_string())
+    writer_state.append(&samples)
 }
 
 #[tauri::command]
 pub async fn meeting_finalize_audio_writer(
     writer_state: State<'_, crate::system::meeting_audio_store::MeetingAudioWriterState>,
 ) -> Result<crate::system::meeting_audio_store::MeetingAudioResult, String> {
-    let mut guard = writer_state.writer.lock().map_err(|err| err.to_string())?;
-    let mut writer = guard
-        .take()
-        .ok_or_else(|| "No active meeting audio writer to finalize".to_string())?;
-
-    writer.finalize().map_err(|err| err.to_string())
+    writer_state.finalize()
 }
 
 #[tauri::command]
@@ -1405,9 +3006,7 @@ pub async fn conversation_create(
     conversation: crate::domain::Conversation,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Conversation, String> {
-    crate::db::conversation_queries::insert_conversation(database.pool(), &conversation)
-        .await
-        .map_err(|err| err.to_string())
+    crate::system::history::create_conversation(database.pool(), conversation).await
 }
 
 #[tauri::command]
@@ -1446,9 +3045,7 @@ pub async fn message_create(
     message: crate::domain::Message,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Message, String> {
-    crate::db::conversation_queries::insert_message(database.pool(), &message)
-        .await
-        .map_err(|err| err.to_string())
+    crate::system::history::create_message(database.pool(), message).await
 }
 
 #[tauri::command]
@@ -1460,3 +3057,72 @@ pub async fn message_list(
         .await
         .map_err(|err| err.to_string())
 }
+
+#[tauri::command]
+pub async fn conversation_branch(
+    conversation_id: String,
+    leaf_message_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::BranchMessage>, String> {
+    crate::system::conversation_context::load_branch(
+        database.pool(),
+        &conversation_id,
+        leaf_message_id.as_deref(),
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn conversation_switch_branch(
+    conversation_id: String,
+    message_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::BranchMessage>, String> {
+    crate::system::conversation_context::switch_branch(
+        database.pool(),
+        &conversation_id,
+        &message_id,
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn conversation_context(
+    request: crate::domain::ConversationContextRequest,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::ConversationContext, String> {
+    let pool = database.pool();
+    let ollama = match request.ollama_api_key_id.clone() {
+        Some(api_key_id) => {
+            let preferences = crate::db::preferences_queries::fetch_user_preferences(
+                pool.clone(),
+                crate::db::preferences_queries::LOCAL_USER_ID,
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+            resolve_ollama_connection(pool.clone(), preferences.as_ref(), Some(api_key_id)).await?
+        }
+        None => None,
+    };
+    crate::system::conversation_context::load_context(pool, request, ollama).await
+}
+
+/// Store the summary a provider wrote for a context's `compaction` prompt.
+#[tauri::command]
+pub async fn conversation_summary_create(
+    request: crate::domain::ConversationSummaryRequest,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::ConversationSummary, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    crate::system::conversation_context::save_summary(database.pool(), request).await
+}
+
+#[tauri::command]
+pub async fn conversation_fork(
+    conversation_id: String,
+    message_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::Conversation, String> {
+    crate::system::incognito::ensure_history_allowed(database.pool()).await?;
+    crate::system::conversation_context::fork(database.pool(), &conversation_id, &message_id).await
+}
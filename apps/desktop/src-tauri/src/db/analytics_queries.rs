@@ -0,0 +1,270 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::{AppUsage, LatencyStage, ModelLatency, UsagePeriod};
+
+/// Transcriptions in `[?1, ?2)` with an approximate word count, recomputed
+/// from the stored transcript on every query. Line breaks and tabs become
+/// spaces, and `texts` keeps halving runs of spaces until a single space
+/// separates each pair of words; only that last row per transcription is
+/// counted.
+const USAGE_CTE: &str = "WITH RECURSIVE texts AS (
+         SELECT timestamp,
+                app_target_id,
+                audio_duration_ms,
+                transcription_mode,
+                model_size,
+                post_process_mode,
+                post_process_device,
+                transcription_duration_ms,
+                postprocess_duration_ms,
+                CASE WHEN warnings_json IS NOT NULL AND warnings_json != '[]' THEN 1 ELSE 0 END
+                    AS has_warnings,
+                trim(replace(replace(replace(transcript, char(13), ' '), char(10), ' '), char(9), ' '))
+                    AS text
+         FROM transcriptions
+         WHERE timestamp >= ?1 AND timestamp < ?2 AND transcript != '[Transcription Failed]'
+         UNION ALL
+         SELECT timestamp,
+                app_target_id,
+                audio_duration_ms,
+                transcription_mode,
+                model_size,
+                post_process_mode,
+                post_process_device,
+                transcription_duration_ms,
+                postprocess_duration_ms,
+                has_warnings,
+                replace(text, '  ', ' ')
+         FROM texts
+         WHERE instr(text, '  ') > 0
+     ),
+     usage AS (
+         SELECT *,
+                CASE WHEN text = '' THEN 0
+                     ELSE length(text) - length(replace(text, ' ', '')) + 1 END AS words
+         FROM texts
+         WHERE instr(text, '  ') = 0
+     )";
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UsageSums {
+    pub transcriptions: i64,
+    pub words: i64,
+    pub speaking_ms: i64,
+    /// Words from transcriptions that kept a recording duration.
+    pub timed_words: i64,
+    pub with_warnings: i64,
+}
+
+pub async fn fetch_usage_sums(
+    pool: SqlitePool,
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<UsageSums, sqlx::Error> {
+    let sql = format!(
+        "{USAGE_CTE}
+         SELECT COUNT(*) AS transcriptions,
+                COALESCE(SUM(words), 0) AS words,
+                COALESCE(SUM(CASE WHEN audio_duration_ms > 0 THEN audio_duration_ms END), 0)
+                    AS speaking_ms,
+                COALESCE(SUM(CASE WHEN audio_duration_ms > 0 THEN words END), 0) AS timed_words,
+                COALESCE(SUM(has_warnings), 0) AS with_warnings
+         FROM usage"
+    );
+    let row = sqlx::query(&sql)
+        .bind(from_ms)
+        .bind(to_ms)
+        .fetch_one(&pool)
+        .await?;
+
+    Ok(UsageSums {
+        transcriptions: row.get::<i64, _>("transcriptions"),
+        words: row.get::<i64, _>("words"),
+        speaking_ms: row.get::<i64, _>("speaking_ms"),
+        timed_words: row.get::<i64, _>("timed_words"),
+        with_warnings: row.get::<i64, _>("with_warnings"),
+    })
+}
+
+/// Usage per local day, or per week starting on Monday when `weekly` is set.
+/// `offset_modifier` is an SQLite date modifier such as `+120 minutes`.
+pub async fn fetch_usage_periods(
+    pool: SqlitePool,
+    from_ms: i64,
+    to_ms: i64,
+    offset_modifier: &str,
+    weekly: bool,
+) -> Result<Vec<UsagePeriod>, sqlx::Error> {
+    let period = if weekly {
+        "date(timestamp / 1000, 'unixepoch', ?3, 'weekday 0', '-6 days')"
+    } else {
+        "date(timestamp / 1000, 'unixepoch', ?3)"
+    };
+    let sql = format!(
+        "{USAGE_CTE}
+         SELECT {period} AS period,
+                COUNT(*) AS transcriptions,
+                COALESCE(SUM(words), 0) AS words,
+                COALESCE(SUM(CASE WHEN audio_duration_ms > 0 THEN audio_duration_ms END), 0)
+                    AS speaking_ms
+         FROM usage
+         GROUP BY period
+         ORDER BY period ASC"
+    );
+    let rows = sqlx::query(&sql)
+        .bind(from_ms)
+        .bind(to_ms)
+        .bind(offset_modifier)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| UsagePeriod {
+            period: row.get::<String, _>("period"),
+            transcriptions: row.get::<i64, _>("transcriptions"),
+            words: row.get::<i64, _>("words"),
+            speaking_ms: row.get::<i64, _>("speaking_ms"),
+        })
+        .collect())
+}
+
+/// Transcription counts per WPM bucket index, where bucket `i` covers
+/// `[i * bucket_wpm, (i + 1) * bucket_wpm)` and `max_bucket` collects the rest.
+/// Clips shorter than a second are left out as too noisy.
+pub async fn fetch_wpm_histogram(
+    pool: SqlitePool,
+    from_ms: i64,
+    to_ms: i64,
+    bucket_wpm: u32,
+    max_bucket: u32,
+) -> Result<Vec<(u32, i64)>, sqlx::Error> {
+    let sql = format!(
+        "{USAGE_CTE}
+         SELECT MIN(CAST(words * 60000.0 / audio_duration_ms / ?3 AS INTEGER), ?4) AS bucket,
+                COUNT(*) AS transcriptions
+         FROM usage
+         WHERE audio_duration_ms >= 1000 AND words > 0
+         GROUP BY bucket
+         ORDER BY bucket ASC"
+    );
+    let rows = sqlx::query(&sql)
+        .bind(from_ms)
+        .bind(to_ms)
+        .bind(i64::from(bucket_wpm))
+        .bind(i64::from(max_bucket))
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let bucket = row.get::<i64, _>("bucket");
+            (
+                u32::try_from(bucket).unwrap_or_default(),
+                row.get::<i64, _>("transcriptions"),
+            )
+        })
+        .collect())
+}
+
+pub async fn fetch_app_usage(
+    pool: SqlitePool,
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<Vec<AppUsage>, sqlx::Error> {
+    let sql = format!(
+        "{USAGE_CTE}
+         SELECT usage.app_target_id AS app_target_id,
+                app_targets.name AS name,
+                COUNT(*) AS transcriptions,
+                COALESCE(SUM(words), 0) AS words,
+                COALESCE(SUM(CASE WHEN audio_duration_ms > 0 THEN audio_duration_ms END), 0)
+                    AS speaking_ms
+         FROM usage
+         LEFT JOIN app_targets ON app_targets.id = usage.app_target_id
+         GROUP BY usage.app_target_id
+         ORDER BY words DESC, transcriptions DESC"
+    );
+    let rows = sqlx::query(&sql)
+        .bind(from_ms)
+        .bind(to_ms)
+        .fetch_all(&pool)
+        .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(AppUsage {
+                app_target_id: row.try_get::<Option<String>, _>("app_target_id")?,
+                name: row.try_get::<Option<String>, _>("name")?,
+                transcriptions: row.get::<i64, _>("transcriptions"),
+                words: row.get::<i64, _>("words"),
+                speaking_ms: row.get::<i64, _>("speaking_ms"),
+            })
+        })
+        .collect()
+}
+
+/// Latency and warning rate per transcription mode and model size, then per
+/// post-processing mode and device. Transcriptions that skipped
+/// post-processing are left out of the second group.
+pub async fn fetch_model_latency(
+    pool: SqlitePool,
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<Vec<ModelLatency>, sqlx::Error> {
+    let stages = [
+        (
+            LatencyStage::Transcription,
+            "transcription_mode",
+            "model_size",
+            "transcription_duration_ms",
+            "1 = 1",
+        ),
+        (
+            LatencyStage::PostProcessing,
+            "post_process_mode",
+            "post_process_device",
+            "postprocess_duration_ms",
+            "post_process_mode IS NOT NULL AND post_process_mode != 'none'",
+        ),
+    ];
+
+    let mut latencies = Vec::new();
+    for (stage, mode, model, duration, filter) in stages {
+        let sql = format!(
+            "{USAGE_CTE}
+             SELECT {mode} AS mode,
+                    {model} AS model,
+                    COUNT(*) AS transcriptions,
+                    AVG({duration}) AS average_ms,
+                    MAX({duration}) AS max_ms,
+                    AVG(has_warnings) AS warning_rate
+             FROM usage
+             WHERE {filter}
+             GROUP BY {mode}, {model}
+             ORDER BY transcriptions DESC"
+        );
+        let rows = sqlx::query(&sql)
+            .bind(from_ms)
+            .bind(to_ms)
+            .fetch_all(&pool)
+            .await?;
+
+        for row in rows {
+            latencies.push(ModelLatency {
+                stage,
+                mode: row.try_get::<Option<String>, _>("mode")?,
+                model: row.try_get::<Option<String>, _>("model")?,
+                transcriptions: row.get::<i64, _>("transcriptions"),
+                average_ms: row.try_get::<Option<f64>, _>("average_ms")?,
+                max_ms: row.try_get::<Option<i64>, _>("max_ms")?,
+                warning_rate: row
+                    .try_get::<Option<f64>, _>("warning_rate")?
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    Ok(latencies)
+}
@@ -0,0 +1,120 @@
+use chrono::Utc;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+use crate::domain::AppProfile;
+
+const APP_PROFILE_COLUMNS: &str = "id, name, created_at, app_identifier, app_target_id, window_title_pattern, priority, language, model_size, insertion_strategy, term_ids, itn_enabled, auto_capitalize, trailing_punctuation";
+
+pub async fn upsert_app_profile(
+    pool: SqlitePool,
+    profile: &AppProfile,
+) -> Result<AppProfile, sqlx::Error> {
+    let existing_created_at =
+        sqlx::query_scalar::<_, String>("SELECT created_at FROM app_profiles WHERE id = ?1")
+            .bind(&profile.id)
+            .fetch_optional(&pool)
+            .await?;
+    let created_at = existing_created_at.unwrap_or_else(|| Utc::now().to_rfc3339());
+    let term_ids = profile
+        .term_ids
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|err| sqlx::Error::Encode(Box::new(err)))?;
+
+    sqlx::query(
+        "INSERT INTO app_profiles (id, name, created_at, app_identifier, app_target_id, window_title_pattern, priority, language, model_size, insertion_strategy, term_ids, itn_enabled, auto_capitalize, trailing_punctuation)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+         ON CONFLICT(id) DO UPDATE SET
+           name = excluded.name,
+           app_identifier = excluded.app_identifier,
+           app_target_id = excluded.app_target_id,
+           window_title_pattern = excluded.window_title_pattern,
+           priority = excluded.priority,
+           language = excluded.language,
+           model_size = excluded.model_size,
+           insertion_strategy = excluded.insertion_strategy,
+           term_ids = excluded.term_ids,
+           itn_enabled = excluded.itn_enabled,
+           auto_capitalize = excluded.auto_capitalize,
+           trailing_punctuation = excluded.trailing_punctuation",
+    )
+    .bind(&profile.id)
+    .bind(&profile.name)
+    .bind(&created_at)
+    .bind(&profile.app_identifier)
+    .bind(&profile.app_target_id)
+    .bind(&profile.window_title_pattern)
+    .bind(profile.priority)
+    .bind(&profile.language)
+    .bind(&profile.model_size)
+    .bind(&profile.insertion_strategy)
+    .bind(term_ids)
+    .bind(profile.itn_enabled)
+    .bind(profile.auto_capitalize)
+    .bind(profile.trailing_punctuation)
+    .execute(&pool)
+    .await?;
+
+    fetch_app_profile(pool, &profile.id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+}
+
+pub async fn fetch_app_profile(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<AppProfile>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {APP_PROFILE_COLUMNS} FROM app_profiles WHERE id = ?1"
+    ))
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(|row| row_to_app_profile(&row)).transpose()
+}
+
+pub async fn fetch_app_profiles(pool: SqlitePool) -> Result<Vec<AppProfile>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {APP_PROFILE_COLUMNS} FROM app_profiles ORDER BY priority DESC, created_at ASC"
+    ))
+    .fetch_all(&pool)
+    .await?;
+
+    rows.iter().map(row_to_app_profile).collect()
+}
+
+pub async fn delete_app_profile(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM app_profiles WHERE id = ?1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+fn row_to_app_profile(row: &SqliteRow) -> Result<AppProfile, sqlx::Error> {
+    let term_ids = row
+        .try_get::<Option<String>, _>("term_ids")?
+        .map(|raw| serde_json::from_str::<Vec<String>>(&raw))
+        .transpose()
+        .map_err(|err| sqlx::Error::Decode(Box::new(err)))?;
+
+    Ok(AppProfile {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        app_identifier: row.try_get("app_identifier")?,
+        app_target_id: row.try_get("app_target_id")?,
+        window_title_pattern: row.try_get("window_title_pattern")?,
+        priority: row.try_get("priority")?,
+        language: row.try_get("language")?,
+        model_size: row.try_get("model_size")?,
+        insertion_strategy: row.try_get("insertion_strategy")?,
+        term_ids,
+        itn_enabled: row.try_get("itn_enabled")?,
+        auto_capitalize: row.try_get("auto_capitalize")?,
+        trailing_punctuation: row.try_get("trailing_punctuation")?,
+    })
+}
@@ -1,8 +1,9 @@
 use chrono::Utc;
-use sqlx::{Row, SqlitePool};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
 use crate::domain::AppTarget;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn upsert_app_target(
     pool: SqlitePool,
     id: &str,
@@ -10,6 +11,8 @@ pub async fn upsert_app_target(
     tone_id: Option<String>,
     icon_path: Option<String>,
     paste_keybind: Option<String>,
+    itn_enabled: Option<bool>,
+    translation_language: Option<String>,
 ) -> Result<AppTarget, sqlx::Error> {
     let existing_created_at =
         sqlx::query_scalar::<_, Option<String>>("SELECT created_at FROM app_targets WHERE id = ?1")
@@ -22,13 +25,17 @@ pub async fn upsert_app_target(
         .unwrap_or_else(|| Utc::now().to_rfc3339());
 
     sqlx::query(
-        "INSERT INTO app_targets (id, name, created_at, tone_id, icon_path, paste_keybind)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "INSERT INTO app_targets (id, name, created_at, tone_id, icon_path, paste_keybind, itn_enabled,
+                                  translation_language)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, COALESCE(?7, 1), NULLIF(?8, ''))
          ON CONFLICT(id) DO UPDATE SET
            name = excluded.name,
            tone_id = excluded.tone_id,
            icon_path = excluded.icon_path,
-           paste_keybind = excluded.paste_keybind",
+           paste_keybind = excluded.paste_keybind,
+           itn_enabled = COALESCE(?7, app_targets.itn_enabled),
+           translation_language = CASE WHEN ?8 IS NULL THEN app_targets.translation_language
+                                       ELSE NULLIF(?8, '') END",
     )
     .bind(id)
     .bind(name)
@@ -36,44 +43,49 @@ pub async fn upsert_app_target(
     .bind(tone_id)
     .bind(icon_path)
     .bind(paste_keybind)
+    .bind(itn_enabled)
+    .bind(translation_language)
     .execute(&pool)
     .await?;
 
+    fetch_app_target(pool, id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+}
+
+pub async fn fetch_app_target(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<AppTarget>, sqlx::Error> {
     let row = sqlx::query(
-        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind FROM app_targets WHERE id = ?1",
+        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind, itn_enabled, translation_language FROM app_targets WHERE id = ?1",
     )
-        .bind(id)
-        .fetch_one(&pool)
-        .await?;
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
 
-    Ok(AppTarget {
-        id: row.get("id"),
-        name: row.get("name"),
-        created_at: row.get("created_at"),
-        tone_id: row.try_get("tone_id")?,
-        icon_path: row.try_get("icon_path")?,
-        paste_keybind: row.try_get("paste_keybind")?,
-    })
+    row.map(|row| row_to_app_target(&row)).transpose()
 }
 
 pub async fn fetch_app_targets(pool: SqlitePool) -> Result<Vec<AppTarget>, sqlx::Error> {
     let rows = sqlx::query(
-        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind FROM app_targets ORDER BY created_at DESC",
+        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind, itn_enabled, translation_language FROM app_targets ORDER BY created_at DESC",
     )
     .fetch_all(&pool)
     .await?;
 
-    let mut targets = Vec::with_capacity(rows.len());
-    for row in rows {
-        targets.push(AppTarget {
-            id: row.get("id"),
-            name: row.get("name"),
-            created_at: row.get("created_at"),
-            tone_id: row.try_get("tone_id")?,
-            icon_path: row.try_get("icon_path")?,
-            paste_keybind: row.try_get("paste_keybind")?,
-        });
-    }
+    rows.iter().map(row_to_app_target).collect()
+}
 
-    Ok(targets)
+fn row_to_app_target(row: &SqliteRow) -> Result<AppTarget, sqlx::Error> {
+    Ok(AppTarget {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        tone_id: row.try_get("tone_id")?,
+        icon_path: row.try_get("icon_path")?,
+        paste_keybind: row.try_get("paste_keybind")?,
+        itn_enabled: row.try_get::<i64, _>("itn_enabled")? != 0,
+        translation_language: row.try_get("translation_language")?,
+    })
 }
@@ -1,11 +1,14 @@
 use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
-use crate::domain::{Conversation, Message};
+use crate::domain::{Conversation, ConversationSummary, Message};
 
 fn row_to_conversation(row: SqliteRow) -> Result<Conversation, sqlx::Error> {
     Ok(Conversation {
         id: row.get::<String, _>("id"),
         title: row.get::<String, _>("title"),
+        meeting_id: row.try_get::<Option<String>, _>("meeting_id")?,
+        head_message_id: row.try_get::<Option<String>, _>("head_message_id")?,
+        starred: row.try_get::<bool, _>("starred")?,
         created_at: row.get::<String, _>("created_at"),
         updated_at: row.get::<String, _>("updated_at"),
     })
@@ -15,6 +18,7 @@ fn row_to_message(row: SqliteRow) -> Result<Message, sqlx::Error> {
     Ok(Message {
         id: row.get::<String, _>("id"),
         conversation_id: row.get::<String, _>("conversation_id"),
+        parent_id: row.try_get::<Option<String>, _>("parent_id")?,
         role: row.get::<String, _>("role"),
         content: row.get::<String, _>("content"),
         model: row.try_get::<Option<String>, _>("model")?,
@@ -29,10 +33,12 @@ pub async fn insert_conversation(
     conversation: &Conversation,
 ) -> Result<Conversation, sqlx::Error> {
     sqlx::query(
-        "INSERT INTO conversations (id, title, created_at, updated_at) VALUES (?1, ?2, ?3, ?4)",
+        "INSERT INTO conversations (id, title, meeting_id, head_message_id, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
     )
     .bind(&conversation.id)
     .bind(&conversation.title)
+    .bind(conversation.meeting_id.as_deref())
+    .bind(conversation.head_message_id.as_deref())
     .bind(&conversation.created_at)
     .bind(&conversation.updated_at)
     .execute(&pool)
@@ -50,7 +56,7 @@ pub async fn fetch_conversations(
 ) -> Result<Vec<Conversation>, sqlx::Error> {
     let capped_limit = limit.min(MAX_CONVERSATION_LIMIT);
     let rows = sqlx::query(
-        "SELECT id, title, created_at, updated_at FROM conversations ORDER BY updated_at DESC LIMIT ?1 OFFSET ?2",
+        "SELECT id, title, meeting_id, head_message_id, starred, created_at, updated_at FROM conversations ORDER BY updated_at DESC LIMIT ?1 OFFSET ?2",
     )
     .bind(capped_limit as i64)
     .bind(offset as i64)
@@ -65,6 +71,35 @@ pub async fn fetch_conversations(
     Ok(conversations)
 }
 
+pub async fn fetch_conversation(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<Conversation>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id, title, meeting_id, head_message_id, starred, created_at, updated_at FROM conversations WHERE id = ?1",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(row_to_conversation).transpose()
+}
+
+/// The most recently active conversation about a meeting, if any.
+pub async fn fetch_latest_meeting_conversation(
+    pool: SqlitePool,
+    meeting_id: &str,
+) -> Result<Option<Conversation>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id, title, meeting_id, head_message_id, starred, created_at, updated_at FROM conversations WHERE meeting_id = ?1 ORDER BY updated_at DESC LIMIT 1",
+    )
+    .bind(meeting_id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(row_to_conversation).transpose()
+}
+
 pub async fn update_conversation(
     pool: SqlitePool,
     conversation: &Conversation,
@@ -88,30 +123,60 @@ pub async fn delete_conversation(pool: SqlitePool, id: &str) -> Result<(), sqlx:
     Ok(())
 }
 
+const INSERT_MESSAGE_SQL: &str = "INSERT INTO messages (id, conversation_id, parent_id, role, content, model, tokens_used, context_json, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+
+type SqliteQuery<'q> = sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>;
+
+fn bind_message<'q>(query: SqliteQuery<'q>, message: &'q Message) -> SqliteQuery<'q> {
+    query
+        .bind(&message.id)
+        .bind(&message.conversation_id)
+        .bind(message.parent_id.as_deref())
+        .bind(&message.role)
+        .bind(&message.content)
+        .bind(message.model.as_deref())
+        .bind(message.tokens_used)
+        .bind(message.context_json.as_deref())
+        .bind(&message.created_at)
+}
+
+/// Inserts a message and makes it the head of its conversation, so the
+/// newest reply is always on the active branch.
 pub async fn insert_message(pool: SqlitePool, message: &Message) -> Result<Message, sqlx::Error> {
-    sqlx::query(
-        "INSERT INTO messages (id, conversation_id, role, content, model, tokens_used, context_json, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-    )
-    .bind(&message.id)
-    .bind(&message.conversation_id)
-    .bind(&message.role)
-    .bind(&message.content)
-    .bind(message.model.as_deref())
-    .bind(message.tokens_used)
-    .bind(message.context_json.as_deref())
-    .bind(&message.created_at)
-    .execute(&pool)
-    .await?;
+    let mut tx = pool.begin().await?;
+    bind_message(sqlx::query(INSERT_MESSAGE_SQL), message)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE conversations SET head_message_id = ?2 WHERE id = ?1")
+        .bind(&message.conversation_id)
+        .bind(&message.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
 
     Ok(message.clone())
 }
 
+pub async fn set_conversation_head(
+    pool: SqlitePool,
+    conversation_id: &str,
+    message_id: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE conversations SET head_message_id = ?2 WHERE id = ?1")
+        .bind(conversation_id)
+        .bind(message_id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
 pub async fn fetch_messages(
     pool: SqlitePool,
     conversation_id: &str,
 ) -> Result<Vec<Message>, sqlx::Error> {
     let rows = sqlx::query(
-        "SELECT id, conversation_id, role, content, model, tokens_used, context_json, created_at FROM messages WHERE conversation_id = ?1 ORDER BY created_at ASC",
+        "SELECT id, conversation_id, parent_id, role, content, model, tokens_used, context_json, created_at FROM messages WHERE conversation_id = ?1 ORDER BY created_at ASC, rowid ASC",
     )
     .bind(conversation_id)
     .fetch_all(&pool)
@@ -123,4 +188,98 @@ pub async fn fetch_messages(
     }
 
     Ok(messages)
-}
\ No newline at end of file
+}
+
+fn row_to_summary(row: SqliteRow) -> Result<ConversationSummary, sqlx::Error> {
+    Ok(ConversationSummary {
+        id: row.get::<String, _>("id"),
+        conversation_id: row.get::<String, _>("conversation_id"),
+        through_message_id: row.get::<String, _>("through_message_id"),
+        content: row.get::<String, _>("content"),
+        token_count: row.get::<i64, _>("token_count"),
+        model: row.try_get::<Option<String>, _>("model")?,
+        created_at: row.get::<String, _>("created_at"),
+    })
+}
+
+const INSERT_SUMMARY_SQL: &str = "INSERT INTO conversation_summaries (id, conversation_id, through_message_id, content, token_count, model, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)";
+
+fn bind_summary<'q>(query: SqliteQuery<'q>, summary: &'q ConversationSummary) -> SqliteQuery<'q> {
+    query
+        .bind(&summary.id)
+        .bind(&summary.conversation_id)
+        .bind(&summary.through_message_id)
+        .bind(&summary.content)
+        .bind(summary.token_count)
+        .bind(summary.model.as_deref())
+        .bind(&summary.created_at)
+}
+
+pub async fn insert_summary(
+    pool: SqlitePool,
+    summary: &ConversationSummary,
+) -> Result<ConversationSummary, sqlx::Error> {
+    bind_summary(sqlx::query(INSERT_SUMMARY_SQL), summary)
+        .execute(&pool)
+        .await?;
+
+    Ok(summary.clone())
+}
+
+pub async fn fetch_summaries(
+    pool: SqlitePool,
+    conversation_id: &str,
+) -> Result<Vec<ConversationSummary>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, conversation_id, through_message_id, content, token_count, model, created_at FROM conversation_summaries WHERE conversation_id = ?1 ORDER BY created_at ASC",
+    )
+    .bind(conversation_id)
+    .fetch_all(&pool)
+    .await?;
+
+    let mut summaries = Vec::with_capacity(rows.len());
+    for row in rows {
+        summaries.push(row_to_summary(row)?);
+    }
+
+    Ok(summaries)
+}
+
+/// Writes a forked conversation with its copied messages and summaries in
+/// one transaction. Messages must be ordered parents first.
+pub async fn insert_forked_conversation(
+    pool: SqlitePool,
+    conversation: &Conversation,
+    messages: &[Message],
+    summaries: &[ConversationSummary],
+) -> Result<Conversation, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO conversations (id, title, meeting_id, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+    )
+    .bind(&conversation.id)
+    .bind(&conversation.title)
+    .bind(conversation.meeting_id.as_deref())
+    .bind(&conversation.created_at)
+    .bind(&conversation.updated_at)
+    .execute(&mut *tx)
+    .await?;
+    for message in messages {
+        bind_message(sqlx::query(INSERT_MESSAGE_SQL), message)
+            .execute(&mut *tx)
+            .await?;
+    }
+    for summary in summaries {
+        bind_summary(sqlx::query(INSERT_SUMMARY_SQL), summary)
+            .execute(&mut *tx)
+            .await?;
+    }
+    sqlx::query("UPDATE conversations SET head_message_id = ?2 WHERE id = ?1")
+        .bind(&conversation.id)
+        .bind(conversation.head_message_id.as_deref())
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(conversation.clone())
+}
@@ -0,0 +1,60 @@
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::{LocalApiSettings, DEFAULT_LOCAL_API_PORT};
+
+pub async fn fetch_local_api_settings(pool: SqlitePool) -> Result<LocalApiSettings, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT enabled, port, token_salt, token_hash, token_ciphertext, token_suffix
+         FROM local_api_settings
+         WHERE id = 1",
+    )
+    .fetch_optional(&pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(LocalApiSettings {
+            port: DEFAULT_LOCAL_API_PORT,
+            ..LocalApiSettings::default()
+        });
+    };
+
+    let port = row.get::<i64, _>("port");
+    Ok(LocalApiSettings {
+        enabled: row.get::<bool, _>("enabled"),
+        port: u16::try_from(port).unwrap_or(DEFAULT_LOCAL_API_PORT),
+        token_salt: row.get::<Option<String>, _>("token_salt"),
+        token_hash: row.get::<Option<String>, _>("token_hash"),
+        token_ciphertext: row.get::<Option<String>, _>("token_ciphertext"),
+        token_suffix: row.get::<Option<String>, _>("token_suffix"),
+    })
+}
+
+pub async fn save_local_api_settings(
+    pool: SqlitePool,
+    settings: &LocalApiSettings,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO local_api_settings (id, enabled, port, token_salt, token_hash, token_ciphertext, token_suffix, updated_at)
+         VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(id) DO UPDATE SET
+           enabled = excluded.enabled,
+           port = excluded.port,
+           token_salt = excluded.token_salt,
+           token_hash = excluded.token_hash,
+           token_ciphertext = excluded.token_ciphertext,
+           token_suffix = excluded.token_suffix,
+           updated_at = excluded.updated_at",
+    )
+    .bind(settings.enabled)
+    .bind(i64::from(settings.port))
+    .bind(&settings.token_salt)
+    .bind(&settings.token_hash)
+    .bind(&settings.token_ciphertext)
+    .bind(&settings.token_suffix)
+    .bind(Utc::now().to_rfc3339())
+    .execute(&pool)
+    .await?;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
-use crate::domain::{Meeting, MeetingSegment};
+use crate::domain::{
+    Meeting, MeetingActionItem, MeetingDecision, MeetingExcerpt, MeetingSegment,
+    OpenMeetingActionItem, ACTION_ITEM_STATUS_OPEN,
+};
 
 fn row_to_meeting(row: SqliteRow) -> Result<Meeting, sqlx::Error> {
     Ok(Meeting {
@@ -14,6 +17,7 @@ fn row_to_meeting(row: SqliteRow) -> Result<Meeting, sqlx::Error> {
         audio_path: row.try_get::<Option<String>, _>("audio_path")?,
         summary: row.try_get::<Option<String>, _>("summary")?,
         action_items: row.try_get::<Option<String>, _>("action_items")?,
+        starred: row.try_get::<bool, _>("starred")?,
         created_at: row.get::<String, _>("created_at"),
         updated_at: row.get::<String, _>("updated_at"),
     })
@@ -32,6 +36,34 @@ fn row_to_segment(row: SqliteRow) -> Result<MeetingSegment, sqlx::Error> {
     })
 }
 
+fn row_to_action_item(row: &SqliteRow) -> Result<MeetingActionItem, sqlx::Error> {
+    Ok(MeetingActionItem {
+        id: row.get::<String, _>("id"),
+        meeting_id: row.get::<String, _>("meeting_id"),
+        task: row.get::<String, _>("task"),
+        owner: row.try_get::<Option<String>, _>("owner")?,
+        due_date: row.try_get::<Option<String>, _>("due_date")?,
+        priority: row.try_get::<Option<String>, _>("priority")?,
+        status: row.get::<String, _>("status"),
+        source_segment_id: row.try_get::<Option<String>, _>("source_segment_id")?,
+        position: row.get::<i64, _>("position"),
+        created_at: row.get::<String, _>("created_at"),
+        updated_at: row.get::<String, _>("updated_at"),
+    })
+}
+
+fn row_to_decision(row: SqliteRow) -> Result<MeetingDecision, sqlx::Error> {
+    Ok(MeetingDecision {
+        id: row.get::<String, _>("id"),
+        meeting_id: row.get::<String, _>("meeting_id"),
+        text: row.get::<String, _>("text"),
+        source_segment_id: row.try_get::<Option<String>, _>("source_segment_id")?,
+        position: row.get::<i64, _>("position"),
+        created_at: row.get::<String, _>("created_at"),
+        updated_at: row.get::<String, _>("updated_at"),
+    })
+}
+
 pub async fn insert_meeting(
     pool: SqlitePool,
     meeting: &Meeting,
@@ -67,7 +99,7 @@ pub async fn fetch_meetings(
 ) -> Result<Vec<Meeting>, sqlx::Error> {
     let capped_limit = limit.min(MAX_MEETING_LIMIT);
     let rows = sqlx::query(
-        "SELECT id, title, app_source, started_at, ended_at, duration_ms, status, audio_path, summary, action_items, created_at, updated_at
+        "SELECT id, title, app_source, started_at, ended_at, duration_ms, status, audio_path, summary, action_items, starred, created_at, updated_at
          FROM meetings
          ORDER BY started_at DESC
          LIMIT ?1 OFFSET ?2",
@@ -85,6 +117,19 @@ pub async fn fetch_meetings(
     Ok(meetings)
 }
 
+pub async fn fetch_meeting(pool: SqlitePool, id: &str) -> Result<Option<Meeting>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id, title, app_source, started_at, ended_at, duration_ms, status, audio_path, summary, action_items, starred, created_at, updated_at
+         FROM meetings
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(row_to_meeting).transpose()
+}
+
 pub async fn update_meeting(
     pool: SqlitePool,
     meeting: &Meeting,
@@ -139,6 +184,81 @@ pub async fn fetch_meeting_segments(
     Ok(segments)
 }
 
+fn row_to_excerpt(row: SqliteRow) -> Result<MeetingExcerpt, sqlx::Error> {
+    let meeting_title = row.get::<String, _>("meeting_title");
+    let meeting_started_at = row.get::<String, _>("meeting_started_at");
+    Ok(MeetingExcerpt {
+        segment: row_to_segment(row)?,
+        meeting_title,
+        meeting_started_at,
+    })
+}
+
+/// Which meetings a segment search covers. Unset fields do not filter;
+/// `from` is inclusive and `to` exclusive, both compared against `started_at`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MeetingScope<'a> {
+    pub meeting_id: Option<&'a str>,
+    pub from: Option<&'a str>,
+    pub to: Option<&'a str>,
+}
+
+const EXCERPT_COLUMNS: &str = "s.id, s.meeting_id, s.speaker_id, s.speaker_name, s.text, s.start_ms, s.end_ms, s.created_at, m.title AS meeting_title, m.started_at AS meeting_started_at";
+
+const SCOPE_FILTER: &str = "(?1 IS NULL OR m.id = ?1) AND (?2 IS NULL OR m.started_at >= ?2) AND (?3 IS NULL OR m.started_at < ?3)";
+
+/// Full-text search over segment text and speaker names, best match first.
+/// `match_query` uses FTS5 query syntax.
+pub async fn search_meeting_excerpts(
+    pool: SqlitePool,
+    match_query: &str,
+    scope: MeetingScope<'_>,
+    limit: u32,
+) -> Result<Vec<MeetingExcerpt>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {EXCERPT_COLUMNS}
+         FROM meeting_segments_fts
+         JOIN meeting_segments AS s ON s.rowid = meeting_segments_fts.rowid
+         JOIN meetings AS m ON m.id = s.meeting_id
+         WHERE {SCOPE_FILTER} AND meeting_segments_fts MATCH ?4
+         ORDER BY bm25(meeting_segments_fts)
+         LIMIT ?5"
+    ))
+    .bind(scope.meeting_id)
+    .bind(scope.from)
+    .bind(scope.to)
+    .bind(match_query)
+    .bind(limit as i64)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter().map(row_to_excerpt).collect()
+}
+
+/// Segments in scope in the order they were spoken.
+pub async fn fetch_meeting_excerpts(
+    pool: SqlitePool,
+    scope: MeetingScope<'_>,
+    limit: u32,
+) -> Result<Vec<MeetingExcerpt>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {EXCERPT_COLUMNS}
+         FROM meeting_segments AS s
+         JOIN meetings AS m ON m.id = s.meeting_id
+         WHERE {SCOPE_FILTER}
+         ORDER BY m.started_at ASC, s.start_ms ASC
+         LIMIT ?4"
+    ))
+    .bind(scope.meeting_id)
+    .bind(scope.from)
+    .bind(scope.to)
+    .bind(limit as i64)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter().map(row_to_excerpt).collect()
+}
+
 pub async fn insert_meeting_segments(
     pool: SqlitePool,
     segments: &[MeetingSegment],
@@ -184,3 +304,248 @@ pub async fn update_meeting_segment_speaker(
 
     Ok(())
 }
+
+const ACTION_ITEM_COLUMNS: &str = "id, meeting_id, task, owner, due_date, priority, status, source_segment_id, position, created_at, updated_at";
+
+pub async fn fetch_meeting_action_items(
+    pool: SqlitePool,
+    meeting_id: &str,
+) -> Result<Vec<MeetingActionItem>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {ACTION_ITEM_COLUMNS} FROM meeting_action_items
+         WHERE meeting_id = ?1
+         ORDER BY position ASC, created_at ASC"
+    ))
+    .bind(meeting_id)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.iter().map(row_to_action_item).collect()
+}
+
+/// Open action items across all meetings: dated items first by due date,
+/// then undated ones from the most recent meetings.
+pub async fn fetch_open_action_items(
+    pool: SqlitePool,
+    limit: u32,
+    offset: u32,
+) -> Result<Vec<OpenMeetingActionItem>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT i.id, i.meeting_id, i.task, i.owner, i.due_date, i.priority, i.status,
+                i.source_segment_id, i.position, i.created_at, i.updated_at,
+                m.title AS meeting_title, m.started_at AS meeting_started_at
+         FROM meeting_action_items i
+         JOIN meetings m ON m.id = i.meeting_id
+         WHERE i.status = ?1
+         ORDER BY i.due_date IS NULL, i.due_date ASC, m.started_at DESC, i.position ASC
+         LIMIT ?2 OFFSET ?3",
+    )
+    .bind(ACTION_ITEM_STATUS_OPEN)
+    .bind(limit as i64)
+    .bind(offset as i64)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.iter()
+        .map(|row| {
+            Ok(OpenMeetingActionItem {
+                item: row_to_action_item(row)?,
+                meeting_title: row.get::<String, _>("meeting_title"),
+                meeting_started_at: row.get::<String, _>("meeting_started_at"),
+            })
+        })
+        .collect()
+}
+
+pub async fn insert_meeting_action_item(
+    pool: SqlitePool,
+    item: &MeetingActionItem,
+) -> Result<MeetingActionItem, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    insert_action_item_row(&mut conn, item).await?;
+    Ok(item.clone())
+}
+
+/// Fails with `RowNotFound` when no item has `item.id`.
+pub async fn update_meeting_action_item(
+    pool: SqlitePool,
+    item: &MeetingActionItem,
+) -> Result<MeetingActionItem, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE meeting_action_items
+         SET task = ?2, owner = ?3, due_date = ?4, priority = ?5, status = ?6,
+             source_segment_id = ?7, position = ?8, updated_at = ?9
+         WHERE id = ?1",
+    )
+    .bind(&item.id)
+    .bind(&item.task)
+    .bind(&item.owner)
+    .bind(&item.due_date)
+    .bind(&item.priority)
+    .bind(&item.status)
+    .bind(&item.source_segment_id)
+    .bind(item.position)
+    .bind(&item.updated_at)
+    .execute(&pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+    Ok(item.clone())
+}
+
+pub async fn delete_meeting_action_item(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM meeting_action_items WHERE id = ?1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Swap a meeting's action items for a freshly generated set.
+pub async fn replace_meeting_action_items(
+    pool: SqlitePool,
+    meeting_id: &str,
+    items: &[MeetingActionItem],
+) -> Result<Vec<MeetingActionItem>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM meeting_action_items WHERE meeting_id = ?1")
+        .bind(meeting_id)
+        .execute(&mut *tx)
+        .await?;
+    for item in items {
+        insert_action_item_row(&mut tx, item).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(items.to_vec())
+}
+
+async fn insert_action_item_row(
+    conn: &mut sqlx::SqliteConnection,
+    item: &MeetingActionItem,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(&format!(
+        "INSERT INTO meeting_action_items ({ACTION_ITEM_COLUMNS})
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+    ))
+    .bind(&item.id)
+    .bind(&item.meeting_id)
+    .bind(&item.task)
+    .bind(&item.owner)
+    .bind(&item.due_date)
+    .bind(&item.priority)
+    .bind(&item.status)
+    .bind(&item.source_segment_id)
+    .bind(item.position)
+    .bind(&item.created_at)
+    .bind(&item.updated_at)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn fetch_meeting_decisions(
+    pool: SqlitePool,
+    meeting_id: &str,
+) -> Result<Vec<MeetingDecision>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, meeting_id, text, source_segment_id, position, created_at, updated_at
+         FROM meeting_decisions
+         WHERE meeting_id = ?1
+         ORDER BY position ASC, created_at ASC",
+    )
+    .bind(meeting_id)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter().map(row_to_decision).collect()
+}
+
+pub async fn insert_meeting_decision(
+    pool: SqlitePool,
+    decision: &MeetingDecision,
+) -> Result<MeetingDecision, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    insert_decision_row(&mut conn, decision).await?;
+    Ok(decision.clone())
+}
+
+/// Fails with `RowNotFound` when no decision has `decision.id`.
+pub async fn update_meeting_decision(
+    pool: SqlitePool,
+    decision: &MeetingDecision,
+) -> Result<MeetingDecision, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE meeting_decisions
+         SET text = ?2, source_segment_id = ?3, position = ?4, updated_at = ?5
+         WHERE id = ?1",
+    )
+    .bind(&decision.id)
+    .bind(&decision.text)
+    .bind(&decision.source_segment_id)
+    .bind(decision.position)
+    .bind(&decision.updated_at)
+    .execute(&pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+    Ok(decision.clone())
+}
+
+pub async fn delete_meeting_decision(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM meeting_decisions WHERE id = ?1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn replace_meeting_decisions(
+    pool: SqlitePool,
+    meeting_id: &str,
+    decisions: &[MeetingDecision],
+) -> Result<Vec<MeetingDecision>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM meeting_decisions WHERE meeting_id = ?1")
+        .bind(meeting_id)
+        .execute(&mut *tx)
+        .await?;
+    for decision in decisions {
+        insert_decision_row(&mut tx, decision).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(decisions.to_vec())
+}
+
+async fn insert_decision_row(
+    conn: &mut sqlx::SqliteConnection,
+    decision: &MeetingDecision,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO meeting_decisions (id, meeting_id, text, source_segment_id, position, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )
+    .bind(&decision.id)
+    .bind(&decision.meeting_id)
+    .bind(&decision.text)
+    .bind(&decision.source_segment_id)
+    .bind(decision.position)
+    .bind(&decision.created_at)
+    .bind(&decision.updated_at)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+use crate::domain::MicrophoneCalibration;
+
+fn row_to_calibration(row: SqliteRow) -> Result<MicrophoneCalibration, sqlx::Error> {
+    Ok(MicrophoneCalibration {
+        device_name: row.try_get("device_name")?,
+        noise_floor_db: row.try_get::<f64, _>("noise_floor_db")? as f32,
+        speech_level_db: row.try_get::<f64, _>("speech_level_db")? as f32,
+        snr_db: row.try_get::<f64, _>("snr_db")? as f32,
+        clipping_ratio: row.try_get::<f64, _>("clipping_ratio")? as f32,
+        dc_offset: row.try_get::<f64, _>("dc_offset")? as f32,
+        score: row.try_get::<f64, _>("score")? as f32,
+        recommended_gain: row.try_get::<f64, _>("recommended_gain")? as f32,
+        measured_at: row.try_get("measured_at")?,
+    })
+}
+
+/// Saved calibrations, best first.
+pub async fn fetch_microphone_calibrations(
+    pool: SqlitePool,
+) -> Result<Vec<MicrophoneCalibration>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT device_name, noise_floor_db, speech_level_db, snr_db, clipping_ratio, dc_offset,
+                score, recommended_gain, measured_at
+         FROM microphone_calibrations
+         ORDER BY score DESC",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter().map(row_to_calibration).collect()
+}
+
+/// Replaces the device's previous calibration.
+pub async fn save_microphone_calibration(
+    pool: SqlitePool,
+    calibration: &MicrophoneCalibration,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO microphone_calibrations (
+             device_name, noise_floor_db, speech_level_db, snr_db, clipping_ratio, dc_offset,
+             score, recommended_gain, measured_at
+         )
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+         ON CONFLICT(device_name) DO UPDATE SET
+            noise_floor_db = excluded.noise_floor_db,
+            speech_level_db = excluded.speech_level_db,
+            snr_db = excluded.snr_db,
+            clipping_ratio = excluded.clipping_ratio,
+            dc_offset = excluded.dc_offset,
+            score = excluded.score,
+            recommended_gain = excluded.recommended_gain,
+            measured_at = excluded.measured_at",
+    )
+    .bind(&calibration.device_name)
+    .bind(f64::from(calibration.noise_floor_db))
+    .bind(f64::from(calibration.speech_level_db))
+    .bind(f64::from(calibration.snr_db))
+    .bind(f64::from(calibration.clipping_ratio))
+    .bind(f64::from(calibration.dc_offset))
+    .bind(f64::from(calibration.score))
+    .bind(f64::from(calibration.recommended_gain))
+    .bind(&calibration.measured_at)
+    .execute(&pool)
+    .await?;
+
+    Ok(())
+}
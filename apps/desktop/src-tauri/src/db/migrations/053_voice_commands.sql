@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS voice_commands (
+    id TEXT PRIMARY KEY,
+    created_at INTEGER NOT NULL,
+    created_by_user_id TEXT NOT NULL,
+    phrase TEXT NOT NULL,
+    action_kind TEXT NOT NULL,
+    action_value TEXT NOT NULL,
+    is_deleted INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX IF NOT EXISTS idx_voice_commands_is_deleted ON voice_commands (is_deleted);
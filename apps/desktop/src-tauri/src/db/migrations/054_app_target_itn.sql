@@ -0,0 +1 @@
+ALTER TABLE app_targets ADD COLUMN itn_enabled INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS app_profiles (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    app_identifier TEXT,
+    app_target_id TEXT,
+    window_title_pattern TEXT,
+    priority INTEGER NOT NULL DEFAULT 0,
+    language TEXT,
+    model_size TEXT,
+    insertion_strategy TEXT,
+    term_ids TEXT,
+    itn_enabled INTEGER,
+    auto_capitalize INTEGER,
+    trailing_punctuation INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_app_profiles_app_identifier ON app_profiles (app_identifier);
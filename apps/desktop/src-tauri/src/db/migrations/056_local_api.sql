@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS local_api_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled INTEGER NOT NULL DEFAULT 0,
+    port INTEGER NOT NULL,
+    token_salt TEXT,
+    token_hash TEXT,
+    token_ciphertext TEXT,
+    token_suffix TEXT,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,85 @@
+CREATE TABLE IF NOT EXISTS meeting_action_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    task TEXT NOT NULL,
+    owner TEXT,
+    due_date TEXT,
+    priority TEXT,
+    status TEXT NOT NULL DEFAULT 'open',
+    source_segment_id TEXT,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (source_segment_id) REFERENCES meeting_segments(id) ON DELETE SET NULL
+);
+
+CREATE TABLE IF NOT EXISTS meeting_decisions (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    source_segment_id TEXT,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (source_segment_id) REFERENCES meeting_segments(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_action_items_meeting ON meeting_action_items(meeting_id, position);
+CREATE INDEX IF NOT EXISTS idx_meeting_action_items_status_due ON meeting_action_items(status, due_date);
+CREATE INDEX IF NOT EXISTS idx_meeting_decisions_meeting ON meeting_decisions(meeting_id, position);
+
+-- Summaries stored action items as a JSON array of {task, assignee, priority}.
+-- Ids are random v4 UUIDs, the same shape the app generates.
+INSERT INTO meeting_action_items (id, meeting_id, task, owner, priority, status, position, created_at, updated_at)
+SELECT
+    lower(hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4' || substr(hex(randomblob(2)), 2) || '-'
+        || substr('89ab', 1 + (random() & 3), 1) || substr(hex(randomblob(2)), 2) || '-' || hex(randomblob(6))),
+    legacy.id,
+    trim(json_extract(item.value, '$.task')),
+    nullif(trim(json_extract(item.value, '$.assignee')), ''),
+    nullif(lower(trim(json_extract(item.value, '$.priority'))), ''),
+    'open',
+    item.key,
+    legacy.updated_at,
+    legacy.updated_at
+FROM meetings AS legacy,
+    json_each(CASE
+        WHEN NOT json_valid(legacy.action_items) THEN '[]'
+        WHEN json_type(legacy.action_items) = 'array' THEN legacy.action_items
+        ELSE '[]'
+    END) AS item
+WHERE item.type = 'object'
+  AND trim(coalesce(json_extract(item.value, '$.task'), '')) <> '';
+
+-- Older rows may hold plain text with one item per line, optionally bulleted.
+WITH RECURSIVE lines(meeting_id, updated_at, line, rest, position) AS (
+    SELECT id, updated_at, '', action_items || char(10), -1
+    FROM meetings
+    WHERE action_items IS NOT NULL
+      AND trim(action_items) <> ''
+      AND NOT json_valid(action_items)
+    UNION ALL
+    SELECT
+        meeting_id,
+        updated_at,
+        trim(substr(rest, 1, instr(rest, char(10)) - 1), ' -*•' || char(9) || char(13)),
+        substr(rest, instr(rest, char(10)) + 1),
+        position + 1
+    FROM lines
+    WHERE rest <> ''
+)
+INSERT INTO meeting_action_items (id, meeting_id, task, status, position, created_at, updated_at)
+SELECT
+    lower(hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4' || substr(hex(randomblob(2)), 2) || '-'
+        || substr('89ab', 1 + (random() & 3), 1) || substr(hex(randomblob(2)), 2) || '-' || hex(randomblob(6))),
+    meeting_id,
+    trim(CASE WHEN line LIKE '[ ]%' OR line LIKE '[x]%' THEN substr(line, 4) ELSE line END),
+    CASE WHEN line LIKE '[x]%' THEN 'done' ELSE 'open' END,
+    position,
+    updated_at,
+    updated_at
+FROM lines
+WHERE position >= 0
+  AND trim(CASE WHEN line LIKE '[ ]%' OR line LIKE '[x]%' THEN substr(line, 4) ELSE line END) <> '';
@@ -0,0 +1,32 @@
+ALTER TABLE conversations ADD COLUMN meeting_id TEXT REFERENCES meetings(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_conversations_meeting ON conversations(meeting_id, updated_at);
+
+-- External-content index keyed by the implicit rowid of meeting_segments.
+-- VACUUM may renumber those rowids, so rebuild the index after vacuuming.
+CREATE VIRTUAL TABLE IF NOT EXISTS meeting_segments_fts USING fts5(
+    text,
+    speaker_name,
+    content = 'meeting_segments',
+    content_rowid = 'rowid',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS meeting_segments_fts_insert AFTER INSERT ON meeting_segments BEGIN
+    INSERT INTO meeting_segments_fts (rowid, text, speaker_name)
+    VALUES (new.rowid, new.text, new.speaker_name);
+END;
+
+CREATE TRIGGER IF NOT EXISTS meeting_segments_fts_delete AFTER DELETE ON meeting_segments BEGIN
+    INSERT INTO meeting_segments_fts (meeting_segments_fts, rowid, text, speaker_name)
+    VALUES ('delete', old.rowid, old.text, old.speaker_name);
+END;
+
+CREATE TRIGGER IF NOT EXISTS meeting_segments_fts_update AFTER UPDATE OF text, speaker_name ON meeting_segments BEGIN
+    INSERT INTO meeting_segments_fts (meeting_segments_fts, rowid, text, speaker_name)
+    VALUES ('delete', old.rowid, old.text, old.speaker_name);
+    INSERT INTO meeting_segments_fts (rowid, text, speaker_name)
+    VALUES (new.rowid, new.text, new.speaker_name);
+END;
+
+INSERT INTO meeting_segments_fts (meeting_segments_fts) VALUES ('rebuild');
@@ -0,0 +1,40 @@
+-- Messages form a tree: editing or regenerating a message adds a sibling under
+-- the same parent, and the conversation remembers which leaf is active.
+ALTER TABLE messages ADD COLUMN parent_id TEXT REFERENCES messages(id) ON DELETE CASCADE;
+
+ALTER TABLE conversations ADD COLUMN head_message_id TEXT REFERENCES messages(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_messages_parent ON messages(parent_id);
+
+-- Existing conversations are linear; chain each message to the one inserted before it.
+UPDATE messages
+SET parent_id = (
+    SELECT previous.id
+    FROM messages AS previous
+    WHERE previous.conversation_id = messages.conversation_id
+      AND previous.rowid < messages.rowid
+    ORDER BY previous.rowid DESC
+    LIMIT 1
+);
+
+UPDATE conversations
+SET head_message_id = (
+    SELECT id FROM messages
+    WHERE messages.conversation_id = conversations.id
+    ORDER BY rowid DESC
+    LIMIT 1
+);
+
+CREATE TABLE IF NOT EXISTS conversation_summaries (
+    id TEXT PRIMARY KEY NOT NULL,
+    conversation_id TEXT NOT NULL,
+    through_message_id TEXT NOT NULL,
+    content TEXT NOT NULL,
+    token_count INTEGER NOT NULL,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE,
+    FOREIGN KEY (through_message_id) REFERENCES messages(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_conversation_summaries_message ON conversation_summaries(through_message_id, created_at);
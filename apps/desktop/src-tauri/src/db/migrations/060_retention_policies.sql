@@ -0,0 +1,21 @@
+ALTER TABLE transcriptions ADD COLUMN starred INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE meetings ADD COLUMN starred INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE conversations ADD COLUMN starred INTEGER NOT NULL DEFAULT 0;
+
+-- One rule set per kind of record. `action` is 'audio' to delete recordings
+-- but keep the text, or 'all' to delete the record itself.
+CREATE TABLE IF NOT EXISTS retention_policies (
+    target TEXT PRIMARY KEY NOT NULL CHECK (target IN ('transcription', 'meeting', 'conversation')),
+    enabled INTEGER NOT NULL DEFAULT 0,
+    max_age_days INTEGER,
+    max_audio_bytes INTEGER,
+    keep_starred INTEGER NOT NULL DEFAULT 1,
+    action TEXT NOT NULL DEFAULT 'audio' CHECK (action IN ('audio', 'all')),
+    last_run_at TEXT,
+    updated_at TEXT NOT NULL
+);
+
+INSERT OR IGNORE INTO retention_policies (target, action, updated_at) VALUES
+    ('transcription', 'audio', strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    ('meeting', 'audio', strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    ('conversation', 'all', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'));
@@ -0,0 +1,6 @@
+-- The app a transcription was dictated into, for per-app usage analytics.
+-- Not a foreign key: app targets are keyed by a normalized name and the
+-- history should outlive them.
+ALTER TABLE transcriptions ADD COLUMN app_target_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_transcriptions_app_target ON transcriptions(app_target_id);
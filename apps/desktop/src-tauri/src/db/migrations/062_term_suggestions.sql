@@ -0,0 +1,22 @@
+-- Substitutions mined from user edits to transcripts, offered as new
+-- replacement terms. `source_key` is the lowercased source so casing
+-- variants of the same mishearing are grouped.
+CREATE TABLE IF NOT EXISTS term_suggestions (
+    id TEXT PRIMARY KEY NOT NULL,
+    source_value TEXT NOT NULL,
+    source_key TEXT NOT NULL,
+    destination_value TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'accepted', 'rejected')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE (source_key, destination_value)
+);
+
+-- One row per transcription a substitution was seen in, so editing the same
+-- transcript twice does not inflate the count.
+CREATE TABLE IF NOT EXISTS term_suggestion_sources (
+    suggestion_id TEXT NOT NULL REFERENCES term_suggestions(id) ON DELETE CASCADE,
+    transcription_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (suggestion_id, transcription_id)
+);
@@ -0,0 +1,10 @@
+-- Language dictation into this app is translated to. NULL inserts the
+-- transcript in the spoken language.
+ALTER TABLE app_targets ADD COLUMN translation_language TEXT;
+
+-- `source_language` is the language dictation was configured for (NULL when
+-- auto-detected), `detected_language` what the model heard and
+-- `target_language` what the transcript was translated to.
+ALTER TABLE transcriptions ADD COLUMN source_language TEXT;
+ALTER TABLE transcriptions ADD COLUMN detected_language TEXT;
+ALTER TABLE transcriptions ADD COLUMN target_language TEXT;
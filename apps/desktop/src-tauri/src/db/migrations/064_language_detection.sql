@@ -0,0 +1,7 @@
+-- Detect the dictation language among the user's configured languages
+-- instead of using the active one.
+ALTER TABLE user_preferences ADD COLUMN language_detection_enabled INTEGER NOT NULL DEFAULT 0;
+
+-- Language used when detection is not confident. Written by the transcriber,
+-- not by the preferences form.
+ALTER TABLE user_preferences ADD COLUMN last_dictation_language TEXT;
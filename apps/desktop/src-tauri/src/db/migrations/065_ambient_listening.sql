@@ -0,0 +1,3 @@
+-- Phrase that starts dictation while ambient listening is armed. Arming
+-- itself is per session and never persisted.
+ALTER TABLE user_preferences ADD COLUMN ambient_wake_phrase TEXT;
@@ -0,0 +1,3 @@
+-- Milliseconds of audio kept from before recording starts while the
+-- microphone is held open between recordings. NULL keeps it closed.
+ALTER TABLE user_preferences ADD COLUMN warm_microphone_ms INTEGER;
@@ -0,0 +1,15 @@
+-- Latest calibration measurements per input device, used to rank devices
+-- by measured quality rather than by their names.
+CREATE TABLE IF NOT EXISTS microphone_calibrations (
+    device_name TEXT PRIMARY KEY,
+    noise_floor_db REAL NOT NULL,
+    speech_level_db REAL NOT NULL,
+    snr_db REAL NOT NULL,
+    clipping_ratio REAL NOT NULL,
+    dc_offset REAL NOT NULL,
+    score REAL NOT NULL,
+    recommended_gain REAL NOT NULL,
+    measured_at TEXT NOT NULL
+);
+
+ALTER TABLE user_preferences ADD COLUMN input_gain REAL;
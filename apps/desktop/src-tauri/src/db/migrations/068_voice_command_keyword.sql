@@ -0,0 +1,3 @@
+-- Whether voice commands only run after the spoken keyword "command".
+-- Off by default, so bare phrases like "new line" are commands.
+ALTER TABLE user_preferences ADD COLUMN voice_command_keyword_required INTEGER NOT NULL DEFAULT 0;
@@ -1,13 +1,19 @@
+pub mod analytics_queries;
 pub mod api_key_queries;
+pub mod app_profile_queries;
 pub mod app_target_queries;
 pub mod conversation_queries;
 pub mod hotkey_queries;
+pub mod local_api_queries;
 pub mod meeting_queries;
+pub mod microphone_calibration_queries;
 pub mod preferences_queries;
+pub mod retention_queries;
 pub mod term_queries;
 pub mod tone_queries;
 pub mod transcription_queries;
 pub mod user_queries;
+pub mod voice_command_queries;
 
 pub const DB_FILENAME: &str = "osvoice.db";
 pub const DB_CONNECTION: &str = "sqlite:osvoice.db";
@@ -95,6 +101,31 @@ pub const PERFORMANCE_INDEXES_MIGRATION_SQL: &str =
     include_str!("migrations/050_performance_indexes.sql");
 pub const MEETINGS_MIGRATION_SQL: &str = include_str!("migrations/051_meetings.sql");
 pub const MESSAGE_CONTEXT_MIGRATION_SQL: &str = include_str!("migrations/052_message_context.sql");
+pub const VOICE_COMMANDS_MIGRATION_SQL: &str = include_str!("migrations/053_voice_commands.sql");
+pub const APP_TARGET_ITN_MIGRATION_SQL: &str = include_str!("migrations/054_app_target_itn.sql");
+pub const APP_PROFILES_MIGRATION_SQL: &str = include_str!("migrations/055_app_profiles.sql");
+pub const LOCAL_API_MIGRATION_SQL: &str = include_str!("migrations/056_local_api.sql");
+pub const MEETING_ACTION_ITEMS_MIGRATION_SQL: &str =
+    include_str!("migrations/057_meeting_action_items.sql");
+pub const MEETING_QA_MIGRATION_SQL: &str = include_str!("migrations/058_meeting_qa.sql");
+pub const CONVERSATION_BRANCHES_MIGRATION_SQL: &str =
+    include_str!("migrations/059_conversation_branches.sql");
+pub const RETENTION_POLICIES_MIGRATION_SQL: &str =
+    include_str!("migrations/060_retention_policies.sql");
+pub const TRANSCRIPTION_APP_TARGET_MIGRATION_SQL: &str =
+    include_str!("migrations/061_transcription_app_target.sql");
+pub const TERM_SUGGESTIONS_MIGRATION_SQL: &str =
+    include_str!("migrations/062_term_suggestions.sql");
+pub const TRANSLATION_MIGRATION_SQL: &str = include_str!("migrations/063_translation.sql");
+pub const LANGUAGE_DETECTION_MIGRATION_SQL: &str =
+    include_str!("migrations/064_language_detection.sql");
+pub const AMBIENT_LISTENING_MIGRATION_SQL: &str =
+    include_str!("migrations/065_ambient_listening.sql");
+pub const WARM_MICROPHONE_MIGRATION_SQL: &str = include_str!("migrations/066_warm_microphone.sql");
+pub const MICROPHONE_CALIBRATION_MIGRATION_SQL: &str =
+    include_str!("migrations/067_microphone_calibration.sql");
+pub const VOICE_COMMAND_KEYWORD_MIGRATION_SQL: &str =
+    include_str!("migrations/068_voice_command_keyword.sql");
 
 pub fn migrations() -> Vec<tauri_plugin_sql::Migration> {
     vec![
@@ -410,5 +441,170 @@ pub fn migrations() -> Vec<tauri_plugin_sql::Migration> {
             sql: MESSAGE_CONTEXT_MIGRATION_SQL,
             kind: tauri_plugin_sql::MigrationKind::Up,
         },
+        tauri_plugin_sql::Migration {
+            version: 53,
+            description: "create_voice_commands_table",
+            sql: VOICE_COMMANDS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 54,
+            description: "add_app_target_itn_enabled",
+            sql: APP_TARGET_ITN_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 55,
+            description: "create_app_profiles_table",
+            sql: APP_PROFILES_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 56,
+            description: "create_local_api_settings_table",
+            sql: LOCAL_API_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 57,
+            description: "create_meeting_action_items_and_decisions_tables",
+            sql: MEETING_ACTION_ITEMS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 58,
+            description: "add_meeting_segments_fts_and_conversation_meeting",
+            sql: MEETING_QA_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 59,
+            description: "add_message_branches_and_conversation_summaries",
+            sql: CONVERSATION_BRANCHES_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 60,
+            description: "add_starred_flags_and_retention_policies",
+            sql: RETENTION_POLICIES_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 61,
+            description: "add_transcription_app_target",
+            sql: TRANSCRIPTION_APP_TARGET_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 62,
+            description: "create_term_suggestions",
+            sql: TERM_SUGGESTIONS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 63,
+            description: "add_translation_languages",
+            sql: TRANSLATION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 64,
+            description: "add_language_detection",
+            sql: LANGUAGE_DETECTION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 65,
+            description: "add_ambient_wake_phrase",
+            sql: AMBIENT_LISTENING_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 66,
+            description: "add_warm_microphone_ms",
+            sql: WARM_MICROPHONE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 67,
+            description: "add_microphone_calibration",
+            sql: MICROPHONE_CALIBRATION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 68,
+            description: "add_voice_command_keyword",
+            sql: VOICE_COMMAND_KEYWORD_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
     ]
 }
+
+/// The app's migrations in the form sqlx runs them, converted the same way
+/// `tauri_plugin_sql` does so both record identical history.
+#[derive(Debug)]
+struct AppMigrations;
+
+impl<'s> sqlx::migrate::MigrationSource<'s> for AppMigrations {
+    fn resolve(
+        self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<sqlx::migrate::Migration>, sqlx::error::BoxDynError>,
+                > + Send
+                + 's,
+        >,
+    > {
+        Box::pin(async {
+            Ok(migrations()
+                .into_iter()
+                .map(|migration| {
+                    let migration_type = match migration.kind {
+                        tauri_plugin_sql::MigrationKind::Up => {
+                            sqlx::migrate::MigrationType::ReversibleUp
+                        }
+                        tauri_plugin_sql::MigrationKind::Down => {
+                            sqlx::migrate::MigrationType::ReversibleDown
+                        }
+                    };
+                    sqlx::migrate::Migration::new(
+                        migration.version,
+                        migration.description.into(),
+                        migration_type,
+                        migration.sql.into(),
+                        false,
+                    )
+                })
+                .collect())
+        })
+    }
+}
+
+/// Apply any migrations the database has not seen yet, as the app does when
+/// it starts. Used where the database is opened without the app, such as
+/// the command-line mode.
+pub async fn run_pending_migrations(
+    pool: &sqlx::SqlitePool,
+) -> Result<(), sqlx::migrate::MigrateError> {
+    sqlx::migrate::Migrator::new(AppMigrations)
+        .await?
+        .run(pool)
+        .await
+}
+
+/// An in-memory database with every migration applied, for tests.
+#[cfg(test)]
+pub fn open_test_pool() -> sqlx::SqlitePool {
+    tauri::async_runtime::block_on(async {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("open database");
+        run_pending_migrations(&pool)
+            .await
+            .expect("apply migrations");
+        pool
+    })
+}
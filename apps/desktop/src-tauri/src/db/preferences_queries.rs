@@ -28,13 +28,19 @@ pub async fn upsert_user_preferences(
              language_switch_enabled,
              secondary_dictation_language,
              active_dictation_language,
+             language_detection_enabled,
              preferred_microphone,
              ignore_update_dialog,
              incognito_mode_enabled,
              incognito_mode_include_in_stats,
-             dictation_pill_visibility
+             dictation_pill_visibility,
+             ambient_wake_phrase,
+             warm_microphone_ms,
+             input_gain,
+             voice_command_keyword_required
          )
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25,
+                 ?26, ?27, ?28, ?29, ?30)
          ON CONFLICT(user_id) DO UPDATE SET
             transcription_mode = excluded.transcription_mode,
             transcription_api_key_id = excluded.transcription_api_key_id,
@@ -55,11 +61,16 @@ pub async fn upsert_user_preferences(
             language_switch_enabled = excluded.language_switch_enabled,
             secondary_dictation_language = excluded.secondary_dictation_language,
             active_dictation_language = excluded.active_dictation_language,
+            language_detection_enabled = excluded.language_detection_enabled,
             preferred_microphone = excluded.preferred_microphone,
             ignore_update_dialog = excluded.ignore_update_dialog,
             incognito_mode_enabled = excluded.incognito_mode_enabled,
             incognito_mode_include_in_stats = excluded.incognito_mode_include_in_stats,
-            dictation_pill_visibility = excluded.dictation_pill_visibility",
+            dictation_pill_visibility = excluded.dictation_pill_visibility,
+            ambient_wake_phrase = excluded.ambient_wake_phrase,
+            warm_microphone_ms = excluded.warm_microphone_ms,
+            input_gain = excluded.input_gain,
+            voice_command_keyword_required = excluded.voice_command_keyword_required",
     )
     .bind(&preferences.user_id)
     .bind(&preferences.transcription_mode)
@@ -81,11 +92,16 @@ pub async fn upsert_user_preferences(
     .bind(preferences.language_switch_enabled)
     .bind(&preferences.secondary_dictation_language)
     .bind(&preferences.active_dictation_language)
+    .bind(preferences.language_detection_enabled)
     .bind(&preferences.preferred_microphone)
     .bind(preferences.ignore_update_dialog)
     .bind(preferences.incognito_mode_enabled)
     .bind(preferences.incognito_mode_include_in_stats)
     .bind(&preferences.dictation_pill_visibility)
+    .bind(&preferences.ambient_wake_phrase)
+    .bind(preferences.warm_microphone_ms)
+    .bind(preferences.input_gain.map(f64::from))
+    .bind(preferences.voice_command_keyword_required)
     .execute(&pool)
     .await?;
 
@@ -118,11 +134,16 @@ pub async fn fetch_user_preferences(
             language_switch_enabled,
             secondary_dictation_language,
             active_dictation_language,
+            language_detection_enabled,
             preferred_microphone,
             ignore_update_dialog,
             incognito_mode_enabled,
             incognito_mode_include_in_stats,
-            dictation_pill_visibility
+            dictation_pill_visibility,
+            ambient_wake_phrase,
+            warm_microphone_ms,
+            input_gain,
+            voice_command_keyword_required
          FROM user_preferences
          WHERE user_id = ?1
          LIMIT 1",
@@ -193,6 +214,10 @@ pub async fn fetch_user_preferences(
         active_dictation_language: row
             .try_get::<Option<String>, _>("active_dictation_language")
             .unwrap_or(None),
+        language_detection_enabled: row
+            .try_get::<i64, _>("language_detection_enabled")
+            .map(|v| v != 0)
+            .unwrap_or(false),
         preferred_microphone: row
             .try_get::<Option<String>, _>("preferred_microphone")
             .unwrap_or(None),
@@ -211,6 +236,21 @@ pub async fn fetch_user_preferences(
         dictation_pill_visibility: row
             .try_get::<String, _>("dictation_pill_visibility")
             .unwrap_or_else(|_| "while_active".to_string()),
+        ambient_wake_phrase: row
+            .try_get::<Option<String>, _>("ambient_wake_phrase")
+            .unwrap_or(None),
+        warm_microphone_ms: row
+            .try_get::<Option<i64>, _>("warm_microphone_ms")
+            .unwrap_or(None)
+            .and_then(|ms| u32::try_from(ms).ok()),
+        input_gain: row
+            .try_get::<Option<f64>, _>("input_gain")
+            .unwrap_or(None)
+            .map(|gain| gain as f32),
+        voice_command_keyword_required: row
+            .try_get::<i64, _>("voice_command_keyword_required")
+            .map(|v| v != 0)
+            .unwrap_or(false),
     });
 
     Ok(preferences)
@@ -241,3 +281,29 @@ pub async fn fetch_transcription_mode(pool: SqlitePool) -> Result<Option<String>
 
     Ok(row.flatten())
 }
+
+pub async fn fetch_last_dictation_language(
+    pool: SqlitePool,
+) -> Result<Option<String>, sqlx::Error> {
+    let row: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT last_dictation_language FROM user_preferences WHERE user_id = ?1 LIMIT 1",
+    )
+    .bind(LOCAL_USER_ID)
+    .fetch_optional(&pool)
+    .await?;
+
+    Ok(row.flatten())
+}
+
+pub async fn update_last_dictation_language(
+    pool: SqlitePool,
+    language: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE user_preferences SET last_dictation_language = ?2 WHERE user_id = ?1")
+        .bind(LOCAL_USER_ID)
+        .bind(language)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
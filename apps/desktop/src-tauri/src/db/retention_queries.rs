@@ -0,0 +1,212 @@
+use chrono::{SecondsFormat, TimeZone, Utc};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+
+use crate::domain::{RetentionAction, RetentionPolicy, RetentionRecord, RetentionTarget};
+
+fn row_to_policy(row: SqliteRow) -> Result<Option<RetentionPolicy>, sqlx::Error> {
+    let target = row.get::<String, _>("target");
+    let action = row.get::<String, _>("action");
+    let (Some(target), Some(action)) = (
+        RetentionTarget::parse(&target),
+        RetentionAction::parse(&action),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(RetentionPolicy {
+        target,
+        enabled: row.get::<bool, _>("enabled"),
+        max_age_days: row
+            .try_get::<Option<i64>, _>("max_age_days")?
+            .and_then(|days| u32::try_from(days).ok()),
+        max_audio_bytes: row
+            .try_get::<Option<i64>, _>("max_audio_bytes")?
+            .and_then(|bytes| u64::try_from(bytes).ok()),
+        keep_starred: row.get::<bool, _>("keep_starred"),
+        action,
+        last_run_at: row.try_get::<Option<String>, _>("last_run_at")?,
+    }))
+}
+
+pub async fn fetch_retention_policies(
+    pool: SqlitePool,
+) -> Result<Vec<RetentionPolicy>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT target, enabled, max_age_days, max_audio_bytes, keep_starred, action, last_run_at
+         FROM retention_policies",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let mut policies = Vec::with_capacity(rows.len());
+    for row in rows {
+        if let Some(policy) = row_to_policy(row)? {
+            policies.push(policy);
+        }
+    }
+    policies.sort_by_key(|policy| {
+        RetentionTarget::ALL
+            .iter()
+            .position(|target| *target == policy.target)
+    });
+
+    Ok(policies)
+}
+
+pub async fn save_retention_policy(
+    pool: SqlitePool,
+    policy: &RetentionPolicy,
+) -> Result<RetentionPolicy, sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO retention_policies (target, enabled, max_age_days, max_audio_bytes, keep_starred, action, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(target) DO UPDATE SET
+           enabled = excluded.enabled,
+           max_age_days = excluded.max_age_days,
+           max_audio_bytes = excluded.max_audio_bytes,
+           keep_starred = excluded.keep_starred,
+           action = excluded.action,
+           updated_at = excluded.updated_at",
+    )
+    .bind(policy.target.as_str())
+    .bind(policy.enabled)
+    .bind(policy.max_age_days.map(i64::from))
+    .bind(policy.max_audio_bytes.and_then(|bytes| i64::try_from(bytes).ok()))
+    .bind(policy.keep_starred)
+    .bind(policy.action.as_str())
+    .bind(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true))
+    .execute(&pool)
+    .await?;
+
+    Ok(policy.clone())
+}
+
+pub async fn mark_retention_run(
+    pool: SqlitePool,
+    target: RetentionTarget,
+    ran_at: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE retention_policies SET last_run_at = ?2 WHERE target = ?1")
+        .bind(target.as_str())
+        .bind(ran_at)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Every record of a kind, newest first. Audio sizes are left at zero for
+/// the caller to fill from disk. Meetings still recording are never listed.
+pub async fn fetch_retention_records(
+    pool: SqlitePool,
+    target: RetentionTarget,
+) -> Result<Vec<RetentionRecord>, sqlx::Error> {
+    let sql = match target {
+        RetentionTarget::Transcription => {
+            "SELECT id, substr(transcript, 1, 80) AS label, timestamp, NULL AS created_at, starred, audio_path
+             FROM transcriptions
+             ORDER BY timestamp DESC"
+        }
+        RetentionTarget::Meeting => {
+            "SELECT id, title AS label, NULL AS timestamp, started_at AS created_at, starred, audio_path
+             FROM meetings
+             WHERE status != 'recording'
+             ORDER BY started_at DESC"
+        }
+        RetentionTarget::Conversation => {
+            "SELECT id, title AS label, NULL AS timestamp, updated_at AS created_at, starred, NULL AS audio_path
+             FROM conversations
+             ORDER BY updated_at DESC"
+        }
+    };
+    let rows = sqlx::query(sql).fetch_all(&pool).await?;
+
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        let created_at = match row.try_get::<Option<i64>, _>("timestamp")? {
+            Some(millis) => Utc
+                .timestamp_millis_opt(millis)
+                .single()
+                .map(|at| at.to_rfc3339_opts(SecondsFormat::Millis, true))
+                .unwrap_or_default(),
+            None => row
+                .try_get::<Option<String>, _>("created_at")?
+                .unwrap_or_default(),
+        };
+        records.push(RetentionRecord {
+            id: row.get::<String, _>("id"),
+            label: row.get::<String, _>("label"),
+            created_at,
+            starred: row.get::<bool, _>("starred"),
+            audio_path: row.try_get::<Option<String>, _>("audio_path")?,
+            audio_bytes: 0,
+        });
+    }
+
+    Ok(records)
+}
+
+/// Forgets the recordings of the given records once their files are gone.
+/// A transcription keeps `audio_duration_ms`: it describes what was said, not
+/// the file, and usage analytics read speaking time and pace from it.
+pub async fn clear_retention_audio(
+    pool: SqlitePool,
+    target: RetentionTarget,
+    ids: &[String],
+) -> Result<(), sqlx::Error> {
+    let sql = match target {
+        RetentionTarget::Transcription => {
+            "UPDATE transcriptions SET audio_path = NULL WHERE id = ?1"
+        }
+        RetentionTarget::Meeting => "UPDATE meetings SET audio_path = NULL WHERE id = ?1",
+        RetentionTarget::Conversation => return Ok(()),
+    };
+
+    let mut tx = pool.begin().await?;
+    for id in ids {
+        sqlx::query(sql).bind(id).execute(&mut *tx).await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+pub async fn delete_retention_records(
+    pool: SqlitePool,
+    target: RetentionTarget,
+    ids: &[String],
+) -> Result<(), sqlx::Error> {
+    let sql = match target {
+        RetentionTarget::Transcription => "DELETE FROM transcriptions WHERE id = ?1",
+        RetentionTarget::Meeting => "DELETE FROM meetings WHERE id = ?1",
+        RetentionTarget::Conversation => "DELETE FROM conversations WHERE id = ?1",
+    };
+
+    let mut tx = pool.begin().await?;
+    for id in ids {
+        sqlx::query(sql).bind(id).execute(&mut *tx).await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+pub async fn set_starred(
+    pool: SqlitePool,
+    target: RetentionTarget,
+    id: &str,
+    starred: bool,
+) -> Result<(), sqlx::Error> {
+    let sql = match target {
+        RetentionTarget::Transcription => "UPDATE transcriptions SET starred = ?2 WHERE id = ?1",
+        RetentionTarget::Meeting => "UPDATE meetings SET starred = ?2 WHERE id = ?1",
+        RetentionTarget::Conversation => "UPDATE conversations SET starred = ?2 WHERE id = ?1",
+    };
+    sqlx::query(sql)
+        .bind(id)
+        .bind(starred)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
+use chrono::{SecondsFormat, Utc};
+use sqlx::query::Query;
+use sqlx::sqlite::{Sqlite, SqliteArguments};
 use sqlx::{Row, SqlitePool};
 
-use crate::domain::Term;
+use crate::domain::{Term, TermSuggestion, TermSuggestionStatus};
 
-pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Error> {
+fn insert_term_query(term: &Term) -> Query<'_, Sqlite, SqliteArguments<'_>> {
     sqlx::query(
         "INSERT INTO terms (id, created_at, created_by_user_id, source_value, destination_value, is_replacement, is_deleted)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
@@ -14,8 +17,10 @@ pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Er
     .bind(&term.destination_value)
     .bind(term.is_replacement as i64)
     .bind(term.is_deleted as i64)
-    .execute(&pool)
-    .await?;
+}
+
+pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Error> {
+    insert_term_query(term).execute(&pool).await?;
 
     Ok(term.clone())
 }
@@ -78,3 +83,141 @@ pub async fn delete_term(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error>
 
     Ok(())
 }
+
+/// Records that the user replaced `source_value` with `destination_value`
+/// while editing a transcription. Repeat edits of one transcription count once.
+pub async fn record_term_correction(
+    pool: SqlitePool,
+    transcription_id: &str,
+    source_value: &str,
+    destination_value: &str,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let source_key = source_value.to_lowercase();
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO term_suggestions (id, source_value, source_key, destination_value, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+         ON CONFLICT (source_key, destination_value) DO UPDATE SET updated_at = excluded.updated_at",
+    )
+    .bind(crate::system::ids::new_id())
+    .bind(source_value)
+    .bind(&source_key)
+    .bind(destination_value)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT OR IGNORE INTO term_suggestion_sources (suggestion_id, transcription_id, created_at)
+         SELECT id, ?3, ?4
+         FROM term_suggestions
+         WHERE source_key = ?1 AND destination_value = ?2",
+    )
+    .bind(&source_key)
+    .bind(destination_value)
+    .bind(transcription_id)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+pub async fn fetch_term_suggestions(pool: SqlitePool) -> Result<Vec<TermSuggestion>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT suggestion.id,
+                suggestion.source_value,
+                suggestion.destination_value,
+                suggestion.status,
+                suggestion.created_at,
+                suggestion.updated_at,
+                (SELECT COUNT(*) FROM term_suggestion_sources
+                 WHERE suggestion_id = suggestion.id) AS occurrences,
+                (SELECT COUNT(*) FROM term_suggestion_sources
+                 JOIN term_suggestions AS sibling ON sibling.id = term_suggestion_sources.suggestion_id
+                 WHERE sibling.source_key = suggestion.source_key) AS source_occurrences
+         FROM term_suggestions AS suggestion
+         ORDER BY occurrences DESC, suggestion.updated_at DESC",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let suggestions = rows
+        .into_iter()
+        .map(|row| {
+            let occurrences = row.get::<i64, _>("occurrences");
+            let source_occurrences = row.get::<i64, _>("source_occurrences");
+            TermSuggestion {
+                id: row.get::<String, _>("id"),
+                source_value: row.get::<String, _>("source_value"),
+                destination_value: row.get::<String, _>("destination_value"),
+                status: TermSuggestionStatus::parse(&row.get::<String, _>("status"))
+                    .unwrap_or(TermSuggestionStatus::Pending),
+                occurrences,
+                confidence: if source_occurrences > 0 {
+                    occurrences as f64 / source_occurrences as f64
+                } else {
+                    0.0
+                },
+                created_at: row.get::<String, _>("created_at"),
+                updated_at: row.get::<String, _>("updated_at"),
+            }
+        })
+        .collect();
+
+    Ok(suggestions)
+}
+
+/// Marks a suggestion accepted and, when given, adds its term in the same
+/// transaction. Returns false without changes if the suggestion is unknown.
+pub async fn accept_term_suggestion(
+    pool: SqlitePool,
+    id: &str,
+    term: Option<&Term>,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        "UPDATE term_suggestions
+         SET status = ?2,
+             updated_at = ?3
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .bind(TermSuggestionStatus::Accepted.as_str())
+    .bind(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true))
+    .execute(&mut *tx)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    if let Some(term) = term {
+        insert_term_query(term).execute(&mut *tx).await?;
+    }
+
+    tx.commit().await?;
+    Ok(true)
+}
+
+pub async fn update_term_suggestion_status(
+    pool: SqlitePool,
+    id: &str,
+    status: TermSuggestionStatus,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE term_suggestions
+         SET status = ?2,
+             updated_at = ?3
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .bind(status.as_str())
+    .bind(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true))
+    .execute(&pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -44,6 +44,11 @@ fn row_to_transcription(row: SqliteRow) -> Result<Transcription, sqlx::Error> {
         transcription_duration_ms: row.try_get::<Option<i64>, _>("transcription_duration_ms")?,
         postprocess_duration_ms: row.try_get::<Option<i64>, _>("postprocess_duration_ms")?,
         warnings,
+        starred: row.try_get::<bool, _>("starred")?,
+        app_target_id: row.try_get::<Option<String>, _>("app_target_id")?,
+        source_language: row.try_get::<Option<String>, _>("source_language")?,
+        detected_language: row.try_get::<Option<String>, _>("detected_language")?,
+        target_language: row.try_get::<Option<String>, _>("target_language")?,
     })
 }
 
@@ -71,9 +76,14 @@ pub async fn insert_transcription(
              post_process_device,
              transcription_duration_ms,
              postprocess_duration_ms,
-             warnings_json
+             warnings_json,
+             app_target_id,
+             source_language,
+             detected_language,
+             target_language
          )
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20,
+                 ?21, ?22, ?23)",
     )
     .bind(&transcription.id)
     .bind(&transcription.transcript)
@@ -99,6 +109,10 @@ pub async fn insert_transcription(
     .bind(transcription.transcription_duration_ms)
     .bind(transcription.postprocess_duration_ms)
     .bind(serialize_warnings(&transcription.warnings))
+    .bind(transcription.app_target_id.as_deref())
+    .bind(transcription.source_language.as_deref())
+    .bind(transcription.detected_language.as_deref())
+    .bind(transcription.target_language.as_deref())
     .execute(&pool)
     .await?;
 
@@ -129,7 +143,12 @@ pub async fn fetch_transcriptions(
                 post_process_device,
                 transcription_duration_ms,
                 postprocess_duration_ms,
-                warnings_json
+                warnings_json,
+                starred,
+                app_target_id,
+                source_language,
+                detected_language,
+                target_language
          FROM transcriptions
          ORDER BY timestamp DESC
          LIMIT ?1 OFFSET ?2",
@@ -148,6 +167,94 @@ pub async fn fetch_transcriptions(
     Ok(transcriptions)
 }
 
+pub async fn fetch_transcription(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<Transcription>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id,
+                transcript,
+                timestamp,
+                audio_path,
+                audio_duration_ms,
+                model_size,
+                inference_device,
+                raw_transcript,
+                sanitized_transcript,
+                transcription_prompt,
+                post_process_prompt,
+                transcription_api_key_id,
+                post_process_api_key_id,
+                transcription_mode,
+                post_process_mode,
+                post_process_device,
+                transcription_duration_ms,
+                postprocess_duration_ms,
+                warnings_json,
+                starred,
+                app_target_id,
+                source_language,
+                detected_language,
+                target_language
+         FROM transcriptions
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(row_to_transcription).transpose()
+}
+
+/// Case-insensitive substring search over final and raw transcripts.
+pub async fn search_transcriptions(
+    pool: SqlitePool,
+    query: &str,
+    limit: u32,
+) -> Result<Vec<Transcription>, sqlx::Error> {
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    let pattern = format!("%{escaped}%");
+    let rows = sqlx::query(
+        "SELECT id,
+                transcript,
+                timestamp,
+                audio_path,
+                audio_duration_ms,
+                model_size,
+                inference_device,
+                raw_transcript,
+                sanitized_transcript,
+                transcription_prompt,
+                post_process_prompt,
+                transcription_api_key_id,
+                post_process_api_key_id,
+                transcription_mode,
+                post_process_mode,
+                post_process_device,
+                transcription_duration_ms,
+                postprocess_duration_ms,
+                warnings_json,
+                starred,
+                app_target_id,
+                source_language,
+                detected_language,
+                target_language
+         FROM transcriptions
+         WHERE transcript LIKE ?1 ESCAPE '\\' OR raw_transcript LIKE ?1 ESCAPE '\\'
+         ORDER BY timestamp DESC
+         LIMIT ?2",
+    )
+    .bind(pattern)
+    .bind(limit as i64)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter().map(row_to_transcription).collect()
+}
+
 pub async fn update_transcription(
     pool: SqlitePool,
     transcription: &Transcription,
@@ -220,7 +327,12 @@ pub async fn update_transcription(
                 post_process_device,
                 transcription_duration_ms,
                 postprocess_duration_ms,
-                warnings_json
+                warnings_json,
+                starred,
+                app_target_id,
+                source_language,
+                detected_language,
+                target_language
          FROM transcriptions
          WHERE id = ?1",
     )
@@ -0,0 +1,86 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::VoiceCommand;
+
+pub async fn insert_voice_command(
+    pool: SqlitePool,
+    command: &VoiceCommand,
+) -> Result<VoiceCommand, sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO voice_commands (id, created_at, created_by_user_id, phrase, action_kind, action_value, is_deleted)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )
+    .bind(&command.id)
+    .bind(command.created_at)
+    .bind(&command.created_by_user_id)
+    .bind(&command.phrase)
+    .bind(&command.action_kind)
+    .bind(&command.action_value)
+    .bind(command.is_deleted as i64)
+    .execute(&pool)
+    .await?;
+
+    Ok(command.clone())
+}
+
+pub async fn fetch_voice_commands(pool: SqlitePool) -> Result<Vec<VoiceCommand>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, created_at, created_by_user_id, phrase, action_kind, action_value, is_deleted
+         FROM voice_commands
+         WHERE is_deleted = 0
+         ORDER BY created_at DESC",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    let commands = rows
+        .into_iter()
+        .map(|row| VoiceCommand {
+            id: row.get::<String, _>("id"),
+            created_at: row.get::<i64, _>("created_at"),
+            created_by_user_id: row.get::<String, _>("created_by_user_id"),
+            phrase: row.get::<String, _>("phrase"),
+            action_kind: row.get::<String, _>("action_kind"),
+            action_value: row.get::<String, _>("action_value"),
+            is_deleted: row.get::<i64, _>("is_deleted") != 0,
+        })
+        .collect();
+
+    Ok(commands)
+}
+
+pub async fn update_voice_command(
+    pool: SqlitePool,
+    command: &VoiceCommand,
+) -> Result<VoiceCommand, sqlx::Error> {
+    sqlx::query(
+        "UPDATE voice_commands
+         SET phrase = ?2,
+             action_kind = ?3,
+             action_value = ?4,
+             is_deleted = ?5
+         WHERE id = ?1",
+    )
+    .bind(&command.id)
+    .bind(&command.phrase)
+    .bind(&command.action_kind)
+    .bind(&command.action_value)
+    .bind(command.is_deleted as i64)
+    .execute(&pool)
+    .await?;
+
+    Ok(command.clone())
+}
+
+pub async fn delete_voice_command(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE voice_commands
+         SET is_deleted = 1
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .execute(&pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+/// Emitted to every window whenever ambient listening is armed or disarmed.
+pub const EVT_AMBIENT_STATUS: &str = "ambient_status";
+/// The wake phrase was heard; dictation should start.
+pub const EVT_AMBIENT_WAKE: &str = "ambient_wake";
+/// The speaker went quiet after a wake-phrase dictation started.
+pub const EVT_AMBIENT_SPEECH_ENDED: &str = "ambient_speech_ended";
+
+pub const DEFAULT_WAKE_PHRASE: &str = "hey os voice";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmbientStatus {
+    pub armed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wake_phrase: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmbientWakePayload {
+    pub wake_phrase: String,
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAnalyticsRequest {
+    /// Inclusive lower bound, in milliseconds since the epoch.
+    #[serde(default)]
+    pub from_ms: Option<i64>,
+    /// Exclusive upper bound, in milliseconds since the epoch.
+    #[serde(default)]
+    pub to_ms: Option<i64>,
+    /// Offset of the user's time zone from UTC, used to bucket days and weeks.
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+}
+
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTotals {
+    pub transcriptions: i64,
+    pub words: i64,
+    /// Recorded speech, counted only for transcriptions that kept a duration.
+    pub speaking_ms: i64,
+    pub average_wpm: Option<f64>,
+    /// Typing the same words at `typing_wpm` minus the time spent speaking.
+    pub time_saved_ms: i64,
+    pub typing_wpm: u32,
+    pub warning_rate: f64,
+}
+
+/// Words dictated in one day or week, starting on `period` (`YYYY-MM-DD`).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsagePeriod {
+    pub period: String,
+    pub transcriptions: i64,
+    pub words: i64,
+    pub speaking_ms: i64,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WpmBucket {
+    pub min_wpm: u32,
+    /// `None` for the open-ended top bucket.
+    pub max_wpm: Option<u32>,
+    pub transcriptions: i64,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AppUsage {
+    /// `None` groups transcriptions made before apps were recorded.
+    pub app_target_id: Option<String>,
+    pub name: Option<String>,
+    pub transcriptions: i64,
+    pub words: i64,
+    pub speaking_ms: i64,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LatencyStage {
+    Transcription,
+    PostProcessing,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelLatency {
+    pub stage: LatencyStage,
+    pub mode: Option<String>,
+    /// Model size for transcription, device for post-processing.
+    pub model: Option<String>,
+    pub transcriptions: i64,
+    pub average_ms: Option<f64>,
+    pub max_ms: Option<i64>,
+    pub warning_rate: f64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAnalytics {
+    pub totals: UsageTotals,
+    pub words_by_day: Vec<UsagePeriod>,
+    pub words_by_week: Vec<UsagePeriod>,
+    pub wpm_distribution: Vec<WpmBucket>,
+    pub apps: Vec<AppUsage>,
+    pub models: Vec<ModelLatency>,
+}
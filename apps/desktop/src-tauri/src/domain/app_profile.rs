@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+pub const INSERTION_STRATEGY_PASTE: &str = "paste";
+pub const INSERTION_STRATEGY_TYPE: &str = "type";
+
+/// Dictation overrides for one application, optionally narrowed to windows
+/// whose title matches a pattern. Unset fields inherit the app target and
+/// global preferences.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppProfile {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub created_at: String,
+    /// Bundle identifier on macOS, executable path or name elsewhere.
+    #[serde(default)]
+    pub app_identifier: Option<String>,
+    /// Display-name based target, used when the identifier is unknown.
+    #[serde(default)]
+    pub app_target_id: Option<String>,
+    /// Regular expression matched against the focused window title.
+    #[serde(default)]
+    pub window_title_pattern: Option<String>,
+    #[serde(default)]
+    pub priority: i64,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub model_size: Option<String>,
+    #[serde(default)]
+    pub insertion_strategy: Option<String>,
+    /// Dictionary terms to use; `None` keeps the whole dictionary.
+    #[serde(default)]
+    pub term_ids: Option<Vec<String>>,
+    #[serde(default)]
+    pub itn_enabled: Option<bool>,
+    #[serde(default)]
+    pub auto_capitalize: Option<bool>,
+    #[serde(default)]
+    pub trailing_punctuation: Option<bool>,
+}
+
+/// Effective dictation settings for the focused window.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedAppProfile {
+    pub profile_id: Option<String>,
+    pub app_target_id: Option<String>,
+    pub app_name: String,
+    pub app_identifier: Option<String>,
+    pub window_title: Option<String>,
+    pub tone_id: Option<String>,
+    pub paste_keybind: Option<String>,
+    pub language: Option<String>,
+    pub model_size: Option<String>,
+    pub insertion_strategy: String,
+    pub term_ids: Option<Vec<String>>,
+    pub itn_enabled: bool,
+    /// `None` leaves the transcript's first letter as transcribed.
+    pub auto_capitalize: Option<bool>,
+    /// `None` leaves trailing punctuation as transcribed.
+    pub trailing_punctuation: Option<bool>,
+    /// Language dictation is translated to; `None` keeps the spoken language.
+    pub translation_language: Option<String>,
+}
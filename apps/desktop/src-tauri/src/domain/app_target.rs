@@ -12,4 +12,16 @@ pub struct AppTarget {
     pub icon_path: Option<String>,
     #[serde(default)]
     pub paste_keybind: Option<String>,
+    /// Whether spoken numbers, dates and the like are rewritten in written
+    /// form when dictating into this app.
+    #[serde(default = "default_itn_enabled")]
+    pub itn_enabled: bool,
+    /// Language code dictation into this app is translated to. English uses
+    /// Whisper's built-in translation; other languages an LLM pass.
+    #[serde(default)]
+    pub translation_language: Option<String>,
+}
+
+fn default_itn_enabled() -> bool {
+    true
 }
@@ -5,6 +5,14 @@ use serde::{Deserialize, Serialize};
 pub struct Conversation {
     pub id: String,
     pub title: String,
+    /// Set for conversations that ask questions about a meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_id: Option<String>,
+    /// The leaf of the branch the user is currently viewing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_message_id: Option<String>,
+    #[serde(default)]
+    pub starred: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -14,6 +22,9 @@ pub struct Conversation {
 pub struct Message {
     pub id: String,
     pub conversation_id: String,
+    /// The message this one replies to; siblings under one parent are branches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
     pub role: String,
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -24,3 +35,85 @@ pub struct Message {
     pub context_json: Option<String>,
     pub created_at: String,
 }
+
+/// A rolling summary of a branch, standing in for every message up to and
+/// including `through_message_id` once they no longer fit the context window.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationSummary {
+    pub id: String,
+    pub conversation_id: String,
+    pub through_message_id: String,
+    pub content: String,
+    pub token_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub created_at: String,
+}
+
+/// A message on the active branch along with the ids of every alternative
+/// at the same position, itself included, oldest first.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchMessage {
+    #[serde(flatten)]
+    pub message: Message,
+    pub sibling_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationContextRequest {
+    pub conversation_id: String,
+    /// Defaults to the conversation's head.
+    pub leaf_message_id: Option<String>,
+    pub model: String,
+    /// Tokens already spent on the system prompt and attachments.
+    #[serde(default)]
+    pub reserved_tokens: u32,
+    /// The model's context window, when the provider's model list reports it.
+    #[serde(default)]
+    pub context_window: Option<u32>,
+    /// Set when the chat runs on Ollama. The backend then asks the server for
+    /// the context window and writes summaries itself.
+    #[serde(default)]
+    pub ollama_api_key_id: Option<String>,
+}
+
+/// The messages to send for a model: the newest messages of the branch that
+/// fit its budget, preceded by a summary of everything older. `compaction`
+/// is set when older messages are not covered by the summary yet; send its
+/// prompt to the model and save the reply with `conversation_summary_create`
+/// to keep the next request small.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ConversationSummary>,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compaction: Option<ConversationCompaction>,
+    pub budget_tokens: u32,
+    pub estimated_tokens: u32,
+}
+
+/// A prompt asking the model to fold the messages up to and including
+/// `through_message_id` into the running summary.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationCompaction {
+    pub through_message_id: String,
+    pub system: String,
+    pub prompt: String,
+}
+
+/// A summary written by the model for a `ConversationCompaction`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationSummaryRequest {
+    pub conversation_id: String,
+    pub through_message_id: String,
+    pub content: String,
+    #[serde(default)]
+    pub model: Option<String>,
+}
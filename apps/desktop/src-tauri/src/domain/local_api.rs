@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use super::OverlayPhase;
+
+pub const DEFAULT_LOCAL_API_PORT: u16 = 47_913;
+
+/// Persisted loopback API settings. The bearer token is stored the same way
+/// as provider API keys: salted hash for checks, ciphertext for display.
+#[derive(Clone, Debug, Default)]
+pub struct LocalApiSettings {
+    pub enabled: bool,
+    pub port: u16,
+    pub token_salt: Option<String>,
+    pub token_hash: Option<String>,
+    pub token_ciphertext: Option<String>,
+    pub token_suffix: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalApiStatus {
+    pub enabled: bool,
+    pub port: u16,
+    pub running: bool,
+    pub url: Option<String>,
+    pub token: Option<String>,
+    pub token_suffix: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalApiConfigureRequest {
+    pub enabled: bool,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub regenerate_token: bool,
+}
+
+/// Messages pushed to WebSocket clients of the local API.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LocalApiEvent {
+    #[serde(rename_all = "camelCase")]
+    RecordingStarted {
+        sample_rate: u32,
+    },
+    RecordingStopped,
+    Level {
+        levels: Vec<f32>,
+    },
+    Phase {
+        phase: OverlayPhase,
+    },
+    Partial {
+        text: String,
+    },
+    Transcript {
+        text: String,
+    },
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+pub const EVT_MCP_PERMISSION_REQUEST: &str = "mcp_permission_request";
+
+/// Sent to the webview when an MCP client calls a tool that records or
+/// types on the user's behalf.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpPermissionRequest {
+    pub request_id: String,
+    pub client_name: String,
+    pub tool: String,
+    pub summary: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum McpPermissionDecision {
+    Deny,
+    AllowOnce,
+    /// Allow this tool for this client until the app restarts.
+    AllowSession,
+}
+
+/// Where a running app accepts MCP connections, written next to the
+/// database so `OSVoice cli mcp` can find it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpEndpoint {
+    pub port: u16,
+    pub token: String,
+}
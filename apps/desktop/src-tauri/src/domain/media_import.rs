@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Meeting, Transcription};
+
+pub const EVT_MEDIA_IMPORT_PROGRESS: &str = "media_import_progress";
+
+/// What an imported audio or video file becomes.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MediaImportTarget {
+    Meeting,
+    Transcription,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MediaImportStage {
+    Decoding,
+    Saving,
+    Transcribing,
+    Done,
+}
+
+/// Emitted on `EVT_MEDIA_IMPORT_PROGRESS` while an import runs. `progress`
+/// covers the whole import, from `0.0` to `1.0`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaImportProgress {
+    pub import_id: String,
+    pub stage: MediaImportStage,
+    pub progress: f32,
+}
+
+/// The record an import created; exactly one field is set.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaImportResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting: Option<Meeting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcription: Option<Transcription>,
+}
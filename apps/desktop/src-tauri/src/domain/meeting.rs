@@ -17,8 +17,11 @@ pub struct Meeting {
     pub audio_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
+    /// Legacy JSON blob, superseded by `meeting_action_items` rows.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_items: Option<String>,
+    #[serde(default)]
+    pub starred: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -37,3 +40,82 @@ pub struct MeetingSegment {
     pub end_ms: i64,
     pub created_at: String,
 }
+
+pub const ACTION_ITEM_STATUS_OPEN: &str = "open";
+pub const ACTION_ITEM_STATUS_DONE: &str = "done";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub task: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Calendar date, `YYYY-MM-DD`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    pub status: String,
+    /// The transcript segment the item was raised in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_segment_id: Option<String>,
+    #[serde(default)]
+    pub position: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl MeetingActionItem {
+    pub fn is_done(&self) -> bool {
+        self.status == ACTION_ITEM_STATUS_DONE
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.task.trim().is_empty() {
+            return Err("Action item task cannot be empty".to_string());
+        }
+        if self.status != ACTION_ITEM_STATUS_OPEN && self.status != ACTION_ITEM_STATUS_DONE {
+            return Err(format!("Unknown action item status '{}'", self.status));
+        }
+        if let Some(due_date) = self.due_date.as_deref() {
+            chrono::NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+                .map_err(|_| format!("Due date '{due_date}' must be YYYY-MM-DD"))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingDecision {
+    pub id: String,
+    pub meeting_id: String,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_segment_id: Option<String>,
+    #[serde(default)]
+    pub position: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl MeetingDecision {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.text.trim().is_empty() {
+            return Err("Decision text cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// An open action item listed across meetings, with the meeting it belongs to.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenMeetingActionItem {
+    #[serde(flatten)]
+    pub item: MeetingActionItem,
+    pub meeting_title: String,
+    pub meeting_started_at: String,
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Conversation, MeetingSegment, Message};
+
+/// A question about one meeting, or about every meeting started in a date
+/// range. At least one of `meeting_id`, `from` and `to` must be set.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingQuestion {
+    pub question: String,
+    #[serde(default)]
+    pub meeting_id: Option<String>,
+    /// Earliest `started_at`, as a `YYYY-MM-DD` date or RFC 3339 timestamp.
+    #[serde(default)]
+    pub from: Option<String>,
+    /// Latest `started_at`. A bare date includes that whole day.
+    #[serde(default)]
+    pub to: Option<String>,
+    /// Continue this conversation instead of the meeting's latest one.
+    #[serde(default)]
+    pub conversation_id: Option<String>,
+    #[serde(default)]
+    pub api_key_id: Option<String>,
+}
+
+/// A transcript segment retrieved as context, with its meeting.
+#[derive(Clone, Debug)]
+pub struct MeetingExcerpt {
+    pub segment: MeetingSegment,
+    pub meeting_title: String,
+    pub meeting_started_at: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingCitation {
+    pub segment_id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speaker_name: Option<String>,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// One turn of a meeting question prompt.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MeetingPromptMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// A meeting question prepared for a provider the webview calls itself.
+/// Send `system` and `messages` to the model, then store its reply with
+/// `meeting_ask_record`, passing `excerpts` back so `[n]` citations resolve.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingQuestionPrompt {
+    pub conversation_id: String,
+    /// The message the question continues from.
+    pub parent_message_id: Option<String>,
+    pub question: String,
+    pub asked_at: String,
+    pub system: String,
+    pub messages: Vec<MeetingPromptMessage>,
+    /// The numbered excerpts in the prompt, `[1]` first.
+    pub excerpts: Vec<MeetingCitation>,
+}
+
+/// The stored question and answer. The answer cites excerpts as `[n]`;
+/// `citations` lists the segments behind them in order of first mention.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingAnswer {
+    pub conversation: Conversation,
+    pub question: Message,
+    pub answer: Message,
+    pub citations: Vec<MeetingCitation>,
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+pub const EVT_MICROPHONE_CALIBRATION_PROGRESS: &str = "microphone_calibration_progress";
+
+/// What a calibration recording showed about one input device.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneCalibration {
+    /// The device's label, as listed by `list_microphones`.
+    pub device_name: String,
+    /// Level of the quietest stretches, in dBFS.
+    pub noise_floor_db: f32,
+    /// Level of the loudest stretches, in dBFS.
+    pub speech_level_db: f32,
+    pub snr_db: f32,
+    /// Fraction of samples at full scale.
+    pub clipping_ratio: f32,
+    pub dc_offset: f32,
+    /// Overall quality from 0 to 100; higher is better.
+    pub score: f32,
+    /// Gain that brings speech to a comfortable level without clipping.
+    pub recommended_gain: f32,
+    pub measured_at: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneCalibrationFailure {
+    pub device_name: String,
+    pub error: String,
+}
+
+/// Emitted on `EVT_MICROPHONE_CALIBRATION_PROGRESS` as each device starts
+/// recording, so the user knows which microphone to speak into.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneCalibrationProgress {
+    pub device_name: String,
+    /// Position of the device in the run, from 1.
+    pub index: usize,
+    pub total: usize,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneCalibrationReport {
+    /// Devices that were measured, best first.
+    pub results: Vec<MicrophoneCalibration>,
+    pub failures: Vec<MicrophoneCalibrationFailure>,
+    pub recommended_device: Option<String>,
+    pub recommended_gain: Option<f32>,
+}
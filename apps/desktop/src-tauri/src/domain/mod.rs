@@ -1,34 +1,97 @@
+pub mod ambient;
+pub mod analytics;
 pub mod api_key;
+pub mod app_profile;
 pub mod app_target;
 pub mod conversation;
 pub mod hotkey;
 pub mod keyboard;
+pub mod local_api;
+pub mod mcp;
+pub mod media_import;
 pub mod meeting;
+pub mod meeting_qa;
+pub mod microphone;
 pub mod monitor;
 pub mod overlay;
 pub mod permissions;
+pub mod post_process;
 pub mod preferences;
 pub mod recording;
+pub mod retention;
 pub mod term;
 pub mod tone;
+pub mod transcript_export;
 pub mod transcription;
 pub mod user;
+pub mod voice_command;
 
+pub use ambient::{
+    AmbientStatus, AmbientWakePayload, DEFAULT_WAKE_PHRASE, EVT_AMBIENT_SPEECH_ENDED,
+    EVT_AMBIENT_STATUS, EVT_AMBIENT_WAKE,
+};
+pub use analytics::{
+    AppUsage, LatencyStage, ModelLatency, UsageAnalytics, UsageAnalyticsRequest, UsagePeriod,
+    UsageTotals, WpmBucket,
+};
 pub use api_key::{ApiKey, ApiKeyCreateRequest, ApiKeyUpdateRequest, ApiKeyView};
+pub use app_profile::{
+    AppProfile, ResolvedAppProfile, INSERTION_STRATEGY_PASTE, INSERTION_STRATEGY_TYPE,
+};
 pub use app_target::{AppTarget, EVT_REGISTER_CURRENT_APP};
-pub use conversation::{Conversation, Message};
-pub use meeting::{Meeting, MeetingSegment};
+pub use conversation::{
+    BranchMessage, Conversation, ConversationCompaction, ConversationContext,
+    ConversationContextRequest, ConversationSummary, ConversationSummaryRequest, Message,
+};
+pub use mcp::{
+    McpEndpoint, McpPermissionDecision, McpPermissionRequest, EVT_MCP_PERMISSION_REQUEST,
+};
+pub use media_import::{
+    MediaImportProgress, MediaImportResult, MediaImportStage, MediaImportTarget,
+    EVT_MEDIA_IMPORT_PROGRESS,
+};
+pub use meeting::{
+    Meeting, MeetingActionItem, MeetingDecision, MeetingSegment, OpenMeetingActionItem,
+    ACTION_ITEM_STATUS_DONE, ACTION_ITEM_STATUS_OPEN,
+};
+pub use meeting_qa::{
+    MeetingAnswer, MeetingCitation, MeetingExcerpt, MeetingPromptMessage, MeetingQuestion,
+    MeetingQuestionPrompt,
+};
+pub use microphone::{
+    MicrophoneCalibration, MicrophoneCalibrationFailure, MicrophoneCalibrationProgress,
+    MicrophoneCalibrationReport, EVT_MICROPHONE_CALIBRATION_PROGRESS,
+};
 pub use hotkey::Hotkey;
 pub use keyboard::{KeysHeldPayload, EVT_KEYS_HELD};
+pub use local_api::{
+    LocalApiConfigureRequest, LocalApiEvent, LocalApiSettings, LocalApiStatus,
+    DEFAULT_LOCAL_API_PORT,
+};
 pub use overlay::{OverlayPhase, OverlayPhasePayload, PillExpandedPayload, EVT_OVERLAY_PHASE, EVT_PILL_EXPANDED};
 pub use permissions::{PermissionKind, PermissionState, PermissionStatus};
+pub use post_process::{
+    PostProcessFinished, PostProcessOutcome, PostProcessPrompt, TextFieldContext,
+    EVT_POST_PROCESS_FINISHED,
+};
 pub use preferences::UserPreferences;
 pub use recording::{
-    AudioChunkPayload, RecordedAudio, RecordingLevelPayload, RecordingMetrics, RecordingResult,
-    EVT_AUDIO_CHUNK, EVT_REC_LEVEL,
+    AudioChunkPayload, InputFailover, RecordedAudio, RecordingLevelPayload, RecordingMetrics,
+    RecordingResult, EVT_AUDIO_CHUNK, EVT_AUDIO_DEVICES_CHANGED, EVT_REC_LEVEL,
+};
+pub use retention::{
+    RetentionAction, RetentionItem, RetentionPolicy, RetentionReason, RetentionRecord,
+    RetentionReport, RetentionTarget,
 };
 pub use monitor::{MonitorAtCursor, OverlayAnchor, ScreenVisibleArea};
-pub use term::Term;
-pub use tone::Tone;
+pub use term::{Term, TermSuggestion, TermSuggestionReview, TermSuggestionStatus};
+pub use tone::{Tone, ToneTemplateContext, ToneTemplateIssue, ToneTemplatePreview};
+pub use transcript_export::{
+    TimestampGranularity, TranscriptExport, TranscriptExportFormat, TranscriptExportOptions,
+};
 pub use transcription::{Transcription, TranscriptionAudioSnapshot};
 pub use user::User;
+pub use voice_command::{
+    DictationStep, EditKey, KeyChord, KeyModifiers, VoiceCommand, VOICE_COMMAND_ACTION_KEYS,
+    VOICE_COMMAND_ACTION_TEXT,
+};
@@ -17,7 +17,7 @@ pub enum OverlayPhase {
     Loading,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OverlayPhasePayload {
     pub phase: OverlayPhase,
 }
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::Transcription;
+
+pub const EVT_POST_PROCESS_FINISHED: &str = "post_process_finished";
+
+/// Text surrounding the cursor in the focused field, used to fit the rewrite
+/// into what is already there.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextFieldContext {
+    #[serde(default)]
+    pub preceding_text: Option<String>,
+    #[serde(default)]
+    pub selected_text: Option<String>,
+    #[serde(default)]
+    pub following_text: Option<String>,
+}
+
+/// Result of a post-processing run. Failures do not surface as errors: the
+/// input transcript is returned and the reason is recorded in `warnings`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessOutcome {
+    pub transcript: String,
+    pub post_process_prompt: Option<String>,
+    pub post_process_mode: String,
+    pub post_process_device: Option<String>,
+    pub postprocess_duration_ms: Option<i64>,
+    /// Language the rewrite was translated into, if it was.
+    pub target_language: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+impl PostProcessOutcome {
+    /// Store the rewrite and its metadata on the transcription it belongs to.
+    pub fn apply_to(&self, transcription: &mut Transcription) {
+        transcription.transcript = self.transcript.clone();
+        transcription.post_process_prompt = self.post_process_prompt.clone();
+        transcription.post_process_mode = Some(self.post_process_mode.clone());
+        transcription.post_process_device = self.post_process_device.clone();
+        transcription.postprocess_duration_ms = self.postprocess_duration_ms;
+        if self.target_language.is_some() {
+            transcription.target_language = self.target_language.clone();
+        }
+        if !self.warnings.is_empty() {
+            transcription
+                .warnings
+                .get_or_insert_with(Vec::new)
+                .extend(self.warnings.iter().cloned());
+        }
+    }
+}
+
+/// Emitted on `EVT_POST_PROCESS_FINISHED` once a backend rewrite is done,
+/// whether or not it succeeded.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessFinished {
+    pub request_id: String,
+    pub outcome: PostProcessOutcome,
+}
+
+/// A rewrite prompt built for a provider the webview talks to directly.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessPrompt {
+    pub prompt: String,
+    /// Language the prompt asks the rewrite to be translated into, if any.
+    pub target_language: Option<String>,
+    pub warnings: Vec<String>,
+}
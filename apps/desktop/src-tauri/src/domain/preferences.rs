@@ -42,6 +42,10 @@ pub struct UserPreferences {
     pub secondary_dictation_language: Option<String>,
     #[serde(default)]
     pub active_dictation_language: Option<String>,
+    /// Pick the dictation language per recording from the primary and
+    /// secondary languages instead of using the active one.
+    #[serde(default)]
+    pub language_detection_enabled: bool,
     #[serde(default)]
     pub preferred_microphone: Option<String>,
     #[serde(default)]
@@ -52,6 +56,20 @@ pub struct UserPreferences {
     pub incognito_mode_include_in_stats: bool,
     #[serde(default = "default_dictation_pill_visibility")]
     pub dictation_pill_visibility: String,
+    /// Phrase that starts dictation while ambient listening is armed.
+    #[serde(default)]
+    pub ambient_wake_phrase: Option<String>,
+    /// How much audio before pressing record to keep the microphone open
+    /// for. `None` closes the microphone between recordings.
+    #[serde(default)]
+    pub warm_microphone_ms: Option<u32>,
+    /// Gain applied to microphone input, usually the one calibration
+    /// recommended. `None` leaves the input as the device delivers it.
+    #[serde(default)]
+    pub input_gain: Option<f32>,
+    /// Only treat a phrase as a voice command when "command" is said first.
+    #[serde(default)]
+    pub voice_command_keyword_required: bool,
 }
 
 fn default_dictation_pill_visibility() -> String {
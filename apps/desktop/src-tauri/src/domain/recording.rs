@@ -3,6 +3,7 @@ use std::time::Duration;
 
 pub const EVT_REC_LEVEL: &str = "recording_level";
 pub const EVT_AUDIO_CHUNK: &str = "audio_chunk";
+pub const EVT_AUDIO_DEVICES_CHANGED: &str = "audio_devices_changed";
 
 #[derive(Clone, Debug)]
 pub struct RecordingMetrics {
@@ -20,6 +21,25 @@ pub struct RecordedAudio {
 pub struct RecordingResult {
     pub metrics: RecordingMetrics,
     pub audio: RecordedAudio,
+    pub failovers: Vec<InputFailover>,
+}
+
+/// A recording moved to another input device after its own failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputFailover {
+    pub from: String,
+    pub to: String,
+    pub reason: String,
+}
+
+impl InputFailover {
+    /// How the switch is reported in the transcript's warnings.
+    pub fn warning(&self) -> String {
+        format!(
+            "Microphone '{}' stopped working ({}); recording continued on '{}'.",
+            self.from, self.reason, self.to
+        )
+    }
 }
 
 #[derive(Clone, Serialize)]
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum RetentionTarget {
+    Transcription,
+    Meeting,
+    Conversation,
+}
+
+impl RetentionTarget {
+    pub const ALL: [Self; 3] = [Self::Transcription, Self::Meeting, Self::Conversation];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Transcription => "transcription",
+            Self::Meeting => "meeting",
+            Self::Conversation => "conversation",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.as_str() == value)
+    }
+
+    /// Conversations have no recordings, so only whole-record rules apply.
+    pub fn has_audio(self) -> bool {
+        !matches!(self, Self::Conversation)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RetentionAction {
+    /// Delete recordings but keep transcripts and summaries.
+    Audio,
+    /// Delete the record along with its recording.
+    All,
+}
+
+impl RetentionAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Audio => "audio",
+            Self::All => "all",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "audio" => Some(Self::Audio),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    pub target: RetentionTarget,
+    pub enabled: bool,
+    /// Records older than this many days are removed.
+    pub max_age_days: Option<u32>,
+    /// Once recordings take more than this, the oldest are removed first.
+    pub max_audio_bytes: Option<u64>,
+    pub keep_starred: bool,
+    pub action: RetentionAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_run_at: Option<String>,
+}
+
+/// A record as seen by the retention planner, newest first.
+#[derive(Clone, Debug)]
+pub struct RetentionRecord {
+    pub id: String,
+    pub label: String,
+    pub created_at: String,
+    pub starred: bool,
+    pub audio_path: Option<String>,
+    pub audio_bytes: u64,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RetentionReason {
+    Age,
+    Storage,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionItem {
+    pub target: RetentionTarget,
+    pub id: String,
+    pub label: String,
+    pub created_at: String,
+    pub reason: RetentionReason,
+    pub action: RetentionAction,
+    pub audio_bytes: u64,
+}
+
+/// What a retention run removed, or would remove when `dry_run` is set.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionReport {
+    pub dry_run: bool,
+    pub generated_at: String,
+    pub items: Vec<RetentionItem>,
+    pub audio_files: u32,
+    pub audio_bytes: u64,
+    pub records_deleted: u32,
+}
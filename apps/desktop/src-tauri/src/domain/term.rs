@@ -11,3 +11,54 @@ pub struct Term {
     pub is_replacement: bool,
     pub is_deleted: bool,
 }
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TermSuggestionStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+impl TermSuggestionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "accepted" => Some(Self::Accepted),
+            "rejected" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+
+/// A replacement the user keeps making by hand, proposed as a new [`Term`].
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TermSuggestion {
+    pub id: String,
+    pub source_value: String,
+    pub destination_value: String,
+    pub status: TermSuggestionStatus,
+    /// Number of transcriptions this correction was made in.
+    pub occurrences: i64,
+    /// Share of corrections to `source_value` that chose this destination.
+    pub confidence: f64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A reviewed suggestion and, when it was accepted, the term created for it.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermSuggestionReview {
+    pub suggestion: TermSuggestion,
+    pub term: Option<Term>,
+}
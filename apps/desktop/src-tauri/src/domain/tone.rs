@@ -9,3 +9,43 @@ pub struct Tone {
     pub created_at: i64,
     pub sort_order: i32,
 }
+
+/// Values exposed to `{{ … }}` placeholders in a tone's prompt template.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneTemplateContext {
+    #[serde(default)]
+    pub app_name: Option<String>,
+    #[serde(default)]
+    pub selected_text: Option<String>,
+    #[serde(default)]
+    pub preceding_text: Option<String>,
+    #[serde(default)]
+    pub following_text: Option<String>,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    #[serde(default)]
+    pub user_company: Option<String>,
+    #[serde(default)]
+    pub user_title: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// A template problem with a 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneTemplateIssue {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneTemplatePreview {
+    pub rendered: String,
+    pub issues: Vec<ToneTemplateIssue>,
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptExportFormat {
+    Srt,
+    Vtt,
+    Markdown,
+    Text,
+    Json,
+}
+
+impl TranscriptExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Markdown => "md",
+            Self::Text => "txt",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Srt => "application/x-subrip",
+            Self::Vtt => "text/vtt",
+            Self::Markdown => "text/markdown",
+            Self::Text => "text/plain",
+            Self::Json => "application/json",
+        }
+    }
+
+    /// Subtitle formats need a start and end time for every cue.
+    pub fn is_timed(self) -> bool {
+        matches!(self, Self::Srt | Self::Vtt)
+    }
+}
+
+/// How precisely timestamps are written. Subtitle formats always carry
+/// timestamps, so `None` only affects Markdown and plain text there.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+    None,
+    #[default]
+    Seconds,
+    Milliseconds,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptExportOptions {
+    pub format: TranscriptExportFormat,
+    #[serde(default = "default_speaker_labels")]
+    pub speaker_labels: bool,
+    #[serde(default)]
+    pub timestamps: TimestampGranularity,
+    /// Join consecutive segments from the same speaker into one block.
+    #[serde(default)]
+    pub merge_speakers: bool,
+    /// Longest silence between segments that still merges them.
+    #[serde(default)]
+    pub merge_gap_ms: Option<i64>,
+}
+
+fn default_speaker_labels() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptExport {
+    pub file_name: String,
+    pub mime_type: String,
+    pub content: String,
+}
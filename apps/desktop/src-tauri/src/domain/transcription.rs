@@ -43,4 +43,19 @@ pub struct Transcription {
     pub postprocess_duration_ms: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
+    /// Starred transcriptions can be exempt from retention policies.
+    #[serde(default)]
+    pub starred: bool,
+    /// The app dictated into, for per-app usage analytics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_target_id: Option<String>,
+    /// Language dictation was set to; `None` when it was auto-detected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_language: Option<String>,
+    /// Language the transcription model reported hearing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_language: Option<String>,
+    /// Language the transcript was translated to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_language: Option<String>,
 }
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+pub const VOICE_COMMAND_ACTION_KEYS: &str = "keys";
+pub const VOICE_COMMAND_ACTION_TEXT: &str = "text";
+
+/// A user-defined spoken command. `action_kind` is either `keys`, with
+/// `action_value` holding a chord spec such as `primary+shift+z`, or `text`,
+/// with `action_value` inserted verbatim.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceCommand {
+    pub id: String,
+    pub created_at: i64,
+    pub created_by_user_id: String,
+    pub phrase: String,
+    pub action_kind: String,
+    pub action_value: String,
+    pub is_deleted: bool,
+}
+
+/// Platform-neutral key. Line and document movement are kept semantic because
+/// macOS spells them with Command+arrows rather than Home/End.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EditKey {
+    Enter,
+    Tab,
+    Backspace,
+    Delete,
+    Escape,
+    Left,
+    Right,
+    Up,
+    Down,
+    LineStart,
+    LineEnd,
+    DocumentStart,
+    DocumentEnd,
+    Letter(char),
+}
+
+/// `primary` is Command on macOS and Control elsewhere; `word` is the
+/// word-wise movement modifier (Option on macOS, Control elsewhere).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeyModifiers {
+    pub shift: bool,
+    pub primary: bool,
+    pub word: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeyChord {
+    pub key: EditKey,
+    pub modifiers: KeyModifiers,
+    pub repeat: u32,
+}
+
+impl KeyChord {
+    pub fn new(key: EditKey) -> Self {
+        Self {
+            key,
+            modifiers: KeyModifiers::default(),
+            repeat: 1,
+        }
+    }
+
+    pub fn shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    pub fn primary(mut self) -> Self {
+        self.modifiers.primary = true;
+        self
+    }
+
+    pub fn word(mut self) -> Self {
+        self.modifiers.word = true;
+        self
+    }
+
+    pub fn times(mut self, repeat: u32) -> Self {
+        self.repeat = repeat.max(1);
+        self
+    }
+}
+
+/// One step of a dictation: text to insert, or keys to press. Text steps are
+/// post-processed on their own before the steps run.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "camelCase")]
+pub enum DictationStep {
+    Text(String),
+    Keys(KeyChord),
+}
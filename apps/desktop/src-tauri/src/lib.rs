@@ -1,4 +1,5 @@
 pub mod app;
+pub mod cli;
 pub mod commands;
 pub mod db;
 pub mod domain;
@@ -10,6 +11,7 @@ pub mod system;
 
 pub fn run() {
     app::build()
-        .run(tauri::generate_context!())
-        .expect("tauri runtime failure");
+        .build(tauri::generate_context!())
+        .expect("tauri runtime failure")
+        .run(app::handle_run_event);
 }
@@ -24,6 +24,15 @@ fn main() {
     // CRITICAL: Initialize X11 threading before ANY other operations
     init_x11_threads();
 
+    let context = tauri::generate_context!();
+
+    if std::env::args().nth(1).as_deref() == Some(desktop_lib::cli::CLI_ARG) {
+        std::process::exit(desktop_lib::cli::run(
+            std::env::args().skip(2).collect(),
+            &context.config().identifier,
+        ));
+    }
+
     // Initialize startup logging
     eprintln!("=== OS Voice Startup ===");
     eprintln!("[startup] Version: {}", env!("CARGO_PKG_VERSION"));
@@ -52,8 +61,11 @@ fn main() {
 
     eprintln!("[startup] Building Tauri application...");
 
-    let app_result =
-        std::panic::catch_unwind(|| desktop_lib::app::build().run(tauri::generate_context!()));
+    let app_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        desktop_lib::app::build()
+            .build(context)
+            .map(|app| app.run(desktop_lib::app::handle_run_event))
+    }));
 
     match app_result {
         Ok(result) => {
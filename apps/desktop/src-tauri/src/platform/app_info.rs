@@ -24,6 +24,16 @@ const DEFAULT_ICON_SIZE: u32 = 128;
 pub struct CurrentAppInfo {
     pub app_name: String,
     pub icon_base64: String,
+    pub app_identifier: Option<String>,
+    pub window_title: Option<String>,
+}
+
+/// Identity of the focused window without its icon, for profile matching.
+#[derive(Debug, Clone)]
+pub struct FocusedAppIdentity {
+    pub app_name: String,
+    pub app_identifier: Option<String>,
+    pub window_title: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -43,7 +53,21 @@ pub enum AppInfoError {
 pub fn get_current_app_info() -> Result<CurrentAppInfo, AppInfoError> {
     let config = FocusTrackerConfig::new().with_icon_size(DEFAULT_ICON_SIZE);
     let icon_size = config.icon.get_size_or_default();
-    let tracker = FocusTracker::with_config(config.clone());
+    let window = capture_focused_window(config)?;
+    build_app_info(window, icon_size)
+}
+
+pub fn get_focused_app_identity() -> Result<FocusedAppIdentity, AppInfoError> {
+    let window = capture_focused_window(FocusTrackerConfig::new())?;
+    Ok(FocusedAppIdentity {
+        app_name: resolve_app_name(&window),
+        app_identifier: resolve_app_identifier(&window),
+        window_title: window_title(&window),
+    })
+}
+
+fn capture_focused_window(config: FocusTrackerConfig) -> Result<FocusedWindow, AppInfoError> {
+    let tracker = FocusTracker::with_config(config);
     let stop_signal = AtomicBool::new(false);
     let mut captured: Option<FocusedWindow> = None;
 
@@ -58,8 +82,7 @@ pub fn get_current_app_info() -> Result<CurrentAppInfo, AppInfoError> {
         )
         .map_err(map_focus_error)?;
 
-    let window = captured.ok_or(AppInfoError::NotAvailable)?;
-    build_app_info(window, icon_size)
+    captured.ok_or(AppInfoError::NotAvailable)
 }
 
 fn map_focus_error(err: ferrous_focus::FerrousFocusError) -> AppInfoError {
@@ -80,6 +103,8 @@ fn map_focus_error(err: ferrous_focus::FerrousFocusError) -> AppInfoError {
 fn build_app_info(window: FocusedWindow, icon_size: u32) -> Result<CurrentAppInfo, AppInfoError> {
     let mut window = window;
     let app_name = resolve_app_name(&window);
+    let app_identifier = resolve_app_identifier(&window);
+    let window_title = window_title(&window);
 
     let icon = window
         .icon
@@ -90,6 +115,8 @@ fn build_app_info(window: FocusedWindow, icon_size: u32) -> Result<CurrentAppInf
     Ok(CurrentAppInfo {
         app_name,
         icon_base64: general_purpose::STANDARD.encode(encoded_icon),
+        app_identifier,
+        window_title,
     })
 }
 
@@ -200,6 +227,53 @@ fn extract_app_name_from_title(window: &FocusedWindow) -> Option<String> {
     None
 }
 
+fn window_title(window: &FocusedWindow) -> Option<String> {
+    window
+        .window_title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string)
+}
+
+/// A name for the application that survives localisation and window title
+/// changes: the bundle identifier on macOS, the executable path on Linux and
+/// the process name elsewhere.
+fn resolve_app_identifier(window: &FocusedWindow) -> Option<String> {
+    platform_app_identifier(window)
+        .or_else(|| window.process_name.clone())
+        .map(|identifier| identifier.trim().to_string())
+        .filter(|identifier| !identifier.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn platform_app_identifier(window: &FocusedWindow) -> Option<String> {
+    let pid = window.process_id?;
+
+    unsafe {
+        let app: id = msg_send![class!(NSRunningApplication), runningApplicationWithProcessIdentifier: pid as i32];
+        if app == nil {
+            return None;
+        }
+
+        let bundle_identifier: id = msg_send![app, bundleIdentifier];
+        nsstring_to_string(bundle_identifier)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn platform_app_identifier(window: &FocusedWindow) -> Option<String> {
+    let pid = window.process_id?;
+    std::fs::read_link(format!("/proc/{pid}/exe"))
+        .ok()
+        .map(|path| path.to_string_lossy().into_owned())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn platform_app_identifier(_window: &FocusedWindow) -> Option<String> {
+    None
+}
+
 #[cfg(target_os = "macos")]
 unsafe fn nsstring_to_string(string: id) -> Option<String> {
     if string == nil {
@@ -1,12 +1,13 @@
-use crate::domain::{RecordedAudio, RecordingMetrics, RecordingResult};
+use crate::domain::{InputFailover, RecordedAudio, RecordingMetrics, RecordingResult};
 use crate::errors::RecordingError;
+use crate::platform::whisper::resample_to_sample_rate;
 use crate::platform::{ChunkCallback, LevelCallback, Recorder};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::{Device, HostId, SampleFormat, Stream, StreamConfig};
 use serde::Serialize;
 use std::cmp;
-use std::collections::{HashMap, HashSet};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 use std::time::{Duration, Instant};
 
 /// Cached device info for quick recording start.
@@ -19,19 +20,237 @@ struct CachedDeviceInfo {
 
 pub struct RecordingManager {
     inner: Arc<Mutex<Option<ActiveRecording>>>,
+    /// Lock after `inner` when both are needed.
+    standby: Arc<Mutex<Standby>>,
     preferred_input_name: Arc<Mutex<Option<String>>>,
+    input_gain: Arc<Mutex<Option<f32>>>,
     /// Cache of the last successfully used input device.
     /// This allows us to skip full device enumeration on subsequent recordings.
     last_successful_device: Arc<Mutex<Option<CachedDeviceInfo>>>,
 }
 
 struct ActiveRecording {
-    _stream: Stream,
+    input: OpenStream,
     start: Instant,
-    buffer: Arc<Mutex<Vec<f32>>>,
+    /// Devices this recording moved off after they failed.
+    failovers: Vec<InputFailover>,
+}
+
+/// A playing input stream and the state its callback writes to.
+struct OpenStream {
+    stream: Stream,
+    capture: Arc<Mutex<CaptureState>>,
+    /// The capture's sample rate, which the device's audio is converted to.
     sample_rate: u32,
-    _level_emitter: Option<Arc<LevelEmitter>>,
-    _chunk_emitter: Option<Arc<ChunkEmitter>>,
+    device_name: String,
+    /// Set by this stream's error callback. A stream that was replaced can
+    /// still report an error late, which must not fail its replacement.
+    error: Arc<OnceLock<String>>,
+}
+
+/// Everything the input callback touches. It is swapped in place so a stream
+/// that is already open can move between standby and recording.
+#[derive(Default)]
+struct CaptureState {
+    samples: VecDeque<f32>,
+    /// On standby, how many of the most recent samples to keep.
+    standby_limit: Option<usize>,
+    /// Samples passed to `listener` since it was attached.
+    heard: u64,
+    /// Where the listener asked the next recording to begin, and when.
+    pinned_from: Option<(u64, Instant)>,
+    last_push: Option<Instant>,
+    /// Rate of the held samples. Set by the first stream to open, so a
+    /// stream replacing it keeps writing at the same rate.
+    sample_rate: Option<u32>,
+    /// Applied to every sample before anything else sees it.
+    gain: Option<f32>,
+    level_emitter: Option<Arc<LevelEmitter>>,
+    chunk_emitter: Option<Arc<ChunkEmitter>>,
+    listener: Option<ChunkCallback>,
+}
+
+impl CaptureState {
+    fn apply_gain(&self, samples: &mut [f32]) {
+        if let Some(gain) = self.gain {
+            for sample in samples {
+                *sample = (*sample * gain).clamp(-1.0, 1.0);
+            }
+        }
+    }
+
+    fn push(&mut self, samples: &[f32]) {
+        self.last_push = Some(Instant::now());
+        self.samples.extend(samples.iter().copied());
+        if self.listener.is_some() {
+            self.heard += samples.len() as u64;
+        }
+        if let Some(limit) = self.standby_limit {
+            let excess = self.samples.len().saturating_sub(limit);
+            self.samples.drain(..excess);
+        }
+    }
+
+    fn attach_listener(&mut self, listener: Option<ChunkCallback>) {
+        self.listener = listener;
+        self.heard = 0;
+    }
+
+    /// Drop held samples from before the `position`th sample heard and pin
+    /// the next recording to start there, if that audio is still held.
+    fn discard_before(&mut self, position: u64) {
+        let first_held = self.first_held();
+        if position < first_held {
+            return;
+        }
+        let excess = (position - first_held).min(self.samples.len() as u64);
+        self.samples.drain(..excess as usize);
+        self.pinned_from = Some((position, Instant::now()));
+    }
+
+    fn first_held(&self) -> u64 {
+        self.heard.saturating_sub(self.samples.len() as u64)
+    }
+
+    /// Leave standby for a recording. A pin is honoured while the audio it
+    /// points at is still held and it is recent enough to be the recording
+    /// it was made for; otherwise only the last `pre_roll_limit` samples are
+    /// kept.
+    fn begin_recording(&mut self, pre_roll_limit: usize, now: Instant) {
+        let pinned = self
+            .pinned_from
+            .take()
+            .is_some_and(|(position, pinned_at)| {
+                position >= self.first_held() && now.duration_since(pinned_at) <= WAKE_PIN_TIMEOUT
+            });
+        if !pinned {
+            let excess = self.samples.len().saturating_sub(pre_roll_limit);
+            self.samples.drain(..excess);
+        }
+        self.standby_limit = None;
+    }
+
+    /// Why the stream feeding this capture looks dead, given the error it
+    /// reported, if any.
+    fn failure(&self, stream_error: Option<&String>, now: Instant) -> Option<String> {
+        if let Some(err) = stream_error {
+            return Some(err.clone());
+        }
+        self.last_push
+            .is_none_or(|last| now.duration_since(last) >= STREAM_STALL_TIMEOUT)
+            .then(|| "stopped delivering audio".to_string())
+    }
+}
+
+/// Standby keeps an input stream open between recordings, so `start` can
+/// include the audio captured just before it. Warm mode and a listener can
+/// each ask for it; the stream stays open while either does.
+///
+/// Lifecycle: when the preferred device changes, the idle stream is reopened
+/// on the new device, and a stream in use by a recording is closed when the
+/// recording stops instead of returning to standby. A stream that stops
+/// delivering audio, or any stream after the machine wakes from sleep, is
+/// reopened by `refresh_standby`.
+///
+/// Opening a stream enumerates devices, which can block for a long time, so
+/// it runs without the recorder's locks held; the new stream is installed
+/// afterwards only if it is still wanted and no later open replaced it.
+#[derive(Default)]
+struct Standby {
+    /// Pre-roll for a plain `start` while warm mode is on.
+    warm: Option<Duration>,
+    /// Set while a listener wants the stream, including during a recording.
+    listening: Option<StandbyConfig>,
+    /// The recording's stream belongs to a device that is no longer wanted.
+    stale: bool,
+    /// The open stream while no recording is using it.
+    idle: Option<OpenStream>,
+    /// The host's default input when the idle stream opened. With no
+    /// preferred device the stream follows it.
+    default_input: Option<String>,
+    /// Paces reopening after the stream could not be opened.
+    reopen: Backoff,
+    /// Counts the opens started, so one that finishes after a later one
+    /// began is discarded.
+    opening: u64,
+}
+
+impl Standby {
+    fn is_wanted(&self) -> bool {
+        self.warm.is_some() || self.listening.is_some()
+    }
+
+    /// How much audio the idle stream holds.
+    fn buffer(&self) -> Duration {
+        let warm = self.warm.unwrap_or_default();
+        let listening = self
+            .listening
+            .as_ref()
+            .map_or(Duration::ZERO, |config| config.pre_roll);
+        warm.max(listening)
+    }
+
+    fn listener(&self) -> Option<ChunkCallback> {
+        self.listening
+            .as_ref()
+            .and_then(|config| config.listener.clone())
+    }
+}
+
+#[derive(Clone)]
+struct StandbyConfig {
+    pre_roll: Duration,
+    listener: Option<ChunkCallback>,
+}
+
+/// An input stream that has delivered nothing for this long is dead, which
+/// happens after sleep and when a device disappears.
+const STREAM_STALL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A wake pin no recording has used within this long was ignored, and must
+/// not hand its audio to a later recording.
+const WAKE_PIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Bounds of the delay between attempts to reopen a standby stream that
+/// could not be opened.
+const STANDBY_RETRY_MIN: Duration = Duration::from_secs(2);
+const STANDBY_RETRY_MAX: Duration = Duration::from_secs(60);
+
+/// Spaces out retries of an operation that keeps failing, doubling the delay
+/// after each failure.
+#[derive(Debug, Default)]
+struct Backoff {
+    delay: Duration,
+    next_attempt: Option<Instant>,
+}
+
+impl Backoff {
+    fn is_ready(&self, now: Instant) -> bool {
+        self.next_attempt.is_none_or(|next| now >= next)
+    }
+
+    fn failed(&mut self, now: Instant) {
+        self.delay = (self.delay * 2).clamp(STANDBY_RETRY_MIN, STANDBY_RETRY_MAX);
+        self.next_attempt = Some(now + self.delay);
+    }
+
+    fn succeeded(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Name of the host's default input device.
+fn default_input_name() -> Option<String> {
+    cpal::default_host()
+        .default_input_device()
+        .and_then(|device| device.name().ok())
+}
+
+fn lock_capture(capture: &Mutex<CaptureState>) -> MutexGuard<'_, CaptureState> {
+    match capture.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
 }
 
 const LEVEL_BIN_COUNT: usize = 12;
@@ -163,28 +382,66 @@ fn compute_level_bins(samples: &[f32]) -> Vec<f32> {
     bins
 }
 
-impl Drop for ActiveRecording {
+impl Drop for OpenStream {
     fn drop(&mut self) {
-        if let Err(err) = self._stream.pause() {
+        if let Err(err) = self.stream.pause() {
             eprintln!("[recording] failed to pause input stream: {err}");
         }
     }
 }
 
-// cpal::Stream is not Send/Sync across every platform, but we only ever create,
-// use, and drop it on the dedicated event tap thread. The interior mutex prevents
-// concurrent access, so it is safe for our usage to share the manager/type
-// between threads.
+impl OpenStream {
+    /// Switch the stream to standby: recording taps are removed and only the
+    /// last `buffer` of audio is kept.
+    fn enter_standby(&self, buffer: Duration) {
+        let limit = self.samples_in(buffer);
+        let mut capture = lock_capture(&self.capture);
+        capture.standby_limit = Some(limit);
+        capture.level_emitter = None;
+        capture.chunk_emitter = None;
+        let excess = capture.samples.len().saturating_sub(limit);
+        capture.samples.drain(..excess);
+    }
+
+    fn failure(&self, now: Instant) -> Option<String> {
+        lock_capture(&self.capture).failure(self.error.get(), now)
+    }
+
+    fn is_stalled(&self, now: Instant) -> bool {
+        self.failure(now).is_some()
+    }
+
+    fn samples_in(&self, duration: Duration) -> usize {
+        (duration.as_secs_f64() * f64::from(self.sample_rate)) as usize
+    }
+}
+
+// cpal::Stream is marked !Send/!Sync on every platform because some hosts
+// (Android's AAudio, the web backends) cannot move a stream between threads.
+// Streams here are opened, paused and dropped on whichever thread calls in:
+// the recording commands' blocking tasks, the warm microphone watchdog and the
+// device watcher's failover. That is sound on the hosts this app builds for:
+// - ALSA and WASAPI: the stream owns its worker thread and only signals it,
+//   over a pipe or channel, to play, pause or stop; drop joins the thread.
+// - CoreAudio: the stream is an AudioUnit behind cpal's own mutex, and
+//   starting, stopping and disposing of an AudioUnit may happen on any thread.
+// A stream belongs to the one thread opening it until it is stored, and is
+// only reached through the `inner` or `standby` mutex after that, so no two
+// threads ever touch one at once.
 unsafe impl Send for RecordingManager {}
 unsafe impl Sync for RecordingManager {}
 unsafe impl Send for ActiveRecording {}
 unsafe impl Sync for ActiveRecording {}
+unsafe impl Send for OpenStream {}
+unsafe impl Sync for OpenStream {}
 
 impl RecordingManager {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(Mutex::new(None)),
+            standby: Arc::new(Mutex::new(Standby::default())),
             preferred_input_name: Arc::new(Mutex::new(None)),
+            input_gain: Arc::new(Mutex::new(None)),
             last_successful_device: Arc::new(Mutex::new(None)),
         }
     }
@@ -203,10 +460,9 @@ impl RecordingManager {
     /// Returns None if cache is empty or device is no longer available.
     fn try_cached_device(
         &self,
-        level_emitter: Option<Arc<LevelEmitter>>,
-        chunk_emitter: Option<Arc<ChunkEmitter>>,
+        capture: &Arc<Mutex<CaptureState>>,
         preferred_normalized: Option<&str>,
-    ) -> Option<(ActiveRecording, HostId, String)> {
+    ) -> Option<(OpenStream, HostId, String)> {
         let cached = {
             let guard = self.last_successful_device.lock().ok()?;
             guard.clone()
@@ -226,12 +482,7 @@ impl RecordingManager {
         let device = find_device_by_name(&host, &cached.device_name)?;
 
         // Try to start recording on this device
-        let result = try_start_on_device(
-            &device,
-            Some(&cached.device_name),
-            level_emitter,
-            chunk_emitter,
-        );
+        let result = try_start_on_device(&device, Some(&cached.device_name), capture);
 
         match result {
             Ok(active) => {
@@ -262,13 +513,74 @@ impl RecordingManager {
         level_callback: Option<LevelCallback>,
         chunk_callback: Option<ChunkCallback>,
     ) -> Result<(), RecordingError> {
-        let preferred_label = {
-            let guard = match self.preferred_input_name.lock() {
-                Ok(guard) => guard,
-                Err(poisoned) => poisoned.into_inner(),
+        let mut guard = self.guard()?;
+
+        if guard.is_some() {
+            return Err(RecordingError::AlreadyRecording);
+        }
+
+        let level_emitter = level_callback.map(LevelEmitter::new);
+        let chunk_emitter = chunk_callback.map(ChunkEmitter::new);
+
+        let mut standby = lock_standby(&self.standby);
+
+        // Standby already has a stream open with the pre-roll buffered,
+        // unless it died without `refresh_standby` noticing yet.
+        let mut heard = 0;
+        let idle = match standby.idle.take() {
+            Some(input) if input.is_stalled(Instant::now()) => {
+                eprintln!("[recording] standby stream stalled; opening a new one");
+                heard = lock_capture(&input.capture).heard;
+                None
+            }
+            idle => idle,
+        };
+        if let Some(input) = idle {
+            let pre_roll_limit = input.samples_in(standby.warm.unwrap_or_default());
+            let pre_roll_ms = {
+                let mut capture = lock_capture(&input.capture);
+                capture.begin_recording(pre_roll_limit, Instant::now());
+                capture.level_emitter = level_emitter;
+                capture.chunk_emitter = chunk_emitter;
+                capture.samples.len() as u64 * 1000 / u64::from(input.sample_rate.max(1))
             };
-            guard.clone()
+            eprintln!("[recording] started from standby with {pre_roll_ms} ms of pre-roll");
+            *guard = Some(ActiveRecording {
+                input,
+                start: Instant::now(),
+                failovers: Vec::new(),
+            });
+            return Ok(());
+        }
+
+        let listener = standby.listener();
+        drop(standby);
+
+        let mut capture = CaptureState {
+            last_push: Some(Instant::now()),
+            level_emitter,
+            chunk_emitter,
+            ..CaptureState::default()
         };
+        capture.attach_listener(listener);
+        capture.heard = heard;
+        let input = self.open_input(Arc::new(Mutex::new(capture)), None)?;
+        *guard = Some(ActiveRecording {
+            input,
+            start: Instant::now(),
+            failovers: Vec::new(),
+        });
+        Ok(())
+    }
+
+    /// Open a stream on the preferred device, or the best available one,
+    /// skipping the device named `avoid`.
+    fn open_input(
+        &self,
+        capture: Arc<Mutex<CaptureState>>,
+        avoid: Option<&str>,
+    ) -> Result<OpenStream, RecordingError> {
+        let preferred_label = self.preferred_input_name();
         let preferred_trimmed = preferred_label
             .as_ref()
             .map(|value| value.trim())
@@ -278,24 +590,15 @@ impl RecordingManager {
             .as_ref()
             .map(|value| value.to_ascii_lowercase());
 
-        let mut guard = self.guard()?;
-
-        if guard.is_some() {
-            return Err(RecordingError::AlreadyRecording);
-        }
-
-        let level_emitter = level_callback.map(LevelEmitter::new);
-        let chunk_emitter = chunk_callback.map(ChunkEmitter::new);
-
         // Fast path: try the cached device first (avoids full enumeration)
-        if let Some((active, host_id, device_name)) = self.try_cached_device(
-            level_emitter.clone(),
-            chunk_emitter.clone(),
-            preferred_normalized.as_deref(),
-        ) {
-            *guard = Some(active);
-            self.cache_successful_device(host_id, device_name);
-            return Ok(());
+        if avoid.is_none() {
+            if let Some((input, host_id, device_name)) =
+                self.try_cached_device(&capture, preferred_normalized.as_deref())
+            {
+                self.cache_successful_device(host_id, device_name);
+                self.apply_gain(&input);
+                return Ok(input);
+            }
         }
 
         // Slow path: full device enumeration
@@ -312,16 +615,16 @@ impl RecordingManager {
 
             match start_recording_on_host(
                 &host,
-                level_emitter.clone(),
-                chunk_emitter.clone(),
+                &capture,
                 preferred_trimmed.as_deref(),
                 preferred_normalized.as_deref(),
+                avoid,
             ) {
-                Ok((active, device_name)) => {
-                    *guard = Some(active);
+                Ok((input, device_name)) => {
                     // Cache this device for next time
                     self.cache_successful_device(host_id, device_name);
-                    return Ok(());
+                    self.apply_gain(&input);
+                    return Ok(input);
                 }
                 Err(err) => {
                     eprintln!(
@@ -340,15 +643,15 @@ impl RecordingManager {
             .inner
             .lock()
             .map_err(|_| RecordingError::NotRecording)?;
-        let recording = guard.take().ok_or(RecordingError::NotRecording)?;
-
-        let samples = recording
-            .buffer
-            .lock()
-            .map(|buffer| buffer.clone())
-            .unwrap_or_default();
-        let sample_rate = recording.sample_rate;
-        let fallback_duration = recording.start.elapsed();
+        let ActiveRecording {
+            input,
+            start,
+            failovers,
+        } = guard.take().ok_or(RecordingError::NotRecording)?;
+
+        let samples = Vec::from(std::mem::take(&mut lock_capture(&input.capture).samples));
+        let sample_rate = input.sample_rate;
+        let fallback_duration = start.elapsed();
         let duration = if !samples.is_empty() && sample_rate > 0 {
             let duration_secs = samples.len() as f64 / f64::from(sample_rate);
             std::time::Duration::from_secs_f64(duration_secs)
@@ -357,7 +660,26 @@ impl RecordingManager {
         };
         let size_bytes = samples.len() as u64 * std::mem::size_of::<f32>() as u64;
 
-        drop(recording);
+        let mut standby = lock_standby(&self.standby);
+        let stale = std::mem::take(&mut standby.stale);
+        let mut reopen_from = None;
+        if standby.is_wanted() && !stale {
+            input.enter_standby(standby.buffer());
+            standby.idle = Some(input);
+        } else {
+            if standby.is_wanted() {
+                reopen_from = Some(lock_capture(&input.capture).heard);
+            }
+            drop(input);
+        }
+        drop(standby);
+        drop(guard);
+
+        if let Some(heard) = reopen_from {
+            if let Err(err) = self.open_standby(heard) {
+                eprintln!("[recording] failed to reopen standby: {err}");
+            }
+        }
 
         Ok(RecordingResult {
             metrics: RecordingMetrics {
@@ -368,8 +690,355 @@ impl RecordingManager {
                 samples,
                 sample_rate,
             },
+            failovers,
         })
     }
+
+    /// Move a recording whose input failed to the next best device. The
+    /// samples captured so far stay in place and the new device's audio is
+    /// converted to the recording's sample rate.
+    fn recover_input(&self) -> Option<InputFailover> {
+        let (capture, from, reason) = {
+            let guard = self.inner.lock().ok()?;
+            let active = guard.as_ref()?;
+            let reason = active.input.failure(Instant::now())?;
+            (
+                Arc::clone(&active.input.capture),
+                active.input.device_name.clone(),
+                reason,
+            )
+        };
+        eprintln!("[recording] input '{from}' failed ({reason}); switching device");
+
+        // Opening a device enumerates them, which can be slow, so the
+        // recording is not held meanwhile and can still be stopped. The
+        // cached device is the one that failed. It is tried again last, in
+        // case it has come back.
+        self.clear_device_cache();
+        let replacement = match self
+            .open_input(Arc::clone(&capture), Some(&from))
+            .or_else(|_| self.open_input(Arc::clone(&capture), None))
+        {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("[recording] no input device to switch to: {err}");
+                return None;
+            }
+        };
+
+        let mut guard = self.inner.lock().ok()?;
+        let Some(active) = guard
+            .as_mut()
+            .filter(|active| Arc::ptr_eq(&active.input.capture, &capture))
+        else {
+            eprintln!("[recording] recording ended while switching input");
+            return None;
+        };
+        let failover = InputFailover {
+            from,
+            to: replacement.device_name.clone(),
+            reason,
+        };
+        active.input = replacement;
+        lock_capture(&capture).last_push = Some(Instant::now());
+        eprintln!("[recording] switched input to '{}'", failover.to);
+        active.failovers.push(failover.clone());
+        Some(failover)
+    }
+
+    fn start_standby(
+        &self,
+        pre_roll: Duration,
+        listener: Option<ChunkCallback>,
+    ) -> Result<u32, RecordingError> {
+        let guard = self.guard()?;
+        let mut standby = lock_standby(&self.standby);
+        let config = StandbyConfig { pre_roll, listener };
+
+        // A running recording's stream is kept open once it stops.
+        if let Some(active) = guard.as_ref() {
+            lock_capture(&active.input.capture).attach_listener(config.listener.clone());
+            standby.listening = Some(config);
+            return Ok(active.input.sample_rate);
+        }
+
+        if let Some(idle) = standby.idle.as_ref() {
+            lock_capture(&idle.capture).attach_listener(config.listener.clone());
+        }
+        standby.listening = Some(config);
+        if !sync_standby(&mut standby) {
+            return Ok(standby.idle.as_ref().map_or(0, |idle| idle.sample_rate));
+        }
+        drop(standby);
+        drop(guard);
+        Ok(self.open_standby(0)?.unwrap_or_default())
+    }
+
+    fn stop_standby(&self) {
+        let guard = match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(active) = guard.as_ref() {
+            lock_capture(&active.input.capture).attach_listener(None);
+        }
+
+        let mut standby = lock_standby(&self.standby);
+        standby.listening = None;
+        if let Some(idle) = standby.idle.as_ref() {
+            lock_capture(&idle.capture).attach_listener(None);
+        }
+        if !sync_standby(&mut standby) {
+            return;
+        }
+        drop(standby);
+        drop(guard);
+        if let Err(err) = self.open_standby(0) {
+            eprintln!("[recording] failed to keep standby open: {err}");
+        }
+    }
+
+    fn trim_standby(&self, keep_from: u64) {
+        let standby = lock_standby(&self.standby);
+        if let Some(idle) = standby.idle.as_ref() {
+            lock_capture(&idle.capture).discard_before(keep_from);
+        }
+    }
+
+    fn set_warm_pre_roll(&self, pre_roll: Option<Duration>) -> Result<(), RecordingError> {
+        let guard = self.guard()?;
+        let mut standby = lock_standby(&self.standby);
+        standby.warm = pre_roll.filter(|value| !value.is_zero());
+        if guard.is_some() {
+            // Applied when the recording stops.
+            return Ok(());
+        }
+        if !sync_standby(&mut standby) {
+            return Ok(());
+        }
+        drop(standby);
+        drop(guard);
+        self.open_standby(0).map(|_| ())
+    }
+
+    /// Reopen the idle stream if it went quiet or the machine slept.
+    fn refresh_standby(&self, resumed: bool) {
+        // Looking up the default device can block, so it happens before the
+        // locks are taken.
+        let default_input = self
+            .preferred_input_name()
+            .is_none()
+            .then(default_input_name);
+
+        let Ok(guard) = self.inner.lock() else {
+            return;
+        };
+        if guard.is_some() {
+            return;
+        }
+
+        let mut standby = lock_standby(&self.standby);
+        if !standby.is_wanted() {
+            return;
+        }
+        let now = Instant::now();
+        let stalled = match standby.idle.as_ref() {
+            Some(idle) => idle.is_stalled(now),
+            None => standby.reopen.is_ready(now),
+        };
+        let default_changed = standby.idle.is_some()
+            && default_input.is_some_and(|name| name != standby.default_input);
+        if !resumed && !stalled && !default_changed {
+            return;
+        }
+
+        let idle = standby.idle.take();
+        drop(standby);
+        drop(guard);
+        let heard = idle.map_or(0, |idle| lock_capture(&idle.capture).heard);
+        eprintln!(
+            "[recording] reopening standby ({})",
+            if resumed {
+                "resumed from sleep"
+            } else if stalled {
+                "stream stalled"
+            } else {
+                "default input changed"
+            }
+        );
+        // The cached device may be the one that disappeared.
+        self.clear_device_cache();
+        if let Err(err) = self.open_standby(heard) {
+            eprintln!("[recording] failed to reopen standby: {err}");
+        }
+    }
+
+    fn preferred_input_name(&self) -> Option<String> {
+        match self.preferred_input_name.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    fn input_gain(&self) -> Option<f32> {
+        match self.input_gain.lock() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    /// Apply the gain to new streams and to the ones already open.
+    fn set_input_gain(&self, gain: Option<f32>) {
+        let gain = gain.filter(|value| value.is_finite() && *value > 0.0);
+        match self.input_gain.lock() {
+            Ok(mut guard) => *guard = gain,
+            Err(poisoned) => *poisoned.into_inner() = gain,
+        }
+
+        let guard = match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(active) = guard.as_ref() {
+            self.apply_gain(&active.input);
+        }
+        let standby = lock_standby(&self.standby);
+        if let Some(idle) = standby.idle.as_ref() {
+            self.apply_gain(idle);
+        }
+    }
+
+    /// Record `input` at its device's calibrated gain, or else the one set
+    /// by `set_input_gain`.
+    fn apply_gain(&self, input: &OpenStream) {
+        let gain = measured_input(&input.device_name)
+            .map(|measured| measured.gain)
+            .or_else(|| self.input_gain());
+        lock_capture(&input.capture).gain = gain;
+    }
+
+    /// Reopen standby on the newly preferred device.
+    fn switch_standby_device(&self) {
+        let guard = match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut standby = lock_standby(&self.standby);
+        if guard.is_some() {
+            standby.stale = standby.is_wanted();
+            return;
+        }
+
+        let Some(idle) = standby.idle.take() else {
+            return;
+        };
+        drop(standby);
+        drop(guard);
+        let heard = lock_capture(&idle.capture).heard;
+        drop(idle);
+        if let Err(err) = self.open_standby(heard) {
+            eprintln!("[recording] failed to reopen standby: {err}");
+        }
+    }
+
+    /// Open a new idle stream, continuing the listener's sample count from
+    /// `heard` so positions it already handed out stay valid. Must be called
+    /// without the recorder's locks held. Returns the sample rate of the
+    /// stream standby ends up with, which is the recording's if one started
+    /// meanwhile, or `None` once nothing wants standby.
+    fn open_standby(&self, heard: u64) -> Result<Option<u32>, RecordingError> {
+        let (generation, listener) = {
+            let mut standby = lock_standby(&self.standby);
+            standby.opening += 1;
+            (standby.opening, standby.listener())
+        };
+        let mut capture = CaptureState {
+            last_push: Some(Instant::now()),
+            ..CaptureState::default()
+        };
+        capture.attach_listener(listener.clone());
+        capture.heard = heard;
+        let default_input = default_input_name();
+        let opened = self.open_input(Arc::new(Mutex::new(capture)), None);
+
+        let guard = match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut standby = lock_standby(&self.standby);
+        let current = guard
+            .as_ref()
+            .map(|active| active.input.sample_rate)
+            .or_else(|| standby.idle.as_ref().map(|idle| idle.sample_rate));
+        if standby.opening != generation {
+            // A later open started meanwhile; its stream is the one kept.
+            drop(standby);
+            drop(guard);
+            return opened.map(|_| current);
+        }
+        let input = match opened {
+            Ok(input) => input,
+            Err(err) => {
+                standby.reopen.failed(Instant::now());
+                return Err(err);
+            }
+        };
+        standby.reopen.succeeded();
+        if guard.is_some() || !standby.is_wanted() || standby.idle.is_some() {
+            drop(standby);
+            drop(guard);
+            drop(input);
+            return Ok(current);
+        }
+
+        // The listener may have changed while the stream was opening.
+        let latest = standby.listener();
+        let unchanged = match (&listener, &latest) {
+            (Some(before), Some(after)) => Arc::ptr_eq(before, after),
+            (None, None) => true,
+            _ => false,
+        };
+        if !unchanged {
+            lock_capture(&input.capture).attach_listener(latest);
+        }
+        input.enter_standby(standby.buffer());
+        let sample_rate = input.sample_rate;
+        eprintln!(
+            "[recording] standby open with {} ms of buffer",
+            standby.buffer().as_millis()
+        );
+        standby.default_input = default_input;
+        standby.idle = Some(input);
+        Ok(Some(sample_rate))
+    }
+}
+
+/// Bring the idle stream in line with what standby wants: closed when nothing
+/// wants it, otherwise holding the current buffer. Returns whether a stream
+/// still has to be opened, which the caller does with `open_standby` once it
+/// has released the locks.
+fn sync_standby(standby: &mut Standby) -> bool {
+    if !standby.is_wanted() {
+        if standby.idle.take().is_some() {
+            eprintln!("[recording] standby stopped");
+        }
+        return false;
+    }
+
+    match standby.idle.as_ref() {
+        Some(idle) => {
+            idle.enter_standby(standby.buffer());
+            false
+        }
+        None => true,
+    }
+}
+
+fn lock_standby(standby: &Mutex<Standby>) -> MutexGuard<'_, Standby> {
+    match standby.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
 }
 
 impl Recorder for RecordingManager {
@@ -386,22 +1055,61 @@ impl Recorder for RecordingManager {
         self.stop_recording().map_err(|err| Box::new(err) as _)
     }
 
+    fn start_standby(
+        &self,
+        pre_roll: Duration,
+        listener: Option<ChunkCallback>,
+    ) -> Result<u32, Box<dyn std::error::Error>> {
+        RecordingManager::start_standby(self, pre_roll, listener).map_err(|err| Box::new(err) as _)
+    }
+
+    fn stop_standby(&self) {
+        RecordingManager::stop_standby(self);
+    }
+
+    fn trim_standby(&self, keep_from: u64) {
+        RecordingManager::trim_standby(self, keep_from);
+    }
+
+    fn set_warm_pre_roll(
+        &self,
+        pre_roll: Option<Duration>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        RecordingManager::set_warm_pre_roll(self, pre_roll).map_err(|err| Box::new(err) as _)
+    }
+
+    fn refresh_standby(&self, resumed: bool) {
+        RecordingManager::refresh_standby(self, resumed);
+    }
+
+    fn recover_input(&self) -> Option<InputFailover> {
+        RecordingManager::recover_input(self)
+    }
+
+    fn set_input_gain(&self, gain: Option<f32>) {
+        RecordingManager::set_input_gain(self, gain);
+    }
+
+    fn refresh_input_gain(&self) {
+        RecordingManager::set_input_gain(self, self.input_gain());
+    }
+
     fn set_preferred_input_device(&self, name: Option<String>) {
         let sanitized = name
             .map(|value| value.trim().to_string())
             .filter(|value| !value.is_empty());
 
-        match self.preferred_input_name.lock() {
-            Ok(mut guard) => {
-                *guard = sanitized;
-            }
-            Err(poisoned) => {
-                *poisoned.into_inner() = sanitized;
-            }
-        }
+        let previous = match self.preferred_input_name.lock() {
+            Ok(mut guard) => std::mem::replace(&mut *guard, sanitized.clone()),
+            Err(poisoned) => std::mem::replace(&mut *poisoned.into_inner(), sanitized.clone()),
+        };
 
         // Clear device cache so next recording uses the new preference
         self.clear_device_cache();
+
+        if previous != sanitized {
+            self.switch_standby_device();
+        }
     }
 
     fn clear_device_cache(&self) {
@@ -416,7 +1124,7 @@ impl Recorder for RecordingManager {
             Ok(inner) => inner,
             Err(poisoned) => poisoned.into_inner(),
         };
-        guard.as_ref().map(|active| active.sample_rate)
+        guard.as_ref().map(|active| active.input.sample_rate)
     }
 }
 
@@ -466,6 +1174,68 @@ fn should_avoid_input_device(device: &Device, default_output_name: Option<&str>)
     false
 }
 
+/// Calibration scores below this mark a device as one to avoid.
+const POOR_MEASURED_SCORE: f32 = 40.0;
+
+/// What calibrating an input device found.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeasuredInput {
+    /// Quality out of 100.
+    pub score: f32,
+    /// Gain its audio is recorded at.
+    pub gain: f32,
+}
+
+/// Calibration results by lowercased device label.
+fn measured_inputs() -> &'static Mutex<HashMap<String, MeasuredInput>> {
+    static MEASURED: OnceLock<Mutex<HashMap<String, MeasuredInput>>> = OnceLock::new();
+    MEASURED.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Replace the calibration results used to rank devices and set their gain,
+/// keyed by the labels `list_input_devices` reports.
+pub fn set_measured_inputs(inputs: HashMap<String, MeasuredInput>) {
+    let inputs = inputs
+        .into_iter()
+        .map(|(name, input)| (name.trim().to_ascii_lowercase(), input))
+        .collect();
+    match measured_inputs().lock() {
+        Ok(mut guard) => *guard = inputs,
+        Err(poisoned) => *poisoned.into_inner() = inputs,
+    }
+}
+
+fn measured_input(device_name: &str) -> Option<MeasuredInput> {
+    let inputs = match measured_inputs().lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(input) = inputs.get(&device_name.trim().to_ascii_lowercase()) {
+        return Some(*input);
+    }
+
+    // Labels are friendly names on Linux
+    #[cfg(target_os = "linux")]
+    {
+        let friendly_name = crate::platform::linux::audio::get_friendly_device_name(device_name);
+        if let Some(input) = inputs.get(&friendly_name.to_ascii_lowercase()) {
+            return Some(*input);
+        }
+    }
+
+    None
+}
+
+/// Priority for a measured device, in place of the guesses made from its
+/// name: 1 for a perfect score up to 201 for the worst.
+fn measured_priority(score: f32) -> (u32, Option<String>) {
+    let score = score.clamp(0.0, 100.0);
+    let priority = 1 + ((100.0 - score) * 2.0).round() as u32;
+    let avoid_reason =
+        (score < POOR_MEASURED_SCORE).then(|| format!("measured quality {score:.0}/100"));
+    (priority, avoid_reason)
+}
+
 fn is_preferred_input_device_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     if LOW_QUALITY_INPUT_KEYWORDS
@@ -573,9 +1343,8 @@ fn find_device_by_name(host: &cpal::Host, target_name: &str) -> Option<Device> {
 fn try_start_on_device(
     device: &Device,
     device_name: Option<&str>,
-    level_emitter: Option<Arc<LevelEmitter>>,
-    chunk_emitter: Option<Arc<ChunkEmitter>>,
-) -> Result<ActiveRecording, RecordingError> {
+    capture: &Arc<Mutex<CaptureState>>,
+) -> Result<OpenStream, RecordingError> {
     let label = device_name.unwrap_or("<unknown>");
 
     let config = device
@@ -584,31 +1353,18 @@ fn try_start_on_device(
 
     let sample_format = config.sample_format();
     let stream_config: StreamConfig = config.into();
-    let sample_rate = stream_config.sample_rate.0;
-    let buffer = Arc::new(Mutex::new(Vec::<f32>::new()));
+    let sample_rate = capture_sample_rate(capture, &stream_config);
 
-    let stream = match sample_format {
-        SampleFormat::I16 => build_input_stream::<i16>(
-            device,
-            &stream_config,
-            buffer.clone(),
-            level_emitter.clone(),
-            chunk_emitter.clone(),
-        ),
-        SampleFormat::U16 => build_input_stream::<u16>(
-            device,
-            &stream_config,
-            buffer.clone(),
-            level_emitter.clone(),
-            chunk_emitter.clone(),
-        ),
-        SampleFormat::F32 => build_input_stream::<f32>(
-            device,
-            &stream_config,
-            buffer.clone(),
-            level_emitter.clone(),
-            chunk_emitter.clone(),
-        ),
+    let (stream, error) = match sample_format {
+        SampleFormat::I16 => {
+            build_input_stream::<i16>(device, &stream_config, capture.clone(), sample_rate)
+        }
+        SampleFormat::U16 => {
+            build_input_stream::<u16>(device, &stream_config, capture.clone(), sample_rate)
+        }
+        SampleFormat::F32 => {
+            build_input_stream::<f32>(device, &stream_config, capture.clone(), sample_rate)
+        }
         other => return Err(RecordingError::UnsupportedFormat(other)),
     }?;
 
@@ -617,31 +1373,31 @@ fn try_start_on_device(
         .map_err(|err| RecordingError::StreamPlay(err.to_string()))?;
 
     eprintln!("[recording] started on device '{label}'");
+    lock_capture(capture).sample_rate = Some(sample_rate);
 
-    Ok(ActiveRecording {
-        _stream: stream,
-        start: Instant::now(),
-        buffer,
+    Ok(OpenStream {
+        stream,
+        capture: capture.clone(),
         sample_rate,
-        _level_emitter: level_emitter,
-        _chunk_emitter: chunk_emitter,
+        device_name: label.to_string(),
+        error,
     })
 }
 
 fn start_recording_on_host(
     host: &cpal::Host,
-    level_emitter: Option<Arc<LevelEmitter>>,
-    chunk_emitter: Option<Arc<ChunkEmitter>>,
+    capture: &Arc<Mutex<CaptureState>>,
     preferred_label: Option<&str>,
     preferred_normalized: Option<&str>,
-) -> Result<(ActiveRecording, String), RecordingError> {
+    avoid: Option<&str>,
+) -> Result<(OpenStream, String), RecordingError> {
     let default_output_name = host
         .default_output_device()
         .and_then(|device| device.name().ok());
 
     let mut candidates =
         device_candidates_for_host(host, default_output_name.as_deref(), preferred_normalized);
-    candidates.sort_by_key(|candidate| (!candidate.matches_preferred, candidate.priority));
+    rank_candidates(&mut candidates, avoid);
 
     let mut last_err: Option<RecordingError> = None;
 
@@ -673,31 +1429,18 @@ fn start_recording_on_host(
 
         let sample_format = config.sample_format();
         let stream_config: StreamConfig = config.into();
-        let sample_rate = stream_config.sample_rate.0;
-        let buffer = Arc::new(Mutex::new(Vec::<f32>::new()));
+        let sample_rate = capture_sample_rate(capture, &stream_config);
 
         let stream_result = match sample_format {
-            SampleFormat::I16 => build_input_stream::<i16>(
-                &device,
-                &stream_config,
-                buffer.clone(),
-                level_emitter.clone(),
-                chunk_emitter.clone(),
-            ),
-            SampleFormat::U16 => build_input_stream::<u16>(
-                &device,
-                &stream_config,
-                buffer.clone(),
-                level_emitter.clone(),
-                chunk_emitter.clone(),
-            ),
-            SampleFormat::F32 => build_input_stream::<f32>(
-                &device,
-                &stream_config,
-                buffer.clone(),
-                level_emitter.clone(),
-                chunk_emitter.clone(),
-            ),
+            SampleFormat::I16 => {
+                build_input_stream::<i16>(&device, &stream_config, capture.clone(), sample_rate)
+            }
+            SampleFormat::U16 => {
+                build_input_stream::<u16>(&device, &stream_config, capture.clone(), sample_rate)
+            }
+            SampleFormat::F32 => {
+                build_input_stream::<f32>(&device, &stream_config, capture.clone(), sample_rate)
+            }
             other => {
                 eprintln!("[recording] device '{label}' has unsupported sample format: {other:?}");
                 last_err = Some(RecordingError::UnsupportedFormat(other));
@@ -705,8 +1448,8 @@ fn start_recording_on_host(
             }
         };
 
-        let stream = match stream_result {
-            Ok(stream) => stream,
+        let (stream, error) = match stream_result {
+            Ok(built) => built,
             Err(err) => {
                 eprintln!("[recording] failed to build stream for '{label}': {err}");
                 last_err = Some(err);
@@ -737,15 +1480,15 @@ fn start_recording_on_host(
             );
         }
 
+        lock_capture(capture).sample_rate = Some(sample_rate);
         let device_name_for_cache = name.clone().unwrap_or_else(|| label.to_string());
         return Ok((
-            ActiveRecording {
-                _stream: stream,
-                start: Instant::now(),
-                buffer,
+            OpenStream {
+                stream,
+                capture: capture.clone(),
                 sample_rate,
-                _level_emitter: level_emitter.clone(),
-                _chunk_emitter: chunk_emitter.clone(),
+                device_name: label.to_string(),
+                error,
             },
             device_name_for_cache,
         ));
@@ -754,8 +1497,8 @@ fn start_recording_on_host(
     Err(last_err.unwrap_or(RecordingError::InputDeviceUnavailable))
 }
 
-struct DeviceCandidate {
-    device: Device,
+struct DeviceCandidate<D = Device> {
+    device: D,
     name: Option<String>,
     _normalized_name: Option<String>,
     priority: u32,
@@ -764,6 +1507,17 @@ struct DeviceCandidate {
     is_default: bool,
 }
 
+/// Order `candidates` to be tried: the preferred device first, then by
+/// priority, leaving out the device named `avoid`.
+fn rank_candidates<D>(candidates: &mut Vec<DeviceCandidate<D>>, avoid: Option<&str>) {
+    if let Some(avoid) = avoid {
+        let avoid = avoid.trim().to_ascii_lowercase();
+        candidates
+            .retain(|candidate| candidate._normalized_name.as_deref() != Some(avoid.as_str()));
+    }
+    candidates.sort_by_key(|candidate| (!candidate.matches_preferred, candidate.priority));
+}
+
 /// Check if a device name matches the preferred name.
 /// On Linux, also checks the friendly name.
 fn device_matches_preferred(device_name: &str, preferred_lower: &str) -> bool {
@@ -880,10 +1634,20 @@ fn device_candidates_for_host(
         }
     }
 
+    // Calibration results outrank what the names suggest.
+    for candidate in &mut candidates {
+        if candidate.matches_preferred {
+            continue;
+        }
+        if let Some(measured) = candidate.name.as_deref().and_then(measured_input) {
+            (candidate.priority, candidate.avoid_reason) = measured_priority(measured.score);
+        }
+    }
+
     candidates
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct InputDeviceDescriptor {
     pub label: String,
     pub is_default: bool,
@@ -958,21 +1722,58 @@ pub fn list_input_devices() -> Vec<InputDeviceDescriptor> {
     list
 }
 
+/// The rate a stream writing into `capture` should deliver: the capture's
+/// own if it already holds audio from another stream, otherwise the device's.
+fn capture_sample_rate(capture: &Mutex<CaptureState>, config: &StreamConfig) -> u32 {
+    lock_capture(capture)
+        .sample_rate
+        .unwrap_or(config.sample_rate.0)
+}
+
+/// Record `duration` from the input device labelled `label`, as listed by
+/// `list_input_devices`. The input gain is not applied.
+pub fn record_from_device(
+    label: &str,
+    duration: Duration,
+) -> Result<RecordedAudio, RecordingError> {
+    for host_id in ordered_host_ids() {
+        let Ok(host) = cpal::host_from_id(host_id) else {
+            continue;
+        };
+        let Some(device) = find_device_by_name(&host, label) else {
+            continue;
+        };
+
+        let capture = Arc::new(Mutex::new(CaptureState::default()));
+        let input = try_start_on_device(&device, Some(label), &capture)?;
+        std::thread::sleep(duration);
+        let sample_rate = input.sample_rate;
+        drop(input);
+
+        let samples = Vec::from(std::mem::take(&mut lock_capture(&capture).samples));
+        return Ok(RecordedAudio {
+            samples,
+            sample_rate,
+        });
+    }
+
+    Err(RecordingError::InputDeviceUnavailable)
+}
+
 fn build_input_stream<T>(
     device: &Device,
     config: &StreamConfig,
-    buffer: Arc<Mutex<Vec<f32>>>,
-    level_emitter: Option<Arc<LevelEmitter>>,
-    chunk_emitter: Option<Arc<ChunkEmitter>>,
-) -> Result<Stream, RecordingError>
+    capture: Arc<Mutex<CaptureState>>,
+    sample_rate: u32,
+) -> Result<(Stream, Arc<OnceLock<String>>), RecordingError>
 where
     T: cpal::Sample + cpal::SizedSample,
     f32: cpal::FromSample<T>,
 {
     let channel_count = cmp::max(config.channels as usize, 1);
-    let callback_buffer = buffer.clone();
-    let level_emitter_ref = level_emitter;
-    let chunk_emitter_ref = chunk_emitter;
+    let device_rate = config.sample_rate.0;
+    let error = Arc::new(OnceLock::new());
+    let stream_error = Arc::clone(&error);
     device
         .build_input_stream(
             config,
@@ -1005,27 +1806,55 @@ where
                     }
                 }
 
-                if let Some(ref level_emitter) = level_emitter_ref {
+                if device_rate != sample_rate {
+                    mono_samples = resample_to_sample_rate(&mono_samples, device_rate, sample_rate);
+                }
+
+                // Emit outside the lock so a slow callback never stalls capture.
+                let (level_emitter, chunk_emitter, listener) = match capture.lock() {
+                    Ok(mut state) => {
+                        state.apply_gain(&mut mono_samples);
+                        state.push(&mono_samples);
+                        (
+                            state.level_emitter.clone(),
+                            state.chunk_emitter.clone(),
+                            state.listener.clone(),
+                        )
+                    }
+                    Err(_) => return,
+                };
+
+                if let Some(level_emitter) = level_emitter {
                     level_emitter.emit(&mono_samples);
                 }
 
-                if let Some(ref chunk_emitter) = chunk_emitter_ref {
+                if let Some(chunk_emitter) = chunk_emitter {
                     chunk_emitter.emit(&mono_samples);
                 }
 
-                if let Ok(mut shared_buffer) = callback_buffer.lock() {
-                    shared_buffer.extend_from_slice(&mono_samples);
+                if let Some(listener) = listener {
+                    listener(mono_samples);
                 }
             },
-            |err| eprintln!("[recording] stream error: {err}"),
+            move |err| {
+                eprintln!("[recording] stream error: {err}");
+                let _ = stream_error.set(err.to_string());
+            },
             None,
         )
+        .map(|stream| (stream, error))
         .map_err(|err| RecordingError::StreamBuild(err.to_string()))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::is_preferred_input_device_name;
+    use super::{
+        is_preferred_input_device_name, measured_priority, rank_candidates, Backoff, CaptureState,
+        DeviceCandidate, STANDBY_RETRY_MAX, STANDBY_RETRY_MIN, STREAM_STALL_TIMEOUT,
+        WAKE_PIN_TIMEOUT,
+    };
+    use std::sync::Arc;
+    use std::time::Instant;
 
     #[test]
     fn preferred_name_blocks_low_quality_keywords() {
@@ -1044,4 +1873,152 @@ mod tests {
     fn preferred_name_requires_microphone_context() {
         assert!(!is_preferred_input_device_name("USB Audio Device"));
     }
+
+    #[test]
+    fn standby_holds_the_latest_pre_roll() {
+        let mut capture = CaptureState {
+            standby_limit: Some(4),
+            ..CaptureState::default()
+        };
+        capture.attach_listener(Some(Arc::new(|_| {})));
+        capture.push(&[1.0, 2.0, 3.0]);
+        capture.push(&[4.0, 5.0, 6.0]);
+        assert_eq!(capture.samples, [3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(capture.heard, 6);
+
+        // Sample 4 onwards is what came after the wake phrase started.
+        capture.discard_before(4);
+        assert_eq!(capture.samples, [5.0, 6.0]);
+        capture.discard_before(1);
+        assert_eq!(capture.samples, [5.0, 6.0]);
+
+        // The pin outranks the warm pre-roll while its audio is still held.
+        capture.begin_recording(1, Instant::now());
+        assert_eq!(capture.samples, [5.0, 6.0]);
+        assert_eq!(capture.standby_limit, None);
+    }
+
+    #[test]
+    fn an_ignored_wake_pin_expires() {
+        let mut capture = CaptureState {
+            standby_limit: Some(4),
+            ..CaptureState::default()
+        };
+        capture.attach_listener(Some(Arc::new(|_| {})));
+        capture.push(&[1.0, 2.0, 3.0, 4.0]);
+        capture.discard_before(1);
+        capture.begin_recording(1, Instant::now() + WAKE_PIN_TIMEOUT * 2);
+        assert_eq!(capture.samples, [4.0]);
+    }
+
+    #[test]
+    fn recording_keeps_only_the_warm_pre_roll_without_a_pin() {
+        let mut capture = CaptureState {
+            standby_limit: Some(4),
+            ..CaptureState::default()
+        };
+        capture.attach_listener(Some(Arc::new(|_| {})));
+        capture.push(&[1.0, 2.0, 3.0]);
+        capture.discard_before(2);
+        // The pinned sample has since fallen out of the buffer.
+        capture.push(&[4.0, 5.0, 6.0, 7.0]);
+        capture.begin_recording(2, Instant::now());
+        assert_eq!(capture.samples, [6.0, 7.0]);
+
+        let mut capture = CaptureState {
+            standby_limit: Some(4),
+            ..CaptureState::default()
+        };
+        capture.push(&[1.0, 2.0, 3.0]);
+        capture.begin_recording(0, Instant::now());
+        assert!(capture.samples.is_empty());
+    }
+
+    #[test]
+    fn standby_reopening_backs_off_until_it_succeeds() {
+        let now = Instant::now();
+        let mut backoff = Backoff::default();
+        assert!(backoff.is_ready(now));
+
+        backoff.failed(now);
+        assert!(!backoff.is_ready(now + STANDBY_RETRY_MIN / 2));
+        assert!(backoff.is_ready(now + STANDBY_RETRY_MIN));
+        backoff.failed(now);
+        assert!(!backoff.is_ready(now + STANDBY_RETRY_MIN));
+        for _ in 0..10 {
+            backoff.failed(now);
+        }
+        assert!(backoff.is_ready(now + STANDBY_RETRY_MAX));
+
+        backoff.succeeded();
+        assert!(backoff.is_ready(now));
+    }
+
+    #[test]
+    fn failed_streams_are_detected_by_errors_or_silence() {
+        let now = Instant::now();
+        let capture = CaptureState {
+            last_push: Some(now),
+            ..CaptureState::default()
+        };
+        assert_eq!(capture.failure(None, now), None);
+        assert!(capture.failure(None, now + STREAM_STALL_TIMEOUT).is_some());
+
+        let error = "device removed".to_string();
+        assert_eq!(
+            capture.failure(Some(&error), now).as_deref(),
+            Some("device removed")
+        );
+    }
+
+    fn candidate(name: &str, priority: u32, matches_preferred: bool) -> DeviceCandidate<()> {
+        DeviceCandidate {
+            device: (),
+            name: Some(name.to_string()),
+            _normalized_name: Some(name.to_ascii_lowercase()),
+            priority,
+            avoid_reason: None,
+            matches_preferred,
+            is_default: false,
+        }
+    }
+
+    #[test]
+    fn failover_skips_the_failed_device() {
+        let names = |candidates: &[DeviceCandidate<()>]| {
+            candidates
+                .iter()
+                .map(|candidate| candidate.name.clone().unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+        let candidates = || {
+            vec![
+                candidate("USB Mic", 10, false),
+                candidate("Headset", 250, true),
+                candidate("Built-in Microphone", 0, false),
+            ]
+        };
+
+        let mut ranked = candidates();
+        rank_candidates(&mut ranked, None);
+        assert_eq!(
+            names(&ranked),
+            ["Headset", "Built-in Microphone", "USB Mic"]
+        );
+
+        let mut ranked = candidates();
+        rank_candidates(&mut ranked, Some(" headset "));
+        assert_eq!(names(&ranked), ["Built-in Microphone", "USB Mic"]);
+    }
+
+    #[test]
+    fn measured_quality_ranks_devices() {
+        let (best, best_reason) = measured_priority(95.0);
+        let (worse, _) = measured_priority(70.0);
+        let (poor, poor_reason) = measured_priority(20.0);
+        assert!(best < worse && worse < poor);
+        assert_eq!(best_reason, None);
+        assert_eq!(poor_reason.as_deref(), Some("measured quality 20/100"));
+        assert_eq!(measured_priority(150.0).0, 1);
+    }
 }
@@ -1,17 +1,19 @@
 use crate::domain::{KeysHeldPayload, EVT_KEYS_HELD};
+use crate::platform::keyboard_ipc::{
+    generate_token, read_frame, verify_hello, write_frame, Frame, FrameBody,
+    KeyboardEventPayload, ProtocolError, RestartBackoff, WireEventKind,
+};
 use rdev::{Event, EventType, Key as RdevKey};
 use std::collections::HashSet;
 use std::env;
-use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
-use std::net::{TcpListener, TcpStream};
-use std::process::{Child, Command, Stdio};
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::process::{Child, ChildStdout, Command, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::thread::{self, JoinHandle};
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tauri::{AppHandle, Emitter, EventTarget};
 
-use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
 type PressedKeys = Arc<Mutex<HashSet<String>>>;
@@ -136,26 +138,16 @@ pub fn stop_key_listener() -> Result<(), String> {
     Ok(())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-enum WireEventKind {
-    Press,
-    Release,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct KeyboardEventPayload {
-    kind: WireEventKind,
-    key_label: String,
-    raw_code: Option<u32>,
-    #[serde(default)]
-    scan_code: u32,
-}
-
 fn debug_keys_enabled() -> bool {
     static DEBUG: OnceLock<bool> = OnceLock::new();
     *DEBUG.get_or_init(|| matches!(env::var("OSVOICE_DEBUG_KEYS"), Ok(value) if value == "1"))
 }
 
+/// A listener child that stays up this long is considered healthy, so the
+/// next crash restarts it without waiting out the accumulated backoff.
+const HEALTHY_CHILD_RUNTIME: Duration = Duration::from_secs(10);
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 fn child_store() -> &'static Mutex<Option<Child>> {
     static CHILD: OnceLock<Mutex<Option<Child>>> = OnceLock::new();
     CHILD.get_or_init(|| Mutex::new(None))
@@ -164,68 +156,80 @@ fn child_store() -> &'static Mutex<Option<Child>> {
 fn start_external_listener(
     emitter: Arc<KeyEventEmitter>,
 ) -> Result<(JoinHandle<()>, Arc<AtomicBool>), String> {
-    let listener = TcpListener::bind(("127.0.0.1", 0))
-        .map_err(|err| format!("failed to bind keyboard listener socket: {err}"))?;
-    listener
-        .set_nonblocking(true)
-        .map_err(|err| format!("failed to configure keyboard listener socket: {err}"))?;
-    let port = listener
-        .local_addr()
-        .map_err(|err| format!("failed to read listener address: {err}"))?
-        .port();
-
     let running = Arc::new(AtomicBool::new(true));
     let thread_running = running.clone();
     let thread_emitter = emitter.clone();
 
-    let handle = thread::spawn(move || {
-        run_listener_thread(listener, port, thread_running, thread_emitter);
-    });
+    let handle = thread::Builder::new()
+        .name("keyboard-listener-supervisor".to_string())
+        .spawn(move || {
+            supervise_listener_child(thread_running, thread_emitter);
+        })
+        .map_err(|err| format!("failed to start keyboard listener supervisor: {err}"))?;
 
     Ok((handle, running))
 }
 
-fn run_listener_thread(
-    listener: TcpListener,
-    port: u16,
-    running: Arc<AtomicBool>,
-    emitter: Arc<KeyEventEmitter>,
-) {
+/// Keep a listener child alive for as long as `running` is set, restarting it
+/// with exponential backoff whenever it exits or breaks protocol.
+fn supervise_listener_child(running: Arc<AtomicBool>, emitter: Arc<KeyEventEmitter>) {
+    let mut backoff = RestartBackoff::new();
+
     while running.load(Ordering::SeqCst) {
-        if let Err(err) = ensure_listener_child(port) {
-            eprintln!("Keyboard listener child error: {err}");
-            thread::sleep(Duration::from_millis(500));
-            continue;
-        }
+        let token = generate_token();
+        let launched_at = Instant::now();
 
-        match listener.accept() {
-            Ok((stream, _addr)) => {
-                if let Err(err) = pump_stream(stream, emitter.clone()) {
+        match spawn_listener_child(&token, &running) {
+            Ok(stdout) => {
+                if let Err(err) = pump_child(stdout, &token, &emitter) {
                     eprintln!("Keyboard listener stream error: {err}");
                 }
             }
-            Err(err) if err.kind() == ErrorKind::WouldBlock => {
-                thread::sleep(Duration::from_millis(50));
-            }
-            Err(err) => {
-                eprintln!("Keyboard listener accept error: {err}");
-                thread::sleep(Duration::from_millis(200));
-            }
+            Err(err) => eprintln!("Keyboard listener child error: {err}"),
+        }
+
+        let status = stop_listener_child();
+        if !running.load(Ordering::SeqCst) {
+            break;
         }
+
+        // Whatever the dead child reported as held can no longer be released.
+        emitter.reset();
+
+        if launched_at.elapsed() >= HEALTHY_CHILD_RUNTIME {
+            backoff.reset();
+        }
+        let delay = backoff.next_delay();
+        eprintln!(
+            "Keyboard listener child exited ({}); restarting in {delay:?}",
+            status.as_deref().unwrap_or("not running")
+        );
+        sleep_while_running(delay, &running);
     }
 
     stop_listener_child();
 }
-fn spawn_listener_child(port: u16) -> Result<Child, String> {
+
+fn sleep_while_running(duration: Duration, running: &AtomicBool) {
+    let deadline = Instant::now() + duration;
+    while running.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        thread::sleep(SUPERVISOR_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+fn spawn_listener_child(token: &str, running: &AtomicBool) -> Result<ChildStdout, String> {
     let exe = std::env::current_exe()
         .map_err(|err| format!("failed to resolve current executable: {err}"))?;
 
     let mut command = Command::new(exe);
     command
         .env("OSVOICE_KEYBOARD_LISTENER", "1")
-        .env("OSVOICE_KEYBOARD_PORT", port.to_string())
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
         .stderr(Stdio::inherit());
 
     #[cfg(target_os = "windows")]
@@ -235,97 +239,98 @@ fn spawn_listener_child(port: u16) -> Result<Child, String> {
         command.creation_flags(CREATE_NO_WINDOW);
     }
 
-    command
-        .spawn()
-        .map_err(|err| format!("failed to spawn keyboard listener process: {err}"))
-}
-
-fn ensure_listener_child(port: u16) -> Result<(), String> {
-    let should_spawn = {
-        let mut guard = child_store()
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
-
-        if let Some(child) = guard.as_mut() {
-            match child.try_wait() {
-                Ok(Some(_status)) => {
-                    *guard = None;
-                    true
-                }
-                Ok(None) => {
-                    return Ok(());
-                }
-                Err(err) => {
-                    eprintln!("Keyboard listener child wait failed: {err}");
-                    *guard = None;
-                    true
-                }
-            }
-        } else {
-            true
-        }
-    };
-
-    if !should_spawn {
-        return Ok(());
-    }
-
-    let child = spawn_listener_child(port)?;
+    // Hold the store lock across the running check and the spawn so a
+    // concurrent `stop_key_listener` either sees this child or stops us first.
     let mut guard = child_store()
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !running.load(Ordering::SeqCst) {
+        return Err("keyboard listener is stopping".to_string());
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|err| format!("failed to spawn keyboard listener process: {err}"))?;
+
+    let handshake = child
+        .stdin
+        .take()
+        .ok_or_else(|| "keyboard listener stdin unavailable".to_string())
+        .and_then(|mut stdin| {
+            writeln!(stdin, "{token}")
+                .and_then(|_| stdin.flush())
+                .map_err(|err| format!("failed to send keyboard listener token: {err}"))
+        });
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "keyboard listener stdout unavailable".to_string());
+
     *guard = Some(child);
-    Ok(())
+    handshake?;
+    stdout
 }
 
-fn stop_listener_child() {
+/// Kill and reap the current listener child, returning its exit status.
+fn stop_listener_child() -> Option<String> {
     let mut guard = child_store()
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
-    if let Some(mut child) = guard.take() {
-        if let Err(err) = child.kill() {
-            eprintln!("Failed to kill keyboard listener child: {err}");
-        }
-        if let Err(err) = child.wait() {
+    let mut child = guard.take()?;
+
+    if let Ok(Some(status)) = child.try_wait() {
+        return Some(status.to_string());
+    }
+
+    if let Err(err) = child.kill() {
+        eprintln!("Failed to kill keyboard listener child: {err}");
+    }
+    match child.wait() {
+        Ok(status) => Some(status.to_string()),
+        Err(err) => {
             eprintln!("Failed to wait for keyboard listener child: {err}");
+            None
         }
     }
 }
 
-fn pump_stream(stream: TcpStream, emitter: Arc<KeyEventEmitter>) -> Result<(), String> {
-    stream
-        .set_nodelay(true)
-        .map_err(|err| format!("failed to configure keyboard stream: {err}"))?;
-    stream
-        .set_nonblocking(false)
-        .map_err(|err| format!("failed to set blocking mode for keyboard stream: {err}"))?;
-
-    let reader = BufReader::new(stream);
-    let lines = reader.lines();
-    for line in lines {
-        let line = line.map_err(|err| format!("failed to read keyboard event: {err}"))?;
-        if line.trim().is_empty() {
+fn pump_child(
+    stdout: ChildStdout,
+    token: &str,
+    emitter: &KeyEventEmitter,
+) -> Result<(), ProtocolError> {
+    let mut reader = BufReader::new(stdout);
+
+    let hello = read_frame(&mut reader)?.ok_or_else(|| {
+        ProtocolError::Io(std::io::Error::new(
+            ErrorKind::UnexpectedEof,
+            "listener exited before handshake",
+        ))
+    })?;
+    verify_hello(&hello, token)?;
+
+    while let Some(frame) = read_frame(&mut reader)? {
+        let payload = match frame.body {
+            FrameBody::Key(payload) => payload,
+            FrameBody::Hello { .. } => {
+                eprintln!("Ignoring repeated keyboard listener handshake");
+                continue;
+            }
+        };
+
+        #[cfg(target_os = "windows")]
+        if payload.scan_code == 0 {
+            if debug_keys_enabled() {
+                eprintln!(
+                    "[keys] Ignoring injected event (scan_code=0): {:?} {}",
+                    payload.kind, payload.key_label
+                );
+            }
             continue;
         }
 
-        match serde_json::from_str::<KeyboardEventPayload>(&line) {
-            Ok(payload) => {
-                #[cfg(target_os = "windows")]
-                if payload.scan_code == 0 {
-                    if debug_keys_enabled() {
-                        eprintln!(
-                            "[keys] Ignoring injected event (scan_code=0): {:?} {}",
-                            payload.kind, payload.key_label
-                        );
-                    }
-                    continue;
-                }
-
-                if let Some(event) = event_from_payload(payload) {
-                    emitter.handle_event(&event);
-                }
-            }
-            Err(err) => eprintln!("Malformed keyboard event payload: {err}: {line}"),
+        if let Some(event) = event_from_payload(payload) {
+            emitter.handle_event(&event);
         }
     }
 
@@ -393,18 +398,21 @@ fn key_raw_code(key: RdevKey) -> Option<u32> {
 }
 
 pub fn run_listener_process() -> Result<(), String> {
-    let port = env::var("OSVOICE_KEYBOARD_PORT")
-        .map_err(|_| "OSVOICE_KEYBOARD_PORT env var missing".to_string())?
-        .parse::<u16>()
-        .map_err(|err| format!("invalid OSVOICE_KEYBOARD_PORT: {err}"))?;
+    let mut token = String::new();
+    std::io::stdin()
+        .read_line(&mut token)
+        .map_err(|err| format!("keyboard listener failed to read token: {err}"))?;
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("keyboard listener token missing on stdin".to_string());
+    }
 
-    let stream = TcpStream::connect(("127.0.0.1", port))
-        .map_err(|err| format!("keyboard listener failed to connect: {err}"))?;
-    stream
-        .set_nodelay(true)
-        .map_err(|err| format!("failed to configure listener socket: {err}"))?;
+    let mut writer = BufWriter::new(std::io::stdout());
+    write_frame(&mut writer, &Frame::hello(token))
+        .and_then(|_| writer.flush().map_err(ProtocolError::from))
+        .map_err(|err| format!("keyboard listener handshake failed: {err}"))?;
 
-    let writer = Arc::new(Mutex::new(BufWriter::new(stream)));
+    let writer = Arc::new(Mutex::new(writer));
 
     let result = rdev::listen({
         let writer = writer.clone();
@@ -426,16 +434,14 @@ pub fn run_listener_process() -> Result<(), String> {
             };
 
             if let Some(payload) = payload {
-                if let Ok(json) = serde_json::to_string(&payload) {
-                    if let Ok(mut guard) = writer.lock() {
-                        if let Err(err) = writeln!(guard, "{json}") {
-                            eprintln!("Keyboard listener write error: {err}");
-                            std::process::exit(1);
-                        }
-                        if let Err(err) = guard.flush() {
-                            eprintln!("Keyboard listener flush error: {err}");
-                            std::process::exit(1);
-                        }
+                if let Ok(mut guard) = writer.lock() {
+                    if let Err(err) = write_frame(&mut *guard, &Frame::key(payload)) {
+                        eprintln!("Keyboard listener write error: {err}");
+                        std::process::exit(1);
+                    }
+                    if let Err(err) = guard.flush() {
+                        eprintln!("Keyboard listener flush error: {err}");
+                        std::process::exit(1);
                     }
                 }
             }
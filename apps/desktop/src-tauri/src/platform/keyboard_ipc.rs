@@ -0,0 +1,310 @@
+//! Wire protocol between the app and the keyboard listener child process.
+//!
+//! The child inherits a pipe on stdin/stdout instead of connecting to a local
+//! socket, so no other process can reach the channel. On launch the parent
+//! writes a random per-launch token to the child's stdin; the child must echo
+//! it back in a `hello` frame before any key events are accepted.
+//!
+//! Every frame is a big-endian `u32` length prefix followed by a JSON body
+//! carrying the protocol version.
+
+use base64::{engine::general_purpose, Engine as _};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::io::{self, ErrorKind, Read, Write};
+use std::time::Duration;
+
+pub const PROTOCOL_VERSION: u16 = 1;
+pub const MAX_FRAME_LEN: usize = 16 * 1024;
+
+const TOKEN_BYTES: usize = 32;
+const LENGTH_PREFIX_LEN: usize = 4;
+
+const INITIAL_RESTART_DELAY: Duration = Duration::from_millis(250);
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProtocolError {
+    #[error("keyboard listener pipe error: {0}")]
+    Io(#[from] io::Error),
+    #[error("keyboard listener frame too large: {0} bytes (max {MAX_FRAME_LEN})")]
+    FrameTooLarge(usize),
+    #[error("malformed keyboard listener frame: {0}")]
+    Malformed(String),
+    #[error("keyboard listener protocol version {found} does not match expected {PROTOCOL_VERSION}")]
+    VersionMismatch { found: u16 },
+    #[error("keyboard listener failed token handshake")]
+    BadToken,
+    #[error("keyboard listener sent {0} before completing the handshake")]
+    UnexpectedFrame(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WireEventKind {
+    Press,
+    Release,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyboardEventPayload {
+    pub kind: WireEventKind,
+    pub key_label: String,
+    pub raw_code: Option<u32>,
+    #[serde(default)]
+    pub scan_code: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrameBody {
+    Hello { token: String },
+    Key(KeyboardEventPayload),
+}
+
+impl FrameBody {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Hello { .. } => "hello",
+            Self::Key(_) => "key",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Frame {
+    pub version: u16,
+    #[serde(flatten)]
+    pub body: FrameBody,
+}
+
+impl Frame {
+    pub fn hello(token: &str) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            body: FrameBody::Hello {
+                token: token.to_string(),
+            },
+        }
+    }
+
+    pub fn key(payload: KeyboardEventPayload) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            body: FrameBody::Key(payload),
+        }
+    }
+}
+
+/// Generate a fresh token for one listener launch.
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn write_frame<W: Write>(writer: &mut W, frame: &Frame) -> Result<(), ProtocolError> {
+    let body =
+        serde_json::to_vec(frame).map_err(|err| ProtocolError::Malformed(err.to_string()))?;
+    if body.len() > MAX_FRAME_LEN {
+        return Err(ProtocolError::FrameTooLarge(body.len()));
+    }
+
+    writer.write_all(&(body.len() as u32).to_be_bytes())?;
+    writer.write_all(&body)?;
+    Ok(())
+}
+
+/// Read the next frame. Returns `Ok(None)` when the pipe closes cleanly on a
+/// frame boundary.
+pub fn read_frame<R: Read>(reader: &mut R) -> Result<Option<Frame>, ProtocolError> {
+    let mut prefix = [0u8; LENGTH_PREFIX_LEN];
+    let mut filled = 0;
+    while filled < LENGTH_PREFIX_LEN {
+        match reader.read(&mut prefix[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => {
+                return Err(ProtocolError::Io(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "truncated frame length",
+                )))
+            }
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    let len = u32::from_be_bytes(prefix) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(ProtocolError::FrameTooLarge(len));
+    }
+
+    let mut body = vec![0u8; len];
+    reader.read_exact(&mut body)?;
+
+    let frame: Frame =
+        serde_json::from_slice(&body).map_err(|err| ProtocolError::Malformed(err.to_string()))?;
+    if frame.version != PROTOCOL_VERSION {
+        return Err(ProtocolError::VersionMismatch {
+            found: frame.version,
+        });
+    }
+
+    Ok(Some(frame))
+}
+
+/// Check that the first frame from the child is a `hello` carrying the token
+/// handed to it at launch.
+pub fn verify_hello(frame: &Frame, expected_token: &str) -> Result<(), ProtocolError> {
+    match &frame.body {
+        FrameBody::Hello { token } => {
+            if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) {
+                Ok(())
+            } else {
+                Err(ProtocolError::BadToken)
+            }
+        }
+        other => Err(ProtocolError::UnexpectedFrame(other.label())),
+    }
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// Exponential restart delay for the supervised listener child.
+#[derive(Debug)]
+pub struct RestartBackoff {
+    next: Duration,
+}
+
+impl Default for RestartBackoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RestartBackoff {
+    pub fn new() -> Self {
+        Self {
+            next: INITIAL_RESTART_DELAY,
+        }
+    }
+
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(MAX_RESTART_DELAY);
+        delay
+    }
+
+    pub fn reset(&mut self) {
+        self.next = INITIAL_RESTART_DELAY;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn sample_payload() -> KeyboardEventPayload {
+        KeyboardEventPayload {
+            kind: WireEventKind::Press,
+            key_label: "ControlLeft".to_string(),
+            raw_code: None,
+            scan_code: 29,
+        }
+    }
+
+    #[test]
+    fn frames_round_trip_through_the_pipe() {
+        let mut buffer = Vec::new();
+        write_frame(&mut buffer, &Frame::hello("token")).unwrap();
+        write_frame(&mut buffer, &Frame::key(sample_payload())).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+        assert_eq!(read_frame(&mut reader).unwrap(), Some(Frame::hello("token")));
+        assert_eq!(
+            read_frame(&mut reader).unwrap(),
+            Some(Frame::key(sample_payload()))
+        );
+        assert_eq!(read_frame(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn truncated_frames_are_errors() {
+        let mut buffer = Vec::new();
+        write_frame(&mut buffer, &Frame::key(sample_payload())).unwrap();
+        buffer.truncate(buffer.len() - 3);
+
+        assert!(matches!(
+            read_frame(&mut Cursor::new(buffer)),
+            Err(ProtocolError::Io(_))
+        ));
+        assert!(matches!(
+            read_frame(&mut Cursor::new(vec![0u8, 0])),
+            Err(ProtocolError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn oversized_length_prefix_is_rejected_before_allocating() {
+        let prefix = ((MAX_FRAME_LEN + 1) as u32).to_be_bytes().to_vec();
+        assert!(matches!(
+            read_frame(&mut Cursor::new(prefix)),
+            Err(ProtocolError::FrameTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn version_mismatch_is_rejected() {
+        let body = br#"{"version":99,"type":"hello","token":"t"}"#;
+        let mut buffer = (body.len() as u32).to_be_bytes().to_vec();
+        buffer.extend_from_slice(body);
+
+        assert!(matches!(
+            read_frame(&mut Cursor::new(buffer)),
+            Err(ProtocolError::VersionMismatch { found: 99 })
+        ));
+    }
+
+    #[test]
+    fn handshake_requires_matching_token() {
+        let token = generate_token();
+        assert!(verify_hello(&Frame::hello(&token), &token).is_ok());
+        assert!(matches!(
+            verify_hello(&Frame::hello("forged"), &token),
+            Err(ProtocolError::BadToken)
+        ));
+        assert!(matches!(
+            verify_hello(&Frame::key(sample_payload()), &token),
+            Err(ProtocolError::UnexpectedFrame("key"))
+        ));
+    }
+
+    #[test]
+    fn tokens_are_unique_per_launch() {
+        assert_ne!(generate_token(), generate_token());
+    }
+
+    #[test]
+    fn restart_backoff_doubles_up_to_cap_and_resets() {
+        let mut backoff = RestartBackoff::new();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(250));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+        for _ in 0..20 {
+            backoff.next_delay();
+        }
+        assert_eq!(backoff.next_delay(), MAX_RESTART_DELAY);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(250));
+    }
+}
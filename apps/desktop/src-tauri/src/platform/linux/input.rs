@@ -1,3 +1,4 @@
+use crate::domain::{EditKey, KeyChord};
 use enigo::{Enigo, Key, KeyboardControllable};
 use std::{env, thread, time::Duration};
 
@@ -67,3 +68,63 @@ fn paste_via_clipboard(text: &str, keybind: Option<&str>) -> Result<(), String>
 
     Ok(())
 }
+
+/// Type text key by key, for fields that mangle or block clipboard pastes.
+pub(crate) fn type_text_into_focused_field(text: &str) -> Result<(), String> {
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    let mut enigo = Enigo::new();
+    enigo.key_up(Key::Shift);
+    enigo.key_up(Key::Control);
+    enigo.key_up(Key::Alt);
+    thread::sleep(Duration::from_millis(30));
+    enigo.key_sequence(text);
+    Ok(())
+}
+
+pub(crate) fn send_key_chord(chord: &KeyChord) -> Result<(), String> {
+    let (key, needs_control) = match chord.key {
+        EditKey::Enter => (Key::Return, false),
+        EditKey::Tab => (Key::Tab, false),
+        EditKey::Backspace => (Key::Backspace, false),
+        EditKey::Delete => (Key::Delete, false),
+        EditKey::Escape => (Key::Escape, false),
+        EditKey::Left => (Key::LeftArrow, false),
+        EditKey::Right => (Key::RightArrow, false),
+        EditKey::Up => (Key::UpArrow, false),
+        EditKey::Down => (Key::DownArrow, false),
+        EditKey::LineStart => (Key::Home, false),
+        EditKey::LineEnd => (Key::End, false),
+        EditKey::DocumentStart => (Key::Home, true),
+        EditKey::DocumentEnd => (Key::End, true),
+        EditKey::Letter(ch) => (Key::Layout(ch), false),
+    };
+    let control = needs_control || chord.modifiers.primary || chord.modifiers.word;
+
+    let mut enigo = Enigo::new();
+    enigo.key_up(Key::Shift);
+    enigo.key_up(Key::Control);
+    enigo.key_up(Key::Alt);
+    thread::sleep(Duration::from_millis(30));
+
+    if control {
+        enigo.key_down(Key::Control);
+    }
+    if chord.modifiers.shift {
+        enigo.key_down(Key::Shift);
+    }
+    for _ in 0..chord.repeat.max(1) {
+        enigo.key_click(key);
+        thread::sleep(Duration::from_millis(5));
+    }
+    if chord.modifiers.shift {
+        enigo.key_up(Key::Shift);
+    }
+    if control {
+        enigo.key_up(Key::Control);
+    }
+
+    Ok(())
+}
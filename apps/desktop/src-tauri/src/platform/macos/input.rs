@@ -1,8 +1,19 @@
+use crate::domain::{EditKey, KeyChord};
 use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
 use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 use std::{thread, time::Duration};
 
 const KEY_V: CGKeyCode = 9;
+const KEY_RETURN: CGKeyCode = 36;
+const KEY_TAB: CGKeyCode = 48;
+const KEY_DELETE: CGKeyCode = 51;
+const KEY_ESCAPE: CGKeyCode = 53;
+const KEY_FORWARD_DELETE: CGKeyCode = 117;
+const KEY_LEFT_ARROW: CGKeyCode = 123;
+const KEY_RIGHT_ARROW: CGKeyCode = 124;
+const KEY_DOWN_ARROW: CGKeyCode = 125;
+const KEY_UP_ARROW: CGKeyCode = 126;
+const TYPE_CHUNK_CHARS: usize = 10;
 
 pub(crate) fn paste_text_into_focused_field(text: &str, _keybind: Option<&str>) -> Result<(), String> {
     if text.trim().is_empty() {
@@ -34,6 +45,30 @@ fn paste_via_clipboard(text: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Type text key by key, for fields that mangle or block clipboard pastes.
+pub(crate) fn type_text_into_focused_field(text: &str) -> Result<(), String> {
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
+        .map_err(|_| "failed to create event source")?;
+    let chars: Vec<char> = text.chars().collect();
+    // Keyboard events carry at most 20 UTF-16 units of text.
+    for chunk in chars.chunks(TYPE_CHUNK_CHARS) {
+        let chunk: String = chunk.iter().collect();
+        for key_down in [true, false] {
+            let event = CGEvent::new_keyboard_event(source.clone(), 0, key_down)
+                .map_err(|_| "failed to create typing event")?;
+            event.set_string(&chunk);
+            event.post(CGEventTapLocation::HID);
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    Ok(())
+}
+
 fn simulate_cmd_v() -> Result<(), String> {
     let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
         .map_err(|_| "failed to create event source")?;
@@ -52,3 +87,89 @@ fn simulate_cmd_v() -> Result<(), String> {
 
     Ok(())
 }
+
+fn letter_key_code(ch: char) -> Option<CGKeyCode> {
+    let code = match ch.to_ascii_lowercase() {
+        'a' => 0,
+        's' => 1,
+        'd' => 2,
+        'f' => 3,
+        'h' => 4,
+        'g' => 5,
+        'z' => 6,
+        'x' => 7,
+        'c' => 8,
+        'v' => 9,
+        'b' => 11,
+        'q' => 12,
+        'w' => 13,
+        'e' => 14,
+        'r' => 15,
+        'y' => 16,
+        't' => 17,
+        'o' => 31,
+        'u' => 32,
+        'i' => 34,
+        'p' => 35,
+        'l' => 37,
+        'j' => 38,
+        'k' => 40,
+        'n' => 45,
+        'm' => 46,
+        _ => return None,
+    };
+    Some(code)
+}
+
+pub(crate) fn send_key_chord(chord: &KeyChord) -> Result<(), String> {
+    // Line and document movement are Command+arrow on macOS.
+    let (key_code, needs_command) = match chord.key {
+        EditKey::Enter => (KEY_RETURN, false),
+        EditKey::Tab => (KEY_TAB, false),
+        EditKey::Backspace => (KEY_DELETE, false),
+        EditKey::Delete => (KEY_FORWARD_DELETE, false),
+        EditKey::Escape => (KEY_ESCAPE, false),
+        EditKey::Left => (KEY_LEFT_ARROW, false),
+        EditKey::Right => (KEY_RIGHT_ARROW, false),
+        EditKey::Up => (KEY_UP_ARROW, false),
+        EditKey::Down => (KEY_DOWN_ARROW, false),
+        EditKey::LineStart => (KEY_LEFT_ARROW, true),
+        EditKey::LineEnd => (KEY_RIGHT_ARROW, true),
+        EditKey::DocumentStart => (KEY_UP_ARROW, true),
+        EditKey::DocumentEnd => (KEY_DOWN_ARROW, true),
+        EditKey::Letter(ch) => (
+            letter_key_code(ch).ok_or_else(|| format!("unsupported key '{ch}'"))?,
+            false,
+        ),
+    };
+
+    let mut flags = CGEventFlags::CGEventFlagNull;
+    if needs_command || chord.modifiers.primary {
+        flags |= CGEventFlags::CGEventFlagCommand;
+    }
+    if chord.modifiers.word {
+        flags |= CGEventFlags::CGEventFlagAlternate;
+    }
+    if chord.modifiers.shift {
+        flags |= CGEventFlags::CGEventFlagShift;
+    }
+
+    for _ in 0..chord.repeat.max(1) {
+        let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
+            .map_err(|_| "failed to create event source")?;
+
+        let key_down = CGEvent::new_keyboard_event(source.clone(), key_code, true)
+            .map_err(|_| "failed to create key-down event")?;
+        key_down.set_flags(flags);
+        key_down.post(CGEventTapLocation::HID);
+
+        thread::sleep(Duration::from_millis(5));
+
+        let key_up = CGEvent::new_keyboard_event(source, key_code, false)
+            .map_err(|_| "failed to create key-up event")?;
+        key_up.set_flags(flags);
+        key_up.post(CGEventTapLocation::HID);
+    }
+
+    Ok(())
+}
@@ -52,6 +52,8 @@ pub mod whisper;
 
 #[cfg(desktop)]
 pub mod keyboard;
+#[cfg(desktop)]
+pub mod keyboard_ipc;
 
 pub type LevelCallback = Arc<dyn Fn(Vec<f32>) + Send + Sync>;
 pub type ChunkCallback = Arc<dyn Fn(Vec<f32>) + Send + Sync>;
@@ -62,6 +64,25 @@ pub struct TranscriptionRequest {
     pub model_path: Option<String>,
     pub initial_prompt: Option<String>,
     pub language: Option<String>,
+    /// Translate speech to English instead of transcribing it as spoken.
+    pub translate: bool,
+    /// Languages to decode in when `language` is unset, chosen by detecting
+    /// on the transcription's own model state. Empty leaves detection to the
+    /// model.
+    pub language_candidates: Vec<String>,
+    /// Candidate used when detection cannot tell the candidates apart.
+    pub fallback_language: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TranscriptionOutput {
+    pub text: String,
+    /// Language code the model detected, when it reports one.
+    pub detected_language: Option<String>,
+    /// Whether the text was translated to English rather than transcribed.
+    pub translated: bool,
+    /// How the language was picked from `language_candidates`, if it was.
+    pub language_choice: Option<crate::system::language_id::LanguageChoice>,
 }
 
 #[derive(Clone, Debug)]
@@ -88,6 +109,43 @@ pub trait Recorder: Send + Sync {
     fn current_sample_rate(&self) -> Option<u32> {
         None
     }
+
+    /// Keep an input stream open between recordings, holding the last
+    /// `pre_roll` of audio in memory. `listener` receives every captured
+    /// chunk until `stop_standby`, during recordings too. Returns the
+    /// stream's sample rate.
+    fn start_standby(
+        &self,
+        _pre_roll: std::time::Duration,
+        _listener: Option<ChunkCallback>,
+    ) -> Result<u32, Box<dyn std::error::Error>> {
+        Err("this recorder has no standby mode".into())
+    }
+    fn stop_standby(&self) {}
+    /// Make the next `start` begin at the `keep_from`th sample the listener
+    /// received, dropping the buffered audio before it.
+    fn trim_standby(&self, _keep_from: u64) {}
+    /// Keep the input device warm between recordings so `start` begins with
+    /// the last `pre_roll` of audio; `None` turns this off.
+    fn set_warm_pre_roll(
+        &self,
+        _pre_roll: Option<std::time::Duration>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    /// Reopen the standby stream if it stopped delivering audio, or in any
+    /// case when the machine has `resumed` from sleep.
+    fn refresh_standby(&self, _resumed: bool) {}
+    /// If the running recording's input failed, continue it on the next best
+    /// device without dropping what was captured. Returns the switch made.
+    fn recover_input(&self) -> Option<crate::domain::InputFailover> {
+        None
+    }
+    /// Scale microphone input by `gain`; `None` leaves it unchanged. Devices
+    /// with a calibrated gain use that instead.
+    fn set_input_gain(&self, _gain: Option<f32>) {}
+    /// Apply newly saved calibrated gains to the streams already open.
+    fn refresh_input_gain(&self) {}
 }
 
 pub trait Transcriber: Send + Sync {
@@ -97,4 +155,26 @@ pub trait Transcriber: Send + Sync {
         sample_rate: u32,
         request: Option<&TranscriptionRequest>,
     ) -> Result<String, String>;
+
+    /// Like `transcribe`, also reporting the language the model detected.
+    fn transcribe_detailed(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        self.transcribe(samples, sample_rate, request)
+            .map(|text| TranscriptionOutput {
+                text,
+                detected_language: None,
+                translated: false,
+                language_choice: None,
+            })
+    }
+
+    /// Number of prompt tokens `text` takes for the model the request would
+    /// use, or `None` when the transcriber cannot tell.
+    fn count_tokens(&self, _text: &str, _request: Option<&TranscriptionRequest>) -> Option<usize> {
+        None
+    }
 }
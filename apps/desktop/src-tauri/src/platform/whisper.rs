@@ -1,4 +1,6 @@
-use crate::platform::{GpuDescriptor, Transcriber, TranscriptionDevice, TranscriptionRequest};
+use crate::platform::{
+    GpuDescriptor, Transcriber, TranscriptionDevice, TranscriptionOutput, TranscriptionRequest,
+};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use whisper_rs::{
@@ -363,42 +365,60 @@ impl Transcriber for WhisperTranscriber {
         sample_rate: u32,
         request: Option<&TranscriptionRequest>,
     ) -> Result<String, String> {
-        const TARGET_SAMPLE_RATE: u32 = 16_000;
-
-        if samples.is_empty() {
-            return Err("No audio samples captured".to_string());
-        }
-        if sample_rate == 0 {
-            return Err("Invalid sample rate (0 Hz)".to_string());
-        }
-
-        let processed = if sample_rate == TARGET_SAMPLE_RATE {
-            samples.to_vec()
-        } else {
-            resample_to_sample_rate(samples, sample_rate, TARGET_SAMPLE_RATE)
-        };
-
-        if processed.is_empty() {
-            return Err("Resampled audio is empty".to_string());
-        }
+        self.transcribe_detailed(samples, sample_rate, request)
+            .map(|output| output.text)
+    }
 
+    fn transcribe_detailed(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        let processed = prepare_samples(samples, sample_rate)?;
         let context = self.context_for_request(request)?;
         let mut state = context
             .create_state()
             .map_err(|err| format!("Failed to create Whisper state: {err}"))?;
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-        let language_code = request
+        let multilingual = context.is_multilingual();
+        let forced_language = request
             .and_then(|req| req.language.as_deref())
             .filter(|value| !value.is_empty());
 
+        // Pick among the candidates before decoding, on the same state, so
+        // Whisper cannot settle on a language the user does not speak.
+        let mut language_choice = None;
+        if let Some(req) = request.filter(|req| {
+            forced_language.is_none() && multilingual && req.language_candidates.len() > 1
+        }) {
+            let probabilities =
+                language_probabilities(&mut state, &processed).unwrap_or_else(|err| {
+                    eprintln!("[whisper] language detection failed: {err}");
+                    Vec::new()
+                });
+            language_choice = crate::system::language_id::choose(
+                &probabilities,
+                &req.language_candidates,
+                req.fallback_language.as_deref(),
+            );
+        }
+        let language_code = forced_language.or(language_choice
+            .as_ref()
+            .map(|choice| choice.language.as_str()));
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+
         // If no language specified, don't call set_language - Whisper will auto-detect
         eprintln!("[whisper] using language code: {:?}", language_code);
         if let Some(language) = language_code {
             params.set_language(Some(language));
         }
 
-        params.set_translate(false);
+        // Whisper can only translate into English, and English-only models
+        // cannot translate at all.
+        let translate = multilingual && request.is_some_and(|req| req.translate);
+        params.set_translate(translate);
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
@@ -419,11 +439,99 @@ impl Transcriber for WhisperTranscriber {
             .full(params, &processed)
             .map_err(|err| format!("Failed to run Whisper inference: {err}"))?;
 
-        Self::collect_transcription(&state)
+        let detected_language = match language_choice.as_ref() {
+            Some(choice) => Some(choice.language.clone()),
+            None => reported_language(
+                multilingual,
+                forced_language,
+                whisper_rs::get_lang_str(state.full_lang_id_from_state()),
+            ),
+        };
+        Ok(TranscriptionOutput {
+            text: Self::collect_transcription(&state)?,
+            detected_language,
+            translated: translate,
+            language_choice,
+        })
     }
+
+    fn count_tokens(&self, text: &str, request: Option<&TranscriptionRequest>) -> Option<usize> {
+        let context = self.context_for_request(request).ok()?;
+        // A token never covers less than a byte.
+        context
+            .tokenize(text, text.len() + 1)
+            .ok()
+            .map(|tokens| tokens.len())
+    }
+}
+
+/// Probability of each language code being spoken, detected on `state` so
+/// the decode that follows shares its model state.
+fn language_probabilities(
+    state: &mut whisper_rs::WhisperState,
+    samples: &[f32],
+) -> Result<Vec<(String, f32)>, String> {
+    let threads = std::thread::available_parallelism().map_or(1, |count| count.get().min(4));
+    state
+        .pcm_to_mel(samples, threads)
+        .map_err(|err| format!("Failed to compute Whisper spectrogram: {err}"))?;
+    let (_, probabilities) = state
+        .lang_detect(0, threads)
+        .map_err(|err| format!("Failed to detect language: {err}"))?;
+
+    Ok(probabilities
+        .into_iter()
+        .enumerate()
+        .filter_map(|(id, probability)| {
+            let code = whisper_rs::get_lang_str(i32::try_from(id).ok()?)?;
+            Some((code.to_string(), probability))
+        })
+        .collect())
+}
+
+/// The language Whisper detected, or `None` when it did not detect one.
+/// Whisper echoes a forced language, and English-only models always report
+/// English.
+fn reported_language(
+    multilingual: bool,
+    forced_language: Option<&str>,
+    state_language: Option<&str>,
+) -> Option<String> {
+    if !multilingual || forced_language.is_some() {
+        return None;
+    }
+    state_language.map(str::to_string)
+}
+
+/// Whisper expects 16 kHz mono samples.
+fn prepare_samples(samples: &[f32], sample_rate: u32) -> Result<Vec<f32>, String> {
+    const TARGET_SAMPLE_RATE: u32 = 16_000;
+
+    if samples.is_empty() {
+        return Err("No audio samples captured".to_string());
+    }
+    if sample_rate == 0 {
+        return Err("Invalid sample rate (0 Hz)".to_string());
+    }
+
+    let processed = if sample_rate == TARGET_SAMPLE_RATE {
+        samples.to_vec()
+    } else {
+        resample_to_sample_rate(samples, sample_rate, TARGET_SAMPLE_RATE)
+    };
+
+    if processed.is_empty() {
+        return Err("Resampled audio is empty".to_string());
+    }
+
+    Ok(processed)
 }
 
-fn resample_to_sample_rate(samples: &[f32], input_rate: u32, target_rate: u32) -> Vec<f32> {
+pub(crate) fn resample_to_sample_rate(
+    samples: &[f32],
+    input_rate: u32,
+    target_rate: u32,
+) -> Vec<f32> {
     if samples.is_empty() || input_rate == 0 || target_rate == 0 {
         return Vec::new();
     }
@@ -435,24 +543,108 @@ fn resample_to_sample_rate(samples: &[f32], input_rate: u32, target_rate: u32) -
     let ratio = f64::from(target_rate) / f64::from(input_rate);
     let output_len = ((samples.len() as f64) * ratio).ceil().max(1.0) as usize;
     let mut output = Vec::with_capacity(output_len);
+    Resampler::new(input_rate, target_rate).process(samples, &mut output);
 
-    for i in 0..output_len {
-        let src_pos = (i as f64) / ratio;
-        let base_index = src_pos.floor() as usize;
-        let frac = src_pos - base_index as f64;
-
-        let sample = if base_index + 1 < samples.len() {
-            let a = samples[base_index];
-            let b = samples[base_index + 1];
-            a + (b - a) * frac as f32
-        } else {
-            samples[base_index]
+    // The stream stops short of the last input sample; hold it for the tail.
+    let tail = output.last().copied().unwrap_or(samples[samples.len() - 1]);
+    output.resize(output_len, tail);
+    output
+}
+
+/// Linear-interpolating resampler that keeps its position between calls so
+/// packet boundaries don't click. When downsampling, samples are low-pass
+/// filtered at the target Nyquist frequency first so content above it does
+/// not alias into the speech band.
+pub(crate) struct Resampler {
+    /// Input samples advanced per output sample.
+    step: f64,
+    /// Next output position relative to the start of the upcoming input;
+    /// `-1.0..0.0` falls between the last and the first new sample.
+    position: f64,
+    /// Low-pass FIR taps; a single unit tap when upsampling.
+    taps: Vec<f32>,
+    /// The last `taps.len()` input samples from previous calls.
+    history: Vec<f32>,
+}
+
+impl Resampler {
+    pub(crate) fn new(input_rate: u32, output_rate: u32) -> Self {
+        let step = f64::from(input_rate.max(1)) / f64::from(output_rate.max(1));
+        Self {
+            step,
+            position: 0.0,
+            taps: low_pass_taps(step),
+            history: Vec::new(),
+        }
+    }
+
+    pub(crate) fn process(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        let Some(&first) = input.first() else {
+            return;
+        };
+        if self.step == 1.0 {
+            output.extend_from_slice(input);
+            return;
+        }
+
+        let taps = self.taps.len();
+        if self.history.is_empty() {
+            self.history = vec![first; taps];
+        }
+        let mut extended = std::mem::take(&mut self.history);
+        extended.extend_from_slice(input);
+
+        // Filtered sample at `index` of `input`; -1 is the last sample of the
+        // previous call.
+        let sample_at = |index: isize| -> f32 {
+            let end = (index + taps as isize) as usize;
+            self.taps
+                .iter()
+                .enumerate()
+                .map(|(offset, tap)| tap * extended[end - offset])
+                .sum()
         };
+        let len = input.len() as isize;
+        while (self.position.floor() as isize) + 1 < len {
+            let index = self.position.floor() as isize;
+            let fraction = (self.position - index as f64) as f32;
+            let a = sample_at(index);
+            let b = sample_at(index + 1);
+            output.push(a + (b - a) * fraction);
+            self.position += self.step;
+        }
 
-        output.push(sample);
+        self.position -= input.len() as f64;
+        self.history = extended.split_off(extended.len() - taps);
     }
+}
 
-    output
+/// Blackman-windowed sinc low-pass with its cutoff just below the output
+/// Nyquist frequency, normalised to unity gain.
+fn low_pass_taps(step: f64) -> Vec<f32> {
+    if step <= 1.0 {
+        return vec![1.0];
+    }
+
+    let cutoff = 0.45 / step;
+    let half = (8.0 * step).ceil() as usize;
+    let len = 2 * half + 1;
+    let mut taps: Vec<f64> = (0..len)
+        .map(|n| {
+            let x = n as f64 - half as f64;
+            let sinc = if x == 0.0 {
+                2.0 * cutoff
+            } else {
+                (2.0 * std::f64::consts::PI * cutoff * x).sin() / (std::f64::consts::PI * x)
+            };
+            let phase = 2.0 * std::f64::consts::PI * n as f64 / (len - 1) as f64;
+            let window = 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos();
+            sinc * window
+        })
+        .collect();
+    let sum: f64 = taps.iter().sum();
+    taps.iter_mut().for_each(|tap| *tap /= sum);
+    taps.into_iter().map(|tap| tap as f32).collect()
 }
 
 #[cfg(all(target_os = "linux", feature = "linux-gpu"))]
@@ -785,7 +977,17 @@ fn configure_windows_gpu_selection(
 
 #[cfg(test)]
 mod tests {
-    use super::resample_to_sample_rate;
+    use super::{reported_language, resample_to_sample_rate, Resampler};
+
+    #[test]
+    fn only_a_detected_language_is_reported() {
+        assert_eq!(
+            reported_language(true, None, Some("de")),
+            Some("de".to_string())
+        );
+        assert_eq!(reported_language(true, Some("fr"), Some("fr")), None);
+        assert_eq!(reported_language(false, None, Some("en")), None);
+    }
 
     #[test]
     fn resample_returns_empty_for_invalid_input() {
@@ -806,4 +1008,43 @@ mod tests {
         let resampled = resample_to_sample_rate(&data, 8_000, 16_000);
         assert_eq!(resampled.len(), 8);
     }
+
+    fn tone(frequency: f32, sample_rate: u32, seconds: usize) -> Vec<f32> {
+        (0..sample_rate as usize * seconds)
+            .map(|i| (std::f32::consts::TAU * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|sample| sample * sample).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn downsampling_filters_out_content_above_the_target_nyquist() {
+        let speech = resample_to_sample_rate(&tone(1_000.0, 48_000, 1), 48_000, 16_000);
+        let hiss = resample_to_sample_rate(&tone(20_000.0, 48_000, 1), 48_000, 16_000);
+
+        assert_eq!(speech.len(), 16_000);
+        assert!(rms(&speech[100..]) > 0.65, "{}", rms(&speech[100..]));
+        assert!(rms(&hiss[100..]) < 0.01, "{}", rms(&hiss[100..]));
+    }
+
+    #[test]
+    fn streaming_matches_one_shot_resampling() {
+        let input = tone(440.0, 44_100, 1);
+
+        let mut whole = Vec::new();
+        Resampler::new(44_100, 16_000).process(&input, &mut whole);
+
+        let mut chunked = Vec::new();
+        let mut resampler = Resampler::new(44_100, 16_000);
+        for chunk in input.chunks(1_000) {
+            resampler.process(chunk, &mut chunked);
+        }
+
+        assert_eq!(chunked.len(), whole.len());
+        for (a, b) in chunked.iter().zip(&whole) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
 }
@@ -1,10 +1,13 @@
+use crate::domain::{EditKey, KeyChord};
 use std::{env, mem, thread, time::Duration};
 use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     GetAsyncKeyState, SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT,
-    KEYEVENTF_KEYUP, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSEINPUT, VIRTUAL_KEY,
-    VK_CONTROL, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_RCONTROL, VK_RMENU,
-    VK_RSHIFT, VK_RWIN, VK_SHIFT, VK_V,
+    KEYBD_EVENT_FLAGS, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, MOUSEEVENTF_RIGHTDOWN,
+    MOUSEEVENTF_RIGHTUP, MOUSEINPUT, VIRTUAL_KEY, VK_BACK, VK_CONTROL, VK_DELETE, VK_DOWN,
+    VK_END, VK_ESCAPE, VK_HOME, VK_LCONTROL, VK_LEFT, VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU,
+    VK_RCONTROL, VK_RETURN, VK_RIGHT, VK_RMENU, VK_RSHIFT, VK_RWIN, VK_SHIFT, VK_TAB, VK_UP,
+    VK_V,
 };
 use windows::Win32::UI::WindowsAndMessaging::{GetClassNameW, GetForegroundWindow};
 
@@ -34,6 +37,20 @@ pub(crate) fn paste_text_into_focused_field(
     })
 }
 
+/// Type text key by key, for fields that mangle or block clipboard pastes.
+pub(crate) fn type_text_into_focused_field(text: &str) -> Result<(), String> {
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    use enigo::{Enigo, KeyboardControllable};
+    let mut enigo = Enigo::new();
+    release_modifier_keys();
+    thread::sleep(Duration::from_millis(50));
+    enigo.key_sequence(text);
+    Ok(())
+}
+
 fn is_console_window() -> bool {
     unsafe {
         let hwnd: HWND = GetForegroundWindow();
@@ -198,3 +215,77 @@ fn paste_via_clipboard(text: &str, keybind: Option<&str>) -> Result<(), String>
 
     Ok(())
 }
+
+fn send_chord_key(vk: VIRTUAL_KEY, key_up: bool, extended: bool) {
+    let mut flags = KEYBD_EVENT_FLAGS::default();
+    if key_up {
+        flags |= KEYEVENTF_KEYUP;
+    }
+    // Navigation keys must be flagged extended or Shift+arrow is read as the
+    // numpad arrow and drops the selection.
+    if extended {
+        flags |= KEYEVENTF_EXTENDEDKEY;
+    }
+
+    let input = INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: vk,
+                wScan: 0,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+    unsafe {
+        SendInput(&[input], mem::size_of::<INPUT>() as i32);
+    }
+}
+
+pub(crate) fn send_key_chord(chord: &KeyChord) -> Result<(), String> {
+    let (vk, extended, needs_control) = match chord.key {
+        EditKey::Enter => (VK_RETURN, false, false),
+        EditKey::Tab => (VK_TAB, false, false),
+        EditKey::Backspace => (VK_BACK, false, false),
+        EditKey::Delete => (VK_DELETE, true, false),
+        EditKey::Escape => (VK_ESCAPE, false, false),
+        EditKey::Left => (VK_LEFT, true, false),
+        EditKey::Right => (VK_RIGHT, true, false),
+        EditKey::Up => (VK_UP, true, false),
+        EditKey::Down => (VK_DOWN, true, false),
+        EditKey::LineStart => (VK_HOME, true, false),
+        EditKey::LineEnd => (VK_END, true, false),
+        EditKey::DocumentStart => (VK_HOME, true, true),
+        EditKey::DocumentEnd => (VK_END, true, true),
+        EditKey::Letter(ch) if ch.is_ascii_alphabetic() => {
+            (VIRTUAL_KEY(ch.to_ascii_uppercase() as u16), false, false)
+        }
+        EditKey::Letter(ch) => return Err(format!("unsupported key '{ch}'")),
+    };
+    let control = needs_control || chord.modifiers.primary || chord.modifiers.word;
+
+    release_modifier_keys();
+    thread::sleep(Duration::from_millis(30));
+
+    if control {
+        send_key_down(VK_CONTROL);
+    }
+    if chord.modifiers.shift {
+        send_key_down(VK_SHIFT);
+    }
+    for _ in 0..chord.repeat.max(1) {
+        send_chord_key(vk, false, extended);
+        thread::sleep(Duration::from_millis(5));
+        send_chord_key(vk, true, extended);
+    }
+    if chord.modifiers.shift {
+        send_key_up(VK_SHIFT);
+    }
+    if control {
+        send_key_up(VK_CONTROL);
+    }
+
+    Ok(())
+}
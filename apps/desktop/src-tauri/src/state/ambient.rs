@@ -0,0 +1,59 @@
+use std::sync::Mutex;
+
+use crate::domain::AmbientStatus;
+use crate::system::ambient::AmbientListener;
+
+/// Owns the ambient listener while it is armed.
+pub struct AmbientState {
+    listener: Mutex<Option<AmbientListener>>,
+}
+
+impl Default for AmbientState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AmbientState {
+    pub fn new() -> Self {
+        Self {
+            listener: Mutex::new(None),
+        }
+    }
+
+    /// Stop any running listener, then start a fresh one. The old one goes
+    /// first because dropping it takes the recorder out of standby.
+    pub fn arm(
+        &self,
+        start: impl FnOnce() -> Result<AmbientListener, String>,
+    ) -> Result<AmbientStatus, String> {
+        let mut guard = self.listener.lock().map_err(|err| err.to_string())?;
+        guard.take();
+
+        let listener = start()?;
+        let status = status_of(Some(&listener));
+        *guard = Some(listener);
+        Ok(status)
+    }
+
+    pub fn disarm(&self) -> AmbientStatus {
+        if let Ok(mut guard) = self.listener.lock() {
+            guard.take();
+        }
+        status_of(None)
+    }
+
+    pub fn status(&self) -> AmbientStatus {
+        match self.listener.lock() {
+            Ok(guard) => status_of(guard.as_ref()),
+            Err(_) => status_of(None),
+        }
+    }
+}
+
+fn status_of(listener: Option<&AmbientListener>) -> AmbientStatus {
+    AmbientStatus {
+        armed: listener.is_some(),
+        wake_phrase: listener.map(|listener| listener.wake_phrase().to_string()),
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Mutex;
+
+use crate::domain::ResolvedAppProfile;
+
+/// The app profile for the dictation in progress, resolved by the backend
+/// when recording starts so later steps do not depend on the webview.
+pub struct ActiveAppProfileState {
+    current: Mutex<Option<ResolvedAppProfile>>,
+}
+
+impl Default for ActiveAppProfileState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActiveAppProfileState {
+    pub fn new() -> Self {
+        Self {
+            current: Mutex::new(None),
+        }
+    }
+
+    pub fn set(&self, profile: Option<ResolvedAppProfile>) {
+        match self.current.lock() {
+            Ok(mut guard) => *guard = profile,
+            Err(poisoned) => *poisoned.into_inner() = profile,
+        }
+    }
+
+    pub fn get(&self) -> Option<ResolvedAppProfile> {
+        match self.current.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+}
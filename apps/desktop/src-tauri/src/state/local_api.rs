@@ -0,0 +1,60 @@
+use std::net::SocketAddr;
+use std::sync::Mutex;
+
+use tauri::AppHandle;
+
+use crate::system::local_api::{LocalApiServer, TokenVerifier};
+
+/// Owns the loopback API server while it is enabled.
+pub struct LocalApiState {
+    server: Mutex<Option<LocalApiServer>>,
+}
+
+impl Default for LocalApiState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalApiState {
+    pub fn new() -> Self {
+        Self {
+            server: Mutex::new(None),
+        }
+    }
+
+    /// Stop any running server and start a fresh one with the given port and
+    /// token. The old server is dropped outside the lock so `addr()` callers
+    /// never wait on its shutdown.
+    pub fn restart(
+        &self,
+        app: AppHandle,
+        port: u16,
+        verifier: TokenVerifier,
+    ) -> Result<SocketAddr, String> {
+        drop(self.take());
+
+        let server = LocalApiServer::start(app, port, verifier)
+            .map_err(|err| format!("Failed to start local API on port {port}: {err}"))?;
+        let addr = server.addr();
+        let replaced = self
+            .server
+            .lock()
+            .map_err(|err| err.to_string())?
+            .replace(server);
+        drop(replaced);
+        Ok(addr)
+    }
+
+    pub fn stop(&self) {
+        drop(self.take());
+    }
+
+    fn take(&self) -> Option<LocalApiServer> {
+        self.server.lock().ok()?.take()
+    }
+
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.server.lock().ok()?.as_ref().map(LocalApiServer::addr)
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+
+use crate::domain::McpPermissionDecision;
+
+/// Pending MCP permission prompts and the grants the user chose to keep for
+/// the rest of the session. Grants are keyed by connection, not by the name a
+/// client reports for itself, and end when that connection closes.
+pub struct McpPermissionState {
+    pending: Mutex<HashMap<String, Sender<McpPermissionDecision>>>,
+    session_grants: Mutex<HashSet<(String, String)>>,
+}
+
+impl Default for McpPermissionState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl McpPermissionState {
+    pub fn new() -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+            session_grants: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn is_granted(&self, connection: &str, tool: &str) -> bool {
+        self.session_grants
+            .lock()
+            .map(|grants| grants.contains(&(connection.to_string(), tool.to_string())))
+            .unwrap_or(false)
+    }
+
+    pub fn grant_for_session(&self, connection: &str, tool: &str) {
+        if let Ok(mut grants) = self.session_grants.lock() {
+            grants.insert((connection.to_string(), tool.to_string()));
+        }
+    }
+
+    /// Drop the grants of a connection that has closed.
+    pub fn end_session(&self, connection: &str) {
+        if let Ok(mut grants) = self.session_grants.lock() {
+            grants.retain(|(granted, _)| granted != connection);
+        }
+    }
+
+    /// Register a prompt and return the receiver its answer arrives on.
+    pub fn register(&self, request_id: &str) -> Result<Receiver<McpPermissionDecision>, String> {
+        let (sender, receiver) = mpsc::channel();
+        self.pending
+            .lock()
+            .map_err(|err| err.to_string())?
+            .insert(request_id.to_string(), sender);
+        Ok(receiver)
+    }
+
+    /// Drop a prompt that timed out or failed to show.
+    pub fn forget(&self, request_id: &str) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.remove(request_id);
+        }
+    }
+
+    pub fn respond(&self, request_id: &str, decision: McpPermissionDecision) -> Result<(), String> {
+        let sender = self
+            .pending
+            .lock()
+            .map_err(|err| err.to_string())?
+            .remove(request_id)
+            .ok_or_else(|| "Permission request has expired".to_string())?;
+        sender
+            .send(decision)
+            .map_err(|_| "Permission request has expired".to_string())
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Cancellation flags for the media imports currently running, by import id.
+pub struct MediaImportState {
+    running: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl Default for MediaImportState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MediaImportState {
+    pub fn new() -> Self {
+        Self {
+            running: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn begin(&self, import_id: &str) -> Result<Arc<AtomicBool>, String> {
+        let mut running = self.running.lock().map_err(|err| err.to_string())?;
+        if running.contains_key(import_id) {
+            return Err(format!("Import {import_id} is already running"));
+        }
+        let cancel = Arc::new(AtomicBool::new(false));
+        running.insert(import_id.to_string(), Arc::clone(&cancel));
+        Ok(cancel)
+    }
+
+    pub fn finish(&self, import_id: &str) {
+        if let Ok(mut running) = self.running.lock() {
+            running.remove(import_id);
+        }
+    }
+
+    /// Returns `false` when no import with that id is running.
+    pub fn cancel(&self, import_id: &str) -> bool {
+        self.running
+            .lock()
+            .ok()
+            .and_then(|running| running.get(import_id).cloned())
+            .map(|cancel| cancel.store(true, Ordering::SeqCst))
+            .is_some()
+    }
+}
@@ -1,9 +1,21 @@
+pub mod ambient;
+pub mod app_profile;
 pub mod database;
+pub mod local_api;
+pub mod mcp;
+pub mod media_import;
 pub mod oauth;
 pub mod overlay;
+pub mod retention;
 pub mod transcriber;
 
+pub use ambient::AmbientState;
+pub use app_profile::ActiveAppProfileState;
 pub use database::OptionKeyDatabase;
+pub use local_api::LocalApiState;
+pub use mcp::McpPermissionState;
+pub use media_import::MediaImportState;
 pub use oauth::GoogleOAuthState;
 pub use overlay::OverlayState;
+pub use retention::RetentionState;
 pub use transcriber::TranscriberState;
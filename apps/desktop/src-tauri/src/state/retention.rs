@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+
+use sqlx::SqlitePool;
+use tauri::AppHandle;
+
+use crate::system::retention::RetentionScheduler;
+
+/// Owns the retention scheduler while the app runs.
+pub struct RetentionState {
+    scheduler: Mutex<Option<RetentionScheduler>>,
+}
+
+impl Default for RetentionState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetentionState {
+    pub fn new() -> Self {
+        Self {
+            scheduler: Mutex::new(None),
+        }
+    }
+
+    /// Start the scheduler, stopping any earlier one first.
+    pub fn start(&self, app: AppHandle, pool: SqlitePool) {
+        drop(self.take());
+        if let Ok(mut scheduler) = self.scheduler.lock() {
+            *scheduler = Some(RetentionScheduler::start(app, pool));
+        }
+    }
+
+    /// Stop the scheduler, waiting for a cleanup in progress. It is dropped
+    /// outside the lock.
+    pub fn stop(&self) {
+        drop(self.take());
+    }
+
+    fn take(&self) -> Option<RetentionScheduler> {
+        self.scheduler.lock().ok()?.take()
+    }
+}
@@ -0,0 +1,445 @@
+//! Ambient listening: the microphone stays on standby and dictation starts
+//! when the wake phrase is heard.
+//!
+//! Captured audio only lives in memory, in the recorder's pre-roll and in the
+//! speech burst being checked here. Nothing is written to disk until the wake
+//! phrase starts a dictation. An energy detector gates the spotter, so the
+//! tiny Whisper model only runs on bursts of speech and stays idle while the
+//! room is quiet.
+
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter};
+
+use crate::domain::{
+    AmbientStatus, AmbientWakePayload, EVT_AMBIENT_SPEECH_ENDED, EVT_AMBIENT_STATUS,
+    EVT_AMBIENT_WAKE,
+};
+use crate::platform::{ChunkCallback, Recorder, Transcriber, TranscriptionRequest};
+
+/// Audio kept before the wake phrase is recognised. It covers the longest
+/// burst, the spotter and the UI starting the recording, so nothing said
+/// right after the phrase is lost.
+pub const AMBIENT_PRE_ROLL: Duration = Duration::from_secs(8);
+
+const FRAME_MS: usize = 30;
+/// Silence that closes a speech burst.
+const BURST_HANGOVER_MS: usize = 450;
+/// Shorter bursts are clicks and coughs rather than words.
+const MIN_BURST_MS: usize = 300;
+/// Longer speech is checked in pieces, so a phrase that opens a long
+/// sentence is still caught.
+const MAX_BURST_MS: usize = 3_000;
+/// Silence that ends a dictation started by the wake phrase.
+const END_OF_SPEECH_MS: usize = 1_500;
+/// A dictation started by the wake phrase with no speech after it ends once
+/// this has passed.
+const NO_SPEECH_TIMEOUT: Duration = Duration::from_secs(8);
+
+const MIN_SPEECH_RMS: f32 = 0.01;
+const SPEECH_TO_NOISE_RATIO: f32 = 3.0;
+
+/// Energy voice activity detector with a noise floor that follows the room.
+pub struct EnergyVad {
+    noise_floor: f32,
+}
+
+impl Default for EnergyVad {
+    fn default() -> Self {
+        Self {
+            noise_floor: MIN_SPEECH_RMS / SPEECH_TO_NOISE_RATIO,
+        }
+    }
+}
+
+impl EnergyVad {
+    pub fn is_speech(&mut self, frame: &[f32]) -> bool {
+        let rms = rms(frame);
+        let threshold = (self.noise_floor * SPEECH_TO_NOISE_RATIO).max(MIN_SPEECH_RMS);
+        let speech = rms >= threshold;
+        // Speech moves the floor far slower than silence does, so talking
+        // does not raise it but a fan that switches on is learned in time.
+        let rate = if speech { 0.001 } else { 0.05 };
+        self.noise_floor += (rms - self.noise_floor) * rate;
+        speech
+    }
+}
+
+fn rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    let energy: f32 = frame.iter().map(|sample| sample * sample).sum();
+    (energy / frame.len() as f32).sqrt()
+}
+
+/// A stretch of speech and where it starts in the standby stream.
+#[derive(Debug)]
+pub struct Burst {
+    pub start: u64,
+    pub samples: Vec<f32>,
+}
+
+/// Splits the standby stream into bursts of speech.
+pub struct BurstDetector {
+    vad: EnergyVad,
+    frame_len: usize,
+    hangover: usize,
+    min_len: usize,
+    max_len: usize,
+    pending: Vec<f32>,
+    position: u64,
+    current: Option<Burst>,
+    silence: usize,
+}
+
+impl BurstDetector {
+    pub fn new(sample_rate: u32) -> Self {
+        let samples_for = |ms: usize| ms * sample_rate as usize / 1000;
+        Self {
+            vad: EnergyVad::default(),
+            frame_len: samples_for(FRAME_MS).max(1),
+            hangover: samples_for(BURST_HANGOVER_MS),
+            min_len: samples_for(MIN_BURST_MS),
+            max_len: samples_for(MAX_BURST_MS),
+            pending: Vec::new(),
+            position: 0,
+            current: None,
+            silence: 0,
+        }
+    }
+
+    /// Samples of silence since the last speech.
+    pub fn silence(&self) -> usize {
+        self.silence
+    }
+
+    /// Feeds captured samples and returns the bursts they complete.
+    pub fn push(&mut self, samples: &[f32]) -> Vec<Burst> {
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.extend_from_slice(samples);
+
+        let mut finished = Vec::new();
+        let mut frames = pending.chunks_exact(self.frame_len);
+        for frame in frames.by_ref() {
+            self.push_frame(frame, &mut finished);
+        }
+        self.pending = frames.remainder().to_vec();
+        finished
+    }
+
+    fn push_frame(&mut self, frame: &[f32], finished: &mut Vec<Burst>) {
+        let position = self.position;
+        self.position += frame.len() as u64;
+
+        if self.vad.is_speech(frame) {
+            self.silence = 0;
+            self.current
+                .get_or_insert_with(|| Burst {
+                    start: position,
+                    samples: Vec::new(),
+                })
+                .samples
+                .extend_from_slice(frame);
+        } else {
+            self.silence += frame.len();
+            if let Some(burst) = self.current.as_mut() {
+                burst.samples.extend_from_slice(frame);
+            }
+        }
+
+        let closed = match self.current.as_ref() {
+            Some(burst) => self.silence >= self.hangover || burst.samples.len() >= self.max_len,
+            None => false,
+        };
+        if !closed {
+            return;
+        }
+        if let Some(burst) = self.current.take() {
+            let voiced = burst.samples.len() - self.silence.min(burst.samples.len());
+            if voiced >= self.min_len {
+                finished.push(burst);
+            }
+        }
+    }
+}
+
+/// The wake phrase, compared on normalised words so case, punctuation and
+/// small spelling differences in the transcript do not matter.
+#[derive(Clone, Debug)]
+pub struct WakePhrase {
+    text: String,
+    words: Vec<String>,
+}
+
+impl WakePhrase {
+    pub fn new(phrase: &str) -> Option<Self> {
+        let words = words(phrase);
+        if words.is_empty() {
+            return None;
+        }
+        Some(Self {
+            text: words.join(" "),
+            words,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether `transcript` contains the phrase. Runs of words are compared
+    /// with their spaces removed, so "OSVoice" matches "os voice", and one
+    /// edit is allowed for every five letters.
+    pub fn is_heard_in(&self, transcript: &str) -> bool {
+        let target = self.words.concat();
+        let allowed = (target.chars().count() / 5).max(1);
+        let heard = words(transcript);
+        let shortest = self.words.len().saturating_sub(1).max(1);
+        let longest = self.words.len() + 1;
+
+        (0..heard.len()).any(|start| {
+            (shortest..=longest)
+                .take_while(|len| start + len <= heard.len())
+                .any(|len| edit_distance(&heard[start..start + len].concat(), &target) <= allowed)
+        })
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Runs the wake-phrase spotter on the recorder's standby stream until it is
+/// dropped.
+pub struct AmbientListener {
+    recorder: Arc<dyn Recorder>,
+    phrase: WakePhrase,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl AmbientListener {
+    pub fn start(
+        app: AppHandle,
+        recorder: Arc<dyn Recorder>,
+        transcriber: Arc<dyn Transcriber>,
+        model_path: String,
+        phrase: WakePhrase,
+    ) -> Result<Self, String> {
+        let (sender, receiver) = mpsc::channel::<Vec<f32>>();
+        let listener: ChunkCallback = Arc::new(move |chunk| {
+            // Fails only once the worker has stopped.
+            let _ = sender.send(chunk);
+        });
+        let sample_rate = recorder
+            .start_standby(AMBIENT_PRE_ROLL, Some(listener))
+            .map_err(|err| format!("Failed to open the microphone: {err}"))?;
+
+        let worker = Worker {
+            app,
+            recorder: Arc::clone(&recorder),
+            transcriber,
+            model_path,
+            phrase: phrase.clone(),
+            sample_rate,
+        };
+        let handle = thread::Builder::new()
+            .name("ambient-listener".into())
+            .spawn(move || worker.run(receiver));
+        let handle = match handle {
+            Ok(handle) => handle,
+            Err(err) => {
+                recorder.stop_standby();
+                return Err(format!("Failed to start ambient listener: {err}"));
+            }
+        };
+
+        eprintln!("[ambient] Listening for \"{}\"", phrase.as_str());
+        Ok(Self {
+            recorder,
+            phrase,
+            worker: Some(handle),
+        })
+    }
+
+    pub fn wake_phrase(&self) -> &str {
+        self.phrase.as_str()
+    }
+}
+
+impl Drop for AmbientListener {
+    fn drop(&mut self) {
+        // Leaving standby drops the listener and its sender, which ends the
+        // worker's receive loop.
+        self.recorder.stop_standby();
+        if let Some(handle) = self.worker.take() {
+            let _ = handle.join();
+        }
+        eprintln!("[ambient] Stopped listening");
+    }
+}
+
+/// Tells the windows and the tray whether ambient listening is armed.
+pub fn publish_status(app: &AppHandle, status: &AmbientStatus) {
+    if let Err(err) = app.emit(EVT_AMBIENT_STATUS, status) {
+        eprintln!("[ambient] Failed to emit status: {err}");
+    }
+    #[cfg(desktop)]
+    if let Err(err) = crate::system::tray::set_ambient_indicator(app, status) {
+        eprintln!("[ambient] Failed to update tray: {err}");
+    }
+}
+
+struct Worker {
+    app: AppHandle,
+    recorder: Arc<dyn Recorder>,
+    transcriber: Arc<dyn Transcriber>,
+    model_path: String,
+    phrase: WakePhrase,
+    sample_rate: u32,
+}
+
+/// A dictation the wake phrase started, watched for the speaker to finish.
+struct Dictation {
+    started: Instant,
+    heard_speech: bool,
+}
+
+impl Worker {
+    fn run(self, receiver: Receiver<Vec<f32>>) {
+        let mut detector = BurstDetector::new(self.sample_rate);
+        let end_of_speech = END_OF_SPEECH_MS * self.sample_rate as usize / 1000;
+        let mut dictation: Option<Dictation> = None;
+
+        while let Ok(chunk) = receiver.recv() {
+            let bursts = detector.push(&chunk);
+
+            if let Some(active) = dictation.as_mut() {
+                active.heard_speech |= detector.silence() == 0 || !bursts.is_empty();
+                let finished = if active.heard_speech {
+                    detector.silence() >= end_of_speech
+                } else {
+                    active.started.elapsed() >= NO_SPEECH_TIMEOUT
+                };
+                if finished {
+                    self.emit(EVT_AMBIENT_SPEECH_ENDED, ());
+                    dictation = None;
+                }
+                continue;
+            }
+
+            if let Some(burst) = bursts.iter().find(|burst| self.is_wake(burst)) {
+                // The recording starts at the phrase; the UI strips it from
+                // the transcript.
+                self.recorder.trim_standby(burst.start);
+                self.emit(
+                    EVT_AMBIENT_WAKE,
+                    AmbientWakePayload {
+                        wake_phrase: self.phrase.as_str().to_string(),
+                    },
+                );
+                dictation = Some(Dictation {
+                    started: Instant::now(),
+                    heard_speech: false,
+                });
+            }
+        }
+    }
+
+    fn is_wake(&self, burst: &Burst) -> bool {
+        // No prompt: Whisper tends to echo a prompt back on noise, which
+        // would trigger on anything.
+        let request = TranscriptionRequest {
+            model_path: Some(self.model_path.clone()),
+            ..TranscriptionRequest::default()
+        };
+        match self
+            .transcriber
+            .transcribe(&burst.samples, self.sample_rate, Some(&request))
+        {
+            Ok(text) => self.phrase.is_heard_in(&text),
+            Err(err) => {
+                eprintln!("[ambient] Wake phrase check failed: {err}");
+                false
+            }
+        }
+    }
+
+    fn emit<S: serde::Serialize + Clone>(&self, event: &str, payload: S) {
+        if let Err(err) = self.app.emit(event, payload) {
+            eprintln!("[ambient] Failed to emit {event}: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    fn tone(ms: usize, amplitude: f32) -> Vec<f32> {
+        (0..ms * RATE as usize / 1000)
+            .map(|i| amplitude * (i as f32 * 0.2).sin())
+            .collect()
+    }
+
+    #[test]
+    fn splits_speech_into_bursts() {
+        let mut detector = BurstDetector::new(RATE);
+        assert!(detector.push(&tone(1_000, 0.0005)).is_empty());
+
+        // A click is too short to be a word.
+        assert!(detector.push(&tone(60, 0.3)).is_empty());
+        assert!(detector.push(&tone(600, 0.0005)).is_empty());
+
+        let mut bursts = detector.push(&tone(800, 0.3));
+        bursts.extend(detector.push(&tone(600, 0.0005)));
+        assert_eq!(bursts.len(), 1);
+        let start_ms = bursts[0].start * 1000 / RATE as u64;
+        assert!((1_650..=1_700).contains(&start_ms), "{start_ms}");
+        assert!(detector.silence() >= BURST_HANGOVER_MS * RATE as usize / 1000);
+
+        // Long speech is cut into pieces for the spotter.
+        let bursts = detector.push(&tone(7_000, 0.3));
+        assert_eq!(bursts.len(), 2);
+        assert_eq!(detector.silence(), 0);
+    }
+
+    #[test]
+    fn matches_the_wake_phrase_loosely() {
+        let phrase = WakePhrase::new("Hey, OS Voice").expect("phrase");
+        assert_eq!(phrase.as_str(), "hey os voice");
+
+        assert!(phrase.is_heard_in("Hey OS Voice."));
+        assert!(phrase.is_heard_in("Okay. Hey, O.S. voice, take a note"));
+        assert!(phrase.is_heard_in("hey osvoice"));
+        assert!(phrase.is_heard_in("Hey, OS choice."));
+        assert!(!phrase.is_heard_in("Hey, how are you?"));
+        assert!(!phrase.is_heard_in("the voice of reason"));
+        assert!(!phrase.is_heard_in(""));
+
+        assert!(WakePhrase::new(" ,. ").is_none());
+    }
+}
@@ -0,0 +1,296 @@
+use sqlx::SqlitePool;
+
+use crate::db::analytics_queries::{self, UsageSums};
+use crate::domain::{UsageAnalytics, UsageAnalyticsRequest, UsageTotals, WpmBucket};
+
+/// Typing speed used to estimate how long dictated words would take to type.
+pub const TYPING_WPM: u32 = 40;
+
+const WPM_BUCKET_WIDTH: u32 = 20;
+const WPM_TOP_BUCKET: u32 = 10;
+const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// Builds every usage statistic from the transcription rows in range. Nothing
+/// is cached, so edits and deletions show up on the next call.
+pub async fn usage(
+    pool: SqlitePool,
+    request: &UsageAnalyticsRequest,
+) -> Result<UsageAnalytics, String> {
+    let from_ms = request.from_ms.unwrap_or(0);
+    let to_ms = request.to_ms.unwrap_or(i64::MAX);
+    let offset = offset_modifier(request.utc_offset_minutes);
+
+    let sums = analytics_queries::fetch_usage_sums(pool.clone(), from_ms, to_ms)
+        .await
+        .map_err(|err| err.to_string())?;
+    let words_by_day =
+        analytics_queries::fetch_usage_periods(pool.clone(), from_ms, to_ms, &offset, false)
+            .await
+            .map_err(|err| err.to_string())?;
+    let words_by_week =
+        analytics_queries::fetch_usage_periods(pool.clone(), from_ms, to_ms, &offset, true)
+            .await
+            .map_err(|err| err.to_string())?;
+    let histogram = analytics_queries::fetch_wpm_histogram(
+        pool.clone(),
+        from_ms,
+        to_ms,
+        WPM_BUCKET_WIDTH,
+        WPM_TOP_BUCKET,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    let apps = analytics_queries::fetch_app_usage(pool.clone(), from_ms, to_ms)
+        .await
+        .map_err(|err| err.to_string())?;
+    let models = analytics_queries::fetch_model_latency(pool, from_ms, to_ms)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(UsageAnalytics {
+        totals: totals(&sums),
+        words_by_day,
+        words_by_week,
+        wpm_distribution: wpm_buckets(&histogram),
+        apps,
+        models,
+    })
+}
+
+/// SQLite date modifier that shifts UTC timestamps into the user's time zone.
+fn offset_modifier(utc_offset_minutes: i32) -> String {
+    let minutes = utc_offset_minutes.clamp(-MAX_UTC_OFFSET_MINUTES, MAX_UTC_OFFSET_MINUTES);
+    format!("{minutes:+} minutes")
+}
+
+fn totals(sums: &UsageSums) -> UsageTotals {
+    let average_wpm = (sums.speaking_ms > 0)
+        .then(|| sums.timed_words as f64 * 60_000.0 / sums.speaking_ms as f64);
+    let typing_ms = sums.timed_words * 60_000 / i64::from(TYPING_WPM);
+    let warning_rate = if sums.transcriptions > 0 {
+        sums.with_warnings as f64 / sums.transcriptions as f64
+    } else {
+        0.0
+    };
+
+    UsageTotals {
+        transcriptions: sums.transcriptions,
+        words: sums.words,
+        speaking_ms: sums.speaking_ms,
+        average_wpm,
+        time_saved_ms: (typing_ms - sums.speaking_ms).max(0),
+        typing_wpm: TYPING_WPM,
+        warning_rate,
+    }
+}
+
+fn wpm_buckets(histogram: &[(u32, i64)]) -> Vec<WpmBucket> {
+    histogram
+        .iter()
+        .map(|&(bucket, transcriptions)| WpmBucket {
+            min_wpm: bucket * WPM_BUCKET_WIDTH,
+            max_wpm: (bucket < WPM_TOP_BUCKET).then(|| (bucket + 1) * WPM_BUCKET_WIDTH),
+            transcriptions,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::LatencyStage;
+
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+    // Monday 2024-01-01 00:00 UTC.
+    const MONDAY: i64 = 1_704_067_200_000;
+
+    fn open_pool() -> SqlitePool {
+        let pool = crate::db::open_test_pool();
+        tauri::async_runtime::block_on(async {
+            sqlx::query(
+                "INSERT INTO app_targets (id, name, created_at) VALUES ('mail', 'Mail', '')",
+            )
+            .execute(&pool)
+            .await
+            .expect("insert app target");
+            pool
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn insert(
+        pool: &SqlitePool,
+        id: &str,
+        timestamp: i64,
+        transcript: &str,
+        audio_duration_ms: Option<i64>,
+        app_target_id: Option<&str>,
+        transcription_duration_ms: Option<i64>,
+        warnings: Option<&str>,
+    ) {
+        tauri::async_runtime::block_on(
+            sqlx::query(
+                "INSERT INTO transcriptions (id, transcript, timestamp, audio_duration_ms,
+                     app_target_id, transcription_mode, model_size, transcription_duration_ms,
+                     warnings_json)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 'local', 'base', ?6, ?7)",
+            )
+            .bind(id)
+            .bind(transcript)
+            .bind(timestamp)
+            .bind(audio_duration_ms)
+            .bind(app_target_id)
+            .bind(transcription_duration_ms)
+            .bind(warnings)
+            .execute(pool),
+        )
+        .expect("insert transcription");
+    }
+
+    fn fixture() -> SqlitePool {
+        let pool = open_pool();
+        // 60 words in 30 seconds: 120 WPM.
+        let sixty = vec!["word"; 60].join(" ");
+        insert(
+            &pool,
+            "a",
+            MONDAY + 1_000,
+            &sixty,
+            Some(30_000),
+            Some("mail"),
+            Some(400),
+            None,
+        );
+        insert(
+            &pool,
+            "b",
+            MONDAY + DAY_MS,
+            "hello  there\nfriend",
+            None,
+            None,
+            Some(800),
+            Some("[\"clipped\"]"),
+        );
+        insert(
+            &pool,
+            "c",
+            MONDAY + 7 * DAY_MS,
+            "",
+            Some(2_000),
+            Some("mail"),
+            None,
+            Some("[]"),
+        );
+        insert(
+            &pool,
+            "d",
+            MONDAY + 8 * DAY_MS,
+            "[Transcription Failed]",
+            Some(5_000),
+            None,
+            None,
+            None,
+        );
+        pool
+    }
+
+    #[test]
+    fn recomputes_usage_from_transcription_rows() {
+        let pool = fixture();
+        let analytics =
+            tauri::async_runtime::block_on(usage(pool.clone(), &UsageAnalyticsRequest::default()))
+                .expect("usage");
+
+        let totals = &analytics.totals;
+        assert_eq!(totals.transcriptions, 3);
+        assert_eq!(totals.words, 63);
+        assert_eq!(totals.speaking_ms, 32_000);
+        assert_eq!(totals.time_saved_ms, 60 * 60_000 / 40 - 32_000);
+        assert!((totals.warning_rate - 1.0 / 3.0).abs() < 1e-9);
+
+        let days: Vec<_> = analytics
+            .words_by_day
+            .iter()
+            .map(|day| (day.period.as_str(), day.words))
+            .collect();
+        assert_eq!(
+            days,
+            [("2024-01-01", 60), ("2024-01-02", 3), ("2024-01-08", 0)]
+        );
+        let weeks: Vec<_> = analytics
+            .words_by_week
+            .iter()
+            .map(|week| (week.period.as_str(), week.transcriptions))
+            .collect();
+        assert_eq!(weeks, [("2024-01-01", 2), ("2024-01-08", 1)]);
+
+        assert_eq!(
+            analytics.wpm_distribution,
+            [WpmBucket {
+                min_wpm: 120,
+                max_wpm: Some(140),
+                transcriptions: 1
+            }]
+        );
+
+        let mail = &analytics.apps[0];
+        assert_eq!(mail.name.as_deref(), Some("Mail"));
+        assert_eq!((mail.transcriptions, mail.words), (2, 60));
+
+        let model = &analytics.models[0];
+        assert_eq!(model.stage, LatencyStage::Transcription);
+        assert_eq!(model.average_ms, Some(600.0));
+        assert_eq!(model.max_ms, Some(800));
+
+        // Deleting a row is reflected immediately; nothing is cached.
+        tauri::async_runtime::block_on(
+            sqlx::query("DELETE FROM transcriptions WHERE id = 'a'").execute(&pool),
+        )
+        .expect("delete");
+        let analytics =
+            tauri::async_runtime::block_on(usage(pool, &UsageAnalyticsRequest::default()))
+                .expect("usage");
+        assert_eq!(analytics.totals.words, 3);
+    }
+
+    #[test]
+    fn long_whitespace_runs_count_as_one_gap() {
+        let pool = open_pool();
+        insert(
+            &pool,
+            "a",
+            MONDAY,
+            "  one     two \t\t  three\r\n\r\n\r\nfour                five ",
+            None,
+            None,
+            None,
+            None,
+        );
+        insert(&pool, "b", MONDAY, " \n\t ", None, None, None, None);
+
+        let analytics =
+            tauri::async_runtime::block_on(usage(pool, &UsageAnalyticsRequest::default()))
+                .expect("usage");
+        assert_eq!(analytics.totals.transcriptions, 2);
+        assert_eq!(analytics.totals.words, 5);
+    }
+
+    #[test]
+    fn buckets_days_in_the_local_time_zone() {
+        let pool = fixture();
+        let request = UsageAnalyticsRequest {
+            from_ms: Some(MONDAY),
+            to_ms: Some(MONDAY + 2 * DAY_MS),
+            utc_offset_minutes: -60,
+        };
+        let analytics = tauri::async_runtime::block_on(usage(pool, &request)).expect("usage");
+
+        let days: Vec<_> = analytics
+            .words_by_day
+            .iter()
+            .map(|day| day.period.as_str())
+            .collect();
+        assert_eq!(days, ["2023-12-31", "2024-01-01"]);
+        assert_eq!(analytics.words_by_week[0].period, "2023-12-25");
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use regex::{Regex, RegexBuilder};
+use thiserror::Error;
+
+use crate::domain::{
+    AppProfile, AppTarget, ResolvedAppProfile, INSERTION_STRATEGY_PASTE, INSERTION_STRATEGY_TYPE,
+};
+
+/// Keeps user-supplied title patterns from compiling into huge automata.
+const TITLE_PATTERN_SIZE_LIMIT: usize = 1 << 16;
+/// Compiled patterns kept between dictations; far more than anyone has
+/// profiles, so the cache only resets after many pattern edits.
+const TITLE_PATTERN_CACHE_LIMIT: usize = 256;
+
+#[derive(Debug, Error)]
+pub enum AppProfileError {
+    #[error("Profile name cannot be empty")]
+    EmptyName,
+    #[error("Profile must match an application identifier, app or window title")]
+    NoMatchRule,
+    #[error("Invalid window title pattern: {0}")]
+    InvalidTitlePattern(String),
+    #[error("Unknown insertion strategy '{0}'")]
+    UnknownInsertionStrategy(String),
+}
+
+/// The focused window as seen at dictation time.
+#[derive(Clone, Debug, Default)]
+pub struct FocusedApp<'a> {
+    pub app_identifier: Option<&'a str>,
+    pub app_target_id: Option<&'a str>,
+    pub window_title: Option<&'a str>,
+}
+
+pub fn validate_profile(profile: &AppProfile) -> Result<(), AppProfileError> {
+    if profile.name.trim().is_empty() {
+        return Err(AppProfileError::EmptyName);
+    }
+
+    if non_empty(&profile.app_identifier).is_none()
+        && non_empty(&profile.app_target_id).is_none()
+        && non_empty(&profile.window_title_pattern).is_none()
+    {
+        return Err(AppProfileError::NoMatchRule);
+    }
+
+    if let Some(pattern) = non_empty(&profile.window_title_pattern) {
+        compile_title_pattern(pattern)
+            .map_err(|err| AppProfileError::InvalidTitlePattern(err.to_string()))?;
+    }
+
+    if let Some(strategy) = non_empty(&profile.insertion_strategy) {
+        if strategy != INSERTION_STRATEGY_PASTE && strategy != INSERTION_STRATEGY_TYPE {
+            return Err(AppProfileError::UnknownInsertionStrategy(
+                strategy.to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pick the most specific profile for the focused window. Title rules beat
+/// app-wide profiles, identifier matches beat display-name matches, and
+/// `priority` breaks the remaining ties.
+pub fn match_profile<'p>(profiles: &'p [AppProfile], app: &FocusedApp) -> Option<&'p AppProfile> {
+    profiles
+        .iter()
+        .filter_map(|profile| match_score(profile, app).map(|score| (score, profile)))
+        .max_by_key(|(score, _)| *score)
+        .map(|(_, profile)| profile)
+}
+
+fn match_score(profile: &AppProfile, app: &FocusedApp) -> Option<(bool, bool, i64)> {
+    let by_identifier = match non_empty(&profile.app_identifier) {
+        Some(expected) => {
+            if !app
+                .app_identifier
+                .is_some_and(|actual| actual.eq_ignore_ascii_case(expected))
+            {
+                return None;
+            }
+            true
+        }
+        None => false,
+    };
+
+    if !by_identifier {
+        if let Some(expected) = non_empty(&profile.app_target_id) {
+            if app.app_target_id != Some(expected) {
+                return None;
+            }
+        }
+    }
+
+    let by_title = match non_empty(&profile.window_title_pattern) {
+        Some(pattern) => {
+            let title = app.window_title?;
+            if !cached_title_pattern(pattern)?.is_match(title) {
+                return None;
+            }
+            true
+        }
+        None => false,
+    };
+
+    Some((by_title, by_identifier, profile.priority))
+}
+
+/// Merge a matched profile over the app target's settings.
+pub fn resolve(
+    profile: Option<&AppProfile>,
+    target: Option<&AppTarget>,
+    app_name: &str,
+    app_identifier: Option<&str>,
+    window_title: Option<&str>,
+) -> ResolvedAppProfile {
+    ResolvedAppProfile {
+        profile_id: profile.map(|profile| profile.id.clone()),
+        app_target_id: target.map(|target| target.id.clone()),
+        app_name: app_name.to_string(),
+        app_identifier: app_identifier.map(str::to_string),
+        window_title: window_title.map(str::to_string),
+        tone_id: target.and_then(|target| target.tone_id.clone()),
+        paste_keybind: target.and_then(|target| target.paste_keybind.clone()),
+        language: profile.and_then(|profile| non_empty(&profile.language).map(str::to_string)),
+        model_size: profile.and_then(|profile| non_empty(&profile.model_size).map(str::to_string)),
+        insertion_strategy: profile
+            .and_then(|profile| non_empty(&profile.insertion_strategy))
+            .unwrap_or(INSERTION_STRATEGY_PASTE)
+            .to_string(),
+        term_ids: profile.and_then(|profile| profile.term_ids.clone()),
+        itn_enabled: profile
+            .and_then(|profile| profile.itn_enabled)
+            .unwrap_or_else(|| target.is_none_or(|target| target.itn_enabled)),
+        auto_capitalize: profile.and_then(|profile| profile.auto_capitalize),
+        trailing_punctuation: profile.and_then(|profile| profile.trailing_punctuation),
+        translation_language: target
+            .and_then(|target| non_empty(&target.translation_language).map(str::to_string)),
+    }
+}
+
+/// App target ids are derived from display names. The webview gets them from
+/// `get_current_app_info` rather than deriving its own.
+pub fn app_target_id_for_name(name: &str) -> Option<String> {
+    let mut id = String::new();
+    for ch in name.trim().to_lowercase().chars() {
+        if ch.is_ascii_alphanumeric() {
+            id.push(ch);
+        } else if !id.is_empty() && !id.ends_with('_') {
+            id.push('_');
+        }
+    }
+    let id = id.trim_end_matches('_');
+
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+/// Apply a profile's capitalization and trailing punctuation preferences.
+pub fn apply_text_style(
+    text: &str,
+    auto_capitalize: Option<bool>,
+    trailing_punctuation: Option<bool>,
+) -> String {
+    let mut output = text.trim_end().to_string();
+    let trailing_whitespace = &text[output.len()..];
+
+    match auto_capitalize {
+        Some(true) => output = capitalize_first(&output),
+        Some(false) => output = decapitalize_first(&output),
+        None => {}
+    }
+
+    match trailing_punctuation {
+        Some(false) if output.ends_with('.') && !output.ends_with("..") => {
+            output.pop();
+        }
+        Some(true) if output.chars().last().is_some_and(char::is_alphanumeric) => {
+            output.push('.');
+        }
+        _ => {}
+    }
+
+    output.push_str(trailing_whitespace);
+    output
+}
+
+fn capitalize_first(text: &str) -> String {
+    let Some(index) = text.find(char::is_alphabetic) else {
+        return text.to_string();
+    };
+    let ch = text[index..].chars().next().unwrap_or_default();
+    format!(
+        "{}{}{}",
+        &text[..index],
+        ch.to_uppercase(),
+        &text[index + ch.len_utf8()..]
+    )
+}
+
+/// Lowercase the first letter unless the word is "I" or an acronym.
+fn decapitalize_first(text: &str) -> String {
+    let Some(index) = text.find(char::is_alphabetic) else {
+        return text.to_string();
+    };
+    let word: String = text[index..]
+        .chars()
+        .take_while(|ch| ch.is_alphanumeric() || *ch == '\'')
+        .collect();
+    let keep =
+        word == "I" || word.starts_with("I'") || word.chars().skip(1).any(char::is_uppercase);
+    if keep {
+        return text.to_string();
+    }
+
+    let ch = text[index..].chars().next().unwrap_or_default();
+    format!(
+        "{}{}{}",
+        &text[..index],
+        ch.to_lowercase(),
+        &text[index + ch.len_utf8()..]
+    )
+}
+
+fn compile_title_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .size_limit(TITLE_PATTERN_SIZE_LIMIT)
+        .build()
+}
+
+/// The compiled form of a title pattern, compiled on first use. `None` for
+/// patterns that do not compile, which then match nothing.
+fn cached_title_pattern(pattern: &str) -> Option<Regex> {
+    static PATTERNS: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    let mut patterns = match PATTERNS.get_or_init(|| Mutex::new(HashMap::new())).lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(regex) = patterns.get(pattern) {
+        return Some(regex.clone());
+    }
+
+    let regex = compile_title_pattern(pattern).ok()?;
+    if patterns.len() >= TITLE_PATTERN_CACHE_LIMIT {
+        patterns.clear();
+    }
+    patterns.insert(pattern.to_string(), regex.clone());
+    Some(regex)
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(id: &str) -> AppProfile {
+        AppProfile {
+            id: id.to_string(),
+            name: id.to_string(),
+            created_at: String::new(),
+            app_identifier: None,
+            app_target_id: None,
+            window_title_pattern: None,
+            priority: 0,
+            language: None,
+            model_size: None,
+            insertion_strategy: None,
+            term_ids: None,
+            itn_enabled: None,
+            auto_capitalize: None,
+            trailing_punctuation: None,
+        }
+    }
+
+    #[test]
+    fn prefers_title_rules_and_identifiers() {
+        let chrome = AppProfile {
+            app_identifier: Some("/opt/google/chrome/chrome".into()),
+            ..profile("chrome")
+        };
+        let jira = AppProfile {
+            app_identifier: Some("/opt/google/chrome/chrome".into()),
+            window_title_pattern: Some(r"(?i)\bjira\b".into()),
+            ..profile("jira")
+        };
+        let by_name = AppProfile {
+            app_target_id: Some("google_chrome".into()),
+            priority: 5,
+            ..profile("by-name")
+        };
+        let profiles = vec![by_name, chrome, jira];
+
+        let mut app = FocusedApp {
+            app_identifier: Some("/opt/google/chrome/chrome"),
+            app_target_id: Some("google_chrome"),
+            window_title: Some("PROJ-12 - Jira - Google Chrome"),
+        };
+        assert_eq!(match_profile(&profiles, &app).unwrap().id, "jira");
+
+        app.window_title = Some("Inbox - Google Chrome");
+        assert_eq!(match_profile(&profiles, &app).unwrap().id, "chrome");
+
+        app.app_identifier = None;
+        assert_eq!(match_profile(&profiles, &app).unwrap().id, "by-name");
+
+        app.app_target_id = Some("slack");
+        assert!(match_profile(&profiles, &app).is_none());
+    }
+
+    #[test]
+    fn profile_overrides_target_settings() {
+        let target = AppTarget {
+            id: "slack".into(),
+            name: "Slack".into(),
+            created_at: String::new(),
+            tone_id: Some("casual".into()),
+            icon_path: None,
+            paste_keybind: None,
+            itn_enabled: true,
+            translation_language: Some("fr".into()),
+        };
+        let slack = AppProfile {
+            itn_enabled: Some(false),
+            insertion_strategy: Some("type".into()),
+            language: Some(" ".into()),
+            ..profile("slack")
+        };
+
+        let resolved = resolve(Some(&slack), Some(&target), "Slack", None, None);
+        assert!(!resolved.itn_enabled);
+        assert_eq!(resolved.insertion_strategy, "type");
+        assert_eq!(resolved.language, None);
+        assert_eq!(resolved.tone_id.as_deref(), Some("casual"));
+        assert_eq!(resolved.translation_language.as_deref(), Some("fr"));
+
+        let resolved = resolve(None, None, "Slack", None, None);
+        assert!(resolved.itn_enabled);
+        assert_eq!(resolved.insertion_strategy, INSERTION_STRATEGY_PASTE);
+    }
+
+    #[test]
+    fn validates_rules() {
+        assert!(matches!(
+            validate_profile(&profile("empty")),
+            Err(AppProfileError::NoMatchRule)
+        ));
+        let bad_pattern = AppProfile {
+            window_title_pattern: Some("(unclosed".into()),
+            ..profile("bad")
+        };
+        assert!(matches!(
+            validate_profile(&bad_pattern),
+            Err(AppProfileError::InvalidTitlePattern(_))
+        ));
+        assert_eq!(
+            app_target_id_for_name("  Visual Studio Code "),
+            Some("visual_studio_code".into())
+        );
+    }
+
+    #[test]
+    fn applies_text_style() {
+        assert_eq!(
+            apply_text_style("Sounds good.", Some(false), Some(false)),
+            "sounds good"
+        );
+        assert_eq!(
+            apply_text_style("I think so.", Some(false), None),
+            "I think so."
+        );
+        assert_eq!(
+            apply_text_style("NASA launched", Some(false), None),
+            "NASA launched"
+        );
+        assert_eq!(
+            apply_text_style("hello there", Some(true), Some(true)),
+            "Hello there."
+        );
+        assert_eq!(apply_text_style("wait...", None, Some(false)), "wait...");
+        assert_eq!(apply_text_style("ok? ", None, Some(true)), "ok? ");
+    }
+}
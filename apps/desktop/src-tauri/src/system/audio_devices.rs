@@ -0,0 +1,81 @@
+//! Watches input devices coming and going.
+//!
+//! Hosts do not reliably report a device being unplugged: some call the
+//! stream's error callback, others just stop delivering audio. The watcher
+//! checks the running recording often and moves it to another device when its
+//! own fails. Enumerating devices is costly, so it rescans the device list only
+//! while a recording runs or a microphone picker is open, to pick up and show
+//! hot-plugged devices.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter};
+
+use crate::domain::EVT_AUDIO_DEVICES_CHANGED;
+use crate::platform::audio::list_input_devices;
+use crate::platform::Recorder;
+
+const RECOVERY_INTERVAL: Duration = Duration::from_millis(500);
+const SCAN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Number of microphone pickers showing the device list.
+static PICKERS_OPEN: AtomicUsize = AtomicUsize::new(0);
+
+/// Note a microphone picker opening or closing. Devices are rescanned while
+/// any is open.
+pub fn set_picker_open(open: bool) {
+    if open {
+        PICKERS_OPEN.fetch_add(1, Ordering::Relaxed);
+    } else {
+        let _ = PICKERS_OPEN.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+            count.checked_sub(1)
+        });
+    }
+}
+
+pub fn start_watcher(app: AppHandle, recorder: Arc<dyn Recorder>) {
+    let spawned = thread::Builder::new()
+        .name("audio-device-watcher".into())
+        .spawn(move || {
+            let mut known = list_input_devices();
+            let mut last_scan = Instant::now();
+            loop {
+                thread::sleep(RECOVERY_INTERVAL);
+
+                let failover = recorder.recover_input();
+                if let Some(failover) = &failover {
+                    eprintln!(
+                        "[audio_devices] Recording moved from '{}' to '{}'",
+                        failover.from, failover.to
+                    );
+                }
+                // A failover usually means a device went away, so rescan now.
+                if failover.is_none() {
+                    let watched = PICKERS_OPEN.load(Ordering::Relaxed) > 0
+                        || recorder.current_sample_rate().is_some();
+                    if !watched || last_scan.elapsed() < SCAN_INTERVAL {
+                        continue;
+                    }
+                }
+
+                last_scan = Instant::now();
+                let devices = list_input_devices();
+                if devices == known {
+                    continue;
+                }
+                known = devices.clone();
+
+                // A device that just appeared may be the better choice now.
+                recorder.clear_device_cache();
+                if let Err(err) = app.emit(EVT_AUDIO_DEVICES_CHANGED, devices) {
+                    eprintln!("[audio_devices] Failed to emit devices changed event: {err}");
+                }
+            }
+        });
+    if let Err(err) = spawned {
+        eprintln!("[audio_devices] Failed to start device watcher: {err}");
+    }
+}
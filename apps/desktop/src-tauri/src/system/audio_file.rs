@@ -0,0 +1,187 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL, CODEC_TYPE_OPUS};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::platform::whisper::Resampler;
+
+/// Audio and video containers that can be decoded from disk for offline
+/// transcription. Only the first audio track is read.
+pub const SUPPORTED_AUDIO_EXTENSIONS: &[&str] = &[
+    "wav", "flac", "mp3", "ogg", "oga", "m4a", "mp4", "aac", "mov",
+];
+
+/// Decode an audio file into mono `f32` samples and its sample rate.
+pub fn decode_audio_file(path: &Path) -> io::Result<(Vec<f32>, u32)> {
+    decode(path, None, None, &mut |_| {})
+}
+
+/// Decode an audio file into mono samples at `target_rate`, resampling as
+/// packets arrive so long recordings never sit in memory at their native
+/// rate. `on_progress` receives the decoded fraction when the container
+/// reports its length. Setting `cancel` stops with `ErrorKind::Interrupted`.
+pub fn decode_audio_file_resampled(
+    path: &Path,
+    target_rate: u32,
+    cancel: &AtomicBool,
+    mut on_progress: impl FnMut(f32),
+) -> io::Result<Vec<f32>> {
+    decode(path, Some(target_rate), Some(cancel), &mut on_progress).map(|(samples, _)| samples)
+}
+
+fn decode(
+    path: &Path,
+    target_rate: Option<u32>,
+    cancel: Option<&AtomicBool>,
+    on_progress: &mut dyn FnMut(f32),
+) -> io::Result<(Vec<f32>, u32)> {
+    let extension = path
+        .extension()
+        .and_then(|value| value.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    if !SUPPORTED_AUDIO_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Unsupported audio format '{}'; expected one of: {}",
+                path.display(),
+                SUPPORTED_AUDIO_EXTENSIONS.join(", ")
+            ),
+        ));
+    }
+
+    let source = MediaSourceStream::new(Box::new(File::open(path)?), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension(&extension);
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(invalid_data)?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| invalid_data("File has no audio track"))?;
+    if track.codec_params.codec == CODEC_TYPE_OPUS {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Opus audio is not supported yet; convert the file to MP3, M4A, FLAC or WAV first",
+        ));
+    }
+
+    let track_id = track.id;
+    let total_frames = track.codec_params.n_frames.filter(|frames| *frames > 0);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(invalid_data)?;
+
+    let mut source_rate = track.codec_params.sample_rate;
+    let mut resampler: Option<Resampler> = None;
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+    let mut mono = Vec::new();
+    let mut samples = Vec::new();
+    let mut decoded_frames = 0_u64;
+
+    loop {
+        if cancel.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Decoding cancelled",
+            ));
+        }
+
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(err) => return Err(invalid_data(err)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // A corrupt packet costs a few milliseconds of audio, not the file.
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(err) => return Err(invalid_data(err)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let frames = decoded.frames();
+        let rate = *source_rate.get_or_insert(spec.rate);
+
+        if buffer
+            .as_ref()
+            .is_none_or(|existing| existing.capacity() < decoded.capacity() * channels)
+        {
+            buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        let Some(sample_buffer) = buffer.as_mut() else {
+            continue;
+        };
+        sample_buffer.copy_interleaved_ref(decoded);
+
+        mono.clear();
+        mono.extend(
+            sample_buffer
+                .samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+
+        match target_rate {
+            Some(target) => resampler
+                .get_or_insert_with(|| Resampler::new(rate, target))
+                .process(&mono, &mut samples),
+            None => samples.extend_from_slice(&mono),
+        }
+
+        decoded_frames += frames as u64;
+        if let Some(total) = total_frames {
+            on_progress((decoded_frames as f32 / total as f32).min(1.0));
+        }
+    }
+
+    let sample_rate = target_rate
+        .or(source_rate)
+        .ok_or_else(|| invalid_data("File has no decodable audio"))?;
+    Ok((samples, sample_rate))
+}
+
+fn invalid_data(err: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_extensions() {
+        let err = decode_audio_file(Path::new("notes.txt")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        // WebM and Matroska audio is almost always Opus, which has no decoder.
+        let err = decode_audio_file(Path::new("call.webm")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
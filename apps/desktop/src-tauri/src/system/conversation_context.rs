@@ -0,0 +1,597 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::SecondsFormat;
+use sqlx::SqlitePool;
+
+use crate::db::conversation_queries;
+use crate::domain::{
+    BranchMessage, Conversation, ConversationCompaction, ConversationContext,
+    ConversationContextRequest, ConversationSummary, ConversationSummaryRequest, Message,
+};
+use crate::system::post_processing::{ChatMessage, OllamaConnection, OllamaSettings};
+
+/// Assumed when neither the caller nor the provider reports a window.
+pub const DEFAULT_CONTEXT_WINDOW: u32 = 8_192;
+const MAX_OUTPUT_RESERVE: u32 = 4_096;
+const MAX_SUMMARY_RESERVE: u32 = 1_024;
+/// Role markers and separators every chat format adds around a message.
+const MESSAGE_OVERHEAD_TOKENS: u32 = 4;
+
+const SUMMARY_SYSTEM_PROMPT: &str = "You condense chat history. Summarize the conversation so far in a few short paragraphs, keeping names, facts, decisions and open questions the assistant will need later. Reply with the summary only.";
+
+/// Tokens available for history after leaving room for the reply and for
+/// whatever the caller sends besides the messages.
+pub fn history_budget(window: u32, reserved_tokens: u32) -> u32 {
+    let output = (window / 4).min(MAX_OUTPUT_RESERVE);
+    window
+        .saturating_sub(output)
+        .saturating_sub(reserved_tokens)
+        .max(MESSAGE_OVERHEAD_TOKENS)
+}
+
+/// A rough count that needs no tokenizer: about four characters per token.
+pub fn estimate_tokens(text: &str) -> u32 {
+    let chars = text.chars().count() as u32;
+    chars.div_ceil(4)
+}
+
+pub fn message_tokens(message: &Message) -> u32 {
+    let content = match message.tokens_used {
+        Some(tokens) if tokens > 0 => tokens as u32,
+        _ => estimate_tokens(&message.content),
+    };
+    content + MESSAGE_OVERHEAD_TOKENS
+}
+
+/// The messages from the root down to `leaf_id`, or to the newest message
+/// when no leaf is given. `messages` is a whole conversation in any order.
+pub fn branch_path(messages: &[Message], leaf_id: Option<&str>) -> Vec<Message> {
+    let by_id: HashMap<&str, &Message> = messages.iter().map(|m| (m.id.as_str(), m)).collect();
+    let leaf = match leaf_id {
+        Some(id) => by_id.get(id).copied(),
+        None => messages
+            .iter()
+            .max_by(|a, b| a.created_at.cmp(&b.created_at)),
+    };
+
+    let mut path = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = leaf;
+    while let Some(message) = current {
+        if !seen.insert(message.id.as_str()) {
+            break;
+        }
+        path.push(message.clone());
+        current = message
+            .parent_id
+            .as_deref()
+            .and_then(|parent| by_id.get(parent).copied());
+    }
+    path.reverse();
+    path
+}
+
+/// Follows the newest reply from `message_id` down to a leaf, which is where
+/// the user left off on that branch.
+pub fn latest_leaf(messages: &[Message], message_id: &str) -> String {
+    let mut children: HashMap<&str, &Message> = HashMap::new();
+    for message in messages {
+        if let Some(parent) = message.parent_id.as_deref() {
+            let newer = children
+                .get(parent)
+                .is_none_or(|existing| existing.created_at <= message.created_at);
+            if newer {
+                children.insert(parent, message);
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut current = message_id;
+    while let Some(child) = children.get(current) {
+        if !seen.insert(current) {
+            break;
+        }
+        current = child.id.as_str();
+    }
+    current.to_string()
+}
+
+pub fn with_siblings(messages: &[Message], path: Vec<Message>) -> Vec<BranchMessage> {
+    let mut siblings: HashMap<Option<&str>, Vec<&Message>> = HashMap::new();
+    for message in messages {
+        siblings
+            .entry(message.parent_id.as_deref())
+            .or_default()
+            .push(message);
+    }
+    for group in siblings.values_mut() {
+        group.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    }
+
+    path.into_iter()
+        .map(|message| {
+            let sibling_ids = siblings
+                .get(&message.parent_id.as_deref())
+                .map(|group| group.iter().map(|m| m.id.clone()).collect())
+                .unwrap_or_else(|| vec![message.id.clone()]);
+            BranchMessage {
+                message,
+                sibling_ids,
+            }
+        })
+        .collect()
+}
+
+/// Fits a branch into `budget` tokens. The newest messages are kept verbatim;
+/// older ones are replaced by the latest summary on the branch, and anything
+/// between that summary and the kept messages goes into a compaction prompt.
+pub fn build_context(
+    path: Vec<Message>,
+    summaries: &[ConversationSummary],
+    budget: u32,
+) -> ConversationContext {
+    let total: u32 = path.iter().map(message_tokens).sum();
+    if total <= budget {
+        return ConversationContext {
+            summary: None,
+            messages: path,
+            compaction: None,
+            budget_tokens: budget,
+            estimated_tokens: total,
+        };
+    }
+
+    let positions: HashMap<&str, usize> = path
+        .iter()
+        .enumerate()
+        .map(|(index, message)| (message.id.as_str(), index))
+        .collect();
+    let summary = summaries
+        .iter()
+        .filter_map(|summary| {
+            positions
+                .get(summary.through_message_id.as_str())
+                .map(|position| (*position, summary))
+        })
+        .max_by(|(a, x), (b, y)| a.cmp(b).then(x.created_at.cmp(&y.created_at)));
+    let covered = summary
+        .map(|(position, _)| position + 1)
+        .unwrap_or(0)
+        .min(path.len().saturating_sub(1));
+
+    // Leave room for a summary even before one exists, since compaction will
+    // produce one for the next request.
+    let summary_tokens = summary
+        .map(|(_, summary)| summary.token_count.max(0) as u32 + MESSAGE_OVERHEAD_TOKENS)
+        .unwrap_or_else(|| (budget / 8).min(MAX_SUMMARY_RESERVE));
+    let available = budget.saturating_sub(summary_tokens);
+
+    let mut kept_from = path.len();
+    let mut used = 0;
+    while kept_from > covered {
+        let cost = message_tokens(&path[kept_from - 1]);
+        // The newest message is always sent, even when it alone is too long.
+        if used + cost > available && kept_from < path.len() {
+            break;
+        }
+        used += cost;
+        kept_from -= 1;
+    }
+
+    let mut path = path;
+    let messages = path.split_off(kept_from);
+    let to_compact = path.split_off(covered);
+    let summary = summary.map(|(_, summary)| summary.clone());
+    let estimated_tokens = used + summary.as_ref().map(|_| summary_tokens).unwrap_or_default();
+
+    ConversationContext {
+        compaction: compaction_prompt(summary.as_ref(), &to_compact),
+        summary,
+        messages,
+        budget_tokens: budget,
+        estimated_tokens,
+    }
+}
+
+/// Asks for a summary that folds `messages` into `previous`. `None` when
+/// there is nothing new to fold in.
+fn compaction_prompt(
+    previous: Option<&ConversationSummary>,
+    messages: &[Message],
+) -> Option<ConversationCompaction> {
+    let through = messages.last()?;
+    let transcript = messages
+        .iter()
+        .filter(|message| !message.content.is_empty())
+        .map(|message| {
+            let speaker = if message.role == "user" {
+                "User"
+            } else {
+                "Assistant"
+            };
+            format!("{speaker}: {}", message.content)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let prompt = match previous {
+        Some(previous) => format!(
+            "Summary so far:\n{}\n\nNew messages:\n{transcript}",
+            previous.content
+        ),
+        None => transcript,
+    };
+
+    Some(ConversationCompaction {
+        through_message_id: through.id.clone(),
+        system: SUMMARY_SYSTEM_PROMPT.to_string(),
+        prompt,
+    })
+}
+
+/// Copies of a branch and its summaries under fresh ids for a new
+/// conversation, with parent links remapped to the copies.
+pub fn fork_branch(
+    path: &[Message],
+    summaries: &[ConversationSummary],
+    conversation_id: &str,
+    new_id: impl Fn() -> String,
+) -> (Vec<Message>, Vec<ConversationSummary>) {
+    let ids: HashMap<&str, String> = path
+        .iter()
+        .map(|message| (message.id.as_str(), new_id()))
+        .collect();
+
+    let messages = path
+        .iter()
+        .map(|message| Message {
+            id: ids[message.id.as_str()].clone(),
+            conversation_id: conversation_id.to_string(),
+            parent_id: message
+                .parent_id
+                .as_deref()
+                .and_then(|parent| ids.get(parent).cloned()),
+            ..message.clone()
+        })
+        .collect();
+    let summaries = summaries
+        .iter()
+        .filter_map(|summary| {
+            ids.get(summary.through_message_id.as_str())
+                .map(|through| ConversationSummary {
+                    id: new_id(),
+                    conversation_id: conversation_id.to_string(),
+                    through_message_id: through.clone(),
+                    ..summary.clone()
+                })
+        })
+        .collect();
+
+    (messages, summaries)
+}
+
+async fn load_conversation(
+    pool: SqlitePool,
+    conversation_id: &str,
+) -> Result<(Conversation, Vec<Message>), String> {
+    let conversation = conversation_queries::fetch_conversation(pool.clone(), conversation_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("Conversation {conversation_id} not found"))?;
+    let messages = conversation_queries::fetch_messages(pool, conversation_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok((conversation, messages))
+}
+
+/// The active branch of a conversation, or the branch ending at `leaf_id`.
+pub async fn load_branch(
+    pool: SqlitePool,
+    conversation_id: &str,
+    leaf_id: Option<&str>,
+) -> Result<Vec<BranchMessage>, String> {
+    let (conversation, messages) = load_conversation(pool, conversation_id).await?;
+    let leaf_id = leaf_id.or(conversation.head_message_id.as_deref());
+    let path = branch_path(&messages, leaf_id);
+    Ok(with_siblings(&messages, path))
+}
+
+/// Makes the branch through `message_id` active, resuming at its newest leaf.
+pub async fn switch_branch(
+    pool: SqlitePool,
+    conversation_id: &str,
+    message_id: &str,
+) -> Result<Vec<BranchMessage>, String> {
+    let (_, messages) = load_conversation(pool.clone(), conversation_id).await?;
+    if !messages.iter().any(|message| message.id == message_id) {
+        return Err(format!("Message {message_id} not found"));
+    }
+    let leaf = latest_leaf(&messages, message_id);
+    conversation_queries::set_conversation_head(pool, conversation_id, &leaf)
+        .await
+        .map_err(|err| err.to_string())?;
+    let path = branch_path(&messages, Some(&leaf));
+    Ok(with_siblings(&messages, path))
+}
+
+/// The context for the next reply. With an Ollama connection the window is
+/// read from the server and pending history is summarized here; otherwise
+/// the caller gets the compaction prompt to run on its own provider.
+pub async fn load_context(
+    pool: SqlitePool,
+    request: ConversationContextRequest,
+    ollama: Option<OllamaConnection>,
+) -> Result<ConversationContext, String> {
+    let (conversation, messages) =
+        load_conversation(pool.clone(), &request.conversation_id).await?;
+    let leaf_id = request
+        .leaf_message_id
+        .as_deref()
+        .or(conversation.head_message_id.as_deref());
+    let path = branch_path(&messages, leaf_id);
+    let mut summaries = conversation_queries::fetch_summaries(pool.clone(), &conversation.id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let window = match (request.context_window.filter(|window| *window > 0), &ollama) {
+        (Some(window), _) => window,
+        (None, Some(connection)) => ollama_context_window(connection).await,
+        (None, None) => DEFAULT_CONTEXT_WINDOW,
+    };
+    let budget = history_budget(window, request.reserved_tokens);
+    let context = build_context(path.clone(), &summaries, budget);
+
+    let (Some(connection), Some(compaction)) = (ollama, context.compaction.as_ref()) else {
+        return Ok(context);
+    };
+    let model = connection.model.clone();
+    let messages = vec![
+        ChatMessage::system(compaction.system.clone()),
+        ChatMessage::user(compaction.prompt.clone()),
+    ];
+    let reply = tauri::async_runtime::spawn_blocking(move || {
+        connection
+            .client(OllamaSettings::default())?
+            .chat(&messages, None)
+    })
+    .await
+    .map_err(|err| err.to_string())
+    .and_then(|reply| reply.map_err(|err| err.to_string()));
+    let summary = match reply {
+        Ok(reply) => {
+            save_summary(
+                pool,
+                ConversationSummaryRequest {
+                    conversation_id: conversation.id,
+                    through_message_id: compaction.through_message_id.clone(),
+                    content: reply.content,
+                    model: Some(model),
+                },
+            )
+            .await
+        }
+        Err(err) => Err(err),
+    };
+
+    match summary {
+        Ok(summary) => {
+            summaries.push(summary);
+            Ok(build_context(path, &summaries, budget))
+        }
+        Err(err) => {
+            // The reply can still go out with the older messages left out.
+            eprintln!("[conversation] Failed to summarize chat history: {err}");
+            Ok(context)
+        }
+    }
+}
+
+/// Ollama's window for a model, asked once per server and model.
+async fn ollama_context_window(connection: &OllamaConnection) -> u32 {
+    static WINDOWS: OnceLock<Mutex<HashMap<(String, String), u32>>> = OnceLock::new();
+    let windows = WINDOWS.get_or_init(|| Mutex::new(HashMap::new()));
+    let key = (connection.base_url.clone(), connection.model.clone());
+    if let Some(window) = windows.lock().ok().and_then(|w| w.get(&key).copied()) {
+        return window;
+    }
+
+    let connection = connection.clone();
+    let window = tauri::async_runtime::spawn_blocking(move || {
+        connection
+            .client(OllamaSettings::default())?
+            .context_window()
+    })
+    .await
+    .map_err(|err| err.to_string())
+    .and_then(|window| window.map_err(|err| err.to_string()));
+    match window {
+        Ok(window) => {
+            if let Ok(mut windows) = windows.lock() {
+                windows.insert(key, window);
+            }
+            window
+        }
+        Err(err) => {
+            eprintln!("[conversation] Failed to read the Ollama context window: {err}");
+            DEFAULT_CONTEXT_WINDOW
+        }
+    }
+}
+
+/// Stores a summary for messages up to `through_message_id`, which must be
+/// on the conversation.
+pub async fn save_summary(
+    pool: SqlitePool,
+    request: ConversationSummaryRequest,
+) -> Result<ConversationSummary, String> {
+    let content = request.content.trim().to_string();
+    if content.is_empty() {
+        return Err("Summary cannot be empty".to_string());
+    }
+    let (_, messages) = load_conversation(pool.clone(), &request.conversation_id).await?;
+    if !messages
+        .iter()
+        .any(|message| message.id == request.through_message_id)
+    {
+        return Err(format!("Message {} not found", request.through_message_id));
+    }
+
+    let summary = ConversationSummary {
+        id: crate::system::ids::new_id(),
+        conversation_id: request.conversation_id,
+        through_message_id: request.through_message_id,
+        token_count: estimate_tokens(&content) as i64,
+        content,
+        model: request.model.filter(|model| !model.trim().is_empty()),
+        created_at: chrono::Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+    };
+    conversation_queries::insert_summary(pool, &summary)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Starts a new conversation holding a copy of the branch that ends at
+/// `message_id`, leaving the original untouched.
+pub async fn fork(
+    pool: SqlitePool,
+    conversation_id: &str,
+    message_id: &str,
+) -> Result<Conversation, String> {
+    let (source, messages) = load_conversation(pool.clone(), conversation_id).await?;
+    if !messages.iter().any(|message| message.id == message_id) {
+        return Err(format!("Message {message_id} not found"));
+    }
+    let summaries = conversation_queries::fetch_summaries(pool.clone(), conversation_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let id = crate::system::ids::new_id();
+    let path = branch_path(&messages, Some(message_id));
+    let (messages, summaries) = fork_branch(&path, &summaries, &id, crate::system::ids::new_id);
+    let now = chrono::Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let conversation = Conversation {
+        id,
+        title: source.title,
+        meeting_id: source.meeting_id,
+        head_message_id: messages.last().map(|message| message.id.clone()),
+        starred: false,
+        created_at: now.clone(),
+        updated_at: now,
+    };
+    conversation_queries::insert_forked_conversation(pool, &conversation, &messages, &summaries)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: &str, parent: Option<&str>, content: &str, at: &str) -> Message {
+        Message {
+            id: id.to_string(),
+            conversation_id: "c".to_string(),
+            parent_id: parent.map(str::to_string),
+            role: "user".to_string(),
+            content: content.to_string(),
+            model: None,
+            tokens_used: None,
+            context_json: None,
+            created_at: at.to_string(),
+        }
+    }
+
+    fn tree() -> Vec<Message> {
+        vec![
+            message("a", None, "hello", "1"),
+            message("b", Some("a"), "first reply", "2"),
+            message("c", Some("b"), "question", "3"),
+            message("b2", Some("a"), "regenerated reply", "4"),
+            message("d", Some("b2"), "follow up", "5"),
+        ]
+    }
+
+    fn ids(messages: &[Message]) -> Vec<&str> {
+        messages.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn walks_branches_and_lists_siblings() {
+        let messages = tree();
+        assert_eq!(ids(&branch_path(&messages, Some("c"))), ["a", "b", "c"]);
+        assert_eq!(ids(&branch_path(&messages, None)), ["a", "b2", "d"]);
+        assert_eq!(latest_leaf(&messages, "b"), "c");
+        assert_eq!(latest_leaf(&messages, "a"), "d");
+
+        let branch = with_siblings(&messages, branch_path(&messages, Some("d")));
+        assert_eq!(branch[0].sibling_ids, ["a"]);
+        assert_eq!(branch[1].sibling_ids, ["b", "b2"]);
+    }
+
+    #[test]
+    fn keeps_everything_that_fits() {
+        let context = build_context(branch_path(&tree(), Some("d")), &[], 1_000);
+        assert_eq!(ids(&context.messages), ["a", "b2", "d"]);
+        assert!(context.compaction.is_none());
+        assert!(context.summary.is_none());
+    }
+
+    #[test]
+    fn compacts_older_messages_behind_the_latest_summary() {
+        let long = "x".repeat(400);
+        let messages: Vec<Message> = (0..6)
+            .map(|i| {
+                let parent = (i > 0).then(|| format!("m{}", i - 1));
+                message(&format!("m{i}"), parent.as_deref(), &long, &i.to_string())
+            })
+            .collect();
+        let path = branch_path(&messages, Some("m5"));
+
+        let context = build_context(path.clone(), &[], 400);
+        assert_eq!(ids(&context.messages), ["m3", "m4", "m5"]);
+        let compaction = context.compaction.unwrap();
+        assert_eq!(compaction.through_message_id, "m2");
+        assert_eq!(compaction.prompt.matches("User: x").count(), 3);
+
+        let summary = ConversationSummary {
+            id: "s".to_string(),
+            conversation_id: "c".to_string(),
+            through_message_id: "m2".to_string(),
+            content: "earlier".to_string(),
+            token_count: 20,
+            model: None,
+            created_at: "9".to_string(),
+        };
+        let context = build_context(path.clone(), std::slice::from_ref(&summary), 400);
+        assert_eq!(context.summary.map(|s| s.id).as_deref(), Some("s"));
+        assert_eq!(ids(&context.messages), ["m3", "m4", "m5"]);
+        assert!(context.compaction.is_none());
+        assert!(context.estimated_tokens <= 400);
+
+        let context = build_context(path, &[summary], 250);
+        let compaction = context.compaction.unwrap();
+        assert_eq!(compaction.through_message_id, "m3");
+        assert!(compaction.prompt.starts_with("Summary so far:\nearlier"));
+    }
+
+    #[test]
+    fn forks_with_remapped_parents() {
+        let path = branch_path(&tree(), Some("c"));
+        let counter = std::cell::Cell::new(0);
+        let (copies, _) = fork_branch(&path, &[], "fork", || {
+            counter.set(counter.get() + 1);
+            format!("n{}", counter.get())
+        });
+        assert_eq!(ids(&copies), ["n1", "n2", "n3"]);
+        assert_eq!(copies[2].parent_id.as_deref(), Some("n2"));
+        assert!(copies.iter().all(|m| m.conversation_id == "fork"));
+    }
+
+    #[test]
+    fn budget_leaves_room_for_the_reply() {
+        assert_eq!(history_budget(8_192, 0), 8_192 - 2_048);
+        assert_eq!(history_budget(128_000, 500), 128_000 - 4_096 - 500);
+        assert_eq!(history_budget(100, 1_000), MESSAGE_OVERHEAD_TOKENS);
+    }
+}
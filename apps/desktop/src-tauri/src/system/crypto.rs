@@ -170,6 +170,24 @@ pub fn reveal_api_key(salt_b64: &str, ciphertext_b64: &str) -> Result<String, Cr
     ))
 }
 
+/// Check a presented key against the salted hash from `protect_api_key`.
+pub fn verify_api_key(key: &str, salt_b64: &str, hash_b64: &str) -> bool {
+    let (Ok(salt), Ok(expected)) = (
+        general_purpose::STANDARD.decode(salt_b64),
+        general_purpose::STANDARD.decode(hash_b64),
+    ) else {
+        return false;
+    };
+
+    let actual = hash_key(runtime_secret(), &salt, key.as_bytes());
+    actual.len() == expected.len()
+        && actual
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
 fn try_aead_decrypt(secret: &[u8], salt: &[u8], combined: &[u8]) -> Option<String> {
     if combined.len() < NONCE_LEN + TAG_LEN {
         return None;
@@ -253,3 +271,39 @@ fn compute_key_suffix(key: &str) -> Option<String> {
         Some(buffer.into_iter().collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_accepts_the_protected_key_only() {
+        let protected = protect_api_key("sk-local-123456");
+
+        assert!(verify_api_key(
+            "sk-local-123456",
+            &protected.salt_b64,
+            &protected.hash_b64
+        ));
+        assert!(!verify_api_key(
+            "sk-local-123457",
+            &protected.salt_b64,
+            &protected.hash_b64
+        ));
+        assert!(!verify_api_key(
+            "",
+            &protected.salt_b64,
+            &protected.hash_b64
+        ));
+    }
+
+    #[test]
+    fn verify_rejects_malformed_stored_values() {
+        let protected = protect_api_key("token");
+        let truncated = general_purpose::STANDARD.encode([0u8; 16]);
+
+        assert!(!verify_api_key("token", "not base64!", &protected.hash_b64));
+        assert!(!verify_api_key("token", &protected.salt_b64, "not base64!"));
+        assert!(!verify_api_key("token", &protected.salt_b64, &truncated));
+    }
+}
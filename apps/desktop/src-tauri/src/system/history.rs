@@ -0,0 +1,242 @@
+use sqlx::SqlitePool;
+
+use crate::db::{conversation_queries, meeting_queries, transcription_queries};
+use crate::domain::{
+    Conversation, Meeting, MeetingActionItem, MeetingDecision, MeetingSegment, Message,
+    Transcription,
+};
+use crate::system::incognito;
+
+pub const MAX_BATCH_SEGMENTS: usize = 500;
+
+// New history created while incognito mode is on is handed back unsaved, so
+// a recording or chat carries on in memory without touching the database.
+
+pub async fn create_transcription(
+    pool: SqlitePool,
+    transcription: Transcription,
+) -> Result<Transcription, String> {
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(transcription);
+    }
+    transcription_queries::insert_transcription(pool, &transcription)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_meeting(pool: SqlitePool, meeting: Meeting) -> Result<Meeting, String> {
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(meeting);
+    }
+    meeting_queries::insert_meeting(pool, &meeting)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_meeting_segments(
+    pool: SqlitePool,
+    segments: Vec<MeetingSegment>,
+) -> Result<Vec<MeetingSegment>, String> {
+    if segments.len() > MAX_BATCH_SEGMENTS {
+        return Err(format!(
+            "Segment batch too large: {} segments (max {})",
+            segments.len(),
+            MAX_BATCH_SEGMENTS
+        ));
+    }
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(segments);
+    }
+    meeting_queries::insert_meeting_segments(pool, &segments)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_action_item(
+    pool: SqlitePool,
+    item: MeetingActionItem,
+) -> Result<MeetingActionItem, String> {
+    item.validate()?;
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(item);
+    }
+    meeting_queries::insert_meeting_action_item(pool, &item)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_decision(
+    pool: SqlitePool,
+    decision: MeetingDecision,
+) -> Result<MeetingDecision, String> {
+    decision.validate()?;
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(decision);
+    }
+    meeting_queries::insert_meeting_decision(pool, &decision)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_conversation(
+    pool: SqlitePool,
+    conversation: Conversation,
+) -> Result<Conversation, String> {
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(conversation);
+    }
+    conversation_queries::insert_conversation(pool, &conversation)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+pub async fn create_message(pool: SqlitePool, message: Message) -> Result<Message, String> {
+    if incognito::is_enabled(pool.clone()).await? {
+        return Ok(message);
+    }
+    conversation_queries::insert_message(pool, &message)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::open_test_pool;
+    use crate::db::preferences_queries::{self, LOCAL_USER_ID};
+    use crate::domain::UserPreferences;
+
+    const NOW: &str = "2024-01-01T00:00:00.000Z";
+
+    const HISTORY_TABLES: [&str; 7] = [
+        "transcriptions",
+        "meetings",
+        "meeting_segments",
+        "meeting_action_items",
+        "meeting_decisions",
+        "conversations",
+        "messages",
+    ];
+
+    fn set_incognito(pool: &SqlitePool, enabled: bool) {
+        let preferences: UserPreferences = serde_json::from_value(serde_json::json!({
+            "userId": LOCAL_USER_ID,
+            "incognitoModeEnabled": enabled,
+        }))
+        .expect("preferences");
+        tauri::async_runtime::block_on(preferences_queries::upsert_user_preferences(
+            pool.clone(),
+            &preferences,
+        ))
+        .expect("save preferences");
+    }
+
+    fn count(pool: &SqlitePool, table: &str) -> i64 {
+        tauri::async_runtime::block_on(
+            sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {table}")).fetch_one(pool),
+        )
+        .expect("count rows")
+    }
+
+    fn record_session(pool: &SqlitePool) -> Result<(), String> {
+        let transcription: Transcription = serde_json::from_value(serde_json::json!({
+            "id": "t1",
+            "transcript": "hello",
+            "timestamp": 0,
+        }))
+        .expect("transcription");
+        let meeting: Meeting = serde_json::from_value(serde_json::json!({
+            "id": "m1",
+            "title": "Standup",
+            "startedAt": NOW,
+            "status": "completed",
+            "createdAt": NOW,
+            "updatedAt": NOW,
+        }))
+        .expect("meeting");
+        let segment: MeetingSegment = serde_json::from_value(serde_json::json!({
+            "id": "s1",
+            "meetingId": "m1",
+            "text": "hello",
+            "startMs": 0,
+            "endMs": 1000,
+            "createdAt": NOW,
+        }))
+        .expect("segment");
+        let item: MeetingActionItem = serde_json::from_value(serde_json::json!({
+            "id": "a1",
+            "meetingId": "m1",
+            "task": "Send notes",
+            "status": "open",
+            "createdAt": NOW,
+            "updatedAt": NOW,
+        }))
+        .expect("action item");
+        let decision: MeetingDecision = serde_json::from_value(serde_json::json!({
+            "id": "d1",
+            "meetingId": "m1",
+            "text": "Ship on Friday",
+            "createdAt": NOW,
+            "updatedAt": NOW,
+        }))
+        .expect("decision");
+        let conversation: Conversation = serde_json::from_value(serde_json::json!({
+            "id": "c1",
+            "title": "Chat",
+            "createdAt": NOW,
+            "updatedAt": NOW,
+        }))
+        .expect("conversation");
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "id": "msg1",
+            "conversationId": "c1",
+            "role": "user",
+            "content": "hello",
+            "contextJson": "{\"app\":\"Mail\"}",
+            "createdAt": NOW,
+        }))
+        .expect("message");
+
+        tauri::async_runtime::block_on(async {
+            let saved = create_transcription(pool.clone(), transcription).await?;
+            assert_eq!(saved.id, "t1");
+            let saved = create_meeting(pool.clone(), meeting).await?;
+            assert_eq!(saved.id, "m1");
+            let saved = create_meeting_segments(pool.clone(), vec![segment]).await?;
+            assert_eq!(saved.len(), 1);
+            let saved = create_action_item(pool.clone(), item).await?;
+            assert_eq!(saved.id, "a1");
+            let saved = create_decision(pool.clone(), decision).await?;
+            assert_eq!(saved.id, "d1");
+            let saved = create_conversation(pool.clone(), conversation).await?;
+            assert_eq!(saved.id, "c1");
+            let saved = create_message(pool.clone(), message).await?;
+            assert_eq!(saved.id, "msg1");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn incognito_session_writes_nothing() {
+        let pool = open_test_pool();
+        set_incognito(&pool, true);
+
+        record_session(&pool).expect("incognito writes are skipped, not refused");
+
+        for table in HISTORY_TABLES {
+            assert_eq!(count(&pool, table), 0, "{table} should stay empty");
+        }
+    }
+
+    #[test]
+    fn normal_session_persists_history() {
+        let pool = open_test_pool();
+        set_incognito(&pool, false);
+
+        record_session(&pool).expect("record session");
+
+        for table in HISTORY_TABLES {
+            assert_eq!(count(&pool, table), 1, "{table} should have a row");
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use sqlx::SqlitePool;
+
+use crate::db::preferences_queries::{self, LOCAL_USER_ID};
+use crate::db::user_queries;
+use crate::domain::User;
+
+pub const INCOGNITO_BLOCKED: &str =
+    "Incognito mode is on, so transcripts, recordings and chats are not saved.";
+
+pub async fn is_enabled(pool: SqlitePool) -> Result<bool, String> {
+    let preferences = preferences_queries::fetch_user_preferences(pool, LOCAL_USER_ID)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(preferences.is_some_and(|preferences| preferences.incognito_mode_enabled))
+}
+
+/// Fails with [`INCOGNITO_BLOCKED`] while incognito mode is on. Commands that
+/// import, fork or rewrite history check this first, so a frontend bug cannot
+/// leak an incognito session to disk; new recordings and chats are skipped
+/// quietly by `system::history` instead.
+pub async fn ensure_history_allowed(pool: SqlitePool) -> Result<(), String> {
+    if is_enabled(pool).await? {
+        Err(INCOGNITO_BLOCKED.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// The user row to save for `user`. While incognito mode is on, the only
+/// thing dictation may change is the anonymous usage counters, and only when
+/// `incognito_mode_include_in_stats` allows it; otherwise the stored
+/// counters are kept.
+pub async fn guard_usage(pool: SqlitePool, mut user: User) -> Result<User, String> {
+    let preferences = preferences_queries::fetch_user_preferences(pool.clone(), LOCAL_USER_ID)
+        .await
+        .map_err(|err| err.to_string())?;
+    let hides_usage = preferences.is_some_and(|preferences| {
+        preferences.incognito_mode_enabled && !preferences.incognito_mode_include_in_stats
+    });
+    if !hides_usage {
+        return Ok(user);
+    }
+
+    let stored = user_queries::fetch_user(pool)
+        .await
+        .map_err(|err| err.to_string())?;
+    if let Some(stored) = stored {
+        user.words_this_month = stored.words_this_month;
+        user.words_this_month_month = stored.words_this_month_month;
+        user.words_total = stored.words_total;
+        user.best_wpm = stored.best_wpm;
+        user.total_duration_ms = stored.total_duration_ms;
+    }
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::open_test_pool;
+    use crate::domain::UserPreferences;
+
+    fn set_incognito(pool: &SqlitePool, enabled: bool, include_in_stats: bool) {
+        let preferences: UserPreferences = serde_json::from_value(serde_json::json!({
+            "userId": LOCAL_USER_ID,
+            "incognitoModeEnabled": enabled,
+            "incognitoModeIncludeInStats": include_in_stats,
+        }))
+        .expect("preferences");
+        tauri::async_runtime::block_on(preferences_queries::upsert_user_preferences(
+            pool.clone(),
+            &preferences,
+        ))
+        .expect("save preferences");
+    }
+
+    fn user(words: i64, name: &str) -> User {
+        serde_json::from_value(serde_json::json!({
+            "id": LOCAL_USER_ID,
+            "name": name,
+            "bio": "",
+            "onboarded": true,
+            "wordsThisMonth": words,
+            "wordsTotal": words,
+            "bestWpm": words,
+            "totalDurationMs": words * 1000,
+        }))
+        .expect("user")
+    }
+
+    fn dictate(pool: &SqlitePool, words: i64, name: &str) -> User {
+        tauri::async_runtime::block_on(async {
+            let guarded = guard_usage(pool.clone(), user(words, name)).await?;
+            user_queries::upsert_user(pool.clone(), &guarded)
+                .await
+                .map_err(|err| err.to_string())
+        })
+        .expect("save user")
+    }
+
+    #[test]
+    fn incognito_keeps_usage_counters_unless_stats_are_included() {
+        let pool = open_test_pool();
+        set_incognito(&pool, false, false);
+        dictate(&pool, 10, "Ana");
+
+        set_incognito(&pool, true, false);
+        let saved = dictate(&pool, 25, "Ana B");
+        assert_eq!(saved.name, "Ana B");
+        assert_eq!(saved.words_total, 10);
+        assert_eq!(saved.words_this_month, 10);
+        assert_eq!(saved.best_wpm, 10);
+        assert_eq!(saved.total_duration_ms, 10_000);
+
+        set_incognito(&pool, true, true);
+        let saved = dictate(&pool, 25, "Ana B");
+        assert_eq!(saved.words_total, 25);
+        assert_eq!(saved.total_duration_ms, 25_000);
+    }
+
+    #[test]
+    fn ensure_history_allowed_follows_the_preference() {
+        let pool = open_test_pool();
+        let check = || tauri::async_runtime::block_on(ensure_history_allowed(pool.clone()));
+
+        assert_eq!(check(), Ok(()));
+        set_incognito(&pool, true, false);
+        assert_eq!(check(), Err(INCOGNITO_BLOCKED.to_string()));
+        set_incognito(&pool, false, false);
+        assert_eq!(check(), Ok(()));
+    }
+}
@@ -0,0 +1,1197 @@
+//! Inverse text normalization: spoken forms to written forms.
+//!
+//! Runs in the transcription pipeline, after dictionary replacements. Numbers, ordinals,
+//! dates, times, currency, percentages, units, emails and URLs that Whisper
+//! left spelled out are rewritten ("twenty five dollars on march third" becomes
+//! "$25 on March 3"). Rules are deliberately conservative: small standalone
+//! numbers stay as words, and runs of number words that do not parse as one
+//! value are left alone. Only English has rules today; other dictation
+//! languages pass through unchanged.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DateOrder {
+    MonthFirst,
+    DayFirst,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItnLocale {
+    date_order: DateOrder,
+    pounds_are_currency: bool,
+}
+
+impl ItnLocale {
+    pub const EN_US: Self = Self {
+        date_order: DateOrder::MonthFirst,
+        pounds_are_currency: false,
+    };
+
+    pub const EN_GB: Self = Self {
+        date_order: DateOrder::DayFirst,
+        pounds_are_currency: true,
+    };
+
+    /// Resolve a dictation language tag such as `en-GB`. Returns `None` for
+    /// languages without rules; a missing tag is treated as US English.
+    pub fn from_language_tag(tag: Option<&str>) -> Option<Self> {
+        let tag = tag
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .unwrap_or("en");
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next()?.to_ascii_lowercase();
+        if language != "en" {
+            return None;
+        }
+
+        let region = parts.next().map(str::to_ascii_uppercase);
+        let locale = match region.as_deref() {
+            Some("GB") | Some("UK") => Self::EN_GB,
+            Some("AU") | Some("NZ") | Some("IE") | Some("IN") | Some("ZA") => Self {
+                date_order: DateOrder::DayFirst,
+                pounds_are_currency: false,
+            },
+            _ => Self::EN_US,
+        };
+        Some(locale)
+    }
+
+    fn uppercase_meridiem(&self) -> bool {
+        self.date_order == DateOrder::MonthFirst
+    }
+}
+
+/// Normalize `text` for the given dictation language, or return it unchanged
+/// when the language has no rules.
+pub fn normalize_for_language(text: &str, language_tag: Option<&str>) -> String {
+    match ItnLocale::from_language_tag(language_tag) {
+        Some(locale) => normalize(text, locale),
+        None => text.to_string(),
+    }
+}
+
+/// The language a transcript is written in, for picking ITN rules: the
+/// dictation language, unless Whisper heard, or translated into, another one.
+pub fn transcript_language<'a>(
+    dictation_language: Option<&'a str>,
+    written_language: Option<&'a str>,
+) -> Option<&'a str> {
+    let base = |tag: &str| {
+        tag.split(['-', '_'])
+            .next()
+            .unwrap_or(tag)
+            .trim()
+            .to_ascii_lowercase()
+    };
+    match (dictation_language, written_language) {
+        (Some(dictation), Some(written)) if base(dictation) != base(written) => Some(written),
+        (None, written) => written,
+        (dictation, _) => dictation,
+    }
+}
+
+/// `normalize_for_language` for the transcription pipeline. A rule that
+/// panics must not cost the user their dictation, so the text is then kept
+/// as transcribed.
+pub fn normalize_or_keep(text: String, language_tag: Option<&str>) -> String {
+    std::panic::catch_unwind(|| normalize_for_language(&text, language_tag)).unwrap_or_else(|_| {
+        eprintln!("[itn] Normalization failed; keeping the transcript as is");
+        text
+    })
+}
+
+pub fn normalize(text: &str, locale: ItnLocale) -> String {
+    let (words, tail) = split_words(text);
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < words.len() {
+        let rewrite = try_email(&words, index)
+            .or_else(|| try_url(&words, index))
+            .or_else(|| try_date(&words, index, locale))
+            .or_else(|| try_time(&words, index, locale))
+            .or_else(|| try_quantity(&words, index, locale))
+            .or_else(|| try_plain_number(&words, index));
+
+        match rewrite {
+            Some(Rewrite::Replace { end, text }) => {
+                let first = &words[index];
+                output.push_str(first.space);
+                output.push_str(first.leading);
+                output.push_str(&text);
+                output.push_str(words[end - 1].trailing);
+                index = end;
+            }
+            Some(Rewrite::ReplaceWithTrailing { end, text }) => {
+                let first = &words[index];
+                output.push_str(first.space);
+                output.push_str(first.leading);
+                output.push_str(&text);
+                index = end;
+            }
+            Some(Rewrite::Keep { end }) => {
+                for word in &words[index..end] {
+                    word.write_to(&mut output);
+                }
+                index = end;
+            }
+            None => {
+                words[index].write_to(&mut output);
+                index += 1;
+            }
+        }
+    }
+
+    output.push_str(tail);
+    output
+}
+
+enum Rewrite {
+    Replace {
+        end: usize,
+        text: String,
+    },
+    /// Like `Replace`, but `text` already includes the last word's trailing
+    /// punctuation.
+    ReplaceWithTrailing {
+        end: usize,
+        text: String,
+    },
+    Keep {
+        end: usize,
+    },
+}
+
+#[derive(Debug)]
+struct Word<'a> {
+    /// Whitespace (or a hyphen, for split compounds) preceding the word.
+    space: &'a str,
+    leading: &'a str,
+    core: &'a str,
+    trailing: &'a str,
+    lower: String,
+}
+
+impl Word<'_> {
+    fn write_to(&self, output: &mut String) {
+        output.push_str(self.space);
+        output.push_str(self.leading);
+        output.push_str(self.core);
+        output.push_str(self.trailing);
+    }
+}
+
+fn split_words(text: &str) -> (Vec<Word<'_>>, &str) {
+    let mut words = Vec::new();
+    let mut rest = text;
+
+    loop {
+        let word_start = rest
+            .char_indices()
+            .find(|(_, ch)| !ch.is_whitespace())
+            .map(|(index, _)| index);
+        let Some(word_start) = word_start else {
+            return (words, rest);
+        };
+        let space = &rest[..word_start];
+        let after = &rest[word_start..];
+        let word_end = after
+            .char_indices()
+            .find(|(_, ch)| ch.is_whitespace())
+            .map(|(index, _)| index)
+            .unwrap_or(after.len());
+        push_segment(&mut words, space, &after[..word_end]);
+        rest = &after[word_end..];
+    }
+}
+
+fn push_segment<'a>(words: &mut Vec<Word<'a>>, space: &'a str, segment: &'a str) {
+    let core_start = segment
+        .char_indices()
+        .find(|(_, ch)| ch.is_alphanumeric())
+        .map(|(index, _)| index)
+        .unwrap_or(segment.len());
+    let core_end = segment[core_start..]
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_alphanumeric())
+        .map(|(index, ch)| core_start + index + ch.len_utf8())
+        .unwrap_or(core_start);
+    let leading = &segment[..core_start];
+    let core = &segment[core_start..core_end];
+    let trailing = &segment[core_end..];
+
+    // "twenty-five" is parsed as two number words joined by a hyphen.
+    let parts: Vec<&str> = core.split('-').collect();
+    if parts.len() > 1
+        && parts
+            .iter()
+            .all(|part| is_number_word(&part.to_lowercase()))
+    {
+        let mut offset = 0;
+        for (position, part) in parts.iter().enumerate() {
+            let part_space = if position == 0 {
+                space
+            } else {
+                &core[offset - 1..offset]
+            };
+            words.push(Word {
+                space: part_space,
+                leading: if position == 0 { leading } else { "" },
+                core: part,
+                trailing: if position == parts.len() - 1 {
+                    trailing
+                } else {
+                    ""
+                },
+                lower: part.to_lowercase(),
+            });
+            offset += part.len() + 1;
+        }
+        return;
+    }
+
+    words.push(Word {
+        space,
+        leading,
+        core,
+        trailing,
+        lower: core.to_lowercase().replace('\u{2019}', "'"),
+    });
+}
+
+/// Whether `words[index]` continues a phrase from the previous word, i.e. no
+/// punctuation separates them.
+fn joinable(words: &[Word], index: usize) -> bool {
+    index > 0
+        && index < words.len()
+        && words[index].leading.is_empty()
+        && words[index - 1].trailing.is_empty()
+}
+
+fn unit_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "zero" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn teen_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn tens_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn scale_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "thousand" => 1_000,
+        "million" => 1_000_000,
+        "billion" => 1_000_000_000,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn ordinal_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "first" => 1,
+        "second" => 2,
+        "third" => 3,
+        "fourth" => 4,
+        "fifth" => 5,
+        "sixth" => 6,
+        "seventh" => 7,
+        "eighth" => 8,
+        "ninth" => 9,
+        "tenth" => 10,
+        "eleventh" => 11,
+        "twelfth" => 12,
+        "thirteenth" => 13,
+        "fourteenth" => 14,
+        "fifteenth" => 15,
+        "sixteenth" => 16,
+        "seventeenth" => 17,
+        "eighteenth" => 18,
+        "nineteenth" => 19,
+        "twentieth" => 20,
+        "thirtieth" => 30,
+        "fortieth" => 40,
+        "fiftieth" => 50,
+        "sixtieth" => 60,
+        "seventieth" => 70,
+        "eightieth" => 80,
+        "ninetieth" => 90,
+        "hundredth" => 100,
+        "thousandth" => 1_000,
+        "millionth" => 1_000_000,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn is_number_word(word: &str) -> bool {
+    unit_value(word).is_some()
+        || teen_value(word).is_some()
+        || tens_value(word).is_some()
+        || scale_value(word).is_some()
+        || ordinal_value(word).is_some()
+        || word == "hundred"
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Last {
+    Start,
+    Unit,
+    Teen,
+    Tens,
+    Hundred,
+    Scale,
+    And,
+}
+
+#[derive(Debug)]
+struct NumberMatch {
+    integer: u64,
+    fraction: Option<String>,
+    ordinal: bool,
+    spelled: bool,
+    end: usize,
+}
+
+impl NumberMatch {
+    fn is_whole(&self) -> bool {
+        !self.ordinal && self.fraction.is_none()
+    }
+}
+
+fn parse_digits(core: &str) -> Option<(u64, Option<String>)> {
+    let (integer, fraction) = match core.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (core, None),
+    };
+    let integer = integer.replace(',', "");
+    if integer.is_empty() || !integer.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.chars().all(|ch| ch.is_ascii_digit()) {
+            return None;
+        }
+    }
+    Some((integer.parse().ok()?, fraction.map(str::to_string)))
+}
+
+/// Parse a number starting at `start`, either a digit token or a run of
+/// spelled-out number words.
+fn parse_number(words: &[Word], start: usize) -> Option<NumberMatch> {
+    let first = words.get(start)?;
+    if first.core.starts_with(|ch: char| ch.is_ascii_digit()) {
+        let (integer, fraction) = parse_digits(first.core)?;
+        return Some(NumberMatch {
+            integer,
+            fraction,
+            ordinal: false,
+            spelled: false,
+            end: start + 1,
+        });
+    }
+
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last = Last::Start;
+    let mut ordinal = false;
+    let mut smallest_scale = u64::MAX;
+    let mut index = start;
+    let mut end = start;
+
+    while index < words.len() {
+        if index > start && !joinable(words, index) {
+            break;
+        }
+        let word = words[index].lower.as_str();
+
+        if word == "and" {
+            if matches!(last, Last::Hundred | Last::Scale) {
+                last = Last::And;
+                index += 1;
+                continue;
+            }
+            break;
+        }
+
+        let after_group = matches!(last, Last::Start | Last::Hundred | Last::Scale | Last::And);
+        if let Some(value) = unit_value(word) {
+            let allowed = if value == 0 {
+                last == Last::Start
+            } else {
+                after_group || last == Last::Tens
+            };
+            if !allowed {
+                break;
+            }
+            current += value;
+            last = Last::Unit;
+        } else if let Some(value) = teen_value(word).or_else(|| tens_value(word)) {
+            if !after_group {
+                break;
+            }
+            current += value;
+            last = if value < 20 { Last::Teen } else { Last::Tens };
+        } else if word == "hundred" {
+            if !matches!(last, Last::Unit | Last::Teen) || current >= 100 {
+                break;
+            }
+            current *= 100;
+            last = Last::Hundred;
+        } else if let Some(scale) = scale_value(word) {
+            if !matches!(last, Last::Unit | Last::Teen | Last::Tens | Last::Hundred)
+                || scale >= smallest_scale
+            {
+                break;
+            }
+            total += current * scale;
+            current = 0;
+            smallest_scale = scale;
+            last = Last::Scale;
+        } else if let Some(value) = ordinal_value(word) {
+            let allowed = match value {
+                1..=9 => after_group || last == Last::Tens,
+                10..=90 => after_group,
+                100 => matches!(last, Last::Unit | Last::Teen) && current < 100,
+                _ => matches!(last, Last::Unit | Last::Teen | Last::Tens | Last::Hundred),
+            };
+            if !allowed {
+                break;
+            }
+            if value >= 100 {
+                total += current.max(1) * value;
+                current = 0;
+            } else {
+                current += value;
+            }
+            ordinal = true;
+            end = index + 1;
+            break;
+        } else {
+            break;
+        }
+
+        index += 1;
+        end = index;
+    }
+
+    if end == start {
+        return None;
+    }
+
+    let mut number = NumberMatch {
+        integer: total + current,
+        fraction: None,
+        ordinal,
+        spelled: true,
+        end,
+    };
+
+    if !ordinal && words.get(end).is_some_and(|word| word.lower == "point") && joinable(words, end)
+    {
+        let mut digits = String::new();
+        let mut index = end + 1;
+        while index < words.len() && joinable(words, index) {
+            let word = words[index].lower.as_str();
+            let digit = if word == "oh" {
+                Some(0)
+            } else {
+                unit_value(word)
+            };
+            let Some(digit) = digit else {
+                break;
+            };
+            digits.push(char::from(b'0' + digit as u8));
+            index += 1;
+        }
+        if !digits.is_empty() {
+            number.fraction = Some(digits);
+            number.end = index;
+        }
+    }
+
+    Some(number)
+}
+
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
+fn format_amount(number: &NumberMatch) -> String {
+    let mut text = group_thousands(number.integer);
+    if let Some(fraction) = &number.fraction {
+        text.push('.');
+        text.push_str(fraction);
+    }
+    text
+}
+
+fn ordinal_suffix(value: u64) -> &'static str {
+    match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+fn month_name(word: &str) -> Option<&'static str> {
+    let name = match word {
+        "january" => "January",
+        "february" => "February",
+        "march" => "March",
+        "april" => "April",
+        "may" => "May",
+        "june" => "June",
+        "july" => "July",
+        "august" => "August",
+        "september" => "September",
+        "october" => "October",
+        "november" => "November",
+        "december" => "December",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// A spelled day of the month. "May" doubles as a verb, so it only takes
+/// ordinal days.
+fn parse_day(words: &[Word], start: usize, month: &str) -> Option<(u64, usize)> {
+    let number = parse_number(words, start)?;
+    if !number.spelled || number.fraction.is_some() || !(1..=31).contains(&number.integer) {
+        return None;
+    }
+    if month == "May" && !number.ordinal {
+        return None;
+    }
+    Some((number.integer, number.end))
+}
+
+/// A year spoken as a pair ("nineteen ninety nine", "twenty oh five") or as a
+/// plain number ("two thousand twenty four"), or written as four digits.
+fn parse_year(words: &[Word], start: usize) -> Option<(u64, usize)> {
+    let first = words.get(start)?;
+    if first.core.len() == 4 && first.core.chars().all(|ch| ch.is_ascii_digit()) {
+        return Some((first.core.parse().ok()?, start + 1));
+    }
+
+    let century = parse_number(words, start)?;
+    if !century.spelled || !century.is_whole() {
+        return None;
+    }
+    if (1000..=2999).contains(&century.integer) {
+        return Some((century.integer, century.end));
+    }
+    if !(10..=99).contains(&century.integer) || !joinable(words, century.end) {
+        return None;
+    }
+
+    let next = &words[century.end];
+    if next.lower == "hundred" {
+        return Some((century.integer * 100, century.end + 1));
+    }
+    if next.lower == "oh" && joinable(words, century.end + 1) {
+        let digit = unit_value(&words[century.end + 1].lower).filter(|digit| *digit > 0)?;
+        return Some((century.integer * 100 + digit, century.end + 2));
+    }
+
+    let rest = parse_number(words, century.end)?;
+    if !rest.spelled || !rest.is_whole() || !(10..=99).contains(&rest.integer) {
+        return None;
+    }
+    Some((century.integer * 100 + rest.integer, rest.end))
+}
+
+fn format_date(month: &str, day: u64, year: Option<u64>, locale: ItnLocale) -> String {
+    match (locale.date_order, year) {
+        (DateOrder::MonthFirst, Some(year)) => format!("{month} {day}, {year}"),
+        (DateOrder::MonthFirst, None) => format!("{month} {day}"),
+        (DateOrder::DayFirst, Some(year)) => format!("{day} {month} {year}"),
+        (DateOrder::DayFirst, None) => format!("{day} {month}"),
+    }
+}
+
+/// A year may follow the day directly or after a comma.
+fn optional_year(words: &[Word], day_end: usize) -> Option<(u64, usize)> {
+    if day_end >= words.len() || !words[day_end].leading.is_empty() {
+        return None;
+    }
+    let trailing = words[day_end - 1].trailing;
+    if !(trailing.is_empty() || trailing == ",") {
+        return None;
+    }
+    parse_year(words, day_end)
+}
+
+/// A spelled year right after a month, as in "june twenty oh five". Only
+/// pair forms count, so "june twenty" stays a day.
+fn month_year(words: &[Word], start: usize, month: &str) -> Option<(u64, usize)> {
+    if month == "May" {
+        return None;
+    }
+    let (year, end) = parse_year(words, start)?;
+    let spelled = words[start].core.chars().any(|ch| !ch.is_ascii_digit());
+    (spelled && end - start >= 2).then_some((year, end))
+}
+
+fn try_date(words: &[Word], start: usize, locale: ItnLocale) -> Option<Rewrite> {
+    // "march third", "march third twenty twenty four", "june twenty oh five"
+    if let Some(month) = month_name(&words[start].lower) {
+        if !joinable(words, start + 1) {
+            return None;
+        }
+        let day = parse_day(words, start + 1, month);
+        let full_date = day.and_then(|(day, day_end)| {
+            let (year, end) = optional_year(words, day_end)?;
+            Some((day, year, end))
+        });
+        if let Some((day, year, end)) = full_date {
+            return Some(Rewrite::Replace {
+                end,
+                text: format_date(month, day, Some(year), locale),
+            });
+        }
+
+        let ordinal_day = parse_number(words, start + 1).is_some_and(|number| number.ordinal);
+        if !ordinal_day {
+            if let Some((year, end)) = month_year(words, start + 1, month) {
+                return Some(Rewrite::Replace {
+                    end,
+                    text: format!("{month} {year}"),
+                });
+            }
+        }
+
+        let (day, end) = day?;
+        return Some(Rewrite::Replace {
+            end,
+            text: format_date(month, day, None, locale),
+        });
+    }
+
+    // "the third of march", "third of march"
+    let day_start = if words[start].lower == "the" && joinable(words, start + 1) {
+        start + 1
+    } else {
+        start
+    };
+    let number = parse_number(words, day_start)?;
+    if !number.ordinal || !(1..=31).contains(&number.integer) {
+        return None;
+    }
+    let of_index = number.end;
+    if !joinable(words, of_index) || words[of_index].lower != "of" || !joinable(words, of_index + 1)
+    {
+        return None;
+    }
+    let month = month_name(&words[of_index + 1].lower)?;
+    let month_end = of_index + 2;
+    let (year, end) = match optional_year(words, month_end) {
+        Some((year, end)) => (Some(year), end),
+        None => (None, month_end),
+    };
+
+    let date = format_date(month, number.integer, year, locale);
+    Some(Rewrite::Replace { end, text: date })
+}
+
+fn meridiem(word: &Word) -> Option<&'static str> {
+    match word.lower.replace('.', "").as_str() {
+        "am" => Some("am"),
+        "pm" => Some("pm"),
+        _ => None,
+    }
+}
+
+fn try_time(words: &[Word], start: usize, locale: ItnLocale) -> Option<Rewrite> {
+    let hour = parse_number(words, start)?;
+    if !hour.is_whole() || !(1..=12).contains(&hour.integer) {
+        return None;
+    }
+
+    let mut index = hour.end;
+    let mut minutes: Option<u64> = None;
+    if joinable(words, index) {
+        if words[index].lower == "oh" && joinable(words, index + 1) {
+            if let Some(digit) = unit_value(&words[index + 1].lower).filter(|digit| *digit > 0) {
+                minutes = Some(digit);
+                index += 2;
+            }
+        } else if let Some(number) = parse_number(words, index) {
+            if number.spelled && number.is_whole() && (10..=59).contains(&number.integer) {
+                minutes = Some(number.integer);
+                index = number.end;
+            }
+        }
+    }
+
+    if !joinable(words, index) {
+        return None;
+    }
+
+    let marker = &words[index];
+    let suffix = if let Some(meridiem) = meridiem(marker) {
+        if locale.uppercase_meridiem() {
+            format!(" {}", meridiem.to_uppercase())
+        } else {
+            format!(" {meridiem}")
+        }
+    } else if marker.lower == "o'clock" && minutes.is_none() {
+        minutes = Some(0);
+        String::new()
+    } else {
+        return None;
+    };
+
+    let clock = match minutes {
+        Some(minutes) => format!("{}:{minutes:02}{suffix}", hour.integer),
+        None => format!("{}{suffix}", hour.integer),
+    };
+
+    // "p.m." carries its final dot as trailing punctuation. Keep it only as a
+    // sentence end, i.e. when no lowercase word follows.
+    let end = index + 1;
+    if let Some(rest) = marker.trailing.strip_prefix('.') {
+        let continues = words
+            .get(end)
+            .is_some_and(|next| next.core.starts_with(|ch: char| ch.is_lowercase()));
+        let period = if continues { "" } else { "." };
+        return Some(Rewrite::ReplaceWithTrailing {
+            end,
+            text: format!("{clock}{period}{rest}"),
+        });
+    }
+
+    Some(Rewrite::Replace { end, text: clock })
+}
+
+fn currency_symbol(word: &str, locale: ItnLocale) -> Option<&'static str> {
+    let symbol = match word {
+        "dollar" | "dollars" => "$",
+        "euro" | "euros" => "\u{20ac}",
+        "pound" | "pounds" if locale.pounds_are_currency => "\u{a3}",
+        "yen" => "\u{a5}",
+        "rupee" | "rupees" => "\u{20b9}",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+fn unit_symbol(word: &str, locale: ItnLocale) -> Option<&'static str> {
+    let symbol = match word {
+        "kilometer" | "kilometers" | "kilometre" | "kilometres" => "km",
+        "meter" | "meters" | "metre" | "metres" => "m",
+        "centimeter" | "centimeters" | "centimetre" | "centimetres" => "cm",
+        "millimeter" | "millimeters" | "millimetre" | "millimetres" => "mm",
+        "kilogram" | "kilograms" => "kg",
+        "gram" | "grams" => "g",
+        "mile" | "miles" => "mi",
+        "foot" | "feet" => "ft",
+        "inch" | "inches" => "in",
+        "pound" | "pounds" if !locale.pounds_are_currency => "lb",
+        "ounce" | "ounces" => "oz",
+        "liter" | "liters" | "litre" | "litres" => "L",
+        "milliliter" | "milliliters" | "millilitre" | "millilitres" => "mL",
+        "kilobyte" | "kilobytes" => "KB",
+        "megabyte" | "megabytes" => "MB",
+        "gigabyte" | "gigabytes" => "GB",
+        "terabyte" | "terabytes" => "TB",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+/// Amounts followed by a currency, "percent", a unit or degrees.
+fn try_quantity(words: &[Word], start: usize, locale: ItnLocale) -> Option<Rewrite> {
+    let amount = parse_number(words, start)?;
+    if amount.ordinal || !joinable(words, amount.end) {
+        return None;
+    }
+    let unit_index = amount.end;
+    let unit = words[unit_index].lower.as_str();
+
+    if let Some(symbol) = currency_symbol(unit, locale) {
+        let mut end = unit_index + 1;
+        let mut text = format!("{symbol}{}", format_amount(&amount));
+
+        // "five dollars and fifty cents"
+        if amount.fraction.is_none()
+            && joinable(words, end)
+            && words[end].lower == "and"
+            && joinable(words, end + 1)
+        {
+            if let Some(cents) = parse_number(words, end + 1) {
+                let cents_word = words.get(cents.end).map(|word| word.lower.as_str());
+                if cents.is_whole()
+                    && (1..=99).contains(&cents.integer)
+                    && joinable(words, cents.end)
+                    && matches!(cents_word, Some("cents" | "cent" | "pence" | "p"))
+                {
+                    text.push_str(&format!(".{:02}", cents.integer));
+                    end = cents.end + 1;
+                }
+            }
+        }
+        return Some(Rewrite::Replace { end, text });
+    }
+
+    if unit == "percent" {
+        return Some(Rewrite::Replace {
+            end: unit_index + 1,
+            text: format!("{}%", format_amount(&amount)),
+        });
+    }
+    if unit == "per" && joinable(words, unit_index + 1) && words[unit_index + 1].lower == "cent" {
+        return Some(Rewrite::Replace {
+            end: unit_index + 2,
+            text: format!("{}%", format_amount(&amount)),
+        });
+    }
+
+    if unit == "degree" || unit == "degrees" {
+        let scale = words
+            .get(unit_index + 1)
+            .filter(|_| joinable(words, unit_index + 1))
+            .and_then(|word| match word.lower.as_str() {
+                "celsius" | "centigrade" => Some("C"),
+                "fahrenheit" => Some("F"),
+                _ => None,
+            });
+        let (end, scale) = match scale {
+            Some(scale) => (unit_index + 2, scale),
+            None => (unit_index + 1, ""),
+        };
+        return Some(Rewrite::Replace {
+            end,
+            text: format!("{}\u{b0}{scale}", format_amount(&amount)),
+        });
+    }
+
+    let symbol = unit_symbol(unit, locale)?;
+    Some(Rewrite::Replace {
+        end: unit_index + 1,
+        text: format!("{} {symbol}", format_amount(&amount)),
+    })
+}
+
+/// Standalone spelled numbers. Small cardinals and ordinals ("one of them",
+/// "first place") read better as words and are left alone, as are runs of
+/// number words that do not form a single value ("twenty twenty").
+fn try_plain_number(words: &[Word], start: usize) -> Option<Rewrite> {
+    let number = parse_number(words, start)?;
+    if !number.spelled {
+        return None;
+    }
+
+    if joinable(words, number.end) && is_number_word(&words[number.end].lower) {
+        let mut end = number.end;
+        while end < words.len()
+            && (end == number.end || joinable(words, end))
+            && (is_number_word(&words[end].lower) || words[end].lower == "and")
+        {
+            end += 1;
+        }
+        return Some(Rewrite::Keep { end });
+    }
+
+    if number.ordinal {
+        if number.integer < 10 {
+            return None;
+        }
+        return Some(Rewrite::Replace {
+            end: number.end,
+            text: format!("{}{}", number.integer, ordinal_suffix(number.integer)),
+        });
+    }
+
+    if number.integer < 10 && number.fraction.is_none() {
+        return None;
+    }
+    Some(Rewrite::Replace {
+        end: number.end,
+        text: format_amount(&number),
+    })
+}
+
+const TOP_LEVEL_DOMAINS: &[&str] = &[
+    "com", "org", "net", "io", "dev", "ai", "app", "co", "uk", "us", "edu", "gov", "me", "info",
+    "biz", "tv", "ca", "de", "fr",
+];
+
+/// Words that mark what follows as an email address.
+const EMAIL_TRIGGERS: &[&str] = &["email", "e-mail", "emailed", "emailing", "address"];
+/// How many words before the mailbox a trigger may appear, as in "my email
+/// address is ...".
+const EMAIL_TRIGGER_WINDOW: usize = 4;
+
+/// Words that precede "at" in ordinary speech and are never a mailbox name.
+const NON_MAILBOX_WORDS: &[&str] = &[
+    "me", "us", "him", "her", "them", "you", "it", "this", "that", "here", "there", "home", "work",
+    "least", "all", "once", "first", "last", "look", "looking", "is", "are", "was",
+];
+
+fn is_label(word: &Word) -> bool {
+    !word.core.is_empty()
+        && word
+            .core
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || ch == '-')
+        && !matches!(word.lower.as_str(), "dot" | "at" | "slash")
+}
+
+/// Parse `label (dot label)+` ending in a known top-level domain. Returns the
+/// joined host and the index after it.
+fn parse_domain(words: &[Word], start: usize) -> Option<(String, usize)> {
+    if !words.get(start).is_some_and(is_label) {
+        return None;
+    }
+    let mut labels = vec![words[start].lower.clone()];
+    let mut index = start + 1;
+    while joinable(words, index)
+        && words[index].lower == "dot"
+        && joinable(words, index + 1)
+        && is_label(&words[index + 1])
+    {
+        labels.push(words[index + 1].lower.clone());
+        index += 2;
+    }
+
+    let tld = labels.last()?;
+    if labels.len() < 2 || !TOP_LEVEL_DOMAINS.contains(&tld.as_str()) {
+        return None;
+    }
+    Some((labels.join("."), index))
+}
+
+/// "team at acme dot io" is just as likely to be prose, so an address needs
+/// a trigger word shortly before it or an explicit "at sign".
+fn try_email(words: &[Word], start: usize) -> Option<Rewrite> {
+    let first = &words[start];
+    if !is_label(first) || NON_MAILBOX_WORDS.contains(&first.lower.as_str()) {
+        return None;
+    }
+    let triggered = words[start.saturating_sub(EMAIL_TRIGGER_WINDOW)..start]
+        .iter()
+        .any(|word| EMAIL_TRIGGERS.contains(&word.lower.as_str()));
+
+    let mut mailbox = first.lower.clone();
+    let mut index = start + 1;
+    loop {
+        if !joinable(words, index) || !joinable(words, index + 1) || !is_label(&words[index + 1]) {
+            break;
+        }
+        let connector = match words[index].lower.as_str() {
+            "dot" => '.',
+            "underscore" => '_',
+            "dash" | "hyphen" => '-',
+            _ => break,
+        };
+        mailbox.push(connector);
+        mailbox.push_str(&words[index + 1].lower);
+        index += 2;
+    }
+
+    if !joinable(words, index) || words[index].lower != "at" || !joinable(words, index + 1) {
+        return None;
+    }
+    let mut domain_start = index + 1;
+    if words[domain_start].lower == "sign" && joinable(words, domain_start + 1) {
+        domain_start += 1;
+    } else if !triggered {
+        return None;
+    }
+    let (domain, end) = parse_domain(words, domain_start)?;
+    Some(Rewrite::Replace {
+        end,
+        text: format!("{mailbox}@{domain}"),
+    })
+}
+
+fn try_url(words: &[Word], start: usize) -> Option<Rewrite> {
+    let (mut url, mut index) = parse_domain(words, start)?;
+    while joinable(words, index)
+        && words[index].lower == "slash"
+        && joinable(words, index + 1)
+        && is_label(&words[index + 1])
+    {
+        url.push('/');
+        url.push_str(&words[index + 1].lower);
+        index += 2;
+    }
+    Some(Rewrite::Replace {
+        end: index,
+        text: url,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(text: &str) -> String {
+        normalize(text, ItnLocale::EN_US)
+    }
+
+    fn gb(text: &str) -> String {
+        normalize(text, ItnLocale::EN_GB)
+    }
+
+    #[test]
+    fn locale_comes_from_dictation_language() {
+        assert_eq!(ItnLocale::from_language_tag(None), Some(ItnLocale::EN_US));
+        assert_eq!(
+            ItnLocale::from_language_tag(Some("en-GB")),
+            Some(ItnLocale::EN_GB)
+        );
+        assert_eq!(ItnLocale::from_language_tag(Some("fr-FR")), None);
+        assert_eq!(
+            transcript_language(Some("en-GB"), Some("en")),
+            Some("en-GB")
+        );
+        assert_eq!(transcript_language(Some("en-GB"), Some("fr")), Some("fr"));
+        assert_eq!(transcript_language(None, Some("de")), Some("de"));
+        assert_eq!(transcript_language(Some("en-GB"), None), Some("en-GB"));
+        assert_eq!(
+            normalize_for_language("vingt cinq dollars", Some("fr")),
+            "vingt cinq dollars"
+        );
+    }
+
+    #[test]
+    fn numbers_and_ordinals() {
+        assert_eq!(us("one of the two options"), "one of the two options");
+        assert_eq!(us("about twenty five people"), "about 25 people");
+        assert_eq!(us("twenty-five people"), "25 people");
+        assert_eq!(us("one hundred and twenty three items"), "123 items");
+        assert_eq!(us("two million three hundred thousand"), "2,300,000");
+        assert_eq!(us("pi is three point one four"), "pi is 3.14");
+        assert_eq!(us("the twenty first century"), "the 21st century");
+        assert_eq!(
+            us("first place and twelfth place"),
+            "first place and 12th place"
+        );
+        assert_eq!(us("five six seven"), "five six seven");
+        assert_eq!(us("in twenty twenty"), "in twenty twenty");
+        assert_eq!(us("twenty, thirty"), "20, 30");
+    }
+
+    #[test]
+    fn dates_follow_locale_order() {
+        assert_eq!(us("twenty five dollars on march third"), "$25 on March 3");
+        assert_eq!(
+            us("due march third, twenty twenty four."),
+            "due March 3, 2024."
+        );
+        assert_eq!(us("on the first of may"), "on May 1");
+        assert_eq!(gb("on march third nineteen ninety nine"), "on 3 March 1999");
+        assert_eq!(gb("the fifth of november"), "5 November");
+        assert_eq!(us("you may five times"), "you may five times");
+        assert_eq!(us("june twenty oh five"), "June 2005");
+        assert_eq!(gb("in june nineteen ninety nine"), "in June 1999");
+        assert_eq!(us("june twenty"), "June 20");
+        assert_eq!(us("june twentieth twenty oh five"), "June 20, 2005");
+        assert_eq!(us("march twenty five twenty twenty four"), "March 25, 2024");
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(us("meet at three thirty pm"), "meet at 3:30 PM");
+        assert_eq!(us("at ten oh five a.m. tomorrow"), "at 10:05 AM tomorrow");
+        assert_eq!(us("Call at seven p.m."), "Call at 7 PM.");
+        assert_eq!(gb("at seven pm"), "at 7 pm");
+        assert_eq!(us("at five o'clock"), "at 5:00");
+        assert_eq!(us("three thirty"), "three thirty");
+    }
+
+    #[test]
+    fn currency_percent_and_units() {
+        assert_eq!(
+            us("it costs five dollars and fifty cents"),
+            "it costs $5.50"
+        );
+        assert_eq!(us("a 25 dollar fee"), "a $25 fee");
+        assert_eq!(us("two thousand euros"), "\u{20ac}2,000");
+        assert_eq!(us("up fifteen percent"), "up 15%");
+        assert_eq!(gb("three per cent"), "3%");
+        assert_eq!(us("five pounds of flour"), "5 lb of flour");
+        assert_eq!(gb("five pounds for lunch"), "\u{a3}5 for lunch");
+        assert_eq!(us("run five kilometers"), "run 5 km");
+        assert_eq!(us("twenty degrees celsius"), "20\u{b0}C");
+    }
+
+    #[test]
+    fn emails_and_urls() {
+        assert_eq!(
+            us("email john dot smith at example dot com today"),
+            "email john.smith@example.com today"
+        );
+        assert_eq!(
+            us("my email address is team at acme dot io"),
+            "my email address is team@acme.io"
+        );
+        assert_eq!(us("ping team at sign acme dot io"), "ping team@acme.io");
+        assert_eq!(us("the team at acme dot io"), "the team at acme.io");
+        assert_eq!(us("write to me at home"), "write to me at home");
+        assert_eq!(
+            us("visit www dot example dot co dot uk slash pricing."),
+            "visit www.example.co.uk/pricing."
+        );
+        assert_eq!(us("that was a dot product"), "that was a dot product");
+    }
+
+    #[test]
+    fn whitespace_and_punctuation_survive() {
+        assert_eq!(us("  (twenty five)  \n"), "  (25)  \n");
+        assert_eq!(us(""), "");
+    }
+}
@@ -0,0 +1,148 @@
+//! Picks the dictation language from a shortlist of the user's languages.
+//!
+//! Whisper's own detection considers every language it knows, which makes
+//! short clips from bilingual speakers land in a third language. Restricting
+//! it to the configured languages and falling back to the last one used when
+//! the shortlist is close keeps those clips in the right language.
+
+/// Shortlist probability a detected language needs to be used.
+pub const MIN_LANGUAGE_PROBABILITY: f32 = 0.6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanguageChoice {
+    pub language: String,
+    /// Probability of `language` within the shortlist.
+    pub probability: f32,
+    /// Whether detection was sure enough; otherwise this is the fallback.
+    pub confident: bool,
+}
+
+/// Bare Whisper language codes for the candidates, in order and without
+/// duplicates.
+pub fn shortlist(candidates: &[String]) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let code = base_language(candidate);
+        if !code.is_empty() && !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    codes
+}
+
+/// The shortlist to detect the language from, or nothing when detection is
+/// off in the preferences or the language is fixed.
+pub fn detection_candidates(
+    detection_enabled: bool,
+    fixed_language: Option<&str>,
+    candidates: &[String],
+) -> Vec<String> {
+    if !detection_enabled || fixed_language.is_some() {
+        return Vec::new();
+    }
+    shortlist(candidates)
+}
+
+/// Chooses among `candidates` given Whisper's probability for each language
+/// code. Probabilities are renormalised over the shortlist. When the best
+/// candidate is below the threshold, `fallback` is used if it is on the
+/// shortlist, otherwise the first candidate.
+pub fn choose(
+    probabilities: &[(String, f32)],
+    candidates: &[String],
+    fallback: Option<&str>,
+) -> Option<LanguageChoice> {
+    let candidates = shortlist(candidates);
+    let probability_of = |code: &str| {
+        probabilities
+            .iter()
+            .find(|(language, _)| language == code)
+            .map_or(0.0, |&(_, probability)| probability.max(0.0))
+    };
+    let total: f32 = candidates.iter().map(|code| probability_of(code)).sum();
+    let share = |code: &str| {
+        if total > 0.0 {
+            probability_of(code) / total
+        } else {
+            0.0
+        }
+    };
+
+    let best = candidates
+        .iter()
+        .max_by(|a, b| share(a).total_cmp(&share(b)))?;
+    if share(best) >= MIN_LANGUAGE_PROBABILITY {
+        return Some(LanguageChoice {
+            language: best.clone(),
+            probability: share(best),
+            confident: true,
+        });
+    }
+
+    let fallback = fallback
+        .map(base_language)
+        .filter(|code| candidates.contains(code))
+        .unwrap_or_else(|| candidates[0].clone());
+    Some(LanguageChoice {
+        probability: share(&fallback),
+        language: fallback,
+        confident: false,
+    })
+}
+
+fn base_language(value: &str) -> String {
+    value
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probabilities(values: &[(&str, f32)]) -> Vec<(String, f32)> {
+        values
+            .iter()
+            .map(|&(code, probability)| (code.to_string(), probability))
+            .collect()
+    }
+
+    #[test]
+    fn restricts_detection_to_the_shortlist() {
+        let candidates = vec!["en-US".to_string(), "es".to_string(), "EN".to_string()];
+        assert_eq!(shortlist(&candidates), ["en", "es"]);
+
+        // Portuguese wins overall but is not one of the user's languages.
+        let detected = probabilities(&[("pt", 0.5), ("es", 0.4), ("en", 0.1)]);
+        let choice = choose(&detected, &candidates, Some("en")).expect("choice");
+        assert_eq!(choice.language, "es");
+        assert!(choice.confident);
+        assert!((choice.probability - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn detection_follows_the_preference() {
+        let candidates = vec!["en-US".to_string(), "de".to_string()];
+        assert_eq!(detection_candidates(true, None, &candidates), ["en", "de"]);
+        assert!(detection_candidates(false, None, &candidates).is_empty());
+        assert!(detection_candidates(true, Some("fr"), &candidates).is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_the_last_language_when_unsure() {
+        let candidates = vec!["en".to_string(), "fr".to_string()];
+        let detected = probabilities(&[("en", 0.3), ("fr", 0.25)]);
+
+        let choice = choose(&detected, &candidates, Some("fr-CA")).expect("choice");
+        assert_eq!(choice.language, "fr");
+        assert!(!choice.confident);
+
+        let choice = choose(&[], &candidates, Some("de")).expect("choice");
+        assert_eq!((choice.language.as_str(), choice.probability), ("en", 0.0));
+
+        assert_eq!(choose(&detected, &[], None), None);
+    }
+}
@@ -0,0 +1,925 @@
+//! Opt-in loopback HTTP/WebSocket API so local tools can drive dictation.
+//! Requests run through the same command functions as the webview, so the
+//! recorder, transcriber and emitted Tauri events are shared with the UI.
+//!
+//! Every request needs the bearer token (`Authorization: Bearer …`). The
+//! `/v1/events` WebSocket upgrade also accepts `?token=` because browser
+//! clients cannot set headers there.
+//!
+//! - `GET  /v1/status`
+//! - `POST /v1/recording/start` `{ preferredMicrophone? }`
+//! - `POST /v1/recording/stop` `{ transcribe?, options? }`
+//! - `POST /v1/transcribe` `{ path, options? }`
+//! - `POST /v1/paste` `{ text, keybind? }`
+//! - `GET  /v1/events` WebSocket stream of `LocalApiEvent`s
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tauri::{AppHandle, EventId, Listener, Manager};
+
+use crate::commands::{StartRecordingArgs, TranscriptionOptionsDto};
+use crate::domain::{
+    LocalApiEvent, LocalApiSettings, OverlayPhase, EVT_AUDIO_CHUNK, EVT_OVERLAY_PHASE,
+    EVT_REC_LEVEL,
+};
+use crate::platform::{Recorder, Transcriber};
+use crate::system::websocket;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+const WS_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(20);
+const PARTIAL_INTERVAL: Duration = Duration::from_millis(1500);
+/// Partials re-transcribe at most this much trailing audio.
+const PARTIAL_WINDOW_SECS: usize = 30;
+const FALLBACK_SAMPLE_RATE: u32 = 16_000;
+/// Open connections, event streams included, before new ones get a 503.
+const MAX_CONNECTIONS: usize = 16;
+
+/// Checks bearer tokens against the salted hash stored in the database.
+#[derive(Clone)]
+pub struct TokenVerifier {
+    salt_b64: String,
+    hash_b64: String,
+}
+
+impl TokenVerifier {
+    pub fn from_settings(settings: &LocalApiSettings) -> Option<Self> {
+        Some(Self {
+            salt_b64: settings.token_salt.clone()?,
+            hash_b64: settings.token_hash.clone()?,
+        })
+    }
+
+    fn verify(&self, token: &str) -> bool {
+        crate::system::crypto::verify_api_key(token, &self.salt_b64, &self.hash_b64)
+    }
+}
+
+/// A running server. Dropping it stops accepting connections and closes
+/// open event streams.
+pub struct LocalApiServer {
+    addr: SocketAddr,
+    app: AppHandle,
+    hub: Arc<EventHub>,
+    shutdown: Arc<AtomicBool>,
+    listeners: Vec<EventId>,
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+impl LocalApiServer {
+    pub fn start(app: AppHandle, port: u16, verifier: TokenVerifier) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let hub = Arc::new(EventHub::default());
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let listeners = forward_app_events(&app, &hub);
+
+        let context = Arc::new(ApiContext {
+            app: app.clone(),
+            hub: Arc::clone(&hub),
+            verifier,
+        });
+        let accept_shutdown = Arc::clone(&shutdown);
+        let accept_thread = thread::Builder::new()
+            .name("local-api".into())
+            .spawn(move || accept_loop(listener, context, accept_shutdown))?;
+
+        let partial_app = app.clone();
+        let partial_hub = Arc::clone(&hub);
+        let partial_shutdown = Arc::clone(&shutdown);
+        // Detached: a partial transcription in flight can take seconds, and
+        // the thread exits on its own once it sees the shutdown flag.
+        thread::Builder::new()
+            .name("local-api-partials".into())
+            .spawn(move || run_partials(partial_app, partial_hub, partial_shutdown))?;
+
+        eprintln!("[local_api] Listening on http://{addr}");
+        Ok(Self {
+            addr,
+            app,
+            hub,
+            shutdown,
+            listeners,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for LocalApiServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        for id in self.listeners.drain(..) {
+            self.app.unlisten(id);
+        }
+        self.hub.close();
+        // The accept loop polls the flag every `POLL_INTERVAL`; waiting for it
+        // releases the port before a restart binds it again.
+        if let Some(handle) = self.accept_thread.take() {
+            let _ = handle.join();
+        }
+        eprintln!("[local_api] Stopped listening on {}", self.addr);
+    }
+}
+
+struct ApiContext {
+    app: AppHandle,
+    hub: Arc<EventHub>,
+    verifier: TokenVerifier,
+}
+
+/// Fans events out to WebSocket clients and buffers recording audio for
+/// partial transcripts while anyone is listening.
+#[derive(Default)]
+struct EventHub {
+    subscribers: Mutex<Vec<Sender<String>>>,
+    audio: Mutex<PartialAudio>,
+}
+
+#[derive(Default)]
+struct PartialAudio {
+    samples: Vec<f32>,
+    sample_rate: u32,
+    pending: bool,
+    last_text: String,
+}
+
+impl PartialAudio {
+    fn window_samples(&self) -> usize {
+        let sample_rate = match self.sample_rate {
+            0 => FALLBACK_SAMPLE_RATE,
+            rate => rate,
+        };
+        PARTIAL_WINDOW_SECS * sample_rate as usize
+    }
+}
+
+impl EventHub {
+    fn subscribe(&self) -> Receiver<String> {
+        let (sender, receiver) = mpsc::channel();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(sender);
+        }
+        receiver
+    }
+
+    fn has_subscribers(&self) -> bool {
+        self.subscribers
+            .lock()
+            .map(|subscribers| !subscribers.is_empty())
+            .unwrap_or(false)
+    }
+
+    fn broadcast(&self, event: &LocalApiEvent) {
+        let Ok(mut subscribers) = self.subscribers.lock() else {
+            return;
+        };
+        if subscribers.is_empty() {
+            return;
+        }
+        match serde_json::to_string(event) {
+            Ok(message) => subscribers.retain(|sender| sender.send(message.clone()).is_ok()),
+            Err(err) => eprintln!("[local_api] Failed to serialize event: {err}"),
+        }
+    }
+
+    fn close(&self) {
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.clear();
+        }
+    }
+
+    fn reset_audio(&self, sample_rate: u32) {
+        if let Ok(mut audio) = self.audio.lock() {
+            *audio = PartialAudio {
+                sample_rate,
+                ..PartialAudio::default()
+            };
+        }
+    }
+
+    fn push_audio(&self, samples: &[f32]) {
+        if let Ok(mut audio) = self.audio.lock() {
+            audio.samples.extend_from_slice(samples);
+            let excess = audio.samples.len().saturating_sub(audio.window_samples());
+            audio.samples.drain(..excess);
+            audio.pending = true;
+        }
+    }
+
+    fn take_pending_audio(&self) -> Option<(Vec<f32>, u32)> {
+        let mut audio = self.audio.lock().ok()?;
+        if !audio.pending {
+            return None;
+        }
+        audio.pending = false;
+        Some((audio.samples.clone(), audio.sample_rate))
+    }
+
+    /// Remember the latest partial; returns false when it did not change.
+    fn record_partial(&self, text: &str) -> bool {
+        let Ok(mut audio) = self.audio.lock() else {
+            return false;
+        };
+        if audio.last_text == text {
+            return false;
+        }
+        audio.last_text = text.to_string();
+        true
+    }
+}
+
+#[derive(Deserialize)]
+struct LevelEventPayload {
+    levels: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct ChunkEventPayload {
+    samples: Vec<f32>,
+}
+
+#[derive(Deserialize)]
+struct PhaseEventPayload {
+    phase: OverlayPhase,
+}
+
+fn recorder_sample_rate(app: &AppHandle) -> u32 {
+    app.try_state::<Arc<dyn Recorder>>()
+        .and_then(|recorder| recorder.current_sample_rate())
+        .unwrap_or(FALLBACK_SAMPLE_RATE)
+}
+
+fn forward_app_events(app: &AppHandle, hub: &Arc<EventHub>) -> Vec<EventId> {
+    let level_hub = Arc::clone(hub);
+    let chunk_hub = Arc::clone(hub);
+    let phase_hub = Arc::clone(hub);
+    let phase_app = app.clone();
+
+    vec![
+        app.listen_any(EVT_REC_LEVEL, move |event| {
+            if !level_hub.has_subscribers() {
+                return;
+            }
+            if let Ok(payload) = serde_json::from_str::<LevelEventPayload>(event.payload()) {
+                level_hub.broadcast(&LocalApiEvent::Level {
+                    levels: payload.levels,
+                });
+            }
+        }),
+        app.listen_any(EVT_AUDIO_CHUNK, move |event| {
+            if !chunk_hub.has_subscribers() {
+                return;
+            }
+            if let Ok(payload) = serde_json::from_str::<ChunkEventPayload>(event.payload()) {
+                chunk_hub.push_audio(&payload.samples);
+            }
+        }),
+        app.listen_any(EVT_OVERLAY_PHASE, move |event| {
+            if let Ok(payload) = serde_json::from_str::<PhaseEventPayload>(event.payload()) {
+                if payload.phase == OverlayPhase::Recording {
+                    phase_hub.reset_audio(recorder_sample_rate(&phase_app));
+                }
+                phase_hub.broadcast(&LocalApiEvent::Phase {
+                    phase: payload.phase,
+                });
+            }
+        }),
+    ]
+}
+
+/// Periodically re-transcribe the trailing audio of the current recording
+/// and push the text to event stream clients.
+fn run_partials(app: AppHandle, hub: Arc<EventHub>, shutdown: Arc<AtomicBool>) {
+    let mut last_run = Instant::now();
+    while !shutdown.load(Ordering::Relaxed) {
+        thread::sleep(POLL_INTERVAL);
+        if last_run.elapsed() < PARTIAL_INTERVAL {
+            continue;
+        }
+        last_run = Instant::now();
+
+        let Some((samples, sample_rate)) = hub.take_pending_audio() else {
+            continue;
+        };
+        let Some(transcriber) = app
+            .try_state::<crate::state::TranscriberState>()
+            .and_then(|state| state.get().cloned())
+        else {
+            continue;
+        };
+
+        let sample_rate = match sample_rate {
+            0 => recorder_sample_rate(&app),
+            rate => rate,
+        };
+        match transcriber.transcribe(&samples, sample_rate, None) {
+            Ok(text) => {
+                let text = text.trim();
+                if !text.is_empty() && hub.record_partial(text) {
+                    hub.broadcast(&LocalApiEvent::Partial {
+                        text: text.to_string(),
+                    });
+                }
+            }
+            Err(err) => eprintln!("[local_api] Partial transcription failed: {err}"),
+        }
+    }
+}
+
+/// Counts a connection thread for as long as it is alive.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(active: &Arc<AtomicUsize>) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < MAX_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn accept_loop(listener: TcpListener, context: Arc<ApiContext>, shutdown: Arc<AtomicBool>) {
+    let active = Arc::new(AtomicUsize::new(0));
+    while !shutdown.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                let Some(slot) = ConnectionSlot::acquire(&active) else {
+                    let _ = respond(
+                        &mut stream,
+                        503,
+                        &json!({ "error": "Too many connections" }),
+                    );
+                    continue;
+                };
+                let context = Arc::clone(&context);
+                let spawned = thread::Builder::new()
+                    .name("local-api-connection".into())
+                    .spawn(move || {
+                        let _slot = slot;
+                        if let Err(err) = handle_connection(stream, &context) {
+                            eprintln!("[local_api] Connection error: {err}");
+                        }
+                    });
+                if let Err(err) = spawned {
+                    eprintln!("[local_api] Failed to spawn connection thread: {err}");
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(err) => {
+                eprintln!("[local_api] Accept failed: {err}");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    fn is_event_stream_upgrade(&self) -> bool {
+        self.method == "GET"
+            && self.path == "/v1/events"
+            && self
+                .header("upgrade")
+                .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+    }
+
+    /// The `Authorization` header, or `?token=` on the event stream upgrade
+    /// only, so tokens do not end up in URLs for ordinary requests.
+    fn bearer_token(&self) -> Option<&str> {
+        let query_token = || {
+            self.query
+                .get("token")
+                .map(String::as_str)
+                .filter(|_| self.is_event_stream_upgrade())
+        };
+        self.header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .or_else(query_token)
+            .map(str::trim)
+    }
+
+    fn is_authorized(&self, verifier: &TokenVerifier) -> bool {
+        self.bearer_token()
+            .is_some_and(|token| !token.is_empty() && verifier.verify(token))
+    }
+}
+
+struct ApiError {
+    status: u16,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        Self::new(500, message)
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, context: &ApiContext) -> io::Result<()> {
+    // Listeners in non-blocking mode hand out non-blocking sockets on some
+    // platforms.
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(REQUEST_READ_TIMEOUT))?;
+
+    let request = match read_request(&mut stream) {
+        Ok(request) => request,
+        Err(err) => return respond(&mut stream, 400, &json!({ "error": err.to_string() })),
+    };
+
+    if !request.is_authorized(&context.verifier) {
+        return respond(&mut stream, 401, &json!({ "error": "Unauthorized" }));
+    }
+
+    let Some(endpoint) = Endpoint::from_request(&request) else {
+        return respond(&mut stream, 404, &json!({ "error": "Not found" }));
+    };
+    if endpoint == Endpoint::Events {
+        return serve_events(stream, &request, &context.hub);
+    }
+
+    match route(endpoint, &request, context) {
+        Ok(body) => respond(&mut stream, 200, &body),
+        Err(err) => respond(&mut stream, err.status, &json!({ "error": err.message })),
+    }
+}
+
+fn read_request(stream: impl Read) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut header_bytes = 0;
+    let mut read_line = |reader: &mut dyn BufRead| -> io::Result<String> {
+        let mut line = String::new();
+        header_bytes += Read::take(&mut *reader, (MAX_HEADER_BYTES + 1 - header_bytes) as u64)
+            .read_line(&mut line)?;
+        if header_bytes > MAX_HEADER_BYTES {
+            return Err(invalid_request("Request headers too large"));
+        }
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    };
+
+    let request_line = read_line(&mut reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(invalid_request("Malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    let mut headers = HashMap::new();
+    loop {
+        let line = read_line(&mut reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = headers
+        .get("content-length")
+        .map(|value| {
+            value
+                .parse::<usize>()
+                .map_err(|_| invalid_request("Invalid Content-Length"))
+        })
+        .transpose()?
+        .unwrap_or(0);
+    if content_length > MAX_BODY_BYTES {
+        return Err(invalid_request("Request body too large"));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query,
+        headers,
+        body,
+    })
+}
+
+fn invalid_request(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn respond(stream: &mut impl Write, status: u16, body: &Value) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        422 => "Unprocessable Entity",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let body = body.to_string();
+
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Endpoint {
+    Status,
+    RecordingStart,
+    RecordingStop,
+    Transcribe,
+    Paste,
+    Events,
+}
+
+impl Endpoint {
+    fn from_request(request: &Request) -> Option<Self> {
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/v1/status") => Some(Self::Status),
+            ("POST", "/v1/recording/start") => Some(Self::RecordingStart),
+            ("POST", "/v1/recording/stop") => Some(Self::RecordingStop),
+            ("POST", "/v1/transcribe") => Some(Self::Transcribe),
+            ("POST", "/v1/paste") => Some(Self::Paste),
+            ("GET", "/v1/events") => Some(Self::Events),
+            _ => None,
+        }
+    }
+}
+
+fn route(endpoint: Endpoint, request: &Request, context: &ApiContext) -> Result<Value, ApiError> {
+    let app = &context.app;
+    match endpoint {
+        Endpoint::Status => Ok(json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "phase": app.state::<crate::state::OverlayState>().get_phase(),
+            "transcriberReady": app.state::<crate::state::TranscriberState>().is_initialized(),
+        })),
+        Endpoint::RecordingStart => {
+            let args = parse_optional_body::<StartRecordingArgs>(request)?;
+            let response = tauri::async_runtime::block_on(crate::commands::start_recording(
+                app.clone(),
+                app.state(),
+                app.state(),
+                app.state(),
+                Some(args),
+            ))?;
+            context.hub.reset_audio(response.sample_rate);
+            context.hub.broadcast(&LocalApiEvent::RecordingStarted {
+                sample_rate: response.sample_rate,
+            });
+            Ok(json!(response))
+        }
+        Endpoint::RecordingStop => {
+            let body = parse_optional_body::<StopRecordingBody>(request)?;
+            let recording = tauri::async_runtime::block_on(crate::commands::stop_recording(
+                app.clone(),
+                app.state(),
+            ))?;
+            context.hub.reset_audio(recording.sample_rate);
+            context.hub.broadcast(&LocalApiEvent::RecordingStopped);
+
+            let duration_ms = duration_ms(recording.samples.len(), recording.sample_rate);
+            if !body.transcribe.unwrap_or(true) || recording.samples.is_empty() {
+                return Ok(json!({ "text": null, "durationMs": duration_ms }));
+            }
+
+            let text = transcribe(app, recording.samples, recording.sample_rate, body.options)?;
+            context
+                .hub
+                .broadcast(&LocalApiEvent::Transcript { text: text.clone() });
+            Ok(json!({ "text": text, "durationMs": duration_ms }))
+        }
+        Endpoint::Transcribe => {
+            let body = parse_body::<TranscribeFileBody>(request)?;
+            let (samples, sample_rate) =
+                crate::system::audio_file::decode_audio_file(std::path::Path::new(&body.path))
+                    .map_err(|err| ApiError::new(422, err.to_string()))?;
+            let duration_ms = duration_ms(samples.len(), sample_rate);
+            let text = transcribe(app, samples, sample_rate, body.options)?;
+            Ok(json!({ "text": text, "durationMs": duration_ms }))
+        }
+        Endpoint::Paste => {
+            let body = parse_body::<PasteBody>(request)?;
+            tauri::async_runtime::block_on(crate::commands::paste(body.text, body.keybind))?;
+            Ok(json!({ "pasted": true }))
+        }
+        Endpoint::Events => Err(ApiError::new(400, "Expected a WebSocket upgrade")),
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StopRecordingBody {
+    #[serde(default)]
+    transcribe: Option<bool>,
+    #[serde(default)]
+    options: Option<TranscriptionOptionsDto>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TranscribeFileBody {
+    path: String,
+    #[serde(default)]
+    options: Option<TranscriptionOptionsDto>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PasteBody {
+    text: String,
+    #[serde(default)]
+    keybind: Option<String>,
+}
+
+fn parse_body<T: DeserializeOwned>(request: &Request) -> Result<T, ApiError> {
+    serde_json::from_slice(&request.body)
+        .map_err(|err| ApiError::new(400, format!("Invalid request body: {err}")))
+}
+
+fn parse_optional_body<T: DeserializeOwned + Default>(request: &Request) -> Result<T, ApiError> {
+    if request.body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(T::default());
+    }
+    parse_body(request)
+}
+
+fn transcribe(
+    app: &AppHandle,
+    samples: Vec<f32>,
+    sample_rate: u32,
+    options: Option<TranscriptionOptionsDto>,
+) -> Result<String, ApiError> {
+    let samples = samples.into_iter().map(f64::from).collect();
+    Ok(tauri::async_runtime::block_on(
+        crate::commands::transcribe_audio(
+            app.clone(),
+            samples,
+            sample_rate,
+            options,
+            app.state(),
+            app.state(),
+            app.state(),
+        ),
+    )?)
+}
+
+fn duration_ms(samples: usize, sample_rate: u32) -> u64 {
+    samples as u64 * 1000 / u64::from(sample_rate.max(1))
+}
+
+fn serve_events(stream: TcpStream, request: &Request, hub: &EventHub) -> io::Result<()> {
+    let mut stream = stream;
+    let upgrade = request
+        .header("upgrade")
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"));
+    let Some(key) = request.header("sec-websocket-key").filter(|_| upgrade) else {
+        return respond(
+            &mut stream,
+            400,
+            &json!({ "error": "Expected a WebSocket upgrade" }),
+        );
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        websocket::accept_key(key)
+    )?;
+    stream.set_read_timeout(None)?;
+
+    let receiver = hub.subscribe();
+    let writer = Arc::new(Mutex::new(stream.try_clone()?));
+
+    // Clients only send control frames; answer pings and stop on close.
+    let reader_writer = Arc::clone(&writer);
+    thread::Builder::new()
+        .name("local-api-events".into())
+        .spawn(move || {
+            let mut reader = stream;
+            loop {
+                match websocket::read_frame(&mut reader) {
+                    Ok(frame) if frame.opcode == websocket::OPCODE_PING => {
+                        let _ = send(&reader_writer, websocket::OPCODE_PONG, &frame.payload);
+                    }
+                    Ok(frame) if frame.opcode == websocket::OPCODE_CLOSE => {
+                        let _ = send(&reader_writer, websocket::OPCODE_CLOSE, &[]);
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+            let _ = reader.shutdown(Shutdown::Both);
+        })?;
+
+    loop {
+        let sent = match receiver.recv_timeout(WS_KEEPALIVE_INTERVAL) {
+            Ok(message) => send(&writer, websocket::OPCODE_TEXT, message.as_bytes()),
+            Err(RecvTimeoutError::Timeout) => send(&writer, websocket::OPCODE_PING, &[]),
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = send(&writer, websocket::OPCODE_CLOSE, &[]);
+                break;
+            }
+        };
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    if let Ok(stream) = writer.lock() {
+        let _ = stream.shutdown(Shutdown::Both);
+    }
+    Ok(())
+}
+
+fn send(writer: &Mutex<TcpStream>, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let mut stream = writer
+        .lock()
+        .map_err(|_| io::Error::other("event stream writer poisoned"))?;
+    websocket::write_frame(&mut *stream, opcode, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(raw: &str) -> io::Result<Request> {
+        read_request(raw.as_bytes())
+    }
+
+    fn verifier_for(token: &str) -> TokenVerifier {
+        let protected = crate::system::crypto::protect_api_key(token);
+        TokenVerifier {
+            salt_b64: protected.salt_b64,
+            hash_b64: protected.hash_b64,
+        }
+    }
+
+    #[test]
+    fn reads_request_line_headers_and_body() {
+        let request = parse(
+            "POST /v1/paste?x=1&y=a%20b HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: 15\r\n\r\n{\"text\":\"hi\"} trailing",
+        )
+        .unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/v1/paste");
+        assert_eq!(request.query.get("y").map(String::as_str), Some("a b"));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(request.body, b"{\"text\":\"hi\"} t");
+    }
+
+    #[test]
+    fn rejects_malformed_and_oversized_requests() {
+        assert!(parse("GARBAGE\r\n\r\n").is_err());
+        assert!(parse("POST /v1/paste HTTP/1.1\r\nContent-Length: nope\r\n\r\n").is_err());
+        assert!(parse(&format!(
+            "POST /v1/paste HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        ))
+        .is_err());
+        let huge_header = format!(
+            "GET /v1/status HTTP/1.1\r\nX-Filler: {}\r\n\r\n",
+            "a".repeat(MAX_HEADER_BYTES)
+        );
+        assert!(parse(&huge_header).is_err());
+        // A body shorter than its Content-Length never completes.
+        assert!(parse("POST /v1/paste HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort").is_err());
+    }
+
+    #[test]
+    fn query_token_only_counts_for_the_event_stream_upgrade() {
+        let verifier = verifier_for("secret-token");
+        let authorized = |raw: &str| parse(raw).unwrap().is_authorized(&verifier);
+
+        assert!(authorized(
+            "GET /v1/status HTTP/1.1\r\nAuthorization: Bearer secret-token\r\n\r\n"
+        ));
+        assert!(authorized(
+            "GET /v1/events?token=secret-token HTTP/1.1\r\nUpgrade: websocket\r\n\r\n"
+        ));
+        assert!(!authorized(
+            "GET /v1/status?token=secret-token HTTP/1.1\r\n\r\n"
+        ));
+        assert!(!authorized(
+            "GET /v1/events?token=secret-token HTTP/1.1\r\n\r\n"
+        ));
+        assert!(!authorized(
+            "GET /v1/status HTTP/1.1\r\nAuthorization: Bearer wrong-token\r\n\r\n"
+        ));
+        assert!(!authorized(
+            "GET /v1/status HTTP/1.1\r\nAuthorization: Bearer \r\n\r\n"
+        ));
+        assert!(!authorized("GET /v1/status HTTP/1.1\r\n\r\n"));
+    }
+
+    #[test]
+    fn routes_by_method_and_path() {
+        let endpoint = |raw: &str| Endpoint::from_request(&parse(raw).unwrap());
+
+        assert_eq!(
+            endpoint("GET /v1/status HTTP/1.1\r\n\r\n"),
+            Some(Endpoint::Status)
+        );
+        assert_eq!(
+            endpoint("POST /v1/recording/start HTTP/1.1\r\n\r\n"),
+            Some(Endpoint::RecordingStart)
+        );
+        assert_eq!(
+            endpoint("POST /v1/recording/stop HTTP/1.1\r\n\r\n"),
+            Some(Endpoint::RecordingStop)
+        );
+        assert_eq!(
+            endpoint("GET /v1/events?token=x HTTP/1.1\r\n\r\n"),
+            Some(Endpoint::Events)
+        );
+        assert_eq!(endpoint("GET /v1/paste HTTP/1.1\r\n\r\n"), None);
+        assert_eq!(endpoint("POST /v1/unknown HTTP/1.1\r\n\r\n"), None);
+    }
+
+    #[test]
+    fn connection_slots_are_bounded_and_released() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let slots: Vec<_> = (0..MAX_CONNECTIONS)
+            .map(|_| ConnectionSlot::acquire(&active).unwrap())
+            .collect();
+        assert!(ConnectionSlot::acquire(&active).is_none());
+
+        drop(slots);
+        assert_eq!(active.load(Ordering::Acquire), 0);
+        assert!(ConnectionSlot::acquire(&active).is_some());
+    }
+
+    #[test]
+    fn partial_audio_window_follows_the_recording_rate() {
+        let hub = EventHub::default();
+        hub.reset_audio(8_000);
+        hub.push_audio(&vec![0.0; 8_000 * (PARTIAL_WINDOW_SECS + 5)]);
+
+        let (samples, sample_rate) = hub.take_pending_audio().unwrap();
+        assert_eq!(sample_rate, 8_000);
+        assert_eq!(samples.len(), 8_000 * PARTIAL_WINDOW_SECS);
+        assert!(hub.take_pending_audio().is_none());
+    }
+
+    #[test]
+    fn responses_carry_status_and_length() {
+        let mut out = Vec::new();
+        respond(&mut out, 503, &json!({ "error": "busy" })).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(text.contains("Content-Length: 16\r\n"));
+        assert!(text.ends_with("{\"error\":\"busy\"}"));
+    }
+}
@@ -0,0 +1,671 @@
+//! Model Context Protocol server core: newline-delimited JSON-RPC 2.0 over
+//! any reader/writer pair. The app serves it on a loopback socket and
+//! `OSVoice cli mcp` bridges stdio to that socket (or serves stdio itself,
+//! database-only, when the app is not running).
+//!
+//! Every tool goes through [`McpHost::approve`] first, so the user confirms
+//! in the app before a client reads their history or records and types into
+//! other apps.
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+
+pub const SERVER_NAME: &str = "osvoice";
+const DEFAULT_PROTOCOL_VERSION: &str = "2024-11-05";
+const DEFAULT_SEARCH_LIMIT: u32 = 20;
+const MAX_SEARCH_LIMIT: u32 = 100;
+const DEFAULT_DICTATION_SECS: u64 = 5;
+const MAX_DICTATION_SECS: u64 = 60;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// What the protocol core needs from wherever it is running.
+pub trait McpHost {
+    fn pool(&self) -> Result<SqlitePool, String>;
+
+    /// Ask the user to allow `tool` for `client`. Returns an error message
+    /// when the user declines or nobody answers.
+    fn approve(&self, client: &str, tool: &str, summary: &str) -> Result<(), String>;
+
+    /// Record from the default microphone for `duration` and return the
+    /// transcript.
+    fn dictate(&self, duration: Duration) -> Result<String, String>;
+
+    /// Paste `text` into the focused app.
+    fn paste_text(&self, text: &str) -> Result<(), String>;
+}
+
+struct ToolSpec {
+    name: &'static str,
+    description: &'static str,
+    sensitive: bool,
+    schema: fn() -> Value,
+}
+
+const TOOLS: &[ToolSpec] = &[
+    ToolSpec {
+        name: "search_transcriptions",
+        description: "Search dictation history for text. Returns matching transcripts, newest first.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Text to look for" },
+                    "limit": { "type": "integer", "minimum": 1, "maximum": MAX_SEARCH_LIMIT },
+                },
+                "required": ["query"],
+            })
+        },
+    },
+    ToolSpec {
+        name: "list_meetings",
+        description: "List recorded meetings, newest first.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "integer", "minimum": 1, "maximum": MAX_SEARCH_LIMIT },
+                },
+            })
+        },
+    },
+    ToolSpec {
+        name: "get_meeting_transcript",
+        description: "Full transcript of a meeting with speaker names and timestamps.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "meetingId": { "type": "string" },
+                },
+                "required": ["meetingId"],
+            })
+        },
+    },
+    ToolSpec {
+        name: "list_action_items",
+        description: "Action items from one meeting, or open action items across all meetings when no id is given.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "meetingId": { "type": "string" },
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": MAX_SEARCH_LIMIT,
+                        "description": "How many open items to return",
+                    },
+                },
+            })
+        },
+    },
+    ToolSpec {
+        name: "dictate",
+        description: "Record from the user's microphone and return the transcript. Requires the user's approval.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "durationSeconds": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": MAX_DICTATION_SECS,
+                    },
+                },
+            })
+        },
+    },
+    ToolSpec {
+        name: "paste_text",
+        description: "Paste text into the app the user has focused. Requires the user's approval.",
+        sensitive: true,
+        schema: || {
+            json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string" },
+                },
+                "required": ["text"],
+            })
+        },
+    },
+];
+
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// One client connection. Remembers the client's name from `initialize` so
+/// permission prompts can say who is asking.
+pub struct McpSession<H> {
+    host: H,
+    client_name: String,
+}
+
+impl<H: McpHost> McpSession<H> {
+    pub fn new(host: H) -> Self {
+        Self {
+            host,
+            client_name: "MCP client".to_string(),
+        }
+    }
+
+    /// Read requests until EOF, writing one response line per request.
+    pub fn serve<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> io::Result<()> {
+        for line in reader.lines() {
+            if let Some(response) = self.handle_line(&line?) {
+                writer.write_all(response.as_bytes())?;
+                writer.write_all(b"\n")?;
+                writer.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one JSON-RPC message. Notifications produce no response.
+    pub fn handle_line(&mut self, line: &str) -> Option<String> {
+        if line.trim().is_empty() {
+            return None;
+        }
+
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(err) => {
+                return Some(error_response(
+                    Value::Null,
+                    RpcError::new(PARSE_ERROR, err.to_string()),
+                ))
+            }
+        };
+
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return id
+                .map(|id| error_response(id, RpcError::new(INVALID_REQUEST, "Missing method")));
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = self.dispatch(method, params);
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+            Err(err) => error_response(id, err),
+        })
+    }
+
+    fn dispatch(&mut self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => {
+                if let Some(name) = params.pointer("/clientInfo/name").and_then(Value::as_str) {
+                    self.client_name = name.to_string();
+                }
+                let version = params
+                    .get("protocolVersion")
+                    .and_then(Value::as_str)
+                    .unwrap_or(DEFAULT_PROTOCOL_VERSION);
+                Ok(json!({
+                    "protocolVersion": version,
+                    "capabilities": { "tools": { "listChanged": false } },
+                    "serverInfo": {
+                        "name": SERVER_NAME,
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }))
+            }
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({
+                "tools": TOOLS
+                    .iter()
+                    .map(|tool| json!({
+                        "name": tool.name,
+                        "description": tool.description,
+                        "inputSchema": (tool.schema)(),
+                    }))
+                    .collect::<Vec<_>>(),
+            })),
+            "tools/call" => {
+                let call: ToolCall = serde_json::from_value(params)
+                    .map_err(|err| RpcError::new(INVALID_PARAMS, err.to_string()))?;
+                let tool = TOOLS
+                    .iter()
+                    .find(|tool| tool.name == call.name)
+                    .ok_or_else(|| {
+                        RpcError::new(INVALID_PARAMS, format!("Unknown tool: {}", call.name))
+                    })?;
+                Ok(match self.call_tool(tool, call.arguments) {
+                    Ok(text) => json!({
+                        "content": [{ "type": "text", "text": text }],
+                        "isError": false,
+                    }),
+                    Err(message) => json!({
+                        "content": [{ "type": "text", "text": message }],
+                        "isError": true,
+                    }),
+                })
+            }
+            _ if method.starts_with("notifications/") => Ok(Value::Null),
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {method}"),
+            )),
+        }
+    }
+
+    fn call_tool(&self, tool: &ToolSpec, arguments: Value) -> Result<String, String> {
+        let args: ToolArguments =
+            serde_json::from_value(arguments).map_err(|err| format!("Invalid arguments: {err}"))?;
+        if tool.sensitive {
+            let summary = approval_summary(tool.name, &args)?;
+            self.host.approve(&self.client_name, tool.name, &summary)?;
+        }
+
+        match tool.name {
+            "search_transcriptions" => {
+                let query = args.query.ok_or("Missing query")?;
+                let limit = clamp_limit(args.limit);
+                let pool = self.host.pool()?;
+                let transcriptions = tauri::async_runtime::block_on(
+                    crate::db::transcription_queries::search_transcriptions(pool, &query, limit),
+                )
+                .map_err(|err| err.to_string())?;
+                to_text(&json!(transcriptions
+                    .iter()
+                    .map(|transcription| json!({
+                        "id": transcription.id,
+                        "timestamp": transcription.timestamp,
+                        "transcript": transcription.transcript,
+                    }))
+                    .collect::<Vec<_>>()))
+            }
+            "list_meetings" => {
+                let pool = self.host.pool()?;
+                let meetings = tauri::async_runtime::block_on(
+                    crate::db::meeting_queries::fetch_meetings(pool, clamp_limit(args.limit), 0),
+                )
+                .map_err(|err| err.to_string())?;
+                to_text(&json!(meetings
+                    .iter()
+                    .map(|meeting| json!({
+                        "id": meeting.id,
+                        "title": meeting.title,
+                        "startedAt": meeting.started_at,
+                        "durationMs": meeting.duration_ms,
+                        "status": meeting.status,
+                    }))
+                    .collect::<Vec<_>>()))
+            }
+            "get_meeting_transcript" => {
+                let meeting_id = args.meeting_id.ok_or("Missing meetingId")?;
+                let pool = self.host.pool()?;
+                let (meeting, segments) = tauri::async_runtime::block_on(async {
+                    let meeting =
+                        crate::db::meeting_queries::fetch_meeting(pool.clone(), &meeting_id)
+                            .await?;
+                    let segments =
+                        crate::db::meeting_queries::fetch_meeting_segments(pool, &meeting_id)
+                            .await?;
+                    Ok::<_, sqlx::Error>((meeting, segments))
+                })
+                .map_err(|err| err.to_string())?;
+                let meeting = meeting.ok_or_else(|| format!("Meeting {meeting_id} not found"))?;
+
+                let mut lines = vec![format!("# {}", meeting.title)];
+                lines.extend(segments.iter().map(|segment| {
+                    format!(
+                        "[{}] {}: {}",
+                        format_timestamp(segment.start_ms),
+                        segment.speaker_name.as_deref().unwrap_or("Speaker"),
+                        segment.text.trim()
+                    )
+                }));
+                Ok(lines.join("\n"))
+            }
+            "list_action_items" => {
+                let pool = self.host.pool()?;
+                match args.meeting_id.as_deref() {
+                    Some(id) => {
+                        let (meeting, items) = tauri::async_runtime::block_on(async {
+                            let meeting =
+                                crate::db::meeting_queries::fetch_meeting(pool.clone(), id).await?;
+                            let items =
+                                crate::db::meeting_queries::fetch_meeting_action_items(pool, id)
+                                    .await?;
+                            Ok::<_, sqlx::Error>((meeting, items))
+                        })
+                        .map_err(|err| err.to_string())?;
+                        let meeting = meeting.ok_or_else(|| "Meeting not found".to_string())?;
+                        to_text(&json!({
+                            "meetingId": meeting.id,
+                            "title": meeting.title,
+                            "items": items,
+                        }))
+                    }
+                    None => {
+                        let items = tauri::async_runtime::block_on(
+                            crate::db::meeting_queries::fetch_open_action_items(
+                                pool,
+                                clamp_limit(args.limit),
+                                0,
+                            ),
+                        )
+                        .map_err(|err| err.to_string())?;
+                        to_text(&json!(items))
+                    }
+                }
+            }
+            "dictate" => self
+                .host
+                .dictate(Duration::from_secs(dictation_seconds(&args))),
+            "paste_text" => {
+                self.host
+                    .paste_text(args.text.as_deref().unwrap_or_default())?;
+                Ok("Pasted.".to_string())
+            }
+            _ => Err(format!("Unknown tool: {}", tool.name)),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ToolCall {
+    name: String,
+    #[serde(default = "empty_object")]
+    arguments: Value,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolArguments {
+    query: Option<String>,
+    limit: Option<u32>,
+    meeting_id: Option<String>,
+    duration_seconds: Option<u64>,
+    text: Option<String>,
+}
+
+fn empty_object() -> Value {
+    json!({})
+}
+
+fn error_response(id: Value, err: RpcError) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": err.code, "message": err.message },
+    })
+    .to_string()
+}
+
+fn clamp_limit(limit: Option<u32>) -> u32 {
+    limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT)
+}
+
+fn to_text(value: &Value) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|err| err.to_string())
+}
+
+fn dictation_seconds(args: &ToolArguments) -> u64 {
+    args.duration_seconds
+        .unwrap_or(DEFAULT_DICTATION_SECS)
+        .clamp(1, MAX_DICTATION_SECS)
+}
+
+/// What the permission prompt tells the user the tool is about to do.
+fn approval_summary(tool: &str, args: &ToolArguments) -> Result<String, String> {
+    const PREVIEW_CHARS: usize = 80;
+    match tool {
+        "dictate" => Ok(format!(
+            "Record your microphone for {} seconds",
+            dictation_seconds(args)
+        )),
+        "paste_text" => {
+            let text = args.text.as_deref().ok_or("Missing text")?;
+            let preview: String = text.chars().take(PREVIEW_CHARS).collect();
+            let ellipsis = if text.chars().count() > PREVIEW_CHARS {
+                "…"
+            } else {
+                ""
+            };
+            Ok(format!(
+                "Paste \"{preview}{ellipsis}\" into the focused app"
+            ))
+        }
+        "search_transcriptions" => Ok(format!(
+            "Search your dictation history for \"{}\"",
+            args.query.as_deref().ok_or("Missing query")?
+        )),
+        "list_meetings" => Ok("Read your list of meetings".to_string()),
+        "get_meeting_transcript" => Ok("Read a meeting transcript".to_string()),
+        "list_action_items" => Ok("Read your meeting action items".to_string()),
+        _ => Ok(format!("Run {tool}")),
+    }
+}
+
+fn format_timestamp(ms: i64) -> String {
+    let seconds = ms.max(0) / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DenyingHost;
+
+    impl McpHost for DenyingHost {
+        fn pool(&self) -> Result<SqlitePool, String> {
+            Err("no database".to_string())
+        }
+
+        fn approve(&self, _client: &str, _tool: &str, _summary: &str) -> Result<(), String> {
+            Err("Denied by the user".to_string())
+        }
+
+        fn dictate(&self, _duration: Duration) -> Result<String, String> {
+            panic!("dictate must not run without approval");
+        }
+
+        fn paste_text(&self, _text: &str) -> Result<(), String> {
+            panic!("paste_text must not run without approval");
+        }
+    }
+
+    fn call<H: McpHost>(session: &mut McpSession<H>, request: Value) -> Value {
+        let response = session.handle_line(&request.to_string()).unwrap();
+        serde_json::from_str(&response).unwrap()
+    }
+
+    #[test]
+    fn handshake_and_tool_listing() {
+        let mut session = McpSession::new(DenyingHost);
+        let init = call(
+            &mut session,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": { "protocolVersion": "2025-03-26", "clientInfo": { "name": "test" } },
+            }),
+        );
+        assert_eq!(init["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(init["result"]["serverInfo"]["name"], SERVER_NAME);
+        assert_eq!(session.client_name, "test");
+
+        let initialized = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        assert!(session.handle_line(&initialized.to_string()).is_none());
+
+        let list = call(
+            &mut session,
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+        );
+        let names: Vec<_> = list["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert!(names.contains(&"search_transcriptions"));
+        assert!(names.contains(&"dictate"));
+
+        let unknown = call(
+            &mut session,
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "resources/list" }),
+        );
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    struct ApprovingHost {
+        pool: SqlitePool,
+    }
+
+    impl McpHost for ApprovingHost {
+        fn pool(&self) -> Result<SqlitePool, String> {
+            Ok(self.pool.clone())
+        }
+
+        fn approve(&self, _client: &str, _tool: &str, _summary: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn dictate(&self, _duration: Duration) -> Result<String, String> {
+            Ok(String::new())
+        }
+
+        fn paste_text(&self, _text: &str) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn tool_call(name: &str, arguments: Value) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments },
+        })
+    }
+
+    #[test]
+    fn history_tools_require_approval() {
+        let mut session = McpSession::new(DenyingHost);
+        for (name, arguments) in [
+            ("search_transcriptions", json!({ "query": "invoice" })),
+            ("list_meetings", json!({})),
+            ("get_meeting_transcript", json!({ "meetingId": "m1" })),
+            ("list_action_items", json!({})),
+        ] {
+            let response = call(&mut session, tool_call(name, arguments));
+            assert_eq!(
+                response["result"]["content"][0]["text"], "Denied by the user",
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_bulleted_action_items() {
+        let pool = tauri::async_runtime::block_on(async {
+            let pool = sqlx::sqlite::SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .unwrap();
+            let (legacy, current): (Vec<_>, Vec<_>) = crate::db::migrations()
+                .into_iter()
+                .partition(|migration| migration.version < 57);
+            for migration in legacy {
+                sqlx::raw_sql(migration.sql).execute(&pool).await.unwrap();
+            }
+            sqlx::query(
+                "INSERT INTO meetings (id, title, started_at, status, action_items, created_at, updated_at)
+                 VALUES ('m1', 'Standup', '2024-01-01', 'done', ?1, '2024-01-01', '2024-01-01')",
+            )
+            .bind("- Send notes\n\n* [ ] Book room\n• Follow up\n[x] Ship it")
+            .execute(&pool)
+            .await
+            .unwrap();
+            for migration in current {
+                sqlx::raw_sql(migration.sql).execute(&pool).await.unwrap();
+            }
+            pool
+        });
+
+        let mut session = McpSession::new(ApprovingHost { pool });
+        let response = call(
+            &mut session,
+            tool_call("list_action_items", json!({ "meetingId": "m1" })),
+        );
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        let listing: Value = serde_json::from_str(text).unwrap();
+        let items: Vec<_> = listing["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| {
+                (
+                    item["task"].as_str().unwrap(),
+                    item["status"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                ("Send notes", "open"),
+                ("Book room", "open"),
+                ("Follow up", "open"),
+                ("Ship it", "done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sensitive_tools_require_approval() {
+        let mut session = McpSession::new(DenyingHost);
+        let response = call(
+            &mut session,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": { "name": "paste_text", "arguments": { "text": "hi" } },
+            }),
+        );
+        assert_eq!(response["result"]["isError"], true);
+        assert_eq!(
+            response["result"]["content"][0]["text"],
+            "Denied by the user"
+        );
+    }
+}
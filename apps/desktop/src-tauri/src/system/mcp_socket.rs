@@ -0,0 +1,322 @@
+//! Serves the MCP core to local clients while the app is running. The
+//! server listens on an ephemeral loopback port and writes the port and a
+//! per-launch token to `mcp-endpoint.json` in the app data directory; a
+//! client (normally `OSVoice cli mcp`) sends the token as its first line.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Emitter, Listener, Manager};
+
+use crate::domain::{
+    McpEndpoint, McpPermissionDecision, McpPermissionRequest, OverlayPhase, OverlayPhasePayload,
+    EVT_MCP_PERMISSION_REQUEST, EVT_OVERLAY_PHASE,
+};
+use crate::system::mcp::{McpHost, McpSession};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const PERMISSION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Dropping the server stops accepting connections and removes the
+/// endpoint file. Sessions already open run until their client disconnects.
+pub struct McpSocketServer {
+    addr: SocketAddr,
+    endpoint_path: PathBuf,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl McpSocketServer {
+    pub fn start(app: AppHandle) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let token = crate::platform::keyboard_ipc::generate_token();
+
+        let endpoint_path = crate::system::paths::mcp_endpoint_path(&app)?;
+        write_endpoint(
+            &endpoint_path,
+            &McpEndpoint {
+                port: addr.port(),
+                token: token.clone(),
+            },
+        )?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || accept_loop(listener, app, token, shutdown))
+        };
+
+        Ok(Self {
+            addr,
+            endpoint_path,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for McpSocketServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let _ = fs::remove_file(&self.endpoint_path);
+    }
+}
+
+fn write_endpoint(path: &Path, endpoint: &McpEndpoint) -> io::Result<()> {
+    let contents = serde_json::to_vec(endpoint)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(&contents)
+    }
+
+    #[cfg(windows)]
+    {
+        create_owner_only_file(path)?.write_all(&contents)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        fs::write(path, contents)
+    }
+}
+
+/// Create `path` with a protected DACL that only grants the file's owner
+/// access, the Windows counterpart of mode 0600.
+#[cfg(windows)]
+fn create_owner_only_file(path: &Path) -> io::Result<fs::File> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::io::FromRawHandle;
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::{LocalFree, GENERIC_WRITE, HLOCAL};
+    use windows::Win32::Security::Authorization::{
+        ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+    };
+    use windows::Win32::Security::{PSECURITY_DESCRIPTOR, SECURITY_ATTRIBUTES};
+    use windows::Win32::Storage::FileSystem::{CreateFileW, CREATE_NEW, FILE_ATTRIBUTE_NORMAL};
+
+    // Security attributes only apply to new files, so never reuse an old one.
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+
+    let wide_path: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut descriptor = PSECURITY_DESCRIPTOR::default();
+    unsafe {
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            w!("D:P(A;;FA;;;OW)"),
+            SDDL_REVISION_1,
+            &mut descriptor,
+            None,
+        )
+    }
+    .map_err(io::Error::other)?;
+
+    let attributes = SECURITY_ATTRIBUTES {
+        nLength: std::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor.0,
+        bInheritHandle: false.into(),
+    };
+    let handle = unsafe {
+        CreateFileW(
+            PCWSTR(wide_path.as_ptr()),
+            GENERIC_WRITE.0,
+            Default::default(),
+            Some(&attributes),
+            CREATE_NEW,
+            FILE_ATTRIBUTE_NORMAL,
+            None,
+        )
+    };
+    unsafe {
+        let _ = LocalFree(Some(HLOCAL(descriptor.0)));
+    }
+
+    let handle = handle.map_err(io::Error::other)?;
+    Ok(unsafe { fs::File::from_raw_handle(handle.0) })
+}
+
+fn accept_loop(listener: TcpListener, app: AppHandle, token: String, shutdown: Arc<AtomicBool>) {
+    while !shutdown.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let app = app.clone();
+                let token = token.clone();
+                thread::spawn(move || {
+                    if let Err(err) = serve_connection(stream, app, &token) {
+                        eprintln!("[mcp] Connection closed with error: {err}");
+                    }
+                });
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(err) => {
+                eprintln!("[mcp] Accept failed: {err}");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn serve_connection(stream: TcpStream, app: AppHandle, token: &str) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut presented = String::new();
+    reader.read_line(&mut presented)?;
+    if !tokens_match(presented.trim(), token) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "invalid MCP token",
+        ));
+    }
+
+    stream.set_read_timeout(None)?;
+    let connection = crate::platform::keyboard_ipc::generate_token();
+    let result = McpSession::new(AppMcpHost {
+        app: app.clone(),
+        connection: connection.clone(),
+    })
+    .serve(reader, stream);
+    app.state::<crate::state::McpPermissionState>()
+        .end_session(&connection);
+    result
+}
+
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Runs tools against the live app: its database, recorder, transcriber
+/// and paste path, with permission prompts shown in the main window.
+struct AppMcpHost {
+    app: AppHandle,
+    /// Identifies this connection's session grants.
+    connection: String,
+}
+
+impl McpHost for AppMcpHost {
+    fn pool(&self) -> Result<SqlitePool, String> {
+        Ok(self.app.state::<crate::state::OptionKeyDatabase>().pool())
+    }
+
+    fn approve(&self, client: &str, tool: &str, summary: &str) -> Result<(), String> {
+        let permissions = self.app.state::<crate::state::McpPermissionState>();
+        if permissions.is_granted(&self.connection, tool) {
+            return Ok(());
+        }
+
+        let request_id = crate::platform::keyboard_ipc::generate_token();
+        let receiver = permissions.register(&request_id)?;
+
+        let request = McpPermissionRequest {
+            request_id: request_id.clone(),
+            client_name: client.to_string(),
+            tool: tool.to_string(),
+            summary: summary.to_string(),
+        };
+        if let Err(err) = self.app.emit(EVT_MCP_PERMISSION_REQUEST, request) {
+            permissions.forget(&request_id);
+            return Err(err.to_string());
+        }
+        let _ = crate::commands::surface_main_window(self.app.clone());
+
+        match receiver.recv_timeout(PERMISSION_TIMEOUT) {
+            Ok(McpPermissionDecision::AllowOnce) => Ok(()),
+            Ok(McpPermissionDecision::AllowSession) => {
+                permissions.grant_for_session(&self.connection, tool);
+                Ok(())
+            }
+            Ok(McpPermissionDecision::Deny) => Err("Denied by the user".to_string()),
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => {
+                permissions.forget(&request_id);
+                Err("The user did not answer the permission prompt".to_string())
+            }
+        }
+    }
+
+    /// Records for up to `duration`. Stopping the recording in the app
+    /// (hotkey or pill) ends the wait early.
+    fn dictate(&self, duration: Duration) -> Result<String, String> {
+        let app = &self.app;
+        tauri::async_runtime::block_on(crate::commands::start_recording(
+            app.clone(),
+            app.state(),
+            app.state(),
+            app.state(),
+            None,
+        ))?;
+        let _ = crate::commands::set_phase(app.clone(), "recording".to_string(), app.state());
+
+        let (stopped_sender, stopped) = mpsc::channel();
+        let listener = app.listen_any(EVT_OVERLAY_PHASE, move |event| {
+            let left_recording = serde_json::from_str::<OverlayPhasePayload>(event.payload())
+                .is_ok_and(|payload| payload.phase != OverlayPhase::Recording);
+            if left_recording {
+                let _ = stopped_sender.send(());
+            }
+        });
+        let _ = stopped.recv_timeout(duration);
+        app.unlisten(listener);
+
+        let recording = tauri::async_runtime::block_on(crate::commands::stop_recording(
+            app.clone(),
+            app.state(),
+        ));
+        let _ = crate::commands::set_phase(app.clone(), "loading".to_string(), app.state());
+
+        let result = recording.and_then(|recording| {
+            if recording.samples.is_empty() {
+                return Ok(String::new());
+            }
+            let samples = recording.samples.into_iter().map(f64::from).collect();
+            tauri::async_runtime::block_on(crate::commands::transcribe_audio(
+                app.clone(),
+                samples,
+                recording.sample_rate,
+                None,
+                app.state(),
+                app.state(),
+                app.state(),
+            ))
+        });
+        let _ = crate::commands::set_phase(app.clone(), "idle".to_string(), app.state());
+        result
+    }
+
+    fn paste_text(&self, text: &str) -> Result<(), String> {
+        tauri::async_runtime::block_on(crate::commands::paste(text.to_string(), None))
+    }
+}
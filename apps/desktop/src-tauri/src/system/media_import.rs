@@ -0,0 +1,425 @@
+//! Imports audio and video files from disk as meetings or transcriptions.
+//! The file is decoded and resampled to 16 kHz mono, stored the same way as
+//! recorded audio, and transcribed locally in chunks of up to 30 seconds so
+//! progress can be reported and the import cancelled between chunks.
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::SecondsFormat;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::commands::TranscriptionOptionsDto;
+use crate::domain::{
+    MediaImportProgress, MediaImportResult, MediaImportStage, MediaImportTarget, Meeting,
+    MeetingSegment, Transcription, EVT_MEDIA_IMPORT_PROGRESS,
+};
+use crate::system::meeting_audio_store::{meeting_audio_path_for, MeetingWavWriter};
+
+pub const IMPORT_SAMPLE_RATE: u32 = 16_000;
+pub const IMPORT_CANCELLED: &str = "Import cancelled";
+
+const CHUNK_SECONDS: usize = 30;
+/// How far back from a chunk's hard end to look for a quiet place to cut.
+const SPLIT_SEARCH_SECONDS: usize = 5;
+const SPLIT_FRAME_MS: usize = 50;
+
+// Share of the overall progress bar each stage ends at.
+const DECODED_PROGRESS: f32 = 0.3;
+const SAVED_PROGRESS: f32 = 0.35;
+
+pub async fn import_media(
+    app: AppHandle,
+    import_id: String,
+    path: PathBuf,
+    target: MediaImportTarget,
+    options: Option<TranscriptionOptionsDto>,
+    cancel: Arc<AtomicBool>,
+) -> Result<MediaImportResult, String> {
+    let reporter = ProgressReporter::new(&app, &import_id);
+    let samples = decode(&reporter, &path, &cancel).await?;
+    if samples.is_empty() {
+        return Err("The file contains no audio".to_string());
+    }
+
+    let title = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Imported audio".to_string());
+
+    let result = match target {
+        MediaImportTarget::Meeting => {
+            import_meeting(
+                &app, &reporter, &import_id, title, samples, options, &cancel,
+            )
+            .await
+        }
+        MediaImportTarget::Transcription => {
+            import_transcription(&app, &reporter, &import_id, samples, options, &cancel).await
+        }
+    }?;
+
+    reporter.report(MediaImportStage::Done, 1.0);
+    Ok(result)
+}
+
+async fn decode(
+    reporter: &ProgressReporter,
+    path: &Path,
+    cancel: &Arc<AtomicBool>,
+) -> Result<Vec<f32>, String> {
+    reporter.report(MediaImportStage::Decoding, 0.0);
+
+    let path = path.to_path_buf();
+    let cancel = Arc::clone(cancel);
+    let reporter = reporter.clone();
+    let decoded = tauri::async_runtime::spawn_blocking(move || {
+        crate::system::audio_file::decode_audio_file_resampled(
+            &path,
+            IMPORT_SAMPLE_RATE,
+            &cancel,
+            |fraction| reporter.report(MediaImportStage::Decoding, fraction * DECODED_PROGRESS),
+        )
+    })
+    .await
+    .map_err(|err| err.to_string())?;
+
+    decoded.map_err(|err| match err.kind() {
+        std::io::ErrorKind::Interrupted => IMPORT_CANCELLED.to_string(),
+        _ => err.to_string(),
+    })
+}
+
+async fn import_meeting(
+    app: &AppHandle,
+    reporter: &ProgressReporter,
+    meeting_id: &str,
+    title: String,
+    samples: Vec<f32>,
+    options: Option<TranscriptionOptionsDto>,
+    cancel: &AtomicBool,
+) -> Result<MediaImportResult, String> {
+    reporter.report(MediaImportStage::Saving, DECODED_PROGRESS);
+    let audio_path = meeting_audio_path_for(app, meeting_id).map_err(|err| err.to_string())?;
+    let (samples, audio) = {
+        let audio_path = audio_path.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            let audio =
+                MeetingWavWriter::create(audio_path, IMPORT_SAMPLE_RATE).and_then(|mut writer| {
+                    writer.append_samples(&samples)?;
+                    writer.finalize()
+                });
+            (samples, audio)
+        })
+        .await
+        .map_err(|err| err.to_string())?
+    };
+    let audio = audio.map_err(|err| err.to_string())?;
+
+    let now = now_iso();
+    let mut meeting = Meeting {
+        id: meeting_id.to_string(),
+        title,
+        app_source: None,
+        started_at: now.clone(),
+        ended_at: None,
+        duration_ms: Some(audio.duration_ms),
+        status: "completed".to_string(),
+        audio_path: audio.file_path,
+        summary: None,
+        action_items: None,
+        starred: false,
+        created_at: now.clone(),
+        updated_at: now.clone(),
+    };
+
+    let segments = match transcribe_chunks(app, reporter, &samples, options, cancel).await {
+        Ok(chunks) => chunks
+            .into_iter()
+            .map(|(range, text)| MeetingSegment {
+                id: crate::system::ids::new_id(),
+                meeting_id: meeting.id.clone(),
+                speaker_id: None,
+                speaker_name: None,
+                text,
+                start_ms: samples_to_ms(range.start),
+                end_ms: samples_to_ms(range.end),
+                created_at: now.clone(),
+            })
+            .collect::<Vec<_>>(),
+        Err(err) if err == IMPORT_CANCELLED => {
+            let _ = crate::system::meeting_audio_store::delete_meeting_audio_file(app, &audio_path);
+            return Err(err);
+        }
+        Err(err) => {
+            // Keep the audio so the meeting can be processed again later.
+            meeting.status = "failed".to_string();
+            let pool = app.state::<crate::state::OptionKeyDatabase>().pool();
+            if crate::db::meeting_queries::insert_meeting(pool, &meeting)
+                .await
+                .is_err()
+            {
+                let _ =
+                    crate::system::meeting_audio_store::delete_meeting_audio_file(app, &audio_path);
+            }
+            return Err(err);
+        }
+    };
+
+    let pool = app.state::<crate::state::OptionKeyDatabase>().pool();
+    let meeting = match crate::db::meeting_queries::insert_meeting(pool.clone(), &meeting).await {
+        Ok(meeting) => meeting,
+        Err(err) => {
+            // Without its meeting row nothing refers to the audio any more.
+            let _ = crate::system::meeting_audio_store::delete_meeting_audio_file(app, &audio_path);
+            return Err(err.to_string());
+        }
+    };
+    if !segments.is_empty() {
+        crate::db::meeting_queries::insert_meeting_segments(pool, &segments)
+            .await
+            .map_err(|err| err.to_string())?;
+    }
+
+    Ok(MediaImportResult {
+        meeting: Some(meeting),
+        ..Default::default()
+    })
+}
+
+async fn import_transcription(
+    app: &AppHandle,
+    reporter: &ProgressReporter,
+    transcription_id: &str,
+    samples: Vec<f32>,
+    options: Option<TranscriptionOptionsDto>,
+    cancel: &AtomicBool,
+) -> Result<MediaImportResult, String> {
+    let model_size = options
+        .as_ref()
+        .and_then(|options| options.model_size.clone());
+    let started = Instant::now();
+    let chunks = transcribe_chunks(app, reporter, &samples, options, cancel).await?;
+    let transcription_duration_ms = started.elapsed().as_millis() as i64;
+    let text = chunks
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    reporter.report(MediaImportStage::Saving, 1.0);
+    let audio = {
+        let app = app.clone();
+        let id = transcription_id.to_string();
+        tauri::async_runtime::spawn_blocking(move || {
+            crate::system::audio_store::save_transcription_audio(
+                &app,
+                &id,
+                &samples,
+                IMPORT_SAMPLE_RATE,
+            )
+        })
+        .await
+        .map_err(|err| err.to_string())?
+        .map_err(|err| err.to_string())?
+    };
+
+    let transcription = Transcription {
+        id: transcription_id.to_string(),
+        transcript: text.clone(),
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        audio: Some(audio),
+        model_size,
+        inference_device: None,
+        raw_transcript: Some(text),
+        sanitized_transcript: None,
+        transcription_prompt: None,
+        post_process_prompt: None,
+        transcription_api_key_id: None,
+        post_process_api_key_id: None,
+        transcription_mode: Some("local".to_string()),
+        post_process_mode: None,
+        post_process_device: None,
+        transcription_duration_ms: Some(transcription_duration_ms),
+        postprocess_duration_ms: None,
+        warnings: None,
+        starred: false,
+        app_target_id: None,
+        source_language: None,
+        detected_language: None,
+        target_language: None,
+    };
+    let pool = app.state::<crate::state::OptionKeyDatabase>().pool();
+    let transcription =
+        match crate::db::transcription_queries::insert_transcription(pool, &transcription).await {
+            Ok(transcription) => transcription,
+            Err(err) => {
+                if let Some(audio) = &transcription.audio {
+                    let _ = crate::system::audio_store::delete_audio_file(
+                        app,
+                        Path::new(&audio.file_path),
+                    );
+                }
+                return Err(err.to_string());
+            }
+        };
+
+    Ok(MediaImportResult {
+        transcription: Some(transcription),
+        ..Default::default()
+    })
+}
+
+/// Transcribe `samples` chunk by chunk, returning each non-empty chunk's
+/// text with its sample range.
+async fn transcribe_chunks(
+    app: &AppHandle,
+    reporter: &ProgressReporter,
+    samples: &[f32],
+    options: Option<TranscriptionOptionsDto>,
+    cancel: &AtomicBool,
+) -> Result<Vec<(Range<usize>, String)>, String> {
+    reporter.report(MediaImportStage::Transcribing, SAVED_PROGRESS);
+
+    let mut transcribed = Vec::new();
+    for range in chunk_ranges(samples, IMPORT_SAMPLE_RATE) {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(IMPORT_CANCELLED.to_string());
+        }
+
+        let chunk = samples[range.clone()]
+            .iter()
+            .copied()
+            .map(f64::from)
+            .collect();
+        let text = crate::commands::transcribe_audio(
+            app.clone(),
+            chunk,
+            IMPORT_SAMPLE_RATE,
+            options.clone(),
+            app.state(),
+            app.state(),
+            app.state(),
+        )
+        .await?;
+
+        let fraction = range.end as f32 / samples.len() as f32;
+        reporter.report(
+            MediaImportStage::Transcribing,
+            SAVED_PROGRESS + fraction * (1.0 - SAVED_PROGRESS),
+        );
+
+        let text = text.trim();
+        if !text.is_empty() {
+            transcribed.push((range, text.to_string()));
+        }
+    }
+
+    Ok(transcribed)
+}
+
+/// Split audio into chunks of at most `CHUNK_SECONDS`, cutting each one at
+/// the quietest frame near its end so words aren't split between chunks.
+fn chunk_ranges(samples: &[f32], sample_rate: u32) -> Vec<Range<usize>> {
+    let rate = sample_rate.max(1) as usize;
+    let max_len = CHUNK_SECONDS * rate;
+    let search_len = SPLIT_SEARCH_SECONDS * rate;
+    let frame_len = (SPLIT_FRAME_MS * rate / 1000).max(1);
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < samples.len() {
+        let hard_end = start + max_len;
+        if hard_end >= samples.len() {
+            ranges.push(start..samples.len());
+            break;
+        }
+
+        let end = (hard_end - search_len..hard_end - frame_len)
+            .step_by(frame_len)
+            .map(|frame_start| {
+                let frame = &samples[frame_start..frame_start + frame_len];
+                (
+                    frame_start,
+                    frame.iter().map(|sample| sample * sample).sum::<f32>(),
+                )
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(frame_start, _)| frame_start + frame_len / 2)
+            .unwrap_or(hard_end);
+        ranges.push(start..end);
+        start = end;
+    }
+    ranges
+}
+
+fn samples_to_ms(samples: usize) -> i64 {
+    (samples as u64 * 1000 / u64::from(IMPORT_SAMPLE_RATE)) as i64
+}
+
+fn now_iso() -> String {
+    chrono::Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Emits progress events, skipping updates of less than a percent within a
+/// stage so long decodes don't flood the webview.
+#[derive(Clone)]
+struct ProgressReporter {
+    app: AppHandle,
+    import_id: String,
+    last: Arc<std::sync::Mutex<Option<(MediaImportStage, f32)>>>,
+}
+
+impl ProgressReporter {
+    fn new(app: &AppHandle, import_id: &str) -> Self {
+        Self {
+            app: app.clone(),
+            import_id: import_id.to_string(),
+            last: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    fn report(&self, stage: MediaImportStage, progress: f32) {
+        if let Ok(mut last) = self.last.lock() {
+            if matches!(*last, Some((last_stage, last_progress))
+                if last_stage == stage && progress - last_progress < 0.01)
+            {
+                return;
+            }
+            *last = Some((stage, progress));
+        }
+
+        let _ = self.app.emit(
+            EVT_MEDIA_IMPORT_PROGRESS,
+            MediaImportProgress {
+                import_id: self.import_id.clone(),
+                stage,
+                progress,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_cut_at_the_quietest_frame() {
+        let rate = 1_000;
+        let mut samples = vec![0.5_f32; 70 * rate as usize];
+        // A pause 27 seconds in, inside the search window of the first chunk.
+        samples[27_000..27_200].fill(0.0);
+
+        let ranges = chunk_ranges(&samples, rate);
+
+        assert_eq!(ranges.first().map(|range| range.start), Some(0));
+        assert_eq!(ranges.last().map(|range| range.end), Some(samples.len()));
+        assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(ranges.iter().all(|range| range.len() <= 30 * rate as usize));
+        assert!((27_000..27_200).contains(&ranges[0].end));
+    }
+}
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 use hound::{SampleFormat, WavSpec, WavWriter};
+use sqlx::SqlitePool;
 use tauri::Manager;
 
 const MEETING_AUDIO_DIR_NAME: &str = "meeting-audio";
@@ -59,11 +60,15 @@ pub fn delete_meeting_audio_file(app: &tauri::AppHandle, file_path: &Path) -> io
     }
 }
 
+/// Writes a meeting's audio to a WAV file as it is recorded. A writer made
+/// with [`MeetingWavWriter::discarding`] keeps only the duration, so an
+/// incognito meeting records nothing to disk.
 pub struct MeetingWavWriter {
     writer: Option<WavWriter<io::BufWriter<fs::File>>>,
-    path: PathBuf,
+    path: Option<PathBuf>,
     sample_rate: u32,
     total_samples: usize,
+    finalized: bool,
 }
 
 impl MeetingWavWriter {
@@ -80,25 +85,40 @@ impl MeetingWavWriter {
 
         Ok(Self {
             writer: Some(writer),
-            path,
+            path: Some(path),
             sample_rate,
             total_samples: 0,
+            finalized: false,
         })
     }
 
+    pub fn discarding(sample_rate: u32) -> Self {
+        Self {
+            writer: None,
+            path: None,
+            sample_rate,
+            total_samples: 0,
+            finalized: false,
+        }
+    }
+
     pub fn append_samples(&mut self, samples: &[f32]) -> io::Result<()> {
-        let writer = self
-            .writer
-            .as_mut()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "WAV writer already finalized"))?;
+        if self.finalized {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "WAV writer already finalized",
+            ));
+        }
 
         for sample in samples {
             if sample.is_finite() {
-                let normalized = sample.clamp(-1.0, 1.0);
-                let quantized = (normalized * i16::MAX as f32).round() as i16;
-                writer
-                    .write_sample(quantized)
-                    .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+                if let Some(writer) = self.writer.as_mut() {
+                    let normalized = sample.clamp(-1.0, 1.0);
+                    let quantized = (normalized * i16::MAX as f32).round() as i16;
+                    writer
+                        .write_sample(quantized)
+                        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+                }
                 self.total_samples += 1;
             }
         }
@@ -107,14 +127,19 @@ impl MeetingWavWriter {
     }
 
     pub fn finalize(&mut self) -> io::Result<MeetingAudioResult> {
-        let writer = self
-            .writer
-            .take()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "WAV writer already finalized"))?;
+        if self.finalized {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "WAV writer already finalized",
+            ));
+        }
+        self.finalized = true;
 
-        writer
-            .finalize()
-            .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+        if let Some(writer) = self.writer.take() {
+            writer
+                .finalize()
+                .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+        }
 
         let duration_ms = if self.sample_rate > 0 {
             ((self.total_samples as f64 / self.sample_rate as f64) * 1_000.0).round() as i64
@@ -123,7 +148,10 @@ impl MeetingWavWriter {
         };
 
         Ok(MeetingAudioResult {
-            file_path: self.path.to_string_lossy().to_string(),
+            file_path: self
+                .path
+                .as_ref()
+                .map(|path| path.to_string_lossy().to_string()),
             duration_ms,
         })
     }
@@ -132,7 +160,8 @@ impl MeetingWavWriter {
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MeetingAudioResult {
-    pub file_path: String,
+    /// Unset when the meeting was recorded in incognito mode.
+    pub file_path: Option<String>,
     pub duration_ms: i64,
 }
 
@@ -146,4 +175,119 @@ impl MeetingAudioWriterState {
             writer: Mutex::new(None),
         }
     }
+
+    /// Start writing a meeting to `path`. Incognito mode is read once here
+    /// and holds for the whole meeting, so toggling it mid-meeting neither
+    /// leaves a half-written file nor drops the end of a saved one.
+    pub async fn start(
+        &self,
+        pool: SqlitePool,
+        path: PathBuf,
+        sample_rate: u32,
+    ) -> Result<(), String> {
+        if sample_rate == 0 {
+            return Err("Sample rate must be greater than zero".to_string());
+        }
+        if self.writer.lock().map_err(|err| err.to_string())?.is_some() {
+            return Err("A meeting audio writer is already active".to_string());
+        }
+
+        let writer = if crate::system::incognito::is_enabled(pool).await? {
+            MeetingWavWriter::discarding(sample_rate)
+        } else {
+            MeetingWavWriter::create(path, sample_rate).map_err(|err| err.to_string())?
+        };
+
+        let mut guard = self.writer.lock().map_err(|err| err.to_string())?;
+        if guard.is_some() {
+            return Err("A meeting audio writer is already active".to_string());
+        }
+        *guard = Some(writer);
+        Ok(())
+    }
+
+    pub fn append(&self, samples: &[f32]) -> Result<(), String> {
+        let mut guard = self.writer.lock().map_err(|err| err.to_string())?;
+        let writer = guard
+            .as_mut()
+            .ok_or_else(|| "No active meeting audio writer".to_string())?;
+
+        writer
+            .append_samples(samples)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn finalize(&self) -> Result<MeetingAudioResult, String> {
+        let mut guard = self.writer.lock().map_err(|err| err.to_string())?;
+        let mut writer = guard
+            .take()
+            .ok_or_else(|| "No active meeting audio writer to finalize".to_string())?;
+
+        writer.finalize().map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::open_test_pool;
+    use crate::db::preferences_queries::{self, LOCAL_USER_ID};
+    use crate::domain::UserPreferences;
+
+    fn set_incognito(pool: &SqlitePool, enabled: bool) {
+        let preferences: UserPreferences = serde_json::from_value(serde_json::json!({
+            "userId": LOCAL_USER_ID,
+            "incognitoModeEnabled": enabled,
+        }))
+        .expect("preferences");
+        tauri::async_runtime::block_on(preferences_queries::upsert_user_preferences(
+            pool.clone(),
+            &preferences,
+        ))
+        .expect("save preferences");
+    }
+
+    fn record_meeting(pool: &SqlitePool, incognito: [bool; 2]) -> (PathBuf, MeetingAudioResult) {
+        let path = std::env::temp_dir().join(format!(
+            "meeting-audio-{}.wav",
+            crate::system::ids::new_id()
+        ));
+        let state = MeetingAudioWriterState::new();
+
+        set_incognito(pool, incognito[0]);
+        tauri::async_runtime::block_on(state.start(pool.clone(), path.clone(), 1_000))
+            .expect("start writer");
+        state.append(&[0.5; 500]).expect("append first half");
+        set_incognito(pool, incognito[1]);
+        state.append(&[0.5; 500]).expect("append second half");
+
+        (path, state.finalize().expect("finalize"))
+    }
+
+    #[test]
+    fn incognito_meeting_writes_no_audio_even_if_turned_off_midway() {
+        let pool = open_test_pool();
+
+        let (path, result) = record_meeting(&pool, [true, false]);
+
+        assert!(!path.exists());
+        assert_eq!(result.file_path, None);
+        assert_eq!(result.duration_ms, 1_000);
+    }
+
+    #[test]
+    fn saved_meeting_keeps_its_audio_if_incognito_turns_on_midway() {
+        let pool = open_test_pool();
+
+        let (path, result) = record_meeting(&pool, [false, true]);
+
+        assert_eq!(
+            result.file_path.as_deref(),
+            Some(path.to_string_lossy().as_ref())
+        );
+        assert_eq!(result.duration_ms, 1_000);
+        let reader = hound::WavReader::open(&path).expect("open wav");
+        assert_eq!(reader.len(), 1_000);
+        let _ = std::fs::remove_file(path);
+    }
 }
@@ -0,0 +1,706 @@
+//! Question answering over meeting transcripts.
+//!
+//! When the whole transcript in scope fits in `MAX_CONTEXT_CHARS` it is sent
+//! as is. Otherwise the segments that best match the question are taken from
+//! the `meeting_segments_fts` index, or the transcript is sampled evenly when
+//! the question shares no terms with it. Excerpts are numbered in the prompt,
+//! the model cites them as `[n]`, and the numbers are mapped back to segments.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Local, NaiveDate, SecondsFormat, TimeZone, Utc};
+use regex::Regex;
+use serde_json::json;
+use sqlx::SqlitePool;
+
+use crate::db::meeting_queries::MeetingScope;
+use crate::domain::{
+    Conversation, MeetingAnswer, MeetingCitation, MeetingExcerpt, MeetingPromptMessage,
+    MeetingQuestion, MeetingQuestionPrompt, Message,
+};
+use crate::system::post_processing::{ChatMessage, OllamaConnection, OllamaSettings};
+
+pub const MAX_CONTEXT_CHARS: usize = 12_000;
+const MAX_SEARCH_HITS: u32 = 60;
+const MAX_SCOPE_SEGMENTS: u32 = 5_000;
+/// Earlier turns of the conversation sent along for follow-up questions.
+const HISTORY_MESSAGES: usize = 6;
+/// Per-excerpt allowance for the number, meeting, timestamp and speaker.
+const EXCERPT_OVERHEAD_CHARS: usize = 64;
+
+const SYSTEM_PROMPT: &str = "You answer questions about meeting transcripts using only the numbered excerpts you are given. Cite the excerpts that support each statement with their numbers in square brackets, for example [2] or [3][5]. If the excerpts do not contain the answer, say so plainly instead of guessing.";
+
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "an", "and", "any", "are", "as", "at", "be", "by", "can", "did", "do", "does",
+    "for", "from", "had", "has", "have", "how", "i", "in", "is", "it", "me", "meeting", "of", "on",
+    "or", "our", "said", "say", "so", "that", "the", "their", "there", "they", "this", "to", "us",
+    "was", "we", "were", "what", "when", "where", "which", "who", "why", "will", "with", "you",
+];
+
+/// Answer through an Ollama-compatible server, storing the question and the
+/// cited answer on the conversation.
+pub async fn ask(
+    pool: SqlitePool,
+    connection: OllamaConnection,
+    request: MeetingQuestion,
+) -> Result<MeetingAnswer, String> {
+    let prompt = prepare(pool.clone(), request).await?;
+
+    let mut messages = vec![ChatMessage::system(prompt.system.clone())];
+    for message in &prompt.messages {
+        messages.push(match message.role.as_str() {
+            "assistant" => ChatMessage::assistant(message.content.clone()),
+            _ => ChatMessage::user(message.content.clone()),
+        });
+    }
+
+    let model = connection.model.clone();
+    let reply = tauri::async_runtime::spawn_blocking(move || {
+        connection
+            .client(OllamaSettings::default())?
+            .chat(&messages, None)
+    })
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())?;
+
+    record(pool, prompt, &reply.content, Some(model)).await
+}
+
+/// Retrieve the excerpts for a question and build its prompt, continuing the
+/// requested conversation or the meeting's latest one.
+pub async fn prepare(
+    pool: SqlitePool,
+    request: MeetingQuestion,
+) -> Result<MeetingQuestionPrompt, String> {
+    let question = request.question.trim().to_string();
+    if question.is_empty() {
+        return Err("Question cannot be empty".to_string());
+    }
+    let meeting_id = request
+        .meeting_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    let from = scope_bound(request.from.as_deref(), false)?;
+    let to = scope_bound(request.to.as_deref(), true)?;
+    if meeting_id.is_none() && from.is_none() && to.is_none() {
+        return Err("Choose a meeting or a date range to ask about".to_string());
+    }
+    let scope = MeetingScope {
+        meeting_id,
+        from: from.as_deref(),
+        to: to.as_deref(),
+    };
+
+    let excerpts = retrieve(pool.clone(), &question, scope)
+        .await
+        .map_err(|err| err.to_string())?;
+    if excerpts.is_empty() {
+        return Err("No transcript found for that meeting or date range".to_string());
+    }
+
+    let conversation = open_conversation(
+        pool.clone(),
+        request.conversation_id.as_deref(),
+        meeting_id,
+        &question,
+        &excerpts,
+    )
+    .await?;
+    let stored = crate::db::conversation_queries::fetch_messages(pool, &conversation.id)
+        .await
+        .map_err(|err| err.to_string())?;
+    let history = crate::system::conversation_context::branch_path(
+        &stored,
+        conversation.head_message_id.as_deref(),
+    );
+
+    let mut messages = Vec::new();
+    let skip = history.len().saturating_sub(HISTORY_MESSAGES);
+    for message in history.iter().skip(skip) {
+        let content = match message.role.as_str() {
+            "user" => message.content.clone(),
+            // Old citation numbers refer to excerpts that are not resent.
+            "assistant" => strip_citations(&message.content),
+            _ => continue,
+        };
+        messages.push(MeetingPromptMessage {
+            role: message.role.clone(),
+            content,
+        });
+    }
+    messages.push(MeetingPromptMessage {
+        role: "user".to_string(),
+        content: build_prompt(&question, &excerpts),
+    });
+
+    Ok(MeetingQuestionPrompt {
+        conversation_id: conversation.id,
+        parent_message_id: history.last().map(|message| message.id.clone()),
+        question,
+        asked_at: now_iso(),
+        system: SYSTEM_PROMPT.to_string(),
+        messages,
+        excerpts: excerpts.iter().map(citation).collect(),
+    })
+}
+
+/// Store a prepared question and the model's answer, and move the
+/// conversation's head to the answer.
+pub async fn record(
+    pool: SqlitePool,
+    prompt: MeetingQuestionPrompt,
+    answer: &str,
+    model: Option<String>,
+) -> Result<MeetingAnswer, String> {
+    use crate::db::conversation_queries::{
+        fetch_conversation, insert_message, update_conversation,
+    };
+
+    let content = answer.trim().to_string();
+    if content.is_empty() {
+        return Err("The model returned an empty answer".to_string());
+    }
+    let conversation = fetch_conversation(pool.clone(), &prompt.conversation_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("Conversation {} not found", prompt.conversation_id))?;
+    let citations = parse_citations(&content, &prompt.excerpts);
+    let answered_at = now_iso();
+
+    let question = insert_message(
+        pool.clone(),
+        &Message {
+            id: crate::system::ids::new_id(),
+            conversation_id: conversation.id.clone(),
+            parent_id: prompt.parent_message_id,
+            role: "user".to_string(),
+            content: prompt.question,
+            model: None,
+            tokens_used: None,
+            context_json: None,
+            created_at: prompt.asked_at,
+        },
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    let answer = insert_message(
+        pool.clone(),
+        &Message {
+            id: crate::system::ids::new_id(),
+            conversation_id: conversation.id.clone(),
+            parent_id: Some(question.id.clone()),
+            role: "assistant".to_string(),
+            content,
+            model,
+            tokens_used: None,
+            context_json: (!citations.is_empty()).then(|| citations_context_json(&citations)),
+            created_at: answered_at.clone(),
+        },
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    let conversation = update_conversation(
+        pool,
+        &Conversation {
+            head_message_id: Some(answer.id.clone()),
+            updated_at: answered_at,
+            ..conversation
+        },
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    Ok(MeetingAnswer {
+        conversation,
+        question,
+        answer,
+        citations,
+    })
+}
+
+pub async fn retrieve(
+    pool: SqlitePool,
+    question: &str,
+    scope: MeetingScope<'_>,
+) -> Result<Vec<MeetingExcerpt>, sqlx::Error> {
+    use crate::db::meeting_queries::{fetch_meeting_excerpts, search_meeting_excerpts};
+
+    let transcript = fetch_meeting_excerpts(pool.clone(), scope, MAX_SCOPE_SEGMENTS).await?;
+    let transcript_chars: usize = transcript.iter().map(excerpt_cost).sum();
+    if transcript_chars <= MAX_CONTEXT_CHARS {
+        return Ok(transcript);
+    }
+
+    if let Some(query) = match_query(question) {
+        let hits = search_meeting_excerpts(pool, &query, scope, MAX_SEARCH_HITS).await?;
+        if !hits.is_empty() {
+            return Ok(select_ranked(hits, MAX_CONTEXT_CHARS));
+        }
+    }
+    Ok(sample_evenly(transcript, MAX_CONTEXT_CHARS))
+}
+
+async fn open_conversation(
+    pool: SqlitePool,
+    conversation_id: Option<&str>,
+    meeting_id: Option<&str>,
+    question: &str,
+    excerpts: &[MeetingExcerpt],
+) -> Result<Conversation, String> {
+    use crate::db::conversation_queries::{
+        fetch_conversation, fetch_latest_meeting_conversation, insert_conversation,
+    };
+
+    if let Some(conversation_id) = conversation_id {
+        let conversation = fetch_conversation(pool, conversation_id)
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| format!("Conversation {conversation_id} not found"))?;
+        if conversation.meeting_id.as_deref() != meeting_id {
+            return Err(format!(
+                "Conversation {conversation_id} is not about this meeting"
+            ));
+        }
+        return Ok(conversation);
+    }
+    if let Some(meeting_id) = meeting_id {
+        let existing = fetch_latest_meeting_conversation(pool.clone(), meeting_id)
+            .await
+            .map_err(|err| err.to_string())?;
+        if let Some(existing) = existing {
+            return Ok(existing);
+        }
+    }
+
+    let title = match (meeting_id, excerpts.first()) {
+        (Some(_), Some(excerpt)) if !excerpt.meeting_title.trim().is_empty() => {
+            format!("Questions about {}", excerpt.meeting_title.trim())
+        }
+        (Some(_), _) => "Questions about a meeting".to_string(),
+        (None, _) => question.chars().take(80).collect(),
+    };
+    let now = now_iso();
+    insert_conversation(
+        pool,
+        &Conversation {
+            id: crate::system::ids::new_id(),
+            title,
+            meeting_id: meeting_id.map(str::to_string),
+            head_message_id: None,
+            starred: false,
+            created_at: now.clone(),
+            updated_at: now,
+        },
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// An FTS5 query matching any meaningful word of the question. Longer words
+/// also match as prefixes so "budget" finds "budgets".
+pub fn match_query(question: &str) -> Option<String> {
+    let mut seen = HashSet::new();
+    let terms: Vec<String> = question
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|term| term.chars().count() > 1 && !STOP_WORDS.contains(&term.as_str()))
+        .filter(|term| seen.insert(term.clone()))
+        .map(|term| {
+            if term.chars().count() >= 4 {
+                format!("\"{term}\"*")
+            } else {
+                format!("\"{term}\"")
+            }
+        })
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+/// Normalise a range bound for comparison with `started_at`. A date means
+/// local midnight; an upper date bound moves to the next day so the range
+/// is exclusive at the end and covers the whole day.
+fn scope_bound(value: Option<&str>, upper: bool) -> Result<Option<String>, String> {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    let time = match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => {
+            let date = if upper {
+                date.succ_opt().unwrap_or(date)
+            } else {
+                date
+            };
+            local_midnight(date).ok_or_else(|| format!("'{value}' has no local midnight"))?
+        }
+        Err(_) => DateTime::parse_from_rfc3339(value)
+            .map_err(|_| format!("'{value}' is not a YYYY-MM-DD date or RFC 3339 timestamp"))?
+            .with_timezone(&Utc),
+    };
+    Ok(Some(time.to_rfc3339_opts(SecondsFormat::Millis, true)))
+}
+
+/// The first local instant of `date`. Where a daylight saving change skips
+/// midnight, the day starts an hour later.
+fn local_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| {
+            Local
+                .from_local_datetime(&(midnight + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn excerpt_cost(excerpt: &MeetingExcerpt) -> usize {
+    excerpt.segment.text.len() + EXCERPT_OVERHEAD_CHARS
+}
+
+/// Keep the best-ranked excerpts that fit the budget, in spoken order.
+fn select_ranked(ranked: Vec<MeetingExcerpt>, budget: usize) -> Vec<MeetingExcerpt> {
+    let mut used = 0;
+    let mut selected: Vec<MeetingExcerpt> = ranked
+        .into_iter()
+        .filter(|excerpt| {
+            let cost = excerpt_cost(excerpt);
+            let fits = used + cost <= budget;
+            if fits {
+                used += cost;
+            }
+            fits
+        })
+        .collect();
+    selected.sort_by(|a, b| {
+        (&a.meeting_started_at, a.segment.start_ms)
+            .cmp(&(&b.meeting_started_at, b.segment.start_ms))
+    });
+    selected
+}
+
+/// Spread the budget across the whole transcript.
+fn sample_evenly(transcript: Vec<MeetingExcerpt>, budget: usize) -> Vec<MeetingExcerpt> {
+    let total: usize = transcript.iter().map(excerpt_cost).sum();
+    if total <= budget {
+        return transcript;
+    }
+    let keep_every = total.div_ceil(budget);
+    let mut used = 0;
+    transcript
+        .into_iter()
+        .step_by(keep_every)
+        .filter(|excerpt| {
+            let cost = excerpt_cost(excerpt);
+            let fits = used + cost <= budget;
+            if fits {
+                used += cost;
+            }
+            fits
+        })
+        .collect()
+}
+
+fn format_timestamp(ms: i64) -> String {
+    let seconds = ms.max(0) / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+pub fn build_prompt(question: &str, excerpts: &[MeetingExcerpt]) -> String {
+    let lines: Vec<String> = excerpts
+        .iter()
+        .enumerate()
+        .map(|(index, excerpt)| {
+            let title = match excerpt.meeting_title.trim() {
+                "" => "Untitled meeting",
+                title => title,
+            };
+            let date = excerpt.meeting_started_at.get(..10).unwrap_or_default();
+            let speaker = excerpt
+                .segment
+                .speaker_name
+                .as_deref()
+                .or(excerpt.segment.speaker_id.as_deref())
+                .unwrap_or("Speaker");
+            format!(
+                "[{}] {title} ({date}) {} {speaker}: {}",
+                index + 1,
+                format_timestamp(excerpt.segment.start_ms),
+                excerpt.segment.text.trim()
+            )
+        })
+        .collect();
+
+    format!(
+        "Meeting excerpts:\n{}\n\nQuestion: {question}",
+        lines.join("\n")
+    )
+}
+
+fn citation_pattern() -> Regex {
+    Regex::new(r"\[(\d+(?:\s*,\s*\d+)*)\]").expect("valid citation pattern")
+}
+
+fn citation(excerpt: &MeetingExcerpt) -> MeetingCitation {
+    MeetingCitation {
+        segment_id: excerpt.segment.id.clone(),
+        meeting_id: excerpt.segment.meeting_id.clone(),
+        meeting_title: excerpt.meeting_title.clone(),
+        speaker_name: excerpt.segment.speaker_name.clone(),
+        text: excerpt.segment.text.clone(),
+        start_ms: excerpt.segment.start_ms,
+        end_ms: excerpt.segment.end_ms,
+    }
+}
+
+/// The excerpts cited as `[n]` or `[n, m]`, in order of first mention.
+/// Numbers outside the excerpt list are ignored.
+pub fn parse_citations(answer: &str, excerpts: &[MeetingCitation]) -> Vec<MeetingCitation> {
+    let mut seen = HashSet::new();
+    let mut citations = Vec::new();
+    for captures in citation_pattern().captures_iter(answer) {
+        for number in captures[1].split(',') {
+            let Ok(number) = number.trim().parse::<usize>() else {
+                continue;
+            };
+            let Some(excerpt) = number.checked_sub(1).and_then(|index| excerpts.get(index)) else {
+                continue;
+            };
+            if seen.insert(number) {
+                citations.push(excerpt.clone());
+            }
+        }
+    }
+    citations
+}
+
+fn strip_citations(answer: &str) -> String {
+    citation_pattern().replace_all(answer, "").into_owned()
+}
+
+/// Stored on the answer message in the attachment shape the chat view reads.
+fn citations_context_json(citations: &[MeetingCitation]) -> String {
+    let attachments: Vec<_> = citations
+        .iter()
+        .map(|citation| {
+            let timestamp = format_timestamp(citation.start_ms);
+            let label = match citation.speaker_name.as_deref() {
+                Some(speaker) => format!("{speaker} \u{00b7} {timestamp}"),
+                None => timestamp,
+            };
+            json!({
+                "type": "meetingSegment",
+                "id": citation.segment_id,
+                "label": label,
+                "content": citation.text,
+                "meetingId": citation.meeting_id,
+                "meetingTitle": citation.meeting_title,
+                "startMs": citation.start_ms,
+                "endMs": citation.end_ms,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(attachments).to_string()
+}
+
+fn now_iso() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::open_test_pool;
+    use crate::domain::MeetingSegment;
+
+    fn excerpt(id: &str, start_ms: i64, text: &str) -> MeetingExcerpt {
+        MeetingExcerpt {
+            segment: MeetingSegment {
+                id: id.to_string(),
+                meeting_id: "m1".to_string(),
+                speaker_id: Some("spk_0".to_string()),
+                speaker_name: Some("Ana".to_string()),
+                text: text.to_string(),
+                start_ms,
+                end_ms: start_ms + 4_000,
+                created_at: "2026-03-02T10:00:00.000Z".to_string(),
+            },
+            meeting_title: "Budget review".to_string(),
+            meeting_started_at: "2026-03-02T10:00:00.000Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn match_query_drops_stop_words_and_prefixes_long_terms() {
+        assert_eq!(
+            match_query("What did we decide about the Q3 budgets?").as_deref(),
+            Some("\"decide\"* OR \"q3\" OR \"budgets\"*")
+        );
+        assert_eq!(match_query("what was it?"), None);
+    }
+
+    #[test]
+    fn scope_bounds_accept_dates_and_timestamps() {
+        let next_local_midnight = Local
+            .with_ymd_and_hms(2026, 3, 3, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(SecondsFormat::Millis, true);
+        assert_eq!(
+            scope_bound(Some("2026-03-02"), true).unwrap(),
+            Some(next_local_midnight)
+        );
+        assert_eq!(
+            scope_bound(Some("2026-03-02T12:00:00+02:00"), false)
+                .unwrap()
+                .as_deref(),
+            Some("2026-03-02T10:00:00.000Z")
+        );
+        assert!(scope_bound(Some("last week"), false).is_err());
+        assert_eq!(scope_bound(Some("  "), false).unwrap(), None);
+    }
+
+    #[test]
+    fn ranked_excerpts_fit_the_budget_in_spoken_order() {
+        let long = "x".repeat(200);
+        let ranked = vec![
+            excerpt("s3", 30_000, "best"),
+            excerpt("s1", 10_000, &long),
+            excerpt("s2", 20_000, "next"),
+        ];
+
+        let selected = select_ranked(ranked, 2 * EXCERPT_OVERHEAD_CHARS + 10);
+
+        let ids: Vec<_> = selected.iter().map(|e| e.segment.id.as_str()).collect();
+        assert_eq!(ids, ["s2", "s3"]);
+    }
+
+    #[test]
+    fn citations_map_to_segments_once_in_order() {
+        let excerpts = vec![
+            excerpt("s1", 0, "We ship Friday."),
+            excerpt("s2", 65_000, "Marketing owns the launch."),
+        ];
+        let prompt = build_prompt("When do we ship?", &excerpts);
+        assert!(prompt.contains("[2] Budget review (2026-03-02) 00:01:05 Ana: Marketing"));
+
+        let numbered: Vec<_> = excerpts.iter().map(citation).collect();
+        let citations = parse_citations("Friday [1]. Marketing leads [2, 1][7].", &numbered);
+
+        let ids: Vec<_> = citations.iter().map(|c| c.segment_id.as_str()).collect();
+        assert_eq!(ids, ["s1", "s2"]);
+        assert_eq!(strip_citations("Friday [1]."), "Friday .");
+
+        let context: serde_json::Value =
+            serde_json::from_str(&citations_context_json(&citations)).unwrap();
+        assert_eq!(context[1]["type"], "meetingSegment");
+        assert_eq!(context[1]["label"], "Ana \u{00b7} 00:01:05");
+        assert_eq!(context[1]["startMs"], 65_000);
+    }
+
+    fn insert_meeting(pool: &SqlitePool, id: &str, title: &str, texts: &[&str]) {
+        use crate::db::meeting_queries::{insert_meeting, insert_meeting_segments};
+        use crate::domain::{Meeting, MeetingSegment};
+
+        let started_at = "2026-03-02T10:00:00.000Z".to_string();
+        tauri::async_runtime::block_on(async {
+            insert_meeting(
+                pool.clone(),
+                &Meeting {
+                    id: id.to_string(),
+                    title: title.to_string(),
+                    app_source: None,
+                    started_at: started_at.clone(),
+                    ended_at: None,
+                    duration_ms: None,
+                    status: "completed".to_string(),
+                    audio_path: None,
+                    summary: None,
+                    action_items: None,
+                    starred: false,
+                    created_at: started_at.clone(),
+                    updated_at: started_at.clone(),
+                },
+            )
+            .await
+            .expect("insert meeting");
+            let segments: Vec<_> = texts
+                .iter()
+                .enumerate()
+                .map(|(index, text)| MeetingSegment {
+                    id: format!("{id}-{index}"),
+                    meeting_id: id.to_string(),
+                    speaker_id: None,
+                    speaker_name: None,
+                    text: text.to_string(),
+                    start_ms: index as i64 * 5_000,
+                    end_ms: index as i64 * 5_000 + 4_000,
+                    created_at: started_at.clone(),
+                })
+                .collect();
+            insert_meeting_segments(pool.clone(), &segments)
+                .await
+                .expect("insert segments");
+        });
+    }
+
+    #[test]
+    fn long_transcripts_are_searched_for_the_question() {
+        let pool = open_test_pool();
+        let filler = "We went through the slides one by one. ".repeat(10);
+        let mut texts = vec![filler.as_str(); 100];
+        texts[37] = "The travel budgets are frozen until June.";
+        insert_meeting(&pool, "m1", "Planning", &texts);
+        insert_meeting(&pool, "m2", "Other", &["Our budget doubled."]);
+
+        let scope = MeetingScope {
+            meeting_id: Some("m1"),
+            from: None,
+            to: None,
+        };
+        let excerpts = tauri::async_runtime::block_on(retrieve(
+            pool,
+            "What happened to the travel budget?",
+            scope,
+        ))
+        .unwrap();
+
+        assert_eq!(excerpts[0].segment.id, "m1-37");
+        assert!(excerpts.iter().all(|e| e.segment.meeting_id == "m1"));
+        assert!(excerpts.iter().map(excerpt_cost).sum::<usize>() <= MAX_CONTEXT_CHARS);
+    }
+
+    #[test]
+    fn conversations_of_other_meetings_are_refused() {
+        let pool = open_test_pool();
+        insert_meeting(&pool, "m1", "Planning", &["We ship Friday."]);
+        insert_meeting(&pool, "m2", "Retro", &["Releases were late."]);
+        let ask_about = |meeting_id: &str, conversation_id: Option<String>| MeetingQuestion {
+            question: "When do we ship?".to_string(),
+            meeting_id: Some(meeting_id.to_string()),
+            from: None,
+            to: None,
+            conversation_id,
+            api_key_id: None,
+        };
+
+        let prompt =
+            tauri::async_runtime::block_on(prepare(pool.clone(), ask_about("m1", None))).unwrap();
+        assert!(prompt.messages[0].content.contains("[1] Planning"));
+
+        let err = tauri::async_runtime::block_on(prepare(
+            pool,
+            ask_about("m2", Some(prompt.conversation_id.clone())),
+        ))
+        .unwrap_err();
+        assert!(err.contains("not about this meeting"));
+    }
+}
@@ -0,0 +1,239 @@
+//! Measures input devices so they can be ranked by how they actually sound.
+//!
+//! Each device records a few seconds while the user speaks. The quietest
+//! stretches give the noise floor and the loudest the speech level; together
+//! with clipping and DC offset they make up a score out of 100 and a gain that
+//! brings speech to a comfortable level. Each device then records at its own
+//! gain.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::{
+    MicrophoneCalibration, MicrophoneCalibrationFailure, MicrophoneCalibrationProgress,
+    MicrophoneCalibrationReport,
+};
+use crate::platform::audio::MeasuredInput;
+
+/// How long each device records for.
+pub const CALIBRATION_DURATION: Duration = Duration::from_secs(3);
+
+const FRAME_DURATION_SECS: f32 = 0.02;
+const SILENCE_DB: f32 = -100.0;
+const CLIPPING_THRESHOLD: f32 = 0.99;
+/// Where the recommended gain puts the loudest speech.
+const TARGET_SPEECH_DB: f32 = -20.0;
+const PEAK_HEADROOM: f32 = 0.9;
+const MIN_GAIN: f32 = 0.5;
+const MAX_GAIN: f32 = 4.0;
+
+/// Measure a calibration recording. Returns `None` when there is too little
+/// audio to judge.
+pub fn measure(
+    device_name: &str,
+    samples: &[f32],
+    sample_rate: u32,
+) -> Option<MicrophoneCalibration> {
+    let frame_len = (sample_rate as f32 * FRAME_DURATION_SECS) as usize;
+    if frame_len == 0 || samples.len() < frame_len * 10 {
+        return None;
+    }
+
+    let dc_offset = samples.iter().sum::<f32>() / samples.len() as f32;
+    let mut frame_levels: Vec<f32> = samples
+        .chunks_exact(frame_len)
+        .map(|frame| {
+            let power = frame
+                .iter()
+                .map(|sample| (sample - dc_offset).powi(2))
+                .sum::<f32>()
+                / frame.len() as f32;
+            to_db(power.sqrt())
+        })
+        .collect();
+    frame_levels.sort_by(f32::total_cmp);
+
+    let noise_floor_db = percentile(&frame_levels, 0.1);
+    let speech_level_db = percentile(&frame_levels, 0.95);
+    let snr_db = speech_level_db - noise_floor_db;
+    let clipped = samples
+        .iter()
+        .filter(|sample| sample.abs() >= CLIPPING_THRESHOLD)
+        .count();
+    let clipping_ratio = clipped as f32 / samples.len() as f32;
+    let peak = samples
+        .iter()
+        .fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+
+    Some(MicrophoneCalibration {
+        device_name: device_name.to_string(),
+        noise_floor_db,
+        speech_level_db,
+        snr_db,
+        clipping_ratio,
+        dc_offset,
+        score: score(noise_floor_db, snr_db, clipping_ratio, dc_offset),
+        recommended_gain: recommended_gain(speech_level_db, peak),
+        measured_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+    })
+}
+
+/// Record and measure every input device, one after another, calling
+/// `on_device` as each one starts. Results are sorted best first.
+pub fn calibrate_all(
+    mut on_device: impl FnMut(MicrophoneCalibrationProgress),
+) -> MicrophoneCalibrationReport {
+    let mut results = Vec::new();
+    let mut failures = Vec::new();
+    let devices = crate::platform::audio::list_input_devices();
+    let total = devices.len();
+    for (index, device) in devices.into_iter().enumerate() {
+        let label = device.label;
+        on_device(MicrophoneCalibrationProgress {
+            device_name: label.clone(),
+            index: index + 1,
+            total,
+        });
+        let audio = match crate::platform::audio::record_from_device(&label, CALIBRATION_DURATION) {
+            Ok(audio) => audio,
+            Err(err) => {
+                eprintln!("[mic_calibration] Failed to record from '{label}': {err}");
+                failures.push(MicrophoneCalibrationFailure {
+                    device_name: label,
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        match measure(&label, &audio.samples, audio.sample_rate) {
+            Some(calibration) => results.push(calibration),
+            None => failures.push(MicrophoneCalibrationFailure {
+                device_name: label,
+                error: "The device delivered too little audio.".to_string(),
+            }),
+        }
+    }
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let best = results.first();
+    MicrophoneCalibrationReport {
+        recommended_device: best.map(|best| best.device_name.clone()),
+        recommended_gain: best.map(|best| best.recommended_gain),
+        results,
+        failures,
+    }
+}
+
+/// Rank devices and set their gain from their saved calibrations.
+pub async fn apply_saved(pool: SqlitePool) -> Result<(), sqlx::Error> {
+    let calibrations =
+        crate::db::microphone_calibration_queries::fetch_microphone_calibrations(pool).await?;
+    crate::platform::audio::set_measured_inputs(measured_inputs(&calibrations));
+    Ok(())
+}
+
+fn measured_inputs(calibrations: &[MicrophoneCalibration]) -> HashMap<String, MeasuredInput> {
+    calibrations
+        .iter()
+        .map(|calibration| {
+            (
+                calibration.device_name.clone(),
+                MeasuredInput {
+                    score: calibration.score,
+                    gain: calibration.recommended_gain,
+                },
+            )
+        })
+        .collect()
+}
+
+fn to_db(amplitude: f32) -> f32 {
+    if amplitude > 0.0 {
+        (20.0 * amplitude.log10()).max(SILENCE_DB)
+    } else {
+        SILENCE_DB
+    }
+}
+
+fn percentile(sorted: &[f32], fraction: f32) -> f32 {
+    let index = ((sorted.len() - 1) as f32 * fraction).round() as usize;
+    sorted[index]
+}
+
+/// Out of 100: signal-to-noise counts most, then a low noise floor and a
+/// centred signal. Clipping takes points off.
+fn score(noise_floor_db: f32, snr_db: f32, clipping_ratio: f32, dc_offset: f32) -> f32 {
+    let snr = (snr_db / 40.0).clamp(0.0, 1.0) * 60.0;
+    let floor = ((-30.0 - noise_floor_db) / 40.0).clamp(0.0, 1.0) * 25.0;
+    let centred = (1.0 - dc_offset.abs() / 0.05).clamp(0.0, 1.0) * 15.0;
+    let clipping = (clipping_ratio * 2000.0).min(40.0);
+    (snr + floor + centred - clipping).clamp(0.0, 100.0)
+}
+
+fn recommended_gain(speech_level_db: f32, peak: f32) -> f32 {
+    if speech_level_db <= SILENCE_DB || peak <= 0.0 {
+        return 1.0;
+    }
+    let gain = 10f32
+        .powf((TARGET_SPEECH_DB - speech_level_db) / 20.0)
+        .min(PEAK_HEADROOM / peak)
+        .clamp(MIN_GAIN, MAX_GAIN);
+    (gain * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16_000;
+
+    /// Quiet hiss throughout, with a tone of `amplitude` in the second half.
+    fn recording(noise: f32, amplitude: f32, offset: f32) -> Vec<f32> {
+        (0..RATE as usize)
+            .map(|index| {
+                let hiss = if index % 2 == 0 { noise } else { -noise };
+                let tone = if index >= RATE as usize / 2 {
+                    amplitude * (index as f32 * 0.05).sin()
+                } else {
+                    0.0
+                };
+                (hiss + tone + offset).clamp(-1.0, 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn measures_noise_speech_and_gain() {
+        let calibration = measure("USB Mic", &recording(0.001, 0.05, 0.0), RATE).expect("measured");
+        assert!((calibration.noise_floor_db + 60.0).abs() < 1.0);
+        assert!(calibration.snr_db > 25.0);
+        assert_eq!(calibration.clipping_ratio, 0.0);
+        assert!(calibration.dc_offset.abs() < 1e-3);
+        // Speech around -29 dBFS is brought up towards -20.
+        assert!(calibration.recommended_gain > 2.0 && calibration.recommended_gain <= MAX_GAIN);
+        assert!(calibration.score > 70.0);
+
+        assert_eq!(measure("USB Mic", &[0.0; 100], RATE), None);
+    }
+
+    #[test]
+    fn penalises_clipping_noise_and_offset() {
+        let clean = measure("a", &recording(0.001, 0.3, 0.0), RATE).expect("clean");
+        let clipped = measure("b", &recording(0.001, 1.5, 0.0), RATE).expect("clipped");
+        let noisy = measure("c", &recording(0.05, 0.3, 0.0), RATE).expect("noisy");
+        let offset = measure("d", &recording(0.001, 0.3, 0.04), RATE).expect("offset");
+
+        assert!(clipped.clipping_ratio > 0.1);
+        assert!(clipped.recommended_gain < 1.0);
+        assert!(clean.score > clipped.score);
+        assert!(clean.score > noisy.score);
+        assert!(clean.score > offset.score);
+
+        let inputs = measured_inputs(&[clean.clone(), clipped]);
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs["a"].gain, clean.recommended_gain);
+    }
+}
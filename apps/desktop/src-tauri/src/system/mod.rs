@@ -1,14 +1,40 @@
+pub mod ambient;
+pub mod analytics;
+pub mod app_profiles;
+pub mod audio_devices;
+pub mod audio_file;
 pub mod audio_feedback;
 pub mod audio_store;
+pub mod conversation_context;
 pub mod crypto;
 pub mod diagnostics;
 pub mod google_oauth;
 pub mod gpu;
+pub mod history;
+pub mod ids;
+pub mod incognito;
+pub mod itn;
+pub mod language_id;
+pub mod local_api;
+pub mod mcp;
+pub mod mcp_socket;
+pub mod media_import;
 pub mod meeting_audio_store;
+pub mod meeting_qa;
+pub mod mic_calibration;
 pub mod models;
 pub mod paths;
+pub mod post_processing;
+pub mod retention;
 pub mod storage_repo;
+pub mod term_engine;
+pub mod term_feedback;
+pub mod tone_template;
+pub mod transcript_export;
 pub mod tray;
+pub mod voice_commands;
+pub mod warm_microphone;
+pub mod websocket;
 
 pub use paths::*;
 pub use storage_repo::StorageRepo;
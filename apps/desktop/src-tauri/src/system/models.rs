@@ -16,6 +16,8 @@ pub enum WhisperModelSize {
 }
 
 impl WhisperModelSize {
+    pub const ALL: [Self; 4] = [Self::Tiny, Self::Base, Self::Small, Self::Medium];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Tiny => "tiny",
@@ -85,14 +87,18 @@ impl fmt::Display for WhisperModelSize {
 
 pub fn ensure_whisper_model(app: &tauri::AppHandle, size: WhisperModelSize) -> io::Result<PathBuf> {
     let model_path = crate::system::paths::whisper_model_path(app, size)?;
+    ensure_whisper_model_at(&model_path, size)?;
+    Ok(model_path)
+}
 
+/// Download the model to `model_path` unless it is already there.
+pub fn ensure_whisper_model_at(model_path: &Path, size: WhisperModelSize) -> io::Result<()> {
     if model_path.exists() {
-        return Ok(model_path);
+        return Ok(());
     }
 
     let url = resolve_model_url(size)?;
-    download_model(&url, &model_path)?;
-    Ok(model_path)
+    download_model(&url, model_path)
 }
 
 fn resolve_model_url(size: WhisperModelSize) -> io::Result<String> {
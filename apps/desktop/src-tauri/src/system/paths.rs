@@ -5,6 +5,7 @@ use super::models::WhisperModelSize;
 
 const MODELS_DIR_NAME: &str = "models";
 const STORAGE_DIR_NAME: &str = "storage";
+const MCP_ENDPOINT_FILE_NAME: &str = "mcp-endpoint.json";
 
 pub fn database_path(app: &tauri::AppHandle) -> io::Result<PathBuf> {
     let mut path = app
@@ -64,3 +65,77 @@ pub fn startup_diagnostics_path(app: &tauri::AppHandle) -> io::Result<PathBuf> {
     path.push("startup_diagnostics.log");
     Ok(path)
 }
+
+pub fn mcp_endpoint_path(app: &tauri::AppHandle) -> io::Result<PathBuf> {
+    let mut path = app
+        .path()
+        .app_data_dir()
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+    fs::create_dir_all(&path)?;
+    path.push(MCP_ENDPOINT_FILE_NAME);
+    Ok(path)
+}
+
+/// The app's config directory for `identifier`, resolved the way Tauri does
+/// but without a running app. Used by the command-line mode.
+pub fn standalone_config_dir(identifier: &str) -> Option<PathBuf> {
+    platform_config_root().map(|root| root.join(identifier))
+}
+
+/// The app's data directory for `identifier`; see [`standalone_config_dir`].
+pub fn standalone_data_dir(identifier: &str) -> Option<PathBuf> {
+    platform_data_root().map(|root| root.join(identifier))
+}
+
+pub fn standalone_models_dir(identifier: &str) -> Option<PathBuf> {
+    standalone_data_dir(identifier).map(|dir| dir.join(MODELS_DIR_NAME))
+}
+
+pub fn standalone_mcp_endpoint_path(identifier: &str) -> Option<PathBuf> {
+    standalone_data_dir(identifier).map(|dir| dir.join(MCP_ENDPOINT_FILE_NAME))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(target_os = "linux")]
+fn xdg_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+    std::env::var_os(variable)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| home_dir().map(|home| home.join(fallback)))
+}
+
+#[cfg(target_os = "linux")]
+fn platform_config_root() -> Option<PathBuf> {
+    xdg_dir("XDG_CONFIG_HOME", ".config")
+}
+
+#[cfg(target_os = "linux")]
+fn platform_data_root() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+#[cfg(target_os = "macos")]
+fn platform_config_root() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library/Application Support"))
+}
+
+#[cfg(target_os = "macos")]
+fn platform_data_root() -> Option<PathBuf> {
+    platform_config_root()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_config_root() -> Option<PathBuf> {
+    std::env::var_os("APPDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "windows")]
+fn platform_data_root() -> Option<PathBuf> {
+    platform_config_root()
+}
@@ -0,0 +1,974 @@
+//! Transcript post-processing against an Ollama-compatible `/api/chat`
+//! endpoint.
+//!
+//! Runs in the backend so a rewrite survives the main window being hidden or
+//! reloaded. The reply is streamed as NDJSON; each read is bounded by the read
+//! timeout and each attempt, stalled or not, by the total timeout. Connection
+//! failures, timeouts and 5xx responses are retried with exponential backoff.
+//!
+//! This is also the only place the post-processing prompt is built; the
+//! webview asks for it when it talks to other providers.
+
+use std::{
+    io::{BufRead, BufReader},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::domain::{PostProcessOutcome, TextFieldContext};
+
+pub const OLLAMA_DEFAULT_URL: &str = "http://127.0.0.1:11434";
+pub const POST_PROCESS_DEVICE_OLLAMA: &str = "API \u{2022} Ollama";
+
+const SYSTEM_PROMPT: &str = "You are a transcript rewriting assistant. You modify the style and tone of the transcript while keeping the subject matter the same.";
+
+#[derive(Debug, thiserror::Error)]
+pub enum OllamaError {
+    #[error("Unable to reach Ollama at {url}: {message}")]
+    Connect { url: String, message: String },
+    #[error("Ollama did not respond within {0:?}")]
+    Timeout(Duration),
+    #[error("Ollama returned status {status}: {body}")]
+    Status { status: u16, body: String },
+    #[error("Ollama request failed: {0}")]
+    Request(String),
+    #[error("Ollama reported an error: {0}")]
+    Remote(String),
+    #[error("Ollama sent a malformed stream: {0}")]
+    Malformed(String),
+}
+
+impl OllamaError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Connect { .. } | Self::Timeout(_) | Self::Request(_) => true,
+            Self::Status { status, .. } => *status >= 500,
+            Self::Remote(_) | Self::Malformed(_) => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OllamaSettings {
+    pub connect_timeout: Duration,
+    /// Longest silence tolerated between streamed chunks.
+    pub read_timeout: Duration,
+    pub total_timeout: Duration,
+    pub max_attempts: u32,
+    pub retry_delay: Duration,
+}
+
+impl Default for OllamaSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            read_timeout: Duration::from_secs(30),
+            total_timeout: Duration::from_secs(120),
+            max_attempts: 3,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ChatMessage {
+    pub role: &'static str,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn system(content: impl Into<String>) -> Self {
+        Self {
+            role: "system",
+            content: content.into(),
+        }
+    }
+
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: "user",
+            content: content.into(),
+        }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self {
+            role: "assistant",
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChatReply {
+    pub content: String,
+    pub attempts: u32,
+}
+
+#[derive(Deserialize)]
+struct StreamLine {
+    #[serde(default)]
+    message: Option<StreamMessage>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StreamMessage {
+    #[serde(default)]
+    content: String,
+}
+
+/// Ollama's `num_ctx` when neither the request nor the model sets one.
+const OLLAMA_DEFAULT_NUM_CTX: u32 = 4_096;
+
+/// The parts of an `/api/show` reply that size the context.
+#[derive(Deserialize)]
+struct ModelDetails {
+    #[serde(default)]
+    parameters: String,
+    #[serde(default)]
+    model_info: serde_json::Map<String, Value>,
+}
+
+impl ModelDetails {
+    fn context_window(&self) -> u32 {
+        let num_ctx = self.parameters.lines().find_map(|line| {
+            let mut parts = line.split_whitespace();
+            (parts.next() == Some("num_ctx"))
+                .then(|| parts.next()?.parse::<u32>().ok())
+                .flatten()
+        });
+        // Keyed by architecture, e.g. `llama.context_length`.
+        let trained = self
+            .model_info
+            .iter()
+            .find(|(key, _)| key.ends_with(".context_length"))
+            .and_then(|(_, value)| value.as_u64())
+            .map(|length| length.min(u32::MAX as u64) as u32);
+
+        let window = num_ctx.unwrap_or(OLLAMA_DEFAULT_NUM_CTX);
+        trained.map_or(window, |trained| window.min(trained))
+    }
+}
+
+/// The configured server, model and optional bearer key.
+#[derive(Clone, Debug)]
+pub struct OllamaConnection {
+    pub base_url: String,
+    pub model: String,
+    pub api_key: Option<String>,
+}
+
+impl OllamaConnection {
+    pub fn client(&self, settings: OllamaSettings) -> Result<OllamaClient, OllamaError> {
+        OllamaClient::new(&self.base_url, &self.model, self.api_key.clone(), settings)
+    }
+}
+
+/// Blocking client; call it from `spawn_blocking`.
+#[derive(Clone)]
+pub struct OllamaClient {
+    http: reqwest::blocking::Client,
+    chat_url: String,
+    show_url: String,
+    model: String,
+    api_key: Option<String>,
+    settings: OllamaSettings,
+}
+
+impl OllamaClient {
+    pub fn new(
+        base_url: &str,
+        model: &str,
+        api_key: Option<String>,
+        settings: OllamaSettings,
+    ) -> Result<Self, OllamaError> {
+        let http = reqwest::blocking::Client::builder()
+            .user_agent("osvoice-desktop")
+            .connect_timeout(settings.connect_timeout)
+            .timeout(settings.read_timeout)
+            .build()
+            .map_err(|err| OllamaError::Request(err.to_string()))?;
+
+        Ok(Self {
+            http,
+            chat_url: api_endpoint(base_url, "chat"),
+            show_url: api_endpoint(base_url, "show"),
+            model: model.to_string(),
+            api_key: api_key.filter(|key| !key.trim().is_empty()),
+            settings,
+        })
+    }
+
+    pub fn chat(
+        &self,
+        messages: &[ChatMessage],
+        format: Option<&Value>,
+    ) -> Result<ChatReply, OllamaError> {
+        let mut body = json!({
+            "model": self.model,
+            "messages": messages,
+            "stream": true,
+            "options": { "temperature": 0 },
+        });
+        if let Some(format) = format {
+            body["format"] = format.clone();
+        }
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.chat_within_deadline(&body) {
+                Ok(content) => {
+                    return Ok(ChatReply {
+                        content,
+                        attempts: attempt,
+                    })
+                }
+                Err(err) if err.is_retryable() && attempt < self.settings.max_attempts => {
+                    eprintln!("[post_processing] Attempt {attempt} failed, retrying: {err}");
+                    thread::sleep(self.settings.retry_delay * 2u32.pow(attempt - 1));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Run one attempt on a worker thread so a server that keeps trickling
+    /// bytes cannot hold the caller past the total timeout. An abandoned
+    /// worker ends on its own once the read timeout fires.
+    fn chat_within_deadline(&self, body: &Value) -> Result<String, OllamaError> {
+        let (sender, receiver) = mpsc::channel();
+        let client = self.clone();
+        let body = body.clone();
+        thread::spawn(move || {
+            let _ = sender.send(client.chat_once(&body));
+        });
+
+        match receiver.recv_timeout(self.settings.total_timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                Err(OllamaError::Timeout(self.settings.total_timeout))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(OllamaError::Request(
+                "request worker stopped unexpectedly".to_string(),
+            )),
+        }
+    }
+
+    fn chat_once(&self, body: &Value) -> Result<String, OllamaError> {
+        let mut request = self.http.post(&self.chat_url).json(body);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().map_err(|err| self.map_reqwest_error(err))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(OllamaError::Status {
+                status: status.as_u16(),
+                body: body.trim().chars().take(200).collect(),
+            });
+        }
+
+        let mut content = String::new();
+        for line in BufReader::new(response).lines() {
+            let line = line.map_err(|err| match err.kind() {
+                std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock => {
+                    OllamaError::Timeout(self.settings.read_timeout)
+                }
+                _ => OllamaError::Request(err.to_string()),
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed: StreamLine = serde_json::from_str(&line)
+                .map_err(|err| OllamaError::Malformed(err.to_string()))?;
+            if let Some(error) = parsed.error {
+                return Err(OllamaError::Remote(error));
+            }
+            if let Some(message) = parsed.message {
+                content.push_str(&message.content);
+            }
+            if parsed.done {
+                return Ok(content);
+            }
+        }
+
+        Err(OllamaError::Malformed(
+            "stream ended before the final message".to_string(),
+        ))
+    }
+
+    /// The context the server gives this model per request: the model's
+    /// `num_ctx` parameter, or Ollama's default, never more than the model
+    /// was trained on.
+    pub fn context_window(&self) -> Result<u32, OllamaError> {
+        let mut request = self
+            .http
+            .post(&self.show_url)
+            .json(&json!({ "model": self.model }));
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().map_err(|err| self.map_reqwest_error(err))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(OllamaError::Status {
+                status: status.as_u16(),
+                body: body.trim().chars().take(200).collect(),
+            });
+        }
+        let details: ModelDetails = response
+            .json()
+            .map_err(|err| OllamaError::Malformed(err.to_string()))?;
+        Ok(details.context_window())
+    }
+
+    fn map_reqwest_error(&self, err: reqwest::Error) -> OllamaError {
+        if err.is_timeout() {
+            OllamaError::Timeout(self.settings.read_timeout)
+        } else if err.is_connect() {
+            OllamaError::Connect {
+                url: self.chat_url.clone(),
+                message: err.to_string(),
+            }
+        } else {
+            OllamaError::Request(err.to_string())
+        }
+    }
+}
+
+/// Accept the base URL with or without the OpenAI-compatible `/v1` suffix
+/// used by the webview client.
+fn api_endpoint(base_url: &str, path: &str) -> String {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    let root = trimmed.strip_suffix("/v1").unwrap_or(trimmed);
+    let root = if root.is_empty() {
+        OLLAMA_DEFAULT_URL
+    } else {
+        root
+    };
+    format!("{root}/api/{path}")
+}
+
+pub struct PostProcessRequest<'a> {
+    pub transcript: &'a str,
+    pub dictation_language: &'a str,
+    pub tone_template: Option<&'a str>,
+    pub context: Option<&'a TextFieldContext>,
+    /// Language to translate the transcript into while rewriting it.
+    pub target_language: Option<&'a str>,
+}
+
+impl PostProcessRequest<'_> {
+    /// The language the rewrite is translated into, unless it is the one the
+    /// transcript is already in.
+    pub fn translation_target(&self) -> Option<&str> {
+        self.target_language
+            .map(str::trim)
+            .filter(|target| !target.is_empty())
+            .filter(|target| base_language(target) != base_language(self.dictation_language))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessedTranscription {
+    processed_transcription: String,
+}
+
+fn processed_transcription_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "processedTranscription": {
+                "type": "string",
+                "description": "The processed version of the transcript. Empty if no transcript."
+            }
+        },
+        "required": ["processedTranscription"],
+        "additionalProperties": false
+    })
+}
+
+/// Rewrite a transcript with the tone's style. Never fails: on any error the
+/// input transcript is kept and the reason is added to `warnings`.
+pub fn post_process(client: &OllamaClient, request: &PostProcessRequest) -> PostProcessOutcome {
+    let prompt = build_post_process_prompt(request);
+    let mut outcome = PostProcessOutcome {
+        transcript: request.transcript.to_string(),
+        post_process_prompt: Some(prompt.clone()),
+        post_process_mode: "api".to_string(),
+        post_process_device: Some(POST_PROCESS_DEVICE_OLLAMA.to_string()),
+        postprocess_duration_ms: None,
+        target_language: None,
+        warnings: Vec::new(),
+    };
+
+    if request.transcript.trim().is_empty() {
+        return outcome;
+    }
+
+    let started = Instant::now();
+    let messages = [
+        ChatMessage::system(SYSTEM_PROMPT),
+        ChatMessage::user(prompt),
+    ];
+    let reply = client.chat(&messages, Some(&processed_transcription_schema()));
+    outcome.postprocess_duration_ms = Some(started.elapsed().as_millis() as i64);
+
+    match reply {
+        Ok(reply) => {
+            match serde_json::from_str::<ProcessedTranscription>(reply.content.trim()) {
+                Ok(parsed) => {
+                    outcome.transcript = parsed.processed_transcription.trim().to_string();
+                    outcome.target_language = request.translation_target().map(str::to_string);
+                }
+                Err(err) => outcome
+                    .warnings
+                    .push(format!("Failed to parse post-processing response: {err}")),
+            }
+            if reply.attempts > 1 {
+                outcome.warnings.push(format!(
+                    "Post-processing succeeded after {} attempts",
+                    reply.attempts
+                ));
+            }
+        }
+        Err(err) => outcome
+            .warnings
+            .push(format!("Post-processing failed: {err}")),
+    }
+
+    outcome
+}
+
+fn base_language(code: &str) -> &str {
+    code.split(['-', '_']).next().unwrap_or(code)
+}
+
+fn language_display_name(code: &str) -> &str {
+    match base_language(code) {
+        "en" => "English",
+        "es" => "Spanish",
+        "fr" => "French",
+        "de" => "German",
+        "it" => "Italian",
+        "pt" => "Portuguese",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "ru" => "Russian",
+        "uk" => "Ukrainian",
+        "tr" => "Turkish",
+        "ar" => "Arabic",
+        "hi" => "Hindi",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "zh" => "Chinese",
+        "sv" => "Swedish",
+        "da" => "Danish",
+        "no" => "Norwegian",
+        "fi" => "Finnish",
+        _ => code,
+    }
+}
+
+fn unless_english(language: &str, sentence: &str) -> String {
+    if base_language(language) == "en" {
+        String::new()
+    } else {
+        format!(" {sentence}")
+    }
+}
+
+/// What the prompt says about the output language after naming it: translate
+/// when asked to, otherwise `keep_language` for non-English transcripts.
+fn language_rule(request: &PostProcessRequest, keep_language: &str) -> String {
+    match request.translation_target() {
+        Some(target) => format!(
+            " Translate the dictation from {} into {}, keeping names, product names, code, links and email addresses as they are.",
+            language_display_name(request.dictation_language),
+            language_display_name(target),
+        ),
+        None => unless_english(request.dictation_language, keep_language),
+    }
+}
+
+fn style_section(tone_template: Option<&str>) -> String {
+    match tone_template.map(str::trim).filter(|template| !template.is_empty()) {
+        Some(template) => format!(
+            "\nSTYLE INSTRUCTIONS:\nApply the following writing style to your output:\n```\n{template}\n```\n"
+        ),
+        None => "\nSTYLE INSTRUCTIONS:\nDo not modify the style or tone of the transcript. Focus solely on fixing grammar mistakes and punctuation errors without changing the speaker's original tone or intent.\n".to_string(),
+    }
+}
+
+const FORMATTING_RULES: &str = "
+FORMATTING RULES (MUST APPLY):
+
+EMAIL FORMAT:
+When the transcript contains ends with a sign-off followed by a proper noun (thanks/thank you/best/cheers/sincerely/regards + name), format as an email. Place the greeting on its own line followed by a comma, separate body content into paragraphs with blank lines between them, and place the sign-off on its own line followed by a comma with the sender name on the next line.
+
+LIST FORMAT:
+Format as a list when the transcript contains three or more distinct items of the same category or type. Detect lists by identifying parallel structures where items are separated by conjunctions, pauses, or transitions. Use numbered format (1. 2. 3.) when the speaker used ordinal or cardinal enumeration words, or when the items represent sequential steps or a ranked order. Use bulleted format (- item) for all other lists where items are parallel but order is not significant. Place each item on its own line. Remove enumeration words and connective words between items.
+
+DEFAULT FORMAT:
+When no email or list patterns are detected, output as flowing prose. Insert paragraph breaks at natural topic transitions.
+";
+
+const SYMBOL_RULE: &str = "Convert spoken symbol cues to actual symbols: \"hashtag [word]\" or \"pound sign [word]\" becomes \"#[word]\", and \"at [name]\" or \"at sign [name]\" becomes \"@[name]\".";
+
+const STEP_MARKER_RULE: &str = "The transcript contains markers like [[1]] where the speaker gave an editing command. Keep every marker exactly once and in the same order, and do not move words across a marker.";
+
+/// Dictations with voice commands are rewritten in one request: their text
+/// runs are joined with `[[1]]`, `[[2]]`, ... where each command was.
+fn step_marker_pattern() -> Regex {
+    Regex::new(r"\s*\[\[\d+\]\]\s*").expect("valid step marker pattern")
+}
+
+/// The rewrite as it reads once pasted, without the command markers.
+pub fn strip_step_markers(text: &str) -> String {
+    step_marker_pattern()
+        .replace_all(text, " ")
+        .trim()
+        .to_string()
+}
+
+fn step_marker_rule(transcript: &str, prefix: &str) -> String {
+    if step_marker_pattern().is_match(transcript) {
+        format!("\n{prefix}{STEP_MARKER_RULE}")
+    } else {
+        String::new()
+    }
+}
+
+/// Build the rewrite prompt for a transcript, fitted to the text field
+/// context when there is one.
+pub fn build_post_process_prompt(request: &PostProcessRequest) -> String {
+    let transcript = request.transcript;
+    let language = request.dictation_language;
+    let language_name = language_display_name(language);
+    let output_name = language_display_name(request.translation_target().unwrap_or(language));
+    let style = style_section(request.tone_template);
+    let selected_text = request
+        .context
+        .and_then(|context| context.selected_text.as_deref())
+        .filter(|text| !text.trim().is_empty());
+
+    if let (Some(context), Some(selected_text)) = (request.context, selected_text) {
+        let before = context.preceding_text.as_deref().unwrap_or("");
+        let after = context.following_text.as_deref().unwrap_or("");
+        return format!(
+            "You are a dictation assistant. Output ONLY the text that should replace the user's selected text.
+
+INPUTS:
+- Text before (immediately preceding selection): \"{before}\"
+- Text after (immediately following selection): \"{after}\"
+- Selected text (being replaced): \"{selected_text}\"
+- User dictation: \"{transcript}\"
+
+TASK: Rewrite the user dictation so it fits seamlessly between \"Text before\" and \"Text after\".
+{style}
+{FORMATTING_RULES}
+RULES (must follow):
+1. Use only the user's dictation words. Do not add new words or reintroduce words from the selected text unless they also appear in the dictation.
+2. Remove only speech disfluencies (e.g., \"um\", \"uh\", stutters, false starts). Keep all meaningful words.
+3. Boundary deduplication:
+   - If the last 1-6 words of your output would duplicate the first 1-6 words of \"Text after\", remove those duplicated words from your output.
+   - If the first 1-6 words of your output would duplicate the last 1-6 words of \"Text before\", remove those duplicated words from your output.
+4. Casing:
+   - If \"Text before\" ends with a sentence boundary (. ? !) or is empty, start with a capital letter.
+   - Otherwise, start with lowercase (unless the first word is a proper noun or \"I\").
+5. Punctuation:
+   - Do not end with punctuation that makes the combined text ungrammatical.
+   - Use a comma if \"Text after\" continues the same sentence; use a period/question mark only if appropriate.
+6. Output must be plain text with no quotes, labels, or extra commentary.
+7. {SYMBOL_RULE}{}
+
+Your response MUST be in {output_name}.{} Return only the replacement text.",
+            step_marker_rule(transcript, "8. "),
+            language_rule(request, "DO NOT translate to English or any other language."),
+        );
+    }
+
+    if let Some(context) = request.context {
+        let before = match context.preceding_text.as_deref() {
+            Some(text) if !text.is_empty() => format!("Text before cursor: \"{text}\""),
+            _ => "Start of document".to_string(),
+        };
+        let after = match context.following_text.as_deref() {
+            Some(text) if !text.is_empty() => format!("Text after cursor: \"{text}\""),
+            _ => "End of document".to_string(),
+        };
+        return format!(
+            "You are cleaning dictated text that will be inserted into an existing document.
+
+SURROUNDING CONTEXT (for capitalization reference only):
+{before}
+{after}
+
+TRANSCRIPT TO CLEAN:
+{transcript}
+{style}
+{FORMATTING_RULES}
+INSTRUCTIONS:
+1. Remove filler words (um, uh, like, you know, so, basically, actually, I mean) and speech disfluencies (stutters, false starts, repeated words)
+2. Preserve all meaningful content from the transcript
+3. Adjust capitalization based on whether text before cursor ends with sentence-ending punctuation
+4. Apply the formatting rules above to detect and format emails and lists
+5. {SYMBOL_RULE}{}
+
+CRITICAL: Your output must contain ONLY the cleaned transcript. Never include the \"text before cursor\" or \"text after cursor\" in your output. Those are provided solely for capitalization context.
+
+Return ONLY the cleaned transcript in {output_name}.{}",
+            step_marker_rule(transcript, "6. "),
+            language_rule(request, "Do not translate to English."),
+        );
+    }
+
+    format!(
+        "Clean and format the {language_name} transcript below.
+{style}
+{FORMATTING_RULES}
+CLEANING RULES:
+- Remove filler words (um, uh, like, you know, so, basically, actually, I mean) and speech disfluencies (stutters, false starts, repeated words)
+- Preserve all meaningful content
+- Apply the formatting rules above to detect and format emails and lists
+- {SYMBOL_RULE}{}
+
+Here is the transcript:
+-------
+{transcript}
+-------
+
+Your response MUST be in {output_name}.{}",
+        step_marker_rule(transcript, "- "),
+        language_rule(request, "Do not translate to English."),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::{Arc, Mutex},
+    };
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 1024];
+        let header_end = loop {
+            let read = stream.read(&mut chunk).unwrap();
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(index) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break index + 4;
+            }
+        };
+        let headers = String::from_utf8_lossy(&buffer[..header_end]).to_ascii_lowercase();
+        let length: usize = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map(|value| value.trim().parse().unwrap())
+            .unwrap_or(0);
+        while buffer.len() < header_end + length {
+            let read = stream.read(&mut chunk).unwrap();
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        String::from_utf8_lossy(&buffer[header_end..]).into_owned()
+    }
+
+    fn http(status: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/x-ndjson\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    /// Serves one canned response per connection and records request bodies.
+    fn mock_server(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let body = read_request(&mut stream);
+                seen.lock().unwrap().push(body);
+                if response.is_empty() {
+                    thread::sleep(Duration::from_millis(600));
+                    continue;
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn fast_settings() -> OllamaSettings {
+        OllamaSettings {
+            connect_timeout: Duration::from_millis(500),
+            read_timeout: Duration::from_millis(200),
+            total_timeout: Duration::from_secs(5),
+            max_attempts: 3,
+            retry_delay: Duration::from_millis(10),
+        }
+    }
+
+    fn request(transcript: &str) -> PostProcessRequest<'_> {
+        PostProcessRequest {
+            transcript,
+            dictation_language: "en",
+            tone_template: Some("Be brief."),
+            context: None,
+            target_language: None,
+        }
+    }
+
+    const STREAMED_REPLY: &str = concat!(
+        "{\"message\":{\"role\":\"assistant\",\"content\":\"{\\\"processedTranscription\\\":\"},\"done\":false}\n",
+        "{\"message\":{\"role\":\"assistant\",\"content\":\" \\\"Hello there.\\\"}\"},\"done\":false}\n",
+        "{\"message\":{\"role\":\"assistant\",\"content\":\"\"},\"done\":true}\n",
+    );
+
+    #[test]
+    fn context_window_comes_from_the_model_details() {
+        let reply = r#"{"parameters":"stop \"<|eot_id|>\"\nnum_ctx 16384","model_info":{"general.architecture":"llama","llama.context_length":131072}}"#;
+        let (url, requests) = mock_server(vec![http("200 OK", reply)]);
+        let client = OllamaClient::new(&url, "llama3.1", None, fast_settings()).unwrap();
+
+        assert_eq!(client.context_window().unwrap(), 16_384);
+        let body: Value = serde_json::from_str(&requests.lock().unwrap()[0]).unwrap();
+        assert_eq!(body["model"], "llama3.1");
+
+        let details: ModelDetails =
+            serde_json::from_str(r#"{"model_info":{"phi3.context_length":2048}}"#).unwrap();
+        assert_eq!(details.context_window(), 2_048);
+        let details: ModelDetails = serde_json::from_str("{}").unwrap();
+        assert_eq!(details.context_window(), OLLAMA_DEFAULT_NUM_CTX);
+    }
+
+    #[test]
+    fn streamed_reply_is_assembled_and_parsed() {
+        let (url, requests) = mock_server(vec![http("200 OK", STREAMED_REPLY)]);
+        let client =
+            OllamaClient::new(&format!("{url}/v1/"), "llama3", None, fast_settings()).unwrap();
+
+        let outcome = post_process(&client, &request("um hello there"));
+
+        assert_eq!(outcome.transcript, "Hello there.");
+        assert!(outcome.warnings.is_empty(), "{:?}", outcome.warnings);
+        assert!(outcome.postprocess_duration_ms.is_some());
+        let body: Value = serde_json::from_str(&requests.lock().unwrap()[0]).unwrap();
+        assert_eq!(body["model"], "llama3");
+        assert_eq!(body["stream"], true);
+        assert!(body["messages"][1]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Be brief."));
+    }
+
+    #[test]
+    fn translating_rewrite_reports_its_target_language() {
+        let (url, requests) = mock_server(vec![http("200 OK", STREAMED_REPLY)]);
+        let client = OllamaClient::new(&url, "llama3", None, fast_settings()).unwrap();
+
+        let outcome = post_process(
+            &client,
+            &PostProcessRequest {
+                target_language: Some("de"),
+                ..request("hello there")
+            },
+        );
+
+        assert_eq!(outcome.target_language.as_deref(), Some("de"));
+        let body: Value = serde_json::from_str(&requests.lock().unwrap()[0]).unwrap();
+        assert!(body["messages"][1]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Translate the dictation from English into German"));
+
+        let (url, _) = mock_server(vec![http("500 Internal Server Error", "")]);
+        let client = OllamaClient::new(
+            &url,
+            "llama3",
+            None,
+            OllamaSettings {
+                max_attempts: 1,
+                ..fast_settings()
+            },
+        )
+        .unwrap();
+        let failed = post_process(
+            &client,
+            &PostProcessRequest {
+                target_language: Some("de"),
+                ..request("hello there")
+            },
+        );
+        assert_eq!(failed.transcript, "hello there");
+        assert_eq!(failed.target_language, None);
+    }
+
+    #[test]
+    fn server_errors_are_retried() {
+        let (url, requests) = mock_server(vec![
+            http("503 Service Unavailable", "loading model"),
+            http("200 OK", STREAMED_REPLY),
+        ]);
+        let client = OllamaClient::new(&url, "llama3", None, fast_settings()).unwrap();
+
+        let outcome = post_process(&client, &request("hello there"));
+
+        assert_eq!(outcome.transcript, "Hello there.");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert_eq!(outcome.warnings.len(), 1);
+    }
+
+    #[test]
+    fn failures_keep_the_transcript_and_warn() {
+        let (url, requests) = mock_server(vec![http("404 Not Found", "model not found")]);
+        let client = OllamaClient::new(&url, "missing", None, fast_settings()).unwrap();
+
+        let outcome = post_process(&client, &request("hello there"));
+
+        assert_eq!(outcome.transcript, "hello there");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert!(
+            outcome.warnings[0].contains("404"),
+            "{:?}",
+            outcome.warnings
+        );
+
+        let (url, _) = mock_server(vec![String::new()]);
+        let settings = OllamaSettings {
+            max_attempts: 1,
+            ..fast_settings()
+        };
+        let client = OllamaClient::new(&url, "llama3", None, settings).unwrap();
+        let outcome = post_process(&client, &request("hello there"));
+        assert_eq!(outcome.transcript, "hello there");
+        assert!(
+            outcome.warnings[0].contains("did not respond"),
+            "{:?}",
+            outcome.warnings
+        );
+    }
+
+    #[test]
+    fn trickling_stream_hits_the_total_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
+            let _ = stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nTransfer-Encoding: chunked\r\n\r\n",
+            );
+            let line = "{\"message\":{\"role\":\"assistant\",\"content\":\"a\"},\"done\":false}\n";
+            // Each chunk arrives well inside the read timeout.
+            for _ in 0..50 {
+                let chunk = format!("{:x}\r\n{line}\r\n", line.len());
+                if stream.write_all(chunk.as_bytes()).is_err() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+        });
+        let settings = OllamaSettings {
+            total_timeout: Duration::from_millis(400),
+            max_attempts: 1,
+            ..fast_settings()
+        };
+        let client = OllamaClient::new(&url, "llama3", None, settings).unwrap();
+
+        let started = Instant::now();
+        let outcome = post_process(&client, &request("hello there"));
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(outcome.transcript, "hello there");
+        assert!(
+            outcome.warnings[0].contains("did not respond"),
+            "{:?}",
+            outcome.warnings
+        );
+    }
+
+    #[test]
+    fn prompt_follows_tone_and_context() {
+        let plain = build_post_process_prompt(&PostProcessRequest {
+            transcript: "hola",
+            dictation_language: "es-MX",
+            tone_template: None,
+            context: None,
+            target_language: None,
+        });
+        assert!(plain.starts_with("Clean and format the Spanish transcript below."));
+        assert!(plain.contains("Do not modify the style"));
+        assert!(plain.ends_with("Do not translate to English."));
+
+        let context = TextFieldContext {
+            preceding_text: Some("Dear Sam,".to_string()),
+            selected_text: Some("old words".to_string()),
+            following_text: None,
+        };
+        let selection = build_post_process_prompt(&PostProcessRequest {
+            transcript: "new words",
+            dictation_language: "en",
+            tone_template: Some("Formal."),
+            context: Some(&context),
+            target_language: Some("en-US"),
+        });
+        assert!(selection.contains("Selected text (being replaced): \"old words\""));
+        assert!(selection.contains("```\nFormal.\n```"));
+        assert!(selection
+            .ends_with("Your response MUST be in English. Return only the replacement text."));
+
+        let translation = PostProcessRequest {
+            target_language: Some("fr"),
+            ..request("hello")
+        };
+        assert_eq!(translation.translation_target(), Some("fr"));
+        let translated = build_post_process_prompt(&translation);
+        assert!(translated.starts_with("Clean and format the English transcript below."));
+        assert!(translated.contains(
+            "Your response MUST be in French. Translate the dictation from English into French"
+        ));
+
+        let same_language = PostProcessRequest {
+            dictation_language: "fr-CA",
+            target_language: Some("fr"),
+            ..request("bonjour")
+        };
+        assert_eq!(same_language.translation_target(), None);
+        assert!(build_post_process_prompt(&same_language).ends_with("Do not translate to English."));
+    }
+
+    #[test]
+    fn step_markers_are_kept_in_the_prompt_and_stripped_after() {
+        assert!(!build_post_process_prompt(&request("hello there")).contains("[[1]]"));
+        let marked = build_post_process_prompt(&request("Dear team, [[1]] thanks [[2]] bye"));
+        assert!(marked.contains("- The transcript contains markers like [[1]]"));
+
+        assert_eq!(
+            strip_step_markers("Dear team,[[1]]Thanks for coming. [[2]]  Bye"),
+            "Dear team, Thanks for coming. Bye"
+        );
+        assert_eq!(strip_step_markers("[[1]] Hello"), "Hello");
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::SqlitePool;
+
+use crate::db::retention_queries;
+use crate::domain::{
+    RetentionAction, RetentionItem, RetentionPolicy, RetentionReason, RetentionRecord,
+    RetentionReport, RetentionTarget,
+};
+
+const FIRST_RUN_DELAY: Duration = Duration::from_secs(5 * 60);
+const RUN_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Marks a cleanup as running until dropped, so a failed or cancelled run
+/// never leaves the flag set.
+struct RunGuard;
+
+impl RunGuard {
+    fn acquire() -> Option<Self> {
+        (!RUNNING.swap(true, Ordering::SeqCst)).then_some(Self)
+    }
+}
+
+impl Drop for RunGuard {
+    fn drop(&mut self) {
+        RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Picks the records a policy removes. `records` must be newest first so the
+/// storage limit keeps the most recent recordings.
+pub fn plan(
+    policy: &RetentionPolicy,
+    records: &[RetentionRecord],
+    now: DateTime<Utc>,
+) -> Vec<RetentionItem> {
+    if !policy.enabled {
+        return Vec::new();
+    }
+    let audio_only = policy.action == RetentionAction::Audio;
+    if audio_only && !policy.target.has_audio() {
+        return Vec::new();
+    }
+
+    let cutoff = policy
+        .max_age_days
+        .map(|days| now - chrono::Duration::days(i64::from(days)));
+    let mut stored_bytes: u64 = 0;
+    let mut items = Vec::new();
+
+    for record in records {
+        // Starred recordings still count toward the storage limit, so older
+        // unstarred ones make room for them.
+        stored_bytes = stored_bytes.saturating_add(record.audio_bytes);
+        if policy.keep_starred && record.starred {
+            continue;
+        }
+        if audio_only && record.audio_path.is_none() {
+            continue;
+        }
+
+        let expired = cutoff.is_some_and(|cutoff| {
+            DateTime::parse_from_rfc3339(&record.created_at)
+                .is_ok_and(|created_at| created_at.with_timezone(&Utc) < cutoff)
+        });
+        let over_quota = record.audio_bytes > 0
+            && policy
+                .max_audio_bytes
+                .is_some_and(|limit| stored_bytes > limit);
+        let reason = if expired {
+            RetentionReason::Age
+        } else if over_quota {
+            RetentionReason::Storage
+        } else {
+            continue;
+        };
+
+        items.push(RetentionItem {
+            target: policy.target,
+            id: record.id.clone(),
+            label: record.label.clone(),
+            created_at: record.created_at.clone(),
+            reason,
+            action: policy.action,
+            audio_bytes: record.audio_bytes,
+        });
+    }
+
+    items
+}
+
+/// Applies every enabled policy, or only reports what would be removed when
+/// `dry_run` is set. Recordings are deleted through the audio stores' guards,
+/// and a record is only forgotten once its file is gone.
+pub async fn run(
+    app: &tauri::AppHandle,
+    pool: SqlitePool,
+    dry_run: bool,
+) -> Result<RetentionReport, String> {
+    let _guard = if dry_run {
+        None
+    } else {
+        Some(
+            RunGuard::acquire()
+                .ok_or_else(|| "Retention cleanup is already running".to_string())?,
+        )
+    };
+    run_policies(app, pool, dry_run).await
+}
+
+async fn run_policies(
+    app: &tauri::AppHandle,
+    pool: SqlitePool,
+    dry_run: bool,
+) -> Result<RetentionReport, String> {
+    let policies = retention_queries::fetch_retention_policies(pool.clone())
+        .await
+        .map_err(|err| err.to_string())?;
+    let now = Utc::now();
+    let generated_at = now.to_rfc3339_opts(SecondsFormat::Millis, true);
+
+    let mut report = RetentionReport {
+        dry_run,
+        generated_at: generated_at.clone(),
+        items: Vec::new(),
+        audio_files: 0,
+        audio_bytes: 0,
+        records_deleted: 0,
+    };
+
+    for policy in policies.iter().filter(|policy| policy.enabled) {
+        let records = load_records(pool.clone(), policy.target).await?;
+        let mut items = plan(policy, &records, now);
+
+        if !dry_run {
+            let removed = remove_audio(app, policy.target, &records, &items).await?;
+            items.retain(|item| removed.contains(&item.id));
+            let ids: Vec<String> = items.iter().map(|item| item.id.clone()).collect();
+            match policy.action {
+                RetentionAction::Audio => {
+                    retention_queries::clear_retention_audio(pool.clone(), policy.target, &ids)
+                        .await
+                }
+                RetentionAction::All => {
+                    retention_queries::delete_retention_records(pool.clone(), policy.target, &ids)
+                        .await
+                }
+            }
+            .map_err(|err| err.to_string())?;
+            retention_queries::mark_retention_run(pool.clone(), policy.target, &generated_at)
+                .await
+                .map_err(|err| err.to_string())?;
+        }
+
+        for item in &items {
+            if records
+                .iter()
+                .any(|record| record.id == item.id && record.audio_path.is_some())
+            {
+                report.audio_files += 1;
+                report.audio_bytes = report.audio_bytes.saturating_add(item.audio_bytes);
+            }
+            if item.action == RetentionAction::All {
+                report.records_deleted += 1;
+            }
+        }
+        report.items.extend(items);
+    }
+
+    Ok(report)
+}
+
+async fn load_records(
+    pool: SqlitePool,
+    target: RetentionTarget,
+) -> Result<Vec<RetentionRecord>, String> {
+    let mut records = retention_queries::fetch_retention_records(pool, target)
+        .await
+        .map_err(|err| err.to_string())?;
+    if !target.has_audio() {
+        return Ok(records);
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        for record in &mut records {
+            record.audio_bytes = record
+                .audio_path
+                .as_deref()
+                .and_then(|path| std::fs::metadata(path).ok())
+                .map(|metadata| metadata.len())
+                .unwrap_or_default();
+        }
+        records
+    })
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Deletes the recordings behind `items` and returns the ids whose audio is
+/// gone, including records that had none to begin with.
+async fn remove_audio(
+    app: &tauri::AppHandle,
+    target: RetentionTarget,
+    records: &[RetentionRecord],
+    items: &[RetentionItem],
+) -> Result<HashSet<String>, String> {
+    let planned: HashSet<&str> = items.iter().map(|item| item.id.as_str()).collect();
+    let entries: Vec<(String, Option<String>)> = records
+        .iter()
+        .filter(|record| planned.contains(record.id.as_str()))
+        .map(|record| (record.id.clone(), record.audio_path.clone()))
+        .collect();
+
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut removed = HashSet::new();
+        for (id, audio_path) in entries {
+            let Some(audio_path) = audio_path else {
+                removed.insert(id);
+                continue;
+            };
+            let file_path = PathBuf::from(&audio_path);
+            let result = match target {
+                RetentionTarget::Transcription => {
+                    crate::system::audio_store::delete_audio_file(&app, &file_path)
+                }
+                RetentionTarget::Meeting => {
+                    crate::system::meeting_audio_store::delete_meeting_audio_file(&app, &file_path)
+                }
+                RetentionTarget::Conversation => Ok(()),
+            };
+            match result {
+                Ok(()) => {
+                    removed.insert(id);
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    removed.insert(id);
+                }
+                Err(err) => {
+                    eprintln!("[retention] Failed to delete audio for {id}: {err}");
+                }
+            }
+        }
+        removed
+    })
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Enforces retention policies shortly after launch and then periodically.
+/// Dropping the scheduler stops it, waiting for a cleanup in progress.
+pub struct RetentionScheduler {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RetentionScheduler {
+    pub fn start(app: tauri::AppHandle, pool: SqlitePool) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            let mut delay = FIRST_RUN_DELAY;
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(delay) {
+                match tauri::async_runtime::block_on(run(&app, pool.clone(), false)) {
+                    Ok(report) if !report.items.is_empty() => eprintln!(
+                        "[retention] Removed {} audio files ({} bytes) and {} records",
+                        report.audio_files, report.audio_bytes, report.records_deleted
+                    ),
+                    Ok(_) => {}
+                    Err(err) => eprintln!("[retention] Cleanup failed: {err}"),
+                }
+                delay = RUN_INTERVAL;
+            }
+        });
+
+        Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for RetentionScheduler {
+    fn drop(&mut self) {
+        // Dropping the sender wakes the thread as well as sending would.
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(action: RetentionAction) -> RetentionPolicy {
+        RetentionPolicy {
+            target: RetentionTarget::Transcription,
+            enabled: true,
+            max_age_days: None,
+            max_audio_bytes: None,
+            keep_starred: true,
+            action,
+            last_run_at: None,
+        }
+    }
+
+    fn record(id: &str, days_ago: i64, starred: bool, audio_bytes: u64) -> RetentionRecord {
+        let created_at = Utc::now() - chrono::Duration::days(days_ago);
+        RetentionRecord {
+            id: id.to_string(),
+            label: id.to_string(),
+            created_at: created_at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            starred,
+            audio_path: (audio_bytes > 0).then(|| format!("/audio/{id}.wav")),
+            audio_bytes,
+        }
+    }
+
+    fn ids(items: &[RetentionItem]) -> Vec<&str> {
+        items.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn removes_records_older_than_the_age_limit() {
+        let records = [
+            record("new", 1, false, 10),
+            record("old", 40, false, 10),
+            record("starred", 40, true, 10),
+            record("text-only", 40, false, 0),
+        ];
+        let mut rule = policy(RetentionAction::Audio);
+        rule.max_age_days = Some(30);
+        assert_eq!(ids(&plan(&rule, &records, Utc::now())), ["old"]);
+
+        rule.action = RetentionAction::All;
+        assert_eq!(
+            ids(&plan(&rule, &records, Utc::now())),
+            ["old", "text-only"]
+        );
+
+        rule.keep_starred = false;
+        assert_eq!(
+            ids(&plan(&rule, &records, Utc::now())),
+            ["old", "starred", "text-only"]
+        );
+    }
+
+    #[test]
+    fn keeps_the_newest_recordings_within_the_storage_limit() {
+        let records = [
+            record("a", 1, false, 40),
+            record("b", 2, true, 40),
+            record("c", 3, false, 40),
+            record("d", 4, false, 40),
+        ];
+        let mut rule = policy(RetentionAction::Audio);
+        rule.max_audio_bytes = Some(100);
+        let items = plan(&rule, &records, Utc::now());
+        assert_eq!(ids(&items), ["c", "d"]);
+        assert!(items
+            .iter()
+            .all(|item| item.reason == RetentionReason::Storage));
+    }
+
+    #[test]
+    fn disabled_policies_remove_nothing() {
+        let mut rule = policy(RetentionAction::All);
+        rule.enabled = false;
+        rule.max_age_days = Some(0);
+        assert!(plan(&rule, &[record("a", 10, false, 0)], Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn run_flag_is_released_when_the_guard_drops() {
+        let guard = RunGuard::acquire().expect("first run");
+        assert!(RunGuard::acquire().is_none());
+        drop(guard);
+
+        let result = std::panic::catch_unwind(|| {
+            let _guard = RunGuard::acquire().expect("after release");
+            panic!("cleanup failed");
+        });
+        assert!(result.is_err());
+        assert!(RunGuard::acquire().is_some());
+    }
+}
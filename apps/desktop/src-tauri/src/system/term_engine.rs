@@ -0,0 +1,625 @@
+//! Dictionary handling for local transcription.
+//!
+//! Non-replacement terms become a plain word list in the Whisper initial
+//! prompt, bounded by Whisper's prompt token budget. The prompt is decoded as
+//! if it had been said just before the recording, so it carries no
+//! instructions that could be echoed into the transcript. Replacement terms are
+//! applied to the transcript afterwards as whole-word phrase matches that
+//! follow the spoken casing and carry plural and possessive endings across.
+
+use sqlx::SqlitePool;
+
+use crate::domain::Term;
+
+/// Whisper keeps only the last 224 prompt tokens and silently drops the
+/// rest from the front, which would lose the preamble.
+pub const MAX_INITIAL_PROMPT_TOKENS: usize = 224;
+
+const GLOSSARY_ANCHOR: &str = "OS Voice";
+const GLOSSARY_SEPARATOR: &str = ", ";
+const PLURAL_SUFFIXES: [&str; 2] = ["es", "s"];
+const POSSESSIVE_SUFFIXES: [&str; 2] = ["'s", "\u{2019}s"];
+/// Short stems like "hi" would otherwise turn "his" into a plural match.
+const MIN_PLURAL_STEM_CHARS: usize = 3;
+
+/// Prompt prefix that steers Whisper toward the right Chinese script.
+pub fn locale_prompt_preamble(locale: &str) -> Option<&'static str> {
+    match locale {
+        "zh-CN" => Some("以下是普通话的句子。"),
+        "zh-TW" | "zh-HK" => Some("以下是普通話的句子。"),
+        _ => None,
+    }
+}
+
+/// Rough Whisper token count for when the model's tokenizer is not at hand.
+/// Errs high: a token per three ASCII characters and per two other bytes.
+pub fn estimate_tokens(text: &str) -> usize {
+    let ascii = text.bytes().filter(u8::is_ascii).count();
+    ascii.div_ceil(3) + (text.len() - ascii).div_ceil(2)
+}
+
+/// Strip control characters from a caller-supplied prompt, then cut it on a
+/// word boundary to `MAX_INITIAL_PROMPT_TOKENS` as counted by `count_tokens`.
+pub fn bound_initial_prompt(prompt: &str, count_tokens: &dyn Fn(&str) -> usize) -> Option<String> {
+    let cleaned: String = prompt
+        .chars()
+        .filter(|ch| *ch == '\n' || !ch.is_control())
+        .collect();
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() {
+        return None;
+    }
+
+    if count_tokens(cleaned) <= MAX_INITIAL_PROMPT_TOKENS {
+        return Some(cleaned.to_string());
+    }
+
+    // Find the longest run of whole words that fits.
+    let word_ends: Vec<usize> = cleaned
+        .char_indices()
+        .filter(|(_, ch)| ch.is_whitespace())
+        .map(|(index, _)| index)
+        .collect();
+    let fits = |end: usize| count_tokens(cleaned[..end].trim_end()) <= MAX_INITIAL_PROMPT_TOKENS;
+    let fitting = word_ends.partition_point(|&end| fits(end));
+    let end = *word_ends.get(fitting.checked_sub(1)?)?;
+    Some(cleaned[..end].trim_end().to_string()).filter(|head| !head.is_empty())
+}
+
+fn sanitize_term(value: &str) -> String {
+    value
+        .split(|ch: char| ch.is_whitespace() || ch.is_control())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug)]
+struct ReplacementRule {
+    source: Vec<String>,
+    destination: String,
+    destination_words: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct TermEngine {
+    glossary: Vec<String>,
+    rules: Vec<ReplacementRule>,
+}
+
+impl TermEngine {
+    pub fn new(terms: &[Term]) -> Self {
+        let mut glossary: Vec<String> = Vec::new();
+        let mut rules: Vec<ReplacementRule> = Vec::new();
+
+        for term in terms.iter().filter(|term| !term.is_deleted) {
+            let source = sanitize_term(&term.source_value);
+            if source.is_empty() {
+                continue;
+            }
+
+            if !term.is_replacement {
+                let duplicate = glossary
+                    .iter()
+                    .any(|existing| existing.to_lowercase() == source.to_lowercase());
+                if !duplicate {
+                    glossary.push(source);
+                }
+                continue;
+            }
+
+            let destination = sanitize_term(&term.destination_value);
+            let source_words: Vec<String> = source
+                .split(' ')
+                .map(|word| split_token(word).core.to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect();
+            if destination.is_empty() || source_words.is_empty() {
+                continue;
+            }
+
+            let duplicate = rules.iter().any(|rule| rule.source == source_words);
+            if duplicate {
+                continue;
+            }
+
+            let destination_words = destination
+                .split(' ')
+                .map(|word| split_token(word).core.to_string())
+                .filter(|word| !word.is_empty())
+                .collect();
+            rules.push(ReplacementRule {
+                source: source_words,
+                destination,
+                destination_words,
+            });
+        }
+
+        // Longer phrases first so "new york times" beats "new york".
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.source.len()));
+
+        Self { glossary, rules }
+    }
+
+    pub async fn load(pool: SqlitePool) -> Result<Self, sqlx::Error> {
+        Self::load_subset(pool, None).await
+    }
+
+    /// Load the dictionary, keeping only `term_ids` when an app profile
+    /// restricts it.
+    pub async fn load_subset(
+        pool: SqlitePool,
+        term_ids: Option<&[String]>,
+    ) -> Result<Self, sqlx::Error> {
+        let mut terms = crate::db::term_queries::fetch_terms(pool).await?;
+        if let Some(term_ids) = term_ids {
+            terms.retain(|term| term_ids.contains(&term.id));
+        }
+        Ok(Self::new(&terms))
+    }
+
+    pub fn glossary(&self) -> &[String] {
+        &self.glossary
+    }
+
+    /// Build the Whisper initial prompt: the preamble, then the glossary as a
+    /// comma-separated list. Entries are taken newest first; any entry that
+    /// would push the prompt past `MAX_INITIAL_PROMPT_TOKENS`, as counted by
+    /// `count_tokens`, is left out.
+    pub fn initial_prompt(
+        &self,
+        preamble: Option<&str>,
+        count_tokens: &dyn Fn(&str) -> usize,
+    ) -> String {
+        let mut prompt = String::new();
+        if let Some(preamble) = preamble.map(str::trim).filter(|value| !value.is_empty()) {
+            prompt.push_str(preamble);
+            prompt.push_str("\n\n");
+        }
+        prompt.push_str(GLOSSARY_ANCHOR);
+
+        let mut used = count_tokens(&prompt);
+        for entry in &self.glossary {
+            let addition = format!("{GLOSSARY_SEPARATOR}{entry}");
+            let cost = count_tokens(&addition);
+            if used + cost > MAX_INITIAL_PROMPT_TOKENS {
+                continue;
+            }
+            used += cost;
+            prompt.push_str(&addition);
+        }
+
+        prompt
+    }
+
+    pub fn apply_replacements(&self, text: &str) -> String {
+        if self.rules.is_empty() {
+            return text.to_string();
+        }
+
+        let pieces = split_pieces(text);
+        let words: Vec<usize> = pieces
+            .iter()
+            .enumerate()
+            .filter(|(_, piece)| matches!(piece, Piece::Word(_)))
+            .map(|(index, _)| index)
+            .collect();
+
+        let tokens: Vec<&Token> = words
+            .iter()
+            .filter_map(|index| match &pieces[*index] {
+                Piece::Word(token) => Some(token),
+                Piece::Space(_) => None,
+            })
+            .collect();
+
+        let mut output = String::with_capacity(text.len());
+        let mut cursor = 0;
+        let mut word_index = 0;
+
+        while word_index < words.len() {
+            let piece_index = words[word_index];
+            for piece in &pieces[cursor..piece_index] {
+                output.push_str(piece.as_str());
+            }
+
+            let remaining = &tokens[word_index..];
+            let guarded = self.destination_len_at(remaining);
+            let replacement = self
+                .rules
+                .iter()
+                .find_map(|rule| rule_match(rule, remaining).map(|plural| (rule, plural)));
+
+            match replacement {
+                Some((rule, plural)) if rule.source.len() > guarded => {
+                    let length = rule.source.len();
+                    let first = remaining[0];
+                    let last = remaining[length - 1];
+                    let spoken: Vec<&str> =
+                        remaining[..length].iter().map(|token| token.core).collect();
+
+                    output.push_str(first.leading);
+                    let replaced = match_case(&rule.destination, &spoken);
+                    match plural {
+                        Some(suffix) => output.push_str(&pluralize(&replaced, suffix)),
+                        None => output.push_str(&replaced),
+                    }
+                    output.push_str(last.trailing);
+
+                    word_index += length;
+                }
+                _ => {
+                    let length = guarded.max(1);
+                    let end = words[word_index + length - 1];
+                    for piece in &pieces[piece_index..=end] {
+                        output.push_str(piece.as_str());
+                    }
+                    word_index += length;
+                }
+            }
+            cursor = words[word_index - 1] + 1;
+        }
+
+        for piece in &pieces[cursor..] {
+            output.push_str(piece.as_str());
+        }
+
+        output
+    }
+
+    /// Length of the longest replacement destination already present at the
+    /// start of `tokens`, so running the engine twice changes nothing.
+    fn destination_len_at(&self, tokens: &[&Token]) -> usize {
+        self.rules
+            .iter()
+            .filter(|rule| {
+                let words = &rule.destination_words;
+                !words.is_empty()
+                    && tokens.len() >= words.len()
+                    && phrase_is_contiguous(&tokens[..words.len()])
+                    && tokens
+                        .iter()
+                        .zip(words)
+                        .all(|(token, word)| token.core == word)
+            })
+            .map(|rule| rule.destination_words.len())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+struct Token<'a> {
+    leading: &'a str,
+    core: &'a str,
+    trailing: &'a str,
+    raw: &'a str,
+}
+
+#[derive(Debug)]
+enum Piece<'a> {
+    Space(&'a str),
+    Word(Token<'a>),
+}
+
+impl Piece<'_> {
+    fn as_str(&self) -> &str {
+        match self {
+            Piece::Space(value) => value,
+            Piece::Word(token) => token.raw,
+        }
+    }
+}
+
+fn split_pieces(text: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut in_space: Option<bool> = None;
+
+    for (index, ch) in text.char_indices() {
+        let is_space = ch.is_whitespace();
+        match in_space {
+            Some(current) if current != is_space => {
+                pieces.push(make_piece(&text[start..index], current));
+                start = index;
+            }
+            _ => {}
+        }
+        in_space = Some(is_space);
+    }
+    if let Some(current) = in_space {
+        pieces.push(make_piece(&text[start..], current));
+    }
+
+    pieces
+}
+
+fn make_piece(segment: &str, is_space: bool) -> Piece<'_> {
+    if is_space {
+        Piece::Space(segment)
+    } else {
+        Piece::Word(split_token(segment))
+    }
+}
+
+/// Split a whitespace-free segment into leading punctuation, the word itself
+/// and trailing punctuation. A possessive `'s` counts as trailing.
+fn split_token(segment: &str) -> Token<'_> {
+    let core_start = segment
+        .char_indices()
+        .find(|(_, ch)| ch.is_alphanumeric())
+        .map(|(index, _)| index)
+        .unwrap_or(segment.len());
+    let leading = &segment[..core_start];
+    let rest = &segment[core_start..];
+
+    let mut core_end = rest
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_alphanumeric())
+        .map(|(index, ch)| index + ch.len_utf8())
+        .unwrap_or(0);
+    for suffix in POSSESSIVE_SUFFIXES {
+        let head = &rest[..core_end];
+        let Some(split) = head
+            .len()
+            .checked_sub(suffix.len())
+            .filter(|split| *split > 0)
+        else {
+            continue;
+        };
+        if head.is_char_boundary(split) && head[split..].eq_ignore_ascii_case(suffix) {
+            core_end = split;
+            break;
+        }
+    }
+
+    Token {
+        leading,
+        core: &rest[..core_end],
+        trailing: &rest[core_end..],
+        raw: segment,
+    }
+}
+
+/// Adjacent words only form a phrase when no punctuation separates them.
+fn phrase_is_contiguous(tokens: &[&Token]) -> bool {
+    let last = tokens.len().saturating_sub(1);
+    tokens.iter().enumerate().all(|(position, token)| {
+        (position == 0 || token.leading.is_empty())
+            && (position == last || token.trailing.is_empty())
+    })
+}
+
+/// Match `rule` at the start of `tokens`. On success returns the plural suffix
+/// spoken on the final word, if any.
+fn rule_match<'a>(rule: &ReplacementRule, tokens: &[&'a Token<'a>]) -> Option<Option<&'a str>> {
+    let length = rule.source.len();
+    if tokens.len() < length || !phrase_is_contiguous(&tokens[..length]) {
+        return None;
+    }
+
+    for (token, word) in tokens[..length - 1].iter().zip(&rule.source) {
+        if token.core.to_lowercase() != *word {
+            return None;
+        }
+    }
+
+    let last = tokens[length - 1].core;
+    let expected = &rule.source[length - 1];
+    let lowered = last.to_lowercase();
+    if lowered == *expected {
+        return Some(None);
+    }
+
+    if expected.chars().count() < MIN_PLURAL_STEM_CHARS {
+        return None;
+    }
+
+    for suffix in PLURAL_SUFFIXES {
+        if lowered.len() == expected.len() + suffix.len()
+            && lowered.starts_with(expected.as_str())
+            && lowered.ends_with(suffix)
+            && last.is_char_boundary(last.len() - suffix.len())
+        {
+            return Some(Some(&last[last.len() - suffix.len()..]));
+        }
+    }
+
+    None
+}
+
+/// Pluralize a replaced word, upper-casing the ending when it was spoken in
+/// capitals.
+fn pluralize(word: &str, spoken_suffix: &str) -> String {
+    let lowered = word.to_lowercase();
+    let ending = if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|tail| lowered.ends_with(tail))
+    {
+        "es"
+    } else {
+        "s"
+    };
+
+    if spoken_suffix.chars().all(char::is_uppercase) {
+        format!("{word}{}", ending.to_uppercase())
+    } else {
+        format!("{word}{ending}")
+    }
+}
+
+/// Follow the spoken casing when the destination is written all lowercase;
+/// destinations with deliberate capitals are kept exactly as written.
+fn match_case(destination: &str, spoken: &[&str]) -> String {
+    if destination.chars().any(char::is_uppercase) {
+        return destination.to_string();
+    }
+
+    let letters: Vec<char> = spoken
+        .iter()
+        .flat_map(|word| word.chars())
+        .filter(|ch| ch.is_alphabetic())
+        .collect();
+    if letters.len() > 1 && letters.iter().all(|ch| ch.is_uppercase()) {
+        return destination.to_uppercase();
+    }
+
+    if letters.first().is_some_and(|ch| ch.is_uppercase()) {
+        let mut chars = destination.chars();
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+
+    destination.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(source: &str, destination: &str, is_replacement: bool) -> Term {
+        Term {
+            id: source.to_string(),
+            created_at: 0,
+            created_by_user_id: "local-user-id".to_string(),
+            source_value: source.to_string(),
+            destination_value: destination.to_string(),
+            is_replacement,
+            is_deleted: false,
+        }
+    }
+
+    #[test]
+    fn prompt_lists_glossary_terms_once() {
+        let engine = TermEngine::new(&[
+            term("Kubernetes", "", false),
+            term("kubernetes", "", false),
+            term("  Tauri\n", "", false),
+            term("gpt", "GPT", true),
+            Term {
+                is_deleted: true,
+                ..term("Removed", "", false)
+            },
+        ]);
+
+        assert_eq!(engine.glossary(), ["Kubernetes", "Tauri"]);
+        assert_eq!(
+            engine.initial_prompt(None, &estimate_tokens),
+            "OS Voice, Kubernetes, Tauri"
+        );
+        assert_eq!(
+            engine.initial_prompt(locale_prompt_preamble("zh-CN"), &estimate_tokens),
+            "以下是普通话的句子。\n\nOS Voice, Kubernetes, Tauri"
+        );
+    }
+
+    #[test]
+    fn prompt_stays_within_the_token_budget() {
+        let terms: Vec<Term> = (0..300)
+            .map(|index| term(&format!("ProjectCodename{index}"), "", false))
+            .collect();
+        let engine = TermEngine::new(&terms);
+
+        let prompt = engine.initial_prompt(None, &estimate_tokens);
+        assert!(estimate_tokens(&prompt) <= MAX_INITIAL_PROMPT_TOKENS);
+        assert!(prompt.starts_with("OS Voice, ProjectCodename0,"));
+
+        // With a token per byte, the budget is what binds.
+        let bytes = |text: &str| text.len();
+        let prompt = engine.initial_prompt(None, &bytes);
+        assert!(prompt.len() <= MAX_INITIAL_PROMPT_TOKENS);
+        assert!(prompt.len() > MAX_INITIAL_PROMPT_TOKENS - ", ProjectCodename100".len());
+    }
+
+    #[test]
+    fn caller_prompts_are_bounded_on_word_boundaries() {
+        assert_eq!(bound_initial_prompt(" \0 ", &estimate_tokens), None);
+        assert_eq!(
+            bound_initial_prompt("a\0b", &estimate_tokens),
+            Some("ab".to_string())
+        );
+
+        let long = "word ".repeat(400);
+        let bounded = bound_initial_prompt(&long, &estimate_tokens).unwrap();
+        assert!(estimate_tokens(&bounded) <= MAX_INITIAL_PROMPT_TOKENS);
+        assert!(bounded.ends_with("word"));
+
+        let words = |text: &str| text.split_whitespace().count();
+        let bounded = bound_initial_prompt(&long, &words).unwrap();
+        assert_eq!(words(&bounded), MAX_INITIAL_PROMPT_TOKENS);
+    }
+
+    #[test]
+    fn replacements_respect_word_boundaries_and_case() {
+        let engine = TermEngine::new(&[
+            term("gpt", "GPT", true),
+            term("colour", "color", true),
+            term("get hub", "GitHub", true),
+        ]);
+
+        assert_eq!(
+            engine.apply_replacements("Ask gpt about the colour of GPTs."),
+            "Ask GPT about the color of GPTs."
+        );
+        assert_eq!(
+            engine.apply_replacements("Colour me COLOUR"),
+            "Color me COLOR"
+        );
+        assert_eq!(engine.apply_replacements("egpt gpts"), "egpt GPTs");
+        assert_eq!(
+            engine.apply_replacements("Push to get hub, then get, hub."),
+            "Push to GitHub, then get, hub."
+        );
+    }
+
+    #[test]
+    fn replacements_carry_plural_and_possessive_endings() {
+        let engine = TermEngine::new(&[
+            term("get hub", "GitHub", true),
+            term("clawed", "Claude", true),
+            term("lamb", "llama", true),
+            term("wrench", "spanner", true),
+            term("fax", "telex", true),
+            term("hi", "Hello", true),
+        ]);
+
+        assert_eq!(
+            engine.apply_replacements("(get hubs) and clawed's reply"),
+            "(GitHubs) and Claude's reply"
+        );
+        assert_eq!(engine.apply_replacements("Lambs, LAMBS"), "Llamas, LLAMAS");
+        assert_eq!(
+            engine.apply_replacements("two wrenches and faxes"),
+            "two spanners and telexes"
+        );
+        assert_eq!(
+            engine.apply_replacements("hi to his team"),
+            "Hello to his team"
+        );
+    }
+
+    #[test]
+    fn longest_phrase_wins_and_engine_is_idempotent() {
+        let engine = TermEngine::new(&[
+            term("new york", "NYC", true),
+            term("new york times", "The New York Times", true),
+            term("bob", "Bob Smith", true),
+        ]);
+
+        let once = engine.apply_replacements("I read new york times in new york with bob.");
+        assert_eq!(once, "I read The New York Times in NYC with Bob Smith.");
+        assert_eq!(engine.apply_replacements(&once), once);
+    }
+
+    #[test]
+    fn whitespace_is_preserved() {
+        let engine = TermEngine::new(&[term("gpt", "GPT", true)]);
+        assert_eq!(
+            engine.apply_replacements("  gpt\n\tgpt  "),
+            "  GPT\n\tGPT  "
+        );
+        assert_eq!(engine.apply_replacements(""), "");
+    }
+}
@@ -0,0 +1,428 @@
+//! Learns dictionary terms from the corrections users make to transcripts.
+//!
+//! Each edit is diffed word by word against the text it replaced, and the
+//! words the user typed are traced back to what Whisper heard, since
+//! replacement terms are applied to Whisper's output. Short substitutions are
+//! recorded per transcription, and ones that recur are offered as replacement
+//! terms for the user to accept or reject.
+
+use sqlx::SqlitePool;
+
+use crate::db::term_queries;
+use crate::domain::{
+    Term, TermSuggestion, TermSuggestionReview, TermSuggestionStatus, Transcription,
+};
+
+/// A correction must be made in this many transcriptions before it is offered.
+pub const MIN_OCCURRENCES: i64 = 2;
+
+/// Longer rewrites are edits to phrasing, not misheard words.
+const MAX_PHRASE_WORDS: usize = 3;
+/// Bounds the diff table for very long transcripts.
+const MAX_DIFF_WORDS: usize = 1_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Correction {
+    pub source: String,
+    pub destination: String,
+}
+
+/// Retranscribing replaces the raw transcript too, and finishing a dictation
+/// stored before post-processing fills in its sanitized transcript, so only a
+/// change to the final text alone is a correction by the user.
+pub fn is_user_edit(previous: &Transcription, updated: &Transcription) -> bool {
+    previous.raw_transcript == updated.raw_transcript
+        && previous.sanitized_transcript == updated.sanitized_transcript
+        && previous.transcript.trim() != updated.transcript.trim()
+}
+
+/// Word substitutions that turn `before` into `after`. Insertions, deletions
+/// and rewrites longer than a few words are ignored.
+pub fn corrections(before: &str, after: &str) -> Vec<Correction> {
+    diff(before, after, |a, b| a == b)
+}
+
+fn diff(before: &str, after: &str, same: impl Fn(&str, &str) -> bool) -> Vec<Correction> {
+    let old = words(before);
+    let new = words(after);
+    if old.len() > MAX_DIFF_WORDS || new.len() > MAX_DIFF_WORDS {
+        return Vec::new();
+    }
+
+    // lcs[i][j] is the longest common subsequence of old[i..] and new[j..].
+    let (n, m) = (old.len(), new.len());
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[at(i, j)] = if same(old[i], new[j]) {
+                lcs[at(i + 1, j + 1)] + 1
+            } else {
+                lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+            };
+        }
+    }
+
+    let mut found = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && same(old[i], new[j]) {
+            push_correction(&mut found, &mut removed, &mut added);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[at(i, j + 1)] >= lcs[at(i + 1, j)]) {
+            added.push(new[j]);
+            j += 1;
+        } else {
+            removed.push(old[i]);
+            i += 1;
+        }
+    }
+    push_correction(&mut found, &mut removed, &mut added);
+
+    found.dedup();
+    found
+}
+
+/// Corrections the user made by turning `previous` into `edited`, with each
+/// source taken from `raw`, the Whisper output the transcript started from.
+/// A source post-processing already rewrote is traced back by aligning `raw`
+/// with the edit; corrections that cannot be traced are dropped.
+pub fn user_corrections(raw: &str, previous: &str, edited: &str) -> Vec<Correction> {
+    let raw_words = words(raw);
+    let mut traced = None;
+    let mut found = Vec::new();
+    for typed in corrections(previous, edited) {
+        let source = words(&typed.source);
+        if raw_words
+            .windows(source.len())
+            .any(|window| window == source)
+        {
+            found.push(typed);
+            continue;
+        }
+        let traced = traced.get_or_insert_with(|| diff(raw, edited, same_but_sentence_case));
+        if let Some(index) = traced
+            .iter()
+            .position(|correction| correction.destination == typed.destination)
+        {
+            found.push(traced.swap_remove(index));
+        }
+    }
+    found.dedup();
+    found
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|ch: char| !ch.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn push_correction<'a>(
+    found: &mut Vec<Correction>,
+    removed: &mut Vec<&'a str>,
+    added: &mut Vec<&'a str>,
+) {
+    let phrase = 1..=MAX_PHRASE_WORDS;
+    if phrase.contains(&removed.len()) && phrase.contains(&added.len()) {
+        let source = removed.join(" ");
+        let destination = added.join(" ");
+        if is_term_like(&source, &destination) {
+            found.push(Correction {
+                source,
+                destination,
+            });
+        }
+    }
+    removed.clear();
+    added.clear();
+}
+
+/// Skips sentence-case fixes and number edits, which a term would not help.
+fn is_term_like(source: &str, destination: &str) -> bool {
+    destination.chars().any(char::is_alphabetic) && !same_but_sentence_case(source, destination)
+}
+
+fn same_but_sentence_case(a: &str, b: &str) -> bool {
+    let mut a_chars = a.chars();
+    let mut b_chars = b.chars();
+    a_chars
+        .next()
+        .zip(b_chars.next())
+        .is_some_and(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+        && a_chars.eq(b_chars)
+}
+
+/// Records the corrections in an edited transcription and returns how many
+/// were found.
+pub async fn learn_from_edit(
+    pool: SqlitePool,
+    transcription_id: &str,
+    raw: &str,
+    before: &str,
+    after: &str,
+) -> Result<usize, String> {
+    let found = user_corrections(raw, before, after);
+    for correction in &found {
+        term_queries::record_term_correction(
+            pool.clone(),
+            transcription_id,
+            &correction.source,
+            &correction.destination,
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+    }
+    Ok(found.len())
+}
+
+/// Pending suggestions seen often enough to offer, leaving out corrections
+/// an existing replacement term already makes.
+pub async fn pending_suggestions(pool: SqlitePool) -> Result<Vec<TermSuggestion>, String> {
+    let terms = term_queries::fetch_terms(pool.clone())
+        .await
+        .map_err(|err| err.to_string())?;
+    let suggestions = term_queries::fetch_term_suggestions(pool)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(suggestions
+        .into_iter()
+        .filter(|suggestion| {
+            suggestion.status == TermSuggestionStatus::Pending
+                && suggestion.occurrences >= MIN_OCCURRENCES
+                && !terms.iter().any(|term| {
+                    term.source_value
+                        .eq_ignore_ascii_case(&suggestion.source_value)
+                        && term.destination_value == suggestion.destination_value
+                })
+        })
+        .collect())
+}
+
+async fn fetch_suggestion(pool: SqlitePool, id: &str) -> Result<TermSuggestion, String> {
+    term_queries::fetch_term_suggestions(pool)
+        .await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .find(|suggestion| suggestion.id == id)
+        .ok_or_else(|| format!("Term suggestion {id} not found"))
+}
+
+/// Marks a suggestion accepted or rejected. Accepting adds the replacement
+/// term in the same transaction unless an identical one exists. Rejected
+/// corrections keep being counted but are not offered again.
+pub async fn review(
+    pool: SqlitePool,
+    id: &str,
+    status: TermSuggestionStatus,
+) -> Result<TermSuggestionReview, String> {
+    let mut term = None;
+    let updated = if status == TermSuggestionStatus::Accepted {
+        let suggestion = fetch_suggestion(pool.clone(), id).await?;
+        let terms = term_queries::fetch_terms(pool.clone())
+            .await
+            .map_err(|err| err.to_string())?;
+        let exists = terms.iter().any(|term| {
+            term.is_replacement
+                && term
+                    .source_value
+                    .eq_ignore_ascii_case(&suggestion.source_value)
+                && term.destination_value == suggestion.destination_value
+        });
+        if !exists {
+            term = Some(Term {
+                id: crate::system::ids::new_id(),
+                created_at: chrono::Utc::now().timestamp_millis(),
+                created_by_user_id: String::new(),
+                source_value: suggestion.source_value,
+                destination_value: suggestion.destination_value,
+                is_replacement: true,
+                is_deleted: false,
+            });
+        }
+        term_queries::accept_term_suggestion(pool.clone(), id, term.as_ref()).await
+    } else {
+        term_queries::update_term_suggestion_status(pool.clone(), id, status).await
+    }
+    .map_err(|err| err.to_string())?;
+    if !updated {
+        return Err(format!("Term suggestion {id} not found"));
+    }
+
+    Ok(TermSuggestionReview {
+        suggestion: fetch_suggestion(pool, id).await?,
+        term,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn correction(source: &str, destination: &str) -> Correction {
+        Correction {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_word_substitutions() {
+        assert_eq!(
+            corrections(
+                "Ship the vocal point build to Jon today.",
+                "Ship the VoicePoint build to John today!",
+            ),
+            [
+                correction("vocal point", "VoicePoint"),
+                correction("Jon", "John")
+            ]
+        );
+        assert_eq!(
+            corrections("we use github", "we use GitHub"),
+            [correction("github", "GitHub")]
+        );
+    }
+
+    #[test]
+    fn ignores_rewrites_case_and_number_edits() {
+        assert!(corrections("hello there", "hello there, friend").is_empty());
+        assert!(corrections("the meeting", "The meeting").is_empty());
+        assert!(corrections("call at 3", "call at 4").is_empty());
+        assert!(corrections(
+            "I think we should maybe go",
+            "Honestly I would rather stay home tonight and go"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn only_changes_to_the_final_text_are_user_edits() {
+        let row = |transcript: &str, sanitized: Option<&str>| -> Transcription {
+            serde_json::from_value(serde_json::json!({
+                "id": "t1",
+                "transcript": transcript,
+                "timestamp": 0,
+                "rawTranscript": "vocal point",
+                "sanitizedTranscript": sanitized,
+            }))
+            .expect("valid transcription")
+        };
+
+        let draft = row("vocal point", None);
+        let finished = row("Focal point.", Some("vocal point"));
+        assert!(!is_user_edit(&draft, &finished));
+        assert!(is_user_edit(
+            &finished,
+            &row("Focal points.", Some("vocal point"))
+        ));
+        assert!(!is_user_edit(
+            &finished,
+            &row(" Focal point. ", Some("vocal point"))
+        ));
+    }
+
+    #[test]
+    fn offers_recurring_corrections_until_reviewed() {
+        let pool = crate::db::open_test_pool();
+        let learn = |id: &str, before: &str, after: &str| {
+            tauri::async_runtime::block_on(learn_from_edit(pool.clone(), id, before, before, after))
+                .expect("learn")
+        };
+        let pending =
+            || tauri::async_runtime::block_on(pending_suggestions(pool.clone())).expect("pending");
+
+        learn("t1", "open vocal point", "open VoicePoint");
+        learn("t1", "open vocal point", "open VoicePoint");
+        assert!(pending().is_empty(), "one transcription is not a pattern");
+
+        learn("t2", "Vocal point is down", "VoicePoint is down");
+        learn("t3", "vocal point rocks", "Vocal Point rocks");
+        let suggestions = pending();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].destination_value, "VoicePoint");
+        assert_eq!(suggestions[0].occurrences, 2);
+        assert!((suggestions[0].confidence - 2.0 / 3.0).abs() < 1e-9);
+
+        let reviewed = tauri::async_runtime::block_on(review(
+            pool.clone(),
+            &suggestions[0].id,
+            TermSuggestionStatus::Rejected,
+        ))
+        .expect("review");
+        assert_eq!(reviewed.suggestion.status, TermSuggestionStatus::Rejected);
+        assert!(reviewed.term.is_none());
+        learn("t4", "vocal point", "VoicePoint");
+        assert!(pending().is_empty());
+    }
+
+    #[test]
+    fn traces_user_corrections_back_to_whisper_output() {
+        // Post-processing turned "vocal point" into "focal point"; the user
+        // fixed it, and the term must match what Whisper heard.
+        assert_eq!(
+            user_corrections(
+                "so um open vocal point now",
+                "So, open focal point now.",
+                "So, open VoicePoint now.",
+            ),
+            [correction("vocal point", "VoicePoint")]
+        );
+        // Words post-processing rewrote but the user left alone are not
+        // corrections by the user.
+        assert!(user_corrections(
+            "we shipped it on friday gonna demo",
+            "We shipped it on Friday, going to demo.",
+            "We shipped it on Friday, going to demo!",
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn accepting_a_suggestion_creates_its_term() {
+        let pool = crate::db::open_test_pool();
+        for id in ["t1", "t2"] {
+            tauri::async_runtime::block_on(learn_from_edit(
+                pool.clone(),
+                id,
+                "open vocal point",
+                "Open focal point.",
+                "Open VoicePoint.",
+            ))
+            .expect("learn");
+        }
+        let suggestion = tauri::async_runtime::block_on(pending_suggestions(pool.clone()))
+            .expect("pending")
+            .remove(0);
+        assert_eq!(suggestion.source_value, "vocal point");
+
+        let reviewed = tauri::async_runtime::block_on(review(
+            pool.clone(),
+            &suggestion.id,
+            TermSuggestionStatus::Accepted,
+        ))
+        .expect("review");
+        assert_eq!(reviewed.suggestion.status, TermSuggestionStatus::Accepted);
+        let term = reviewed.term.expect("term");
+        assert_eq!(term.source_value, "vocal point");
+        assert_eq!(term.destination_value, "VoicePoint");
+        assert!(term.is_replacement);
+
+        let terms =
+            tauri::async_runtime::block_on(term_queries::fetch_terms(pool.clone())).expect("terms");
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].id, term.id);
+
+        let missing = tauri::async_runtime::block_on(review(
+            pool.clone(),
+            "nope",
+            TermSuggestionStatus::Accepted,
+        ));
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,553 @@
+//! Sandboxed templating for `Tone.prompt_template`.
+//!
+//! Templates are plain text with `{{ variable }}` placeholders and
+//! `{% if variable %}…{% else %}…{% endif %}` blocks. Placeholders take
+//! `default: "…"`, `upper`, `lower` and `trim` filters. There are no loops,
+//! calls or includes and the output is capped, so a template can only ever
+//! produce text drawn from the dictation context. Templates without any tags
+//! render unchanged, and so do braces that are not template syntax, such as
+//! `{{ "json": true }}` or `{% 50 %}`: a placeholder must name a variable and
+//! a block must start with a word.
+
+use crate::domain::{ToneTemplateContext, ToneTemplateIssue};
+
+pub const TEMPLATE_VARIABLES: &[&str] = &[
+    "app.name",
+    "text.selected",
+    "text.before",
+    "text.after",
+    "user.name",
+    "user.company",
+    "user.title",
+    "date",
+    "language",
+];
+
+const MAX_RENDERED_CHARS: usize = 20_000;
+const MAX_IF_DEPTH: usize = 8;
+
+#[derive(Debug)]
+enum Filter {
+    Default(String),
+    Upper,
+    Lower,
+    Trim,
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Placeholder {
+        variable: String,
+        filters: Vec<Filter>,
+    },
+    If {
+        variable: String,
+        negate: bool,
+        then_branch: Vec<Node>,
+        else_branch: Vec<Node>,
+    },
+}
+
+struct IfFrame {
+    variable: String,
+    negate: bool,
+    offset: usize,
+    then_branch: Option<Vec<Node>>,
+}
+
+#[derive(Debug)]
+pub struct ToneTemplate {
+    nodes: Vec<Node>,
+}
+
+impl ToneTemplate {
+    pub fn parse(source: &str) -> Result<Self, Vec<ToneTemplateIssue>> {
+        let mut issues = Vec::new();
+        let mut frames: Vec<IfFrame> = Vec::new();
+        let mut stack: Vec<Vec<Node>> = vec![Vec::new()];
+        let mut cursor = 0;
+
+        while cursor < source.len() {
+            let rest = &source[cursor..];
+            let Some(relative) = rest.find("{{").into_iter().chain(rest.find("{%")).min() else {
+                push_text(&mut stack, rest);
+                break;
+            };
+            push_text(&mut stack, &rest[..relative]);
+            let start = cursor + relative;
+            let is_placeholder = source[start..].starts_with("{{");
+            let closer = if is_placeholder { "}}" } else { "%}" };
+
+            let opener = &source[start..start + 2];
+            let Some(length) = source[start + 2..].find(closer) else {
+                let line = source[start + 2..].lines().next().unwrap_or("");
+                if is_tag_body(line, is_placeholder) {
+                    issues.push(issue(source, start, format!("`{opener}` is never closed")));
+                    break;
+                }
+                push_text(&mut stack, opener);
+                cursor = start + 2;
+                continue;
+            };
+            let inner_start = start + 2;
+            let inner = &source[inner_start..inner_start + length];
+            if !is_tag_body(inner, is_placeholder) {
+                push_text(&mut stack, opener);
+                cursor = start + 2;
+                continue;
+            }
+            cursor = inner_start + length + 2;
+
+            if is_placeholder {
+                match parse_placeholder(inner, inner_start, source) {
+                    Ok(node) => current(&mut stack).push(node),
+                    Err(err) => issues.push(err),
+                }
+                continue;
+            }
+
+            let words: Vec<&str> = inner.split_whitespace().collect();
+            match words.as_slice() {
+                ["if", rest @ ..] => {
+                    let (negate, variable) = match rest {
+                        ["not", variable] => (true, *variable),
+                        [variable] => (false, *variable),
+                        _ => {
+                            issues.push(issue(
+                                source,
+                                start,
+                                "Expected `{% if variable %}` or `{% if not variable %}`"
+                                    .to_string(),
+                            ));
+                            continue;
+                        }
+                    };
+                    if frames.len() >= MAX_IF_DEPTH {
+                        issues.push(issue(
+                            source,
+                            start,
+                            format!("`if` blocks nest deeper than {MAX_IF_DEPTH} levels"),
+                        ));
+                        continue;
+                    }
+                    if let Err(err) = check_variable(source, inner_start, inner, variable) {
+                        issues.push(err);
+                    }
+                    frames.push(IfFrame {
+                        variable: variable.to_string(),
+                        negate,
+                        offset: start,
+                        then_branch: None,
+                    });
+                    stack.push(Vec::new());
+                }
+                ["else"] => match frames.last_mut() {
+                    Some(frame) if frame.then_branch.is_none() => {
+                        frame.then_branch = Some(stack.pop().unwrap_or_default());
+                        stack.push(Vec::new());
+                    }
+                    Some(_) => issues.push(issue(source, start, "Duplicate `else`".to_string())),
+                    None => issues.push(issue(
+                        source,
+                        start,
+                        "`else` without a matching `if`".to_string(),
+                    )),
+                },
+                ["endif"] => {
+                    let Some(frame) = frames.pop() else {
+                        issues.push(issue(
+                            source,
+                            start,
+                            "`endif` without a matching `if`".to_string(),
+                        ));
+                        continue;
+                    };
+                    let last = stack.pop().unwrap_or_default();
+                    let (then_branch, else_branch) = match frame.then_branch {
+                        Some(then_branch) => (then_branch, last),
+                        None => (last, Vec::new()),
+                    };
+                    current(&mut stack).push(Node::If {
+                        variable: frame.variable,
+                        negate: frame.negate,
+                        then_branch,
+                        else_branch,
+                    });
+                }
+                _ => issues.push(issue(
+                    source,
+                    start,
+                    format!("Unknown tag `{{% {} %}}`", inner.trim()),
+                )),
+            }
+        }
+
+        for frame in frames.iter().rev() {
+            issues.push(issue(
+                source,
+                frame.offset,
+                "`if` block is never closed with `{% endif %}`".to_string(),
+            ));
+        }
+
+        if !issues.is_empty() {
+            issues.sort_by_key(|issue| (issue.line, issue.column));
+            return Err(issues);
+        }
+
+        Ok(Self {
+            nodes: stack.pop().unwrap_or_default(),
+        })
+    }
+
+    pub fn render(&self, context: &ToneTemplateContext) -> String {
+        let mut output = String::new();
+        render_nodes(&self.nodes, context, &mut output);
+        if output.chars().count() > MAX_RENDERED_CHARS {
+            output = output.chars().take(MAX_RENDERED_CHARS).collect();
+        }
+        output
+    }
+}
+
+/// Problems in `source`, empty when the template is valid.
+pub fn validate(source: &str) -> Vec<ToneTemplateIssue> {
+    ToneTemplate::parse(source).err().unwrap_or_default()
+}
+
+pub fn render_template(
+    source: &str,
+    context: &ToneTemplateContext,
+) -> Result<String, Vec<ToneTemplateIssue>> {
+    ToneTemplate::parse(source).map(|template| template.render(context))
+}
+
+/// One-line summary of template issues, used as a command error.
+pub fn describe_issues(issues: &[ToneTemplateIssue]) -> String {
+    issues
+        .iter()
+        .map(|issue| {
+            format!(
+                "Line {}, column {}: {}",
+                issue.line, issue.column, issue.message
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Stand-in values for previewing a tone in the editor. Fields already set
+/// in `overrides` win.
+pub fn sample_context(overrides: ToneTemplateContext) -> ToneTemplateContext {
+    let or = |value: Option<String>, sample: &str| value.or_else(|| Some(sample.to_string()));
+    ToneTemplateContext {
+        app_name: or(overrides.app_name, "Slack"),
+        selected_text: overrides.selected_text,
+        preceding_text: or(overrides.preceding_text, "Hi team,"),
+        following_text: overrides.following_text,
+        user_name: or(overrides.user_name, "Alex Morgan"),
+        user_company: or(overrides.user_company, "Acme Inc."),
+        user_title: or(overrides.user_title, "Product Manager"),
+        date: or(
+            overrides.date,
+            &chrono::Local::now().format("%A, %B %-d, %Y").to_string(),
+        ),
+        language: or(overrides.language, "en"),
+    }
+}
+
+/// Whether the text after an opener is meant as a tag: a placeholder names a
+/// dotted variable before any filters, a block starts with a word.
+fn is_tag_body(body: &str, is_placeholder: bool) -> bool {
+    let head = if is_placeholder {
+        body.split(['|', '}']).next().unwrap_or("").trim()
+    } else {
+        body.split_whitespace().next().unwrap_or("")
+    };
+    !head.is_empty()
+        && head.split('.').all(|part| {
+            part.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+                && part
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        })
+}
+
+fn current(stack: &mut [Vec<Node>]) -> &mut Vec<Node> {
+    stack.last_mut().expect("template stack always has a root")
+}
+
+fn push_text(stack: &mut [Vec<Node>], text: &str) {
+    if !text.is_empty() {
+        current(stack).push(Node::Text(text.to_string()));
+    }
+}
+
+fn issue(source: &str, offset: usize, message: String) -> ToneTemplateIssue {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    ToneTemplateIssue {
+        message,
+        line,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+/// Check `variable` against the known names; `inner_start` and `inner`
+/// locate the tag body so the issue points at the name itself.
+fn check_variable(
+    source: &str,
+    inner_start: usize,
+    inner: &str,
+    variable: &str,
+) -> Result<(), ToneTemplateIssue> {
+    if TEMPLATE_VARIABLES.contains(&variable) {
+        return Ok(());
+    }
+    let offset = inner_start + inner.find(variable).unwrap_or(0);
+    Err(issue(
+        source,
+        offset,
+        format!("Unknown variable `{variable}`"),
+    ))
+}
+
+fn parse_placeholder(
+    inner: &str,
+    inner_start: usize,
+    source: &str,
+) -> Result<Node, ToneTemplateIssue> {
+    let (name_part, mut filters_part) = match inner.find('|') {
+        Some(index) => (&inner[..index], Some(index + 1)),
+        None => (inner, None),
+    };
+    let variable = name_part.trim();
+    if variable.is_empty() {
+        return Err(issue(source, inner_start, "Empty placeholder".to_string()));
+    }
+    check_variable(source, inner_start, inner, variable)?;
+
+    let mut filters = Vec::new();
+    while let Some(position) = filters_part {
+        let segment = &inner[position..];
+        let trimmed = segment.trim_start();
+        let filter_offset = inner_start + position + (segment.len() - trimmed.len());
+        let name_len = trimmed
+            .find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+            .unwrap_or(trimmed.len());
+        let name = &trimmed[..name_len];
+        let mut rest = trimmed[name_len..].trim_start();
+
+        let filter = match name {
+            "upper" => Filter::Upper,
+            "lower" => Filter::Lower,
+            "trim" => Filter::Trim,
+            "default" => {
+                let Some(after_colon) = rest.strip_prefix(':') else {
+                    return Err(issue(
+                        source,
+                        filter_offset,
+                        "Expected `default: \"text\"`".to_string(),
+                    ));
+                };
+                let (value, remainder) =
+                    parse_string(after_colon.trim_start()).ok_or_else(|| {
+                        issue(
+                            source,
+                            filter_offset,
+                            "Expected a quoted string after `default:`".to_string(),
+                        )
+                    })?;
+                rest = remainder.trim_start();
+                Filter::Default(value)
+            }
+            "" => {
+                return Err(issue(
+                    source,
+                    filter_offset,
+                    "Missing filter name".to_string(),
+                ));
+            }
+            other => {
+                return Err(issue(
+                    source,
+                    filter_offset,
+                    format!("Unknown filter `{other}`"),
+                ));
+            }
+        };
+        filters.push(filter);
+
+        filters_part = if rest.is_empty() {
+            None
+        } else if let Some(after_pipe) = rest.strip_prefix('|') {
+            Some(inner.len() - after_pipe.len())
+        } else {
+            let offset = inner_start + inner.len() - rest.len();
+            return Err(issue(
+                source,
+                offset,
+                format!("Unexpected `{}`", rest.trim_end()),
+            ));
+        };
+    }
+
+    Ok(Node::Placeholder {
+        variable: variable.to_string(),
+        filters,
+    })
+}
+
+/// Parse a double-quoted string with `\"` and `\\` escapes, returning the
+/// value and the text after the closing quote.
+fn parse_string(input: &str) -> Option<(String, &str)> {
+    let body = input.strip_prefix('"')?;
+    let mut value = String::new();
+    let mut chars = body.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((value, &body[index + 1..])),
+            '\\' => value.push(chars.next()?.1),
+            _ => value.push(ch),
+        }
+    }
+    None
+}
+
+fn lookup<'a>(context: &'a ToneTemplateContext, variable: &str) -> Option<&'a str> {
+    let value = match variable {
+        "app.name" => &context.app_name,
+        "text.selected" => &context.selected_text,
+        "text.before" => &context.preceding_text,
+        "text.after" => &context.following_text,
+        "user.name" => &context.user_name,
+        "user.company" => &context.user_company,
+        "user.title" => &context.user_title,
+        "date" => &context.date,
+        "language" => &context.language,
+        _ => return None,
+    };
+    value.as_deref().filter(|value| !value.trim().is_empty())
+}
+
+fn render_nodes(nodes: &[Node], context: &ToneTemplateContext, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Placeholder { variable, filters } => {
+                let mut value = lookup(context, variable).unwrap_or("").to_string();
+                for filter in filters {
+                    value = match filter {
+                        Filter::Default(fallback) if value.trim().is_empty() => fallback.clone(),
+                        Filter::Default(_) => value,
+                        Filter::Upper => value.to_uppercase(),
+                        Filter::Lower => value.to_lowercase(),
+                        Filter::Trim => value.trim().to_string(),
+                    };
+                }
+                output.push_str(&value);
+            }
+            Node::If {
+                variable,
+                negate,
+                then_branch,
+                else_branch,
+            } => {
+                let present = lookup(context, variable).is_some();
+                let branch = if present != *negate {
+                    then_branch
+                } else {
+                    else_branch
+                };
+                render_nodes(branch, context, output);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ToneTemplateContext {
+        ToneTemplateContext {
+            app_name: Some("Slack".to_string()),
+            user_name: Some("Sam".to_string()),
+            user_company: None,
+            language: Some("en".to_string()),
+            ..ToneTemplateContext::default()
+        }
+    }
+
+    #[test]
+    fn placeholders_filters_and_conditionals_render() {
+        let template = "Writing in {{ app.name | upper }} as {{user.name}} from \
+            {{ user.company | default: \"a \\\"small\\\" team\" }}.\
+            {% if text.selected %} Replace the selection.{% else %} Insert at the cursor.{% endif %}\
+            {% if not user.title %} No title.{% endif %}";
+
+        assert_eq!(
+            render_template(template, &context()).unwrap(),
+            "Writing in SLACK as Sam from a \"small\" team. Insert at the cursor. No title."
+        );
+    }
+
+    #[test]
+    fn plain_templates_render_unchanged() {
+        let template = "\nMake only surgical corrections {like this}.\n  ";
+        assert!(validate(template).is_empty());
+        assert_eq!(render_template(template, &context()).unwrap(), template);
+    }
+
+    #[test]
+    fn literal_braces_are_kept_as_text() {
+        let template = "Answer as {{ \"reply\": \"...\" }} or {{double braces}}, \
+            keep {% 50 %} and {%%}, then sign as {{ user.name }}. Unclosed {{ 1 + 2";
+        assert!(validate(template).is_empty(), "{:?}", validate(template));
+        assert_eq!(
+            render_template(template, &context()).unwrap(),
+            "Answer as {{ \"reply\": \"...\" }} or {{double braces}}, \
+            keep {% 50 %} and {%%}, then sign as Sam. Unclosed {{ 1 + 2"
+        );
+
+        assert!(validate("{% iff app.name %}")[0]
+            .message
+            .contains("Unknown tag"));
+        assert!(validate("{{ app.nmae }}")[0]
+            .message
+            .contains("Unknown variable"));
+    }
+
+    #[test]
+    fn issues_report_positions() {
+        let issues = validate("Hello {{ user.nmae }}\n{% if app.name %}x {{ date | shout }}");
+        assert_eq!(
+            issues,
+            vec![
+                ToneTemplateIssue {
+                    message: "Unknown variable `user.nmae`".to_string(),
+                    line: 1,
+                    column: 10,
+                },
+                ToneTemplateIssue {
+                    message: "`if` block is never closed with `{% endif %}`".to_string(),
+                    line: 2,
+                    column: 1,
+                },
+                ToneTemplateIssue {
+                    message: "Unknown filter `shout`".to_string(),
+                    line: 2,
+                    column: 30,
+                },
+            ]
+        );
+
+        let unclosed = validate("ok\n  {{ date");
+        assert_eq!((unclosed[0].line, unclosed[0].column), (2, 3));
+        assert!(validate("{% endif %}")[0]
+            .message
+            .contains("without a matching"));
+    }
+}
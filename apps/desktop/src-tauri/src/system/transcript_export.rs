@@ -0,0 +1,485 @@
+//! Renders meetings and single transcriptions as SRT, WebVTT, Markdown,
+//! plain text or JSON. Both sources are turned into a list of timed cues
+//! first, so every format shares the same merging and labelling rules.
+
+use serde::Serialize;
+
+use crate::domain::{
+    Meeting, MeetingActionItem, MeetingDecision, MeetingSegment, TimestampGranularity,
+    TranscriptExport, TranscriptExportFormat, TranscriptExportOptions, Transcription,
+};
+
+const DEFAULT_MERGE_GAP_MS: i64 = 2_000;
+const MAX_FILE_STEM_CHARS: usize = 80;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCue {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDocument {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub action_items: Vec<MeetingActionItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decisions: Vec<String>,
+    pub cues: Vec<ExportCue>,
+}
+
+impl ExportDocument {
+    pub fn from_meeting(
+        meeting: &Meeting,
+        segments: &[MeetingSegment],
+        action_items: &[MeetingActionItem],
+        decisions: &[MeetingDecision],
+    ) -> Self {
+        Self {
+            title: meeting.title.clone(),
+            started_at: Some(meeting.started_at.clone()),
+            duration_ms: meeting.duration_ms,
+            summary: meeting
+                .summary
+                .clone()
+                .filter(|summary| !summary.trim().is_empty()),
+            action_items: action_items.to_vec(),
+            decisions: decisions
+                .iter()
+                .map(|decision| decision.text.trim().to_string())
+                .filter(|text| !text.is_empty())
+                .collect(),
+            cues: segments
+                .iter()
+                .filter(|segment| !segment.text.trim().is_empty())
+                .map(|segment| ExportCue {
+                    start_ms: segment.start_ms,
+                    end_ms: segment.end_ms,
+                    speaker: segment
+                        .speaker_name
+                        .clone()
+                        .or_else(|| segment.speaker_id.clone()),
+                    text: segment.text.trim().to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Dictations are stored without word timings, so the whole transcript
+    /// becomes one cue spanning the saved audio.
+    pub fn from_transcription(transcription: &Transcription) -> Self {
+        let started_at = chrono::DateTime::from_timestamp_millis(transcription.timestamp)
+            .map(|time| time.to_rfc3339());
+        let duration_ms = transcription.audio.as_ref().map(|audio| audio.duration_ms);
+
+        Self {
+            title: started_at
+                .as_deref()
+                .and_then(|time| time.get(..16))
+                .map(|time| format!("Transcription {}", time.replace('T', " ")))
+                .unwrap_or_else(|| "Transcription".to_string()),
+            started_at,
+            duration_ms,
+            summary: None,
+            action_items: Vec::new(),
+            decisions: Vec::new(),
+            cues: vec![ExportCue {
+                start_ms: 0,
+                end_ms: duration_ms.unwrap_or(0),
+                speaker: None,
+                text: transcription.transcript.trim().to_string(),
+            }],
+        }
+    }
+
+    /// Whether every cue has a usable time range for subtitle formats.
+    pub fn has_timings(&self) -> bool {
+        self.cues.iter().all(|cue| cue.end_ms > cue.start_ms)
+    }
+}
+
+pub fn export(
+    document: &ExportDocument,
+    options: &TranscriptExportOptions,
+) -> Result<TranscriptExport, String> {
+    if options.format.is_timed() && !document.has_timings() {
+        return Err("This transcript has no timings to build subtitles from".to_string());
+    }
+
+    Ok(TranscriptExport {
+        file_name: format!(
+            "{}.{}",
+            file_stem(&document.title),
+            options.format.extension()
+        ),
+        mime_type: options.format.mime_type().to_string(),
+        content: render(document, options)?,
+    })
+}
+
+pub fn render(
+    document: &ExportDocument,
+    options: &TranscriptExportOptions,
+) -> Result<String, String> {
+    let cues = prepare_cues(&document.cues, options);
+
+    Ok(match options.format {
+        TranscriptExportFormat::Srt => render_srt(&cues, options),
+        TranscriptExportFormat::Vtt => render_vtt(&cues, options),
+        TranscriptExportFormat::Markdown => render_markdown(document, &cues, options),
+        TranscriptExportFormat::Text => render_text(&cues, options),
+        TranscriptExportFormat::Json => serde_json::to_string_pretty(&ExportDocument {
+            cues,
+            ..document.clone()
+        })
+        .map_err(|err| err.to_string())?,
+    })
+}
+
+fn prepare_cues(cues: &[ExportCue], options: &TranscriptExportOptions) -> Vec<ExportCue> {
+    let mut prepared: Vec<ExportCue> = Vec::with_capacity(cues.len());
+    let gap = options.merge_gap_ms.unwrap_or(DEFAULT_MERGE_GAP_MS).max(0);
+
+    for cue in cues {
+        if options.merge_speakers {
+            if let Some(previous) = prepared.last_mut() {
+                if previous.speaker == cue.speaker && cue.start_ms - previous.end_ms <= gap {
+                    previous.text.push(' ');
+                    previous.text.push_str(&cue.text);
+                    previous.end_ms = previous.end_ms.max(cue.end_ms);
+                    continue;
+                }
+            }
+        }
+        prepared.push(cue.clone());
+    }
+
+    if !options.speaker_labels {
+        for cue in &mut prepared {
+            cue.speaker = None;
+        }
+    }
+    prepared
+}
+
+fn render_srt(cues: &[ExportCue], options: &TranscriptExportOptions) -> String {
+    let mut out = String::new();
+    let timings = cue_timings(cues, options.timestamps);
+    for (index, (cue, (start, end))) in cues.iter().zip(timings).enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n",
+            index + 1,
+            subtitle_time(start, ','),
+            subtitle_time(end, ',')
+        ));
+        match &cue.speaker {
+            Some(speaker) => out.push_str(&format!("{speaker}: {}\n\n", cue.text)),
+            None => out.push_str(&format!("{}\n\n", cue.text)),
+        }
+    }
+    out
+}
+
+fn render_vtt(cues: &[ExportCue], options: &TranscriptExportOptions) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for (cue, (start, end)) in cues.iter().zip(cue_timings(cues, options.timestamps)) {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            subtitle_time(start, '.'),
+            subtitle_time(end, '.')
+        ));
+        match &cue.speaker {
+            Some(speaker) => {
+                out.push_str(&format!("<v {}>{}\n\n", speaker.replace('>', ""), cue.text))
+            }
+            None => out.push_str(&format!("{}\n\n", cue.text)),
+        }
+    }
+    out
+}
+
+fn render_markdown(
+    document: &ExportDocument,
+    cues: &[ExportCue],
+    options: &TranscriptExportOptions,
+) -> String {
+    let mut out = format!("# {}\n\n", document.title);
+
+    let mut details = Vec::new();
+    if let Some(started_at) = &document.started_at {
+        details.push(started_at.clone());
+    }
+    if let Some(duration_ms) = document.duration_ms.filter(|ms| *ms > 0) {
+        details.push(clock(duration_ms, TimestampGranularity::Seconds));
+    }
+    if !details.is_empty() {
+        out.push_str(&format!("_{}_\n\n", details.join(" · ")));
+    }
+
+    if let Some(summary) = &document.summary {
+        out.push_str(&format!("## Summary\n\n{}\n\n", summary.trim()));
+    }
+
+    if !document.decisions.is_empty() {
+        out.push_str("## Decisions\n\n");
+        for decision in &document.decisions {
+            out.push_str(&format!("- {decision}\n"));
+        }
+        out.push('\n');
+    }
+
+    if !document.action_items.is_empty() {
+        out.push_str("## Action items\n\n");
+        for item in &document.action_items {
+            let check = if item.is_done() { "x" } else { " " };
+            out.push_str(&format!("- [{check}] {}", item.task.trim()));
+            if let Some(owner) = item.owner.as_deref().filter(|name| !name.is_empty()) {
+                out.push_str(&format!(" — {owner}"));
+            }
+            if let Some(due_date) = item.due_date.as_deref() {
+                out.push_str(&format!(", due {due_date}"));
+            }
+            if let Some(priority) = item.priority.as_deref().filter(|p| !p.is_empty()) {
+                out.push_str(&format!(" ({priority})"));
+            }
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    out.push_str("## Transcript\n\n");
+    for cue in cues {
+        let mut prefix = Vec::new();
+        if options.timestamps != TimestampGranularity::None {
+            prefix.push(format!("`{}`", clock(cue.start_ms, options.timestamps)));
+        }
+        if let Some(speaker) = &cue.speaker {
+            prefix.push(format!("**{speaker}:**"));
+        }
+        if prefix.is_empty() {
+            out.push_str(&format!("{}\n\n", cue.text));
+        } else {
+            out.push_str(&format!("{} {}\n\n", prefix.join(" "), cue.text));
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+fn render_text(cues: &[ExportCue], options: &TranscriptExportOptions) -> String {
+    cues.iter()
+        .map(|cue| {
+            let mut line = String::new();
+            if options.timestamps != TimestampGranularity::None {
+                line.push_str(&format!("[{}] ", clock(cue.start_ms, options.timestamps)));
+            }
+            if let Some(speaker) = &cue.speaker {
+                line.push_str(&format!("{speaker}: "));
+            }
+            line.push_str(&cue.text);
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Start and end of every cue. Whole-second granularity widens a cue
+/// outward so it never collapses, and each end is then clamped to the next
+/// cue's start so players never show two cues at once.
+fn cue_timings(cues: &[ExportCue], granularity: TimestampGranularity) -> Vec<(i64, i64)> {
+    let mut timings: Vec<_> = cues
+        .iter()
+        .map(|cue| cue_bounds(cue, granularity))
+        .collect();
+    for index in 1..timings.len() {
+        let next_start = timings[index].0;
+        let (start, end) = &mut timings[index - 1];
+        *end = (*end).min(next_start).max(*start);
+    }
+    timings
+}
+
+fn cue_bounds(cue: &ExportCue, granularity: TimestampGranularity) -> (i64, i64) {
+    match granularity {
+        TimestampGranularity::Seconds => {
+            let start = cue.start_ms.max(0) / 1000 * 1000;
+            let end = (cue.end_ms.max(0) + 999) / 1000 * 1000;
+            (start, end.max(start + 1000))
+        }
+        _ => (cue.start_ms.max(0), cue.end_ms.max(0)),
+    }
+}
+
+fn subtitle_time(ms: i64, separator: char) -> String {
+    format!(
+        "{}{separator}{:03}",
+        clock(ms, TimestampGranularity::Seconds),
+        ms.max(0) % 1000
+    )
+}
+
+fn clock(ms: i64, granularity: TimestampGranularity) -> String {
+    let ms = ms.max(0);
+    let seconds = ms / 1000;
+    let base = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match granularity {
+        TimestampGranularity::Milliseconds => format!("{base}.{:03}", ms % 1000),
+        _ => base,
+    }
+}
+
+/// A file name derived from the title, safe on every platform.
+fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || matches!(ch, ' ' | '-' | '_' | '.') {
+                ch
+            } else {
+                '-'
+            }
+        })
+        .take(MAX_FILE_STEM_CHARS)
+        .collect();
+    let stem = stem.trim_matches(|ch: char| ch == '.' || ch.is_whitespace());
+    if stem.is_empty() {
+        "transcript".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(start_ms: i64, end_ms: i64, speaker: &str, text: &str) -> ExportCue {
+        ExportCue {
+            start_ms,
+            end_ms,
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+        }
+    }
+
+    fn document() -> ExportDocument {
+        ExportDocument {
+            title: "Weekly sync".to_string(),
+            started_at: None,
+            duration_ms: None,
+            summary: None,
+            action_items: Vec::new(),
+            decisions: Vec::new(),
+            cues: vec![
+                cue(1_250, 3_000, "Ana", "Hi all."),
+                cue(3_500, 5_100, "Ana", "Let's start."),
+                cue(6_000, 8_000, "Ben", "Sounds good."),
+            ],
+        }
+    }
+
+    fn options(format: TranscriptExportFormat) -> TranscriptExportOptions {
+        TranscriptExportOptions {
+            format,
+            speaker_labels: true,
+            timestamps: TimestampGranularity::Milliseconds,
+            merge_speakers: false,
+            merge_gap_ms: None,
+        }
+    }
+
+    #[test]
+    fn renders_subtitles() {
+        let srt = render(&document(), &options(TranscriptExportFormat::Srt)).unwrap();
+        assert!(srt.starts_with("1\n00:00:01,250 --> 00:00:03,000\nAna: Hi all.\n\n2\n"));
+
+        let vtt = render(&document(), &options(TranscriptExportFormat::Vtt)).unwrap();
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:01.250 --> 00:00:03.000\n<v Ana>Hi all.\n"));
+    }
+
+    #[test]
+    fn clamps_cues_to_the_next_start() {
+        let mut overlapping = document();
+        overlapping.cues[0].end_ms = 4_000;
+
+        let srt = render(&overlapping, &options(TranscriptExportFormat::Srt)).unwrap();
+        assert!(srt.contains("00:00:01,250 --> 00:00:03,500\n"));
+
+        // Rounding 3.5–6.4 s out to whole seconds gives 3–7 s, which would
+        // overlap the cue starting at 6 s.
+        let mut seconds = options(TranscriptExportFormat::Vtt);
+        seconds.timestamps = TimestampGranularity::Seconds;
+        let mut document = document();
+        document.cues[1].end_ms = 6_400;
+        let vtt = render(&document, &seconds).unwrap();
+        assert!(vtt.contains("00:00:01.000 --> 00:00:03.000\n"));
+        assert!(vtt.contains("00:00:03.000 --> 00:00:06.000\n"));
+        assert!(vtt.contains("00:00:06.000 --> 00:00:08.000\n"));
+    }
+
+    #[test]
+    fn merges_same_speaker_and_drops_labels() {
+        let mut merged = options(TranscriptExportFormat::Text);
+        merged.merge_speakers = true;
+        merged.timestamps = TimestampGranularity::Seconds;
+        assert_eq!(
+            render(&document(), &merged).unwrap(),
+            "[00:00:01] Ana: Hi all. Let's start.\n[00:00:06] Ben: Sounds good."
+        );
+
+        merged.speaker_labels = false;
+        merged.timestamps = TimestampGranularity::None;
+        assert_eq!(
+            render(&document(), &merged).unwrap(),
+            "Hi all. Let's start.\nSounds good."
+        );
+    }
+
+    #[test]
+    fn lists_decisions_and_action_items_in_markdown() {
+        let mut with_notes = document();
+        with_notes.decisions = vec!["Ship on Friday".to_string()];
+        with_notes.action_items = vec![MeetingActionItem {
+            id: "a1".to_string(),
+            meeting_id: "m1".to_string(),
+            task: "Write release notes".to_string(),
+            owner: Some("Ben".to_string()),
+            due_date: Some("2024-05-03".to_string()),
+            priority: None,
+            status: crate::domain::ACTION_ITEM_STATUS_DONE.to_string(),
+            source_segment_id: None,
+            position: 0,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }];
+
+        let markdown = render(&with_notes, &options(TranscriptExportFormat::Markdown)).unwrap();
+        assert!(markdown.contains(
+            "## Decisions\n\n- Ship on Friday\n\n## Action items\n\n- [x] Write release notes — Ben, due 2024-05-03\n"
+        ));
+    }
+
+    #[test]
+    fn rejects_subtitles_without_timings() {
+        let mut untimed = document();
+        untimed.cues = vec![cue(0, 0, "Ana", "Hello")];
+        assert!(export(&untimed, &options(TranscriptExportFormat::Srt)).is_err());
+        assert_eq!(file_stem("Q3: plan/review"), "Q3- plan-review");
+    }
+}
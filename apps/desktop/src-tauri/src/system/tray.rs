@@ -3,26 +3,18 @@ const TRAY_ICON_BYTES: &[u8] = include_bytes!(concat!(
     "/icons/tray/menu-item-36.png"
 ));
 
-use crate::domain::EVT_REGISTER_CURRENT_APP;
+use crate::domain::{AmbientStatus, EVT_REGISTER_CURRENT_APP};
+
+/// Dot drawn on the tray icon while ambient listening is armed.
+const AMBIENT_DOT_RGBA: [u8; 4] = [0xE5, 0x39, 0x35, 0xFF];
 
 #[cfg(desktop)]
 pub fn setup_tray(app: &mut tauri::App) -> tauri::Result<()> {
     use tauri::image::Image;
-    use tauri::menu::{MenuBuilder, MenuItem};
     use tauri::tray::TrayIconBuilder;
     use tauri::{Emitter, Manager};
 
-    let open_item = MenuItem::with_id(app, "open-dashboard", "Open Dashboard", true, None::<&str>)?;
-    let register_current_app_item =
-        MenuItem::with_id(app, "register-current-app", "Register this app", true, None::<&str>)?;
-    let quit_item = MenuItem::with_id(app, "quit-osvoice", "Quit OS Voice", true, None::<&str>)?;
-
-    let menu = MenuBuilder::new(app)
-        .item(&open_item)
-        .item(&register_current_app_item)
-        .separator()
-        .item(&quit_item)
-        .build()?;
+    let menu = build_menu(app, false)?;
 
     let tray_icon_image = Image::from_bytes(TRAY_ICON_BYTES)?;
 
@@ -42,6 +34,10 @@ pub fn setup_tray(app: &mut tauri::App) -> tauri::Result<()> {
                     eprintln!("Failed to emit register-current-app event: {err}");
                 }
             }
+            "stop-ambient" => {
+                let status = app.state::<crate::state::AmbientState>().disarm();
+                crate::system::ambient::publish_status(app, &status);
+            }
             "quit-osvoice" => app.exit(0),
             _ => {}
         });
@@ -55,3 +51,95 @@ pub fn setup_tray(app: &mut tauri::App) -> tauri::Result<()> {
 
     Ok(())
 }
+
+#[cfg(desktop)]
+fn build_menu<M: tauri::Manager<tauri::Wry>>(
+    manager: &M,
+    ambient_armed: bool,
+) -> tauri::Result<tauri::menu::Menu<tauri::Wry>> {
+    use tauri::menu::{MenuBuilder, MenuItem};
+
+    let open_item = MenuItem::with_id(
+        manager,
+        "open-dashboard",
+        "Open Dashboard",
+        true,
+        None::<&str>,
+    )?;
+    let register_current_app_item = MenuItem::with_id(
+        manager,
+        "register-current-app",
+        "Register this app",
+        true,
+        None::<&str>,
+    )?;
+    let quit_item =
+        MenuItem::with_id(manager, "quit-osvoice", "Quit OS Voice", true, None::<&str>)?;
+
+    let mut builder = MenuBuilder::new(manager)
+        .item(&open_item)
+        .item(&register_current_app_item);
+    if ambient_armed {
+        let stop_ambient_item = MenuItem::with_id(
+            manager,
+            "stop-ambient",
+            "Stop ambient listening",
+            true,
+            None::<&str>,
+        )?;
+        builder = builder.separator().item(&stop_ambient_item);
+    }
+    builder.separator().item(&quit_item).build()
+}
+
+/// Shows whether ambient listening is armed: a dot on the icon, the wake
+/// phrase in the tooltip and a menu item to stop it.
+#[cfg(desktop)]
+pub fn set_ambient_indicator(app: &tauri::AppHandle, status: &AmbientStatus) -> tauri::Result<()> {
+    use tauri::image::Image;
+    use tauri::tray::TrayIconId;
+
+    let Some(tray) = app.tray_by_id(&TrayIconId::new("main")) else {
+        return Ok(());
+    };
+
+    let icon = Image::from_bytes(TRAY_ICON_BYTES)?;
+    let icon = if status.armed {
+        with_ambient_dot(&icon)
+    } else {
+        icon
+    };
+    tray.set_icon(Some(icon))?;
+    // A template icon is drawn in one colour, which would hide the dot.
+    #[cfg(target_os = "macos")]
+    tray.set_icon_as_template(!status.armed)?;
+
+    let tooltip = match status.wake_phrase.as_deref() {
+        Some(phrase) if status.armed => format!("OS Voice - listening for \"{phrase}\""),
+        _ => "OS Voice".to_string(),
+    };
+    tray.set_tooltip(Some(tooltip))?;
+    tray.set_menu(Some(build_menu(app, status.armed)?))?;
+    Ok(())
+}
+
+#[cfg(desktop)]
+fn with_ambient_dot(icon: &tauri::image::Image<'_>) -> tauri::image::Image<'static> {
+    let (width, height) = (icon.width(), icon.height());
+    let mut rgba = icon.rgba().to_vec();
+    let radius = width.min(height) as f32 / 5.0;
+    let center_x = width as f32 - radius - 1.0;
+    let center_y = height as f32 - radius - 1.0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f32 + 0.5 - center_x;
+            let dy = y as f32 + 0.5 - center_y;
+            if dx * dx + dy * dy <= radius * radius {
+                let offset = ((y * width + x) * 4) as usize;
+                rgba[offset..offset + 4].copy_from_slice(&AMBIENT_DOT_RGBA);
+            }
+        }
+    }
+    tauri::image::Image::new_owned(rgba, width, height)
+}
@@ -0,0 +1,780 @@
+//! Spoken punctuation, editing and navigation commands inside dictation.
+//!
+//! `CommandGrammar::parse` walks the raw transcript word by word and matches
+//! built-in or user-defined phrases, longest first, so "new line" presses
+//! Enter. Saying the keyword "command" first always marks a command, and users
+//! who dictate those phrases as prose can require it, in which case "a new
+//! line of products" stays text. Plain words are collected into text runs
+//! with their original spacing, punctuation commands are glued onto that
+//! text, and editing or navigation commands become key chords for the
+//! platform input modules.
+
+use crate::domain::{
+    DictationStep, EditKey, KeyChord, VoiceCommand, VOICE_COMMAND_ACTION_KEYS,
+    VOICE_COMMAND_ACTION_TEXT,
+};
+
+/// Spoken before a command to mark it as one.
+pub const COMMAND_KEYWORD: &str = "command";
+
+const MAX_CHORD_REPEAT: u32 = 100;
+const SENTENCE_TERMINATORS: &[char] = &['.', '!', '?'];
+const CLAUSE_MARKS: &[char] = &['.', ',', '!', '?', ';', ':'];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum CommandAction {
+    Punctuation {
+        text: &'static str,
+        space_before: bool,
+        space_after: bool,
+    },
+    Insert(String),
+    Keys(KeyChord),
+    LineBreak(u32),
+    ScratchThat,
+    SelectLastSentence,
+}
+
+#[derive(Debug)]
+struct CommandPhrase {
+    words: Vec<String>,
+    action: CommandAction,
+}
+
+fn trailing(text: &'static str) -> CommandAction {
+    CommandAction::Punctuation {
+        text,
+        space_before: false,
+        space_after: true,
+    }
+}
+
+fn opening(text: &'static str) -> CommandAction {
+    CommandAction::Punctuation {
+        text,
+        space_before: true,
+        space_after: false,
+    }
+}
+
+fn joining(text: &'static str) -> CommandAction {
+    CommandAction::Punctuation {
+        text,
+        space_before: false,
+        space_after: false,
+    }
+}
+
+fn builtin_commands() -> Vec<(&'static str, CommandAction)> {
+    use CommandAction::{Keys, LineBreak, ScratchThat, SelectLastSentence};
+
+    vec![
+        ("period", trailing(".")),
+        ("full stop", trailing(".")),
+        ("comma", trailing(",")),
+        ("question mark", trailing("?")),
+        ("exclamation mark", trailing("!")),
+        ("exclamation point", trailing("!")),
+        ("colon", trailing(":")),
+        ("semicolon", trailing(";")),
+        ("open paren", opening("(")),
+        ("open parenthesis", opening("(")),
+        ("close paren", trailing(")")),
+        ("close parenthesis", trailing(")")),
+        ("open quote", opening("\"")),
+        ("close quote", trailing("\"")),
+        ("hyphen", joining("-")),
+        ("new line", LineBreak(1)),
+        ("new paragraph", LineBreak(2)),
+        ("press enter", Keys(KeyChord::new(EditKey::Enter))),
+        ("press tab", Keys(KeyChord::new(EditKey::Tab))),
+        ("tab key", Keys(KeyChord::new(EditKey::Tab))),
+        ("press escape", Keys(KeyChord::new(EditKey::Escape))),
+        ("backspace", Keys(KeyChord::new(EditKey::Backspace))),
+        ("scratch that", ScratchThat),
+        ("delete that", ScratchThat),
+        (
+            "delete last word",
+            Keys(KeyChord::new(EditKey::Backspace).word()),
+        ),
+        (
+            "undo that",
+            Keys(KeyChord::new(EditKey::Letter('z')).primary()),
+        ),
+        (
+            "redo that",
+            Keys(KeyChord::new(EditKey::Letter('z')).primary().shift()),
+        ),
+        (
+            "select all",
+            Keys(KeyChord::new(EditKey::Letter('a')).primary()),
+        ),
+        (
+            "select last word",
+            Keys(KeyChord::new(EditKey::Left).word().shift()),
+        ),
+        ("select last sentence", SelectLastSentence),
+        (
+            "go to start of line",
+            Keys(KeyChord::new(EditKey::LineStart)),
+        ),
+        (
+            "go to beginning of line",
+            Keys(KeyChord::new(EditKey::LineStart)),
+        ),
+        ("go to end of line", Keys(KeyChord::new(EditKey::LineEnd))),
+        (
+            "go to start of document",
+            Keys(KeyChord::new(EditKey::DocumentStart)),
+        ),
+        (
+            "go to beginning of document",
+            Keys(KeyChord::new(EditKey::DocumentStart)),
+        ),
+        (
+            "go to end of document",
+            Keys(KeyChord::new(EditKey::DocumentEnd)),
+        ),
+        (
+            "go to next word",
+            Keys(KeyChord::new(EditKey::Right).word()),
+        ),
+        (
+            "go to previous word",
+            Keys(KeyChord::new(EditKey::Left).word()),
+        ),
+        ("move left", Keys(KeyChord::new(EditKey::Left))),
+        ("move right", Keys(KeyChord::new(EditKey::Right))),
+        ("move up", Keys(KeyChord::new(EditKey::Up))),
+        ("move down", Keys(KeyChord::new(EditKey::Down))),
+    ]
+}
+
+/// Lowercase a transcript word and strip the punctuation Whisper attaches to
+/// it so "Line." still matches the phrase "new line".
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn normalize_phrase(phrase: &str) -> Vec<String> {
+    phrase
+        .split_whitespace()
+        .map(normalize_word)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn parse_edit_key(name: &str) -> Result<EditKey, String> {
+    let key = match name {
+        "enter" | "return" => EditKey::Enter,
+        "tab" => EditKey::Tab,
+        "backspace" => EditKey::Backspace,
+        "delete" | "del" => EditKey::Delete,
+        "escape" | "esc" => EditKey::Escape,
+        "left" => EditKey::Left,
+        "right" => EditKey::Right,
+        "up" => EditKey::Up,
+        "down" => EditKey::Down,
+        "home" | "linestart" => EditKey::LineStart,
+        "end" | "lineend" => EditKey::LineEnd,
+        "docstart" | "documentstart" => EditKey::DocumentStart,
+        "docend" | "documentend" => EditKey::DocumentEnd,
+        other => {
+            let mut chars = other.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) if ch.is_ascii_alphabetic() => EditKey::Letter(ch),
+                _ => return Err(format!("unknown key '{other}'")),
+            }
+        }
+    };
+
+    Ok(key)
+}
+
+/// Parse a chord spec such as `primary+shift+z` or `backspace*3`. Modifiers are
+/// `shift`, `primary` (alias `cmd`/`ctrl`) and `word`.
+pub fn parse_key_chord(spec: &str) -> Result<KeyChord, String> {
+    let spec = spec.trim().to_lowercase();
+    let (combo, repeat) = match spec.rsplit_once('*') {
+        Some((combo, count)) => {
+            let repeat = count
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|count| (1..=MAX_CHORD_REPEAT).contains(count))
+                .ok_or_else(|| format!("invalid repeat count in '{spec}'"))?;
+            (combo, repeat)
+        }
+        None => (spec.as_str(), 1),
+    };
+
+    let mut parts: Vec<&str> = combo.split('+').map(str::trim).collect();
+    let key_name = parts
+        .pop()
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| format!("missing key in '{spec}'"))?;
+
+    let mut chord = KeyChord::new(parse_edit_key(key_name)?).times(repeat);
+    for modifier in parts {
+        chord = match modifier {
+            "shift" => chord.shift(),
+            "primary" | "cmd" | "command" | "ctrl" | "control" => chord.primary(),
+            "word" => chord.word(),
+            other => return Err(format!("unknown modifier '{other}' in '{spec}'")),
+        };
+    }
+
+    Ok(chord)
+}
+
+fn user_action(command: &VoiceCommand) -> Result<CommandAction, String> {
+    match command.action_kind.as_str() {
+        VOICE_COMMAND_ACTION_KEYS => {
+            parse_key_chord(&command.action_value).map(CommandAction::Keys)
+        }
+        VOICE_COMMAND_ACTION_TEXT if command.action_value.is_empty() => {
+            Err("text action is empty".to_string())
+        }
+        VOICE_COMMAND_ACTION_TEXT => Ok(CommandAction::Insert(command.action_value.clone())),
+        other => Err(format!("unknown voice command action '{other}'")),
+    }
+}
+
+/// Check a user-defined command before it is stored.
+pub fn validate_voice_command(command: &VoiceCommand) -> Result<(), String> {
+    if normalize_phrase(&command.phrase).is_empty() {
+        return Err("voice command phrase must contain at least one word".to_string());
+    }
+
+    user_action(command).map(|_| ())
+}
+
+pub struct CommandGrammar {
+    phrases: Vec<CommandPhrase>,
+    keyword_required: bool,
+}
+
+impl CommandGrammar {
+    pub fn new(user_commands: &[VoiceCommand]) -> Self {
+        let mut phrases = Vec::new();
+
+        // User commands go first so they win over a built-in with the same phrase.
+        for command in user_commands.iter().filter(|command| !command.is_deleted) {
+            let words = normalize_phrase(&command.phrase);
+            if words.is_empty() {
+                continue;
+            }
+
+            match user_action(command) {
+                Ok(action) => phrases.push(CommandPhrase { words, action }),
+                Err(err) => eprintln!(
+                    "[voice-commands] Skipping command '{}': {err}",
+                    command.phrase
+                ),
+            }
+        }
+
+        for (phrase, action) in builtin_commands() {
+            phrases.push(CommandPhrase {
+                words: normalize_phrase(phrase),
+                action,
+            });
+        }
+
+        Self {
+            phrases,
+            keyword_required: false,
+        }
+    }
+
+    /// Only run commands that follow [`COMMAND_KEYWORD`].
+    pub fn require_keyword(mut self, required: bool) -> Self {
+        self.keyword_required = required;
+        self
+    }
+
+    fn match_at(&self, words: &[String]) -> Option<&CommandPhrase> {
+        let mut best: Option<&CommandPhrase> = None;
+        for phrase in &self.phrases {
+            let best_len = best.map(|best| best.words.len()).unwrap_or(0);
+            if phrase.words.len() > best_len && words.starts_with(&phrase.words) {
+                best = Some(phrase);
+            }
+        }
+        best
+    }
+
+    pub fn parse(&self, transcript: &str) -> Vec<DictationStep> {
+        let words = word_spans(transcript);
+        let normalized: Vec<String> = words
+            .iter()
+            .map(|&(start, end)| normalize_word(&transcript[start..end]))
+            .collect();
+        let mut builder = StepBuilder::new();
+        // Index of the word the text run last ended with, so the next plain
+        // word keeps the whitespace that separated them.
+        let mut previous_plain: Option<usize> = None;
+        let mut index = 0;
+
+        while index < words.len() {
+            let after_keyword = (normalized[index] == COMMAND_KEYWORD)
+                .then(|| self.match_at(&normalized[index + 1..]))
+                .flatten()
+                .map(|command| (command, 1));
+            let bare = || {
+                (!self.keyword_required)
+                    .then(|| self.match_at(&normalized[index..]))
+                    .flatten()
+                    .map(|command| (command, 0))
+            };
+            let Some((command, keyword_words)) = after_keyword.or_else(bare) else {
+                let (start, end) = words[index];
+                let separator = previous_plain
+                    .filter(|&previous| previous + 1 == index)
+                    .map(|previous| &transcript[words[previous].1..start]);
+                builder.push_word(&transcript[start..end], separator);
+                previous_plain = Some(index);
+                index += 1;
+                continue;
+            };
+
+            builder.apply(&command.action);
+            index += keyword_words + command.words.len();
+        }
+
+        builder.finish()
+    }
+}
+
+struct StepBuilder {
+    steps: Vec<DictationStep>,
+    text: String,
+    /// Offsets in `text` that "scratch that" can cut back to, oldest first.
+    boundaries: Vec<usize>,
+    join_next: bool,
+    capitalize_next: bool,
+}
+
+impl StepBuilder {
+    fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            text: String::new(),
+            boundaries: vec![0],
+            join_next: false,
+            capitalize_next: false,
+        }
+    }
+
+    fn mark_boundary(&mut self) {
+        if self.boundaries.last() != Some(&self.text.len()) {
+            self.boundaries.push(self.text.len());
+        }
+    }
+
+    /// Append a word. `separator` is the whitespace it had in the transcript,
+    /// when the word directly follows the previous one.
+    fn push_word(&mut self, word: &str, separator: Option<&str>) {
+        if !self.text.is_empty() && !self.join_next {
+            self.text.push_str(separator.unwrap_or(" "));
+        }
+
+        if self.capitalize_next {
+            self.text.push_str(&capitalize_first(word));
+        } else {
+            self.text.push_str(word);
+        }
+        self.join_next = false;
+        self.capitalize_next = false;
+
+        if word.ends_with(SENTENCE_TERMINATORS) {
+            self.mark_boundary();
+        }
+    }
+
+    fn push_punctuation(&mut self, mark: &str, space_before: bool, space_after: bool) {
+        if space_before {
+            self.mark_boundary();
+            if !self.text.is_empty() && !self.join_next {
+                self.text.push(' ');
+            }
+        } else {
+            // Whisper often punctuates the word already; replace its mark
+            // rather than doubling up.
+            if mark.chars().all(|ch| CLAUSE_MARKS.contains(&ch)) {
+                let trimmed = self.text.trim_end_matches(CLAUSE_MARKS).len();
+                self.text.truncate(trimmed);
+            }
+            self.mark_boundary();
+        }
+
+        self.text.push_str(mark);
+        self.join_next = !space_after;
+        self.capitalize_next = mark.ends_with(SENTENCE_TERMINATORS);
+        self.mark_boundary();
+    }
+
+    fn push_keys(&mut self, chord: KeyChord) {
+        self.flush_text();
+        self.steps.push(DictationStep::Keys(chord));
+        self.join_next = false;
+        self.capitalize_next = false;
+    }
+
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            self.steps
+                .push(DictationStep::Text(std::mem::take(&mut self.text)));
+        }
+        self.boundaries = vec![0];
+    }
+
+    fn scratch_that(&mut self) {
+        while let Some(boundary) = self.boundaries.pop() {
+            if boundary < self.text.len() {
+                self.text.truncate(boundary);
+                let trimmed = self.text.trim_end().len();
+                self.text.truncate(trimmed);
+                if self.boundaries.is_empty() {
+                    self.boundaries.push(0);
+                }
+                self.join_next = false;
+                if !self.text.is_empty() {
+                    self.capitalize_next = self.text.ends_with(SENTENCE_TERMINATORS);
+                }
+                return;
+            }
+        }
+
+        // Nothing spoken since the last key action; undo whatever came before.
+        self.boundaries.push(0);
+        self.push_keys(KeyChord::new(EditKey::Letter('z')).primary());
+    }
+
+    fn select_last_sentence(&mut self) {
+        let length = last_sentence_chars(&self.text) as u32;
+        if length == 0 {
+            self.push_keys(KeyChord::new(EditKey::LineStart).shift());
+        } else {
+            self.push_keys(KeyChord::new(EditKey::Left).shift().times(length));
+        }
+    }
+
+    fn apply(&mut self, action: &CommandAction) {
+        match action {
+            CommandAction::Punctuation {
+                text,
+                space_before,
+                space_after,
+            } => self.push_punctuation(text, *space_before, *space_after),
+            CommandAction::Insert(text) => {
+                self.mark_boundary();
+                self.push_word(text, None);
+                self.mark_boundary();
+            }
+            CommandAction::Keys(chord) => self.push_keys(*chord),
+            CommandAction::LineBreak(count) => {
+                self.push_keys(KeyChord::new(EditKey::Enter).times(*count));
+                self.capitalize_next = true;
+            }
+            CommandAction::ScratchThat => self.scratch_that(),
+            CommandAction::SelectLastSentence => self.select_last_sentence(),
+        }
+    }
+
+    fn finish(mut self) -> Vec<DictationStep> {
+        self.flush_text();
+        self.steps
+    }
+}
+
+/// Byte ranges of the whitespace-separated words in `text`.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (index, ch) in text.char_indices() {
+        match (ch.is_whitespace(), start) {
+            (true, Some(word_start)) => {
+                spans.push((word_start, index));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, text.len()));
+    }
+    spans
+}
+
+fn capitalize_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Character count of the trailing sentence in `text`, including its own
+/// terminator but not the whitespace before it.
+fn last_sentence_chars(text: &str) -> usize {
+    let body = text.trim_end_matches(SENTENCE_TERMINATORS);
+    let start = body
+        .rfind(SENTENCE_TERMINATORS)
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    text[start..].trim_start().chars().count()
+}
+
+/// Split a transcript into text and key steps using the built-in grammar plus
+/// the user's own commands.
+pub fn parse_dictation(
+    transcript: &str,
+    user_commands: &[VoiceCommand],
+    keyword_required: bool,
+) -> Vec<DictationStep> {
+    CommandGrammar::new(user_commands)
+        .require_keyword(keyword_required)
+        .parse(transcript)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> DictationStep {
+        DictationStep::Text(value.to_string())
+    }
+
+    fn keys(chord: KeyChord) -> DictationStep {
+        DictationStep::Keys(chord)
+    }
+
+    fn user_command(phrase: &str, kind: &str, value: &str) -> VoiceCommand {
+        VoiceCommand {
+            id: phrase.to_string(),
+            created_at: 0,
+            created_by_user_id: "local-user-id".to_string(),
+            phrase: phrase.to_string(),
+            action_kind: kind.to_string(),
+            action_value: value.to_string(),
+            is_deleted: false,
+        }
+    }
+
+    #[test]
+    fn transcripts_without_commands_pass_through() {
+        assert_eq!(
+            parse_dictation("  Hello there,  how are you? ", &[], false),
+            vec![text("Hello there,  how are you?")]
+        );
+        assert_eq!(
+            parse_dictation("Dear team,\n\nThanks for coming.\nBest", &[], false),
+            vec![text("Dear team,\n\nThanks for coming.\nBest")]
+        );
+        assert!(parse_dictation("   ", &[], false).is_empty());
+    }
+
+    #[test]
+    fn required_keyword_keeps_phrases_as_text() {
+        for transcript in [
+            "Put a period at the end and select all of it.",
+            "A new line of products, then move up the list.",
+            "Press backspace twice.",
+            "The command was clear.",
+        ] {
+            assert_eq!(
+                parse_dictation(transcript, &[], true),
+                vec![text(transcript)],
+                "{transcript}"
+            );
+        }
+    }
+
+    #[test]
+    fn fixtures_produce_expected_steps() {
+        let undo = KeyChord::new(EditKey::Letter('z')).primary();
+        let fixtures: Vec<(&str, Vec<DictationStep>)> = vec![
+            (
+                "hello command comma how are you command question mark",
+                vec![text("hello, how are you?")],
+            ),
+            ("Hello world, command period.", vec![text("Hello world.")]),
+            (
+                "done command period next thing",
+                vec![text("done. Next thing")],
+            ),
+            (
+                "he said command open quote hi command close quote",
+                vec![text("he said \"hi\"")],
+            ),
+            (
+                "a well command hyphen known fix",
+                vec![text("a well-known fix")],
+            ),
+            (
+                "Dear team, Command, new line. thanks for coming",
+                vec![
+                    text("Dear team,"),
+                    keys(KeyChord::new(EditKey::Enter)),
+                    text("Thanks for coming"),
+                ],
+            ),
+            (
+                "first  point command new paragraph second point",
+                vec![
+                    text("first  point"),
+                    keys(KeyChord::new(EditKey::Enter).times(2)),
+                    text("Second point"),
+                ],
+            ),
+            (
+                "First sentence. Second one. Command scratch that.",
+                vec![text("First sentence.")],
+            ),
+            (
+                "hello command comma command scratch that",
+                vec![text("hello")],
+            ),
+            ("Command scratch that.", vec![keys(undo)]),
+            (
+                "hello command new line command scratch that",
+                vec![
+                    text("hello"),
+                    keys(KeyChord::new(EditKey::Enter)),
+                    keys(undo),
+                ],
+            ),
+            (
+                "One. Two three command select last sentence",
+                vec![
+                    text("One. Two three"),
+                    keys(KeyChord::new(EditKey::Left).shift().times(9)),
+                ],
+            ),
+            (
+                "Command select last sentence.",
+                vec![keys(KeyChord::new(EditKey::LineStart).shift())],
+            ),
+            (
+                "fix this command delete last word command go to end of line",
+                vec![
+                    text("fix this"),
+                    keys(KeyChord::new(EditKey::Backspace).word()),
+                    keys(KeyChord::new(EditKey::LineEnd)),
+                ],
+            ),
+            (
+                "command new line new line please",
+                vec![keys(KeyChord::new(EditKey::Enter)), text("New line please")],
+            ),
+        ];
+
+        for (transcript, expected) in fixtures {
+            assert_eq!(
+                parse_dictation(transcript, &[], true),
+                expected,
+                "{transcript}"
+            );
+        }
+    }
+
+    #[test]
+    fn bare_phrases_are_commands_unless_the_keyword_is_required() {
+        let fixtures: Vec<(&str, Vec<DictationStep>)> = vec![
+            (
+                "hello comma how are you question mark",
+                vec![text("hello, how are you?")],
+            ),
+            (
+                "Dear team, new line. thanks for coming",
+                vec![
+                    text("Dear team,"),
+                    keys(KeyChord::new(EditKey::Enter)),
+                    text("Thanks for coming"),
+                ],
+            ),
+            (
+                "fix this delete last word command go to end of line",
+                vec![
+                    text("fix this"),
+                    keys(KeyChord::new(EditKey::Backspace).word()),
+                    keys(KeyChord::new(EditKey::LineEnd)),
+                ],
+            ),
+            (
+                "The command was clear.",
+                vec![text("The command was clear.")],
+            ),
+        ];
+
+        for (transcript, expected) in fixtures {
+            assert_eq!(
+                parse_dictation(transcript, &[], false),
+                expected,
+                "{transcript}"
+            );
+        }
+        assert_eq!(
+            parse_dictation("hello comma world", &[], true),
+            vec![text("hello comma world")]
+        );
+    }
+
+    #[test]
+    fn user_commands_extend_and_override_builtins() {
+        let commands = vec![
+            user_command("sign off", VOICE_COMMAND_ACTION_TEXT, "Best, Ada"),
+            user_command("new line", VOICE_COMMAND_ACTION_KEYS, "shift+enter"),
+            user_command("clear it", VOICE_COMMAND_ACTION_KEYS, "backspace*3"),
+            user_command("broken", VOICE_COMMAND_ACTION_KEYS, "hyper+q"),
+            VoiceCommand {
+                is_deleted: true,
+                ..user_command("thanks", VOICE_COMMAND_ACTION_TEXT, "ignored")
+            },
+        ];
+
+        assert_eq!(
+            parse_dictation(
+                "command thanks command new line command sign off command clear it command broken sign off",
+                &commands,
+                true
+            ),
+            vec![
+                text("command thanks"),
+                keys(KeyChord::new(EditKey::Enter).shift()),
+                text("Best, Ada"),
+                keys(KeyChord::new(EditKey::Backspace).times(3)),
+                text("command broken sign off"),
+            ]
+        );
+    }
+
+    #[test]
+    fn key_chord_specs_parse_and_validate() {
+        assert_eq!(
+            parse_key_chord("Primary + Shift + Z"),
+            Ok(KeyChord::new(EditKey::Letter('z')).primary().shift())
+        );
+        assert_eq!(
+            parse_key_chord("word+left*2"),
+            Ok(KeyChord::new(EditKey::Left).word().times(2))
+        );
+        assert!(parse_key_chord("").is_err());
+        assert!(parse_key_chord("shift+").is_err());
+        assert!(parse_key_chord("meta+a").is_err());
+        assert!(parse_key_chord("f13").is_err());
+        assert!(parse_key_chord("backspace*0").is_err());
+        assert!(parse_key_chord("backspace*1000").is_err());
+
+        assert!(
+            validate_voice_command(&user_command("  ", VOICE_COMMAND_ACTION_TEXT, "x")).is_err()
+        );
+        assert!(validate_voice_command(&user_command("go", "macro", "x")).is_err());
+        assert!(
+            validate_voice_command(&user_command("go", VOICE_COMMAND_ACTION_KEYS, "enter")).is_ok()
+        );
+    }
+}
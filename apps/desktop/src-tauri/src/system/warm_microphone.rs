@@ -0,0 +1,122 @@
+//! Keeps the warm microphone usable across sleep and device hiccups.
+//!
+//! A held-open input stream often goes silent after the machine sleeps, or
+//! when the device behind it disappears, without the host reporting an
+//! error. The watchdog notices both and asks the recorder to reopen its
+//! standby stream.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use sqlx::SqlitePool;
+
+use crate::domain::UserPreferences;
+use crate::platform::Recorder;
+
+/// Longest pre-roll the warm microphone keeps.
+pub const MAX_WARM_PRE_ROLL: Duration = Duration::from_millis(2_000);
+
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Wall-clock time that has to pass beyond the monotonic clock before a
+/// tick counts as a resume from sleep.
+const SLEEP_GAP: Duration = Duration::from_secs(5);
+
+/// The pre-roll the preferences ask for, if the warm microphone is on.
+pub fn pre_roll_from_preferences(preferences: &UserPreferences) -> Option<Duration> {
+    preferences
+        .warm_microphone_ms
+        .filter(|&ms| ms > 0)
+        .map(|ms| Duration::from_millis(u64::from(ms)).min(MAX_WARM_PRE_ROLL))
+}
+
+/// Whether the machine slept between two ticks. The monotonic clock stops
+/// while suspended on most platforms, the wall clock does not.
+fn resumed_from_sleep(wall_elapsed: Duration, monotonic_elapsed: Duration) -> bool {
+    wall_elapsed.saturating_sub(monotonic_elapsed) >= SLEEP_GAP
+}
+
+/// Applies the saved microphone preferences to the recorder and starts the
+/// watchdog.
+pub fn start(recorder: Arc<dyn Recorder>, pool: SqlitePool) {
+    let spawned = std::thread::Builder::new()
+        .name("standby-watchdog".into())
+        .spawn(move || {
+            let preferences = tauri::async_runtime::block_on(
+                crate::db::preferences_queries::fetch_user_preferences(
+                    pool,
+                    crate::db::preferences_queries::LOCAL_USER_ID,
+                ),
+            );
+            match preferences {
+                Ok(Some(preferences)) => {
+                    let pre_roll = pre_roll_from_preferences(&preferences);
+                    recorder.set_input_gain(preferences.input_gain);
+                    recorder.set_preferred_input_device(preferences.preferred_microphone);
+                    if let Err(err) = recorder.set_warm_pre_roll(pre_roll) {
+                        eprintln!("[warm_microphone] Failed to open warm microphone: {err}");
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => eprintln!("[warm_microphone] Failed to load preferences: {err}"),
+            }
+
+            let mut last_wall = SystemTime::now();
+            let mut last_monotonic = Instant::now();
+            loop {
+                std::thread::sleep(WATCHDOG_INTERVAL);
+                let wall = SystemTime::now();
+                let monotonic = Instant::now();
+                let resumed = resumed_from_sleep(
+                    wall.duration_since(last_wall).unwrap_or_default(),
+                    monotonic.duration_since(last_monotonic),
+                );
+                if resumed {
+                    eprintln!("[warm_microphone] Resumed from sleep, reopening standby stream");
+                }
+                recorder.refresh_standby(resumed);
+                last_wall = wall;
+                last_monotonic = monotonic;
+            }
+        });
+    if let Err(err) = spawned {
+        eprintln!("[warm_microphone] Failed to start watchdog: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_the_pre_roll_and_treats_zero_as_off() {
+        let mut preferences: UserPreferences =
+            serde_json::from_str(r#"{"userId":"local"}"#).expect("preferences");
+        assert_eq!(pre_roll_from_preferences(&preferences), None);
+
+        preferences.warm_microphone_ms = Some(0);
+        assert_eq!(pre_roll_from_preferences(&preferences), None);
+
+        preferences.warm_microphone_ms = Some(500);
+        assert_eq!(
+            pre_roll_from_preferences(&preferences),
+            Some(Duration::from_millis(500))
+        );
+
+        preferences.warm_microphone_ms = Some(60_000);
+        assert_eq!(
+            pre_roll_from_preferences(&preferences),
+            Some(MAX_WARM_PRE_ROLL)
+        );
+    }
+
+    #[test]
+    fn detects_sleep_from_the_clock_gap() {
+        let tick = Duration::from_secs(2);
+        assert!(!resumed_from_sleep(tick, tick));
+        assert!(!resumed_from_sleep(tick + Duration::from_secs(1), tick));
+        assert!(resumed_from_sleep(Duration::from_secs(600), tick));
+        // The wall clock moving backwards is not a resume.
+        assert!(!resumed_from_sleep(Duration::ZERO, tick));
+    }
+}
@@ -0,0 +1,135 @@
+//! Minimal RFC 6455 framing for the local API's event stream. Only what a
+//! server pushing text needs: the handshake key, unfragmented frames, and
+//! control frames.
+
+use std::io::{self, Read, Write};
+
+use base64::{engine::general_purpose, Engine as _};
+use sha1::{Digest, Sha1};
+
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+/// Clients only send control frames and short commands.
+const MAX_CLIENT_PAYLOAD: u64 = 64 * 1024;
+
+pub const OPCODE_TEXT: u8 = 0x1;
+pub const OPCODE_CLOSE: u8 = 0x8;
+pub const OPCODE_PING: u8 = 0x9;
+pub const OPCODE_PONG: u8 = 0xA;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub opcode: u8,
+    pub payload: Vec<u8>,
+}
+
+/// `Sec-WebSocket-Accept` value for a client's `Sec-WebSocket-Key`.
+pub fn accept_key(client_key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(client_key.trim().as_bytes());
+    hasher.update(HANDSHAKE_GUID.as_bytes());
+    general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// Write one unmasked, unfragmented server frame.
+pub fn write_frame<W: Write>(writer: &mut W, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let mut header = Vec::with_capacity(10);
+    header.push(0x80 | opcode);
+    match payload.len() {
+        len if len < 126 => header.push(len as u8),
+        len if len <= usize::from(u16::MAX) => {
+            header.push(126);
+            header.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            header.push(127);
+            header.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+
+    writer.write_all(&header)?;
+    writer.write_all(payload)?;
+    writer.flush()
+}
+
+/// Read one client frame. Clients must mask their frames; fragmented
+/// messages are rejected since nothing here needs them.
+pub fn read_frame<R: Read>(reader: &mut R) -> io::Result<Frame> {
+    let mut head = [0u8; 2];
+    reader.read_exact(&mut head)?;
+
+    if head[0] & 0x80 == 0 {
+        return Err(invalid("fragmented frames are not supported"));
+    }
+    if head[1] & 0x80 == 0 {
+        return Err(invalid("client frames must be masked"));
+    }
+
+    let len = match head[1] & 0x7F {
+        126 => {
+            let mut bytes = [0u8; 2];
+            reader.read_exact(&mut bytes)?;
+            u64::from(u16::from_be_bytes(bytes))
+        }
+        127 => {
+            let mut bytes = [0u8; 8];
+            reader.read_exact(&mut bytes)?;
+            u64::from_be_bytes(bytes)
+        }
+        len => u64::from(len),
+    };
+    if len > MAX_CLIENT_PAYLOAD {
+        return Err(invalid("frame payload too large"));
+    }
+
+    let mut mask = [0u8; 4];
+    reader.read_exact(&mut mask)?;
+    let mut payload = vec![0u8; len as usize];
+    reader.read_exact(&mut payload)?;
+    for (index, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[index % 4];
+    }
+
+    Ok(Frame {
+        opcode: head[0] & 0x0F,
+        payload,
+    })
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_key_matches_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn reads_masked_client_frames() {
+        // "Hello" from RFC 6455 section 5.7.
+        let bytes = [
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58,
+        ];
+        let frame = read_frame(&mut &bytes[..]).unwrap();
+        assert_eq!(frame.opcode, OPCODE_TEXT);
+        assert_eq!(frame.payload, b"Hello");
+
+        let unmasked = [0x81, 0x05, b'H', b'e', b'l', b'l', b'o'];
+        assert!(read_frame(&mut &unmasked[..]).is_err());
+    }
+
+    #[test]
+    fn writes_extended_lengths() {
+        let mut out = Vec::new();
+        write_frame(&mut out, OPCODE_TEXT, &[b'a'; 300]).unwrap();
+        assert_eq!(&out[..4], &[0x81, 126, 0x01, 0x2c]);
+        assert_eq!(out.len(), 304);
+    }
+}
@@ -1,11 +1,10 @@
-import { AppTarget, Nullable } from "@repo/types";
+import { AppTarget, Nullable, ResolvedAppProfile } from "@repo/types";
 import { getRec } from "@repo/utilities";
 import { invoke } from "@tauri-apps/api/core";
 import { getAppTargetRepo, getStorageRepo } from "../repos";
 import { AppTargetUpsertParams } from "../repos/app-target.repo";
 import { getAppState, produceAppState } from "../store";
 import { registerAppTargets } from "../utils/app.utils";
-import { normalizeAppTargetId } from "../utils/apptarget.utils";
 import { buildAppIconPath, decodeBase64Icon } from "../utils/storage.utils";
 import { showErrorSnackbar } from "./app.actions";
 
@@ -85,9 +84,92 @@ export const setAppTargetPasteKeybind = async (
   }
 };
 
+export const setAppTargetItnEnabled = async (
+  id: string,
+  itnEnabled: boolean,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      itnEnabled,
+    });
+  } catch (error) {
+    console.error("Failed to update app target formatting", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target formatting.",
+    );
+  }
+};
+
+export const setAppTargetTranslationLanguage = async (
+  id: string,
+  translationLanguage: string | null,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      translationLanguage: translationLanguage ?? "",
+    });
+  } catch (error) {
+    console.error("Failed to update app target translation", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target translation.",
+    );
+  }
+};
+
+/**
+ * Load the app profile the backend resolved for the current dictation and
+ * keep a copy for the webview's own steps. Failures fall back to global
+ * settings.
+ */
+export const resolveActiveAppProfile = async (): Promise<
+  Nullable<ResolvedAppProfile>
+> => {
+  let profile: Nullable<ResolvedAppProfile> = null;
+  try {
+    profile = await getAppTargetRepo().resolveAppProfile();
+  } catch (error) {
+    console.error("Failed to resolve app profile", error);
+  }
+
+  produceAppState((draft) => {
+    draft.activeAppProfile = profile;
+  });
+
+  return profile;
+};
+
 type CurrentAppInfoResponse = {
   appName: string;
+  appTargetId: Nullable<string>;
   iconBase64: string;
+  appIdentifier: Nullable<string>;
+  windowTitle: Nullable<string>;
 };
 
 export const tryRegisterCurrentAppTarget = async (): Promise<
@@ -95,7 +177,11 @@ export const tryRegisterCurrentAppTarget = async (): Promise<
 > => {
   const appInfo = await invoke<CurrentAppInfoResponse>("get_current_app_info");
   const appName = appInfo.appName?.trim() ?? "";
-  const appTargetId = normalizeAppTargetId(appName);
+  // The backend derives target ids from app names; nameless apps get their
+  // own id.
+  const appTargetId =
+    appInfo.appTargetId ??
+    `app_target_${crypto.randomUUID().replace(/-/g, "")}`;
   const existingApp = getRec(getAppState().appTargetById, appTargetId);
 
   const shouldRegisterAppTarget = !existingApp || !existingApp.iconPath;
@@ -1,13 +1,25 @@
-import type { ChatMessage, Conversation, Message } from "@repo/types";
+import type {
+  BranchMessage,
+  ChatMessage,
+  Conversation,
+  ConversationCompaction,
+  Message,
+} from "@repo/types";
 import dayjs from "dayjs";
 import { getAppState, produceAppState } from "../store";
 import { createId } from "../utils/id.utils";
+import { getGenerativePrefs } from "../utils/user.utils";
 import { getConversationRepo, getGenerateTextRepo } from "../repos";
+import type { BaseGenerateTextRepo } from "../repos/generate-text.repo";
+import type { AppState } from "../state/app.state";
+import type { MessageAttachment } from "../state/chat.state";
 import { showErrorSnackbar } from "./app.actions";
 
 const MAX_MESSAGE_LENGTH = 10000;
 const MAX_TITLE_LENGTH = 60;
-const CONTEXT_WINDOW_SIZE = 20;
+
+const SYSTEM_PROMPT =
+  "You are OSVoice, a helpful AI assistant. Be concise and clear.";
 
 let activeStreamAbortController: AbortController | null = null;
 
@@ -62,17 +74,141 @@ export const loadConversations = async (): Promise<void> => {
   }
 };
 
+const applyBranch = (branch: BranchMessage[]): void => {
+  produceAppState((draft) => {
+    for (const mId of draft.chat.messageIds) {
+      delete draft.messageById[mId];
+    }
+    draft.chat.messageIds = branch.map((m) => m.id);
+    draft.chat.siblingIdsByMessageId = {};
+    for (const { siblingIds, ...message } of branch) {
+      draft.messageById[message.id] = message;
+      draft.chat.siblingIdsByMessageId[message.id] = siblingIds;
+    }
+  });
+};
+
+const addSibling = (
+  draft: AppState,
+  siblingIds: string[],
+  messageId: string,
+): void => {
+  const ids = [...siblingIds, messageId];
+  for (const id of ids) {
+    draft.chat.siblingIdsByMessageId[id] = ids;
+  }
+};
+
+const estimateTokens = (text: string): number => Math.ceil(text.length / 4);
+
+const getChatModelName = (): string => {
+  const prefs = getGenerativePrefs(getAppState());
+  return prefs.mode === "api"
+    ? (prefs.postProcessingModel ?? prefs.provider)
+    : prefs.mode;
+};
+
+const parseAttachments = (message: Message): MessageAttachment[] => {
+  if (!message.contextJson) return [];
+  try {
+    return JSON.parse(message.contextJson) as MessageAttachment[];
+  } catch {
+    return [];
+  }
+};
+
+const buildSystemPrompt = (attachments: MessageAttachment[]): string => {
+  if (attachments.length === 0) return SYSTEM_PROMPT;
+  const contextParts = attachments.map(
+    (a) => `[${a.type}: ${a.label}]\n${a.content}`,
+  );
+  return `${SYSTEM_PROMPT}\n\nThe user has attached the following context:\n\n${contextParts.join("\n\n")}`;
+};
+
+const compactHistory = async (
+  repo: BaseGenerateTextRepo,
+  conversationId: string,
+  compaction: ConversationCompaction,
+): Promise<string> => {
+  const { text } = await repo.generateText({
+    system: compaction.system,
+    prompt: compaction.prompt,
+  });
+  const summary = await getConversationRepo().createSummary({
+    conversationId,
+    throughMessageId: compaction.throughMessageId,
+    content: text,
+    model: getChatModelName(),
+  });
+  return summary.content;
+};
+
+// The context window the provider reports for the chat model, if any.
+const getChatContextWindow = (): number | null => {
+  const state = getAppState();
+  const prefs = getGenerativePrefs(state);
+  if (prefs.mode !== "api" || prefs.provider !== "openrouter") return null;
+  const model = state.settings.openRouterModels.find(
+    (m) => m.id === prefs.postProcessingModel,
+  );
+  return model?.context_length ?? null;
+};
+
+// Fits the branch ending at `leafMessageId` into the model's context window,
+// folding messages that no longer fit into the conversation summary. Ollama
+// chats are summarized by the backend.
+const buildChatRequest = async (
+  repo: BaseGenerateTextRepo,
+  conversationId: string,
+  leafMessageId: string,
+  systemPrompt: string,
+): Promise<{ system: string; messages: ChatMessage[] }> => {
+  const prefs = getGenerativePrefs(getAppState());
+  const context = await getConversationRepo().buildContext({
+    conversationId,
+    leafMessageId,
+    model: getChatModelName(),
+    reservedTokens: estimateTokens(systemPrompt),
+    contextWindow: getChatContextWindow(),
+    ollamaApiKeyId:
+      prefs.mode === "api" && prefs.provider === "ollama"
+        ? prefs.apiKeyId
+        : null,
+  });
+
+  let summary = context.summary?.content ?? null;
+  if (context.compaction) {
+    try {
+      summary = await compactHistory(repo, conversationId, context.compaction);
+    } catch (error) {
+      // The reply can still go out with the older messages left out.
+      console.error("Failed to summarize chat history", error);
+    }
+  }
+
+  const messages = context.messages
+    .filter(
+      (m) => (m.role === "user" || m.role === "assistant") && m.content !== "",
+    )
+    .map((m) => ({
+      role: m.role as "user" | "assistant",
+      content: m.content,
+    }));
+
+  return {
+    system: summary
+      ? `${systemPrompt}\n\nSummary of the earlier conversation:\n${summary}`
+      : systemPrompt,
+    messages,
+  };
+};
+
 export const loadConversationMessages = async (
   conversationId: string,
 ): Promise<void> => {
   try {
-    const messages = await getConversationRepo().listMessages(conversationId);
-    produceAppState((draft) => {
-      draft.chat.messageIds = messages.map((m) => m.id);
-      for (const m of messages) {
-        draft.messageById[m.id] = m;
-      }
-    });
+    const branch = await getConversationRepo().getBranch(conversationId);
+    applyBranch(branch);
   } catch (error) {
     showErrorSnackbar(error);
   }
@@ -87,6 +223,7 @@ export const setActiveConversation = async (
     }
     draft.chat.activeConversationId = conversationId;
     draft.chat.messageIds = [];
+    draft.chat.siblingIdsByMessageId = {};
   });
   if (conversationId) {
     await loadConversationMessages(conversationId);
@@ -100,31 +237,18 @@ export const stopChatStream = (): void => {
   }
 };
 
-export const sendChatMessage = async (
+// Streams an assistant reply to `userMessage` into the active branch. The
+// reply is added next to `siblingIds` when regenerating an existing answer.
+const replyTo = async (
   conversationId: string,
-  content: string,
-): Promise<void> => {
-  const { chat } = getAppState();
-  if (chat.isLoading || chat.isStreaming) return;
-
-  const trimmed = content.slice(0, MAX_MESSAGE_LENGTH);
-
-  const attachments = [...chat.pendingAttachments];
-
-  const userMessage: Message = {
-    id: createId(),
-    conversationId,
-    role: "user",
-    content: trimmed,
-    contextJson:
-      attachments.length > 0 ? JSON.stringify(attachments) : undefined,
-    createdAt: dayjs().toISOString(),
-  };
-
+  userMessage: Message,
+  siblingIds: string[] = [],
+): Promise<boolean> => {
   const assistantMessageId = createId();
   const assistantMessage: Message = {
     id: assistantMessageId,
     conversationId,
+    parentId: userMessage.id,
     role: "assistant",
     content: "",
     createdAt: dayjs().toISOString(),
@@ -133,21 +257,14 @@ export const sendChatMessage = async (
   let rafHandle: number | null = null;
 
   produceAppState((draft) => {
-    draft.messageById[userMessage.id] = userMessage;
     draft.messageById[assistantMessageId] = assistantMessage;
-    draft.chat.messageIds = [
-      ...draft.chat.messageIds,
-      userMessage.id,
-      assistantMessageId,
-    ];
+    draft.chat.messageIds = [...draft.chat.messageIds, assistantMessageId];
+    addSibling(draft, siblingIds, assistantMessageId);
     draft.chat.isLoading = true;
     draft.chat.streamingMessageId = assistantMessageId;
-    draft.chat.pendingAttachments = [];
   });
 
   try {
-    await getConversationRepo().createMessage(userMessage);
-
     const { repo, warnings } = getGenerateTextRepo();
     if (!repo) {
       throw new Error(
@@ -157,31 +274,12 @@ export const sendChatMessage = async (
       );
     }
 
-    const state = getAppState();
-    const chatMessages: ChatMessage[] = state.chat.messageIds
-      .slice(0, -1)
-      .slice(-CONTEXT_WINDOW_SIZE)
-      .map((id) => state.messageById[id])
-      .filter(
-        (m): m is Message =>
-          !!m &&
-          (m.role === "user" || m.role === "assistant") &&
-          m.content !== "",
-      )
-      .map((m) => ({
-        role: m.role as "user" | "assistant",
-        content: m.content,
-      }));
-
-    let systemPrompt =
-      "You are OSVoice, a helpful AI assistant. Be concise and clear.";
-
-    if (attachments.length > 0) {
-      const contextParts = attachments.map(
-        (a) => `[${a.type}: ${a.label}]\n${a.content}`,
-      );
-      systemPrompt += `\n\nThe user has attached the following context:\n\n${contextParts.join("\n\n")}`;
-    }
+    const { system, messages } = await buildChatRequest(
+      repo,
+      conversationId,
+      userMessage.id,
+      buildSystemPrompt(parseAttachments(userMessage)),
+    );
 
     activeStreamAbortController = new AbortController();
     const { signal } = activeStreamAbortController;
@@ -201,8 +299,8 @@ export const sendChatMessage = async (
     };
 
     const result = await repo.generateChatStream({
-      system: systemPrompt,
-      messages: chatMessages,
+      system,
+      messages,
       onChunk: (delta: string) => {
         if (signal.aborted) {
           throw new DOMException("Aborted", "AbortError");
@@ -244,23 +342,7 @@ export const sendChatMessage = async (
       content: result.text,
       model: result.metadata?.inferenceDevice ?? undefined,
     });
-
-    const conversation = getAppState().conversationById[conversationId];
-    if (conversation && !conversation.title) {
-      const title =
-        trimmed.length > MAX_TITLE_LENGTH
-          ? `${trimmed.slice(0, MAX_TITLE_LENGTH - 3)}...`
-          : trimmed;
-      const updated: Conversation = {
-        ...conversation,
-        title,
-        updatedAt: dayjs().toISOString(),
-      };
-      produceAppState((draft) => {
-        draft.conversationById[conversationId] = updated;
-      });
-      await getConversationRepo().updateConversation(updated);
-    }
+    return true;
   } catch (error) {
     activeStreamAbortController = null;
     if (rafHandle !== null) {
@@ -278,6 +360,7 @@ export const sendChatMessage = async (
         const msg = draft.messageById[assistantMessageId];
         if (msg && !msg.content) {
           delete draft.messageById[assistantMessageId];
+          delete draft.chat.siblingIdsByMessageId[assistantMessageId];
           draft.chat.messageIds = draft.chat.messageIds.filter(
             (id) => id !== assistantMessageId,
           );
@@ -288,6 +371,180 @@ export const sendChatMessage = async (
     if (!isAbort) {
       showErrorSnackbar(error);
     }
+    return false;
+  }
+};
+
+export const sendChatMessage = async (
+  conversationId: string,
+  content: string,
+): Promise<void> => {
+  const { chat } = getAppState();
+  if (chat.isLoading || chat.isStreaming) return;
+
+  const trimmed = content.slice(0, MAX_MESSAGE_LENGTH);
+
+  const attachments = [...chat.pendingAttachments];
+
+  const userMessage: Message = {
+    id: createId(),
+    conversationId,
+    parentId: chat.messageIds[chat.messageIds.length - 1] ?? undefined,
+    role: "user",
+    content: trimmed,
+    contextJson:
+      attachments.length > 0 ? JSON.stringify(attachments) : undefined,
+    createdAt: dayjs().toISOString(),
+  };
+
+  produceAppState((draft) => {
+    draft.messageById[userMessage.id] = userMessage;
+    draft.chat.messageIds = [...draft.chat.messageIds, userMessage.id];
+    addSibling(draft, [], userMessage.id);
+    draft.chat.pendingAttachments = [];
+  });
+
+  try {
+    await getConversationRepo().createMessage(userMessage);
+  } catch (error) {
+    showErrorSnackbar(error);
+    return;
+  }
+
+  const replied = await replyTo(conversationId, userMessage);
+  if (!replied) return;
+
+  const conversation = getAppState().conversationById[conversationId];
+  if (conversation && !conversation.title) {
+    const title =
+      trimmed.length > MAX_TITLE_LENGTH
+        ? `${trimmed.slice(0, MAX_TITLE_LENGTH - 3)}...`
+        : trimmed;
+    const updated: Conversation = {
+      ...conversation,
+      title,
+      updatedAt: dayjs().toISOString(),
+    };
+    produceAppState((draft) => {
+      draft.conversationById[conversationId] = updated;
+    });
+    try {
+      await getConversationRepo().updateConversation(updated);
+    } catch (error) {
+      showErrorSnackbar(error);
+    }
+  }
+};
+
+// Messages are never rewritten in place: editing adds a sibling of the
+// original under the same parent and answers it, so the old branch survives.
+export const editChatMessage = async (
+  conversationId: string,
+  messageId: string,
+  content: string,
+): Promise<void> => {
+  const { chat, messageById } = getAppState();
+  if (chat.isLoading || chat.isStreaming) return;
+
+  const original = messageById[messageId];
+  const index = chat.messageIds.indexOf(messageId);
+  if (!original || original.role !== "user" || index < 0) return;
+
+  const edited: Message = {
+    id: createId(),
+    conversationId,
+    parentId: original.parentId,
+    role: "user",
+    content: content.slice(0, MAX_MESSAGE_LENGTH),
+    contextJson: original.contextJson,
+    createdAt: dayjs().toISOString(),
+  };
+
+  produceAppState((draft) => {
+    draft.messageById[edited.id] = edited;
+    draft.chat.messageIds = [...chat.messageIds.slice(0, index), edited.id];
+    addSibling(
+      draft,
+      chat.siblingIdsByMessageId[messageId] ?? [messageId],
+      edited.id,
+    );
+  });
+
+  try {
+    await getConversationRepo().createMessage(edited);
+  } catch (error) {
+    showErrorSnackbar(error);
+    await loadConversationMessages(conversationId);
+    return;
+  }
+
+  await replyTo(conversationId, edited);
+};
+
+export const regenerateChatMessage = async (
+  conversationId: string,
+  messageId: string,
+): Promise<void> => {
+  const { chat, messageById } = getAppState();
+  if (chat.isLoading || chat.isStreaming) return;
+
+  const original = messageById[messageId];
+  const userMessage = original?.parentId
+    ? messageById[original.parentId]
+    : undefined;
+  const index = chat.messageIds.indexOf(messageId);
+  if (!original || original.role !== "assistant" || !userMessage || index < 0) {
+    return;
+  }
+
+  produceAppState((draft) => {
+    draft.chat.messageIds = chat.messageIds.slice(0, index);
+  });
+
+  await replyTo(
+    conversationId,
+    userMessage,
+    chat.siblingIdsByMessageId[messageId] ?? [messageId],
+  );
+};
+
+export const switchChatBranch = async (
+  conversationId: string,
+  messageId: string,
+): Promise<void> => {
+  const { chat } = getAppState();
+  if (chat.isLoading || chat.isStreaming) return;
+
+  try {
+    const branch = await getConversationRepo().switchBranch(
+      conversationId,
+      messageId,
+    );
+    applyBranch(branch);
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
+
+export const forkConversation = async (
+  conversationId: string,
+  messageId: string,
+): Promise<void> => {
+  try {
+    const conversation = await getConversationRepo().forkConversation(
+      conversationId,
+      messageId,
+    );
+    produceAppState((draft) => {
+      draft.conversationById[conversation.id] = conversation;
+      draft.chat.conversationIds = [
+        conversation.id,
+        ...draft.chat.conversationIds,
+      ];
+    });
+    await setActiveConversation(conversation.id);
+  } catch (error) {
+    showErrorSnackbar(error);
   }
 };
 
@@ -312,6 +569,7 @@ export const deleteConversation = async (id: string): Promise<void> => {
       }
       draft.chat.activeConversationId = null;
       draft.chat.messageIds = [];
+      draft.chat.siblingIdsByMessageId = {};
     }
     delete draft.conversationById[id];
     draft.chat.conversationIds = draft.chat.conversationIds.filter(
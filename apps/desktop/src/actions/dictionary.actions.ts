@@ -1,7 +1,9 @@
+import { Term, TermSuggestion } from "@repo/types";
 import dayjs from "dayjs";
-import { getTermRepo } from "../repos";
-import { produceAppState } from "../store";
+import { getTermRepo, getTermSuggestionRepo } from "../repos";
+import { getAppState, produceAppState } from "../store";
 import { registerTerms } from "../utils/app.utils";
+import { getHasCloudAccess } from "../utils/user.utils";
 
 export const loadDictionary = async (): Promise<void> => {
   const terms = await getTermRepo().listTerms();
@@ -15,3 +17,71 @@ export const loadDictionary = async (): Promise<void> => {
     draft.dictionary.termIds = activeTerms.map((term) => term.id);
   });
 };
+
+const getReplacementKey = ({
+  sourceValue,
+  destinationValue,
+}: Pick<Term, "sourceValue" | "destinationValue">) =>
+  `${sourceValue.toLowerCase()}\n${destinationValue}`;
+
+export const loadTermSuggestions = async (): Promise<void> => {
+  const suggestions = await getTermSuggestionRepo().listSuggestions();
+
+  // Synced dictionaries are not in the local database, so the backend cannot
+  // filter out corrections they already cover.
+  const state = getAppState();
+  const existing = new Set(
+    state.dictionary.termIds
+      .map((id) => state.termById[id])
+      .filter((term): term is Term => Boolean(term?.isReplacement))
+      .map(getReplacementKey),
+  );
+
+  produceAppState((draft) => {
+    draft.dictionary.suggestions = suggestions.filter(
+      (suggestion) => !existing.has(getReplacementKey(suggestion)),
+    );
+  });
+};
+
+const removeSuggestion = (id: string) => {
+  produceAppState((draft) => {
+    draft.dictionary.suggestions = draft.dictionary.suggestions.filter(
+      (suggestion) => suggestion.id !== id,
+    );
+  });
+};
+
+export const acceptTermSuggestion = async (
+  suggestion: TermSuggestion,
+): Promise<void> => {
+  // The backend adds the term to the local dictionary when it accepts the
+  // suggestion; a synced dictionary still needs its own copy.
+  const review = await getTermSuggestionRepo().reviewSuggestion(
+    suggestion.id,
+    "accepted",
+  );
+  removeSuggestion(suggestion.id);
+
+  let term = review.term;
+  if (term && getHasCloudAccess(getAppState())) {
+    term = await getTermRepo().createTerm(term);
+  }
+  if (!term) {
+    return;
+  }
+
+  const created = term;
+  produceAppState((draft) => {
+    draft.termById[created.id] = created;
+    draft.dictionary.termIds = [
+      created.id,
+      ...draft.dictionary.termIds.filter((id) => id !== created.id),
+    ];
+  });
+};
+
+export const rejectTermSuggestion = async (id: string): Promise<void> => {
+  await getTermSuggestionRepo().reviewSuggestion(id, "rejected");
+  removeSuggestion(id);
+};
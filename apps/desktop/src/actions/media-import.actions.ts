@@ -0,0 +1,87 @@
+import type { MediaImportProgress, MediaImportTarget } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { createId } from "../utils/id.utils";
+import { getMeetingRepo, getTranscriptionRepo } from "../repos";
+import { getAppState, produceAppState } from "../store";
+import { registerTranscriptions } from "../utils/app.utils";
+import { showErrorSnackbar, showSnackbar } from "./app.actions";
+import { loadMeetings } from "./meeting.actions";
+
+let cancelledImportId: string | null = null;
+
+export const importMediaFile = async (
+  path: string,
+  target: MediaImportTarget,
+): Promise<void> => {
+  if (getAppState().meeting.importProgress) return;
+
+  const importId = createId();
+  produceAppState((draft) => {
+    draft.meeting.importProgress = {
+      importId,
+      stage: "decoding",
+      progress: 0,
+    };
+  });
+
+  const unlisten = await listen<MediaImportProgress>(
+    "media_import_progress",
+    (event) => {
+      if (event.payload.importId !== importId) return;
+      produceAppState((draft) => {
+        draft.meeting.importProgress = event.payload;
+      });
+    },
+  );
+
+  const input = {
+    importId,
+    path,
+    modelSize: getAppState().settings.aiTranscription.modelSize,
+  };
+
+  try {
+    if (target === "meeting") {
+      const meeting = await getMeetingRepo().importMeetingMedia(input);
+      produceAppState((draft) => {
+        draft.meetingById[meeting.id] = meeting;
+        draft.meeting.meetingIds.unshift(meeting.id);
+      });
+    } else {
+      const transcription =
+        await getTranscriptionRepo().importTranscriptionMedia(input);
+      produceAppState((draft) => {
+        registerTranscriptions(draft, [transcription]);
+        draft.transcriptions.transcriptionIds.unshift(transcription.id);
+      });
+      showSnackbar("Imported file added to your transcriptions");
+    }
+  } catch (error) {
+    if (cancelledImportId !== importId) {
+      showErrorSnackbar(error);
+    }
+    if (target === "meeting") {
+      // A failed transcription still leaves the meeting and its audio.
+      await loadMeetings();
+    }
+  } finally {
+    unlisten();
+    cancelledImportId = null;
+    produceAppState((draft) => {
+      draft.meeting.importProgress = null;
+    });
+  }
+};
+
+export const cancelMediaImport = async (): Promise<void> => {
+  const progress = getAppState().meeting.importProgress;
+  if (!progress) return;
+
+  cancelledImportId = progress.importId;
+  try {
+    await invoke("media_import_cancel", { importId: progress.importId });
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
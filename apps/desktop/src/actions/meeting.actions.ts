@@ -1,9 +1,18 @@
-import type { Meeting, MeetingSegment } from "@repo/types";
+import type {
+  Meeting,
+  MeetingActionItem,
+  MeetingAnswer,
+  MeetingDecision,
+  MeetingQuestion,
+  MeetingSegment,
+  TranscriptExportOptions,
+} from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import dayjs from "dayjs";
 import { createId } from "../utils/id.utils";
 import {
+  getConversationRepo,
   getDiarizeRepo,
   getGenerateTextRepo,
   getMeetingRepo,
@@ -14,9 +23,13 @@ import {
   parseMeetingSummaryResponse,
 } from "../utils/meeting-prompt.utils";
 import { getAppState, produceAppState } from "../store";
+import type { AppState } from "../state/app.state";
 import { showErrorSnackbar } from "./app.actions";
 import { buildWaveFile } from "../utils/audio.utils";
-import { getMyPreferredMicrophone } from "../utils/user.utils";
+import {
+  getGenerativePrefs,
+  getMyPreferredMicrophone,
+} from "../utils/user.utils";
 
 let audioChunkUnlisten: UnlistenFn | null = null;
 let chunkBuffer: number[] = [];
@@ -70,6 +83,7 @@ export const startMeetingRecording = async (): Promise<string | null> => {
   const state = getAppState();
   if (state.meeting.isRecording) return null;
   if (state.activeRecordingMode) return null;
+  if (state.userPrefs?.incognitoModeEnabled) return null;
 
   const now = dayjs().toISOString();
   const meeting: Meeting = {
@@ -212,6 +226,9 @@ export const deleteMeeting = async (meetingId: string): Promise<void> => {
     if (draft.meeting.activeMeetingId === meetingId) {
       draft.meeting.activeMeetingId = null;
     }
+    draft.meeting.openActionItems = draft.meeting.openActionItems.filter(
+      (item) => item.meetingId !== meetingId,
+    );
     for (const segId of removedSegmentIds) {
       delete draft.meetingSegmentById[segId];
     }
@@ -287,6 +304,10 @@ export const selectMeeting = (meetingId: string | null): void => {
   produceAppState((draft) => {
     draft.meeting.activeMeetingId = meetingId;
     draft.meeting.segmentIds = [];
+    draft.meeting.actionItemIds = [];
+    draft.meeting.decisionIds = [];
+    draft.meeting.qaConversationId = null;
+    draft.meeting.qaMessageIds = [];
   });
 };
 
@@ -451,15 +472,297 @@ export const generateMeetingSummary = async (
     const updated: Meeting = {
       ...meeting,
       summary: parsed.summary,
-      actionItems: JSON.stringify(parsed.actionItems),
       updatedAt: now,
     };
+    const segmentIdAt = (index: number | null) =>
+      index === null ? null : (segments[index]?.id ?? null);
+
+    const actionItems: MeetingActionItem[] = parsed.actionItems.map(
+      (item, position) => ({
+        id: createId(),
+        meetingId,
+        task: item.task,
+        owner: item.assignee,
+        dueDate: item.dueDate,
+        priority: item.priority,
+        status: "open",
+        sourceSegmentId: segmentIdAt(item.sourceSegment),
+        position,
+        createdAt: now,
+        updatedAt: now,
+      }),
+    );
+    const decisions: MeetingDecision[] = parsed.decisions.map(
+      (decision, position) => ({
+        id: createId(),
+        meetingId,
+        text: decision.text,
+        sourceSegmentId: segmentIdAt(decision.sourceSegment),
+        position,
+        createdAt: now,
+        updatedAt: now,
+      }),
+    );
 
     produceAppState((draft) => {
       draft.meetingById[meetingId] = updated;
     });
 
-    await getMeetingRepo().updateMeeting(updated);
+    const repo = getMeetingRepo();
+    await repo.updateMeeting(updated);
+    const [storedItems, storedDecisions] = await Promise.all([
+      repo.replaceActionItems(meetingId, actionItems),
+      repo.replaceDecisions(meetingId, decisions),
+    ]);
+    setMeetingActionItems(storedItems, storedDecisions);
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
+
+const setMeetingActionItems = (
+  items: MeetingActionItem[],
+  decisions: MeetingDecision[],
+): void => {
+  produceAppState((draft) => {
+    for (const item of items) {
+      draft.meetingActionItemById[item.id] = item;
+    }
+    for (const decision of decisions) {
+      draft.meetingDecisionById[decision.id] = decision;
+    }
+    draft.meeting.actionItemIds = items.map((item) => item.id);
+    draft.meeting.decisionIds = decisions.map((decision) => decision.id);
+  });
+};
+
+export const loadMeetingActionItems = async (
+  meetingId: string,
+): Promise<void> => {
+  try {
+    const repo = getMeetingRepo();
+    const [items, decisions] = await Promise.all([
+      repo.listActionItems(meetingId),
+      repo.listDecisions(meetingId),
+    ]);
+    setMeetingActionItems(items, decisions);
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
+
+export const loadOpenActionItems = async (): Promise<void> => {
+  try {
+    const items = await getMeetingRepo().listOpenActionItems();
+    produceAppState((draft) => {
+      draft.meeting.openActionItems = items;
+    });
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
+
+const findOpenActionItem = (
+  state: AppState,
+  id: string,
+): MeetingActionItem | undefined => {
+  const open = state.meeting.openActionItems.find((item) => item.id === id);
+  return open
+    ? {
+        id: open.id,
+        meetingId: open.meetingId,
+        task: open.task,
+        owner: open.owner,
+        dueDate: open.dueDate,
+        priority: open.priority,
+        status: open.status,
+        sourceSegmentId: open.sourceSegmentId,
+        position: open.position,
+        createdAt: open.createdAt,
+        updatedAt: open.updatedAt,
+      }
+    : undefined;
+};
+
+export const updateMeetingActionItem = async (
+  id: string,
+  changes: Partial<
+    Pick<MeetingActionItem, "task" | "owner" | "dueDate" | "status">
+  >,
+): Promise<void> => {
+  const state = getAppState();
+  const existing =
+    state.meetingActionItemById[id] ?? findOpenActionItem(state, id);
+  if (!existing) return;
+
+  const updated: MeetingActionItem = {
+    ...existing,
+    ...changes,
+    updatedAt: dayjs().toISOString(),
+  };
+  produceAppState((draft) => {
+    draft.meetingActionItemById[id] = updated;
+    draft.meeting.openActionItems = draft.meeting.openActionItems.filter(
+      (item) => item.id !== id || updated.status === "open",
+    );
+  });
+
+  try {
+    await getMeetingRepo().updateActionItem(updated);
+  } catch (error) {
+    await loadOpenActionItems();
+    produceAppState((draft) => {
+      draft.meetingActionItemById[id] = existing;
+    });
+    showErrorSnackbar(error);
+  }
+};
+
+export const toggleMeetingActionItem = async (id: string): Promise<void> => {
+  const state = getAppState();
+  const item = state.meetingActionItemById[id] ?? findOpenActionItem(state, id);
+  if (!item) return;
+  await updateMeetingActionItem(id, {
+    status: item.status === "done" ? "open" : "done",
+  });
+};
+
+export const deleteMeetingActionItem = async (id: string): Promise<void> => {
+  const existing = getAppState().meetingActionItemById[id];
+  if (!existing) return;
+
+  produceAppState((draft) => {
+    delete draft.meetingActionItemById[id];
+    draft.meeting.actionItemIds = draft.meeting.actionItemIds.filter(
+      (itemId) => itemId !== id,
+    );
+  });
+
+  try {
+    await getMeetingRepo().deleteActionItem(id);
+  } catch (error) {
+    await loadMeetingActionItems(existing.meetingId);
+    showErrorSnackbar(error);
+  }
+};
+
+export const deleteMeetingDecision = async (id: string): Promise<void> => {
+  const existing = getAppState().meetingDecisionById[id];
+  if (!existing) return;
+
+  produceAppState((draft) => {
+    delete draft.meetingDecisionById[id];
+    draft.meeting.decisionIds = draft.meeting.decisionIds.filter(
+      (decisionId) => decisionId !== id,
+    );
+  });
+
+  try {
+    await getMeetingRepo().deleteDecision(id);
+  } catch (error) {
+    await loadMeetingActionItems(existing.meetingId);
+    showErrorSnackbar(error);
+  }
+};
+
+export const loadMeetingQuestions = async (
+  meetingId: string,
+): Promise<void> => {
+  try {
+    const repo = getConversationRepo();
+    const conversation = await repo.getMeetingConversation(meetingId);
+    const messages = conversation
+      ? await repo.listMessages(conversation.id)
+      : [];
+    produceAppState((draft) => {
+      if (draft.meeting.activeMeetingId !== meetingId) return;
+      if (conversation) {
+        draft.conversationById[conversation.id] = conversation;
+      }
+      for (const message of messages) {
+        draft.messageById[message.id] = message;
+      }
+      draft.meeting.qaConversationId = conversation?.id ?? null;
+      draft.meeting.qaMessageIds = messages.map((message) => message.id);
+    });
+  } catch (error) {
+    showErrorSnackbar(error);
+  }
+};
+
+// Ollama answers run entirely in the backend; other providers are called
+// from here with the prompt the backend builds.
+const answerMeetingQuestion = async (
+  question: MeetingQuestion,
+): Promise<MeetingAnswer> => {
+  const conversationRepo = getConversationRepo();
+  const { repo, apiKeyId } = getGenerateTextRepo();
+  const prefs = getGenerativePrefs(getAppState());
+  if (!repo) {
+    throw new Error("No LLM provider configured for meeting questions");
+  }
+  if (prefs.mode === "api" && prefs.provider === "ollama") {
+    return conversationRepo.askAboutMeetings({ ...question, apiKeyId });
+  }
+
+  const prompt = await conversationRepo.prepareMeetingQuestion(question);
+  const output = await repo.generateChat({
+    system: prompt.system,
+    messages: prompt.messages,
+  });
+  const model = prefs.mode === "api" ? prefs.postProcessingModel : null;
+  return conversationRepo.recordMeetingAnswer(prompt, output.text, model);
+};
+
+export const askMeetingQuestion = async (
+  meetingId: string,
+  question: string,
+): Promise<void> => {
+  const trimmed = question.trim();
+  if (!trimmed || getAppState().meeting.isAsking) return;
+
+  produceAppState((draft) => {
+    draft.meeting.isAsking = true;
+  });
+
+  try {
+    const result = await answerMeetingQuestion({
+      question: trimmed,
+      meetingId,
+      conversationId: getAppState().meeting.qaConversationId,
+    });
+    produceAppState((draft) => {
+      draft.conversationById[result.conversation.id] = result.conversation;
+      draft.messageById[result.question.id] = result.question;
+      draft.messageById[result.answer.id] = result.answer;
+      if (draft.meeting.activeMeetingId === meetingId) {
+        draft.meeting.qaConversationId = result.conversation.id;
+        draft.meeting.qaMessageIds.push(result.question.id, result.answer.id);
+      }
+    });
+  } catch (error) {
+    showErrorSnackbar(error);
+  } finally {
+    produceAppState((draft) => {
+      draft.meeting.isAsking = false;
+    });
+  }
+};
+
+export const exportMeeting = async (
+  meetingId: string,
+  options: TranscriptExportOptions,
+): Promise<void> => {
+  try {
+    const exported = await getMeetingRepo().exportMeeting(meetingId, options);
+    const url = URL.createObjectURL(
+      new Blob([exported.content], { type: exported.mimeType }),
+    );
+    const link = document.createElement("a");
+    link.href = url;
+    link.download = exported.fileName;
+    link.click();
+    URL.revokeObjectURL(url);
   } catch (error) {
     showErrorSnackbar(error);
   }
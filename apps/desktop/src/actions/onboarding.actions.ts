@@ -173,11 +173,16 @@ export const submitOnboarding = async () => {
       languageSwitchEnabled: false,
       secondaryDictationLanguage: null,
       activeDictationLanguage: "primary",
+      languageDetectionEnabled: false,
       preferredMicrophone: normalizedMicrophone,
       ignoreUpdateDialog: false,
       incognitoModeEnabled: false,
       incognitoModeIncludeInStats: false,
       dictationPillVisibility: "persistent",
+      ambientWakePhrase: null,
+      warmMicrophoneMs: null,
+      inputGain: null,
+      voiceCommandKeywordRequired: false,
     };
 
     const [savedUser, savedPreferences] = await Promise.all([
@@ -0,0 +1,36 @@
+import type { RetentionTarget } from "@repo/types";
+import { getRetentionRepo } from "../repos";
+import { produceAppState } from "../store";
+import type { AppState } from "../state/app.state";
+import { showErrorSnackbar } from "./app.actions";
+
+const applyStarred = (
+  draft: AppState,
+  target: RetentionTarget,
+  id: string,
+  starred: boolean,
+): void => {
+  const record =
+    target === "transcription"
+      ? draft.transcriptionById[id]
+      : target === "meeting"
+        ? draft.meetingById[id]
+        : draft.conversationById[id];
+  if (record) {
+    record.starred = starred;
+  }
+};
+
+export const setStarred = async (
+  target: RetentionTarget,
+  id: string,
+  starred: boolean,
+): Promise<void> => {
+  produceAppState((draft) => applyStarred(draft, target, id, starred));
+  try {
+    await getRetentionRepo().setStarred(target, id, starred);
+  } catch (error) {
+    produceAppState((draft) => applyStarred(draft, target, id, !starred));
+    showErrorSnackbar(error);
+  }
+};
@@ -1,4 +1,4 @@
-import { Tone } from "@repo/types";
+import { Tone, ToneTemplatePreview } from "@repo/types";
 import { getToneRepo, getUserPreferencesRepo } from "../repos";
 import { ToneEditorMode } from "../state/tone-editor.state";
 import { getAppState, produceAppState } from "../store";
@@ -48,12 +48,26 @@ export const upsertTone = async (tone: Tone): Promise<Tone> => {
   } catch (error) {
     console.error("Failed to save tone", error);
     showErrorSnackbar(
-      error instanceof Error ? error.message : "Failed to save tone.",
+      error instanceof Error
+        ? error.message
+        : typeof error === "string"
+          ? error
+          : "Failed to save tone.",
     );
     throw error;
   }
 };
 
+/**
+ * Render a template against sample values so the editor can show the result
+ * and any template errors before the tone is saved.
+ */
+export const previewToneTemplate = async (
+  promptTemplate: string,
+): Promise<ToneTemplatePreview> => {
+  return getToneRepo().previewTone(promptTemplate);
+};
+
 export const deleteTone = async (id: string): Promise<void> => {
   try {
     await getToneRepo().deleteTone(id);
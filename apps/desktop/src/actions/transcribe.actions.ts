@@ -5,6 +5,7 @@ import {
 } from "@repo/types";
 import { countWords, dedup, getRec } from "@repo/utilities";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import dayjs from "dayjs";
 import {
   getGenerateTextRepo,
@@ -21,9 +22,11 @@ import {
   extractTextFieldContext,
 } from "../utils/accessibility.utils";
 import { createId } from "../utils/id.utils";
-import { mapLocaleToWhisperLanguage } from "../utils/language.utils";
 import {
-  buildLocalizedPostProcessingPrompt,
+  getDisplayNameForLanguage,
+  mapLocaleToWhisperLanguage,
+} from "../utils/language.utils";
+import {
   buildLocalizedTranscriptionPrompt,
   buildSystemPostProcessingTonePrompt,
   collectDictionaryEntries,
@@ -31,7 +34,9 @@ import {
   PROCESSED_TRANSCRIPTION_SCHEMA,
 } from "../utils/prompt.utils";
 import {
+  getGenerativePrefs,
   getMyDictationLanguage,
+  getMyDictationLanguageCandidates,
   getMyEffectiveUserId,
 } from "../utils/user.utils";
 import { showErrorSnackbar } from "./app.actions";
@@ -49,6 +54,9 @@ export type TranscribeAudioMetadata = {
   transcriptionApiKeyId?: string | null;
   transcriptionMode?: TranscriptionMode | null;
   transcriptionDurationMs?: number | null;
+  sourceLanguage?: string | null;
+  detectedLanguage?: string | null;
+  targetLanguage?: string | null;
 };
 
 export type TranscribeAudioResult = {
@@ -61,6 +69,13 @@ export type PostProcessInput = {
   rawTranscript: string;
   toneId: Nullable<string>;
   a11yInfo: Nullable<TextFieldInfo>;
+  appName?: Nullable<string>;
+  // Language the raw transcript is in, when transcription reported one.
+  transcriptLanguage?: Nullable<string>;
+  // Language the rewrite translates the transcript to, if not done already.
+  translateTo?: Nullable<string>;
+  // Stored transcription the backend writes the rewrite to, if any.
+  transcriptionId?: Nullable<string>;
 };
 
 export type PostProcessMetadata = {
@@ -69,6 +84,28 @@ export type PostProcessMetadata = {
   postProcessMode?: PostProcessingMode | null;
   postProcessDevice?: string | null;
   postprocessDurationMs?: number | null;
+  targetLanguage?: string | null;
+};
+
+type BackendPostProcessOutcome = {
+  transcript: string;
+  postProcessPrompt: string | null;
+  postProcessMode: PostProcessingMode;
+  postProcessDevice: string | null;
+  postprocessDurationMs: number | null;
+  targetLanguage: string | null;
+  warnings: string[];
+};
+
+type PostProcessFinishedPayload = {
+  requestId: string;
+  outcome: BackendPostProcessOutcome;
+};
+
+type PostProcessPromptResult = {
+  prompt: string;
+  targetLanguage: string | null;
+  warnings: string[];
 };
 
 export type PostProcessResult = {
@@ -103,10 +140,20 @@ export const transcribeAudio = async ({
   } = getTranscribeAudioRepo();
   warnings.push(...transcribeWarnings);
 
-  const dictationLanguage = getMyDictationLanguage(state);
+  const appProfile = state.activeAppProfile;
+  const dictationLanguage =
+    appProfile?.language ?? getMyDictationLanguage(state);
   const whisperLanguage = mapLocaleToWhisperLanguage(dictationLanguage);
-
-  const dictionaryEntries = collectDictionaryEntries(state);
+  const translationLanguage = appProfile?.translationLanguage ?? null;
+  // A per-app language always wins over detection.
+  const languageCandidates = appProfile?.language
+    ? []
+    : getMyDictationLanguageCandidates(state).map(mapLocaleToWhisperLanguage);
+
+  const dictionaryEntries = collectDictionaryEntries(
+    state,
+    appProfile?.termIds,
+  );
   const baseTranscriptionPrompt =
     buildLocalizedTranscriptionPrompt(dictionaryEntries);
   const transcriptionPrompt = (() => {
@@ -130,17 +177,26 @@ export const transcribeAudio = async ({
     sampleRate,
     prompt: transcriptionPrompt,
     language: whisperLanguage,
+    translateToEnglish: translationLanguage === "en",
+    languageCandidates,
   });
   const transcribeDuration = performance.now() - transcribeStart;
   const rawTranscript = transcribeOutput.text.trim();
 
-  metadata.modelSize = state.settings.aiTranscription.modelSize || null;
+  metadata.modelSize =
+    appProfile?.modelSize || state.settings.aiTranscription.modelSize || null;
   metadata.inferenceDevice = transcribeOutput.metadata?.inferenceDevice || null;
   metadata.transcriptionDurationMs = Math.round(transcribeDuration);
   metadata.transcriptionPrompt = transcriptionPrompt;
   metadata.transcriptionApiKeyId = transcriptionApiKeyId;
   metadata.transcriptionMode =
     transcribeOutput.metadata?.transcriptionMode || null;
+  metadata.sourceLanguage =
+    transcribeOutput.languageProbability != null
+      ? null
+      : whisperLanguage || null;
+  metadata.detectedLanguage = transcribeOutput.detectedLanguage ?? null;
+  metadata.targetLanguage = transcribeOutput.translated ? "en" : null;
 
   return {
     rawTranscript,
@@ -157,6 +213,10 @@ export const postProcessTranscript = async ({
   rawTranscript,
   toneId,
   a11yInfo,
+  appName,
+  transcriptLanguage,
+  translateTo,
+  transcriptionId,
 }: PostProcessInput): Promise<PostProcessResult> => {
   const state = getAppState();
 
@@ -171,22 +231,63 @@ export const postProcessTranscript = async ({
   warnings.push(...genWarnings);
 
   let processedTranscript = rawTranscript;
+  const generativePrefs = getGenerativePrefs(state);
+  const tone =
+    getRec(state.toneById, toneId) ??
+    getRec(state.toneById, state.userPrefs?.activeToneId) ??
+    null;
+  const textFieldContext = extractTextFieldContext(a11yInfo);
+  const dictationLanguage =
+    transcriptLanguage ??
+    state.activeAppProfile?.language ??
+    getMyDictationLanguage(state);
+  const promptArgs = {
+    transcript: rawTranscript,
+    dictationLanguage,
+    toneId,
+    promptTemplate: tone?.promptTemplate ?? null,
+    textFieldContext: textFieldContext ?? null,
+    appName: appName ?? null,
+    targetLanguage: translateTo ?? null,
+  };
 
-  if (genRepo) {
-    const dictationLanguage = getMyDictationLanguage(state);
-    const myPrefs = state.userPrefs;
-    const tone =
-      getRec(state.toneById, toneId) ??
-      getRec(state.toneById, myPrefs?.activeToneId) ??
-      null;
-
-    const textFieldContext = extractTextFieldContext(a11yInfo);
-    const ppPrompt = buildLocalizedPostProcessingPrompt({
-      transcript: rawTranscript,
-      dictationLanguage,
-      toneTemplate: tone?.promptTemplate ?? null,
-      textFieldContext: textFieldContext ?? null,
+  if (
+    genRepo &&
+    generativePrefs.mode === "api" &&
+    generativePrefs.provider === "ollama"
+  ) {
+    // Ollama rewrites run in the backend so they survive window reloads:
+    // the backend saves the result to the transcription row itself.
+    try {
+      const outcome = await runBackendPostProcess({
+        ...promptArgs,
+        transcriptionId: transcriptionId ?? null,
+        apiKeyId: genApiKeyId,
+      });
+
+      processedTranscript = outcome.transcript;
+      warnings.push(...outcome.warnings);
+      metadata.postprocessDurationMs = outcome.postprocessDurationMs;
+      metadata.postProcessPrompt = outcome.postProcessPrompt;
+      metadata.postProcessApiKeyId = genApiKeyId;
+      metadata.postProcessMode = outcome.postProcessMode;
+      metadata.postProcessDevice = outcome.postProcessDevice;
+      metadata.targetLanguage = outcome.targetLanguage;
+    } catch (error) {
+      console.error("Backend post-processing failed", error);
+      const message = error instanceof Error ? error.message : String(error);
+      warnings.push(`Post-processing failed: ${message}`);
+      metadata.postProcessMode = "none";
+    }
+  } else if (genRepo) {
+    const {
+      prompt: ppPrompt,
+      targetLanguage,
+      warnings: promptWarnings,
+    } = await invoke<PostProcessPromptResult>("post_process_prompt", {
+      args: promptArgs,
     });
+    warnings.push(...promptWarnings);
 
     const ppSystem = buildSystemPostProcessingTonePrompt();
 
@@ -214,6 +315,7 @@ export const postProcessTranscript = async ({
       } else {
         processedTranscript =
           validationResult.data.processedTranscription.trim();
+        metadata.targetLanguage = targetLanguage;
       }
     } catch (e) {
       warnings.push(
@@ -229,6 +331,12 @@ export const postProcessTranscript = async ({
     metadata.postProcessMode = "none";
   }
 
+  if (translateTo && !genRepo && processedTranscript.trim()) {
+    warnings.push(
+      `Translation to ${getDisplayNameForLanguage(translateTo)} needs an AI post-processing model.`,
+    );
+  }
+
   if (a11yInfo) {
     processedTranscript = applySpacingInContext({
       textToInsert: processedTranscript,
@@ -245,6 +353,47 @@ export const postProcessTranscript = async ({
   };
 };
 
+// As long as one backend attempt may take. A rewrite that finishes later is
+// still saved to its transcription, but the dictation no longer waits for it.
+const BACKEND_POST_PROCESS_TIMEOUT_MS = 120_000;
+
+/**
+ * Start an Ollama rewrite in the backend and wait for it to be announced.
+ * The listener is registered first so a fast reply cannot be missed.
+ */
+const runBackendPostProcess = async (
+  args: Record<string, unknown>,
+): Promise<BackendPostProcessOutcome> => {
+  const requestId = createId();
+  let resolveOutcome: (outcome: BackendPostProcessOutcome) => void = () => {};
+  let timeout: ReturnType<typeof setTimeout> | undefined;
+  const finished = new Promise<BackendPostProcessOutcome>((resolve, reject) => {
+    resolveOutcome = resolve;
+    timeout = setTimeout(
+      () => reject(new Error("the rewrite took too long")),
+      BACKEND_POST_PROCESS_TIMEOUT_MS,
+    );
+  });
+  const unlisten = await listen<PostProcessFinishedPayload>(
+    "post_process_finished",
+    (event) => {
+      if (event.payload.requestId === requestId) {
+        resolveOutcome(event.payload.outcome);
+      }
+    },
+  );
+
+  try {
+    await invoke<void>("post_process_transcript", {
+      args: { ...args, requestId },
+    });
+    return await finished;
+  } finally {
+    clearTimeout(timeout);
+    unlisten();
+  }
+};
+
 export type StoreTranscriptionInput = {
   audio: StopRecordingResponse;
   rawTranscript: string | null;
@@ -253,6 +402,14 @@ export type StoreTranscriptionInput = {
   transcriptionMetadata: TranscribeAudioMetadata;
   postProcessMetadata: PostProcessMetadata;
   warnings: string[];
+  appTargetId?: string | null;
+  /** Row stored as a draft earlier, to finish instead of creating one. */
+  transcriptionId?: Nullable<string>;
+  /**
+   * Store the raw transcript before post-processing, so a backend rewrite
+   * has a row to be saved to. Audio and word counts wait for the final store.
+   */
+  draft?: boolean;
 };
 
 export type StoreTranscriptionOutput = {
@@ -283,7 +440,9 @@ export const storeTranscription = async (
 
   if (rate == null || Number.isNaN(rate)) {
     console.error("Received audio payload without sample rate", input.audio);
-    showErrorSnackbar("Recording missing sample rate. Please try again.");
+    if (!input.draft) {
+      showErrorSnackbar("Recording missing sample rate. Please try again.");
+    }
     return { transcription: null, wordCount: 0 };
   }
 
@@ -297,7 +456,7 @@ export const storeTranscription = async (
   const wordsAdded = input.transcript ? countWords(input.transcript) : 0;
 
   if (incognitoEnabled) {
-    if (wordsAdded > 0 && includeInStats) {
+    if (!input.draft && wordsAdded > 0 && includeInStats) {
       try {
         const durationMs =
           input.audio.samples && input.audio.sampleRate
@@ -320,10 +479,13 @@ export const storeTranscription = async (
     return { transcription: null, wordCount: 0 };
   }
 
-  const transcriptionId = createId();
+  const draft = input.transcriptionId
+    ? getRec(state.transcriptionById, input.transcriptionId)
+    : null;
+  const transcriptionId = draft?.id ?? createId();
 
   let audioSnapshot: TranscriptionAudioSnapshot | undefined;
-  if (!incognitoEnabled) {
+  if (!input.draft) {
     try {
       audioSnapshot = await invoke<TranscriptionAudioSnapshot>(
         "store_transcription_audio",
@@ -346,7 +508,7 @@ export const storeTranscription = async (
     transcript: !transcriptionFailed
       ? (input.transcript ?? "")
       : "[Transcription Failed]",
-    createdAt: dayjs().toISOString(),
+    createdAt: draft?.createdAt ?? dayjs().toISOString(),
     createdByUserId: getMyEffectiveUserId(state),
     isDeleted: false,
     audio: audioSnapshot,
@@ -368,13 +530,21 @@ export const storeTranscription = async (
     postprocessDurationMs:
       input.postProcessMetadata.postprocessDurationMs ?? null,
     warnings: input.warnings.length > 0 ? input.warnings : null,
+    appTargetId: input.appTargetId ?? null,
+    sourceLanguage: input.transcriptionMetadata.sourceLanguage ?? null,
+    detectedLanguage: input.transcriptionMetadata.detectedLanguage ?? null,
+    targetLanguage:
+      input.postProcessMetadata.targetLanguage ??
+      input.transcriptionMetadata.targetLanguage ??
+      null,
   };
 
   let storedTranscription: Transcription;
 
   try {
-    storedTranscription =
-      await getTranscriptionRepo().createTranscription(transcription);
+    storedTranscription = draft
+      ? await getTranscriptionRepo().updateTranscription(transcription)
+      : await getTranscriptionRepo().createTranscription(transcription);
   } catch (error) {
     console.error("Failed to store transcription", error);
     showErrorSnackbar("Unable to save transcription. Please try again.");
@@ -392,6 +562,10 @@ export const storeTranscription = async (
     ];
   });
 
+  if (input.draft) {
+    return { transcription: storedTranscription, wordCount: 0 };
+  }
+
   if (wordsAdded > 0) {
     try {
       await addWordsToCurrentUser(wordsAdded, audioSnapshot?.durationMs);
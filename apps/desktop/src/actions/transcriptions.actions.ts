@@ -2,6 +2,7 @@ import { Transcription } from "@repo/types";
 import { getRec } from "@repo/utilities";
 import { getTranscriptionRepo } from "../repos";
 import { getAppState, produceAppState } from "../store";
+import { loadTermSuggestions } from "./dictionary.actions";
 import { postProcessTranscript, transcribeAudio } from "./transcribe.actions";
 
 export const openTranscriptionDetailsDialog = (transcriptionId: string) => {
@@ -45,6 +46,7 @@ export const retranscribeTranscription = async ({
     rawTranscript: transcribeResult.rawTranscript,
     toneId: toneId ?? null,
     a11yInfo: null,
+    transcriptionId,
   });
 
   const finalTranscript = postProcessResult.transcript;
@@ -84,3 +86,28 @@ export const retranscribeTranscription = async ({
     draft.transcriptionById[transcriptionId] = updated;
   });
 };
+
+export const updateTranscriptText = async (
+  transcriptionId: string,
+  transcript: string,
+): Promise<void> => {
+  const state = getAppState();
+  const transcription = getRec(state.transcriptionById, transcriptionId);
+  if (!transcription) {
+    throw new Error("Transcription not found.");
+  }
+
+  const updated = await getTranscriptionRepo().updateTranscription({
+    ...transcription,
+    transcript,
+  });
+
+  produceAppState((draft) => {
+    draft.transcriptionById[transcriptionId] = updated;
+  });
+
+  // The backend learns replacement suggestions from manual corrections.
+  await loadTermSuggestions().catch((error) => {
+    console.error("Failed to refresh term suggestions", error);
+  });
+};
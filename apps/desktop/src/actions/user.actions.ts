@@ -1,9 +1,11 @@
 import {
   DictationPillVisibility,
+  MicrophoneCalibrationReport,
   Nullable,
   User,
   UserPreferences,
 } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
 import { getUserPreferencesRepo, getUserRepo } from "../repos";
 import { CloudUserRepo } from "../repos/user.repo";
 import { getAppState, produceAppState } from "../store";
@@ -75,11 +77,16 @@ export const createDefaultPreferences = (): UserPreferences => ({
   languageSwitchEnabled: false,
   secondaryDictationLanguage: null,
   activeDictationLanguage: "primary",
+  languageDetectionEnabled: false,
   preferredMicrophone: null,
   ignoreUpdateDialog: false,
   incognitoModeEnabled: false,
   incognitoModeIncludeInStats: false,
   dictationPillVisibility: "while_active",
+  ambientWakePhrase: null,
+  warmMicrophoneMs: null,
+  inputGain: null,
+  voiceCommandKeywordRequired: false,
 });
 
 const updateUserPreferences = async (
@@ -267,6 +274,8 @@ export const persistAiPreferences = async (): Promise<void> => {
       state.settings.languageSwitch.secondaryLanguage ?? null;
     preferences.activeDictationLanguage =
       state.settings.languageSwitch.activeLanguage ?? "primary";
+    preferences.languageDetectionEnabled =
+      state.settings.languageSwitch.autoDetect ?? false;
   }, "Failed to save AI preferences. Please try again.");
 };
 
@@ -402,6 +411,16 @@ export const setLanguageSwitchEnabled = async (
   await persistAiPreferences();
 };
 
+export const setLanguageDetectionEnabled = async (
+  enabled: boolean,
+): Promise<void> => {
+  produceAppState((draft) => {
+    draft.settings.languageSwitch.autoDetect = enabled;
+  });
+
+  await persistAiPreferences();
+};
+
 export const setSecondaryDictationLanguage = async (
   language: Nullable<string>,
 ): Promise<void> => {
@@ -522,6 +541,14 @@ export const setIncognitoModeIncludeInStats = async (
   }, "Failed to save incognito mode stats preference. Please try again.");
 };
 
+export const setVoiceCommandKeywordRequired = async (
+  required: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.voiceCommandKeywordRequired = required;
+  }, "Failed to save voice command preference. Please try again.");
+};
+
 export const setDictationPillVisibility = async (
   visibility: DictationPillVisibility,
 ): Promise<void> => {
@@ -530,6 +557,41 @@ export const setDictationPillVisibility = async (
   }, "Failed to save dictation pill visibility preference. Please try again.");
 };
 
+export const setAmbientWakePhrase = async (
+  phrase: Nullable<string>,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.ambientWakePhrase = phrase?.trim() || null;
+  }, "Failed to save wake phrase. Please try again.");
+};
+
+export const setWarmMicrophoneMs = async (
+  ms: Nullable<number>,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.warmMicrophoneMs = ms && ms > 0 ? ms : null;
+  }, "Failed to save warm microphone preference. Please try again.");
+};
+
+export const setInputGain = async (gain: Nullable<number>): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.inputGain = gain && gain > 0 ? gain : null;
+  }, "Failed to save microphone gain. Please try again.");
+};
+
+// Each device's calibrated gain is applied by the backend. The preferred
+// microphone is left for the user to change.
+export const calibrateMicrophones =
+  async (): Promise<Nullable<MicrophoneCalibrationReport>> => {
+    try {
+      return await invoke<MicrophoneCalibrationReport>("microphone_calibrate");
+    } catch (error) {
+      console.error("Failed to calibrate microphones", error);
+      showErrorSnackbar("Failed to calibrate microphones. Please try again.");
+      return null;
+    }
+  };
+
 export const markUpgradeDialogSeen = async (): Promise<void> => {
   await updateUser(
     (user) => {
@@ -2,6 +2,8 @@ import {
   AddRounded,
   ChatBubbleOutlineRounded,
   DeleteOutlineRounded,
+  StarBorderRounded,
+  StarRounded,
 } from "@mui/icons-material";
 import {
   Box,
@@ -17,6 +19,7 @@ import {
   deleteConversation,
   setActiveConversation,
 } from "../../actions/chat.actions";
+import { setStarred } from "../../actions/retention.actions";
 import { useAppStore } from "../../store";
 
 type ChatConversationListProps = {
@@ -125,8 +128,9 @@ const ConversationItem = memo(
           "&.Mui-selected": {
             backgroundColor: theme.vars?.palette.level2,
           },
-          "& .delete-btn": { opacity: 0 },
-          "&:hover .delete-btn": { opacity: 1 },
+          "& .delete-btn, & .star-btn": { opacity: 0 },
+          "&:hover .delete-btn, &:hover .star-btn": { opacity: 1 },
+          "& .star-btn.starred": { opacity: 1 },
         })}
       >
         <ChatBubbleOutlineRounded
@@ -139,6 +143,23 @@ const ConversationItem = memo(
             sx: { fontSize: 13 },
           }}
         />
+        <IconButton
+          className={conversation.starred ? "star-btn starred" : "star-btn"}
+          size="small"
+          aria-label="Star conversation"
+          aria-pressed={Boolean(conversation.starred)}
+          onClick={(e) => {
+            e.stopPropagation();
+            void setStarred("conversation", id, !conversation.starred);
+          }}
+          sx={{ p: 0.25 }}
+        >
+          {conversation.starred ? (
+            <StarRounded sx={{ fontSize: 14 }} />
+          ) : (
+            <StarBorderRounded sx={{ fontSize: 14 }} />
+          )}
+        </IconButton>
         <IconButton
           className="delete-btn"
           size="small"
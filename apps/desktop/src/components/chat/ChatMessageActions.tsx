@@ -0,0 +1,119 @@
+import {
+  CallSplitRounded,
+  ChevronLeftRounded,
+  ChevronRightRounded,
+  EditRounded,
+  RefreshRounded,
+} from "@mui/icons-material";
+import { Box, IconButton, Typography } from "@mui/material";
+import { memo } from "react";
+import { useIntl } from "react-intl";
+import {
+  forkConversation,
+  regenerateChatMessage,
+  switchChatBranch,
+} from "../../actions/chat.actions";
+import { useAppStore } from "../../store";
+
+type ChatMessageActionsProps = {
+  messageId: string;
+  onEdit: () => void;
+};
+
+const ICON_SX = { fontSize: 14 };
+
+export const ChatMessageActions = memo(
+  ({ messageId, onEdit }: ChatMessageActionsProps) => {
+    const intl = useIntl();
+    const message = useAppStore((state) => state.messageById[messageId]);
+    const siblingIds = useAppStore(
+      (state) => state.chat.siblingIdsByMessageId[messageId],
+    );
+    const isBusy = useAppStore(
+      (state) => state.chat.isLoading || state.chat.isStreaming,
+    );
+
+    if (!message) return null;
+
+    const isUser = message.role === "user";
+    const siblings = siblingIds ?? [messageId];
+    const position = siblings.indexOf(messageId);
+    const { conversationId } = message;
+
+    return (
+      <Box
+        sx={{
+          display: "flex",
+          alignItems: "center",
+          justifyContent: isUser ? "flex-end" : "flex-start",
+          gap: 0.25,
+          mt: 0.25,
+          opacity: 0.7,
+        }}
+      >
+        {siblings.length > 1 && (
+          <>
+            <IconButton
+              size="small"
+              disabled={isBusy || position <= 0}
+              onClick={() =>
+                void switchChatBranch(conversationId, siblings[position - 1])
+              }
+              aria-label={intl.formatMessage({
+                defaultMessage: "Previous version",
+              })}
+            >
+              <ChevronLeftRounded sx={ICON_SX} />
+            </IconButton>
+            <Typography variant="caption" sx={{ fontSize: "0.7rem" }}>
+              {position + 1}/{siblings.length}
+            </Typography>
+            <IconButton
+              size="small"
+              disabled={isBusy || position >= siblings.length - 1}
+              onClick={() =>
+                void switchChatBranch(conversationId, siblings[position + 1])
+              }
+              aria-label={intl.formatMessage({
+                defaultMessage: "Next version",
+              })}
+            >
+              <ChevronRightRounded sx={ICON_SX} />
+            </IconButton>
+          </>
+        )}
+        {isUser ? (
+          <IconButton
+            size="small"
+            disabled={isBusy}
+            onClick={onEdit}
+            aria-label={intl.formatMessage({ defaultMessage: "Edit message" })}
+          >
+            <EditRounded sx={ICON_SX} />
+          </IconButton>
+        ) : (
+          <IconButton
+            size="small"
+            disabled={isBusy}
+            onClick={() =>
+              void regenerateChatMessage(conversationId, messageId)
+            }
+            aria-label={intl.formatMessage({ defaultMessage: "Regenerate" })}
+          >
+            <RefreshRounded sx={ICON_SX} />
+          </IconButton>
+        )}
+        <IconButton
+          size="small"
+          disabled={isBusy}
+          onClick={() => void forkConversation(conversationId, messageId)}
+          aria-label={intl.formatMessage({
+            defaultMessage: "Fork conversation from here",
+          })}
+        >
+          <CallSplitRounded sx={ICON_SX} />
+        </IconButton>
+      </Box>
+    );
+  },
+);
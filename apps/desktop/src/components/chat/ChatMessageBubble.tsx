@@ -1,11 +1,21 @@
 import { DescriptionRounded } from "@mui/icons-material";
-import { Box, Chip, keyframes, Typography } from "@mui/material";
-import { memo, useMemo } from "react";
+import {
+  Box,
+  Button,
+  Chip,
+  keyframes,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { memo, useCallback, useMemo, useState } from "react";
+import { FormattedMessage } from "react-intl";
 import Markdown from "react-markdown";
 import rehypeSanitize from "rehype-sanitize";
 import remarkGfm from "remark-gfm";
+import { editChatMessage } from "../../actions/chat.actions";
 import type { MessageAttachment } from "../../state/chat.state";
 import { useAppStore } from "../../store";
+import { ChatMessageActions } from "./ChatMessageActions";
 
 type ChatMessageBubbleProps = {
   messageId: string;
@@ -35,6 +45,21 @@ export const ChatMessageBubble = memo(
       }
     }, [message?.contextJson]);
 
+    const [draft, setDraft] = useState<string | null>(null);
+
+    const handleEdit = useCallback(() => {
+      setDraft(message?.content ?? "");
+    }, [message?.content]);
+
+    const handleSaveEdit = useCallback(() => {
+      if (!message || draft === null) return;
+      const content = draft.trim();
+      setDraft(null);
+      if (content && content !== message.content) {
+        void editChatMessage(message.conversationId, message.id, content);
+      }
+    }, [message, draft]);
+
     if (!message) return null;
 
     const isUser = message.role === "user";
@@ -43,7 +68,8 @@ export const ChatMessageBubble = memo(
       <Box
         sx={{
           display: "flex",
-          justifyContent: isUser ? "flex-end" : "flex-start",
+          flexDirection: "column",
+          alignItems: isUser ? "flex-end" : "flex-start",
           width: "100%",
         }}
       >
@@ -88,7 +114,36 @@ export const ChatMessageBubble = memo(
               ))}
             </Box>
           )}
-          {isUser ? (
+          {isUser && draft !== null ? (
+            <Box sx={{ minWidth: 280 }}>
+              <TextField
+                value={draft}
+                onChange={(event) => setDraft(event.target.value)}
+                multiline
+                fullWidth
+                autoFocus
+                size="small"
+                sx={(theme) => ({
+                  "& .MuiInputBase-root": {
+                    color: theme.vars?.palette.onBlue,
+                    fontSize: 14,
+                  },
+                })}
+              />
+              <Box sx={{ display: "flex", justifyContent: "flex-end", gap: 1 }}>
+                <Button
+                  size="small"
+                  color="inherit"
+                  onClick={() => setDraft(null)}
+                >
+                  <FormattedMessage defaultMessage="Cancel" />
+                </Button>
+                <Button size="small" color="inherit" onClick={handleSaveEdit}>
+                  <FormattedMessage defaultMessage="Send" />
+                </Button>
+              </Box>
+            </Box>
+          ) : isUser ? (
             <Typography
               variant="body2"
               sx={{
@@ -183,6 +238,9 @@ export const ChatMessageBubble = memo(
             </Typography>
           )}
         </Box>
+        {!isStreamingThis && draft === null && (
+          <ChatMessageActions messageId={messageId} onEdit={handleEdit} />
+        )}
       </Box>
     );
   },
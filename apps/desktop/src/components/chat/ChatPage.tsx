@@ -22,6 +22,9 @@ export default function ChatPage() {
   const isLoading = useAppStore((state) => state.chat.isLoading);
   const isStreaming = useAppStore((state) => state.chat.isStreaming);
   const pendingQuery = useAppStore((state) => state.chat.pendingQuickBarQuery);
+  const incognito = useAppStore(
+    (state) => state.userPrefs?.incognitoModeEnabled ?? false,
+  );
   const pendingHandled = useRef(false);
 
   useEffect(() => {
@@ -36,7 +39,7 @@ export default function ChatPage() {
 
   const handleSend = useCallback(
     async (content: string) => {
-      if (!content.trim()) return;
+      if (!content.trim() || incognito) return;
       let convId = activeConversationId;
       if (!convId) {
         convId = await createNewConversation();
@@ -45,7 +48,7 @@ export default function ChatPage() {
         await sendChatMessage(convId, content);
       }
     },
-    [activeConversationId],
+    [activeConversationId, incognito],
   );
 
   const handleNewConversation = useCallback(() => {
@@ -117,9 +120,18 @@ export default function ChatPage() {
               <ChatMessageList />
             )}
           </Box>
+          {incognito && (
+            <Typography
+              variant="body2"
+              color="text.secondary"
+              sx={{ px: 3, pb: 1 }}
+            >
+              <FormattedMessage defaultMessage="Chat is unavailable while incognito mode is on, because conversations are built from saved history." />
+            </Typography>
+          )}
           <ChatInput
             onSend={handleSend}
-            disabled={isLoading || isStreaming}
+            disabled={isLoading || isStreaming || incognito}
             isStreaming={isStreaming}
             onStop={stopChatStream}
           />
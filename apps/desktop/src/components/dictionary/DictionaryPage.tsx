@@ -1,11 +1,16 @@
-import { FindReplaceOutlined, SpellcheckOutlined } from "@mui/icons-material";
+import {
+  AutoFixHighOutlined,
+  FindReplaceOutlined,
+  SpellcheckOutlined,
+} from "@mui/icons-material";
 import AddRoundedIcon from "@mui/icons-material/AddRounded";
-import { Button } from "@mui/material";
+import { Badge, Button, Stack } from "@mui/material";
 import { Term } from "@repo/types";
 import dayjs from "dayjs";
-import { useCallback } from "react";
+import { useCallback, useEffect } from "react";
 import { FormattedMessage } from "react-intl";
 import { showErrorSnackbar } from "../../actions/app.actions";
+import { loadTermSuggestions } from "../../actions/dictionary.actions";
 import { getTermRepo } from "../../repos";
 import { produceAppState, useAppStore } from "../../store";
 import { createId } from "../../utils/id.utils";
@@ -15,6 +20,19 @@ import { DictionaryRow } from "./DictionaryRow";
 
 export default function DictionaryPage() {
   const termIds = useAppStore((state) => state.dictionary.termIds);
+  const suggestionCount = useAppStore(
+    (state) => state.dictionary.suggestions.length,
+  );
+
+  useEffect(() => {
+    loadTermSuggestions().catch(showErrorSnackbar);
+  }, []);
+
+  const handleOpenSuggestions = useCallback(() => {
+    produceAppState((draft) => {
+      draft.dictionary.suggestionsDialogOpen = true;
+    });
+  }, []);
 
   const handleAddTerm = useCallback(async (replacement: boolean) => {
     const newTerm: Term = {
@@ -90,7 +108,24 @@ export default function DictionaryPage() {
       subtitle={
         <FormattedMessage defaultMessage="OS Voice may misunderstand you on occasion. If you see certain words being missed frequently, you can define a replacement rule here to fix the spelling automatically." />
       }
-      action={addButton}
+      action={
+        <Stack direction="row" spacing={1}>
+          {suggestionCount > 0 && (
+            <Button
+              variant="text"
+              startIcon={
+                <Badge badgeContent={suggestionCount} color="primary">
+                  <AutoFixHighOutlined />
+                </Badge>
+              }
+              onClick={handleOpenSuggestions}
+            >
+              <FormattedMessage defaultMessage="Suggestions" />
+            </Button>
+          )}
+          {addButton}
+        </Stack>
+      }
       items={termIds}
       computeItemKey={(id) => id}
       heightMult={10}
@@ -0,0 +1,123 @@
+import ArrowForwardRoundedIcon from "@mui/icons-material/ArrowForwardRounded";
+import {
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  List,
+  ListItem,
+  ListItemText,
+  Stack,
+  Typography,
+} from "@mui/material";
+import type { TermSuggestion } from "@repo/types";
+import { useState } from "react";
+import { FormattedMessage } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import {
+  acceptTermSuggestion,
+  rejectTermSuggestion,
+} from "../../actions/dictionary.actions";
+import { produceAppState, useAppStore } from "../../store";
+
+export const TermSuggestionsDialog = () => {
+  const open = useAppStore((state) => state.dictionary.suggestionsDialogOpen);
+  const suggestions = useAppStore((state) => state.dictionary.suggestions);
+  const [busyId, setBusyId] = useState<string | null>(null);
+
+  const handleClose = () => {
+    produceAppState((draft) => {
+      draft.dictionary.suggestionsDialogOpen = false;
+    });
+  };
+
+  const review = async (id: string, action: () => Promise<void>) => {
+    setBusyId(id);
+    try {
+      await action();
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setBusyId(null);
+    }
+  };
+
+  const renderSuggestion = (suggestion: TermSuggestion) => (
+    <ListItem
+      key={suggestion.id}
+      disableGutters
+      secondaryAction={
+        <Stack direction="row" spacing={1}>
+          <Button
+            size="small"
+            disabled={busyId === suggestion.id}
+            onClick={() =>
+              review(suggestion.id, () => rejectTermSuggestion(suggestion.id))
+            }
+          >
+            <FormattedMessage defaultMessage="Dismiss" />
+          </Button>
+          <Button
+            size="small"
+            variant="contained"
+            disabled={busyId === suggestion.id}
+            onClick={() =>
+              review(suggestion.id, () => acceptTermSuggestion(suggestion))
+            }
+          >
+            <FormattedMessage defaultMessage="Add" />
+          </Button>
+        </Stack>
+      }
+    >
+      <ListItemText
+        primary={
+          <Stack direction="row" spacing={1} alignItems="center">
+            <span>{suggestion.sourceValue}</span>
+            <ArrowForwardRoundedIcon fontSize="small" color="action" />
+            <strong>{suggestion.destinationValue}</strong>
+          </Stack>
+        }
+        secondary={
+          <FormattedMessage
+            defaultMessage="Corrected in {count} transcripts · {confidence}% of the time"
+            values={{
+              count: suggestion.occurrences,
+              confidence: Math.round(suggestion.confidence * 100),
+            }}
+          />
+        }
+      />
+    </ListItem>
+  );
+
+  return (
+    <Dialog open={open} onClose={handleClose} fullWidth maxWidth="sm">
+      <DialogTitle>
+        <FormattedMessage defaultMessage="Suggested replacements" />
+      </DialogTitle>
+      <DialogContent>
+        <Stack spacing={2}>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="These corrections keep coming up when you edit your transcripts. Add them to your dictionary to fix them automatically." />
+          </Typography>
+          {suggestions.length > 0 ? (
+            <List dense disablePadding>
+              {suggestions.map(renderSuggestion)}
+            </List>
+          ) : (
+            <Typography variant="body2" color="text.secondary">
+              <FormattedMessage defaultMessage="No suggestions right now." />
+            </Typography>
+          )}
+        </Stack>
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={handleClose}>
+          <FormattedMessage defaultMessage="Close" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
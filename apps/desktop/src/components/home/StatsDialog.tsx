@@ -7,6 +7,7 @@ import {
   DialogTitle,
   LinearProgress,
   Stack,
+  Tooltip,
   Typography,
 } from "@mui/material";
 import {
@@ -14,11 +15,21 @@ import {
   RocketLaunch,
   EmojiEvents,
   TrendingUp,
+  Timer,
 } from "@mui/icons-material";
+import type { ModelLatency, UsageAnalytics, UsagePeriod } from "@repo/types";
+import dayjs from "dayjs";
+import { useEffect, useMemo, useState } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { getAnalyticsRepo } from "../../repos";
 import { produceAppState, useAppStore } from "../../store";
+import { formatDuration } from "../../utils/format.utils";
 import { getMyUser } from "../../utils/user.utils";
 
+const RECENT_DAYS = 14;
+const TOP_APPS = 5;
+
 const formatNumber = (num: number): string => {
   if (num >= 1000000) {
     return `${(num / 1000000).toFixed(1)}M`;
@@ -58,16 +69,94 @@ const StatCard = ({ icon, label, value, color }: StatCardProps) => (
   </Box>
 );
 
+const formatPercent = (rate: number): string => `${Math.round(rate * 100)}%`;
+
+const getRecentDays = (wordsByDay: UsagePeriod[]): UsagePeriod[] => {
+  const byPeriod = new Map(wordsByDay.map((day) => [day.period, day]));
+  return Array.from({ length: RECENT_DAYS }, (_, index) => {
+    const period = dayjs()
+      .subtract(RECENT_DAYS - 1 - index, "day")
+      .format("YYYY-MM-DD");
+    return (
+      byPeriod.get(period) ?? {
+        period,
+        transcriptions: 0,
+        words: 0,
+        speakingMs: 0,
+      }
+    );
+  });
+};
+
+const RecentDaysChart = ({ days }: { days: UsagePeriod[] }) => {
+  const maxWords = Math.max(1, ...days.map((day) => day.words));
+  return (
+    <Stack direction="row" spacing={0.5} alignItems="flex-end" height={64}>
+      {days.map((day) => (
+        <Tooltip
+          key={day.period}
+          title={`${dayjs(day.period).format("MMM D")}: ${day.words.toLocaleString()}`}
+        >
+          <Box
+            sx={{
+              flex: 1,
+              height: `${Math.max(4, (day.words / maxWords) * 100)}%`,
+              borderRadius: 1,
+              bgcolor: day.words > 0 ? "#4CAF50" : "action.disabledBackground",
+            }}
+          />
+        </Tooltip>
+      ))}
+    </Stack>
+  );
+};
+
+type UsageRowProps = {
+  label: React.ReactNode;
+  detail: React.ReactNode;
+};
+
+const UsageRow = ({ label, detail }: UsageRowProps) => (
+  <Stack direction="row" justifyContent="space-between" spacing={2}>
+    <Typography variant="body2" noWrap>
+      {label}
+    </Typography>
+    <Typography variant="body2" color="text.secondary" noWrap>
+      {detail}
+    </Typography>
+  </Stack>
+);
+
+const getModelLabel = (latency: ModelLatency): string =>
+  [latency.mode, latency.model].filter(Boolean).join(" · ") || "—";
+
 export const StatsDialog = () => {
   const intl = useIntl();
   const open = useAppStore((state) => state.settings.statsDialogOpen);
   const user = useAppStore(getMyUser);
-  const transcriptions = useAppStore((state) =>
-    Object.values(state.transcriptionById),
+  const [analytics, setAnalytics] = useState<UsageAnalytics | null>(null);
+
+  useEffect(() => {
+    if (!open) {
+      return;
+    }
+
+    getAnalyticsRepo()
+      .getUsage({ utcOffsetMinutes: -new Date().getTimezoneOffset() })
+      .then(setAnalytics)
+      .catch(showErrorSnackbar);
+  }, [open]);
+
+  const recentDays = useMemo(
+    () => getRecentDays(analytics?.wordsByDay ?? []),
+    [analytics],
   );
 
-  const wordsTotal = user?.wordsTotal ?? 0;
-  const wordsThisMonth = user?.wordsThisMonth ?? 0;
+  const monthStart = dayjs().startOf("month").format("YYYY-MM-DD");
+  const wordsTotal = analytics?.totals.words ?? 0;
+  const wordsThisMonth = (analytics?.wordsByDay ?? [])
+    .filter((day) => day.period >= monthStart)
+    .reduce((sum, day) => sum + day.words, 0);
 
   const weeksActive = Math.max(
     1,
@@ -78,20 +167,10 @@ export const StatsDialog = () => {
     ),
   );
 
-  const avgWpm =
-    transcriptions.length > 0
-      ? Math.round(
-          transcriptions.reduce((sum, t) => {
-            const words = t.transcript?.split(/\s+/).length ?? 0;
-            const duration = t.audio?.durationMs
-              ? t.audio.durationMs / 60000
-              : 1;
-            return sum + (duration > 0 ? words / duration : 0);
-          }, 0) / transcriptions.length,
-        )
-      : 0;
-
-  const totalTranscriptions = transcriptions.length;
+  const avgWpm = Math.round(analytics?.totals.averageWpm ?? 0);
+  const totalTranscriptions = analytics?.totals.transcriptions ?? 0;
+  const topApps = (analytics?.apps ?? []).slice(0, TOP_APPS);
+  const models = analytics?.models ?? [];
   const monthlyGoal = 10000;
   const monthlyProgress = Math.min((wordsThisMonth / monthlyGoal) * 100, 100);
 
@@ -129,6 +208,12 @@ export const StatsDialog = () => {
               value={avgWpm}
               color="#FFB800"
             />
+            <StatCard
+              icon={<Timer sx={{ fontSize: 28 }} />}
+              label={intl.formatMessage({ defaultMessage: "Time Saved" })}
+              value={formatDuration(analytics?.totals.timeSavedMs ?? 0)}
+              color="#4CAF50"
+            />
           </Stack>
 
           <Box sx={{ p: 2, borderRadius: 2, bgcolor: "action.hover" }}>
@@ -186,6 +271,83 @@ export const StatsDialog = () => {
               </Typography>
             </Box>
           </Stack>
+
+          <Box sx={{ p: 2, borderRadius: 2, bgcolor: "action.hover" }}>
+            <Stack spacing={1.5}>
+              <Typography variant="body1" fontWeight={500}>
+                <FormattedMessage defaultMessage="Words per Day" />
+              </Typography>
+              <RecentDaysChart days={recentDays} />
+            </Stack>
+          </Box>
+
+          {topApps.length > 0 && (
+            <Box sx={{ p: 2, borderRadius: 2, bgcolor: "action.hover" }}>
+              <Stack spacing={1}>
+                <Typography variant="body1" fontWeight={500}>
+                  <FormattedMessage defaultMessage="Top Apps" />
+                </Typography>
+                {topApps.map((app) => (
+                  <UsageRow
+                    key={app.appTargetId ?? "unknown"}
+                    label={
+                      app.name ??
+                      intl.formatMessage({ defaultMessage: "Other apps" })
+                    }
+                    detail={
+                      <FormattedMessage
+                        defaultMessage="{words} words · {count} dictations"
+                        values={{
+                          words: formatNumber(app.words),
+                          count: app.transcriptions,
+                        }}
+                      />
+                    }
+                  />
+                ))}
+              </Stack>
+            </Box>
+          )}
+
+          {models.length > 0 && (
+            <Box sx={{ p: 2, borderRadius: 2, bgcolor: "action.hover" }}>
+              <Stack spacing={1}>
+                <Typography variant="body1" fontWeight={500}>
+                  <FormattedMessage defaultMessage="Model Latency" />
+                </Typography>
+                {models.map((latency) => (
+                  <UsageRow
+                    key={`${latency.stage}-${latency.mode}-${latency.model}`}
+                    label={
+                      latency.stage === "transcription" ? (
+                        <FormattedMessage
+                          defaultMessage="Transcription: {model}"
+                          values={{ model: getModelLabel(latency) }}
+                        />
+                      ) : (
+                        <FormattedMessage
+                          defaultMessage="Post-processing: {model}"
+                          values={{ model: getModelLabel(latency) }}
+                        />
+                      )
+                    }
+                    detail={
+                      <FormattedMessage
+                        defaultMessage="avg {average} · {warnings} warnings"
+                        values={{
+                          average:
+                            latency.averageMs === null
+                              ? "—"
+                              : formatDuration(latency.averageMs),
+                          warnings: formatPercent(latency.warningRate),
+                        }}
+                      />
+                    }
+                  />
+                ))}
+              </Stack>
+            </Box>
+          )}
         </Stack>
       </DialogContent>
       <DialogActions>
@@ -0,0 +1,43 @@
+import { Box, Button, LinearProgress, Stack, Typography } from "@mui/material";
+import type { MediaImportStage } from "@repo/types";
+import { memo } from "react";
+import { FormattedMessage } from "react-intl";
+import { useAppStore } from "../../store";
+import { cancelMediaImport } from "../../actions/media-import.actions";
+
+const StageLabel = ({ stage }: { stage: MediaImportStage }) => {
+  switch (stage) {
+    case "decoding":
+      return <FormattedMessage defaultMessage="Decoding file" />;
+    case "saving":
+      return <FormattedMessage defaultMessage="Saving audio" />;
+    case "transcribing":
+      return <FormattedMessage defaultMessage="Transcribing" />;
+    case "done":
+      return <FormattedMessage defaultMessage="Finishing" />;
+  }
+};
+
+export const MediaImportBar = memo(function MediaImportBar() {
+  const importProgress = useAppStore((s) => s.meeting.importProgress);
+
+  if (!importProgress) return null;
+
+  return (
+    <Box sx={{ px: 2, py: 1.5, borderBottom: 1, borderColor: "divider" }}>
+      <Stack direction="row" alignItems="center" spacing={2}>
+        <Typography variant="body2" fontWeight={600} sx={{ minWidth: 120 }}>
+          <StageLabel stage={importProgress.stage} />
+        </Typography>
+        <LinearProgress
+          variant="determinate"
+          value={Math.round(importProgress.progress * 100)}
+          sx={{ flex: 1 }}
+        />
+        <Button size="small" onClick={() => void cancelMediaImport()}>
+          <FormattedMessage defaultMessage="Cancel" />
+        </Button>
+      </Stack>
+    </Box>
+  );
+});
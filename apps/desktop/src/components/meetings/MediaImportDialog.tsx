@@ -0,0 +1,92 @@
+import {
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  Stack,
+  TextField,
+  ToggleButton,
+  ToggleButtonGroup,
+  Typography,
+} from "@mui/material";
+import type { MediaImportTarget } from "@repo/types";
+import { memo, useCallback, useState } from "react";
+import { FormattedMessage } from "react-intl";
+import { importMediaFile } from "../../actions/media-import.actions";
+
+type Props = {
+  onClose: () => void;
+};
+
+export const MediaImportDialog = memo(function MediaImportDialog({
+  onClose,
+}: Props) {
+  const [path, setPath] = useState("");
+  const [target, setTarget] = useState<MediaImportTarget>("meeting");
+
+  const handleImport = useCallback(() => {
+    const trimmed = path.trim();
+    if (!trimmed) return;
+    void importMediaFile(trimmed, target);
+    onClose();
+  }, [path, target, onClose]);
+
+  const handleKeyDown = useCallback(
+    (e: React.KeyboardEvent) => {
+      if (e.key === "Enter") handleImport();
+    },
+    [handleImport],
+  );
+
+  return (
+    <Dialog open onClose={onClose} maxWidth="sm" fullWidth>
+      <DialogTitle>
+        <FormattedMessage defaultMessage="Import audio or video" />
+      </DialogTitle>
+      <DialogContent>
+        <Stack spacing={2} sx={{ mt: 1 }}>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="WAV, FLAC, MP3, OGG, M4A/AAC, MP4 and MOV files are transcribed on this device." />
+          </Typography>
+          <TextField
+            autoFocus
+            fullWidth
+            size="small"
+            value={path}
+            onChange={(e) => setPath(e.target.value)}
+            onKeyDown={handleKeyDown}
+            placeholder="/path/to/recording.m4a"
+          />
+          <ToggleButtonGroup
+            exclusive
+            size="small"
+            value={target}
+            onChange={(_, value: MediaImportTarget | null) => {
+              if (value) setTarget(value);
+            }}
+          >
+            <ToggleButton value="meeting">
+              <FormattedMessage defaultMessage="Meeting" />
+            </ToggleButton>
+            <ToggleButton value="transcription">
+              <FormattedMessage defaultMessage="Transcription" />
+            </ToggleButton>
+          </ToggleButtonGroup>
+        </Stack>
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={onClose}>
+          <FormattedMessage defaultMessage="Cancel" />
+        </Button>
+        <Button
+          variant="contained"
+          onClick={handleImport}
+          disabled={!path.trim()}
+        >
+          <FormattedMessage defaultMessage="Import" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+});
@@ -1,12 +1,25 @@
-import { CheckCircleOutline } from "@mui/icons-material";
-import { Box, Chip, Stack, Typography } from "@mui/material";
-import { memo, useMemo } from "react";
-
-type ActionItem = {
-  task: string;
-  assignee: string | null;
-  priority: string;
-};
+import {
+  CheckCircleOutline,
+  DeleteOutline,
+  EditOutlined,
+} from "@mui/icons-material";
+import {
+  Box,
+  Checkbox,
+  Chip,
+  IconButton,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { memo, useCallback, useState } from "react";
+import { FormattedMessage } from "react-intl";
+import { useAppStore } from "../../store";
+import {
+  deleteMeetingActionItem,
+  toggleMeetingActionItem,
+  updateMeetingActionItem,
+} from "../../actions/meeting.actions";
 
 const PRIORITY_COLORS: Record<string, "error" | "warning" | "default"> = {
   high: "error",
@@ -14,62 +27,155 @@ const PRIORITY_COLORS: Record<string, "error" | "warning" | "default"> = {
   low: "default",
 };
 
-type Props = {
-  actionItemsJson: string;
+function formatTimestamp(ms: number): string {
+  const totalSec = Math.floor(ms / 1000);
+  const mins = Math.floor(totalSec / 60);
+  const secs = totalSec % 60;
+  return `${mins}:${secs.toString().padStart(2, "0")}`;
+}
+
+type RowProps = {
+  itemId: string;
 };
 
-export const MeetingActionItems = memo(function MeetingActionItems({
-  actionItemsJson,
-}: Props) {
-  const items = useMemo((): ActionItem[] => {
-    try {
-      const parsed = JSON.parse(actionItemsJson) as ActionItem[];
-      return Array.isArray(parsed) ? parsed : [];
-    } catch {
-      return [];
-    }
-  }, [actionItemsJson]);
+const MeetingActionItemRow = memo(function MeetingActionItemRow({
+  itemId,
+}: RowProps) {
+  const item = useAppStore((s) => s.meetingActionItemById[itemId]);
+  const source = useAppStore((s) => {
+    const segmentId = s.meetingActionItemById[itemId]?.sourceSegmentId;
+    return segmentId ? s.meetingSegmentById[segmentId] : undefined;
+  });
+  const [editing, setEditing] = useState(false);
+  const [ownerDraft, setOwnerDraft] = useState("");
+  const [dueDateDraft, setDueDateDraft] = useState("");
+
+  const handleToggle = useCallback(() => {
+    void toggleMeetingActionItem(itemId);
+  }, [itemId]);
+
+  const handleDelete = useCallback(() => {
+    void deleteMeetingActionItem(itemId);
+  }, [itemId]);
+
+  const handleEdit = useCallback(() => {
+    setOwnerDraft(item?.owner ?? "");
+    setDueDateDraft(item?.dueDate ?? "");
+    setEditing(true);
+  }, [item?.owner, item?.dueDate]);
+
+  const handleSave = useCallback(() => {
+    void updateMeetingActionItem(itemId, {
+      owner: ownerDraft.trim() || null,
+      dueDate: dueDateDraft || null,
+    });
+    setEditing(false);
+  }, [itemId, ownerDraft, dueDateDraft]);
+
+  const handleKeyDown = useCallback(
+    (e: React.KeyboardEvent) => {
+      if (e.key === "Enter") handleSave();
+      if (e.key === "Escape") setEditing(false);
+    },
+    [handleSave],
+  );
+
+  if (!item) return null;
+
+  const done = item.status === "done";
+  const details = [
+    item.owner,
+    item.dueDate,
+    source ? formatTimestamp(source.startMs) : null,
+  ].filter(Boolean);
+
+  return (
+    <Stack
+      direction="row"
+      spacing={1}
+      alignItems="flex-start"
+      sx={{ p: 1, borderRadius: 1.5, bgcolor: "action.hover" }}
+    >
+      <Checkbox
+        size="small"
+        checked={done}
+        onChange={handleToggle}
+        sx={{ p: 0.5 }}
+      />
+      <Box sx={{ flex: 1, minWidth: 0, pt: 0.5 }}>
+        <Typography
+          variant="body2"
+          sx={{
+            textDecoration: done ? "line-through" : "none",
+            color: done ? "text.secondary" : "text.primary",
+          }}
+        >
+          {item.task}
+        </Typography>
+        {editing ? (
+          <Stack direction="row" spacing={1} sx={{ mt: 1 }}>
+            <TextField
+              autoFocus
+              size="small"
+              variant="standard"
+              placeholder="Owner"
+              value={ownerDraft}
+              onChange={(e) => setOwnerDraft(e.target.value)}
+              onKeyDown={handleKeyDown}
+            />
+            <TextField
+              size="small"
+              variant="standard"
+              type="date"
+              value={dueDateDraft}
+              onChange={(e) => setDueDateDraft(e.target.value)}
+              onKeyDown={handleKeyDown}
+              onBlur={handleSave}
+            />
+          </Stack>
+        ) : (
+          details.length > 0 && (
+            <Typography variant="caption" color="text.secondary">
+              {details.join(" · ")}
+            </Typography>
+          )
+        )}
+      </Box>
+      {item.priority && (
+        <Chip
+          label={item.priority}
+          size="small"
+          color={PRIORITY_COLORS[item.priority] ?? "default"}
+          variant="outlined"
+          sx={{ mt: 0.5 }}
+        />
+      )}
+      <IconButton size="small" onClick={handleEdit} aria-label="Edit">
+        <EditOutlined sx={{ fontSize: 16 }} />
+      </IconButton>
+      <IconButton size="small" onClick={handleDelete} aria-label="Delete">
+        <DeleteOutline sx={{ fontSize: 16 }} />
+      </IconButton>
+    </Stack>
+  );
+});
+
+export const MeetingActionItems = memo(function MeetingActionItems() {
+  const itemIds = useAppStore((s) => s.meeting.actionItemIds);
 
-  if (items.length === 0) return null;
+  if (itemIds.length === 0) return null;
 
   return (
     <Box>
       <Stack direction="row" spacing={1} alignItems="center" sx={{ mb: 1.5 }}>
         <CheckCircleOutline sx={{ fontSize: 18, color: "primary.main" }} />
-        <Typography variant="titleSmall">Action Items</Typography>
+        <Typography variant="titleSmall">
+          <FormattedMessage defaultMessage="Action Items" />
+        </Typography>
       </Stack>
       <Stack spacing={1}>
-        {items.map((item, idx) => (
-          <Stack
-            key={idx}
-            direction="row"
-            spacing={1.5}
-            alignItems="flex-start"
-            sx={{
-              p: 1.5,
-              borderRadius: 1.5,
-              bgcolor: "action.hover",
-            }}
-          >
-            <Box sx={{ flex: 1, minWidth: 0 }}>
-              <Typography variant="body2">{item.task}</Typography>
-              {item.assignee && (
-                <Typography
-                  variant="caption"
-                  color="text.secondary"
-                  sx={{ mt: 0.5 }}
-                >
-                  {item.assignee}
-                </Typography>
-              )}
-            </Box>
-            <Chip
-              label={item.priority}
-              size="small"
-              color={PRIORITY_COLORS[item.priority] ?? "default"}
-              variant="outlined"
-            />
-          </Stack>
+        {itemIds.map((id) => (
+          <MeetingActionItemRow key={id} itemId={id} />
         ))}
       </Stack>
     </Box>
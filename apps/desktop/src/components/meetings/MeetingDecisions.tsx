@@ -0,0 +1,50 @@
+import { DeleteOutline, GavelOutlined } from "@mui/icons-material";
+import { Box, IconButton, Stack, Typography } from "@mui/material";
+import { memo } from "react";
+import { FormattedMessage } from "react-intl";
+import { useAppStore } from "../../store";
+import { deleteMeetingDecision } from "../../actions/meeting.actions";
+
+export const MeetingDecisions = memo(function MeetingDecisions() {
+  const decisionIds = useAppStore((s) => s.meeting.decisionIds);
+  const decisionById = useAppStore((s) => s.meetingDecisionById);
+
+  if (decisionIds.length === 0) return null;
+
+  return (
+    <Box>
+      <Stack direction="row" spacing={1} alignItems="center" sx={{ mb: 1.5 }}>
+        <GavelOutlined sx={{ fontSize: 18, color: "primary.main" }} />
+        <Typography variant="titleSmall">
+          <FormattedMessage defaultMessage="Decisions" />
+        </Typography>
+      </Stack>
+      <Stack spacing={1}>
+        {decisionIds.map((id) => {
+          const decision = decisionById[id];
+          if (!decision) return null;
+          return (
+            <Stack
+              key={id}
+              direction="row"
+              spacing={1}
+              alignItems="flex-start"
+              sx={{ p: 1.5, borderRadius: 1.5, bgcolor: "action.hover" }}
+            >
+              <Typography variant="body2" sx={{ flex: 1, minWidth: 0 }}>
+                {decision.text}
+              </Typography>
+              <IconButton
+                size="small"
+                onClick={() => void deleteMeetingDecision(id)}
+                aria-label="Delete"
+              >
+                <DeleteOutline sx={{ fontSize: 16 }} />
+              </IconButton>
+            </Stack>
+          );
+        })}
+      </Stack>
+    </Box>
+  );
+});
@@ -2,6 +2,8 @@ import {
   ArrowBack,
   AutoAwesomeOutlined,
   EditOutlined,
+  StarBorderRounded,
+  StarRounded,
 } from "@mui/icons-material";
 import {
   Box,
@@ -17,14 +19,19 @@ import { FormattedMessage } from "react-intl";
 import { useAppStore } from "../../store";
 import {
   generateMeetingSummary,
+  loadMeetingActionItems,
   loadMeetingSegments,
   processMeetingRecording,
   selectMeeting,
   updateMeetingTitle,
 } from "../../actions/meeting.actions";
+import { setStarred } from "../../actions/retention.actions";
 import { MeetingTranscriptTimeline } from "./MeetingTranscriptTimeline";
 import { MeetingSummaryCard } from "./MeetingSummaryCard";
 import { MeetingActionItems } from "./MeetingActionItems";
+import { MeetingDecisions } from "./MeetingDecisions";
+import { MeetingExportMenu } from "./MeetingExportMenu";
+import { MeetingQuestionsPanel } from "./MeetingQuestionsPanel";
 import type { Meeting } from "@repo/types";
 
 type Props = {
@@ -46,6 +53,7 @@ export const MeetingDetailPage = memo(function MeetingDetailPage({
 
   useEffect(() => {
     void loadMeetingSegments(meeting.id);
+    void loadMeetingActionItems(meeting.id);
   }, [meeting.id]);
 
   const handleBack = useCallback(() => {
@@ -128,6 +136,26 @@ export const MeetingDetailPage = memo(function MeetingDetailPage({
               </IconButton>
             </Stack>
           )}
+
+          <IconButton
+            size="small"
+            onClick={() =>
+              void setStarred("meeting", meeting.id, !meeting.starred)
+            }
+            color={meeting.starred ? "primary" : "default"}
+            aria-label="Star"
+            aria-pressed={Boolean(meeting.starred)}
+          >
+            {meeting.starred ? (
+              <StarRounded fontSize="small" />
+            ) : (
+              <StarBorderRounded fontSize="small" />
+            )}
+          </IconButton>
+
+          {segmentIds.length > 0 && (
+            <MeetingExportMenu meetingId={meeting.id} />
+          )}
         </Stack>
       </Box>
 
@@ -168,9 +196,9 @@ export const MeetingDetailPage = memo(function MeetingDetailPage({
 
           {meeting.summary && <MeetingSummaryCard summary={meeting.summary} />}
 
-          {meeting.actionItems && (
-            <MeetingActionItems actionItemsJson={meeting.actionItems} />
-          )}
+          <MeetingDecisions />
+
+          <MeetingActionItems />
 
           {segmentIds.length > 0 &&
             !meeting.summary &&
@@ -186,6 +214,10 @@ export const MeetingDetailPage = memo(function MeetingDetailPage({
               </Button>
             )}
 
+          {segmentIds.length > 0 && (
+            <MeetingQuestionsPanel meetingId={meeting.id} />
+          )}
+
           {segmentIds.length > 0 && (
             <MeetingTranscriptTimeline meetingId={meeting.id} />
           )}
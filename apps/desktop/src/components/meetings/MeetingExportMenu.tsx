@@ -0,0 +1,86 @@
+import { FileDownloadOutlined } from "@mui/icons-material";
+import {
+  Checkbox,
+  Divider,
+  IconButton,
+  ListItemIcon,
+  ListItemText,
+  Menu,
+  MenuItem,
+} from "@mui/material";
+import type { TranscriptExportFormat } from "@repo/types";
+import { memo, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { exportMeeting } from "../../actions/meeting.actions";
+
+type Props = {
+  meetingId: string;
+};
+
+const FORMATS: { format: TranscriptExportFormat; label: string }[] = [
+  { format: "markdown", label: "Markdown (.md)" },
+  { format: "text", label: "Plain text (.txt)" },
+  { format: "srt", label: "Subtitles (.srt)" },
+  { format: "vtt", label: "WebVTT (.vtt)" },
+  { format: "json", label: "JSON (.json)" },
+];
+
+export const MeetingExportMenu = memo(function MeetingExportMenu({
+  meetingId,
+}: Props) {
+  const intl = useIntl();
+  const [anchor, setAnchor] = useState<HTMLElement | null>(null);
+  const [speakerLabels, setSpeakerLabels] = useState(true);
+  const [mergeSpeakers, setMergeSpeakers] = useState(true);
+
+  const handleExport = (format: TranscriptExportFormat) => {
+    setAnchor(null);
+    void exportMeeting(meetingId, {
+      format,
+      speakerLabels,
+      mergeSpeakers,
+      timestamps:
+        format === "srt" || format === "vtt" ? "milliseconds" : "seconds",
+    });
+  };
+
+  return (
+    <>
+      <IconButton
+        size="small"
+        onClick={(e) => setAnchor(e.currentTarget)}
+        aria-label={intl.formatMessage({ defaultMessage: "Export" })}
+      >
+        <FileDownloadOutlined fontSize="small" />
+      </IconButton>
+      <Menu
+        anchorEl={anchor}
+        open={anchor != null}
+        onClose={() => setAnchor(null)}
+      >
+        {FORMATS.map(({ format, label }) => (
+          <MenuItem key={format} onClick={() => handleExport(format)}>
+            {label}
+          </MenuItem>
+        ))}
+        <Divider />
+        <MenuItem dense onClick={() => setSpeakerLabels((value) => !value)}>
+          <ListItemIcon>
+            <Checkbox size="small" edge="start" checked={speakerLabels} />
+          </ListItemIcon>
+          <ListItemText>
+            <FormattedMessage defaultMessage="Speaker labels" />
+          </ListItemText>
+        </MenuItem>
+        <MenuItem dense onClick={() => setMergeSpeakers((value) => !value)}>
+          <ListItemIcon>
+            <Checkbox size="small" edge="start" checked={mergeSpeakers} />
+          </ListItemIcon>
+          <ListItemText>
+            <FormattedMessage defaultMessage="Merge lines from the same speaker" />
+          </ListItemText>
+        </MenuItem>
+      </Menu>
+    </>
+  );
+});
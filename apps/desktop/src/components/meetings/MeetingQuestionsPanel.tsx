@@ -0,0 +1,154 @@
+import { QuestionAnswerOutlined, SendRounded } from "@mui/icons-material";
+import {
+  Box,
+  Chip,
+  CircularProgress,
+  IconButton,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { memo, useCallback, useEffect, useMemo, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import type { MessageAttachment } from "../../state/chat.state";
+import { useAppStore } from "../../store";
+import {
+  askMeetingQuestion,
+  loadMeetingQuestions,
+} from "../../actions/meeting.actions";
+
+const scrollToSegment = (segmentId: string): void => {
+  document
+    .getElementById(`meeting-segment-${segmentId}`)
+    ?.scrollIntoView({ behavior: "smooth", block: "center" });
+};
+
+const MeetingQuestionMessage = memo(function MeetingQuestionMessage({
+  messageId,
+}: {
+  messageId: string;
+}) {
+  const message = useAppStore((s) => s.messageById[messageId]);
+
+  const citations = useMemo((): MessageAttachment[] => {
+    if (!message?.contextJson) return [];
+    try {
+      const parsed = JSON.parse(message.contextJson) as MessageAttachment[];
+      return parsed.filter((a) => a.type === "meetingSegment");
+    } catch {
+      return [];
+    }
+  }, [message?.contextJson]);
+
+  if (!message) return null;
+
+  if (message.role === "user") {
+    return (
+      <Typography variant="body2" sx={{ fontWeight: 600 }}>
+        {message.content}
+      </Typography>
+    );
+  }
+
+  return (
+    <Box>
+      <Typography variant="body2" sx={{ whiteSpace: "pre-wrap" }}>
+        {message.content}
+      </Typography>
+      {citations.length > 0 && (
+        <Stack direction="row" flexWrap="wrap" gap={0.5} sx={{ mt: 1 }}>
+          {citations.map((citation, index) => (
+            <Chip
+              key={citation.id}
+              size="small"
+              variant="outlined"
+              label={`[${index + 1}] ${citation.label}`}
+              title={citation.content}
+              onClick={() => scrollToSegment(citation.id)}
+            />
+          ))}
+        </Stack>
+      )}
+    </Box>
+  );
+});
+
+type Props = {
+  meetingId: string;
+};
+
+export const MeetingQuestionsPanel = memo(function MeetingQuestionsPanel({
+  meetingId,
+}: Props) {
+  const intl = useIntl();
+  const messageIds = useAppStore((s) => s.meeting.qaMessageIds);
+  const isAsking = useAppStore((s) => s.meeting.isAsking);
+  const [question, setQuestion] = useState("");
+
+  useEffect(() => {
+    void loadMeetingQuestions(meetingId);
+  }, [meetingId]);
+
+  const handleAsk = useCallback(() => {
+    if (!question.trim()) return;
+    void askMeetingQuestion(meetingId, question);
+    setQuestion("");
+  }, [meetingId, question]);
+
+  const handleKeyDown = useCallback(
+    (e: React.KeyboardEvent) => {
+      if (e.key === "Enter" && !e.shiftKey) {
+        e.preventDefault();
+        handleAsk();
+      }
+    },
+    [handleAsk],
+  );
+
+  return (
+    <Box>
+      <Stack direction="row" spacing={1} alignItems="center" sx={{ mb: 1.5 }}>
+        <QuestionAnswerOutlined sx={{ fontSize: 18, color: "primary.main" }} />
+        <Typography variant="titleSmall">
+          <FormattedMessage defaultMessage="Ask About This Meeting" />
+        </Typography>
+      </Stack>
+
+      {messageIds.length > 0 && (
+        <Stack spacing={1.5} sx={{ mb: 1.5 }}>
+          {messageIds.map((id) => (
+            <MeetingQuestionMessage key={id} messageId={id} />
+          ))}
+        </Stack>
+      )}
+
+      <Stack direction="row" spacing={1} alignItems="center">
+        <TextField
+          fullWidth
+          size="small"
+          multiline
+          maxRows={4}
+          value={question}
+          disabled={isAsking}
+          onChange={(e) => setQuestion(e.target.value)}
+          onKeyDown={handleKeyDown}
+          placeholder={intl.formatMessage({
+            defaultMessage: "What did we decide about the launch date?",
+          })}
+        />
+        {isAsking ? (
+          <CircularProgress size={20} />
+        ) : (
+          <IconButton
+            size="small"
+            onClick={handleAsk}
+            disabled={!question.trim()}
+            aria-label="Ask"
+          >
+            <SendRounded fontSize="small" />
+          </IconButton>
+        )}
+      </Stack>
+    </Box>
+  );
+});
@@ -82,7 +82,12 @@ export const MeetingTranscriptTimeline = memo(
             const displayName = seg.speakerName ?? seg.speakerId ?? "Speaker";
 
             return (
-              <Stack key={seg.id} direction="row" spacing={1.5}>
+              <Stack
+                key={seg.id}
+                id={`meeting-segment-${seg.id}`}
+                direction="row"
+                spacing={1.5}
+              >
                 <Typography
                   variant="caption"
                   sx={{
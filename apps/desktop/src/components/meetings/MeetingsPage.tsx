@@ -1,6 +1,6 @@
-import { MicNoneOutlined } from "@mui/icons-material";
+import { FileUploadOutlined, MicNoneOutlined } from "@mui/icons-material";
 import { Box, Button, List, Stack, Typography } from "@mui/material";
-import { useCallback, useEffect } from "react";
+import { useCallback, useEffect, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import { useAppStore } from "../../store";
 import {
@@ -9,10 +9,13 @@ import {
   selectMeeting,
   startMeetingRecording,
 } from "../../actions/meeting.actions";
+import { MediaImportBar } from "./MediaImportBar";
+import { MediaImportDialog } from "./MediaImportDialog";
 import { MeetingDetailPage } from "./MeetingDetailPage";
 import { MeetingEmptyState } from "./MeetingEmptyState";
 import { MeetingListItem } from "./MeetingListItem";
 import { MeetingRecordingBar } from "./MeetingRecordingBar";
+import { OpenActionItemsList } from "./OpenActionItemsList";
 
 export default function MeetingsPage() {
   const meetingIds = useAppStore((s) => s.meeting.meetingIds);
@@ -21,6 +24,11 @@ export default function MeetingsPage() {
   const activeMeeting = useAppStore((s) =>
     activeMeetingId ? s.meetingById[activeMeetingId] : undefined,
   );
+  const isImporting = useAppStore((s) => s.meeting.importProgress !== null);
+  const incognito = useAppStore(
+    (s) => s.userPrefs?.incognitoModeEnabled ?? false,
+  );
+  const [importDialogOpen, setImportDialogOpen] = useState(false);
   const meetings = useAppStore((s) => {
     return meetingIds.map((id) => s.meetingById[id]).filter(Boolean);
   });
@@ -41,6 +49,14 @@ export default function MeetingsPage() {
     void startMeetingRecording();
   }, []);
 
+  const handleOpenImport = useCallback(() => {
+    setImportDialogOpen(true);
+  }, []);
+
+  const handleCloseImport = useCallback(() => {
+    setImportDialogOpen(false);
+  }, []);
+
   if (activeMeeting && !isRecording) {
     return <MeetingDetailPage meeting={activeMeeting} />;
   }
@@ -48,6 +64,7 @@ export default function MeetingsPage() {
   return (
     <Box sx={{ display: "flex", flexDirection: "column", height: "100%" }}>
       <MeetingRecordingBar />
+      <MediaImportBar />
 
       <Box sx={{ px: 3, pt: 2, pb: 1 }}>
         <Stack
@@ -58,18 +75,36 @@ export default function MeetingsPage() {
           <Typography variant="titleMedium">
             <FormattedMessage defaultMessage="Meetings" />
           </Typography>
-          <Button
-            variant="contained"
-            size="small"
-            startIcon={<MicNoneOutlined />}
-            onClick={handleStartRecording}
-            disabled={isRecording}
-          >
-            <FormattedMessage defaultMessage="Record" />
-          </Button>
+          <Stack direction="row" spacing={1}>
+            <Button
+              variant="outlined"
+              size="small"
+              startIcon={<FileUploadOutlined />}
+              onClick={handleOpenImport}
+              disabled={isRecording || isImporting || incognito}
+            >
+              <FormattedMessage defaultMessage="Import" />
+            </Button>
+            <Button
+              variant="contained"
+              size="small"
+              startIcon={<MicNoneOutlined />}
+              onClick={handleStartRecording}
+              disabled={isRecording || incognito}
+            >
+              <FormattedMessage defaultMessage="Record" />
+            </Button>
+          </Stack>
         </Stack>
+        {incognito && !isRecording && (
+          <Typography variant="body2" color="text.secondary" sx={{ mt: 1 }}>
+            <FormattedMessage defaultMessage="Meetings are not recorded or imported while incognito mode is on." />
+          </Typography>
+        )}
       </Box>
 
+      <OpenActionItemsList />
+
       {meetings.length === 0 && !isRecording ? (
         <MeetingEmptyState />
       ) : (
@@ -89,6 +124,8 @@ export default function MeetingsPage() {
           </List>
         </Box>
       )}
+
+      {importDialogOpen && <MediaImportDialog onClose={handleCloseImport} />}
     </Box>
   );
 }
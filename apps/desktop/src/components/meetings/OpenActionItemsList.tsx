@@ -0,0 +1,60 @@
+import { CheckCircleOutline } from "@mui/icons-material";
+import { Box, Checkbox, Link, Stack, Typography } from "@mui/material";
+import { memo, useEffect } from "react";
+import { FormattedMessage } from "react-intl";
+import { useAppStore } from "../../store";
+import {
+  loadOpenActionItems,
+  selectMeeting,
+  toggleMeetingActionItem,
+} from "../../actions/meeting.actions";
+
+export const OpenActionItemsList = memo(function OpenActionItemsList() {
+  const items = useAppStore((s) => s.meeting.openActionItems);
+
+  useEffect(() => {
+    void loadOpenActionItems();
+  }, []);
+
+  if (items.length === 0) return null;
+
+  return (
+    <Box sx={{ px: 3, pb: 2 }}>
+      <Stack direction="row" spacing={1} alignItems="center" sx={{ mb: 1 }}>
+        <CheckCircleOutline sx={{ fontSize: 18, color: "primary.main" }} />
+        <Typography variant="titleSmall">
+          <FormattedMessage defaultMessage="Open Action Items" />
+        </Typography>
+      </Stack>
+      <Stack spacing={0.5} sx={{ maxHeight: 240, overflow: "auto" }}>
+        {items.map((item) => (
+          <Stack key={item.id} direction="row" spacing={1} alignItems="center">
+            <Checkbox
+              size="small"
+              checked={false}
+              onChange={() => void toggleMeetingActionItem(item.id)}
+              sx={{ p: 0.5 }}
+            />
+            <Box sx={{ flex: 1, minWidth: 0 }}>
+              <Typography variant="body2" noWrap>
+                {item.task}
+              </Typography>
+              <Typography variant="caption" color="text.secondary" noWrap>
+                {[item.owner, item.dueDate].filter(Boolean).join(" · ")}
+                {(item.owner || item.dueDate) && " · "}
+                <Link
+                  component="button"
+                  variant="caption"
+                  onClick={() => selectMeeting(item.meetingId)}
+                >
+                  {item.meetingTitle ||
+                    new Date(item.meetingStartedAt).toLocaleDateString()}
+                </Link>
+              </Typography>
+            </Box>
+          </Stack>
+        ))}
+      </Stack>
+    </Box>
+  );
+});
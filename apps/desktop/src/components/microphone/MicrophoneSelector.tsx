@@ -17,6 +17,8 @@ import { Nullable } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { FormattedMessage } from "react-intl";
+import { useTauriListen } from "../../hooks/tauri.hooks";
+import { AUDIO_DEVICES_CHANGED_EVENT } from "../../types/audio.types";
 
 const AUTO_OPTION_VALUE = "__microphone_auto__";
 
@@ -34,6 +36,16 @@ export type MicrophoneOption = {
   unavailable?: boolean;
 };
 
+const toMicrophoneOptions = (
+  devices: InputDeviceDescriptor[],
+): MicrophoneOption[] =>
+  devices.map((device) => ({
+    value: device.label,
+    label: device.label,
+    isDefault: device.isDefault,
+    caution: device.caution,
+  }));
+
 export type MicrophoneSelectorProps = {
   value: Nullable<string>;
   onChange: (value: Nullable<string>) => void;
@@ -62,13 +74,7 @@ export const MicrophoneSelector = ({
     setError(null);
     try {
       const result = await invoke<InputDeviceDescriptor[]>("list_microphones");
-      const mapped: MicrophoneOption[] = result.map((device) => ({
-        value: device.label,
-        label: device.label,
-        isDefault: device.isDefault,
-        caution: device.caution,
-      }));
-      setDevices(mapped);
+      setDevices(toMicrophoneOptions(result));
     } catch (err) {
       console.error("Failed to load microphones", err);
       setError("Unable to fetch microphones. Please try again.");
@@ -83,6 +89,24 @@ export const MicrophoneSelector = ({
     }
   }, [loadDevices, microphones]);
 
+  // Keep the list current as devices are plugged in or removed. The backend
+  // only rescans devices while a picker is open.
+  useEffect(() => {
+    void invoke("watch_microphones", { watching: true });
+    return () => {
+      void invoke("watch_microphones", { watching: false });
+    };
+  }, []);
+
+  useTauriListen<InputDeviceDescriptor[]>(
+    AUDIO_DEVICES_CHANGED_EVENT,
+    (payload) => {
+      if (!microphones) {
+        setDevices(toMicrophoneOptions(payload));
+      }
+    },
+  );
+
   const selectValue = value ?? AUTO_OPTION_VALUE;
 
   const options = useMemo(() => {
@@ -1,11 +1,13 @@
 import { Box, LinearProgress, Typography, keyframes } from "@mui/material";
 import { alpha, useTheme } from "@mui/material/styles";
+import type { AmbientStatus } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { emitTo } from "@tauri-apps/api/event";
 import { useEffect, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import { useTauriListen } from "../../hooks/tauri.hooks";
 import { produceAppState, useAppStore } from "../../store";
+import { AMBIENT_STATUS_EVENT } from "../../types/ambient.types";
 import type {
   OverlayPhase,
   OverlaySyncPayload,
@@ -40,6 +42,11 @@ const PILL_COLORS = {
     border: "#6E6E73",
     text: "#FFFFFF",
   },
+  armed: {
+    background: "#2E7D32",
+    border: "#66BB6A",
+    text: "#FFFFFF",
+  },
   recording: {
     background: "#E53935",
     border: "#FF6659",
@@ -68,6 +75,7 @@ type RecordingLevelPayload = {
 export const PillOverlayRoot = () => {
   const [isExpanded, setIsExpanded] = useState(false);
   const [isHovered, setIsHovered] = useState(false);
+  const [ambient, setAmbient] = useState<AmbientStatus | null>(null);
   const theme = useTheme();
   const combos = useAppStore((state) =>
     getHotkeyCombosForAction(state, DICTATE_HOTKEY),
@@ -79,12 +87,15 @@ export const PillOverlayRoot = () => {
   const isIdle = phase === "idle";
   const isListening = phase === "recording";
   const isProcessing = phase === "loading";
+  const isArmed = ambient?.armed ?? false;
 
   const currentColors = isListening
     ? PILL_COLORS.recording
     : isProcessing
       ? PILL_COLORS.loading
-      : PILL_COLORS.idle;
+      : isArmed
+        ? PILL_COLORS.armed
+        : PILL_COLORS.idle;
 
   useEffect(() => {
     document.body.style.margin = "0";
@@ -123,6 +134,16 @@ export const PillOverlayRoot = () => {
     });
   });
 
+  useTauriListen<AmbientStatus>(AMBIENT_STATUS_EVENT, (payload) => {
+    setAmbient(payload);
+  });
+
+  useEffect(() => {
+    invoke<AmbientStatus>("ambient_status")
+      .then(setAmbient)
+      .catch(console.error);
+  }, []);
+
   useEffect(() => {
     emitTo("main", "overlay_ready", { windowLabel: "pill-overlay" }).catch(
       console.error,
@@ -134,11 +155,13 @@ export const PillOverlayRoot = () => {
   );
   const isDictationUnlocked = useAppStore(getIsDictationUnlocked);
 
-  const isOverlayActive = !isIdle;
+  // An armed microphone is always shown, even with the pill hidden, so
+  // ambient listening is never silent.
   const isVisible =
     isDictationUnlocked &&
-    dictationPillVisibility !== "hidden" &&
-    (isOverlayActive || dictationPillVisibility !== "while_active");
+    (isArmed ||
+      (dictationPillVisibility !== "hidden" &&
+        (!isIdle || dictationPillVisibility !== "while_active")));
 
   const handleMouseDownDictate = (e: React.MouseEvent) => {
     e.preventDefault();
@@ -282,7 +305,14 @@ export const PillOverlayRoot = () => {
                   transition: "opacity 150ms ease-out",
                 }}
               >
-                <FormattedMessage defaultMessage="Click to dictate" />
+                {isArmed && ambient?.wakePhrase ? (
+                  <FormattedMessage
+                    defaultMessage="Say “{wakePhrase}”"
+                    values={{ wakePhrase: ambient.wakePhrase }}
+                  />
+                ) : (
+                  <FormattedMessage defaultMessage="Click to dictate" />
+                )}
               </Typography>
             )}
 
@@ -0,0 +1,87 @@
+import {
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  Stack,
+  Typography,
+} from "@mui/material";
+import type { McpPermissionDecision, McpPermissionRequest } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useState } from "react";
+import { FormattedMessage } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { useTauriListen } from "../../hooks/tauri.hooks";
+
+export const McpPermissionDialog = () => {
+  const [queue, setQueue] = useState<McpPermissionRequest[]>([]);
+  const current = queue[0] ?? null;
+
+  useTauriListen<McpPermissionRequest>("mcp_permission_request", (payload) => {
+    setQueue((existing) => [...existing, payload]);
+  });
+
+  const respond = useCallback(
+    async (decision: McpPermissionDecision) => {
+      if (!current) {
+        return;
+      }
+
+      setQueue((existing) =>
+        existing.filter((request) => request.requestId !== current.requestId),
+      );
+      try {
+        await invoke<void>("mcp_permission_respond", {
+          requestId: current.requestId,
+          decision,
+        });
+      } catch (error) {
+        showErrorSnackbar(error);
+      }
+    },
+    [current],
+  );
+
+  return (
+    <Dialog
+      open={current != null}
+      onClose={() => respond("deny")}
+      fullWidth
+      maxWidth="xs"
+    >
+      <DialogTitle>
+        <FormattedMessage defaultMessage="Allow MCP tool?" />
+      </DialogTitle>
+      <DialogContent>
+        {current && (
+          <Stack spacing={1}>
+            <Typography variant="body1">
+              <FormattedMessage
+                defaultMessage="{client} wants to use {tool}."
+                values={{
+                  client: <strong>{current.clientName}</strong>,
+                  tool: <code>{current.tool}</code>,
+                }}
+              />
+            </Typography>
+            <Typography variant="body2" color="text.secondary">
+              {current.summary}
+            </Typography>
+          </Stack>
+        )}
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={() => respond("deny")}>
+          <FormattedMessage defaultMessage="Deny" />
+        </Button>
+        <Button onClick={() => respond("allowSession")}>
+          <FormattedMessage defaultMessage="Allow until it disconnects" />
+        </Button>
+        <Button variant="contained" onClick={() => respond("allowOnce")}>
+          <FormattedMessage defaultMessage="Allow once" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
@@ -1,3 +1,4 @@
+import { TermSuggestionsDialog } from "../dictionary/TermSuggestionsDialog";
 import { StatsDialog } from "../home/StatsDialog";
 import { PaymentDialog } from "../payment/PaymentDialog";
 import { AIAgentModeDialog } from "../settings/AIAgentModeDialog";
@@ -8,9 +9,11 @@ import { ClearLocalDataDialog } from "../settings/ClearLocalDataDialog";
 import { MicrophoneDialog } from "../settings/MicrophoneDialog";
 import { MoreSettingsDialog } from "../settings/MoreSettingsDialog";
 import { ProfileDialog } from "../settings/ProfileDialog";
+import { RetentionDialog } from "../settings/RetentionDialog";
 import { ShortcutsDialog } from "../settings/ShortcutsDialog";
 import { VibeCodingDialog } from "../settings/VibeCodingDialog";
 import { UpgradePlanDialog } from "../pricing/UpgradePlanDialog";
+import { McpPermissionDialog } from "./McpPermissionDialog";
 import { UpdateDialog } from "./UpdateDialog";
 import { DeleteAccountDialog } from "../settings/DeleteAccountDialog";
 import { ToneEditorDialog } from "../tones/ToneEditorDialog";
@@ -30,10 +33,13 @@ export const RootDialogs = () => {
       <ShortcutsDialog />
       <VibeCodingDialog />
       <ClearLocalDataDialog />
+      <RetentionDialog />
+      <TermSuggestionsDialog />
       <UpgradePlanDialog />
       <PaymentDialog />
       <DeleteAccountDialog />
       <MoreSettingsDialog />
+      <McpPermissionDialog />
     </>
   );
 };
@@ -6,6 +6,7 @@ import { useIntl } from "react-intl";
 import { loadApiKeys } from "../../actions/api-key.actions";
 import {
   loadAppTargets,
+  resolveActiveAppProfile,
   tryRegisterCurrentAppTarget,
 } from "../../actions/app-target.actions";
 import { showErrorSnackbar } from "../../actions/app.actions";
@@ -38,6 +39,11 @@ import { AgentStrategy } from "../../strategies/agent.strategy";
 import { BaseStrategy } from "../../strategies/base.strategy";
 import { DictationStrategy } from "../../strategies/dictation.strategy";
 import type { TextFieldInfo } from "../../types/accessibility.types";
+import {
+  AMBIENT_SPEECH_ENDED_EVENT,
+  AMBIENT_WAKE_EVENT,
+  type AmbientWakePayload,
+} from "../../types/ambient.types";
 import { REGISTER_CURRENT_APP_EVENT } from "../../types/app-target.types";
 import type { GoogleAuthPayload } from "../../types/google-auth.types";
 import { GOOGLE_AUTH_EVENT } from "../../types/google-auth.types";
@@ -51,6 +57,7 @@ import {
   debouncedToggle,
   getOrCreateController,
 } from "../../utils/activation.utils";
+import { stripWakePhrase } from "../../utils/ambient.utils";
 import {
   trackAgentStart,
   trackAppUsed,
@@ -115,6 +122,8 @@ export const RootSideEffects = () => {
   const strategyRef = useRef<BaseStrategy | null>(null);
   const recordingWarningTimerRef = useRef<NodeJS.Timeout | null>(null);
   const recordingAutoStopTimerRef = useRef<NodeJS.Timeout | null>(null);
+  // Set while a dictation started by the ambient wake phrase is running.
+  const wakePhraseRef = useRef<string | null>(null);
   const userId = useAppStore((state) => state.auth?.uid);
   const keyPermAuthorized = useAppStore((state) =>
     isPermissionAuthorized(getRec(state.permissions, "accessibility")?.state),
@@ -299,6 +308,8 @@ export const RootSideEffects = () => {
             args: { preferredMicrophone },
           }),
         ]);
+        // The backend resolves the app profile while starting the recording.
+        await resolveActiveAppProfile();
 
         const sampleRate =
           typeof startRecordingResult?.sampleRate === "number" &&
@@ -351,6 +362,7 @@ export const RootSideEffects = () => {
         showErrorSnackbar("Unable to start recording. Please try again.");
         suppressUntilRef.current = Date.now() + 1_000;
         isRecordingRef.current = false;
+        wakePhraseRef.current = null;
         strategyRef.current = null;
         sessionRef.current?.cleanup();
         sessionRef.current = null;
@@ -428,6 +440,8 @@ export const RootSideEffects = () => {
     const [audio, a11yInfo] = await promise;
 
     isRecordingRef.current = false;
+    const wakePhrase = wakePhraseRef.current;
+    wakePhraseRef.current = null;
 
     const session = sessionRef.current;
     sessionRef.current = null;
@@ -439,7 +453,14 @@ export const RootSideEffects = () => {
           session.finalize(audio),
         ]);
         const toneId = currentApp?.toneId ?? null;
-        const rawTranscript = transcribeResult.rawTranscript;
+        const transcriptionWarnings = [
+          ...(audio.warnings ?? []),
+          ...transcribeResult.warnings,
+        ];
+        const rawTranscript =
+          wakePhrase && transcribeResult.rawTranscript
+            ? stripWakePhrase(transcribeResult.rawTranscript, wakePhrase)
+            : transcribeResult.rawTranscript;
         trackAppUsed(currentApp?.name ?? "Unknown");
 
         let transcript: string | null = null;
@@ -447,7 +468,26 @@ export const RootSideEffects = () => {
         let postProcessMetadata = {};
         let postProcessWarnings: string[] = [];
 
+        let transcriptionId: string | null = null;
+
         if (rawTranscript) {
+          // Stored first so a backend rewrite is saved even if the window
+          // reloads before the dictation finishes.
+          if (strategy.shouldStoreTranscript()) {
+            const draft = await storeTranscription({
+              audio,
+              rawTranscript,
+              sanitizedTranscript: null,
+              transcript: rawTranscript,
+              transcriptionMetadata: transcribeResult.metadata,
+              postProcessMetadata: {},
+              warnings: transcriptionWarnings,
+              appTargetId: currentApp?.id ?? null,
+              draft: true,
+            });
+            transcriptionId = draft.transcription?.id ?? null;
+          }
+
           const result = await strategy.handleTranscript({
             rawTranscript,
             toneId,
@@ -456,7 +496,8 @@ export const RootSideEffects = () => {
             loadingToken,
             audio,
             transcriptionMetadata: transcribeResult.metadata,
-            transcriptionWarnings: transcribeResult.warnings,
+            transcriptionWarnings,
+            transcriptionId,
           });
 
           transcript = result.transcript;
@@ -492,7 +533,9 @@ export const RootSideEffects = () => {
             transcript,
             transcriptionMetadata: transcribeResult.metadata,
             postProcessMetadata,
-            warnings: [...transcribeResult.warnings, ...postProcessWarnings],
+            warnings: [...transcriptionWarnings, ...postProcessWarnings],
+            appTargetId: currentApp?.id ?? null,
+            transcriptionId,
           });
         }
       }
@@ -633,6 +676,24 @@ export const RootSideEffects = () => {
     debouncedToggle("dictation", dictationController);
   });
 
+  useTauriListen<AmbientWakePayload>(AMBIENT_WAKE_EVENT, (payload) => {
+    if (
+      dictationController.isActive ||
+      agentController.isActive ||
+      !getIsDictationUnlocked(getAppState())
+    ) {
+      return;
+    }
+    wakePhraseRef.current = payload.wakePhrase;
+    dictationController.toggle();
+  });
+
+  useTauriListen<void>(AMBIENT_SPEECH_ENDED_EVENT, () => {
+    if (wakePhraseRef.current && dictationController.isActive) {
+      dictationController.toggle();
+    }
+  });
+
   useTauriListen<{ query: string }>("quick-bar-query", async (payload) => {
     produceAppState((draft) => {
       draft.chat.pendingQuickBarQuery = payload.query;
@@ -0,0 +1,109 @@
+import { Stack, Switch, TextField, Typography } from "@mui/material";
+import type { AmbientStatus } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { ChangeEvent, useEffect, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { setAmbientWakePhrase } from "../../actions/user.actions";
+import { useTauriListen } from "../../hooks/tauri.hooks";
+import { useAppStore } from "../../store";
+import {
+  AMBIENT_STATUS_EVENT,
+  DEFAULT_WAKE_PHRASE,
+} from "../../types/ambient.types";
+import { SettingSection } from "../common/SettingSection";
+
+export const AmbientListeningSetting = () => {
+  const intl = useIntl();
+  const savedWakePhrase = useAppStore(
+    (state) => state.userPrefs?.ambientWakePhrase ?? null,
+  );
+  const [status, setStatus] = useState<AmbientStatus | null>(null);
+  const [wakePhrase, setWakePhrase] = useState(savedWakePhrase ?? "");
+  const [saving, setSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<AmbientStatus>("ambient_status")
+      .then(setStatus)
+      .catch((error) =>
+        console.error("Failed to load ambient listening status", error),
+      );
+  }, []);
+
+  useEffect(() => {
+    setWakePhrase(savedWakePhrase ?? "");
+  }, [savedWakePhrase]);
+
+  // The tray can stop listening while this dialog is open.
+  useTauriListen<AmbientStatus>(AMBIENT_STATUS_EVENT, (payload) => {
+    setStatus(payload);
+  });
+
+  const setArmed = async (armed: boolean) => {
+    setSaving(true);
+    try {
+      const next = await invoke<AmbientStatus>(
+        armed ? "ambient_arm" : "ambient_disarm",
+      );
+      setStatus(next);
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleToggle = (event: ChangeEvent<HTMLInputElement>) => {
+    void setArmed(event.target.checked);
+  };
+
+  const handleWakePhraseBlur = async () => {
+    const next = wakePhrase.trim() || null;
+    if (next === savedWakePhrase) {
+      return;
+    }
+
+    await setAmbientWakePhrase(next);
+    // Re-arm so the listener picks up the new phrase.
+    if (status?.armed) {
+      await setArmed(true);
+    }
+  };
+
+  return (
+    <Stack spacing={1.5}>
+      <SettingSection
+        title={<FormattedMessage defaultMessage="Ambient listening" />}
+        description={
+          <FormattedMessage defaultMessage="Start dictating by saying the wake phrase. Audio stays in memory and is discarded until the phrase is heard." />
+        }
+        action={
+          <Switch
+            edge="end"
+            checked={status?.armed ?? false}
+            disabled={!status || saving}
+            onChange={handleToggle}
+          />
+        }
+      />
+
+      <Stack direction="row" spacing={1} alignItems="center">
+        <TextField
+          size="small"
+          label={intl.formatMessage({ defaultMessage: "Wake phrase" })}
+          placeholder={DEFAULT_WAKE_PHRASE}
+          value={wakePhrase}
+          onChange={(event) => setWakePhrase(event.target.value)}
+          onBlur={handleWakePhraseBlur}
+          disabled={saving}
+          sx={{ flex: 1 }}
+        />
+        {status?.armed && (
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="Listening" />
+          </Typography>
+        )}
+      </Stack>
+    </Stack>
+  );
+};
@@ -0,0 +1,126 @@
+import { Button, Stack, Switch, TextField, Typography } from "@mui/material";
+import type { LocalApiConfigureRequest, LocalApiStatus } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { ChangeEvent, useEffect, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar, showSnackbar } from "../../actions/app.actions";
+import { SettingSection } from "../common/SettingSection";
+
+export const LocalApiSetting = () => {
+  const intl = useIntl();
+  const [status, setStatus] = useState<LocalApiStatus | null>(null);
+  const [port, setPort] = useState("");
+  const [saving, setSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<LocalApiStatus>("local_api_status")
+      .then((value) => {
+        setStatus(value);
+        setPort(String(value.port));
+      })
+      .catch((error) => console.error("Failed to load local API status", error));
+  }, []);
+
+  const configure = async (request: LocalApiConfigureRequest) => {
+    setSaving(true);
+    try {
+      const next = await invoke<LocalApiStatus>("local_api_configure", {
+        request,
+      });
+      setStatus(next);
+      setPort(String(next.port));
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const parsedPort = (): number | undefined => {
+    const value = Number.parseInt(port, 10);
+    return Number.isFinite(value) ? value : undefined;
+  };
+
+  const handleToggle = (event: ChangeEvent<HTMLInputElement>) => {
+    void configure({ enabled: event.target.checked, port: parsedPort() });
+  };
+
+  const handlePortBlur = () => {
+    const value = parsedPort();
+    if (status && value !== undefined && value !== status.port) {
+      void configure({ enabled: status.enabled, port: value });
+    }
+  };
+
+  const handleCopyToken = async () => {
+    if (!status?.token) {
+      return;
+    }
+    await navigator.clipboard.writeText(status.token);
+    showSnackbar(intl.formatMessage({ defaultMessage: "Token copied" }), {
+      mode: "success",
+    });
+  };
+
+  const handleRegenerate = () => {
+    if (status) {
+      void configure({ enabled: status.enabled, regenerateToken: true });
+    }
+  };
+
+  return (
+    <Stack spacing={1.5}>
+      <SettingSection
+        title={<FormattedMessage defaultMessage="Local API" />}
+        description={
+          <FormattedMessage defaultMessage="Let other apps on this computer start recordings, transcribe files and paste text through a token-protected server on localhost." />
+        }
+        action={
+          <Switch
+            edge="end"
+            checked={status?.enabled ?? false}
+            disabled={!status || saving}
+            onChange={handleToggle}
+          />
+        }
+      />
+
+      {status?.enabled && (
+        <Stack spacing={1.5}>
+          <Stack direction="row" spacing={1} alignItems="center">
+            <TextField
+              size="small"
+              label={intl.formatMessage({ defaultMessage: "Port" })}
+              value={port}
+              onChange={(event) => setPort(event.target.value)}
+              onBlur={handlePortBlur}
+              disabled={saving}
+              sx={{ width: 120 }}
+            />
+            <Typography variant="body2" color="text.secondary">
+              {status.running ? (
+                status.url
+              ) : (
+                <FormattedMessage defaultMessage="Not running" />
+              )}
+            </Typography>
+          </Stack>
+          <Stack direction="row" spacing={1} alignItems="center">
+            <Typography variant="body2" sx={{ flex: 1 }}>
+              <FormattedMessage
+                defaultMessage="Token ending in {suffix}"
+                values={{ suffix: status.tokenSuffix ?? "" }}
+              />
+            </Typography>
+            <Button size="small" onClick={handleCopyToken} disabled={saving}>
+              <FormattedMessage defaultMessage="Copy token" />
+            </Button>
+            <Button size="small" onClick={handleRegenerate} disabled={saving}>
+              <FormattedMessage defaultMessage="Regenerate" />
+            </Button>
+          </Stack>
+        </Stack>
+      )}
+    </Stack>
+  );
+};
@@ -1,4 +1,5 @@
 import { LoadingButton } from "@mui/lab";
+import type { SelectChangeEvent } from "@mui/material";
 import {
   Alert,
   Button,
@@ -7,27 +8,51 @@ import {
   DialogContent,
   DialogTitle,
   Divider,
+  MenuItem,
+  Select,
   Stack,
 } from "@mui/material";
-import { Nullable } from "@repo/types";
+import {
+  MicrophoneCalibrationProgress,
+  MicrophoneCalibrationReport,
+  Nullable,
+} from "@repo/types";
 import { useCallback, useEffect, useState } from "react";
-import { FormattedMessage } from "react-intl";
-import { setPreferredMicrophone } from "../../actions/user.actions";
+import { FormattedMessage, useIntl } from "react-intl";
+import {
+  calibrateMicrophones,
+  setPreferredMicrophone,
+  setWarmMicrophoneMs,
+} from "../../actions/user.actions";
+import { useTauriListen } from "../../hooks/tauri.hooks";
 import { useMyPreferredMicrophone } from "../../hooks/user.hooks";
 import { produceAppState, useAppStore } from "../../store";
+import { MICROPHONE_CALIBRATION_PROGRESS_EVENT } from "../../types/audio.types";
+import { getMyUserPreferences } from "../../utils/user.utils";
 import { SettingSection } from "../common/SettingSection";
 import { MicrophoneSelector } from "../microphone/MicrophoneSelector";
 import { MicrophoneTester } from "../microphone/MicrophoneTester";
 
+const WARM_MICROPHONE_OPTIONS_MS = [250, 500, 1000];
+
 export const MicrophoneDialog = () => {
+  const intl = useIntl();
   const open = useAppStore((state) => state.settings.microphoneDialogOpen);
   const savedPreference = useMyPreferredMicrophone();
+  const warmMicrophoneMs = useAppStore(
+    (state) => getMyUserPreferences(state)?.warmMicrophoneMs ?? 0,
+  );
 
   const [selected, setSelected] = useState<Nullable<string>>(savedPreference);
   const [hasChanges, setHasChanges] = useState(false);
   const [saving, setSaving] = useState(false);
   const [saveError, setSaveError] = useState<string | null>(null);
   const [saveSuccess, setSaveSuccess] = useState(false);
+  const [calibrating, setCalibrating] = useState(false);
+  const [calibration, setCalibration] =
+    useState<Nullable<MicrophoneCalibrationReport>>(null);
+  const [calibrationProgress, setCalibrationProgress] =
+    useState<Nullable<MicrophoneCalibrationProgress>>(null);
 
   useEffect(() => {
     if (!open) {
@@ -39,6 +64,12 @@ export const MicrophoneDialog = () => {
     setSaveSuccess(false);
   }, [open, savedPreference]);
 
+  useEffect(() => {
+    if (!open) {
+      setCalibration(null);
+    }
+  }, [open]);
+
   const handleSelectionChange = useCallback(
     (next: Nullable<string>) => {
       setSelected(next ?? null);
@@ -69,6 +100,35 @@ export const MicrophoneDialog = () => {
     }
   }, [hasChanges, saving, selected]);
 
+  const handleCalibrate = useCallback(async () => {
+    setCalibrating(true);
+    setCalibration(null);
+    try {
+      setCalibration(await calibrateMicrophones());
+    } finally {
+      setCalibrating(false);
+      setCalibrationProgress(null);
+    }
+  }, []);
+
+  useTauriListen<MicrophoneCalibrationProgress>(
+    MICROPHONE_CALIBRATION_PROGRESS_EVENT,
+    (payload) => {
+      setCalibrationProgress(payload);
+    },
+  );
+
+  const recommendedDevice = calibration?.recommendedDevice ?? null;
+  const handleUseRecommended = useCallback(() => {
+    if (recommendedDevice) {
+      handleSelectionChange(recommendedDevice);
+    }
+  }, [handleSelectionChange, recommendedDevice]);
+
+  const handleWarmMicrophoneChange = (event: SelectChangeEvent<number>) => {
+    void setWarmMicrophoneMs(Number(event.target.value));
+  };
+
   const handleClose = useCallback(() => {
     produceAppState((draft) => {
       draft.settings.microphoneDialogOpen = false;
@@ -93,7 +153,7 @@ export const MicrophoneDialog = () => {
             <MicrophoneSelector
               value={selected ?? null}
               onChange={handleSelectionChange}
-              disabled={saving}
+              disabled={saving || calibrating}
             />
             {saveError && <Alert severity="error">{saveError}</Alert>}
             {saveSuccess && (
@@ -103,6 +163,93 @@ export const MicrophoneDialog = () => {
             )}
           </Stack>
 
+          <SettingSection
+            title={<FormattedMessage defaultMessage="Keep microphone warm" />}
+            description={
+              <FormattedMessage defaultMessage="Keep the microphone open between recordings so the first word is never cut off. The last moment of audio is held in memory only and discarded unless you start recording." />
+            }
+            action={
+              <Select<number>
+                size="small"
+                value={warmMicrophoneMs}
+                onChange={handleWarmMicrophoneChange}
+                sx={{ minWidth: 120 }}
+              >
+                <MenuItem value={0}>
+                  {intl.formatMessage({ defaultMessage: "Off" })}
+                </MenuItem>
+                {WARM_MICROPHONE_OPTIONS_MS.map((ms) => (
+                  <MenuItem key={ms} value={ms}>
+                    {intl.formatMessage({ defaultMessage: "{ms} ms" }, { ms })}
+                  </MenuItem>
+                ))}
+              </Select>
+            }
+          />
+
+          <Stack spacing={1.5}>
+            <SettingSection
+              title={
+                <FormattedMessage defaultMessage="Calibrate microphones" />
+              }
+              description={
+                <FormattedMessage defaultMessage="Speak normally while each microphone records for a few seconds. OS Voice sets each one's gain and suggests the one that sounds best." />
+              }
+              action={
+                <LoadingButton
+                  variant="outlined"
+                  size="small"
+                  onClick={handleCalibrate}
+                  loading={calibrating}
+                  disabled={saving}
+                >
+                  <FormattedMessage defaultMessage="Calibrate" />
+                </LoadingButton>
+              }
+            />
+            {calibrating && calibrationProgress && (
+              <Alert severity="info">
+                <FormattedMessage
+                  defaultMessage="Speak into {device} ({index} of {total})."
+                  values={{
+                    device: calibrationProgress.deviceName,
+                    index: calibrationProgress.index,
+                    total: calibrationProgress.total,
+                  }}
+                />
+              </Alert>
+            )}
+            {recommendedDevice && (
+              <Alert
+                severity="success"
+                action={
+                  selected !== recommendedDevice && (
+                    <Button
+                      color="inherit"
+                      size="small"
+                      onClick={handleUseRecommended}
+                    >
+                      <FormattedMessage defaultMessage="Use this microphone" />
+                    </Button>
+                  )
+                }
+              >
+                <FormattedMessage
+                  defaultMessage="{device} sounds best, with a gain of {gain}×."
+                  values={{
+                    device: recommendedDevice,
+                    gain: calibration?.recommendedGain ?? 1,
+                  }}
+                />
+              </Alert>
+            )}
+            {calibration && !calibration.recommendedDevice && (
+              <Alert severity="warning">
+                <FormattedMessage defaultMessage="No microphone could be measured. Check that one is connected and try again." />
+              </Alert>
+            )}
+          </Stack>
+
           <Divider />
 
           <Stack spacing={1.5}>
@@ -18,6 +18,7 @@ import {
   setIgnoreUpdateDialog,
   setIncognitoModeEnabled,
   setIncognitoModeIncludeInStats,
+  setVoiceCommandKeywordRequired,
 } from "../../actions/user.actions";
 import { produceAppState, useAppStore } from "../../store";
 import {
@@ -25,6 +26,8 @@ import {
   getMyUserPreferences,
 } from "../../utils/user.utils";
 import { SettingSection } from "../common/SettingSection";
+import { AmbientListeningSetting } from "./AmbientListeningSetting";
+import { LocalApiSetting } from "./LocalApiSetting";
 
 export const MoreSettingsDialog = () => {
   const intl = useIntl();
@@ -34,6 +37,7 @@ export const MoreSettingsDialog = () => {
     incognitoModeEnabled,
     incognitoIncludeInStats,
     dictationPillVisibility,
+    voiceCommandKeywordRequired,
   ] = useAppStore((state) => {
     const prefs = getMyUserPreferences(state);
     return [
@@ -42,6 +46,7 @@ export const MoreSettingsDialog = () => {
       prefs?.incognitoModeEnabled ?? false,
       prefs?.incognitoModeIncludeInStats ?? false,
       getEffectivePillVisibility(prefs?.dictationPillVisibility),
+      prefs?.voiceCommandKeywordRequired ?? false,
     ] as const;
   });
 
@@ -68,6 +73,13 @@ export const MoreSettingsDialog = () => {
     void setIncognitoModeIncludeInStats(enabled);
   };
 
+  const handleToggleVoiceCommandKeyword = (
+    event: ChangeEvent<HTMLInputElement>,
+  ) => {
+    const required = event.target.checked;
+    void setVoiceCommandKeywordRequired(required);
+  };
+
   const handleDictationPillVisibilityChange = (
     event: SelectChangeEvent<DictationPillVisibility>,
   ) => {
@@ -85,7 +97,7 @@ export const MoreSettingsDialog = () => {
           <SettingSection
             title={<FormattedMessage defaultMessage="Incognito mode" />}
             description={
-              <FormattedMessage defaultMessage="When enabled, OS Voice will not save transcription history or audio snapshots." />
+              <FormattedMessage defaultMessage="When enabled, OS Voice will not save transcription history, audio snapshots, meetings or chats." />
             }
             action={
               <Switch
@@ -156,6 +168,26 @@ export const MoreSettingsDialog = () => {
               </Select>
             }
           />
+
+          <SettingSection
+            title={
+              <FormattedMessage defaultMessage="Require a keyword for voice commands" />
+            }
+            description={
+              <FormattedMessage defaultMessage="Only treat phrases like “new line” as commands when you say “command” first." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={voiceCommandKeywordRequired}
+                onChange={handleToggleVoiceCommandKeyword}
+              />
+            }
+          />
+
+          <AmbientListeningSetting />
+
+          <LocalApiSetting />
         </Stack>
       </DialogContent>
       <DialogActions>
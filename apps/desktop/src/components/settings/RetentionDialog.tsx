@@ -0,0 +1,369 @@
+import {
+  Alert,
+  Button,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  Divider,
+  List,
+  ListItem,
+  ListItemText,
+  MenuItem,
+  Select,
+  Stack,
+  Switch,
+  TextField,
+  Typography,
+} from "@mui/material";
+import type {
+  RetentionAction,
+  RetentionPolicy,
+  RetentionReport,
+  RetentionTarget,
+} from "@repo/types";
+import dayjs from "dayjs";
+import { ChangeEvent, ReactNode, useEffect, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar, showSnackbar } from "../../actions/app.actions";
+import { getRetentionRepo } from "../../repos";
+import { produceAppState, useAppStore } from "../../store";
+import { formatSize } from "../../utils/format.utils";
+import { SettingSection } from "../common/SettingSection";
+
+const BYTES_PER_GB = 1024 * 1024 * 1024;
+const MAX_REPORT_ITEMS = 50;
+
+const TARGET_TITLES: Record<RetentionTarget, ReactNode> = {
+  transcription: <FormattedMessage defaultMessage="Dictations" />,
+  meeting: <FormattedMessage defaultMessage="Meetings" />,
+  conversation: <FormattedMessage defaultMessage="Chats" />,
+};
+
+const parsePositive = (value: string): number | null => {
+  const parsed = Number(value);
+  return value.trim() && Number.isFinite(parsed) && parsed > 0 ? parsed : null;
+};
+
+type PolicyEditorProps = {
+  policy: RetentionPolicy;
+  disabled: boolean;
+  onChange: (policy: RetentionPolicy) => void;
+};
+
+const PolicyEditor = ({ policy, disabled, onChange }: PolicyEditorProps) => {
+  const intl = useIntl();
+  const hasAudio = policy.target !== "conversation";
+  const [days, setDays] = useState(policy.maxAgeDays?.toString() ?? "");
+  const [gigabytes, setGigabytes] = useState(
+    policy.maxAudioBytes ? String(policy.maxAudioBytes / BYTES_PER_GB) : "",
+  );
+
+  const handleDaysChange = (event: ChangeEvent<HTMLInputElement>) => {
+    setDays(event.target.value);
+    const value = parsePositive(event.target.value);
+    onChange({ ...policy, maxAgeDays: value ? Math.round(value) : null });
+  };
+
+  const handleGigabytesChange = (event: ChangeEvent<HTMLInputElement>) => {
+    setGigabytes(event.target.value);
+    const value = parsePositive(event.target.value);
+    onChange({
+      ...policy,
+      maxAudioBytes: value ? Math.round(value * BYTES_PER_GB) : null,
+    });
+  };
+
+  return (
+    <Stack spacing={1.5}>
+      <SettingSection
+        title={TARGET_TITLES[policy.target]}
+        description={
+          policy.lastRunAt ? (
+            <FormattedMessage
+              defaultMessage="Last cleaned up {date}"
+              values={{
+                date: dayjs(policy.lastRunAt).format("MMM D, YYYY h:mm A"),
+              }}
+            />
+          ) : (
+            <FormattedMessage defaultMessage="Never cleaned up" />
+          )
+        }
+        action={
+          <Switch
+            edge="end"
+            checked={policy.enabled}
+            disabled={disabled}
+            onChange={(event) =>
+              onChange({ ...policy, enabled: event.target.checked })
+            }
+          />
+        }
+      />
+      {policy.enabled && (
+        <Stack spacing={1.5}>
+          <Stack direction="row" spacing={1.5}>
+            <TextField
+              size="small"
+              type="number"
+              label={intl.formatMessage({ defaultMessage: "Delete after" })}
+              placeholder={intl.formatMessage({ defaultMessage: "Never" })}
+              value={days}
+              onChange={handleDaysChange}
+              disabled={disabled}
+              InputProps={{
+                endAdornment: (
+                  <Typography variant="body2" color="text.secondary">
+                    <FormattedMessage defaultMessage="days" />
+                  </Typography>
+                ),
+              }}
+              sx={{ flex: 1 }}
+            />
+            {hasAudio && (
+              <TextField
+                size="small"
+                type="number"
+                label={intl.formatMessage({
+                  defaultMessage: "Recording storage limit",
+                })}
+                placeholder={intl.formatMessage({
+                  defaultMessage: "Unlimited",
+                })}
+                value={gigabytes}
+                onChange={handleGigabytesChange}
+                disabled={disabled}
+                InputProps={{
+                  endAdornment: (
+                    <Typography variant="body2" color="text.secondary">
+                      GB
+                    </Typography>
+                  ),
+                }}
+                sx={{ flex: 1 }}
+              />
+            )}
+          </Stack>
+          {hasAudio && (
+            <Select
+              size="small"
+              value={policy.action}
+              disabled={disabled}
+              onChange={(event) =>
+                onChange({
+                  ...policy,
+                  action: event.target.value as RetentionAction,
+                })
+              }
+            >
+              <MenuItem value="audio">
+                <FormattedMessage defaultMessage="Delete recordings, keep transcripts" />
+              </MenuItem>
+              <MenuItem value="all">
+                <FormattedMessage defaultMessage="Delete recordings and transcripts" />
+              </MenuItem>
+            </Select>
+          )}
+          <SettingSection
+            title={<FormattedMessage defaultMessage="Keep starred items" />}
+            description={
+              <FormattedMessage defaultMessage="Starred items are never removed by this policy." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={policy.keepStarred}
+                disabled={disabled}
+                onChange={(event) =>
+                  onChange({ ...policy, keepStarred: event.target.checked })
+                }
+              />
+            }
+          />
+        </Stack>
+      )}
+    </Stack>
+  );
+};
+
+const ReportSummary = ({ report }: { report: RetentionReport }) => {
+  if (report.items.length === 0) {
+    return (
+      <Alert severity="success" variant="outlined">
+        {report.dryRun ? (
+          <FormattedMessage defaultMessage="Nothing would be removed right now." />
+        ) : (
+          <FormattedMessage defaultMessage="Nothing needed to be removed." />
+        )}
+      </Alert>
+    );
+  }
+
+  const values = {
+    files: report.audioFiles,
+    size: formatSize(report.audioBytes),
+    records: report.recordsDeleted,
+  };
+
+  return (
+    <Stack spacing={1}>
+      <Alert severity={report.dryRun ? "info" : "success"} variant="outlined">
+        {report.dryRun ? (
+          <FormattedMessage
+            defaultMessage="This would remove {files} recordings ({size}) and delete {records} items."
+            values={values}
+          />
+        ) : (
+          <FormattedMessage
+            defaultMessage="Removed {files} recordings ({size}) and deleted {records} items."
+            values={values}
+          />
+        )}
+      </Alert>
+      <List dense disablePadding sx={{ maxHeight: 240, overflowY: "auto" }}>
+        {report.items.slice(0, MAX_REPORT_ITEMS).map((item) => (
+          <ListItem key={`${item.target}-${item.id}`} disableGutters>
+            <ListItemText
+              primary={item.label || item.id}
+              primaryTypographyProps={{ noWrap: true }}
+              secondary={
+                <>
+                  {TARGET_TITLES[item.target]} ·{" "}
+                  {dayjs(item.createdAt).format("MMM D, YYYY")} ·{" "}
+                  {item.reason === "age" ? (
+                    <FormattedMessage defaultMessage="too old" />
+                  ) : (
+                    <FormattedMessage defaultMessage="over storage limit" />
+                  )}
+                  {item.audioBytes > 0 && ` · ${formatSize(item.audioBytes)}`}
+                </>
+              }
+            />
+          </ListItem>
+        ))}
+      </List>
+      {report.items.length > MAX_REPORT_ITEMS && (
+        <Typography variant="caption" color="text.secondary">
+          <FormattedMessage
+            defaultMessage="And {count} more"
+            values={{ count: report.items.length - MAX_REPORT_ITEMS }}
+          />
+        </Typography>
+      )}
+    </Stack>
+  );
+};
+
+export const RetentionDialog = () => {
+  const intl = useIntl();
+  const open = useAppStore((state) => state.settings.retentionDialogOpen);
+  const [policies, setPolicies] = useState<RetentionPolicy[]>([]);
+  const [report, setReport] = useState<RetentionReport | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  useEffect(() => {
+    if (!open) {
+      return;
+    }
+
+    setReport(null);
+    getRetentionRepo()
+      .listPolicies()
+      .then(setPolicies)
+      .catch(showErrorSnackbar);
+  }, [open]);
+
+  const handleClose = () => {
+    produceAppState((draft) => {
+      draft.settings.retentionDialogOpen = false;
+    });
+  };
+
+  const handlePolicyChange = (next: RetentionPolicy) => {
+    setPolicies((current) =>
+      current.map((policy) => (policy.target === next.target ? next : policy)),
+    );
+    setReport(null);
+  };
+
+  const savePolicies = async () => {
+    const repo = getRetentionRepo();
+    const saved = await Promise.all(
+      policies.map((policy) => repo.savePolicy(policy)),
+    );
+    setPolicies(saved);
+  };
+
+  const runWith = async (action: () => Promise<RetentionReport | null>) => {
+    setIsBusy(true);
+    try {
+      await savePolicies();
+      const result = await action();
+      if (result) {
+        setReport(result);
+      }
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handlePreview = () =>
+    runWith(() => getRetentionRepo().previewReport());
+
+  const handleRunNow = () =>
+    runWith(async () => {
+      const result = await getRetentionRepo().runNow();
+      setPolicies(await getRetentionRepo().listPolicies());
+      return result;
+    });
+
+  const handleSave = () =>
+    runWith(async () => {
+      showSnackbar(
+        intl.formatMessage({ defaultMessage: "Retention policies saved" }),
+        { mode: "success" },
+      );
+      handleClose();
+      return null;
+    });
+
+  return (
+    <Dialog open={open} onClose={handleClose} fullWidth maxWidth="sm">
+      <DialogTitle>
+        <FormattedMessage defaultMessage="Data retention" />
+      </DialogTitle>
+      <DialogContent>
+        <Stack spacing={2} sx={{ mt: 1 }}>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="Automatically remove old recordings, transcripts and chats from this device. Cleanup runs in the background while the app is open." />
+          </Typography>
+          {policies.map((policy, index) => (
+            <Stack key={policy.target} spacing={2}>
+              {index > 0 && <Divider />}
+              <PolicyEditor
+                policy={policy}
+                disabled={isBusy}
+                onChange={handlePolicyChange}
+              />
+            </Stack>
+          ))}
+          {report && <ReportSummary report={report} />}
+        </Stack>
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={handlePreview} disabled={isBusy}>
+          <FormattedMessage defaultMessage="Preview" />
+        </Button>
+        <Button onClick={handleRunNow} disabled={isBusy} color="error">
+          <FormattedMessage defaultMessage="Clean up now" />
+        </Button>
+        <Button variant="contained" onClick={handleSave} disabled={isBusy}>
+          <FormattedMessage defaultMessage="Save" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
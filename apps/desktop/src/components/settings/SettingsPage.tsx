@@ -1,6 +1,7 @@
 import {
   ArrowOutwardRounded,
   AutoAwesomeOutlined,
+  AutoDeleteOutlined,
   AutoFixHighOutlined,
   CodeOutlined,
   DeleteForeverOutlined,
@@ -17,6 +18,7 @@ import {
   PrivacyTipOutlined,
   RocketLaunchOutlined,
   SwapHorizOutlined,
+  TranslateOutlined,
   VolumeUpOutlined,
   WarningAmberOutlined,
 } from "@mui/icons-material";
@@ -40,6 +42,7 @@ import { showErrorSnackbar } from "../../actions/app.actions";
 import { setAutoLaunchEnabled } from "../../actions/settings.actions";
 import { loadTones } from "../../actions/tone.actions";
 import {
+  setLanguageDetectionEnabled,
   setPreferredLanguage,
   setSecondaryDictationLanguage,
 } from "../../actions/user.actions";
@@ -78,10 +81,12 @@ export default function SettingsPage() {
     return user?.preferredLanguage ?? getDetectedSystemLocale();
   });
 
-  const { languageSwitchEnabled, secondaryLanguage } = useAppStore((state) => ({
-    languageSwitchEnabled: state.settings.languageSwitch.enabled,
-    secondaryLanguage: state.settings.languageSwitch.secondaryLanguage,
-  }));
+  const { languageSwitchEnabled, secondaryLanguage, languageAutoDetect } =
+    useAppStore((state) => ({
+      languageSwitchEnabled: state.settings.languageSwitch.enabled,
+      secondaryLanguage: state.settings.languageSwitch.secondaryLanguage,
+      languageAutoDetect: state.settings.languageSwitch.autoDetect,
+    }));
 
   const dictationLanguageWarning = useAppStore((state) => {
     const hasPostProcessingEnabled =
@@ -113,6 +118,12 @@ export default function SettingsPage() {
     void setSecondaryDictationLanguage(nextValue);
   };
 
+  const handleToggleLanguageAutoDetect = (
+    event: ChangeEvent<HTMLInputElement>,
+  ) => {
+    void setLanguageDetectionEnabled(event.target.checked);
+  };
+
   const openChangePasswordDialog = () => {
     produceAppState((state) => {
       state.settings.changePasswordDialogOpen = true;
@@ -167,6 +178,12 @@ export default function SettingsPage() {
     });
   };
 
+  const openRetentionDialog = () => {
+    produceAppState((draft) => {
+      draft.settings.retentionDialogOpen = true;
+    });
+  };
+
   const openClearLocalDataDialog = () => {
     produceAppState((draft) => {
       draft.settings.clearLocalDataDialogOpen = true;
@@ -233,6 +250,11 @@ export default function SettingsPage() {
         leading={<KeyboardAltOutlined />}
         onClick={openShortcutsDialog}
       />
+      <ListTile
+        title={<FormattedMessage defaultMessage="Data retention" />}
+        leading={<AutoDeleteOutlined />}
+        onClick={openRetentionDialog}
+      />
       <ListTile
         title={<FormattedMessage defaultMessage="More settings" />}
         leading={<MoreVertOutlined />}
@@ -352,6 +374,25 @@ export default function SettingsPage() {
           }
         />
       )}
+      {languageSwitchEnabled && (
+        <ListTile
+          title={
+            <FormattedMessage defaultMessage="Detect language automatically" />
+          }
+          subtitle={
+            <FormattedMessage defaultMessage="Pick between your two languages for each dictation instead of using the shortcut." />
+          }
+          leading={<TranslateOutlined />}
+          disableRipple={true}
+          trailing={
+            <Switch
+              edge="end"
+              checked={languageAutoDetect}
+              onChange={handleToggleLanguageAutoDetect}
+            />
+          }
+        />
+      )}
       <ListTile
         title={<FormattedMessage defaultMessage="AI transcription" />}
         leading={<GraphicEqOutlined />}
@@ -11,9 +11,12 @@ import {
   WorkspacePremium,
   LocalFireDepartment,
 } from "@mui/icons-material";
+import type { UsageAnalytics } from "@repo/types";
 import dayjs from "dayjs";
-import { useMemo } from "react";
+import { useEffect, useMemo, useState } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { getAnalyticsRepo } from "../../repos";
 import { useAppStore } from "../../store";
 import { getMyUser, getMyUserName } from "../../utils/user.utils";
 import { DashboardEntryLayout } from "../dashboard/DashboardEntryLayout";
@@ -180,20 +183,19 @@ export default function StatsPage() {
   const intl = useIntl();
   const user = useAppStore(getMyUser);
   const userName = useAppStore(getMyUserName);
-  const transcriptions = useAppStore((state) =>
-    Object.values(state.transcriptionById),
-  );
-  const appTargets = useAppStore((state) => Object.values(state.appTargetById));
+  const [analytics, setAnalytics] = useState<UsageAnalytics | null>(null);
+
+  useEffect(() => {
+    getAnalyticsRepo()
+      .getUsage({ utcOffsetMinutes: -new Date().getTimezoneOffset() })
+      .then(setAnalytics)
+      .catch(showErrorSnackbar);
+  }, []);
 
-  const wordsTotal = user?.wordsTotal ?? 0;
-  const wordsThisMonth = user?.wordsThisMonth ?? 0;
-  const totalApps = appTargets.length;
+  const wordsTotal = analytics?.totals.words ?? 0;
+  const totalApps =
+    analytics?.apps.filter((app) => app.appTargetId !== null).length ?? 0;
   const bestWpm = user?.bestWpm ?? 0;
-  const totalDurationMs = user?.totalDurationMs ?? 0;
-  const avgWpmFromUser =
-    totalDurationMs > 0
-      ? Math.round((wordsTotal / totalDurationMs) * 60000)
-      : 0;
 
   const weeksActive = Math.max(
     1,
@@ -205,66 +207,29 @@ export default function StatsPage() {
   );
 
   const stats = useMemo(() => {
-    if (transcriptions.length === 0) {
-      return {
-        avgWpm: 0,
-        totalTranscriptions: 0,
-        totalDuration: 0,
-        avgWordsPerTranscription: 0,
-        bestDay: null as string | null,
-        bestDayWords: 0,
-        streakDays: 0,
-        todayWords: 0,
-        thisWeekWords: 0,
-        lastWeekWords: 0,
-      };
-    }
-
-    let totalWpm = 0;
-    let totalWords = 0;
-    let totalDuration = 0;
-    const wordsByDay: Record<string, number> = {};
+    const totals = analytics?.totals;
+    const wordsByDay = new Map(
+      (analytics?.wordsByDay ?? []).map((day) => [day.period, day.words]),
+    );
     const today = dayjs().startOf("day");
-    const weekAgo = today.subtract(7, "day");
-    const twoWeeksAgo = today.subtract(14, "day");
+    const monthStart = today.startOf("month").format("YYYY-MM-DD");
+    const weekAgo = today.subtract(7, "day").format("YYYY-MM-DD");
+    const twoWeeksAgo = today.subtract(14, "day").format("YYYY-MM-DD");
 
+    let wordsThisMonth = 0;
     let thisWeekWords = 0;
     let lastWeekWords = 0;
-    let todayWords = 0;
-
-    for (const t of transcriptions) {
-      const words = t.transcript?.split(/\s+/).length ?? 0;
-      const duration = t.audio?.durationMs ?? 0;
-      totalWords += words;
-      totalDuration += duration;
-
-      if (duration > 0) {
-        const wpm = (words / duration) * 60000;
-        totalWpm += wpm;
-      }
-
-      const day = dayjs(t.createdAt).format("YYYY-MM-DD");
-      wordsByDay[day] = (wordsByDay[day] || 0) + words;
-
-      const createdAt = dayjs(t.createdAt);
-      if (createdAt.isAfter(today)) {
-        todayWords += words;
+    let bestDay: string | null = null;
+    let bestDayWords = 0;
+    for (const [day, words] of wordsByDay) {
+      if (day >= monthStart) {
+        wordsThisMonth += words;
       }
-      if (createdAt.isAfter(weekAgo)) {
+      if (day > weekAgo) {
         thisWeekWords += words;
-      } else if (createdAt.isAfter(twoWeeksAgo)) {
+      } else if (day > twoWeeksAgo) {
         lastWeekWords += words;
       }
-    }
-
-    const avgWpm = Math.round(totalWpm / transcriptions.length);
-    const avgWordsPerTranscription = Math.round(
-      totalWords / transcriptions.length,
-    );
-
-    let bestDay: string | null = null;
-    let bestDayWords = 0;
-    for (const [day, words] of Object.entries(wordsByDay)) {
       if (words > bestDayWords) {
         bestDayWords = words;
         bestDay = day;
@@ -272,10 +237,9 @@ export default function StatsPage() {
     }
 
     let streakDays = 0;
-    let checkDate = dayjs().startOf("day");
+    let checkDate = today;
     for (let i = 0; i < 365; i++) {
-      const dateStr = checkDate.format("YYYY-MM-DD");
-      if (wordsByDay[dateStr]) {
+      if (wordsByDay.get(checkDate.format("YYYY-MM-DD"))) {
         streakDays++;
         checkDate = checkDate.subtract(1, "day");
       } else if (i === 0) {
@@ -285,20 +249,24 @@ export default function StatsPage() {
       }
     }
 
+    const totalTranscriptions = totals?.transcriptions ?? 0;
     return {
-      avgWpm,
-      totalTranscriptions: transcriptions.length,
-      totalDuration,
-      avgWordsPerTranscription,
+      avgWpm: Math.round(totals?.averageWpm ?? 0),
+      totalTranscriptions,
+      avgWordsPerTranscription:
+        totalTranscriptions > 0
+          ? Math.round((totals?.words ?? 0) / totalTranscriptions)
+          : 0,
       bestDay,
       bestDayWords,
       streakDays,
-      todayWords,
+      wordsThisMonth,
       thisWeekWords,
       lastWeekWords,
     };
-  }, [transcriptions]);
+  }, [analytics]);
 
+  const wordsThisMonth = stats.wordsThisMonth;
   const monthlyGoal = 10000;
   const monthlyProgress = Math.min((wordsThisMonth / monthlyGoal) * 100, 100);
 
@@ -340,9 +308,9 @@ export default function StatsPage() {
           />
           <HeroStatCard
             label="AVERAGE SPEED"
-            value={`${avgWpmFromUser || stats.avgWpm} WPM`}
+            value={`${stats.avgWpm} WPM`}
             emoji="🏆"
-            achievement={getSpeedAchievement(avgWpmFromUser || stats.avgWpm)}
+            achievement={getSpeedAchievement(stats.avgWpm)}
           />
           <HeroStatCard
             label="TOTAL WORDS DICTATED"
@@ -4,11 +4,14 @@ import { getRec } from "@repo/utilities";
 import { useCallback } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import {
+  setAppTargetItnEnabled,
   setAppTargetPasteKeybind,
   setAppTargetTone,
+  setAppTargetTranslationLanguage,
 } from "../../actions/app-target.actions";
 import { useAppStore } from "../../store";
 import { isMacOS } from "../../utils/env.utils";
+import { getDisplayNameForLanguage } from "../../utils/language.utils";
 import { ListTile } from "../common/ListTile";
 import {
   MenuPopoverBuilder,
@@ -18,6 +21,9 @@ import { StorageImage } from "../common/StorageImage";
 import { ToneSelect } from "../tones/ToneSelect";
 import { PostProcessingDisabledTooltip } from "./PostProcessingDisabledTooltip";
 
+// English uses Whisper's built-in translation; the rest need post-processing.
+const TRANSLATION_LANGUAGES = ["en", "es", "fr", "de", "it", "pt", "zh", "ja"];
+
 export type StylingRowProps = {
   id: string;
 };
@@ -57,10 +63,94 @@ export const StylingRow = ({ id }: StylingRowProps) => {
     [target],
   );
 
+  const handleItnToggle = useCallback(() => {
+    if (!target) {
+      return;
+    }
+
+    void setAppTargetItnEnabled(target.id, !target.itnEnabled);
+  }, [target]);
+
+  const handleTranslationChange = useCallback(
+    (language: string | null) => {
+      if (!target) {
+        return;
+      }
+
+      void setAppTargetTranslationLanguage(target.id, language);
+    },
+    [target],
+  );
+
   const toneValue = target?.toneId ?? null;
   const pasteKeybindValue = target?.pasteKeybind ?? "ctrl+v";
+  const itnEnabled = target?.itnEnabled ?? true;
+  const translationLanguage = target?.translationLanguage ?? null;
+
+  const formattingMenuItems: MenuPopoverItem[] = [
+    {
+      kind: "genericItem",
+      builder: () => (
+        <Box sx={{ px: 2, py: 1.5, maxWidth: 280 }}>
+          <Typography variant="subtitle2" sx={{ mb: 0.5 }}>
+            <FormattedMessage defaultMessage="Written Formatting" />
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="Write spoken numbers, dates, times, amounts, emails and links the way you would type them." />
+          </Typography>
+        </Box>
+      ),
+    },
+    { kind: "divider" },
+    {
+      kind: "listItem",
+      title: <FormattedMessage defaultMessage="Format numbers and dates" />,
+      trailing: itnEnabled ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleItnToggle();
+        close();
+      },
+    },
+  ];
+
+  const translationMenuItems: MenuPopoverItem[] = [
+    { kind: "divider" },
+    {
+      kind: "genericItem",
+      builder: () => (
+        <Box sx={{ px: 2, py: 1.5, maxWidth: 280 }}>
+          <Typography variant="subtitle2" sx={{ mb: 0.5 }}>
+            <FormattedMessage defaultMessage="Translate To" />
+          </Typography>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="Insert your dictation in another language. English works offline; other languages use your post-processing model." />
+          </Typography>
+        </Box>
+      ),
+    },
+    { kind: "divider" },
+    {
+      kind: "listItem",
+      title: <FormattedMessage defaultMessage="Off" />,
+      trailing: translationLanguage === null ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleTranslationChange(null);
+        close();
+      },
+    },
+    ...TRANSLATION_LANGUAGES.map<MenuPopoverItem>((language) => ({
+      kind: "listItem",
+      title: getDisplayNameForLanguage(language),
+      trailing: translationLanguage === language ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleTranslationChange(language);
+        close();
+      },
+    })),
+  ];
 
   const pasteKeybindMenuItems: MenuPopoverItem[] = [
+    { kind: "divider" },
     {
       kind: "genericItem",
       builder: () => (
@@ -131,21 +221,29 @@ export const StylingRow = ({ id }: StylingRowProps) => {
           formControlSx={{ minWidth: 140 }}
         />
       </PostProcessingDisabledTooltip>
-      {!isMacOS() && (
-        <MenuPopoverBuilder items={pasteKeybindMenuItems}>
-          {({ ref, open }) => (
-            <IconButton
-              ref={ref}
-              onClick={open}
-              disabled={!target}
-              size="small"
-              sx={{ width: 32, height: 32, p: 0 }}
-            >
-              <MoreVert fontSize="small" />
-            </IconButton>
-          )}
-        </MenuPopoverBuilder>
-      )}
+      <MenuPopoverBuilder
+        items={
+          isMacOS()
+            ? [...formattingMenuItems, ...translationMenuItems]
+            : [
+                ...formattingMenuItems,
+                ...translationMenuItems,
+                ...pasteKeybindMenuItems,
+              ]
+        }
+      >
+        {({ ref, open }) => (
+          <IconButton
+            ref={ref}
+            onClick={open}
+            disabled={!target}
+            size="small"
+            sx={{ width: 32, height: 32, p: 0 }}
+          >
+            <MoreVert fontSize="small" />
+          </IconButton>
+        )}
+      </MenuPopoverBuilder>
     </Stack>
   );
 
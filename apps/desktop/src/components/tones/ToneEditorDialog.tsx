@@ -11,13 +11,14 @@ import {
   TextField,
   Typography,
 } from "@mui/material";
-import { Tone } from "@repo/types";
+import { Tone, ToneTemplatePreview } from "@repo/types";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import { setAppTargetTone } from "../../actions/app-target.actions";
 import {
   closeToneEditorDialog,
   deleteTone,
+  previewToneTemplate,
   upsertTone,
 } from "../../actions/tone.actions";
 import { useAppStore } from "../../store";
@@ -25,6 +26,7 @@ import { createId } from "../../utils/id.utils";
 import { ConfirmDialog } from "../common/ConfirmDialog";
 
 const MAX_PROMPT_LEN = 1000;
+const PREVIEW_DEBOUNCE_MS = 300;
 
 export const ToneEditorDialog = () => {
   const toneEditor = useAppStore((state) => state.toneEditor);
@@ -84,6 +86,7 @@ export const ToneEditorDialog = () => {
   const [isSaving, setIsSaving] = useState(false);
   const [isDeleting, setIsDeleting] = useState(false);
   const [isConfirmOpen, setIsConfirmOpen] = useState(false);
+  const [preview, setPreview] = useState<ToneTemplatePreview | null>(null);
 
   useEffect(() => {
     if (isEditMode && tone) {
@@ -101,6 +104,33 @@ export const ToneEditorDialog = () => {
     }
   }, [toneEditor.open]);
 
+  useEffect(() => {
+    if (!toneEditor.open || !promptTemplate.trim()) {
+      setPreview(null);
+      return;
+    }
+
+    let cancelled = false;
+    const timeout = setTimeout(() => {
+      previewToneTemplate(promptTemplate)
+        .then((result) => {
+          if (!cancelled) {
+            setPreview(result);
+          }
+        })
+        .catch((error) => {
+          console.error("Failed to preview tone template", error);
+        });
+    }, PREVIEW_DEBOUNCE_MS);
+
+    return () => {
+      cancelled = true;
+      clearTimeout(timeout);
+    };
+  }, [promptTemplate, toneEditor.open]);
+
+  const templateIssues = preview?.issues ?? [];
+
   const hasChanges =
     isEditMode &&
     tone &&
@@ -223,6 +253,7 @@ export const ToneEditorDialog = () => {
               fullWidth
               placeholder="Make it sound like a professional but friendly email. Use jargon and fun words."
               inputProps={{ maxLength: MAX_PROMPT_LEN }}
+              error={templateIssues.length > 0}
               helperText={
                 <Typography
                   variant="caption"
@@ -232,6 +263,42 @@ export const ToneEditorDialog = () => {
                 </Typography>
               }
             />
+
+            {templateIssues.length > 0 ? (
+              <Stack spacing={0.5}>
+                {templateIssues.map((issue) => (
+                  <Typography
+                    key={`${issue.line}:${issue.column}:${issue.message}`}
+                    variant="caption"
+                    color="error"
+                  >
+                    <FormattedMessage
+                      defaultMessage="Line {line}, column {column}: {message}"
+                      values={{
+                        line: issue.line,
+                        column: issue.column,
+                        message: issue.message,
+                      }}
+                    />
+                  </Typography>
+                ))}
+              </Stack>
+            ) : (
+              preview &&
+              preview.rendered !== promptTemplate && (
+                <Box>
+                  <Typography variant="caption" color="text.secondary">
+                    <FormattedMessage defaultMessage="Preview with sample values" />
+                  </Typography>
+                  <Typography
+                    variant="body2"
+                    sx={{ whiteSpace: "pre-wrap", mt: 0.5 }}
+                  >
+                    {preview.rendered}
+                  </Typography>
+                </Box>
+              )
+            )}
           </Stack>
         </DialogContent>
 
@@ -259,6 +326,7 @@ export const ToneEditorDialog = () => {
               isSaving ||
               !name.trim() ||
               !promptTemplate.trim() ||
+              templateIssues.length > 0 ||
               (isEditMode && !hasChanges)
             }
           >
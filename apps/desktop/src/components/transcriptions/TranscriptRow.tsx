@@ -4,6 +4,8 @@ import InfoOutlinedIcon from "@mui/icons-material/InfoOutlined";
 import PauseRoundedIcon from "@mui/icons-material/PauseRounded";
 import PlayArrowRoundedIcon from "@mui/icons-material/PlayArrowRounded";
 import ReplayRoundedIcon from "@mui/icons-material/ReplayRounded";
+import StarBorderRoundedIcon from "@mui/icons-material/StarBorderRounded";
+import StarRoundedIcon from "@mui/icons-material/StarRounded";
 import {
   Box,
   Divider,
@@ -18,6 +20,7 @@ import dayjs from "dayjs";
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { useIntl } from "react-intl";
 import { showErrorSnackbar, showSnackbar } from "../../actions/app.actions";
+import { setStarred } from "../../actions/retention.actions";
 import {
   openTranscriptionDetailsDialog,
   retranscribeTranscription,
@@ -356,6 +359,32 @@ export const TranscriptionRow = ({ id }: TranscriptionRowProps) => {
           {dayjs(transcription?.createdAt).format("MMM D, YYYY h:mm A")}
         </Typography>
         <Stack direction="row" spacing={1}>
+          <Tooltip
+            title={
+              transcription?.starred
+                ? intl.formatMessage({ defaultMessage: "Unstar" })
+                : intl.formatMessage({
+                    defaultMessage: "Star to keep when cleaning up",
+                  })
+            }
+            placement="top"
+          >
+            <IconButton
+              aria-label={intl.formatMessage({ defaultMessage: "Star" })}
+              aria-pressed={Boolean(transcription?.starred)}
+              onClick={() =>
+                void setStarred("transcription", id, !transcription?.starred)
+              }
+              size="small"
+              color={transcription?.starred ? "primary" : "default"}
+            >
+              {transcription?.starred ? (
+                <StarRoundedIcon fontSize="small" />
+              ) : (
+                <StarBorderRoundedIcon fontSize="small" />
+              )}
+            </IconButton>
+          </Tooltip>
           <Tooltip
             title={intl.formatMessage({
               defaultMessage: "View transcription details",
@@ -1,3 +1,4 @@
+import EditOutlinedIcon from "@mui/icons-material/EditOutlined";
 import ReplayRoundedIcon from "@mui/icons-material/ReplayRounded";
 import {
   Box,
@@ -8,18 +9,21 @@ import {
   DialogTitle,
   Divider,
   Stack,
+  TextField,
   Typography,
 } from "@mui/material";
 import { getRec } from "@repo/utilities";
-import { useCallback, useMemo, useState } from "react";
+import { useCallback, useEffect, useMemo, useState } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import { showErrorSnackbar } from "../../actions/app.actions";
 import {
   closeTranscriptionDetailsDialog,
   retranscribeTranscription,
+  updateTranscriptText,
 } from "../../actions/transcriptions.actions";
 import { AppState } from "../../state/app.state";
 import { useAppStore } from "../../store";
+import { getDisplayNameForLanguage } from "../../utils/language.utils";
 import { TranscriptionToneMenu } from "./TranscriptionToneMenu";
 
 const formatModelSizeLabel = (
@@ -115,11 +119,33 @@ export const TranscriptionDetailsDialog = () => {
   const apiKeysById = useAppStore((state) => state.apiKeyById);
   const intl = useIntl();
   const [isRetranscribing, setIsRetranscribing] = useState(false);
+  const [draftTranscript, setDraftTranscript] = useState<string | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    setDraftTranscript(null);
+  }, [open, transcription?.id]);
 
   const handleClose = useCallback(() => {
     closeTranscriptionDetailsDialog();
   }, []);
 
+  const handleSaveTranscript = useCallback(async () => {
+    if (!transcription?.id || draftTranscript === null) {
+      return;
+    }
+
+    try {
+      setIsSaving(true);
+      await updateTranscriptText(transcription.id, draftTranscript.trim());
+      setDraftTranscript(null);
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setIsSaving(false);
+    }
+  }, [draftTranscript, transcription?.id]);
+
   const handleRetranscribe = useCallback(
     async (toneId: string | null) => {
       if (!transcription?.id) {
@@ -201,6 +227,22 @@ export const TranscriptionDetailsDialog = () => {
     [transcription?.modelSize],
   );
 
+  const languageLabel = useMemo(() => {
+    const spoken =
+      transcription?.detectedLanguage ?? transcription?.sourceLanguage;
+    if (!spoken) {
+      return <FormattedMessage defaultMessage="Unknown" />;
+    }
+
+    const name = getDisplayNameForLanguage(spoken);
+    const target = transcription?.targetLanguage;
+    return target ? `${name} → ${getDisplayNameForLanguage(target)}` : name;
+  }, [
+    transcription?.detectedLanguage,
+    transcription?.sourceLanguage,
+    transcription?.targetLanguage,
+  ]);
+
   const deviceLabel = useMemo(() => {
     const value = transcription?.inferenceDevice?.trim();
     return value && value.length > 0 ? (
@@ -303,15 +345,30 @@ export const TranscriptionDetailsDialog = () => {
                       monospace: true,
                     },
                   )}
-                {renderTextBlock(
-                  <FormattedMessage defaultMessage="Final transcription" />,
-                  finalTranscriptText,
-                  {
-                    placeholder: (
-                      <FormattedMessage defaultMessage="Final transcript unavailable." />
-                    ),
-                    monospace: true,
-                  },
+                {draftTranscript !== null ? (
+                  <TextField
+                    label={
+                      <FormattedMessage defaultMessage="Final transcription" />
+                    }
+                    value={draftTranscript}
+                    onChange={(event) => setDraftTranscript(event.target.value)}
+                    disabled={isSaving}
+                    multiline
+                    minRows={3}
+                    autoFocus
+                    fullWidth
+                  />
+                ) : (
+                  renderTextBlock(
+                    <FormattedMessage defaultMessage="Final transcription" />,
+                    finalTranscriptText,
+                    {
+                      placeholder: (
+                        <FormattedMessage defaultMessage="Final transcript unavailable." />
+                      ),
+                      monospace: true,
+                    },
+                  )
                 )}
               </Stack>
             </Box>
@@ -418,6 +475,14 @@ export const TranscriptionDetailsDialog = () => {
                     {modelSizeLabel}
                   </Typography>
                 </Box>
+                <Box>
+                  <Typography variant="caption" color="text.secondary">
+                    <FormattedMessage defaultMessage="Language" />
+                  </Typography>
+                  <Typography variant="body2" fontWeight={600}>
+                    {languageLabel}
+                  </Typography>
+                </Box>
                 <Box>
                   <Typography variant="caption" color="text.secondary">
                     <FormattedMessage defaultMessage="API Key" />
@@ -490,13 +555,40 @@ export const TranscriptionDetailsDialog = () => {
         )}
       </DialogContent>
       <DialogActions>
+        {draftTranscript !== null ? (
+          <>
+            <Button
+              onClick={() => setDraftTranscript(null)}
+              disabled={isSaving}
+            >
+              <FormattedMessage defaultMessage="Cancel" />
+            </Button>
+            <Button
+              variant="contained"
+              onClick={handleSaveTranscript}
+              disabled={isSaving || !draftTranscript.trim()}
+            >
+              <FormattedMessage defaultMessage="Save" />
+            </Button>
+          </>
+        ) : (
+          <Button
+            startIcon={<EditOutlinedIcon />}
+            onClick={() => setDraftTranscript(finalTranscriptText)}
+            disabled={isRetranscribing || !transcription}
+          >
+            <FormattedMessage defaultMessage="Edit" />
+          </Button>
+        )}
         <TranscriptionToneMenu onToneSelect={handleRetranscribe}>
           {({ ref, open }) => (
             <Button
               ref={ref}
               startIcon={<ReplayRoundedIcon />}
               onClick={open}
-              disabled={isRetranscribing || !transcription}
+              disabled={
+                isRetranscribing || !transcription || draftTranscript !== null
+              }
             >
               <FormattedMessage defaultMessage="Retranscribe" />
             </Button>
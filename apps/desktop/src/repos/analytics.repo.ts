@@ -0,0 +1,13 @@
+import { UsageAnalytics, UsageAnalyticsRequest } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { BaseRepo } from "./base.repo";
+
+export abstract class BaseAnalyticsRepo extends BaseRepo {
+  abstract getUsage(request: UsageAnalyticsRequest): Promise<UsageAnalytics>;
+}
+
+export class LocalAnalyticsRepo extends BaseAnalyticsRepo {
+  async getUsage(request: UsageAnalyticsRequest): Promise<UsageAnalytics> {
+    return invoke<UsageAnalytics>("analytics_usage", { request });
+  }
+}
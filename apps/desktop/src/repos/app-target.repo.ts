@@ -1,4 +1,4 @@
-import { AppTarget } from "@repo/types";
+import { AppProfile, AppTarget, ResolvedAppProfile } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { BaseRepo } from "./base.repo";
 
@@ -8,11 +8,19 @@ export type AppTargetUpsertParams = {
   toneId: string | null;
   iconPath: string | null;
   pasteKeybind: string | null;
+  // Omitted to keep the stored value.
+  itnEnabled?: boolean;
+  // Omitted to keep the stored value; an empty string clears it.
+  translationLanguage?: string;
 };
 
 export abstract class BaseAppTargetRepo extends BaseRepo {
   abstract listAppTargets(): Promise<AppTarget[]>;
   abstract upsertAppTarget(params: AppTargetUpsertParams): Promise<AppTarget>;
+  abstract listAppProfiles(): Promise<AppProfile[]>;
+  abstract upsertAppProfile(profile: AppProfile): Promise<AppProfile>;
+  abstract deleteAppProfile(id: string): Promise<void>;
+  abstract resolveAppProfile(): Promise<ResolvedAppProfile>;
 }
 
 export class LocalAppTargetRepo extends BaseAppTargetRepo {
@@ -25,4 +33,20 @@ export class LocalAppTargetRepo extends BaseAppTargetRepo {
       args: params,
     });
   }
+
+  async listAppProfiles(): Promise<AppProfile[]> {
+    return invoke<AppProfile[]>("app_profile_list");
+  }
+
+  async upsertAppProfile(profile: AppProfile): Promise<AppProfile> {
+    return invoke<AppProfile>("app_profile_upsert", { profile });
+  }
+
+  async deleteAppProfile(id: string): Promise<void> {
+    await invoke("app_profile_delete", { id });
+  }
+
+  async resolveAppProfile(): Promise<ResolvedAppProfile> {
+    return invoke<ResolvedAppProfile>("app_profile_resolve");
+  }
 }
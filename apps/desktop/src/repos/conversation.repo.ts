@@ -1,10 +1,26 @@
-import { Conversation, Message } from "@repo/types";
+import {
+  BranchMessage,
+  Conversation,
+  ConversationContext,
+  ConversationContextRequest,
+  ConversationCompaction,
+  ConversationSummary,
+  ConversationSummaryRequest,
+  MeetingAnswer,
+  MeetingCitation,
+  MeetingQuestion,
+  MeetingQuestionPrompt,
+  Message,
+} from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { BaseRepo } from "./base.repo";
 
 type LocalConversation = {
   id: string;
   title: string;
+  meetingId?: string | null;
+  headMessageId?: string | null;
+  starred?: boolean;
   createdAt: string;
   updatedAt: string;
 };
@@ -12,6 +28,7 @@ type LocalConversation = {
 type LocalMessage = {
   id: string;
   conversationId: string;
+  parentId?: string | null;
   role: string;
   content: string;
   model?: string | null;
@@ -23,6 +40,9 @@ type LocalMessage = {
 const toLocalConversation = (c: Conversation): LocalConversation => ({
   id: c.id,
   title: c.title,
+  meetingId: c.meetingId ?? null,
+  headMessageId: c.headMessageId ?? null,
+  starred: c.starred ?? false,
   createdAt: c.createdAt,
   updatedAt: c.updatedAt,
 });
@@ -30,6 +50,9 @@ const toLocalConversation = (c: Conversation): LocalConversation => ({
 const fromLocalConversation = (c: LocalConversation): Conversation => ({
   id: c.id,
   title: c.title,
+  meetingId: c.meetingId ?? undefined,
+  headMessageId: c.headMessageId ?? undefined,
+  starred: c.starred ?? false,
   createdAt: c.createdAt,
   updatedAt: c.updatedAt,
 });
@@ -37,6 +60,7 @@ const fromLocalConversation = (c: LocalConversation): Conversation => ({
 const toLocalMessage = (m: Message): LocalMessage => ({
   id: m.id,
   conversationId: m.conversationId,
+  parentId: m.parentId ?? null,
   role: m.role,
   content: m.content,
   model: m.model ?? null,
@@ -50,6 +74,7 @@ const VALID_ROLES: Message["role"][] = ["user", "assistant", "system"];
 const fromLocalMessage = (m: LocalMessage): Message => ({
   id: m.id,
   conversationId: m.conversationId,
+  parentId: m.parentId ?? undefined,
   role: VALID_ROLES.includes(m.role as Message["role"])
     ? (m.role as Message["role"])
     : "user",
@@ -60,6 +85,37 @@ const fromLocalMessage = (m: LocalMessage): Message => ({
   createdAt: m.createdAt,
 });
 
+type LocalBranchMessage = LocalMessage & {
+  siblingIds: string[];
+};
+
+const fromLocalBranchMessage = (m: LocalBranchMessage): BranchMessage => ({
+  ...fromLocalMessage(m),
+  siblingIds: m.siblingIds,
+});
+
+type LocalConversationContext = {
+  summary?: ConversationSummary | null;
+  messages: LocalMessage[];
+  compaction?: ConversationCompaction | null;
+  budgetTokens: number;
+  estimatedTokens: number;
+};
+
+type LocalMeetingAnswer = {
+  conversation: LocalConversation;
+  question: LocalMessage;
+  answer: LocalMessage;
+  citations: MeetingCitation[];
+};
+
+const fromLocalMeetingAnswer = (a: LocalMeetingAnswer): MeetingAnswer => ({
+  conversation: fromLocalConversation(a.conversation),
+  question: fromLocalMessage(a.question),
+  answer: fromLocalMessage(a.answer),
+  citations: a.citations,
+});
+
 export type ListConversationsParams = {
   limit?: number;
   offset?: number;
@@ -74,6 +130,36 @@ export abstract class BaseConversationRepo extends BaseRepo {
   abstract deleteConversation(id: string): Promise<void>;
   abstract createMessage(m: Message): Promise<Message>;
   abstract listMessages(conversationId: string): Promise<Message[]>;
+  abstract getBranch(
+    conversationId: string,
+    leafMessageId?: string | null,
+  ): Promise<BranchMessage[]>;
+  abstract switchBranch(
+    conversationId: string,
+    messageId: string,
+  ): Promise<BranchMessage[]>;
+  abstract buildContext(
+    request: ConversationContextRequest,
+  ): Promise<ConversationContext>;
+  abstract createSummary(
+    request: ConversationSummaryRequest,
+  ): Promise<ConversationSummary>;
+  abstract forkConversation(
+    conversationId: string,
+    messageId: string,
+  ): Promise<Conversation>;
+  abstract getMeetingConversation(
+    meetingId: string,
+  ): Promise<Conversation | null>;
+  abstract askAboutMeetings(question: MeetingQuestion): Promise<MeetingAnswer>;
+  abstract prepareMeetingQuestion(
+    question: MeetingQuestion,
+  ): Promise<MeetingQuestionPrompt>;
+  abstract recordMeetingAnswer(
+    prompt: MeetingQuestionPrompt,
+    answer: string,
+    model: string | null,
+  ): Promise<MeetingAnswer>;
 }
 
 export class LocalConversationRepo extends BaseConversationRepo {
@@ -120,4 +206,108 @@ export class LocalConversationRepo extends BaseConversationRepo {
     });
     return messages.map(fromLocalMessage);
   }
+
+  async getBranch(
+    conversationId: string,
+    leafMessageId?: string | null,
+  ): Promise<BranchMessage[]> {
+    const branch = await invoke<LocalBranchMessage[]>("conversation_branch", {
+      conversationId,
+      leafMessageId: leafMessageId ?? null,
+    });
+    return branch.map(fromLocalBranchMessage);
+  }
+
+  async switchBranch(
+    conversationId: string,
+    messageId: string,
+  ): Promise<BranchMessage[]> {
+    const branch = await invoke<LocalBranchMessage[]>(
+      "conversation_switch_branch",
+      { conversationId, messageId },
+    );
+    return branch.map(fromLocalBranchMessage);
+  }
+
+  async buildContext(
+    request: ConversationContextRequest,
+  ): Promise<ConversationContext> {
+    const context = await invoke<LocalConversationContext>(
+      "conversation_context",
+      {
+        request: {
+          conversationId: request.conversationId,
+          leafMessageId: request.leafMessageId ?? null,
+          model: request.model,
+          reservedTokens: Math.max(0, Math.trunc(request.reservedTokens ?? 0)),
+          contextWindow: request.contextWindow ?? null,
+          ollamaApiKeyId: request.ollamaApiKeyId ?? null,
+        },
+      },
+    );
+    return {
+      summary: context.summary ?? undefined,
+      messages: context.messages.map(fromLocalMessage),
+      compaction: context.compaction ?? null,
+      budgetTokens: context.budgetTokens,
+      estimatedTokens: context.estimatedTokens,
+    };
+  }
+
+  async createSummary(
+    request: ConversationSummaryRequest,
+  ): Promise<ConversationSummary> {
+    return invoke<ConversationSummary>("conversation_summary_create", {
+      request: { ...request, model: request.model ?? null },
+    });
+  }
+
+  async forkConversation(
+    conversationId: string,
+    messageId: string,
+  ): Promise<Conversation> {
+    const stored = await invoke<LocalConversation>("conversation_fork", {
+      conversationId,
+      messageId,
+    });
+    return fromLocalConversation(stored);
+  }
+
+  async getMeetingConversation(
+    meetingId: string,
+  ): Promise<Conversation | null> {
+    const stored = await invoke<LocalConversation | null>(
+      "meeting_conversation_get",
+      { meetingId },
+    );
+    return stored ? fromLocalConversation(stored) : null;
+  }
+
+  async askAboutMeetings(question: MeetingQuestion): Promise<MeetingAnswer> {
+    const result = await invoke<LocalMeetingAnswer>("meeting_ask", {
+      request: question,
+    });
+    return fromLocalMeetingAnswer(result);
+  }
+
+  async prepareMeetingQuestion(
+    question: MeetingQuestion,
+  ): Promise<MeetingQuestionPrompt> {
+    return invoke<MeetingQuestionPrompt>("meeting_ask_prepare", {
+      request: question,
+    });
+  }
+
+  async recordMeetingAnswer(
+    prompt: MeetingQuestionPrompt,
+    answer: string,
+    model: string | null,
+  ): Promise<MeetingAnswer> {
+    const result = await invoke<LocalMeetingAnswer>("meeting_ask_record", {
+      prompt,
+      answer,
+      model,
+    });
+    return fromLocalMeetingAnswer(result);
+  }
 }
@@ -55,6 +55,12 @@ import {
 } from "./conversation.repo";
 import { BaseMeetingRepo, LocalMeetingRepo } from "./meeting.repo";
 import { AssemblyAIDiarizeRepo, BaseDiarizeRepo } from "./diarize.repo";
+import { BaseRetentionRepo, LocalRetentionRepo } from "./retention.repo";
+import { BaseAnalyticsRepo, LocalAnalyticsRepo } from "./analytics.repo";
+import {
+  BaseTermSuggestionRepo,
+  LocalTermSuggestionRepo,
+} from "./term-suggestion.repo";
 
 const shouldUseCloud = () => getHasCloudAccess(getAppState());
 
@@ -82,6 +88,10 @@ export const getTermRepo = (): BaseTermRepo => {
   return shouldUseCloud() ? new CloudTermRepo() : new LocalTermRepo();
 };
 
+export const getTermSuggestionRepo = (): BaseTermSuggestionRepo => {
+  return new LocalTermSuggestionRepo();
+};
+
 export const getHotkeyRepo = (): BaseHotkeyRepo => {
   return new LocalHotkeyRepo();
 };
@@ -102,6 +112,14 @@ export const getMeetingRepo = (): BaseMeetingRepo => {
   return new LocalMeetingRepo();
 };
 
+export const getRetentionRepo = (): BaseRetentionRepo => {
+  return new LocalRetentionRepo();
+};
+
+export const getAnalyticsRepo = (): BaseAnalyticsRepo => {
+  return new LocalAnalyticsRepo();
+};
+
 export const getDiarizeRepo = (): BaseDiarizeRepo | null => {
   const state = getAppState();
   const assemblyAiKey = Object.values(state.apiKeyById).find(
@@ -1,4 +1,13 @@
-import type { Meeting, MeetingSegment } from "@repo/types";
+import type {
+  MediaImportInput,
+  Meeting,
+  MeetingActionItem,
+  MeetingDecision,
+  MeetingSegment,
+  OpenMeetingActionItem,
+  TranscriptExport,
+  TranscriptExportOptions,
+} from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { BaseRepo } from "./base.repo";
 
@@ -13,6 +22,7 @@ type LocalMeeting = {
   audioPath?: string | null;
   summary?: string | null;
   actionItems?: string | null;
+  starred?: boolean;
   createdAt: string;
   updatedAt: string;
 };
@@ -29,7 +39,7 @@ type LocalMeetingSegment = {
 };
 
 type MeetingAudioResult = {
-  filePath: string;
+  filePath: string | null;
   durationMs: number;
 };
 
@@ -51,6 +61,7 @@ const toLocalMeeting = (m: Meeting): LocalMeeting => ({
   audioPath: m.audioPath ?? null,
   summary: m.summary ?? null,
   actionItems: m.actionItems ?? null,
+  starred: m.starred ?? false,
   createdAt: m.createdAt,
   updatedAt: m.updatedAt,
 });
@@ -68,6 +79,7 @@ const fromLocalMeeting = (m: LocalMeeting): Meeting => ({
   audioPath: m.audioPath ?? undefined,
   summary: m.summary ?? undefined,
   actionItems: m.actionItems ?? undefined,
+  starred: m.starred ?? false,
   createdAt: m.createdAt,
   updatedAt: m.updatedAt,
 });
@@ -122,6 +134,34 @@ export abstract class BaseMeetingRepo extends BaseRepo {
   abstract loadMeetingAudio(
     meetingId: string,
   ): Promise<{ samples: number[]; sampleRate: number }>;
+  abstract exportMeeting(
+    meetingId: string,
+    options: TranscriptExportOptions,
+  ): Promise<TranscriptExport>;
+  abstract importMeetingMedia(input: MediaImportInput): Promise<Meeting>;
+  abstract listActionItems(meetingId: string): Promise<MeetingActionItem[]>;
+  abstract listOpenActionItems(
+    params?: ListMeetingsParams,
+  ): Promise<OpenMeetingActionItem[]>;
+  abstract createActionItem(
+    item: MeetingActionItem,
+  ): Promise<MeetingActionItem>;
+  abstract updateActionItem(
+    item: MeetingActionItem,
+  ): Promise<MeetingActionItem>;
+  abstract deleteActionItem(id: string): Promise<void>;
+  abstract replaceActionItems(
+    meetingId: string,
+    items: MeetingActionItem[],
+  ): Promise<MeetingActionItem[]>;
+  abstract listDecisions(meetingId: string): Promise<MeetingDecision[]>;
+  abstract createDecision(decision: MeetingDecision): Promise<MeetingDecision>;
+  abstract updateDecision(decision: MeetingDecision): Promise<MeetingDecision>;
+  abstract deleteDecision(id: string): Promise<void>;
+  abstract replaceDecisions(
+    meetingId: string,
+    decisions: MeetingDecision[],
+  ): Promise<MeetingDecision[]>;
 }
 
 export class LocalMeetingRepo extends BaseMeetingRepo {
@@ -207,4 +247,90 @@ export class LocalMeetingRepo extends BaseMeetingRepo {
       { meetingId },
     );
   }
+
+  async exportMeeting(
+    meetingId: string,
+    options: TranscriptExportOptions,
+  ): Promise<TranscriptExport> {
+    return invoke<TranscriptExport>("meeting_export", { meetingId, options });
+  }
+
+  async importMeetingMedia({
+    importId,
+    path,
+    modelSize,
+  }: MediaImportInput): Promise<Meeting> {
+    const result = await invoke<{ meeting: LocalMeeting }>("media_import", {
+      importId,
+      path,
+      target: "meeting",
+      options: { modelSize },
+    });
+    return fromLocalMeeting(result.meeting);
+  }
+
+  async listActionItems(meetingId: string): Promise<MeetingActionItem[]> {
+    return invoke<MeetingActionItem[]>("meeting_action_item_list", {
+      meetingId,
+    });
+  }
+
+  async listOpenActionItems(
+    params: ListMeetingsParams = {},
+  ): Promise<OpenMeetingActionItem[]> {
+    const limit = Math.max(0, Math.trunc(params.limit ?? 50));
+    const offset = Math.max(0, Math.trunc(params.offset ?? 0));
+    return invoke<OpenMeetingActionItem[]>("meeting_action_item_list_open", {
+      limit,
+      offset,
+    });
+  }
+
+  async createActionItem(item: MeetingActionItem): Promise<MeetingActionItem> {
+    return invoke<MeetingActionItem>("meeting_action_item_create", { item });
+  }
+
+  async updateActionItem(item: MeetingActionItem): Promise<MeetingActionItem> {
+    return invoke<MeetingActionItem>("meeting_action_item_update", { item });
+  }
+
+  async deleteActionItem(id: string): Promise<void> {
+    await invoke<void>("meeting_action_item_delete", { id });
+  }
+
+  async replaceActionItems(
+    meetingId: string,
+    items: MeetingActionItem[],
+  ): Promise<MeetingActionItem[]> {
+    return invoke<MeetingActionItem[]>("meeting_action_items_replace", {
+      meetingId,
+      items,
+    });
+  }
+
+  async listDecisions(meetingId: string): Promise<MeetingDecision[]> {
+    return invoke<MeetingDecision[]>("meeting_decision_list", { meetingId });
+  }
+
+  async createDecision(decision: MeetingDecision): Promise<MeetingDecision> {
+    return invoke<MeetingDecision>("meeting_decision_create", { decision });
+  }
+
+  async updateDecision(decision: MeetingDecision): Promise<MeetingDecision> {
+    return invoke<MeetingDecision>("meeting_decision_update", { decision });
+  }
+
+  async deleteDecision(id: string): Promise<void> {
+    await invoke<void>("meeting_decision_delete", { id });
+  }
+
+  async replaceDecisions(
+    meetingId: string,
+    decisions: MeetingDecision[],
+  ): Promise<MeetingDecision[]> {
+    return invoke<MeetingDecision[]>("meeting_decisions_replace", {
+      meetingId,
+      decisions,
+    });
+  }
 }
@@ -30,11 +30,16 @@ type LocalUserPreferences = {
   languageSwitchEnabled: boolean;
   secondaryDictationLanguage: Nullable<string>;
   activeDictationLanguage: Nullable<string>;
+  languageDetectionEnabled: boolean;
   preferredMicrophone: Nullable<string>;
   ignoreUpdateDialog: boolean;
   incognitoModeEnabled: boolean;
   incognitoModeIncludeInStats: boolean;
   dictationPillVisibility: DictationPillVisibility;
+  ambientWakePhrase: Nullable<string>;
+  warmMicrophoneMs: Nullable<number>;
+  inputGain: Nullable<number>;
+  voiceCommandKeywordRequired: boolean;
 };
 
 // Normalize post-processing mode for backwards compatibility
@@ -76,6 +81,7 @@ const fromLocalPreferences = (
   activeDictationLanguage:
     (preferences.activeDictationLanguage as "primary" | "secondary") ??
     "primary",
+  languageDetectionEnabled: preferences.languageDetectionEnabled ?? false,
   preferredMicrophone: preferences.preferredMicrophone ?? null,
   ignoreUpdateDialog: preferences.ignoreUpdateDialog ?? false,
   incognitoModeEnabled: preferences.incognitoModeEnabled ?? false,
@@ -83,6 +89,10 @@ const fromLocalPreferences = (
   dictationPillVisibility: getEffectivePillVisibility(
     preferences.dictationPillVisibility,
   ),
+  ambientWakePhrase: preferences.ambientWakePhrase ?? null,
+  warmMicrophoneMs: preferences.warmMicrophoneMs ?? null,
+  inputGain: preferences.inputGain ?? null,
+  voiceCommandKeywordRequired: preferences.voiceCommandKeywordRequired ?? false,
 });
 
 const toLocalPreferences = (
@@ -107,6 +117,7 @@ const toLocalPreferences = (
   languageSwitchEnabled: preferences.languageSwitchEnabled ?? false,
   secondaryDictationLanguage: preferences.secondaryDictationLanguage ?? null,
   activeDictationLanguage: preferences.activeDictationLanguage ?? "primary",
+  languageDetectionEnabled: preferences.languageDetectionEnabled ?? false,
   preferredMicrophone: preferences.preferredMicrophone ?? null,
   ignoreUpdateDialog: preferences.ignoreUpdateDialog ?? false,
   incognitoModeEnabled: preferences.incognitoModeEnabled ?? false,
@@ -114,6 +125,10 @@ const toLocalPreferences = (
   dictationPillVisibility: getEffectivePillVisibility(
     preferences.dictationPillVisibility,
   ),
+  ambientWakePhrase: preferences.ambientWakePhrase ?? null,
+  warmMicrophoneMs: preferences.warmMicrophoneMs ?? null,
+  inputGain: preferences.inputGain ?? null,
+  voiceCommandKeywordRequired: preferences.voiceCommandKeywordRequired ?? false,
 });
 
 export abstract class BaseUserPreferencesRepo extends BaseRepo {
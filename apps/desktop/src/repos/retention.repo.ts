@@ -0,0 +1,41 @@
+import { RetentionPolicy, RetentionReport, RetentionTarget } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { BaseRepo } from "./base.repo";
+
+export abstract class BaseRetentionRepo extends BaseRepo {
+  abstract listPolicies(): Promise<RetentionPolicy[]>;
+  abstract savePolicy(policy: RetentionPolicy): Promise<RetentionPolicy>;
+  abstract previewReport(): Promise<RetentionReport>;
+  abstract runNow(): Promise<RetentionReport>;
+  abstract setStarred(
+    target: RetentionTarget,
+    id: string,
+    starred: boolean,
+  ): Promise<void>;
+}
+
+export class LocalRetentionRepo extends BaseRetentionRepo {
+  async listPolicies(): Promise<RetentionPolicy[]> {
+    return invoke<RetentionPolicy[]>("retention_policy_list");
+  }
+
+  async savePolicy(policy: RetentionPolicy): Promise<RetentionPolicy> {
+    return invoke<RetentionPolicy>("retention_policy_save", { policy });
+  }
+
+  async previewReport(): Promise<RetentionReport> {
+    return invoke<RetentionReport>("retention_report");
+  }
+
+  async runNow(): Promise<RetentionReport> {
+    return invoke<RetentionReport>("retention_run");
+  }
+
+  async setStarred(
+    target: RetentionTarget,
+    id: string,
+    starred: boolean,
+  ): Promise<void> {
+    await invoke<void>("retention_set_starred", { target, id, starred });
+  }
+}
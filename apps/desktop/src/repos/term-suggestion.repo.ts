@@ -0,0 +1,41 @@
+import {
+  TermSuggestion,
+  TermSuggestionReview,
+  TermSuggestionStatus,
+} from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { BaseRepo } from "./base.repo";
+import { fromLocalTerm, LocalTerm } from "./term.repo";
+
+type LocalTermSuggestionReview = {
+  suggestion: TermSuggestion;
+  term: LocalTerm | null;
+};
+
+export abstract class BaseTermSuggestionRepo extends BaseRepo {
+  abstract listSuggestions(): Promise<TermSuggestion[]>;
+  abstract reviewSuggestion(
+    id: string,
+    status: TermSuggestionStatus,
+  ): Promise<TermSuggestionReview>;
+}
+
+export class LocalTermSuggestionRepo extends BaseTermSuggestionRepo {
+  async listSuggestions(): Promise<TermSuggestion[]> {
+    return invoke<TermSuggestion[]>("term_suggestion_list");
+  }
+
+  async reviewSuggestion(
+    id: string,
+    status: TermSuggestionStatus,
+  ): Promise<TermSuggestionReview> {
+    const review = await invoke<LocalTermSuggestionReview>(
+      "term_suggestion_review",
+      { id, status },
+    );
+    return {
+      suggestion: review.suggestion,
+      term: review.term ? fromLocalTerm(review.term) : null,
+    };
+  }
+}
@@ -4,7 +4,7 @@ import { invoke } from "@tauri-apps/api/core";
 import dayjs from "dayjs";
 import { BaseRepo } from "./base.repo";
 
-type LocalTerm = {
+export type LocalTerm = {
   id: string;
   createdAt: number;
   createdByUserId: string;
@@ -24,7 +24,7 @@ const toLocalTerm = (term: Term): LocalTerm => ({
   isDeleted: false,
 });
 
-const fromLocalTerm = (term: LocalTerm): Term => ({
+export const fromLocalTerm = (term: LocalTerm): Term => ({
   id: term.id,
   createdAt: dayjs(term.createdAt).toISOString(),
   sourceValue: term.sourceValue,
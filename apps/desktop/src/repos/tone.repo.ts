@@ -1,4 +1,4 @@
-import { Tone } from "@repo/types";
+import { Tone, ToneTemplateContext, ToneTemplatePreview } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { BaseRepo } from "./base.repo";
 import { getDefaultSystemTones } from "../utils/tone.utils";
@@ -42,6 +42,10 @@ export abstract class BaseToneRepo extends BaseRepo {
   abstract getTone(id: string): Promise<Tone | null>;
   abstract upsertTone(tone: Tone): Promise<Tone>;
   abstract deleteTone(id: string): Promise<void>;
+  abstract previewTone(
+    promptTemplate: string,
+    context?: ToneTemplateContext,
+  ): Promise<ToneTemplatePreview>;
 }
 
 export class LocalToneRepo extends BaseToneRepo {
@@ -79,4 +83,13 @@ export class LocalToneRepo extends BaseToneRepo {
 
     await invoke("tone_delete", { id });
   }
+
+  async previewTone(
+    promptTemplate: string,
+    context: ToneTemplateContext = {},
+  ): Promise<ToneTemplatePreview> {
+    return invoke<ToneTemplatePreview>("tone_preview", {
+      args: { promptTemplate, context },
+    });
+  }
 }
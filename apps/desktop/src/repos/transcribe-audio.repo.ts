@@ -40,6 +40,8 @@ type TranscriptionDeviceSelection = {
 
 type TranscriptionOptionsPayload = {
   modelSize: string;
+  profileId: Nullable<string>;
+  appTargetId: Nullable<string>;
   device?: TranscriptionDeviceSelection;
   deviceLabel: string;
 };
@@ -55,11 +57,18 @@ export type TranscribeAudioInput = {
   sampleRate: number;
   prompt?: Nullable<string>;
   language?: string;
+  // Only local Whisper translates; other providers ignore it.
+  translateToEnglish?: boolean;
+  // Languages local Whisper picks from per recording; others use `language`.
+  languageCandidates?: string[];
 };
 
 export type TranscribeAudioOutput = {
   text: string;
   metadata?: Nullable<TranscribeAudioMetadata>;
+  detectedLanguage?: Nullable<string>;
+  languageProbability?: Nullable<number>;
+  translated?: boolean;
 };
 
 export type TranscribeSegmentInput = {
@@ -67,6 +76,15 @@ export type TranscribeSegmentInput = {
   sampleRate: number;
   prompt?: Nullable<string>;
   language?: string;
+  translateToEnglish?: boolean;
+  languageCandidates?: string[];
+};
+
+type TranscribedAudioResponse = {
+  text: string;
+  detectedLanguage?: string;
+  languageProbability?: number;
+  translated: boolean;
 };
 
 export abstract class BaseTranscribeAudioRepo extends BaseRepo {
@@ -120,6 +138,8 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
         sampleRate: input.sampleRate,
         prompt: input.prompt,
         language: input.language,
+        translateToEnglish: input.translateToEnglish,
+        languageCandidates: input.languageCandidates,
       });
     }
 
@@ -139,6 +159,8 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
           sampleRate: input.sampleRate,
           prompt: input.prompt,
           language: input.language,
+          translateToEnglish: input.translateToEnglish,
+          languageCandidates: input.languageCandidates,
         }),
     );
 
@@ -158,6 +180,9 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
     return {
       text: mergedText,
       metadata,
+      detectedLanguage: results[0]?.detectedLanguage ?? null,
+      languageProbability: results[0]?.languageProbability ?? null,
+      translated: results.some((r) => r.translated),
     };
   }
 }
@@ -179,13 +204,18 @@ export class LocalTranscribeAudioRepo extends BaseTranscribeAudioRepo {
 
   private async resolveTranscriptionOptions(): Promise<TranscriptionOptionsPayload> {
     const state = getAppState();
-    const { device, modelSize } = state.settings.aiTranscription;
+    const { device } = state.settings.aiTranscription;
+    const appProfile = state.activeAppProfile;
+    const modelSize =
+      appProfile?.modelSize ?? state.settings.aiTranscription.modelSize;
 
     const normalizedModelSize =
       modelSize?.trim().toLowerCase() || DEFAULT_MODEL_SIZE;
 
     const options: TranscriptionOptionsPayload = {
       modelSize: normalizedModelSize,
+      profileId: appProfile?.profileId ?? null,
+      appTargetId: appProfile?.appTargetId ?? null,
       deviceLabel: "CPU",
     };
 
@@ -229,24 +259,35 @@ export class LocalTranscribeAudioRepo extends BaseTranscribeAudioRepo {
     input: TranscribeSegmentInput,
   ): Promise<TranscribeAudioOutput> {
     const options = await this.resolveTranscriptionOptions();
-    const transcript = await invoke<string>("transcribe_audio", {
-      samples: Array.from(input.samples),
-      sampleRate: input.sampleRate,
-      options: {
-        modelSize: options.modelSize,
-        device: options.device,
-        initialPrompt: input.prompt,
-        language: input.language,
+    // The dictionary prompt, replacements and ITN are applied on the Rust side.
+    const response = await invoke<TranscribedAudioResponse>(
+      "transcribe_audio_detailed",
+      {
+        samples: Array.from(input.samples),
+        sampleRate: input.sampleRate,
+        options: {
+          modelSize: options.modelSize,
+          device: options.device,
+          // Used as is unless detection picks among the candidates.
+          language: input.language,
+          profileId: options.profileId,
+          translate: input.translateToEnglish ?? false,
+          languageCandidates: input.languageCandidates ?? [],
+          appTargetId: options.appTargetId,
+        },
       },
-    });
+    );
 
     return {
-      text: transcript,
+      text: response.text,
       metadata: {
         inferenceDevice: options.deviceLabel,
         modelSize: options.modelSize,
         transcriptionMode: "local",
       },
+      detectedLanguage: response.detectedLanguage ?? null,
+      languageProbability: response.languageProbability ?? null,
+      translated: response.translated,
     };
   }
 }
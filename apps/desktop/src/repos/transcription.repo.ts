@@ -1,4 +1,5 @@
 import {
+  MediaImportInput,
   PostProcessingMode,
   Transcription,
   TranscriptionAudioSnapshot,
@@ -31,6 +32,11 @@ type LocalTranscription = {
   transcriptionDurationMs?: number | null;
   postprocessDurationMs?: number | null;
   warnings?: string[] | null;
+  appTargetId?: string | null;
+  sourceLanguage?: string | null;
+  detectedLanguage?: string | null;
+  targetLanguage?: string | null;
+  starred?: boolean;
 };
 
 export type TranscriptionAudioData = {
@@ -69,6 +75,11 @@ const toLocalTranscription = (
   transcriptionDurationMs: transcription.transcriptionDurationMs ?? null,
   postprocessDurationMs: transcription.postprocessDurationMs ?? null,
   warnings: transcription.warnings ?? null,
+  appTargetId: transcription.appTargetId ?? null,
+  sourceLanguage: transcription.sourceLanguage ?? null,
+  detectedLanguage: transcription.detectedLanguage ?? null,
+  targetLanguage: transcription.targetLanguage ?? null,
+  starred: transcription.starred ?? false,
 });
 
 const fromLocalTranscription = (
@@ -99,6 +110,11 @@ const fromLocalTranscription = (
   transcriptionDurationMs: transcription.transcriptionDurationMs ?? undefined,
   postprocessDurationMs: transcription.postprocessDurationMs ?? undefined,
   warnings: transcription.warnings ?? undefined,
+  appTargetId: transcription.appTargetId ?? undefined,
+  sourceLanguage: transcription.sourceLanguage ?? undefined,
+  detectedLanguage: transcription.detectedLanguage ?? undefined,
+  targetLanguage: transcription.targetLanguage ?? undefined,
+  starred: transcription.starred ?? false,
 });
 
 export abstract class BaseTranscriptionRepo extends BaseRepo {
@@ -114,6 +130,9 @@ export abstract class BaseTranscriptionRepo extends BaseRepo {
   ): Promise<Transcription>;
   abstract loadTranscriptionAudio(id: string): Promise<TranscriptionAudioData>;
   abstract purgeStaleAudio(): Promise<string[]>;
+  abstract importTranscriptionMedia(
+    input: MediaImportInput,
+  ): Promise<Transcription>;
 }
 
 export class LocalTranscriptionRepo extends BaseTranscriptionRepo {
@@ -164,4 +183,16 @@ export class LocalTranscriptionRepo extends BaseTranscriptionRepo {
     );
     return Array.isArray(purged) ? purged : [];
   }
+
+  async importTranscriptionMedia({
+    importId,
+    path,
+    modelSize,
+  }: MediaImportInput): Promise<Transcription> {
+    const result = await invoke<{ transcription: LocalTranscription }>(
+      "media_import",
+      { importId, path, target: "transcription", options: { modelSize } },
+    );
+    return fromLocalTranscription(result.transcription);
+  }
 }
@@ -6,10 +6,13 @@ import {
   FullConfig,
   Hotkey,
   Meeting,
+  MeetingActionItem,
+  MeetingDecision,
   MeetingSegment,
   Member,
   Message,
   Nullable,
+  ResolvedAppProfile,
   Term,
   Tone,
   Transcription,
@@ -56,6 +59,8 @@ export type AppState = {
   keysHeld: string[];
   isRecordingHotkey: boolean;
   activeRecordingMode: Nullable<RecordingMode>;
+  // Resolved for the focused window when recording starts.
+  activeAppProfile: Nullable<ResolvedAppProfile>;
   overlayPhase: OverlayPhase;
   audioLevels: number[];
   permissions: PermissionMap;
@@ -74,6 +79,8 @@ export type AppState = {
   messageById: Record<string, Message>;
   meetingById: Record<string, Meeting>;
   meetingSegmentById: Record<string, MeetingSegment>;
+  meetingActionItemById: Record<string, MeetingActionItem>;
+  meetingDecisionById: Record<string, MeetingDecision>;
   config: Nullable<FullConfig>;
   priceValueByKey: Record<string, PriceValue>;
 
@@ -107,6 +114,7 @@ export const INITIAL_APP_STATE: AppState = {
   userPrefs: null,
   isRecordingHotkey: false,
   activeRecordingMode: null,
+  activeAppProfile: null,
   memberById: {},
   userById: {},
   termById: {},
@@ -119,6 +127,8 @@ export const INITIAL_APP_STATE: AppState = {
   messageById: {},
   meetingById: {},
   meetingSegmentById: {},
+  meetingActionItemById: {},
+  meetingDecisionById: {},
   overlayPhase: "idle",
   audioLevels: [],
   permissions: {
@@ -1,16 +1,21 @@
 import { Nullable } from "@repo/types";
 
 export type MessageAttachment = {
-  type: "transcription" | "text";
+  type: "transcription" | "text" | "meetingSegment";
   id: string;
   label: string;
   content: string;
+  meetingId?: string;
+  meetingTitle?: string;
+  startMs?: number;
+  endMs?: number;
 };
 
 export type ChatState = {
   activeConversationId: Nullable<string>;
   conversationIds: string[];
   messageIds: string[];
+  siblingIdsByMessageId: Record<string, string[]>;
   isLoading: boolean;
   isStreaming: boolean;
   streamingMessageId: Nullable<string>;
@@ -22,6 +27,7 @@ export const INITIAL_CHAT_STATE: ChatState = {
   activeConversationId: null,
   conversationIds: [],
   messageIds: [],
+  siblingIdsByMessageId: {},
   isLoading: false,
   isStreaming: false,
   streamingMessageId: null,
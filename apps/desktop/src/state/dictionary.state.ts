@@ -1,11 +1,16 @@
+import { TermSuggestion } from "@repo/types";
 import { ActionStatus } from "../types/state.types";
 
 export type DictionaryState = {
   termIds: string[];
   status: ActionStatus;
+  suggestions: TermSuggestion[];
+  suggestionsDialogOpen: boolean;
 };
 
 export const INITIAL_DICTIONARY_STATE: DictionaryState = {
   termIds: [],
   status: "idle",
+  suggestions: [],
+  suggestionsDialogOpen: false,
 };
@@ -1,19 +1,37 @@
-import { Nullable } from "@repo/types";
+import {
+  MediaImportProgress,
+  Nullable,
+  OpenMeetingActionItem,
+} from "@repo/types";
 
 export type MeetingState = {
   meetingIds: string[];
   activeMeetingId: Nullable<string>;
   segmentIds: string[];
+  actionItemIds: string[];
+  decisionIds: string[];
+  openActionItems: OpenMeetingActionItem[];
+  qaConversationId: Nullable<string>;
+  qaMessageIds: string[];
+  isAsking: boolean;
   isRecording: boolean;
   isProcessing: boolean;
   recordingElapsedMs: number;
+  importProgress: Nullable<MediaImportProgress>;
 };
 
 export const INITIAL_MEETING_STATE: MeetingState = {
   meetingIds: [],
   activeMeetingId: null,
   segmentIds: [],
+  actionItemIds: [],
+  decisionIds: [],
+  openActionItems: [],
+  qaConversationId: null,
+  qaMessageIds: [],
+  isAsking: false,
   isRecording: false,
   isProcessing: false,
   recordingElapsedMs: 0,
+  importProgress: null,
 };
@@ -38,6 +38,7 @@ export type LanguageSwitchState = {
   enabled: boolean;
   secondaryLanguage: Nullable<string>;
   activeLanguage: "primary" | "secondary";
+  autoDetect: boolean;
 };
 
 export type VibeCodingState = {
@@ -57,6 +58,7 @@ export type SettingsState = {
   audioDialogOpen: boolean;
   shortcutsDialogOpen: boolean;
   clearLocalDataDialogOpen: boolean;
+  retentionDialogOpen: boolean;
   profileDialogOpen: boolean;
   aiTranscriptionDialogOpen: boolean;
   aiPostProcessingDialogOpen: boolean;
@@ -90,6 +92,7 @@ export const INITIAL_SETTINGS_STATE: SettingsState = {
   audioDialogOpen: false,
   shortcutsDialogOpen: false,
   clearLocalDataDialogOpen: false,
+  retentionDialogOpen: false,
   profileDialogOpen: false,
   aiTranscriptionDialogOpen: false,
   aiPostProcessingDialogOpen: false,
@@ -116,6 +119,7 @@ export const INITIAL_SETTINGS_STATE: SettingsState = {
     enabled: false,
     secondaryLanguage: null,
     activeLanguage: "primary",
+    autoDetect: false,
   },
   vibeCoding: {
     variableRecognitionEnabled: false,
@@ -8,6 +8,7 @@ import {
 } from "../actions/transcribe.actions";
 import { getIntl } from "../i18n";
 import { getAppState } from "../store";
+import type { DictationStep } from "../types/dictation.types";
 import type { OverlayPhase } from "../types/overlay.types";
 import type {
   HandleTranscriptParams,
@@ -19,6 +20,7 @@ import {
   applyReplacements,
   applySymbolConversions,
 } from "../utils/string.utils";
+import { joinStepTexts, splitStepTexts } from "../utils/transcribe.utils";
 import { BaseStrategy } from "./base.strategy";
 
 export class DictationStrategy extends BaseStrategy {
@@ -61,6 +63,8 @@ export class DictationStrategy extends BaseStrategy {
     a11yInfo,
     currentApp,
     loadingToken,
+    transcriptionMetadata,
+    transcriptionId,
   }: HandleTranscriptParams): Promise<HandleTranscriptResult> {
     const resetPhase = async () => {
       if (
@@ -75,40 +79,118 @@ export class DictationStrategy extends BaseStrategy {
     let transcript: string | null = null;
     let sanitizedTranscript: string | null = null;
     let postProcessMetadata: PostProcessMetadata = {};
-    let postProcessWarnings: string[] = [];
+    const postProcessWarnings: string[] = [];
 
     try {
       const state = getAppState();
+      const appProfile = state.activeAppProfile;
+      const allowedTermIds = appProfile?.termIds
+        ? new Set(appProfile.termIds)
+        : null;
+      // Local transcripts already had dictionary replacements applied in Rust.
+      const isLocalTranscription =
+        state.settings.aiTranscription.mode === "local";
       const replacementRules = Object.values(state.termById)
-        .filter((term) => term.isReplacement)
+        .filter((term) => !isLocalTranscription && term.isReplacement)
+        .filter((term) => !allowedTermIds || allowedTermIds.has(term.id))
         .map((term) => ({
           sourceValue: term.sourceValue,
           destinationValue: term.destinationValue,
         }));
 
-      const afterReplacements = applyReplacements(
-        rawTranscript,
-        replacementRules,
-      );
-      sanitizedTranscript = applySymbolConversions(afterReplacements);
-
-      const result = await postProcessTranscript({
-        rawTranscript: sanitizedTranscript,
-        toneId,
-        a11yInfo,
+      // Commands are parsed from the raw transcript, before post-processing
+      // can reword them; only the text between them is post-processed.
+      const steps = await invoke<DictationStep[]>("dictation_parse", {
+        text: rawTranscript,
       });
 
-      transcript = result.transcript;
-      postProcessMetadata = result.metadata;
-      postProcessWarnings = result.warnings;
+      const sanitizedTexts: string[] = [];
+      for (const step of steps) {
+        if (step.kind !== "text") {
+          continue;
+        }
+
+        const afterReplacements = applyReplacements(
+          step.value,
+          replacementRules,
+        );
+        const converted = applySymbolConversions(afterReplacements);
+        let sanitized = converted;
+        try {
+          // Local transcripts were already normalized while transcribing.
+          sanitized = await invoke<string>("transcript_normalize", {
+            text: converted,
+            appTargetId: currentApp?.id ?? null,
+            profileId: appProfile?.profileId ?? null,
+            applyItn: !isLocalTranscription,
+          });
+        } catch (error) {
+          console.error("Failed to normalize transcript", error);
+        }
+        sanitizedTexts.push(sanitized);
+      }
+
+      // The text runs are post-processed together, so the rewrite keeps
+      // their context and costs one request however many commands there are.
+      let processedTexts = sanitizedTexts;
+      if (sanitizedTexts.length > 0) {
+        // Whisper may already have translated to English while transcribing.
+        const translationLanguage = appProfile?.translationLanguage ?? null;
+        const result = await postProcessTranscript({
+          rawTranscript: joinStepTexts(sanitizedTexts),
+          toneId,
+          a11yInfo,
+          appName: currentApp?.name ?? null,
+          transcriptLanguage:
+            transcriptionMetadata.targetLanguage ??
+            transcriptionMetadata.detectedLanguage,
+          translateTo:
+            translationLanguage !== transcriptionMetadata.targetLanguage
+              ? translationLanguage
+              : null,
+          transcriptionId,
+        });
+        postProcessMetadata = result.metadata;
+        postProcessWarnings.push(...result.warnings);
+
+        const split = splitStepTexts(result.transcript, sanitizedTexts.length);
+        if (split) {
+          processedTexts = split;
+        } else {
+          postProcessWarnings.push(
+            "Post-processing moved a voice command, so the text was pasted as dictated.",
+          );
+        }
+      }
+
+      const processedSteps: DictationStep[] = [];
+      let textIndex = 0;
+      for (const step of steps) {
+        if (step.kind !== "text") {
+          processedSteps.push(step);
+          continue;
+        }
+
+        const value = processedTexts[textIndex++];
+        if (value) {
+          processedSteps.push({ kind: "text", value });
+        }
+      }
+
+      sanitizedTranscript = sanitizedTexts.join(" ");
+      transcript = processedTexts.filter(Boolean).join(" ") || null;
 
       await resetPhase();
 
-      if (transcript) {
+      if (processedSteps.length > 0) {
         await new Promise<void>((resolve) => setTimeout(resolve, 20));
         try {
           const keybind = currentApp?.pasteKeybind ?? null;
-          await invoke<void>("paste", { text: transcript, keybind });
+          await invoke<void>("paste_dictation", {
+            steps: processedSteps,
+            keybind,
+            profileId: appProfile?.profileId ?? null,
+          });
         } catch (error) {
           console.error("Failed to paste transcription", error);
           showErrorSnackbar("Unable to paste transcription.");
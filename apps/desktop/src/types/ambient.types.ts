@@ -0,0 +1,8 @@
+export type AmbientWakePayload = {
+  wakePhrase: string;
+};
+
+export const AMBIENT_STATUS_EVENT = "ambient_status";
+export const AMBIENT_WAKE_EVENT = "ambient_wake";
+export const AMBIENT_SPEECH_ENDED_EVENT = "ambient_speech_ended";
+export const DEFAULT_WAKE_PHRASE = "hey os voice";
@@ -1 +1,6 @@
 export type AudioSamples = number[] | Float32Array | null | undefined;
+
+export const AUDIO_DEVICES_CHANGED_EVENT = "audio_devices_changed";
+
+export const MICROPHONE_CALIBRATION_PROGRESS_EVENT =
+  "microphone_calibration_progress";
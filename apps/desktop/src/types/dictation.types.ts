@@ -0,0 +1,7 @@
+/**
+ * A parsed dictation step. Key chords are built and pressed by the backend,
+ * so the webview only passes them through.
+ */
+export type DictationStep =
+  | { kind: "text"; value: string }
+  | { kind: "keys"; value: unknown };
@@ -23,6 +23,8 @@ export type HandleTranscriptParams = {
   audio: StopRecordingResponse;
   transcriptionMetadata: TranscribeAudioMetadata;
   transcriptionWarnings: string[];
+  /** History row stored before post-processing, for the rewrite to land in. */
+  transcriptionId: Nullable<string>;
 };
 
 export type HandleTranscriptResult = {
@@ -3,6 +3,8 @@ import { TranscribeAudioMetadata } from "../actions/transcribe.actions";
 export type StopRecordingResponse = {
   samples: number[] | Float32Array;
   sampleRate?: number;
+  // Problems with the recording itself, such as a microphone switch.
+  warnings?: string[];
 };
 
 export type TranscriptionSessionResult = {
@@ -47,4 +47,6 @@ export const applyAiPreferences = (
     preferences.secondaryDictationLanguage ?? null;
   draft.settings.languageSwitch.activeLanguage =
     preferences.activeDictationLanguage ?? "primary";
+  draft.settings.languageSwitch.autoDetect =
+    preferences.languageDetectionEnabled ?? false;
 };
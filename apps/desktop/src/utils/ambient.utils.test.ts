@@ -0,0 +1,36 @@
+import { describe, expect, it } from "vitest";
+import { stripWakePhrase } from "./ambient.utils";
+
+describe("stripWakePhrase", () => {
+  it("removes the phrase and the punctuation after it", () => {
+    expect(stripWakePhrase("Hey OS Voice, take a note.", "hey os voice")).toBe(
+      "Take a note.",
+    );
+    expect(stripWakePhrase("Hey, O.S. voice. Send it.", "hey os voice")).toBe(
+      "Send it.",
+    );
+  });
+
+  it("tolerates fillers and close spellings", () => {
+    expect(stripWakePhrase("Okay, hey osvoice remind me", "hey os voice")).toBe(
+      "Remind me",
+    );
+    expect(stripWakePhrase("Hey OS choice. Hello there", "hey os voice")).toBe(
+      "Hello there",
+    );
+  });
+
+  it("leaves transcripts without the phrase alone", () => {
+    expect(stripWakePhrase("Take a note.", "hey os voice")).toBe(
+      "Take a note.",
+    );
+    expect(
+      stripWakePhrase("I said hello to my voice coach", "hey os voice"),
+    ).toBe("I said hello to my voice coach");
+    expect(stripWakePhrase("Hey OS Voice", "")).toBe("Hey OS Voice");
+  });
+
+  it("returns an empty transcript when only the phrase was said", () => {
+    expect(stripWakePhrase("Hey OS Voice.", "hey os voice")).toBe("");
+  });
+});
@@ -0,0 +1,69 @@
+import { editDistance } from "./string.utils";
+
+// The recording starts at the wake phrase, so only a filler word or two may
+// come before it.
+const MAX_WORDS_BEFORE_PHRASE = 2;
+
+type TranscriptWord = {
+  text: string;
+  end: number;
+};
+
+const getWords = (text: string): TranscriptWord[] =>
+  Array.from(text.matchAll(/[\p{L}\p{N}]+/gu), (match) => ({
+    text: match[0].toLowerCase(),
+    end: (match.index ?? 0) + match[0].length,
+  }));
+
+/**
+ * Removes the wake phrase from the start of a transcript dictated through
+ * ambient listening. Words are compared without case, punctuation or spaces,
+ * allowing one edit for every five letters, so "Hey, OS voice." and
+ * "hey osvoice" both match "hey os voice".
+ */
+export const stripWakePhrase = (
+  transcript: string,
+  wakePhrase: string,
+): string => {
+  const phraseWords = getWords(wakePhrase).map((word) => word.text);
+  if (phraseWords.length === 0) {
+    return transcript;
+  }
+
+  const target = phraseWords.join("");
+  const allowedEdits = Math.max(1, Math.floor(target.length / 5));
+  const words = getWords(transcript);
+  const shortest = Math.max(1, phraseWords.length - 1);
+  const longest = phraseWords.length + 1;
+
+  for (
+    let start = 0;
+    start <= MAX_WORDS_BEFORE_PHRASE && start < words.length;
+    start++
+  ) {
+    let bestEnd: number | null = null;
+    let bestDistance = allowedEdits + 1;
+    for (
+      let length = shortest;
+      length <= longest && start + length <= words.length;
+      length++
+    ) {
+      const window = words.slice(start, start + length);
+      const distance = editDistance(
+        window.map((word) => word.text).join(""),
+        target,
+      );
+      if (distance < bestDistance) {
+        bestDistance = distance;
+        bestEnd = window[window.length - 1]!.end;
+      }
+    }
+
+    if (bestEnd !== null) {
+      const rest = transcript.slice(bestEnd).replace(/^[\s\p{P}]+/u, "");
+      return rest.charAt(0).toUpperCase() + rest.slice(1);
+    }
+  }
+
+  return transcript;
+};
@@ -0,0 +1,52 @@
+import { describe, expect, it } from "vitest";
+import { parseMeetingSummaryResponse } from "./meeting-prompt.utils";
+
+describe("parseMeetingSummaryResponse", () => {
+  it("should normalize decisions and action items", () => {
+    const result = parseMeetingSummaryResponse(
+      JSON.stringify({
+        summary: "Planning sync",
+        keyTopics: ["roadmap"],
+        decisions: ["Ship in May", { text: "Drop v1", sourceSegment: 4 }],
+        actionItems: [
+          {
+            task: "Write the spec",
+            assignee: "Sam",
+            dueDate: "2026-05-01",
+            priority: "High",
+            sourceSegment: 2,
+          },
+          { task: "Book a room", dueDate: "next week", sourceSegment: -1 },
+          { task: "  " },
+        ],
+      }),
+    );
+
+    expect(result.decisions).toEqual([
+      { text: "Ship in May", sourceSegment: null },
+      { text: "Drop v1", sourceSegment: 4 },
+    ]);
+    expect(result.actionItems).toEqual([
+      {
+        task: "Write the spec",
+        assignee: "Sam",
+        dueDate: "2026-05-01",
+        priority: "high",
+        sourceSegment: 2,
+      },
+      {
+        task: "Book a room",
+        assignee: null,
+        dueDate: null,
+        priority: null,
+        sourceSegment: null,
+      },
+    ]);
+  });
+
+  it("should fall back to the raw text when the response is not JSON", () => {
+    const result = parseMeetingSummaryResponse("Just a summary");
+    expect(result.summary).toBe("Just a summary");
+    expect(result.actionItems).toEqual([]);
+  });
+});
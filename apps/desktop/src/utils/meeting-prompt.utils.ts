@@ -10,10 +10,10 @@ function formatTimestamp(ms: number): string {
 
 function formatTranscriptFromSegments(segments: MeetingSegment[]): string {
   return segments
-    .map((seg) => {
+    .map((seg, idx) => {
       const time = formatTimestamp(seg.startMs);
       const speaker = seg.speakerName ?? seg.speakerId ?? "Speaker";
-      return `[${time}] ${speaker}: ${seg.text}`;
+      return `#${idx} [${time}] ${speaker}: ${seg.text}`;
     })
     .join("\n");
 }
@@ -26,15 +26,19 @@ export function buildMeetingSummaryPrompt(segments: MeetingSegment[]): string {
 {
   "summary": "A concise 2-4 paragraph summary of the meeting covering the main discussion points.",
   "keyTopics": ["topic1", "topic2", "topic3"],
-  "decisions": ["decision1", "decision2"],
+  "decisions": [
+    {"text": "what was decided", "sourceSegment": 12}
+  ],
   "actionItems": [
-    {"task": "description of task", "assignee": "person name or null", "priority": "high|medium|low"}
+    {"task": "description of task", "assignee": "person name or null", "dueDate": "YYYY-MM-DD or null", "priority": "high|medium|low", "sourceSegment": 7}
   ]
 }
 
 Rules:
 - Write the summary in clear, professional language
 - Extract only action items that were explicitly discussed or agreed upon
+- Each transcript line starts with its number (e.g. #7); set sourceSegment to the number of the line where the decision or action item was stated, or null if unsure
+- Only set dueDate when a specific date was agreed upon
 - If no speaker names are available, use the speaker IDs
 - If no clear decisions or action items exist, return empty arrays
 - Return ONLY valid JSON, no markdown formatting
@@ -43,13 +47,74 @@ Meeting Transcript:
 ${transcript}`;
 }
 
+export type MeetingSummaryDecision = {
+  text: string;
+  sourceSegment: number | null;
+};
+
+export type MeetingSummaryActionItem = {
+  task: string;
+  assignee: string | null;
+  dueDate: string | null;
+  priority: string | null;
+  sourceSegment: number | null;
+};
+
 export type MeetingSummaryResult = {
   summary: string;
   keyTopics: string[];
-  decisions: string[];
-  actionItems: { task: string; assignee: string | null; priority: string }[];
+  decisions: MeetingSummaryDecision[];
+  actionItems: MeetingSummaryActionItem[];
 };
 
+const DUE_DATE_PATTERN = /^\d{4}-\d{2}-\d{2}$/;
+
+function asText(value: unknown): string | null {
+  return typeof value === "string" && value.trim() ? value.trim() : null;
+}
+
+function asSegmentIndex(value: unknown): number | null {
+  return typeof value === "number" && Number.isInteger(value) && value >= 0
+    ? value
+    : null;
+}
+
+function normalizeDecision(value: unknown): MeetingSummaryDecision | null {
+  if (typeof value === "string") {
+    const text = asText(value);
+    return text ? { text, sourceSegment: null } : null;
+  }
+  if (typeof value !== "object" || value === null) return null;
+  const raw = value as Record<string, unknown>;
+  const text = asText(raw.text);
+  return text
+    ? { text, sourceSegment: asSegmentIndex(raw.sourceSegment) }
+    : null;
+}
+
+function normalizeActionItem(value: unknown): MeetingSummaryActionItem | null {
+  if (typeof value !== "object" || value === null) return null;
+  const raw = value as Record<string, unknown>;
+  const task = asText(raw.task);
+  if (!task) return null;
+  const dueDate = asText(raw.dueDate);
+  return {
+    task,
+    assignee: asText(raw.assignee),
+    dueDate: dueDate && DUE_DATE_PATTERN.test(dueDate) ? dueDate : null,
+    priority: asText(raw.priority)?.toLowerCase() ?? null,
+    sourceSegment: asSegmentIndex(raw.sourceSegment),
+  };
+}
+
+function normalizeList<T>(
+  value: unknown,
+  normalize: (v: unknown) => T | null,
+): T[] {
+  if (!Array.isArray(value)) return [];
+  return value.map(normalize).filter((v): v is T => v !== null);
+}
+
 export function parseMeetingSummaryResponse(
   text: string,
 ): MeetingSummaryResult {
@@ -59,12 +124,12 @@ export function parseMeetingSummaryResponse(
     .trim();
 
   try {
-    const parsed = JSON.parse(cleaned) as MeetingSummaryResult;
+    const parsed = JSON.parse(cleaned) as Record<string, unknown>;
     return {
-      summary: parsed.summary ?? "",
-      keyTopics: Array.isArray(parsed.keyTopics) ? parsed.keyTopics : [],
-      decisions: Array.isArray(parsed.decisions) ? parsed.decisions : [],
-      actionItems: Array.isArray(parsed.actionItems) ? parsed.actionItems : [],
+      summary: typeof parsed.summary === "string" ? parsed.summary : "",
+      keyTopics: normalizeList(parsed.keyTopics, asText),
+      decisions: normalizeList(parsed.decisions, normalizeDecision),
+      actionItems: normalizeList(parsed.actionItems, normalizeActionItem),
     };
   } catch {
     return {
@@ -1,7 +1,9 @@
 import { describe, expect, it } from "vitest";
 import {
+  joinStepTexts,
   mergeTranscriptions,
   splitAudioTranscription,
+  splitStepTexts,
 } from "./transcribe.utils";
 
 describe("mergeTranscriptions", () => {
@@ -601,3 +603,28 @@ describe("splitAudioTranscription", () => {
     });
   });
 });
+
+describe("step texts", () => {
+  it("should round-trip runs through a rewrite", () => {
+    const joined = joinStepTexts(["dear team", "thanks for coming", "bye"]);
+    expect(joined).toBe("dear team [[1]] thanks for coming [[2]] bye");
+
+    const rewritten = "Dear team,[[1]] Thanks for coming. [[2]]Bye.";
+    expect(splitStepTexts(rewritten, 3)).toEqual([
+      "Dear team,",
+      "Thanks for coming.",
+      "Bye.",
+    ]);
+  });
+
+  it("should keep a single run unmarked", () => {
+    expect(joinStepTexts(["hello"])).toBe("hello");
+    expect(splitStepTexts("Hello.", 1)).toEqual(["Hello."]);
+  });
+
+  it("should reject dropped or reordered markers", () => {
+    expect(splitStepTexts("Dear team, thanks [[2]] bye", 3)).toBeNull();
+    expect(splitStepTexts("a [[2]] b [[1]] c", 3)).toBeNull();
+    expect(splitStepTexts("a [[1]] b [[1]] c", 3)).toBeNull();
+  });
+});
@@ -254,3 +254,36 @@ export const splitAudioTranscription = (args: {
 
   return segments;
 }
+
+const STEP_MARKER_PATTERN = /\s*\[\[(\d+)\]\]\s*/g;
+
+/**
+ * Joins the text runs between voice commands so they can be post-processed
+ * in one request. `[[1]]`, `[[2]]`, ... mark where each command was.
+ */
+export const joinStepTexts = (texts: string[]): string =>
+  texts
+    .map((text, index) => (index === 0 ? text : `[[${index}]] ${text}`))
+    .join(" ");
+
+/**
+ * Splits a post-processed text back into its runs. Returns null when the
+ * markers were dropped, repeated or reordered.
+ */
+export const splitStepTexts = (
+  text: string,
+  count: number,
+): string[] | null => {
+  const markers = [...text.matchAll(STEP_MARKER_PATTERN)].map((match) =>
+    Number(match[1]),
+  );
+  const inOrder = markers.every((marker, index) => marker === index + 1);
+  if (markers.length !== count - 1 || !inOrder) {
+    return null;
+  }
+
+  return text
+    .split(STEP_MARKER_PATTERN)
+    .filter((_, index) => index % 2 === 0)
+    .map((part) => part.trim());
+};
@@ -88,6 +88,23 @@ export const getMyDictationLanguage = (state: AppState): string => {
   return getMyPrimaryDictationLanguage(state);
 };
 
+/**
+ * Languages to detect between on each dictation, or an empty list when the
+ * active language should be used as-is.
+ */
+export const getMyDictationLanguageCandidates = (
+  state: AppState,
+): string[] => {
+  const { enabled, secondaryLanguage, autoDetect } =
+    state.settings.languageSwitch;
+
+  if (!enabled || !autoDetect || !secondaryLanguage) {
+    return [];
+  }
+
+  return [getMyPrimaryDictationLanguage(state), secondaryLanguage];
+};
+
 export const getMyDictationLanguageCode = (state: AppState): string => {
   const language = getMyDictationLanguage(state);
   const baseCode = language.split("-")[0];
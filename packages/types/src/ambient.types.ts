@@ -0,0 +1,6 @@
+import type { Nullable } from "./common.types";
+
+export type AmbientStatus = {
+  armed: boolean;
+  wakePhrase?: Nullable<string>;
+};
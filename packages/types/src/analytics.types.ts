@@ -0,0 +1,57 @@
+export type UsageAnalyticsRequest = {
+  fromMs?: number | null;
+  toMs?: number | null;
+  utcOffsetMinutes: number;
+};
+
+export type UsageTotals = {
+  transcriptions: number;
+  words: number;
+  speakingMs: number;
+  averageWpm: number | null;
+  timeSavedMs: number;
+  typingWpm: number;
+  warningRate: number;
+};
+
+export type UsagePeriod = {
+  period: string;
+  transcriptions: number;
+  words: number;
+  speakingMs: number;
+};
+
+export type WpmBucket = {
+  minWpm: number;
+  maxWpm: number | null;
+  transcriptions: number;
+};
+
+export type AppUsage = {
+  appTargetId: string | null;
+  name: string | null;
+  transcriptions: number;
+  words: number;
+  speakingMs: number;
+};
+
+export type LatencyStage = "transcription" | "postProcessing";
+
+export type ModelLatency = {
+  stage: LatencyStage;
+  mode: string | null;
+  model: string | null;
+  transcriptions: number;
+  averageMs: number | null;
+  maxMs: number | null;
+  warningRate: number;
+};
+
+export type UsageAnalytics = {
+  totals: UsageTotals;
+  wordsByDay: UsagePeriod[];
+  wordsByWeek: UsagePeriod[];
+  wpmDistribution: WpmBucket[];
+  apps: AppUsage[];
+  models: ModelLatency[];
+};
@@ -7,4 +7,43 @@ export type AppTarget = {
   toneId: Nullable<string>;
   iconPath: Nullable<string>;
   pasteKeybind: Nullable<string>;
+  itnEnabled: boolean;
+  translationLanguage: Nullable<string>;
+};
+
+export type InsertionStrategy = "paste" | "type";
+
+export type AppProfile = {
+  id: string;
+  name: string;
+  createdAt: string;
+  appIdentifier: Nullable<string>;
+  appTargetId: Nullable<string>;
+  windowTitlePattern: Nullable<string>;
+  priority: number;
+  language: Nullable<string>;
+  modelSize: Nullable<string>;
+  insertionStrategy: Nullable<InsertionStrategy>;
+  termIds: Nullable<string[]>;
+  itnEnabled: Nullable<boolean>;
+  autoCapitalize: Nullable<boolean>;
+  trailingPunctuation: Nullable<boolean>;
+};
+
+export type ResolvedAppProfile = {
+  profileId: Nullable<string>;
+  appTargetId: Nullable<string>;
+  appName: string;
+  appIdentifier: Nullable<string>;
+  windowTitle: Nullable<string>;
+  toneId: Nullable<string>;
+  pasteKeybind: Nullable<string>;
+  language: Nullable<string>;
+  modelSize: Nullable<string>;
+  insertionStrategy: InsertionStrategy;
+  termIds: Nullable<string[]>;
+  itnEnabled: boolean;
+  autoCapitalize: Nullable<boolean>;
+  trailingPunctuation: Nullable<boolean>;
+  translationLanguage: Nullable<string>;
 };
@@ -3,6 +3,9 @@ export type MessageRole = "user" | "assistant" | "system";
 export type Conversation = {
   id: string;
   title: string;
+  meetingId?: string | null;
+  headMessageId?: string | null;
+  starred?: boolean;
   createdAt: string;
   updatedAt: string;
 };
@@ -10,6 +13,7 @@ export type Conversation = {
 export type Message = {
   id: string;
   conversationId: string;
+  parentId?: string | null;
   role: MessageRole;
   content: string;
   model?: string | null;
@@ -22,3 +26,50 @@ export type ChatMessage = {
   role: "user" | "assistant";
   content: string;
 };
+
+export type ConversationSummary = {
+  id: string;
+  conversationId: string;
+  throughMessageId: string;
+  content: string;
+  tokenCount: number;
+  model?: string | null;
+  createdAt: string;
+};
+
+export type BranchMessage = Message & {
+  siblingIds: string[];
+};
+
+export type ConversationContextRequest = {
+  conversationId: string;
+  leafMessageId?: string | null;
+  model: string;
+  reservedTokens?: number;
+  /** The model's context window, when the provider reports one. */
+  contextWindow?: number | null;
+  /** Set for Ollama chats, which the backend summarizes itself. */
+  ollamaApiKeyId?: string | null;
+};
+
+/** A prompt that folds older messages into the conversation summary. */
+export type ConversationCompaction = {
+  throughMessageId: string;
+  system: string;
+  prompt: string;
+};
+
+export type ConversationContext = {
+  summary?: ConversationSummary | null;
+  messages: Message[];
+  compaction?: ConversationCompaction | null;
+  budgetTokens: number;
+  estimatedTokens: number;
+};
+
+export type ConversationSummaryRequest = {
+  conversationId: string;
+  throughMessageId: string;
+  content: string;
+  model?: string | null;
+};
@@ -13,3 +13,10 @@ export * from "./tone.types";
 export * from "./openrouter.types";
 export * from "./conversation.types";
 export * from "./meeting.types";
+export * from "./local-api.types";
+export * from "./mcp.types";
+export * from "./media-import.types";
+export * from "./retention.types";
+export * from "./analytics.types";
+export * from "./ambient.types";
+export * from "./microphone.types";
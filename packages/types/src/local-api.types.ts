@@ -0,0 +1,16 @@
+import type { Nullable } from "./common.types";
+
+export type LocalApiStatus = {
+  enabled: boolean;
+  port: number;
+  running: boolean;
+  url: Nullable<string>;
+  token: Nullable<string>;
+  tokenSuffix: Nullable<string>;
+};
+
+export type LocalApiConfigureRequest = {
+  enabled: boolean;
+  port?: number;
+  regenerateToken?: boolean;
+};
@@ -0,0 +1,8 @@
+export type McpPermissionRequest = {
+  requestId: string;
+  clientName: string;
+  tool: string;
+  summary: string;
+};
+
+export type McpPermissionDecision = "deny" | "allowOnce" | "allowSession";
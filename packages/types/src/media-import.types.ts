@@ -0,0 +1,15 @@
+export type MediaImportTarget = "meeting" | "transcription";
+
+export type MediaImportStage = "decoding" | "saving" | "transcribing" | "done";
+
+export type MediaImportProgress = {
+  importId: string;
+  stage: MediaImportStage;
+  progress: number;
+};
+
+export type MediaImportInput = {
+  importId: string;
+  path: string;
+  modelSize?: string;
+};
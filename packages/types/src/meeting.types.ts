@@ -1,3 +1,5 @@
+import type { ChatMessage, Conversation, Message } from "./conversation.types";
+
 export type MeetingStatus = "recording" | "processing" | "completed" | "failed";
 
 export type Meeting = {
@@ -11,6 +13,7 @@ export type Meeting = {
   audioPath?: string | null;
   summary?: string | null;
   actionItems?: string | null;
+  starred?: boolean;
   createdAt: string;
   updatedAt: string;
 };
@@ -25,3 +28,92 @@ export type MeetingSegment = {
   endMs: number;
   createdAt: string;
 };
+
+export type MeetingActionItemStatus = "open" | "done";
+
+export type MeetingActionItem = {
+  id: string;
+  meetingId: string;
+  task: string;
+  owner?: string | null;
+  dueDate?: string | null;
+  priority?: string | null;
+  status: MeetingActionItemStatus;
+  sourceSegmentId?: string | null;
+  position: number;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type OpenMeetingActionItem = MeetingActionItem & {
+  meetingTitle: string;
+  meetingStartedAt: string;
+};
+
+export type MeetingDecision = {
+  id: string;
+  meetingId: string;
+  text: string;
+  sourceSegmentId?: string | null;
+  position: number;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type MeetingQuestion = {
+  question: string;
+  meetingId?: string | null;
+  /** Earliest start, `YYYY-MM-DD` or an ISO timestamp. */
+  from?: string | null;
+  /** Latest start; a bare date includes the whole day. */
+  to?: string | null;
+  conversationId?: string | null;
+  apiKeyId?: string | null;
+};
+
+export type MeetingCitation = {
+  segmentId: string;
+  meetingId: string;
+  meetingTitle: string;
+  speakerName?: string | null;
+  text: string;
+  startMs: number;
+  endMs: number;
+};
+
+/** A meeting question prepared for a provider the app calls directly. */
+export type MeetingQuestionPrompt = {
+  conversationId: string;
+  parentMessageId?: string | null;
+  question: string;
+  askedAt: string;
+  system: string;
+  messages: ChatMessage[];
+  /** The numbered excerpts in the prompt, `[1]` first. */
+  excerpts: MeetingCitation[];
+};
+
+export type MeetingAnswer = {
+  conversation: Conversation;
+  question: Message;
+  answer: Message;
+  citations: MeetingCitation[];
+};
+
+export type TranscriptExportFormat = "srt" | "vtt" | "markdown" | "text" | "json";
+
+export type TimestampGranularity = "none" | "seconds" | "milliseconds";
+
+export type TranscriptExportOptions = {
+  format: TranscriptExportFormat;
+  speakerLabels?: boolean;
+  timestamps?: TimestampGranularity;
+  mergeSpeakers?: boolean;
+  mergeGapMs?: number;
+};
+
+export type TranscriptExport = {
+  fileName: string;
+  mimeType: string;
+  content: string;
+};
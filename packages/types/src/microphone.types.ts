@@ -0,0 +1,31 @@
+import type { Nullable } from "./common.types";
+
+export type MicrophoneCalibration = {
+  deviceName: string;
+  noiseFloorDb: number;
+  speechLevelDb: number;
+  snrDb: number;
+  clippingRatio: number;
+  dcOffset: number;
+  score: number;
+  recommendedGain: number;
+  measuredAt: string;
+};
+
+export type MicrophoneCalibrationFailure = {
+  deviceName: string;
+  error: string;
+};
+
+export type MicrophoneCalibrationProgress = {
+  deviceName: string;
+  index: number;
+  total: number;
+};
+
+export type MicrophoneCalibrationReport = {
+  results: MicrophoneCalibration[];
+  failures: MicrophoneCalibrationFailure[];
+  recommendedDevice: Nullable<string>;
+  recommendedGain: Nullable<number>;
+};
@@ -26,9 +26,14 @@ export type UserPreferences = {
   languageSwitchEnabled: boolean;
   secondaryDictationLanguage: Nullable<string>;
   activeDictationLanguage: "primary" | "secondary";
+  languageDetectionEnabled: boolean;
   preferredMicrophone: Nullable<string>;
   ignoreUpdateDialog: boolean;
   incognitoModeEnabled: boolean;
   incognitoModeIncludeInStats: boolean;
   dictationPillVisibility: DictationPillVisibility;
+  ambientWakePhrase: Nullable<string>;
+  warmMicrophoneMs: Nullable<number>;
+  inputGain: Nullable<number>;
+  voiceCommandKeywordRequired: boolean;
 };
@@ -0,0 +1,34 @@
+export type RetentionTarget = "transcription" | "meeting" | "conversation";
+
+export type RetentionAction = "audio" | "all";
+
+export type RetentionPolicy = {
+  target: RetentionTarget;
+  enabled: boolean;
+  maxAgeDays?: number | null;
+  maxAudioBytes?: number | null;
+  keepStarred: boolean;
+  action: RetentionAction;
+  lastRunAt?: string | null;
+};
+
+export type RetentionReason = "age" | "storage";
+
+export type RetentionItem = {
+  target: RetentionTarget;
+  id: string;
+  label: string;
+  createdAt: string;
+  reason: RetentionReason;
+  action: RetentionAction;
+  audioBytes: number;
+};
+
+export type RetentionReport = {
+  dryRun: boolean;
+  generatedAt: string;
+  items: RetentionItem[];
+  audioFiles: number;
+  audioBytes: number;
+  recordsDeleted: number;
+};
@@ -27,3 +27,21 @@ export const TermZod = z
     isReplacement: z.boolean(),
   })
   .strict() satisfies z.ZodType<Term>;
+
+export type TermSuggestionStatus = "pending" | "accepted" | "rejected";
+
+export type TermSuggestion = {
+  id: string;
+  sourceValue: string;
+  destinationValue: string;
+  status: TermSuggestionStatus;
+  occurrences: number;
+  confidence: number;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type TermSuggestionReview = {
+  suggestion: TermSuggestion;
+  term: Term | null;
+};
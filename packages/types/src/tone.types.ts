@@ -6,3 +6,26 @@ export type Tone = {
   createdAt: number;
   sortOrder: number;
 };
+
+export type ToneTemplateContext = {
+  appName?: string | null;
+  selectedText?: string | null;
+  precedingText?: string | null;
+  followingText?: string | null;
+  userName?: string | null;
+  userCompany?: string | null;
+  userTitle?: string | null;
+  date?: string | null;
+  language?: string | null;
+};
+
+export type ToneTemplateIssue = {
+  message: string;
+  line: number;
+  column: number;
+};
+
+export type ToneTemplatePreview = {
+  rendered: string;
+  issues: ToneTemplateIssue[];
+};
@@ -21,6 +21,11 @@ export type Transcription = {
   transcriptionDurationMs?: number | null;
   postprocessDurationMs?: number | null;
   warnings?: string[] | null;
+  appTargetId?: string | null;
+  sourceLanguage?: string | null;
+  detectedLanguage?: string | null;
+  targetLanguage?: string | null;
+  starred?: boolean;
 };
 
 export type TranscriptionAudioSnapshot = {